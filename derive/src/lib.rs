@@ -0,0 +1,194 @@
+//! The `#[derive(SipFingerprint)]` macro of the `siphash_c_d` crate, re-exported by its `derive` feature.
+//!
+//! A struct or an enum is encoded by its `u32` version, its name, the name of the variant for an enum, its `u64`
+//! number of fields and the name and value of each field, the fields of tuples being named by their index. The
+//! `#[sip(...)]` attributes are:
+//!
+//! * `rename = "name"` on the type, a variant or a field, to keep the fingerprints after a renaming;
+//! * `skip` on a field, which is not part of the fingerprint;
+//! * `version = 1` on the type, to change all its fingerprints on purpose (the default version is 0).
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Fields, Ident, LitInt, LitStr,
+};
+
+/// Implement `SipFingerprint` for a struct or an enum.
+#[proc_macro_derive(SipFingerprint, attributes(sip))]
+pub fn derive_sip_fingerprint(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+// the values of the #[sip(...)] attributes
+#[derive(Default)]
+struct Options {
+    rename: Option<String>,
+    skip: bool,
+    version: u32,
+}
+
+impl Options {
+    // the attributes of an item, which accepts only the allowed ones
+    fn parse(attrs: &[Attribute], allowed: &[&str]) -> syn::Result<Self> {
+        let mut options = Options::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("sip")) {
+            attr.parse_nested_meta(|meta| {
+                let name = meta
+                    .path
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                if !allowed.contains(&name.as_str()) {
+                    return Err(meta.error(format!(
+                        "unsupported sip attribute, expected: {}",
+                        allowed.join(", ")
+                    )));
+                }
+
+                match name.as_str() {
+                    "rename" => options.rename = Some(meta.value()?.parse::<LitStr>()?.value()),
+                    "skip" => options.skip = true,
+                    _ => options.version = meta.value()?.parse::<LitInt>()?.base10_parse()?,
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(options)
+    }
+
+    fn name(&self, ident: &Ident) -> String {
+        self.rename.clone().unwrap_or_else(|| ident.to_string())
+    }
+}
+
+// the encoding of the fields, whose values are the expressions of the accessors
+fn fields(fields: &Fields, accessors: &[TokenStream2]) -> syn::Result<TokenStream2> {
+    let mut names = Vec::new();
+    let mut values = Vec::new();
+
+    for (i, (field, accessor)) in fields.iter().zip(accessors).enumerate() {
+        let options = Options::parse(&field.attrs, &["rename", "skip"])?;
+        if options.skip {
+            continue;
+        }
+
+        names.push(match &field.ident {
+            Some(ident) => options.name(ident),
+            None => options.rename.unwrap_or_else(|| i.to_string()),
+        });
+        values.push(accessor);
+    }
+
+    let count = names.len() as u64;
+    Ok(quote! {
+        <u64 as ::siphash_c_d::SipFingerprint>::fingerprint(&#count, __siphash);
+        #(
+            <str as ::siphash_c_d::SipFingerprint>::fingerprint(#names, __siphash);
+            ::siphash_c_d::SipFingerprint::fingerprint(#values, __siphash);
+        )*
+    })
+}
+
+// the bindings of the fields of a variant
+fn bindings(fields: &Fields) -> (TokenStream2, Vec<TokenStream2>) {
+    let idents: Vec<Ident> = (0..fields.len())
+        .map(|i| format_ident!("__field{}", i))
+        .collect();
+    let accessors = idents.iter().map(|ident| quote!(#ident)).collect();
+
+    let pattern = match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|field| &field.ident);
+            quote!({ #(#names: #idents),* })
+        }
+        Fields::Unnamed(_) => quote!(( #(#idents),* )),
+        Fields::Unit => quote!(),
+    };
+    (pattern, accessors)
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let options = Options::parse(&input.attrs, &["rename", "version"])?;
+    let name = options.name(&input.ident);
+    let version = options.version;
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let accessors: Vec<TokenStream2> = data
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| match &field.ident {
+                    Some(ident) => quote!(&self.#ident),
+                    None => {
+                        let index = syn::Index::from(i);
+                        quote!(&self.#index)
+                    }
+                })
+                .collect();
+            fields(&data.fields, &accessors)?
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                let variant_name =
+                    Options::parse(&variant.attrs, &["rename"])?.name(&variant.ident);
+                let ident = &variant.ident;
+                let (pattern, accessors) = bindings(&variant.fields);
+                let fields = fields(&variant.fields, &accessors)?;
+
+                // the skipped fields are bound too
+                arms.push(quote! {
+                    #[allow(unused_variables)]
+                    Self::#ident #pattern => {
+                        <str as ::siphash_c_d::SipFingerprint>::fingerprint(#variant_name, __siphash);
+                        #fields
+                    }
+                });
+            }
+            if arms.is_empty() {
+                quote!(match *self {})
+            } else {
+                quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "SipFingerprint can't be derived for unions",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(parse_quote!(::siphash_c_d::SipFingerprint));
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::siphash_c_d::SipFingerprint for #ident #type_generics #where_clause {
+            fn fingerprint<const __C: u8, const __D: u8, __T: ::siphash_c_d::HashOutput>(
+                &self,
+                __siphash: &mut ::siphash_c_d::SipHash<__C, __D, __T>,
+            ) {
+                <u32 as ::siphash_c_d::SipFingerprint>::fingerprint(&#version, __siphash);
+                <str as ::siphash_c_d::SipFingerprint>::fingerprint(#name, __siphash);
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,180 @@
+use siphash_c_d::{vectors::KEY, SipFingerprint, SipHash24};
+
+// the fingerprint without the encoding version, to compare it with the expected encoding
+fn fingerprint<V: SipFingerprint>(value: &V) -> u64 {
+    let mut siphash = SipHash24::new(&KEY).unwrap();
+    value.fingerprint(&mut siphash);
+    siphash.finalize()
+}
+
+fn hash(bytes: &[u8]) -> u64 {
+    SipHash24::with_key(&KEY, bytes).unwrap()
+}
+
+// the encoding of a name
+fn name(bytes: &mut Vec<u8>, name: &str) {
+    bytes.extend_from_slice(&(name.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(name.as_bytes());
+}
+
+#[derive(SipFingerprint)]
+struct Config {
+    name: String,
+    retries: u8,
+}
+
+#[test]
+fn test_struct_encoding() {
+    let config = Config {
+        name: "db".to_string(),
+        retries: 3,
+    };
+
+    let mut expected = 0u32.to_le_bytes().to_vec();
+    name(&mut expected, "Config");
+    expected.extend_from_slice(&2u64.to_le_bytes());
+    name(&mut expected, "name");
+    name(&mut expected, "db");
+    name(&mut expected, "retries");
+    expected.push(3);
+
+    assert_eq!(fingerprint(&config), hash(&expected));
+
+    let mut versioned = vec![1];
+    versioned.extend_from_slice(&expected);
+    assert_eq!(config.sip_fingerprint(&KEY).unwrap(), hash(&versioned));
+}
+
+// the same fingerprint as Config after renamings and a new skipped field
+#[derive(SipFingerprint)]
+#[sip(rename = "Config")]
+struct Settings {
+    #[sip(rename = "name")]
+    service: String,
+    retries: u8,
+    #[sip(skip)]
+    #[allow(dead_code)]
+    cache: std::collections::HashMap<String, String>,
+}
+
+#[derive(SipFingerprint)]
+#[sip(version = 2)]
+struct Versioned {
+    name: String,
+    retries: u8,
+}
+
+#[test]
+fn test_rename_skip_version() {
+    let config = Config {
+        name: "db".to_string(),
+        retries: 3,
+    };
+    let settings = Settings {
+        service: "db".to_string(),
+        retries: 3,
+        cache: [("a".to_string(), "b".to_string())].into_iter().collect(),
+    };
+
+    assert_eq!(fingerprint(&config), fingerprint(&settings));
+
+    // the version is the first encoded value
+    let versioned = Versioned {
+        name: "db".to_string(),
+        retries: 3,
+    };
+    assert_ne!(fingerprint(&config), fingerprint(&versioned));
+}
+
+#[derive(SipFingerprint)]
+enum Shape {
+    Empty,
+    Circle(f64),
+    #[sip(rename = "Rect")]
+    Rectangle {
+        width: u32,
+        #[sip(rename = "h")]
+        height: u32,
+        #[sip(skip)]
+        label: &'static str,
+    },
+}
+
+#[test]
+fn test_enum_encoding() {
+    let mut expected = 0u32.to_le_bytes().to_vec();
+    name(&mut expected, "Shape");
+    let header = expected.clone();
+
+    name(&mut expected, "Empty");
+    expected.extend_from_slice(&0u64.to_le_bytes());
+    assert_eq!(fingerprint(&Shape::Empty), hash(&expected));
+
+    let mut expected = header.clone();
+    name(&mut expected, "Circle");
+    expected.extend_from_slice(&1u64.to_le_bytes());
+    name(&mut expected, "0");
+    expected.extend_from_slice(&1.5f64.to_bits().to_le_bytes());
+    assert_eq!(fingerprint(&Shape::Circle(1.5)), hash(&expected));
+
+    let mut expected = header;
+    name(&mut expected, "Rect");
+    expected.extend_from_slice(&2u64.to_le_bytes());
+    name(&mut expected, "width");
+    expected.extend_from_slice(&4u32.to_le_bytes());
+    name(&mut expected, "h");
+    expected.extend_from_slice(&5u32.to_le_bytes());
+    assert_eq!(
+        fingerprint(&Shape::Rectangle {
+            width: 4,
+            height: 5,
+            label: "skipped"
+        }),
+        hash(&expected)
+    );
+}
+
+#[derive(SipFingerprint)]
+struct Pair<A, B>(A, B);
+
+#[derive(SipFingerprint)]
+struct Unit;
+
+#[derive(SipFingerprint)]
+enum Never {}
+
+#[derive(SipFingerprint)]
+struct Nested<'a, T> {
+    items: &'a [T],
+    unit: Unit,
+    pair: Option<Pair<T, &'a str>>,
+}
+
+#[test]
+fn test_generics_and_nesting() {
+    let items = [1u16, 2];
+    let a = Nested {
+        items: &items,
+        unit: Unit,
+        pair: Some(Pair(3u16, "ab")),
+    };
+    let b = Nested {
+        items: &items,
+        unit: Unit,
+        pair: Some(Pair(3u16, "a")),
+    };
+    assert_ne!(fingerprint(&a), fingerprint(&b));
+
+    // the fields are prefix-free
+    assert_ne!(fingerprint(&Pair("ab", "c")), fingerprint(&Pair("a", "bc")));
+
+    let mut expected = 0u32.to_le_bytes().to_vec();
+    name(&mut expected, "Unit");
+    expected.extend_from_slice(&0u64.to_le_bytes());
+    assert_eq!(fingerprint(&Unit), hash(&expected));
+
+    fn never(n: &Never) -> u64 {
+        fingerprint(n)
+    }
+    let _ = never;
+}
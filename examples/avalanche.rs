@@ -0,0 +1,19 @@
+// Compare the diffusion of several siphash_c_d variants.
+//
+// Usage: cargo run --release --features tools --example avalanche [SAMPLES]
+use siphash_c_d::{analysis::Avalanche, Hash128, Hash64};
+
+fn main() {
+    let samples = std::env::args()
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(1000);
+
+    // 16-byte messages, so 2 blocks are compressed
+    println!("{}", Avalanche::<1, 1, Hash64>::new(16, samples).report());
+    println!("{}", Avalanche::<1, 2, Hash64>::new(16, samples).report());
+    println!("{}", Avalanche::<1, 3, Hash64>::new(16, samples).report());
+    println!("{}", Avalanche::<2, 4, Hash64>::new(16, samples).report());
+    println!("{}", Avalanche::<1, 3, Hash128>::new(16, samples).report());
+    println!("{}", Avalanche::<2, 4, Hash128>::new(16, samples).report());
+}
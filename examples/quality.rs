@@ -0,0 +1,48 @@
+// Run the SMHasher-style quality tests on several siphash_c_d variants.
+//
+// Usage: cargo run --release --features tools --example quality
+use siphash_c_d::{
+    quality::{SipTarget, Suite, Target},
+    Hash128, Hash64,
+};
+
+const KEY: (u64, u64) = (0x0706050403020100, 0x0f0e0d0c0b0a0908);
+
+fn run<G: Target>(name: &str, target: &G, buffer: &mut [u64]) {
+    println!("{}", name);
+    let passed = Suite::default().run(target, buffer, |result| println!("  {}", result));
+    println!(
+        "  => {}",
+        if passed { "all tests passed" } else { "FAILED" }
+    );
+}
+
+fn main() {
+    let mut buffer = vec![0u64; Suite::default().buffer_length()];
+
+    run(
+        "siphash_1_1",
+        &SipTarget::<1, 1, Hash64>::new(KEY).unwrap(),
+        &mut buffer,
+    );
+    run(
+        "siphash_1_2",
+        &SipTarget::<1, 2, Hash64>::new(KEY).unwrap(),
+        &mut buffer,
+    );
+    run(
+        "siphash_1_3",
+        &SipTarget::<1, 3, Hash64>::new(KEY).unwrap(),
+        &mut buffer,
+    );
+    run(
+        "siphash_2_4",
+        &SipTarget::<2, 4, Hash64>::new(KEY).unwrap(),
+        &mut buffer,
+    );
+    run(
+        "siphash_1_3 (128-bit, high half)",
+        &SipTarget::<1, 3, Hash128>::new(KEY).unwrap().high(),
+        &mut buffer,
+    );
+}
@@ -0,0 +1,110 @@
+// Parse the exported functions of src/lib.rs for the C header: their documentation, name, return type and
+// parameters, the Rust types being mapped to C types. The result is the FUNCTIONS table included by src/header.rs.
+use std::{env, fmt::Write, fs, path::Path};
+
+use quote::ToTokens;
+use syn::{Expr, FnArg, Item, ItemFn, Lit, Meta, Pat, ReturnType, Type};
+
+// the C type of a Rust parameter or return type
+fn c_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            let name = path.path.segments.last().map(|s| s.ident.to_string());
+            match name.as_deref() {
+                Some("c_int") => "int",
+                Some("usize") => "size_t",
+                Some("u8") => "uint8_t",
+                Some("State") => "siphash_c_d_state",
+                _ => panic!("no C type for {}", ty.to_token_stream()),
+            }
+            .to_string()
+        }
+        Type::Ptr(ptr) => {
+            let pointee = c_type(&ptr.elem);
+            match (pointee.ends_with('*'), ptr.const_token.is_some()) {
+                (true, true) => format!("{}const *", pointee),
+                (true, false) => format!("{}*", pointee),
+                (false, true) => format!("const {} *", pointee),
+                (false, false) => format!("{} *", pointee),
+            }
+        }
+        _ => panic!("no C type for {}", ty.to_token_stream()),
+    }
+}
+
+// the lines of the /// comments
+fn doc(function: &ItemFn) -> Vec<String> {
+    function
+        .attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(nv) if nv.path.is_ident("doc") => match &nv.value {
+                Expr::Lit(lit) => match &lit.lit {
+                    Lit::Str(s) => Some(s.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_exported(function: &ItemFn) -> bool {
+    let no_mangle = function
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("no_mangle"));
+    let abi_c = function
+        .sig
+        .abi
+        .as_ref()
+        .and_then(|abi| abi.name.as_ref())
+        .is_some_and(|name| name.value() == "C");
+    no_mangle && abi_c
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/lib.rs");
+
+    let source = fs::read_to_string("src/lib.rs").expect("can't read src/lib.rs");
+    let file = syn::parse_file(&source).expect("can't parse src/lib.rs");
+
+    let mut functions = String::from("&[\n");
+    for item in &file.items {
+        let function = match item {
+            Item::Fn(function) if is_exported(function) => function,
+            _ => continue,
+        };
+
+        let ret = match &function.sig.output {
+            ReturnType::Default => "void".to_string(),
+            ReturnType::Type(_, ty) => c_type(ty),
+        };
+        let params: Vec<(String, String)> = function
+            .sig
+            .inputs
+            .iter()
+            .map(|input| match input {
+                FnArg::Typed(arg) => match &*arg.pat {
+                    Pat::Ident(name) => (c_type(&arg.ty), name.ident.to_string()),
+                    _ => panic!("unnamed parameter in {}", function.sig.ident),
+                },
+                FnArg::Receiver(_) => panic!("method {} can't be exported", function.sig.ident),
+            })
+            .collect();
+
+        let _ = writeln!(
+            functions,
+            "    Function {{ doc: &{:?}, name: {:?}, ret: {:?}, params: &{:?} }},",
+            doc(function),
+            function.sig.ident.to_string(),
+            ret,
+            params
+        );
+    }
+    functions.push_str("]\n");
+
+    let out = Path::new(&env::var("OUT_DIR").expect("no OUT_DIR")).join("functions.rs");
+    fs::write(out, functions).expect("can't write the functions");
+}
@@ -0,0 +1,63 @@
+/* Hash a message with the C bindings, in one shot and by streaming.
+ *
+ * cargo build -p siphash_c_d_ffi
+ * cc -I ffi/include ffi/examples/example.c target/debug/libsiphash_c_d_ffi.a -lpthread -ldl -lm -o example
+ */
+#include <stdio.h>
+#include <string.h>
+
+#include "siphash_c_d.h"
+
+static void print_hex(const char *label, const uint8_t *bytes, size_t length) {
+    printf("%s: ", label);
+    for (size_t i = 0; i < length; i++) {
+        printf("%02x", bytes[i]);
+    }
+    printf("\n");
+}
+
+int main(void) {
+    uint8_t key[16];
+    for (int i = 0; i < 16; i++) {
+        key[i] = (uint8_t)i;
+    }
+    const char *message = "hello, world";
+    size_t length = strlen(message);
+
+    if (siphash_c_d_self_test() != SIPHASH_C_D_OK) {
+        fprintf(stderr, "self-test failed\n");
+        return 1;
+    }
+
+    uint8_t out64[8];
+    int rc = siphash_c_d_hash(SIPHASH_C_D_2_4_64, key, sizeof key, (const uint8_t *)message, length, out64,
+                              sizeof out64);
+    if (rc != SIPHASH_C_D_OK) {
+        fprintf(stderr, "siphash_c_d_hash: %d\n", rc);
+        return 1;
+    }
+    print_hex("siphash_2_4_64", out64, sizeof out64);
+
+    siphash_c_d_state *state;
+    rc = siphash_c_d_init(SIPHASH_C_D_2_4_128, key, sizeof key, &state);
+    if (rc != SIPHASH_C_D_OK) {
+        fprintf(stderr, "siphash_c_d_init: %d\n", rc);
+        return 1;
+    }
+    siphash_c_d_update(state, (const uint8_t *)message, 5);
+    siphash_c_d_update(state, (const uint8_t *)message + 5, length - 5);
+
+    uint8_t out128[16];
+    rc = siphash_c_d_final(state, out128, sizeof out128);
+    siphash_c_d_free(state);
+    if (rc != SIPHASH_C_D_OK) {
+        fprintf(stderr, "siphash_c_d_final: %d\n", rc);
+        return 1;
+    }
+    print_hex("siphash_2_4_128", out128, sizeof out128);
+
+    /* an output buffer of the wrong length is an error */
+    rc = siphash_c_d_hash(SIPHASH_C_D_2_4_64, key, sizeof key, NULL, 0, out128, sizeof out128);
+    printf("16-byte output for a 64-bit variant: %d\n", rc);
+    return 0;
+}
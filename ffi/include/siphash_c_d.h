@@ -0,0 +1,63 @@
+/* C bindings of the siphash_c_d crate. Generated by siphash_c_d_header: don't edit. */
+#ifndef SIPHASH_C_D_H
+#define SIPHASH_C_D_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* variants */
+#define SIPHASH_C_D_1_3_64 0 /* siphash_1_3 with a 64-bit output. */
+#define SIPHASH_C_D_2_4_64 1 /* siphash_2_4 with a 64-bit output. */
+#define SIPHASH_C_D_4_8_64 2 /* siphash_4_8 with a 64-bit output. */
+#define SIPHASH_C_D_1_3_128 3 /* siphash_1_3 with a 128-bit output. */
+#define SIPHASH_C_D_2_4_128 4 /* siphash_2_4 with a 128-bit output. */
+#define SIPHASH_C_D_4_8_128 5 /* siphash_4_8 with a 128-bit output. */
+
+/* error codes */
+#define SIPHASH_C_D_OK (0) /* Success. */
+#define SIPHASH_C_D_ERROR_NULL_POINTER (-1) /* A pointer is NULL. */
+#define SIPHASH_C_D_ERROR_INVALID_VARIANT (-2) /* The variant is unknown. */
+#define SIPHASH_C_D_ERROR_KEY_TOO_SHORT (-3) /* The key is less than 16 bytes. */
+#define SIPHASH_C_D_ERROR_INVALID_OUTPUT_LENGTH (-4) /* The output buffer is not 8 bytes for a 64-bit variant, or 16 bytes for a 128-bit variant. */
+#define SIPHASH_C_D_ERROR_SELF_TEST_FAILED (-5) /* The self-test failed. */
+#define SIPHASH_C_D_ERROR_OTHER (-6) /* Any other error. */
+#define SIPHASH_C_D_ERROR_KEY_TOO_LONG (-7) /* The key is more than 16 bytes. */
+
+/* opaque streaming state */
+typedef struct siphash_c_d_state siphash_c_d_state;
+
+/* Hash the input_length bytes of input using the key_length bytes of key, which must be 16, and write the 8
+   or 16 bytes of the hash value to out. The pointers must be valid for their lengths. input can be NULL if
+   input_length is 0. */
+int siphash_c_d_hash(int variant, const uint8_t *key, size_t key_length, const uint8_t *input,
+                     size_t input_length, uint8_t *out, size_t out_length);
+
+/* Allocate a streaming state in *state using the key_length bytes of key, which must be 16. It must be
+   released by siphash_c_d_free(). key must be valid for key_length bytes and state must be a valid pointer. */
+int siphash_c_d_init(int variant, const uint8_t *key, size_t key_length, siphash_c_d_state **state);
+
+/* Add the input_length bytes of input to the message being hashed. state must have been allocated by
+   siphash_c_d_init(), and input must be valid for input_length bytes. */
+int siphash_c_d_update(siphash_c_d_state *state, const uint8_t *input, size_t input_length);
+
+/* Write the 8 or 16 bytes of the hash value of the bytes added so far to out. More bytes can be added
+   afterwards. state must have been allocated by siphash_c_d_init(), and out must be valid for out_length
+   bytes. */
+int siphash_c_d_final(const siphash_c_d_state *state, uint8_t *out, size_t out_length);
+
+/* Release a state allocated by siphash_c_d_init(). Nothing is done if state is NULL. state must have been
+   allocated by siphash_c_d_init() and not already released. */
+void siphash_c_d_free(siphash_c_d_state *state);
+
+/* Check the implementation against the reference test vectors. */
+int siphash_c_d_self_test(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SIPHASH_C_D_H */
@@ -0,0 +1,6 @@
+// Print the C header of the bindings.
+//
+// Usage: cargo run -p siphash_c_d_ffi --bin siphash_c_d_header > ffi/include/siphash_c_d.h
+fn main() {
+    print!("{}", siphash_c_d_ffi::header());
+}
@@ -0,0 +1,169 @@
+// generation of the C header from the constants and the exported functions of lib.rs
+use std::fmt::Write;
+
+use crate::{ERRORS, VARIANTS};
+
+// maximum length of the lines of the comments and of the prototypes
+const WIDTH: usize = 110;
+
+// an exported function, as parsed by build.rs: its documentation and its C types
+struct Function {
+    doc: &'static [&'static str],
+    name: &'static str,
+    ret: &'static str,
+    // the type and name of the parameters
+    params: &'static [(&'static str, &'static str)],
+}
+
+// the exported functions, in the order of lib.rs
+const FUNCTIONS: &[Function] = include!(concat!(env!("OUT_DIR"), "/functions.rs"));
+
+// the documentation without its Markdown: headings are dropped, code spans become plain text and null is NULL
+fn c_doc(doc: &[&str]) -> String {
+    doc.iter()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .flat_map(|line| line.split_whitespace())
+        .map(|word| {
+            let word = word.replace('`', "");
+            match word.strip_prefix("null") {
+                Some(rest) if rest.chars().all(|c| c.is_ascii_punctuation()) => {
+                    format!("NULL{}", rest)
+                }
+                _ => word,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// the C declaration of a function, the parameters being wrapped at WIDTH
+fn prototype(function: &Function) -> String {
+    let params: Vec<String> = function
+        .params
+        .iter()
+        .map(|(c, name)| {
+            if c.ends_with('*') {
+                format!("{}{}", c, name)
+            } else {
+                format!("{} {}", c, name)
+            }
+        })
+        .collect();
+
+    let mut prototype = format!("{} {}(", function.ret, function.name);
+    let indent = prototype.len();
+    let mut line_start = 0;
+    if params.is_empty() {
+        prototype.push_str("void");
+    }
+    for (i, param) in params.iter().enumerate() {
+        let last = i + 1 == params.len();
+        let text = if last {
+            format!("{});", param)
+        } else {
+            format!("{},", param)
+        };
+        if i != 0 {
+            if prototype.len() - line_start + 1 + text.len() > WIDTH {
+                prototype.push('\n');
+                line_start = prototype.len();
+                prototype.push_str(&" ".repeat(indent));
+            } else {
+                prototype.push(' ');
+            }
+        }
+        prototype.push_str(&text);
+    }
+    if params.is_empty() {
+        prototype.push_str(");");
+    }
+    prototype
+}
+
+// the comment of text, wrapped at WIDTH
+fn comment(text: &str) -> String {
+    let mut comment = String::from("/*");
+    let mut line_start = 0;
+    for word in text.split_whitespace() {
+        if comment.len() - line_start + 1 + word.len() > WIDTH {
+            comment.push_str("\n  ");
+            line_start = comment.len() - 2;
+        }
+        comment.push(' ');
+        comment.push_str(word);
+    }
+    comment.push_str(" */");
+    comment
+}
+
+/// The content of the C header.
+pub fn header() -> String {
+    let mut h = String::new();
+
+    h.push_str(
+        "/* C bindings of the siphash_c_d crate. Generated by siphash_c_d_header: don't edit. */\n",
+    );
+    h.push_str("#ifndef SIPHASH_C_D_H\n#define SIPHASH_C_D_H\n\n");
+    h.push_str("#include <stddef.h>\n#include <stdint.h>\n\n");
+    h.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n\n");
+
+    h.push_str("/* variants */\n");
+    for (name, value, doc) in VARIANTS {
+        let _ = writeln!(h, "#define {} {} /* {} */", name, value, c_doc(&[doc]));
+    }
+
+    h.push_str("\n/* error codes */\n");
+    for (name, value, doc) in ERRORS {
+        let _ = writeln!(h, "#define {} ({}) /* {} */", name, value, c_doc(&[doc]));
+    }
+
+    h.push_str(
+        "\n/* opaque streaming state */\ntypedef struct siphash_c_d_state siphash_c_d_state;\n",
+    );
+
+    for function in FUNCTIONS {
+        let _ = write!(
+            h,
+            "\n{}\n{}\n",
+            comment(&c_doc(function.doc)),
+            prototype(function)
+        );
+    }
+
+    h.push_str("\n#ifdef __cplusplus\n}\n#endif\n\n#endif /* SIPHASH_C_D_H */\n");
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::ErrorKind, path::Path, process::Command};
+
+    use super::*;
+
+    #[test]
+    fn test_all_functions() {
+        let source = include_str!("lib.rs");
+        assert_eq!(FUNCTIONS.len(), source.matches("#[no_mangle]").count());
+        assert_eq!(
+            prototype(&FUNCTIONS[0]),
+            "int siphash_c_d_hash(int variant, const uint8_t *key, size_t key_length, const uint8_t *input,\n                     size_t input_length, uint8_t *out, size_t out_length);"
+        );
+        assert_eq!(prototype(&FUNCTIONS[5]), "int siphash_c_d_self_test(void);");
+    }
+
+    #[test]
+    fn test_example_compiles() {
+        // the example must agree with the header, when a C compiler is available
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let status = Command::new("cc")
+            .args(["-std=c99", "-Wall", "-Werror", "-fsyntax-only", "-I"])
+            .arg(dir.join("include"))
+            .arg(dir.join("examples/example.c"))
+            .status();
+
+        match status {
+            Ok(status) => assert!(status.success()),
+            Err(e) => assert_eq!(e.kind(), ErrorKind::NotFound),
+        }
+    }
+}
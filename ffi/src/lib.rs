@@ -0,0 +1,403 @@
+//! C bindings of the `siphash_c_d` crate, built as a dynamic and a static library, so that C and C++ programs get the
+//! same hash values as the Rust ones.
+//!
+//! The `siphash_1_3`, `siphash_2_4` and `siphash_4_8` variants are available in 64 and 128 bits, either:
+//!
+//! * in one shot with `siphash_c_d_hash()`;
+//! * by streaming over an opaque state with `siphash_c_d_init()`, `siphash_c_d_update()`, `siphash_c_d_final()` and
+//!   `siphash_c_d_free()`.
+//!
+//! The output bytes are the ones of the `out[]` buffer of the reference implementation. All functions are returning
+//! `SIPHASH_C_D_OK` or a negative error code, the pointers and lengths being checked.
+//!
+//! The C header is `include/siphash_c_d.h`, generated by the [`header`] function from the constants and the
+//! declarations of the exported functions, which are parsed by `build.rs`:
+//!
+//! ```text
+//! cargo run -p siphash_c_d_ffi --bin siphash_c_d_header > ffi/include/siphash_c_d.h
+//! ```
+use std::{os::raw::c_int, ptr, slice};
+
+use siphash_c_d::{AnySipHash, SipError};
+
+mod header;
+pub use header::header;
+
+// define the constants and keep them in a table for the header
+macro_rules! constants {
+    ($table:ident: $($(#[doc = $doc:literal])+ $name:ident = $value:literal;)+) => {
+        $(
+            $(#[doc = $doc])+
+            pub const $name: c_int = $value;
+        )+
+
+        const $table: &[(&str, c_int, &str)] = &[$((stringify!($name), $name, concat!($($doc),+)),)+];
+    };
+}
+
+constants! {
+    VARIANTS:
+    /// `siphash_1_3` with a 64-bit output.
+    SIPHASH_C_D_1_3_64 = 0;
+    /// `siphash_2_4` with a 64-bit output.
+    SIPHASH_C_D_2_4_64 = 1;
+    /// `siphash_4_8` with a 64-bit output.
+    SIPHASH_C_D_4_8_64 = 2;
+    /// `siphash_1_3` with a 128-bit output.
+    SIPHASH_C_D_1_3_128 = 3;
+    /// `siphash_2_4` with a 128-bit output.
+    SIPHASH_C_D_2_4_128 = 4;
+    /// `siphash_4_8` with a 128-bit output.
+    SIPHASH_C_D_4_8_128 = 5;
+}
+
+constants! {
+    ERRORS:
+    /// Success.
+    SIPHASH_C_D_OK = 0;
+    /// A pointer is null.
+    SIPHASH_C_D_ERROR_NULL_POINTER = -1;
+    /// The variant is unknown.
+    SIPHASH_C_D_ERROR_INVALID_VARIANT = -2;
+    /// The key is less than 16 bytes.
+    SIPHASH_C_D_ERROR_KEY_TOO_SHORT = -3;
+    /// The output buffer is not 8 bytes for a 64-bit variant, or 16 bytes for a 128-bit variant.
+    SIPHASH_C_D_ERROR_INVALID_OUTPUT_LENGTH = -4;
+    /// The self-test failed.
+    SIPHASH_C_D_ERROR_SELF_TEST_FAILED = -5;
+    /// Any other error.
+    SIPHASH_C_D_ERROR_OTHER = -6;
+    /// The key is more than 16 bytes.
+    SIPHASH_C_D_ERROR_KEY_TOO_LONG = -7;
+}
+
+/// The error code of a `SipError`.
+pub fn error_code(e: SipError) -> c_int {
+    match e {
+        SipError::UnsupportedVariant { .. } => SIPHASH_C_D_ERROR_INVALID_VARIANT,
+        SipError::KeyTooShort(_) => SIPHASH_C_D_ERROR_KEY_TOO_SHORT,
+        SipError::InvalidOutputLength(_) => SIPHASH_C_D_ERROR_INVALID_OUTPUT_LENGTH,
+        SipError::SelfTestFailed { .. } => SIPHASH_C_D_ERROR_SELF_TEST_FAILED,
+        _ => SIPHASH_C_D_ERROR_OTHER,
+    }
+}
+
+/// The opaque streaming state.
+pub struct State(AnySipHash);
+
+// the calculation of a variant constant
+fn siphash(variant: c_int, key: &[u8]) -> Result<AnySipHash, c_int> {
+    let (c, d, bits) = match variant {
+        SIPHASH_C_D_1_3_64 => (1, 3, 64),
+        SIPHASH_C_D_2_4_64 => (2, 4, 64),
+        SIPHASH_C_D_4_8_64 => (4, 8, 64),
+        SIPHASH_C_D_1_3_128 => (1, 3, 128),
+        SIPHASH_C_D_2_4_128 => (2, 4, 128),
+        SIPHASH_C_D_4_8_128 => (4, 8, 128),
+        _ => return Err(SIPHASH_C_D_ERROR_INVALID_VARIANT),
+    };
+    AnySipHash::new(c, d, bits, key).map_err(error_code)
+}
+
+// the length bytes at p, which can be null if the length is 0
+unsafe fn input<'a>(p: *const u8, length: usize) -> Result<&'a [u8], c_int> {
+    if length == 0 {
+        Ok(&[])
+    } else if p.is_null() {
+        Err(SIPHASH_C_D_ERROR_NULL_POINTER)
+    } else {
+        Ok(slice::from_raw_parts(p, length))
+    }
+}
+
+// the key, which can't be null and must be 16 bytes, as the extra bytes would be ignored
+unsafe fn key_bytes<'a>(key: *const u8, key_length: usize) -> Result<&'a [u8], c_int> {
+    if key.is_null() {
+        return Err(SIPHASH_C_D_ERROR_NULL_POINTER);
+    }
+    if key_length > 16 {
+        return Err(SIPHASH_C_D_ERROR_KEY_TOO_LONG);
+    }
+    input(key, key_length)
+}
+
+unsafe fn output<'a>(p: *mut u8, length: usize) -> Result<&'a mut [u8], c_int> {
+    if p.is_null() {
+        Err(SIPHASH_C_D_ERROR_NULL_POINTER)
+    } else {
+        Ok(slice::from_raw_parts_mut(p, length))
+    }
+}
+
+fn code(result: Result<(), c_int>) -> c_int {
+    result.err().unwrap_or(SIPHASH_C_D_OK)
+}
+
+/// Hash the `input_length` bytes of `input` using the `key_length` bytes of `key`, which must be 16, and write the 8
+/// or 16 bytes of the hash value to `out`.
+///
+/// # Safety
+///
+/// The pointers must be valid for their lengths. `input` can be null if `input_length` is 0.
+#[no_mangle]
+pub unsafe extern "C" fn siphash_c_d_hash(
+    variant: c_int,
+    key: *const u8,
+    key_length: usize,
+    input: *const u8,
+    input_length: usize,
+    out: *mut u8,
+    out_length: usize,
+) -> c_int {
+    code((|| {
+        let key = key_bytes(key, key_length)?;
+        let mut siphash = siphash(variant, key)?;
+        siphash.update(self::input(input, input_length)?);
+        siphash
+            .finalize_into(output(out, out_length)?)
+            .map_err(error_code)
+    })())
+}
+
+/// Allocate a streaming state in `*state` using the `key_length` bytes of `key`, which must be 16. It must be released
+/// by `siphash_c_d_free()`.
+///
+/// # Safety
+///
+/// `key` must be valid for `key_length` bytes and `state` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn siphash_c_d_init(
+    variant: c_int,
+    key: *const u8,
+    key_length: usize,
+    state: *mut *mut State,
+) -> c_int {
+    if state.is_null() {
+        return SIPHASH_C_D_ERROR_NULL_POINTER;
+    }
+    *state = ptr::null_mut();
+
+    code(key_bytes(key, key_length).and_then(|key| {
+        let siphash = siphash(variant, key)?;
+        *state = Box::into_raw(Box::new(State(siphash)));
+        Ok(())
+    }))
+}
+
+/// Add the `input_length` bytes of `input` to the message being hashed.
+///
+/// # Safety
+///
+/// `state` must have been allocated by `siphash_c_d_init()`, and `input` must be valid for `input_length` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn siphash_c_d_update(
+    state: *mut State,
+    input: *const u8,
+    input_length: usize,
+) -> c_int {
+    if state.is_null() {
+        return SIPHASH_C_D_ERROR_NULL_POINTER;
+    }
+
+    code(self::input(input, input_length).map(|input| (*state).0.update(input)))
+}
+
+/// Write the 8 or 16 bytes of the hash value of the bytes added so far to `out`. More bytes can be added afterwards.
+///
+/// # Safety
+///
+/// `state` must have been allocated by `siphash_c_d_init()`, and `out` must be valid for `out_length` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn siphash_c_d_final(
+    state: *const State,
+    out: *mut u8,
+    out_length: usize,
+) -> c_int {
+    if state.is_null() {
+        return SIPHASH_C_D_ERROR_NULL_POINTER;
+    }
+
+    code(output(out, out_length).and_then(|out| (*state).0.finalize_into(out).map_err(error_code)))
+}
+
+/// Release a state allocated by `siphash_c_d_init()`. Nothing is done if `state` is null.
+///
+/// # Safety
+///
+/// `state` must have been allocated by `siphash_c_d_init()` and not already released.
+#[no_mangle]
+pub unsafe extern "C" fn siphash_c_d_free(state: *mut State) {
+    if !state.is_null() {
+        drop(Box::from_raw(state));
+    }
+}
+
+/// Check the implementation against the reference test vectors.
+#[no_mangle]
+pub extern "C" fn siphash_c_d_self_test() -> c_int {
+    code(siphash_c_d::self_test().map_err(error_code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use siphash_c_d::vectors::{self, KEY};
+
+    #[test]
+    fn test_hash() {
+        let msg: Vec<u8> = (0..64).collect();
+
+        for i in 0..64 {
+            let mut out = [0u8; 8];
+            let rc = unsafe {
+                siphash_c_d_hash(
+                    SIPHASH_C_D_2_4_64,
+                    KEY.as_ptr(),
+                    16,
+                    msg.as_ptr(),
+                    i,
+                    out.as_mut_ptr(),
+                    8,
+                )
+            };
+            assert_eq!(rc, SIPHASH_C_D_OK);
+            assert_eq!(out, vectors::SIPHASH_2_4_64[i].to_le_bytes());
+
+            let mut out = [0u8; 16];
+            let rc = unsafe {
+                siphash_c_d_hash(
+                    SIPHASH_C_D_4_8_128,
+                    KEY.as_ptr(),
+                    16,
+                    msg.as_ptr(),
+                    i,
+                    out.as_mut_ptr(),
+                    16,
+                )
+            };
+            assert_eq!(rc, SIPHASH_C_D_OK);
+            assert_eq!(out, vectors::SIPHASH_4_8_128[i]);
+        }
+    }
+
+    #[test]
+    fn test_streaming() {
+        let msg: Vec<u8> = (0..64).collect();
+
+        unsafe {
+            let mut state = ptr::null_mut();
+            assert_eq!(
+                siphash_c_d_init(SIPHASH_C_D_1_3_128, KEY.as_ptr(), 16, &mut state),
+                SIPHASH_C_D_OK
+            );
+            assert!(!state.is_null());
+
+            assert_eq!(siphash_c_d_update(state, msg.as_ptr(), 10), SIPHASH_C_D_OK);
+            assert_eq!(siphash_c_d_update(state, ptr::null(), 0), SIPHASH_C_D_OK);
+            assert_eq!(
+                siphash_c_d_update(state, msg[10..].as_ptr(), 53),
+                SIPHASH_C_D_OK
+            );
+
+            let mut out = [0u8; 16];
+            assert_eq!(
+                siphash_c_d_final(state, out.as_mut_ptr(), 16),
+                SIPHASH_C_D_OK
+            );
+            assert_eq!(out, vectors::SIPHASH_1_3_128[63]);
+
+            siphash_c_d_free(state);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut out = [0u8; 16];
+        let msg = [0u8; 4];
+
+        unsafe {
+            let hash = |variant,
+                        key: *const u8,
+                        key_length,
+                        input: *const u8,
+                        out: *mut u8,
+                        out_length| {
+                siphash_c_d_hash(variant, key, key_length, input, 4, out, out_length)
+            };
+            let out = out.as_mut_ptr();
+
+            assert_eq!(
+                hash(6, KEY.as_ptr(), 16, msg.as_ptr(), out, 8),
+                SIPHASH_C_D_ERROR_INVALID_VARIANT
+            );
+            assert_eq!(
+                hash(-1, KEY.as_ptr(), 16, msg.as_ptr(), out, 8),
+                SIPHASH_C_D_ERROR_INVALID_VARIANT
+            );
+            assert_eq!(
+                hash(0, ptr::null(), 16, msg.as_ptr(), out, 8),
+                SIPHASH_C_D_ERROR_NULL_POINTER
+            );
+            assert_eq!(
+                hash(0, KEY.as_ptr(), 15, msg.as_ptr(), out, 8),
+                SIPHASH_C_D_ERROR_KEY_TOO_SHORT
+            );
+            let long_key = [0u8; 17];
+            assert_eq!(
+                hash(0, long_key.as_ptr(), 17, msg.as_ptr(), out, 8),
+                SIPHASH_C_D_ERROR_KEY_TOO_LONG
+            );
+            assert_eq!(
+                hash(0, KEY.as_ptr(), 16, ptr::null(), out, 8),
+                SIPHASH_C_D_ERROR_NULL_POINTER
+            );
+            assert_eq!(
+                hash(0, KEY.as_ptr(), 16, msg.as_ptr(), ptr::null_mut(), 8),
+                SIPHASH_C_D_ERROR_NULL_POINTER
+            );
+            assert_eq!(
+                hash(0, KEY.as_ptr(), 16, msg.as_ptr(), out, 16),
+                SIPHASH_C_D_ERROR_INVALID_OUTPUT_LENGTH
+            );
+            assert_eq!(
+                hash(3, KEY.as_ptr(), 16, msg.as_ptr(), out, 8),
+                SIPHASH_C_D_ERROR_INVALID_OUTPUT_LENGTH
+            );
+
+            let mut state = ptr::null_mut();
+            assert_eq!(
+                siphash_c_d_init(0, KEY.as_ptr(), 8, &mut state),
+                SIPHASH_C_D_ERROR_KEY_TOO_SHORT
+            );
+            assert!(state.is_null());
+            assert_eq!(
+                siphash_c_d_init(0, long_key.as_ptr(), 17, &mut state),
+                SIPHASH_C_D_ERROR_KEY_TOO_LONG
+            );
+            assert!(state.is_null());
+            assert_eq!(
+                siphash_c_d_init(0, KEY.as_ptr(), 16, ptr::null_mut()),
+                SIPHASH_C_D_ERROR_NULL_POINTER
+            );
+            assert_eq!(
+                siphash_c_d_update(ptr::null_mut(), msg.as_ptr(), 4),
+                SIPHASH_C_D_ERROR_NULL_POINTER
+            );
+            assert_eq!(
+                siphash_c_d_final(ptr::null(), out, 8),
+                SIPHASH_C_D_ERROR_NULL_POINTER
+            );
+            siphash_c_d_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_self_test() {
+        assert_eq!(siphash_c_d_self_test(), SIPHASH_C_D_OK);
+    }
+
+    #[test]
+    fn test_header_up_to_date() {
+        assert_eq!(header(), include_str!("../include/siphash_c_d.h"));
+    }
+}
@@ -0,0 +1,308 @@
+//! Python bindings of the `siphash_c_d` crate, so that Python programs get the same hash values as the Rust ones.
+//!
+//! The `siphash_c_d` Python module provides:
+//!
+//! * the `siphash(key, data, c=2, d=4, bits=64)` function, returning the hash value as an `int`;
+//! * the `SipHash(key, data=None, c=2, d=4, bits=64)` streaming object, similar to the ones of `hashlib`, with
+//!   the `update()`, `digest()`, `hexdigest()` and `copy()` methods.
+//!
+//! The `siphash_1_3`, `siphash_2_4` and `siphash_4_8` variants are available in 64 and 128 bits. The key and the
+//! data are any bytes-like objects, the key being at least 16 bytes. The GIL is released while hashing large
+//! buffers.
+//!
+//! ```python
+//! >>> import siphash_c_d
+//! >>> key = bytes(range(16))
+//! >>> hex(siphash_c_d.siphash(key, b"hello, world"))
+//! '0x5222c673f3faebb2'
+//! >>> h = siphash_c_d.SipHash(key, bits=128)
+//! >>> h.update(b"hello, world")
+//! >>> h.hexdigest()
+//! 'b5ab91c0d82ebdda2b64a05a5a32d768'
+//! ```
+//!
+//! The extension module is built with [maturin](https://www.maturin.rs/) from the `python` directory:
+//!
+//! ```text
+//! maturin develop --release
+//! ```
+use std::slice;
+
+use pyo3::{
+    buffer::PyBuffer,
+    exceptions::{PyBufferError, PyValueError},
+    prelude::*,
+    types::PyBytes,
+};
+use siphash_c_d::{AnySipHash, SipError};
+
+// the same as hashlib: smaller buffers are hashed without releasing the GIL
+const GIL_MINSIZE: usize = 2048;
+
+// the errors of the bindings, which pyo3 converts to Python exceptions
+enum Error {
+    Sip(SipError),
+    NotContiguous,
+}
+
+impl From<SipError> for Error {
+    fn from(e: SipError) -> Self {
+        Error::Sip(e)
+    }
+}
+
+impl From<Error> for PyErr {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Sip(e) => PyValueError::new_err(e.to_string()),
+            Error::NotContiguous => PyBufferError::new_err("the buffer must be contiguous"),
+        }
+    }
+}
+
+fn update(py: Python<'_>, siphash: &mut AnySipHash, bytes: &[u8]) {
+    if bytes.len() < GIL_MINSIZE {
+        siphash.update(bytes)
+    } else {
+        py.allow_threads(|| siphash.update(bytes))
+    }
+}
+
+// the little endian bytes of the hash value
+fn digest(siphash: &AnySipHash) -> Vec<u8> {
+    let mut out = vec![0; siphash.bits() as usize / 8];
+    siphash
+        .finalize_into(&mut out)
+        .expect("the output is the size of the hash value");
+    out
+}
+
+// the bytes of a bytes-like object
+fn bytes(buffer: &PyBuffer<u8>) -> Result<&[u8], Error> {
+    if !buffer.is_c_contiguous() {
+        return Err(Error::NotContiguous);
+    }
+
+    // SAFETY: the buffer is contiguous, and the object can't be released as long as the buffer is kept. Like
+    // hashlib, a buffer modified by another thread while the GIL is released gives an unspecified hash value.
+    Ok(unsafe { slice::from_raw_parts(buffer.buf_ptr() as *const u8, buffer.len_bytes()) })
+}
+
+/// Return the hash value of `data` using `key`, as an `int`.
+#[pyfunction]
+#[pyo3(signature = (key, data, c = 2, d = 4, bits = 64))]
+fn siphash(
+    py: Python<'_>,
+    key: PyBuffer<u8>,
+    data: PyBuffer<u8>,
+    c: u8,
+    d: u8,
+    bits: u16,
+) -> Result<u128, Error> {
+    let mut siphash = AnySipHash::new(c, d, bits, bytes(&key)?)?;
+    update(py, &mut siphash, bytes(&data)?);
+    Ok(siphash.finalize())
+}
+
+/// A streaming `siphash_c_d` object, similar to the ones of `hashlib`.
+#[pyclass(name = "SipHash", module = "siphash_c_d")]
+#[derive(Clone)]
+struct PySipHash(AnySipHash);
+
+#[pymethods]
+impl PySipHash {
+    #[new]
+    #[pyo3(signature = (key, data = None, c = 2, d = 4, bits = 64))]
+    fn new(
+        py: Python<'_>,
+        key: PyBuffer<u8>,
+        data: Option<PyBuffer<u8>>,
+        c: u8,
+        d: u8,
+        bits: u16,
+    ) -> Result<Self, Error> {
+        let mut siphash = AnySipHash::new(c, d, bits, bytes(&key)?)?;
+        if let Some(data) = data {
+            update(py, &mut siphash, bytes(&data)?);
+        }
+        Ok(PySipHash(siphash))
+    }
+
+    /// Add the bytes of `data` to the message being hashed.
+    fn update(&mut self, py: Python<'_>, data: PyBuffer<u8>) -> Result<(), Error> {
+        update(py, &mut self.0, bytes(&data)?);
+        Ok(())
+    }
+
+    /// Return the little endian bytes of the hash value of the data added so far.
+    fn digest<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &digest(&self.0))
+    }
+
+    /// Return the digest as a string of hexadecimal digits.
+    fn hexdigest(&self) -> String {
+        digest(&self.0)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    /// Return the hash value of the data added so far, as an `int`.
+    fn intdigest(&self) -> u128 {
+        self.0.finalize()
+    }
+
+    /// Return a copy of the object, which can be updated independently.
+    fn copy(&self) -> Self {
+        self.clone()
+    }
+
+    /// The name of the variant, e.g. `siphash_2_4_64`.
+    #[getter]
+    fn name(&self) -> String {
+        format!("siphash_{}_{}_{}", self.0.c(), self.0.d(), self.0.bits())
+    }
+
+    /// The size of the digest in bytes.
+    #[getter]
+    fn digest_size(&self) -> u16 {
+        self.0.bits() / 8
+    }
+
+    /// The size of the blocks of the algorithm in bytes.
+    #[getter]
+    fn block_size(&self) -> usize {
+        8
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<{} SipHash object>", self.name())
+    }
+}
+
+/// The `siphash_c_d` Python module.
+#[pymodule]
+#[pyo3(name = "siphash_c_d")]
+fn siphash_c_d_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(siphash, m)?)?;
+    m.add_class::<PySipHash>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::{types::PyDict, wrap_pymodule};
+    use siphash_c_d::vectors;
+
+    // run the Python code with the module imported as `s`, the key as `key` and the vectors message as `msg`
+    fn run(code: &str) -> PyResult<()> {
+        Python::with_gil(|py| {
+            let locals = PyDict::new_bound(py);
+            locals.set_item("s", wrap_pymodule!(siphash_c_d_py)(py))?;
+            locals.set_item("key", PyBytes::new_bound(py, &vectors::KEY))?;
+            locals.set_item("msg", PyBytes::new_bound(py, &(0..64).collect::<Vec<u8>>()))?;
+            py.run_bound(code, None, Some(&locals))
+        })
+    }
+
+    #[test]
+    fn test_siphash() {
+        let expected_64: Vec<u64> = vectors::SIPHASH_2_4_64.to_vec();
+        let expected_128: Vec<u128> = vectors::SIPHASH_4_8_128
+            .iter()
+            .map(|v| u128::from_le_bytes(*v))
+            .collect();
+
+        Python::with_gil(|py| {
+            let m = wrap_pymodule!(siphash_c_d_py)(py);
+            let siphash = m.getattr(py, "siphash").unwrap();
+            let msg: Vec<u8> = (0..64).collect();
+
+            for i in 0..64 {
+                let key = PyBytes::new_bound(py, &vectors::KEY);
+                let data = PyBytes::new_bound(py, &msg[..i]);
+                let v: u64 = siphash
+                    .call1(py, (&key, &data))
+                    .unwrap()
+                    .extract(py)
+                    .unwrap();
+                assert_eq!(v, expected_64[i]);
+
+                let kwargs = PyDict::new_bound(py);
+                kwargs.set_item("c", 4).unwrap();
+                kwargs.set_item("d", 8).unwrap();
+                kwargs.set_item("bits", 128).unwrap();
+                let v: u128 = siphash
+                    .call_bound(py, (&key, &data), Some(&kwargs))
+                    .unwrap()
+                    .extract(py)
+                    .unwrap();
+                assert_eq!(v, expected_128[i]);
+            }
+        });
+    }
+
+    #[test]
+    fn test_streaming() {
+        run(r#"
+h = s.SipHash(key, msg[:10], c=1, d=3, bits=128)
+h.update(bytearray(msg[10:20]))
+h.update(memoryview(msg)[20:63])
+assert h.digest() == bytes.fromhex("{}")
+assert h.hexdigest() == h.digest().hex()
+assert h.intdigest() == int.from_bytes(h.digest(), "little")
+assert (h.name, h.digest_size, h.block_size) == ("siphash_1_3_128", 16, 8)
+"#
+        .replace(
+            "{}",
+            &vectors::SIPHASH_1_3_128[63]
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>(),
+        )
+        .as_str())
+        .unwrap();
+    }
+
+    #[test]
+    fn test_copy_and_large_buffers() {
+        // the large buffer is hashed without the GIL
+        run(r#"
+data = bytes(range(256)) * 100
+h = s.SipHash(key)
+h.update(data[:1000])
+c = h.copy()
+h.update(data[1000:])
+assert h.intdigest() == s.siphash(key, data)
+assert c.intdigest() == s.siphash(key, data[:1000])
+assert repr(c) == "<siphash_2_4_64 SipHash object>"
+"#)
+        .unwrap();
+    }
+
+    #[test]
+    fn test_errors() {
+        run(r#"
+for args, kwargs, error in [
+    ((key[:15], msg), {}, ValueError),
+    ((key, msg), {"c": 3, "d": 5}, ValueError),
+    ((key, msg), {"bits": 32}, ValueError),
+    ((key, "text"), {}, TypeError),
+    ((key, memoryview(msg)[::2]), {}, BufferError),
+]:
+    try:
+        s.siphash(*args, **kwargs)
+    except error:
+        pass
+    else:
+        raise AssertionError((args, kwargs))
+
+try:
+    s.siphash(key[:15], msg)
+except ValueError as e:
+    assert str(e) == "the key must be at least 16 bytes long, not 15 bytes", e
+"#)
+        .unwrap();
+    }
+}
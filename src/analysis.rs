@@ -0,0 +1,397 @@
+//! Statistical analysis of the diffusion of reduced or increased round variants.
+//!
+//! For a `siphash_c_d` variant, the [`Avalanche`] analysis flips each bit of the message and of the key
+//! on random inputs, and measures:
+//!
+//! * the strict avalanche criterion (SAC) matrix: for each input bit and each output bit, the probability that
+//!   flipping the input bit flips the output bit. It should be 1/2;
+//! * the output bit bias: the probability that each output bit is set. It should be 1/2;
+//! * the bit independence criterion (BIC): for each input bit, the correlation between the flips of each pair of
+//!   output bits. It should be 0.
+//!
+//! The resulting [`Report`] can be compared between the `(C, D)` choices. The only allocation is the 64 KiB table
+//! of the pairs of output bits, and the random inputs are derived from a seed, so the reports are reproducible.
+//!
+//! ```rust
+//! use siphash_c_d::{analysis::Avalanche, Hash64};
+//!
+//! let report = Avalanche::<1, 3, Hash64>::new(8, 100).report();
+//! assert_eq!(report.input_bits, 8 * 8 + 128);
+//! assert!(report.sac_max_bias < 0.25);
+//! ```
+use core::{fmt, marker::PhantomData};
+
+use crate::{Hash128, HashOutput, SipHash};
+
+/// The maximum length of the analyzed messages.
+pub const MAX_MESSAGE_LENGTH: usize = 64;
+
+/// An input bit which is flipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputBit {
+    /// The n-th bit of the message, bit 0 being the least significant bit of the first byte.
+    Message(usize),
+
+    /// The n-th bit of the key, bit 0 being the least significant bit of the first byte.
+    Key(usize),
+}
+
+/// The avalanche analysis of the `siphash_c_d` variant with an output of `T` bits.
+#[derive(Debug, Clone)]
+pub struct Avalanche<const C: u8, const D: u8, T> {
+    length: usize,
+    samples: u32,
+    seed: u64,
+    output: PhantomData<T>,
+}
+
+/// The results of an avalanche analysis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Report {
+    /// Number of compression rounds.
+    pub c: u8,
+
+    /// Number of finalization rounds.
+    pub d: u8,
+
+    /// Size of the hash value in bits.
+    pub bits: u16,
+
+    /// Number of random inputs for each flipped bit.
+    pub samples: u32,
+
+    /// Number of flipped input bits: the message bits followed by the 128 key bits.
+    pub input_bits: usize,
+
+    /// Largest deviation from 1/2 of a probability of the SAC matrix.
+    pub sac_max_bias: f64,
+
+    /// Mean deviation from 1/2 of the probabilities of the SAC matrix.
+    pub sac_mean_bias: f64,
+
+    /// The input and output bits having the largest SAC deviation.
+    pub sac_worst: (InputBit, usize),
+
+    /// Largest deviation from 1/2 of the probability of an output bit being set.
+    pub output_max_bias: f64,
+
+    /// Largest absolute correlation between the flips of 2 output bits, for the same flipped input bit.
+    pub bic_max_correlation: f64,
+}
+
+impl<const C: u8, const D: u8, T: HashOutput> Avalanche<C, D, T> {
+    /// An analysis for messages of `length` bytes, using `samples` random inputs for each flipped bit.
+    ///
+    /// # Panics
+    ///
+    /// If `length` is greater than [`MAX_MESSAGE_LENGTH`] or `samples` is 0.
+    pub fn new(length: usize, samples: u32) -> Self {
+        assert!(length <= MAX_MESSAGE_LENGTH, "message is too long");
+        assert!(samples > 0, "at least one sample is needed");
+
+        Self {
+            length,
+            samples,
+            seed: 0,
+            output: PhantomData,
+        }
+    }
+
+    /// Use another seed for the random inputs.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Number of flipped input bits: the message bits followed by the 128 key bits.
+    pub fn input_bits(&self) -> usize {
+        8 * self.length + 128
+    }
+
+    /// The n-th input bit, as ordered in the analysis.
+    pub fn input_bit(&self, n: usize) -> InputBit {
+        if n < 8 * self.length {
+            InputBit::Message(n)
+        } else {
+            InputBit::Key(n - 8 * self.length)
+        }
+    }
+
+    /// A row of the SAC matrix: for each output bit, the number of samples for which flipping `input` flipped
+    /// this output bit. Only the first `T::BITS` values are used.
+    pub fn sac_row(&self, input: InputBit) -> [u32; 128] {
+        let mut row = [0u32; 128];
+
+        for sample in 0..self.samples {
+            let diff = self.flip(sample, input);
+            for (j, count) in row.iter_mut().enumerate().take(T::BITS as usize) {
+                *count += (diff >> j) as u32 & 1;
+            }
+        }
+
+        row
+    }
+
+    /// Call `f` with each row of the SAC matrix, as returned by [`Avalanche::sac_row`].
+    pub fn sac_matrix<F: FnMut(InputBit, &[u32])>(&self, mut f: F) {
+        for n in 0..self.input_bits() {
+            let input = self.input_bit(n);
+            f(input, &self.sac_row(input)[..T::BITS as usize]);
+        }
+    }
+
+    /// Run the whole analysis.
+    pub fn report(&self) -> Report {
+        let bits = T::BITS as usize;
+        let n = self.samples as f64;
+
+        let mut sac_max_bias = 0.0;
+        let mut sac_sum_bias = 0.0;
+        let mut sac_worst = (self.input_bit(0), 0);
+        let mut bic_max_correlation = 0.0;
+
+        // the pairs of output bits flipped together, only j < k is used
+        let mut pairs = alloc::vec![[0u32; 128]; 128];
+
+        for i in 0..self.input_bits() {
+            let input = self.input_bit(i);
+            let mut row = [0u32; 128];
+            pairs.iter_mut().for_each(|p| *p = [0; 128]);
+
+            for sample in 0..self.samples {
+                let diff = self.flip(sample, input);
+
+                // only loop over the flipped bits
+                let mut rest = diff;
+                while rest != 0 {
+                    let j = rest.trailing_zeros() as usize;
+                    rest &= rest - 1;
+                    row[j] += 1;
+
+                    let mut others = rest;
+                    while others != 0 {
+                        let k = others.trailing_zeros() as usize;
+                        others &= others - 1;
+                        pairs[j][k] += 1;
+                    }
+                }
+            }
+
+            for j in 0..bits {
+                let bias = abs(row[j] as f64 / n - 0.5);
+                sac_sum_bias += bias;
+                if bias > sac_max_bias {
+                    sac_max_bias = bias;
+                    sac_worst = (input, j);
+                }
+
+                for k in j + 1..bits {
+                    let correlation = correlation(row[j], row[k], pairs[j][k], self.samples);
+                    if correlation > bic_max_correlation {
+                        bic_max_correlation = correlation;
+                    }
+                }
+            }
+        }
+
+        // output bits of the random inputs
+        let mut ones = [0u32; 128];
+        for sample in 0..self.samples {
+            let (key, msg) = self.input(sample);
+            let h = self.hash(&key, &msg[..self.length]);
+            for (j, count) in ones.iter_mut().enumerate().take(bits) {
+                *count += (h >> j) as u32 & 1;
+            }
+        }
+        let output_max_bias = ones[..bits]
+            .iter()
+            .map(|count| abs(*count as f64 / n - 0.5))
+            .fold(0.0, f64::max);
+
+        Report {
+            c: C,
+            d: D,
+            bits: T::BITS,
+            samples: self.samples,
+            input_bits: self.input_bits(),
+            sac_max_bias,
+            sac_mean_bias: sac_sum_bias / (self.input_bits() * bits) as f64,
+            sac_worst,
+            output_max_bias,
+            bic_max_correlation,
+        }
+    }
+
+    // the random key and message of a sample, derived from the seed
+    fn input(&self, sample: u32) -> ([u8; 16], [u8; MAX_MESSAGE_LENGTH]) {
+        let random = |counter: u32| {
+            let block = (self.seed as u128) << 64 | (sample as u128) << 32 | counter as u128;
+            match SipHash::<2, 4, Hash128>::with_key(0x616e616c79736973_u128, &block.to_le_bytes())
+            {
+                Ok(h) => h.to_le_bytes(),
+                Err(_) => unreachable!("a u128 key is always valid"),
+            }
+        };
+
+        let key = random(0);
+        let mut msg = [0u8; MAX_MESSAGE_LENGTH];
+        for (i, chunk) in msg.chunks_mut(16).enumerate() {
+            chunk.copy_from_slice(&random(i as u32 + 1));
+        }
+
+        (key, msg)
+    }
+
+    // hash value as an u128 integer
+    fn hash(&self, key: &[u8; 16], msg: &[u8]) -> u128 {
+        let mut siphash = match SipHash::<C, D, T>::new(key) {
+            Ok(siphash) => siphash,
+            Err(_) => unreachable!("a 16-byte key is always valid"),
+        };
+        siphash.update(msg);
+
+        let mut bytes = [0u8; 16];
+        let value = T::to_le_bytes(siphash.finalize());
+        bytes[..value.as_ref().len()].copy_from_slice(value.as_ref());
+        u128::from_le_bytes(bytes)
+    }
+
+    // output difference when flipping the input bit
+    fn flip(&self, sample: u32, input: InputBit) -> u128 {
+        let (mut key, mut msg) = self.input(sample);
+        let h0 = self.hash(&key, &msg[..self.length]);
+
+        match input {
+            InputBit::Message(n) => msg[n / 8] ^= 1 << (n % 8),
+            InputBit::Key(n) => key[n / 8] ^= 1 << (n % 8),
+        }
+
+        h0 ^ self.hash(&key, &msg[..self.length])
+    }
+}
+
+impl fmt::Display for InputBit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputBit::Message(n) => write!(f, "message bit {}", n),
+            InputBit::Key(n) => write!(f, "key bit {}", n),
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "siphash_{}_{} {}-bit: {} input bits, {} samples",
+            self.c, self.d, self.bits, self.input_bits, self.samples
+        )?;
+        writeln!(
+            f,
+            "  SAC bias:        max {:.4} ({} -> output bit {}), mean {:.4}",
+            self.sac_max_bias, self.sac_worst.0, self.sac_worst.1, self.sac_mean_bias
+        )?;
+        writeln!(f, "  output bias:     max {:.4}", self.output_max_bias)?;
+        writeln!(f, "  BIC correlation: max {:.4}", self.bic_max_correlation)
+    }
+}
+
+// no f64::abs() in core for the minimum supported Rust version
+fn abs(x: f64) -> f64 {
+    if x < 0.0 {
+        -x
+    } else {
+        x
+    }
+}
+
+// no f64::sqrt() in core: Newton's method is enough for a statistic, also used by the quality module
+pub(crate) fn sqrt(x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    let mut y = if x > 1.0 { x } else { 1.0 };
+    for _ in 0..64 {
+        let next = 0.5 * (y + x / y);
+        if next >= y {
+            break;
+        }
+        y = next;
+    }
+    y
+}
+
+// absolute value of the Pearson correlation of 2 binary variables, from their counts
+fn correlation(count_j: u32, count_k: u32, count_jk: u32, samples: u32) -> f64 {
+    let n = samples as f64;
+    let (pj, pk, pjk) = (count_j as f64 / n, count_k as f64 / n, count_jk as f64 / n);
+
+    let variance = pj * (1.0 - pj) * pk * (1.0 - pk);
+    if variance == 0.0 {
+        // a constant bit is fully dependent
+        return if pj == pk { 1.0 } else { 0.0 };
+    }
+
+    abs(pjk - pj * pk) / sqrt(variance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hash64;
+
+    #[test]
+    fn test_sqrt() {
+        assert!(abs(sqrt(2.0) - core::f64::consts::SQRT_2) < 1e-12);
+        assert!(abs(sqrt(0.0625) - 0.25) < 1e-12);
+        assert_eq!(sqrt(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_correlation() {
+        // independent
+        assert!(correlation(50, 50, 25, 100) < 1e-12);
+
+        // always flipped together
+        assert!(abs(correlation(50, 50, 50, 100) - 1.0) < 1e-12);
+    }
+
+    #[test]
+    fn test_good_diffusion() {
+        let report = Avalanche::<2, 4, Hash64>::new(8, 200).report();
+
+        assert_eq!((report.c, report.d, report.bits), (2, 4, 64));
+        assert_eq!(report.input_bits, 192);
+        assert!(report.sac_max_bias < 0.15);
+        assert!(report.sac_mean_bias < 0.05);
+        assert!(report.output_max_bias < 0.15);
+        assert!(report.bic_max_correlation < 0.4);
+    }
+
+    #[test]
+    fn test_poor_diffusion() {
+        // a single finalization round can't diffuse a message bit
+        let report = Avalanche::<0, 1, Hash64>::new(8, 200).report();
+        assert!(report.sac_max_bias > 0.4);
+        assert!(report.bic_max_correlation > 0.9);
+    }
+
+    #[test]
+    fn test_sac_row() {
+        let avalanche = Avalanche::<1, 3, Hash128>::new(4, 50).with_seed(1);
+        assert_eq!(avalanche.input_bit(31), InputBit::Message(31));
+        assert_eq!(avalanche.input_bit(32), InputBit::Key(0));
+
+        let row = avalanche.sac_row(InputBit::Key(5));
+        assert!(row.iter().all(|count| *count <= 50));
+        assert!(row.iter().sum::<u32>() > 128 * 10);
+
+        let mut rows = 0;
+        avalanche.sac_matrix(|_, row| {
+            assert_eq!(row.len(), 128);
+            rows += 1;
+        });
+        assert_eq!(rows, 32 + 128);
+    }
+}
@@ -0,0 +1,178 @@
+//! A `siphash_c_d` calculation whose parameters are only known at runtime, as in the C, Python and WebAssembly
+//! bindings.
+use crate::{Hash128, Hash64, SipError, SipHash, SipHashKey};
+
+/// A `siphash_1_3`, `siphash_2_4` or `siphash_4_8` calculation in 64 or 128 bits, chosen when it is created.
+///
+/// ```rust
+/// use siphash_c_d::{AnySipHash, SipHash24};
+///
+/// let key = [0u8; 16];
+/// let mut siphash = AnySipHash::new(2, 4, 64, &key).unwrap();
+/// siphash.update(b"hello, world");
+/// assert_eq!(siphash.finalize(), SipHash24::with_key(&key, b"hello, world").unwrap() as u128);
+///
+/// assert!(AnySipHash::new(3, 5, 64, &key).is_err());
+/// ```
+#[derive(Clone)]
+pub struct AnySipHash(Variant);
+
+#[derive(Clone)]
+enum Variant {
+    Siphash13x64(SipHash<1, 3, Hash64>),
+    Siphash24x64(SipHash<2, 4, Hash64>),
+    Siphash48x64(SipHash<4, 8, Hash64>),
+    Siphash13x128(SipHash<1, 3, Hash128>),
+    Siphash24x128(SipHash<2, 4, Hash128>),
+    Siphash48x128(SipHash<4, 8, Hash128>),
+}
+
+// the same expression for all the variants
+macro_rules! dispatch {
+    ($variant:expr, $siphash:ident => $e:expr) => {
+        match $variant {
+            Variant::Siphash13x64($siphash) => $e,
+            Variant::Siphash24x64($siphash) => $e,
+            Variant::Siphash48x64($siphash) => $e,
+            Variant::Siphash13x128($siphash) => $e,
+            Variant::Siphash24x128($siphash) => $e,
+            Variant::Siphash48x128($siphash) => $e,
+        }
+    };
+}
+
+impl AnySipHash {
+    /// Assign the key for the `siphash_c_d` calculation with a `bits` output.
+    ///
+    /// If `c` and `d` are not 1 and 3, 2 and 4 or 4 and 8, or `bits` is not 64 or 128, returns an error
+    /// (`SipError::UnsupportedVariant`). If the length of the key is less than 16 bytes, returns an error
+    /// (`SipError::KeyTooShort`).
+    pub fn new<K>(c: u8, d: u8, bits: u16, key: K) -> Result<Self, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        let variant = match (c, d, bits) {
+            (1, 3, 64) => Variant::Siphash13x64(SipHash::new(key)?),
+            (2, 4, 64) => Variant::Siphash24x64(SipHash::new(key)?),
+            (4, 8, 64) => Variant::Siphash48x64(SipHash::new(key)?),
+            (1, 3, 128) => Variant::Siphash13x128(SipHash::new(key)?),
+            (2, 4, 128) => Variant::Siphash24x128(SipHash::new(key)?),
+            (4, 8, 128) => Variant::Siphash48x128(SipHash::new(key)?),
+            _ => return Err(SipError::UnsupportedVariant { c, d, bits }),
+        };
+        Ok(AnySipHash(variant))
+    }
+
+    /// Add the bytes of `bytes` to the message being hashed.
+    pub fn update(&mut self, bytes: &[u8]) {
+        dispatch!(&mut self.0, siphash => siphash.update(bytes))
+    }
+
+    /// The hash value of the bytes added so far, the 64-bit values being widened.
+    // the conversion is the identity for the 128-bit variants
+    #[allow(clippy::useless_conversion)]
+    pub fn finalize(&self) -> u128 {
+        dispatch!(&self.0, siphash => u128::from(siphash.finalize()))
+    }
+
+    /// Write the hash value of the bytes added so far to `out`, as the `out[]` buffer of the reference
+    /// implementation.
+    ///
+    /// If the length of `out` is not 8 bytes for a 64-bit output or 16 bytes for a 128-bit output, returns an
+    /// error (`SipError::InvalidOutputLength`).
+    pub fn finalize_into(&self, out: &mut [u8]) -> Result<(), SipError> {
+        dispatch!(&self.0, siphash => siphash.finalize_into(out))
+    }
+
+    /// The number of compression rounds `c`.
+    pub fn c(&self) -> u8 {
+        match self.0 {
+            Variant::Siphash13x64(_) | Variant::Siphash13x128(_) => 1,
+            Variant::Siphash24x64(_) | Variant::Siphash24x128(_) => 2,
+            Variant::Siphash48x64(_) | Variant::Siphash48x128(_) => 4,
+        }
+    }
+
+    /// The number of finalization rounds `d`.
+    pub fn d(&self) -> u8 {
+        match self.0 {
+            Variant::Siphash13x64(_) | Variant::Siphash13x128(_) => 3,
+            Variant::Siphash24x64(_) | Variant::Siphash24x128(_) => 4,
+            Variant::Siphash48x64(_) | Variant::Siphash48x128(_) => 8,
+        }
+    }
+
+    /// The number of bits of the hash value, 64 or 128.
+    pub fn bits(&self) -> u16 {
+        match self.0 {
+            Variant::Siphash13x64(_) | Variant::Siphash24x64(_) | Variant::Siphash48x64(_) => 64,
+            _ => 128,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::{self, KEY};
+
+    #[test]
+    fn test_vectors() {
+        let mut msg = [0u8; 64];
+        for (i, b) in msg.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+
+        for i in 0..64 {
+            let mut siphash = AnySipHash::new(2, 4, 64, &KEY).unwrap();
+            siphash.update(&msg[..i]);
+            assert_eq!(siphash.finalize(), vectors::SIPHASH_2_4_64[i] as u128);
+
+            let mut siphash = AnySipHash::new(1, 3, 128, &KEY).unwrap();
+            siphash.update(&msg[..i]);
+            let mut out = [0u8; 16];
+            siphash.finalize_into(&mut out).unwrap();
+            assert_eq!(out, vectors::SIPHASH_1_3_128[i]);
+        }
+    }
+
+    #[test]
+    fn test_parameters() {
+        for (c, d) in [(1, 3), (2, 4), (4, 8)] {
+            for bits in [64, 128] {
+                let siphash = AnySipHash::new(c, d, bits, &KEY).unwrap();
+                assert_eq!((siphash.c(), siphash.d(), siphash.bits()), (c, d, bits));
+            }
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            AnySipHash::new(3, 5, 64, &KEY).err(),
+            Some(SipError::UnsupportedVariant {
+                c: 3,
+                d: 5,
+                bits: 64
+            })
+        );
+        assert_eq!(
+            AnySipHash::new(2, 4, 32, &KEY).err(),
+            Some(SipError::UnsupportedVariant {
+                c: 2,
+                d: 4,
+                bits: 32
+            })
+        );
+        assert_eq!(
+            AnySipHash::new(2, 4, 64, &KEY[..15]).err(),
+            Some(SipError::KeyTooShort(15))
+        );
+
+        let siphash = AnySipHash::new(2, 4, 128, &KEY).unwrap();
+        assert_eq!(
+            siphash.finalize_into(&mut [0u8; 8]),
+            Err(SipError::InvalidOutputLength(8))
+        );
+    }
+}
@@ -0,0 +1,178 @@
+// Generate or verify siphash_c_d test vectors.
+//
+// Usage:
+//   siphash_vectors generate [--c C] [--d D] [--bits 64|128] [--format c|json|csv] [--key HEX] [--lengths FROM..TO]
+//   siphash_vectors verify [--format c|json|csv] FILE
+//
+// The c and d values are limited to 1..=8 by the dispatch to the generic functions.
+use std::{env, fmt, fs, ops::Range, process};
+
+use siphash_c_d::{
+    generator::{self, Format},
+    vectors::KEY,
+    Hash128, Hash64, HashOutput, SipError,
+};
+
+enum Command {
+    Generate {
+        format: Format,
+        key: [u8; 16],
+        lengths: Range<usize>,
+    },
+    Verify {
+        format: Format,
+        input: String,
+    },
+}
+
+// adapter to use fmt::Write on a String
+struct Output(String);
+
+impl fmt::Write for Output {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.push_str(s);
+        Ok(())
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("usage: siphash_vectors generate [--c C] [--d D] [--bits 64|128] [--format c|json|csv] [--key HEX] [--lengths FROM..TO]");
+    eprintln!("       siphash_vectors verify [--format c|json|csv] FILE");
+    process::exit(2);
+}
+
+fn fail(msg: &str) -> ! {
+    eprintln!("error: {}", msg);
+    process::exit(1);
+}
+
+fn parse_format(s: &str) -> Format {
+    match s {
+        "c" => Format::CHeader,
+        "json" => Format::Json,
+        "csv" => Format::Csv,
+        _ => usage(),
+    }
+}
+
+fn parse_key(s: &str) -> [u8; 16] {
+    // slicing by byte pairs needs ASCII digits
+    if s.len() != 32 || !s.is_ascii() {
+        fail("the key must be 32 hexadecimal digits");
+    }
+    let mut key = [0u8; 16];
+    for (i, b) in key.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&s[2 * i..2 * i + 2], 16)
+            .unwrap_or_else(|_| fail("the key must be 32 hexadecimal digits"));
+    }
+    key
+}
+
+fn parse_lengths(s: &str) -> Range<usize> {
+    let (from, to) = s.split_once("..").unwrap_or_else(|| usage());
+    match (from.parse(), to.parse()) {
+        (Ok(from), Ok(to)) if from <= to => from..to,
+        _ => usage(),
+    }
+}
+
+fn run<const C: u8, const D: u8, T: HashOutput>(command: &Command) -> Result<String, SipError> {
+    match command {
+        Command::Generate {
+            format,
+            key,
+            lengths,
+        } => {
+            let mut out = Output(String::new());
+            generator::generate::<C, D, T, _>(&mut out, *format, key, lengths.clone())
+                .unwrap_or_else(|_| fail("formatting error"));
+            Ok(out.0)
+        }
+        Command::Verify { format, input } => {
+            let count = generator::verify::<C, D, T>(input, *format)?;
+            Ok(format!("{} vectors verified\n", count))
+        }
+    }
+}
+
+// map runtime values to the const generic parameters
+macro_rules! dispatch_d {
+    ($c:literal, $d:expr, $bits:expr, $command:expr, $($d_value:literal)*) => {
+        match $d {
+            $($d_value => match $bits {
+                64 => run::<$c, $d_value, Hash64>($command),
+                _ => run::<$c, $d_value, Hash128>($command),
+            },)*
+            _ => fail("d must be between 1 and 8"),
+        }
+    };
+}
+
+macro_rules! dispatch {
+    ($c:expr, $d:expr, $bits:expr, $command:expr, $($c_value:literal)*) => {
+        match $c {
+            $($c_value => dispatch_d!($c_value, $d, $bits, $command, 1 2 3 4 5 6 7 8),)*
+            _ => fail("c must be between 1 and 8"),
+        }
+    };
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        usage();
+    }
+
+    let (mut c, mut d, mut bits) = (2u8, 4u8, 64u16);
+    let mut format = Format::CHeader;
+    let mut key = KEY;
+    let mut lengths = 0..64;
+    let mut file = None;
+
+    let mut iter = args[1..].iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().map(String::as_str).unwrap_or_else(|| usage());
+        match arg.as_str() {
+            "--c" => c = value().parse().unwrap_or_else(|_| usage()),
+            "--d" => d = value().parse().unwrap_or_else(|_| usage()),
+            "--bits" => bits = value().parse().unwrap_or_else(|_| usage()),
+            "--format" => format = parse_format(value()),
+            "--key" => key = parse_key(value()),
+            "--lengths" => lengths = parse_lengths(value()),
+            _ if file.is_none() && !arg.starts_with("--") => file = Some(arg.clone()),
+            _ => usage(),
+        }
+    }
+
+    let command = match args[0].as_str() {
+        "generate" => Command::Generate {
+            format,
+            key,
+            lengths,
+        },
+        "verify" => {
+            let file = file.unwrap_or_else(|| usage());
+            let input = fs::read_to_string(&file)
+                .unwrap_or_else(|e| fail(&format!("can't read {}: {}", file, e)));
+
+            // the parameters are read from the vectors
+            let parameters =
+                generator::parameters(&input, format).unwrap_or_else(|e| fail(&format!("{:?}", e)));
+            c = parameters.c;
+            d = parameters.d;
+            bits = parameters.bits;
+
+            Command::Verify { format, input }
+        }
+        _ => usage(),
+    };
+
+    if bits != 64 && bits != 128 {
+        fail("the output size must be 64 or 128 bits");
+    }
+
+    match dispatch!(c, d, bits, &command, 1 2 3 4 5 6 7 8) {
+        Ok(output) => print!("{}", output),
+        Err(e) => fail(&format!("{:?}", e)),
+    }
+}
@@ -0,0 +1,356 @@
+//! A drop-in replacement of the [`siphasher`](https://crates.io/crates/siphasher) crate API: switching only
+//! requires changing `siphasher::` to `siphash_c_d::compat::` in the imports.
+//!
+//! ```rust
+//! use core::hash::Hasher;
+//! use siphash_c_d::compat::sip128::{Hasher128, SipHasher13};
+//!
+//! let mut hasher = SipHasher13::new_with_keys(0x0706050403020100, 0x0f0e0d0c0b0a0908);
+//! hasher.write(b"hello");
+//!
+//! let h = hasher.finish128();
+//! assert_eq!(hasher.finish(), h.h2);
+//! ```
+
+// the common methods of the hashers, which keep their key
+macro_rules! hasher {
+    ($(#[$doc:meta])* $name:ident, $c:literal, $d:literal, $output:ty) => {
+        $(#[$doc])*
+        #[derive(Clone)]
+        pub struct $name {
+            k0: u64,
+            k1: u64,
+            siphash: crate::SipHash<$c, $d, $output>,
+        }
+
+        impl $name {
+            /// A hasher with a key made of zeroes.
+            pub fn new() -> Self {
+                Self::new_with_keys(0, 0)
+            }
+
+            /// A hasher with the key made of `k0` and `k1`.
+            pub fn new_with_keys(k0: u64, k1: u64) -> Self {
+                Self {
+                    k0,
+                    k1,
+                    siphash: crate::SipHash::from_key(crate::SipHashKey::new(k0, k1)),
+                }
+            }
+
+            /// A hasher with the key made of 16 bytes.
+            pub fn new_with_key(key: &[u8; 16]) -> Self {
+                let k = crate::SipHashKey::from_bytes(key);
+                Self::new_with_keys(k.0, k.1)
+            }
+
+            /// The key as 2 `u64` values.
+            pub fn keys(&self) -> (u64, u64) {
+                (self.k0, self.k1)
+            }
+
+            /// The key as 16 bytes.
+            pub fn key(&self) -> [u8; 16] {
+                let mut key = [0u8; 16];
+                key[..8].copy_from_slice(&self.k0.to_le_bytes());
+                key[8..].copy_from_slice(&self.k1.to_le_bytes());
+                key
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        // the key is not shown
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($name)).finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+/// The 64-bit hashers of `siphasher::sip`.
+pub mod sip {
+    use core::hash::Hasher;
+
+    use crate::Hash64;
+
+    hasher!(
+        /// `siphash_1_3` 64-bit hasher.
+        SipHasher13, 1, 3, Hash64
+    );
+    hasher!(
+        /// `siphash_2_4` 64-bit hasher.
+        SipHasher24, 2, 4, Hash64
+    );
+    hasher!(
+        /// `siphash_2_4` 64-bit hasher.
+        SipHasher, 2, 4, Hash64
+    );
+
+    macro_rules! hasher64 {
+        ($name:ident) => {
+            impl Hasher for $name {
+                fn write(&mut self, bytes: &[u8]) {
+                    self.siphash.update(bytes);
+                }
+
+                // siphasher writes the integers as little-endian bytes, whatever the platform
+                fn write_u32(&mut self, i: u32) {
+                    self.siphash.update(&i.to_le_bytes());
+                }
+
+                fn write_u64(&mut self, i: u64) {
+                    self.siphash.update(&i.to_le_bytes());
+                }
+
+                fn write_usize(&mut self, i: usize) {
+                    self.siphash.update(&i.to_le_bytes());
+                }
+
+                fn finish(&self) -> u64 {
+                    self.siphash.finalize()
+                }
+            }
+        };
+    }
+
+    hasher64!(SipHasher13);
+    hasher64!(SipHasher24);
+    hasher64!(SipHasher);
+}
+
+/// The 128-bit hashers of `siphasher::sip128`.
+pub mod sip128 {
+    use core::hash::Hasher;
+
+    use crate::Digest128;
+
+    /// A 128-bit hash value, made of the 2 halves `h1` and `h2`.
+    #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Hash128 {
+        pub h1: u64,
+        pub h2: u64,
+    }
+
+    impl Hash128 {
+        /// The 16 bytes of the hash value: `h1` then `h2` as little-endian bytes.
+        pub fn as_bytes(&self) -> [u8; 16] {
+            Digest128::from(*self).to_le_bytes()
+        }
+
+        /// The hash value as a `u128` integer, `h1` being its low 64 bits.
+        pub fn as_u128(&self) -> u128 {
+            Digest128::from(*self).value()
+        }
+    }
+
+    impl From<u128> for Hash128 {
+        fn from(value: u128) -> Self {
+            Digest128::new(value).into()
+        }
+    }
+
+    impl From<Hash128> for u128 {
+        fn from(h: Hash128) -> Self {
+            h.as_u128()
+        }
+    }
+
+    impl From<Digest128> for Hash128 {
+        fn from(digest: Digest128) -> Self {
+            Self {
+                h1: digest.h1(),
+                h2: digest.h2(),
+            }
+        }
+    }
+
+    impl From<Hash128> for Digest128 {
+        fn from(h: Hash128) -> Self {
+            Digest128::from_halves(h.h1, h.h2)
+        }
+    }
+
+    /// A hasher with a 128-bit hash value.
+    pub trait Hasher128 {
+        /// The 128-bit hash value of the bytes written so far.
+        fn finish128(&self) -> Hash128;
+    }
+
+    hasher!(
+        /// `siphash_1_3` 128-bit hasher.
+        SipHasher13, 1, 3, crate::Hash128
+    );
+    hasher!(
+        /// `siphash_2_4` 128-bit hasher.
+        SipHasher24, 2, 4, crate::Hash128
+    );
+    hasher!(
+        /// `siphash_2_4` 128-bit hasher.
+        SipHasher, 2, 4, crate::Hash128
+    );
+
+    macro_rules! hasher128 {
+        ($name:ident) => {
+            impl Hasher128 for $name {
+                fn finish128(&self) -> Hash128 {
+                    Hash128::from(self.siphash.finalize())
+                }
+            }
+
+            // as siphasher, finish() is returning the second half of the 128-bit hash value
+            impl Hasher for $name {
+                fn write(&mut self, bytes: &[u8]) {
+                    self.siphash.update(bytes);
+                }
+
+                // siphasher writes the integers as little-endian bytes, whatever the platform
+                fn write_u32(&mut self, i: u32) {
+                    self.siphash.update(&i.to_le_bytes());
+                }
+
+                fn write_u64(&mut self, i: u64) {
+                    self.siphash.update(&i.to_le_bytes());
+                }
+
+                fn write_usize(&mut self, i: usize) {
+                    self.siphash.update(&i.to_le_bytes());
+                }
+
+                fn finish(&self) -> u64 {
+                    self.finish128().h2
+                }
+            }
+        };
+    }
+
+    hasher128!(SipHasher13);
+    hasher128!(SipHasher24);
+    hasher128!(SipHasher);
+}
+
+#[cfg(test)]
+mod tests {
+    use core::hash::Hasher;
+
+    use super::{sip, sip128, sip128::Hasher128};
+    use crate::{vectors, Hash128, Hash64, SipHash};
+
+    #[test]
+    fn test_sip_vectors() {
+        let mut msg = [0u8; 64];
+        (0..64).for_each(|i| msg[i] = i as u8);
+
+        for i in 0..64 {
+            let mut hasher = sip::SipHasher::new_with_key(&vectors::KEY);
+            hasher.write(&msg[..i]);
+            assert_eq!(hasher.finish(), vectors::SIPHASH_2_4_64[i]);
+
+            let mut hasher = sip::SipHasher13::new_with_key(&vectors::KEY);
+            hasher.write(&msg[..i]);
+            assert_eq!(hasher.finish(), vectors::SIPHASH_1_3_64[i]);
+        }
+    }
+
+    #[test]
+    fn test_sip128_vectors() {
+        let mut msg = [0u8; 64];
+        (0..64).for_each(|i| msg[i] = i as u8);
+
+        for i in 0..64 {
+            let mut hasher = sip128::SipHasher24::new_with_key(&vectors::KEY);
+            hasher.write(&msg[..i]);
+            let h = hasher.finish128();
+            assert_eq!(h.as_bytes(), vectors::SIPHASH_2_4_128[i]);
+            assert_eq!(hasher.finish(), h.h2);
+
+            let mut hasher = sip128::SipHasher13::new_with_key(&vectors::KEY);
+            hasher.write(&msg[..i]);
+            assert_eq!(hasher.finish128().as_bytes(), vectors::SIPHASH_1_3_128[i]);
+        }
+    }
+
+    #[test]
+    fn test_same_as_siphash() {
+        let (k0, k1) = (0x0706050403020100, 0x0f0e0d0c0b0a0908);
+
+        let mut compat = sip::SipHasher24::new_with_keys(k0, k1);
+        let mut siphash = SipHash::<2, 4, Hash64>::new((k0, k1)).unwrap();
+        compat.write_u64(0xdead_beef);
+        siphash.write(&0xdead_beef_u64.to_le_bytes());
+        compat.write_u8(1);
+        siphash.write_u8(1);
+        assert_eq!(compat.finish(), siphash.finish());
+
+        let mut compat = sip128::SipHasher13::new_with_keys(k0, k1);
+        compat.write_u64(0xdead_beef);
+        let h =
+            SipHash::<1, 3, Hash128>::with_key((k0, k1), &0xdead_beef_u64.to_le_bytes()).unwrap();
+        assert_eq!(compat.finish128().as_u128(), h);
+        assert_eq!(compat.finish128(), sip128::Hash128::from(h));
+        assert_eq!(u128::from(compat.finish128()), h);
+    }
+
+    // from the reference implementation, with the little-endian bytes of the integers as siphasher writes them
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_integers() {
+        fn write<H: Hasher>(hasher: &mut H) {
+            hasher.write_u64(0x0102030405060708);
+            hasher.write_u32(0xdeadbeef);
+            hasher.write_usize(42);
+        }
+
+        let mut hasher = sip::SipHasher24::new();
+        write(&mut hasher);
+        assert_eq!(hasher.finish(), 0xa20b10fbafb3b0e0);
+
+        let mut hasher = sip::SipHasher13::new();
+        write(&mut hasher);
+        assert_eq!(hasher.finish(), 0x3c0de252fffecfa1);
+
+        let mut hasher = sip128::SipHasher24::new();
+        write(&mut hasher);
+        assert_eq!(
+            hasher.finish128().as_u128(),
+            0x61e2d36fdaedbec23a9283cc8e2bae37
+        );
+
+        let mut hasher = sip128::SipHasher13::new();
+        write(&mut hasher);
+        assert_eq!(
+            hasher.finish128().as_u128(),
+            0x8f5fbf40cb2a09206ce016b3e708c3c6
+        );
+    }
+
+    #[test]
+    fn test_keys() {
+        let hasher = sip::SipHasher13::new_with_key(&vectors::KEY);
+        assert_eq!(hasher.keys(), (0x0706050403020100, 0x0f0e0d0c0b0a0908));
+        assert_eq!(hasher.key(), vectors::KEY);
+
+        let hasher = sip128::SipHasher::default();
+        assert_eq!(hasher.keys(), (0, 0));
+        assert_eq!(
+            hasher.finish128(),
+            sip128::SipHasher::new_with_keys(0, 0).finish128()
+        );
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut hasher = sip::SipHasher::new();
+        hasher.write(b"hello");
+        let mut other = hasher.clone();
+
+        hasher.write(b" world");
+        other.write(b" world");
+        assert_eq!(hasher.finish(), other.finish());
+    }
+}
@@ -0,0 +1,229 @@
+//! Typed hash values, usable directly as cache keys.
+use core::{cmp::Ordering, fmt, str::FromStr};
+
+use crate::{hex, SipError};
+
+// the methods and conversions shared by both digests
+macro_rules! digest {
+    ($name:ident, $value:ty, $bytes:literal) => {
+        impl $name {
+            /// The digest of the hash value `value`.
+            pub const fn new(value: $value) -> Self {
+                Self(value)
+            }
+
+            /// The hash value.
+            pub const fn value(&self) -> $value {
+                self.0
+            }
+
+            /// The little-endian bytes of the hash value, as written in the `out[]` buffer of the reference
+            /// implementation.
+            pub const fn to_le_bytes(&self) -> [u8; $bytes] {
+                self.0.to_le_bytes()
+            }
+
+            /// The digest of the little-endian bytes `bytes`.
+            pub const fn from_le_bytes(bytes: [u8; $bytes]) -> Self {
+                Self(<$value>::from_le_bytes(bytes))
+            }
+        }
+
+        impl From<$value> for $name {
+            fn from(value: $value) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for $value {
+            fn from(digest: $name) -> Self {
+                digest.0
+            }
+        }
+
+        impl From<[u8; $bytes]> for $name {
+            fn from(bytes: [u8; $bytes]) -> Self {
+                Self::from_le_bytes(bytes)
+            }
+        }
+
+        impl From<$name> for [u8; $bytes] {
+            fn from(digest: $name) -> Self {
+                digest.to_le_bytes()
+            }
+        }
+
+        /// The lowercase hexadecimal digits of the little-endian bytes.
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                hex::write_hex(f, &self.to_le_bytes())
+            }
+        }
+
+        /// Digests are ordered by their little-endian bytes, as their hexadecimal digits are, and not by their value.
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.to_le_bytes().cmp(&other.to_le_bytes())
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        /// Parse the hexadecimal digits of the little-endian bytes, either lowercase or uppercase.
+        impl FromStr for $name {
+            type Err = SipError;
+
+            fn from_str(s: &str) -> Result<Self, SipError> {
+                let mut bytes = [0u8; $bytes];
+                hex::decode_hex(s, &mut bytes).ok_or(SipError::InvalidDigest)?;
+                Ok(Self::from_le_bytes(bytes))
+            }
+        }
+    };
+}
+
+/// A 64-bit hash value.
+///
+/// ```rust
+/// use siphash_c_d::{Digest64, Hash64, SipHash};
+///
+/// let msg: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+/// let digest = SipHash::<2, 4, Hash64>::digest((0x0706050403020100, 0x0f0e0d0c0b0a0908), msg).unwrap();
+///
+/// assert_eq!(digest.value(), 0xa129ca6149be45e5);
+/// assert_eq!(digest.to_string(), "e545be4961ca29a1");
+/// assert_eq!("e545be4961ca29a1".parse::<Digest64>().unwrap(), digest);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Digest64(u64);
+
+digest!(Digest64, u64, 8);
+
+/// A 128-bit hash value, made of the 2 halves `h1` and `h2`.
+///
+/// ```rust
+/// use siphash_c_d::{Digest128, Hash128, SipHash};
+///
+/// let key: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+/// let digest = SipHash::<2, 4, Hash128>::digest(key, &[]).unwrap();
+///
+/// assert_eq!(digest.to_string(), "a3817f04ba25a8e66df67214c7550293");
+/// assert_eq!(digest.h1(), 0xe6a825ba047f81a3);
+/// assert_eq!(digest.h2(), 0x930255c71472f66d);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Digest128(u128);
+
+digest!(Digest128, u128, 16);
+
+impl Digest128 {
+    /// The digest made of the 2 halves: `h1` is the first 64-bit value of the finalization, `h2` the second one.
+    pub const fn from_halves(h1: u64, h2: u64) -> Self {
+        Self((h2 as u128) << 64 | h1 as u128)
+    }
+
+    /// The first half of the hash value, which is its first 8 little-endian bytes.
+    pub const fn h1(&self) -> u64 {
+        self.0 as u64
+    }
+
+    /// The second half of the hash value, which is its last 8 little-endian bytes.
+    pub const fn h2(&self) -> u64 {
+        (self.0 >> 64) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{vectors, Hash128, Hash64, SipHash};
+
+    // no String in no_std
+    struct Buffer([u8; 32], usize);
+
+    impl fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0[self.1..self.1 + s.len()].copy_from_slice(s.as_bytes());
+            self.1 += s.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_vectors() {
+        let mut msg = [0u8; 64];
+        (0..64).for_each(|i| msg[i] = i as u8);
+
+        for i in 0..64 {
+            let d = SipHash::<2, 4, Hash64>::digest(&vectors::KEY, &msg[..i]).unwrap();
+            assert_eq!(d, Digest64::new(vectors::SIPHASH_2_4_64[i]));
+
+            let d = SipHash::<2, 4, Hash128>::digest(&vectors::KEY, &msg[..i]).unwrap();
+            assert_eq!(d.to_le_bytes(), vectors::SIPHASH_2_4_128[i]);
+            assert_eq!(Digest128::from_halves(d.h1(), d.h2()), d);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        use core::fmt::Write;
+
+        let d = Digest128::from_le_bytes(vectors::SIPHASH_2_4_128[0]);
+        let mut buf = Buffer([0; 32], 0);
+        write!(buf, "{}", d).unwrap();
+        assert_eq!(&buf.0, b"a3817f04ba25a8e66df67214c7550293");
+
+        let s = core::str::from_utf8(&buf.0).unwrap();
+        assert_eq!(s.parse::<Digest128>(), Ok(d));
+        assert_eq!(
+            "A3817F04BA25A8E66DF67214C7550293".parse::<Digest128>(),
+            Ok(d)
+        );
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!("".parse::<Digest64>(), Err(SipError::InvalidDigest));
+        assert_eq!(
+            "e545be4961ca29a".parse::<Digest64>(),
+            Err(SipError::InvalidDigest)
+        );
+        assert_eq!(
+            "e545be4961ca29a1e5".parse::<Digest64>(),
+            Err(SipError::InvalidDigest)
+        );
+        assert_eq!(
+            "e545be4961ca29ag".parse::<Digest64>(),
+            Err(SipError::InvalidDigest)
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let d = Digest64::from(0xa129ca6149be45e5);
+        assert_eq!(u64::from(d), 0xa129ca6149be45e5);
+        assert_eq!(
+            <[u8; 8]>::from(d),
+            [0xe5, 0x45, 0xbe, 0x49, 0x61, 0xca, 0x29, 0xa1]
+        );
+        assert_eq!(Digest64::from(d.to_le_bytes()), d);
+        assert!(Digest64::new(1) < Digest64::new(2));
+    }
+
+    #[test]
+    fn test_order() {
+        // the order of the bytes, and so of the hexadecimal digits, not of the values
+        let (a, b) = (Digest64::new(0x0100), Digest64::new(0x02));
+        assert!(a < b);
+        assert!(a.to_le_bytes() < b.to_le_bytes());
+
+        let (a, b) = (Digest128::from_halves(0, 1), Digest128::from_halves(1, 0));
+        assert!(a < b);
+        assert!(a.to_le_bytes() < b.to_le_bytes());
+        assert_eq!(a.cmp(&a), Ordering::Equal);
+    }
+}
@@ -0,0 +1,849 @@
+//! Keyed fingerprints of any `Serialize` value, enabled by the `serde` feature.
+//!
+//! The [`Fingerprinter`] serializer feeds a [`SipHash`] directly, without any intermediate buffer. The encoding is
+//! canonical, prefix-free and platform-independent:
+//!
+//! * each value starts with a tag byte giving its type, so that `1u8` and `1u32`, or `None` and `()`, are different;
+//! * the integers, the floats (their bits) and the chars (their `u32` value) are little-endian;
+//! * the strings, the bytes and the names of the types, variants and fields are prefixed by their `u64` length;
+//! * the sequences, tuples, structs and maps are prefixed by their `u64` number of elements, and a variant by its
+//!   `u32` index and name;
+//! * the map entries are sorted by their encoded key, so that a `HashMap` and a `BTreeMap` give the same
+//!   fingerprint. This needs the `alloc` feature, and maps are an error without it.
+//!
+//! The sequences need to know their length before their elements are serialized.
+//!
+//! ```rust
+//! use serde::Serialize;
+//! use siphash_c_d::fingerprint::sip_fingerprint;
+//!
+//! #[derive(Serialize)]
+//! struct Config<'a> {
+//!     name: &'a str,
+//!     retries: u32,
+//!     hosts: [&'a str; 2],
+//! }
+//!
+//! let key = [0u8; 16];
+//! let config = Config { name: "cache", retries: 3, hosts: ["a.example", "b.example"] };
+//!
+//! let fingerprint = sip_fingerprint(&key, &config).unwrap();
+//! assert_eq!(fingerprint, sip_fingerprint(&key, &config).unwrap());
+//! ```
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(not(feature = "alloc"))]
+use serde::ser::Impossible;
+#[cfg(feature = "alloc")]
+use serde::ser::SerializeMap;
+use serde::{
+    ser::{self, SerializeSeq, SerializeStruct, SerializeStructVariant},
+    ser::{SerializeTuple, SerializeTupleStruct, SerializeTupleVariant},
+    Serialize, Serializer,
+};
+
+use crate::{siphashkey::SipHashKey, HashOutput, SipError, SipHash, SipHash24};
+
+// the tags of the encoded values
+const UNIT: u8 = 0x00;
+const BOOL: u8 = 0x01;
+const I8: u8 = 0x02;
+const I16: u8 = 0x03;
+const I32: u8 = 0x04;
+const I64: u8 = 0x05;
+const I128: u8 = 0x06;
+const U8: u8 = 0x07;
+const U16: u8 = 0x08;
+const U32: u8 = 0x09;
+const U64: u8 = 0x0A;
+const U128: u8 = 0x0B;
+const F32: u8 = 0x0C;
+const F64: u8 = 0x0D;
+const CHAR: u8 = 0x0E;
+const STR: u8 = 0x0F;
+const BYTES: u8 = 0x10;
+const NONE: u8 = 0x11;
+const SOME: u8 = 0x12;
+const UNIT_STRUCT: u8 = 0x13;
+const UNIT_VARIANT: u8 = 0x14;
+const NEWTYPE_STRUCT: u8 = 0x15;
+const NEWTYPE_VARIANT: u8 = 0x16;
+const SEQ: u8 = 0x17;
+const TUPLE: u8 = 0x18;
+const TUPLE_STRUCT: u8 = 0x19;
+const TUPLE_VARIANT: u8 = 0x1A;
+#[cfg(feature = "alloc")]
+const MAP: u8 = 0x1B;
+const STRUCT: u8 = 0x1C;
+const STRUCT_VARIANT: u8 = 0x1D;
+
+/// The errors of the fingerprints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The key is invalid.
+    Key(SipError),
+
+    /// The value returned a custom error, whose message is not kept.
+    Custom,
+
+    /// A sequence or a map didn't give its length before its elements.
+    UnknownLength,
+
+    /// The number of elements of a sequence, tuple or struct is not the announced one: the expected and the found
+    /// numbers.
+    LengthMismatch { expected: usize, found: usize },
+
+    /// Maps are sorted by key, which needs the `alloc` feature.
+    MapRequiresAlloc,
+
+    /// A map entry is a key without a value, or a value without a key.
+    IncompleteMapEntry,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Key(e) => write!(f, "invalid key: {:?}", e),
+            Error::Custom => f.write_str("the value failed to serialize"),
+            Error::UnknownLength => f.write_str("the length of a sequence is unknown"),
+            Error::LengthMismatch { expected, found } => {
+                write!(
+                    f,
+                    "{} elements were announced but {} were found",
+                    expected, found
+                )
+            }
+            Error::MapRequiresAlloc => f.write_str("maps need the alloc feature"),
+            Error::IncompleteMapEntry => {
+                f.write_str("a map key and its value are not given in pairs")
+            }
+        }
+    }
+}
+
+impl ser::StdError for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        Error::Custom
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The destination of the encoded bytes: a [`SipHash`], or a `Vec<u8>` with the `alloc` feature.
+pub trait Sink: private::Sealed {
+    #[doc(hidden)]
+    fn write(&mut self, bytes: &[u8]);
+}
+
+impl<const C: u8, const D: u8, T: HashOutput> private::Sealed for SipHash<C, D, T> {}
+
+impl<const C: u8, const D: u8, T: HashOutput> Sink for SipHash<C, D, T> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl private::Sealed for Vec<u8> {}
+
+#[cfg(feature = "alloc")]
+impl Sink for Vec<u8> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+/// Add the encoding of `value` to the message being hashed by `siphash`.
+pub fn update<const C: u8, const D: u8, T: HashOutput, V: Serialize + ?Sized>(
+    siphash: &mut SipHash<C, D, T>,
+    value: &V,
+) -> Result<(), Error> {
+    value.serialize(Fingerprinter::new(siphash))
+}
+
+/// The `siphash_2_4` 64-bit fingerprint of `value` using the key `key`.
+pub fn sip_fingerprint<K, V>(key: K, value: &V) -> Result<u64, Error>
+where
+    K: TryInto<SipHashKey, Error = SipError>,
+    V: Serialize + ?Sized,
+{
+    let mut siphash = SipHash24::new(key).map_err(Error::Key)?;
+    update(&mut siphash, value)?;
+    Ok(siphash.finalize())
+}
+
+/// The serializer writing the canonical encoding of the values to a [`Sink`].
+pub struct Fingerprinter<'a, W: Sink> {
+    sink: &'a mut W,
+}
+
+impl<'a, W: Sink> Fingerprinter<'a, W> {
+    /// The serializer writing to `sink`.
+    pub fn new(sink: &'a mut W) -> Self {
+        Self { sink }
+    }
+
+    fn tag(&mut self, tag: u8) {
+        self.sink.write(&[tag]);
+    }
+
+    fn length(&mut self, length: usize) {
+        self.sink.write(&(length as u64).to_le_bytes());
+    }
+
+    fn name(&mut self, name: &str) {
+        self.length(name.len());
+        self.sink.write(name.as_bytes());
+    }
+
+    fn variant(&mut self, name: &str, index: u32, variant: &str) {
+        self.name(name);
+        self.sink.write(&index.to_le_bytes());
+        self.name(variant);
+    }
+
+    fn compound(self, length: usize) -> Compound<'a, W> {
+        Compound {
+            sink: self.sink,
+            expected: length,
+            found: 0,
+        }
+    }
+}
+
+// the length of a Display, which is written twice
+struct Counter(usize);
+
+impl fmt::Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+struct Writer<'a, W: Sink>(&'a mut W);
+
+impl<W: Sink> fmt::Write for Writer<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write(s.as_bytes());
+        Ok(())
+    }
+}
+
+// the integers, little-endian after their tag
+macro_rules! number {
+    ($($method:ident($type:ty) => $tag:ident,)+) => {
+        $(
+            fn $method(mut self, v: $type) -> Result<(), Error> {
+                self.tag($tag);
+                self.sink.write(&v.to_le_bytes());
+                Ok(())
+            }
+        )+
+    };
+}
+
+impl<'a, W: Sink> Serializer for Fingerprinter<'a, W> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Compound<'a, W>;
+    type SerializeTuple = Compound<'a, W>;
+    type SerializeTupleStruct = Compound<'a, W>;
+    type SerializeTupleVariant = Compound<'a, W>;
+    #[cfg(feature = "alloc")]
+    type SerializeMap = MapCompound<'a, W>;
+    #[cfg(not(feature = "alloc"))]
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = Compound<'a, W>;
+    type SerializeStructVariant = Compound<'a, W>;
+
+    number! {
+        serialize_i8(i8) => I8,
+        serialize_i16(i16) => I16,
+        serialize_i32(i32) => I32,
+        serialize_i64(i64) => I64,
+        serialize_i128(i128) => I128,
+        serialize_u8(u8) => U8,
+        serialize_u16(u16) => U16,
+        serialize_u32(u32) => U32,
+        serialize_u64(u64) => U64,
+        serialize_u128(u128) => U128,
+    }
+
+    fn serialize_bool(mut self, v: bool) -> Result<(), Error> {
+        self.tag(BOOL);
+        self.sink.write(&[v as u8]);
+        Ok(())
+    }
+
+    fn serialize_f32(mut self, v: f32) -> Result<(), Error> {
+        self.tag(F32);
+        self.sink.write(&v.to_bits().to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_f64(mut self, v: f64) -> Result<(), Error> {
+        self.tag(F64);
+        self.sink.write(&v.to_bits().to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(mut self, v: char) -> Result<(), Error> {
+        self.tag(CHAR);
+        self.sink.write(&(v as u32).to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_str(mut self, v: &str) -> Result<(), Error> {
+        self.tag(STR);
+        self.name(v);
+        Ok(())
+    }
+
+    // the same as serialize_str() without allocation
+    fn collect_str<T: fmt::Display + ?Sized>(mut self, value: &T) -> Result<(), Error> {
+        let mut counter = Counter(0);
+        fmt::write(&mut counter, format_args!("{}", value)).map_err(|_| Error::Custom)?;
+
+        self.tag(STR);
+        self.length(counter.0);
+        fmt::write(&mut Writer(self.sink), format_args!("{}", value)).map_err(|_| Error::Custom)
+    }
+
+    fn serialize_bytes(mut self, v: &[u8]) -> Result<(), Error> {
+        self.tag(BYTES);
+        self.length(v.len());
+        self.sink.write(v);
+        Ok(())
+    }
+
+    fn serialize_none(mut self) -> Result<(), Error> {
+        self.tag(NONE);
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(mut self, value: &T) -> Result<(), Error> {
+        self.tag(SOME);
+        value.serialize(self)
+    }
+
+    fn serialize_unit(mut self) -> Result<(), Error> {
+        self.tag(UNIT);
+        Ok(())
+    }
+
+    fn serialize_unit_struct(mut self, name: &'static str) -> Result<(), Error> {
+        self.tag(UNIT_STRUCT);
+        self.name(name);
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), Error> {
+        self.tag(UNIT_VARIANT);
+        self.variant(name, variant_index, variant);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.tag(NEWTYPE_STRUCT);
+        self.name(name);
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.tag(NEWTYPE_VARIANT);
+        self.variant(name, variant_index, variant);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(mut self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        let len = len.ok_or(Error::UnknownLength)?;
+        self.tag(SEQ);
+        self.length(len);
+        Ok(self.compound(len))
+    }
+
+    fn serialize_tuple(mut self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        self.tag(TUPLE);
+        self.length(len);
+        Ok(self.compound(len))
+    }
+
+    fn serialize_tuple_struct(
+        mut self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        self.tag(TUPLE_STRUCT);
+        self.name(name);
+        self.length(len);
+        Ok(self.compound(len))
+    }
+
+    fn serialize_tuple_variant(
+        mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        self.tag(TUPLE_VARIANT);
+        self.variant(name, variant_index, variant);
+        self.length(len);
+        Ok(self.compound(len))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Ok(MapCompound {
+            sink: self.sink,
+            entries: Vec::new(),
+            value_pending: false,
+        })
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Err(Error::MapRequiresAlloc)
+    }
+
+    fn serialize_struct(
+        mut self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        self.tag(STRUCT);
+        self.name(name);
+        self.length(len);
+        Ok(self.compound(len))
+    }
+
+    fn serialize_struct_variant(
+        mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        self.tag(STRUCT_VARIANT);
+        self.variant(name, variant_index, variant);
+        self.length(len);
+        Ok(self.compound(len))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+/// The serializer of the elements of sequences, tuples and structs, checking their announced number.
+pub struct Compound<'a, W: Sink> {
+    sink: &'a mut W,
+    expected: usize,
+    found: usize,
+}
+
+impl<W: Sink> Compound<'_, W> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.found += 1;
+        value.serialize(Fingerprinter::new(&mut *self.sink))
+    }
+
+    fn field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Error> {
+        Fingerprinter::new(&mut *self.sink).name(key);
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        if self.found == self.expected {
+            Ok(())
+        } else {
+            Err(Error::LengthMismatch {
+                expected: self.expected,
+                found: self.found,
+            })
+        }
+    }
+}
+
+// the same implementation for the sequences and tuples
+macro_rules! compound {
+    ($($trait:ident::$method:ident,)+) => {
+        $(
+            impl<W: Sink> $trait for Compound<'_, W> {
+                type Ok = ();
+                type Error = Error;
+
+                fn $method<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+                    self.element(value)
+                }
+
+                fn end(self) -> Result<(), Error> {
+                    Compound::end(self)
+                }
+            }
+        )+
+    };
+}
+
+compound! {
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field,
+}
+
+impl<W: Sink> SerializeStruct for Compound<'_, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+impl<W: Sink> SerializeStructVariant for Compound<'_, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Compound::end(self)
+    }
+}
+
+/// The serializer of the map entries, which are encoded separately to be sorted.
+#[cfg(feature = "alloc")]
+pub struct MapCompound<'a, W: Sink> {
+    sink: &'a mut W,
+    entries: Vec<(Vec<u8>, Vec<u8>)>,
+
+    // the last key has no value yet
+    value_pending: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<W: Sink> SerializeMap for MapCompound<'_, W> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        if self.value_pending {
+            return Err(Error::IncompleteMapEntry);
+        }
+
+        let mut encoded = Vec::new();
+        key.serialize(Fingerprinter::new(&mut encoded))?;
+        self.entries.push((encoded, Vec::new()));
+        self.value_pending = true;
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        match self.entries.last_mut() {
+            Some(entry) if self.value_pending => {
+                self.value_pending = false;
+                value.serialize(Fingerprinter::new(&mut entry.1))
+            }
+            _ => Err(Error::IncompleteMapEntry),
+        }
+    }
+
+    fn end(mut self) -> Result<(), Error> {
+        if self.value_pending {
+            return Err(Error::IncompleteMapEntry);
+        }
+
+        // the values are also compared for duplicate keys
+        self.entries.sort_unstable();
+
+        let mut fingerprinter = Fingerprinter::new(self.sink);
+        fingerprinter.tag(MAP);
+        fingerprinter.length(self.entries.len());
+        for (key, value) in &self.entries {
+            fingerprinter.sink.write(key);
+            fingerprinter.sink.write(value);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::vectors::KEY;
+
+    #[derive(Serialize)]
+    struct Config<'a> {
+        name: &'a str,
+        retries: Option<u8>,
+    }
+
+    #[derive(Serialize)]
+    enum Mode {
+        Fast,
+        Slow(u16),
+        Custom { level: i8 },
+    }
+
+    // a fixed buffer to check the encoding without alloc
+    struct Buffer([u8; 128], usize);
+
+    impl private::Sealed for Buffer {}
+
+    impl Sink for Buffer {
+        fn write(&mut self, bytes: &[u8]) {
+            self.0[self.1..self.1 + bytes.len()].copy_from_slice(bytes);
+            self.1 += bytes.len();
+        }
+    }
+
+    fn encode<V: Serialize + ?Sized>(value: &V) -> Buffer {
+        let mut buffer = Buffer([0; 128], 0);
+        value.serialize(Fingerprinter::new(&mut buffer)).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn test_encoding() {
+        let config = Config {
+            name: "db",
+            retries: Some(3),
+        };
+        let buffer = encode(&config);
+
+        let expected: &[u8] = &[
+            STRUCT, 6, 0, 0, 0, 0, 0, 0, 0, b'C', b'o', b'n', b'f', b'i', b'g', 2, 0, 0, 0, 0, 0,
+            0, 0, // struct Config of 2 fields
+            4, 0, 0, 0, 0, 0, 0, 0, b'n', b'a', b'm', b'e', // field name
+            STR, 2, 0, 0, 0, 0, 0, 0, 0, b'd', b'b', // "db"
+            7, 0, 0, 0, 0, 0, 0, 0, b'r', b'e', b't', b'r', b'i', b'e', b's', // field retries
+            SOME, U8, 3, // Some(3u8)
+        ];
+        assert_eq!(&buffer.0[..buffer.1], expected);
+
+        // the fingerprint is the hash of the encoding
+        assert_eq!(
+            sip_fingerprint(&KEY, &config).unwrap(),
+            SipHash24::with_key(&KEY, expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_variants() {
+        let fast = encode(&Mode::Fast);
+        assert_eq!(
+            &fast.0[..fast.1],
+            &[
+                UNIT_VARIANT,
+                4,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                b'M',
+                b'o',
+                b'd',
+                b'e',
+                0,
+                0,
+                0,
+                0,
+                4,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                b'F',
+                b'a',
+                b's',
+                b't'
+            ]
+        );
+
+        let slow = encode(&Mode::Slow(1));
+        assert_eq!(slow.0[0], NEWTYPE_VARIANT);
+        assert_eq!(&slow.0[slow.1 - 3..slow.1], &[U16, 1, 0]);
+
+        let custom = encode(&Mode::Custom { level: -1 });
+        assert_eq!(custom.0[0], STRUCT_VARIANT);
+        assert_eq!(&custom.0[custom.1 - 2..custom.1], &[I8, 0xFF]);
+    }
+
+    #[test]
+    fn test_prefix_free() {
+        let fingerprint = |v: &(&str, &str)| sip_fingerprint(&KEY, v).unwrap();
+
+        assert_ne!(fingerprint(&("ab", "c")), fingerprint(&("a", "bc")));
+        assert_ne!(fingerprint(&("", "abc")), fingerprint(&("abc", "")));
+        assert_ne!(
+            sip_fingerprint(&KEY, &1u8).unwrap(),
+            sip_fingerprint(&KEY, &1u32).unwrap()
+        );
+        assert_ne!(
+            sip_fingerprint(&KEY, &None::<()>).unwrap(),
+            sip_fingerprint(&KEY, &()).unwrap()
+        );
+        assert_ne!(
+            sip_fingerprint(&KEY, &[1u8, 2][..]).unwrap(),
+            sip_fingerprint(&KEY, &(1u8, 2u8)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_collect_str() {
+        struct Displayed;
+
+        impl Serialize for Displayed {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(&format_args!("{}-{}", "ab", 12))
+            }
+        }
+
+        assert_eq!(
+            sip_fingerprint(&KEY, &Displayed).unwrap(),
+            sip_fingerprint(&KEY, "ab-12").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        // a sequence of unknown length
+        struct Unknown;
+
+        impl Serialize for Unknown {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_seq(None)?.end()
+            }
+        }
+
+        // a tuple announcing 2 elements but giving 1
+        struct Short;
+
+        impl Serialize for Short {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut tuple = serializer.serialize_tuple(2)?;
+                tuple.serialize_element(&1u8)?;
+                tuple.end()
+            }
+        }
+
+        assert_eq!(sip_fingerprint(&KEY, &Unknown), Err(Error::UnknownLength));
+        assert_eq!(
+            sip_fingerprint(&KEY, &Short),
+            Err(Error::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            sip_fingerprint(&KEY[..8], &()),
+            Err(Error::Key(SipError::KeyTooShort(8)))
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc"))]
+    fn test_map_without_alloc() {
+        let map: std::collections::BTreeMap<u8, u8> = [(1, 2)].into_iter().collect();
+        assert_eq!(sip_fingerprint(&KEY, &map), Err(Error::MapRequiresAlloc));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_map_sorted() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let entries = [("zeta", 1u32), ("alpha", 2), ("mu", 3), ("", 4)];
+        let btree: BTreeMap<_, _> = entries.iter().copied().collect();
+        let hash: HashMap<_, _> = entries.iter().copied().collect();
+
+        let fingerprint = sip_fingerprint(&KEY, &btree).unwrap();
+        assert_eq!(fingerprint, sip_fingerprint(&KEY, &hash).unwrap());
+
+        let mut other = btree.clone();
+        other.insert("mu", 30);
+        assert_ne!(fingerprint, sip_fingerprint(&KEY, &other).unwrap());
+
+        // the entries sorted by their encoding
+        let mut encoded = Vec::new();
+        btree.serialize(Fingerprinter::new(&mut encoded)).unwrap();
+        assert_eq!(&encoded[..9], &[MAP, 4, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(&encoded[9..18], &[STR, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_map_entries() {
+        use serde::ser::SerializeMap;
+
+        // the calls of serialize_key() and serialize_value(), in this order
+        struct Calls(&'static [bool]);
+
+        impl Serialize for Calls {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut map = serializer.serialize_map(Some(1))?;
+                for &key in self.0 {
+                    if key {
+                        map.serialize_key(&1u8)?;
+                    } else {
+                        map.serialize_value(&2u8)?;
+                    }
+                }
+                map.end()
+            }
+        }
+
+        assert!(sip_fingerprint(&KEY, &Calls(&[true, false])).is_ok());
+        for calls in [&[false][..], &[true], &[true, true], &[true, false, false]] {
+            assert_eq!(
+                sip_fingerprint(&KEY, &Calls(calls)),
+                Err(Error::IncompleteMapEntry)
+            );
+        }
+    }
+}
@@ -0,0 +1,751 @@
+//! Generation and verification of test vectors, to share them with other implementations.
+//!
+//! As in the reference `vectors.h`, all the vectors of a set are using the same key, and the message of length `n`
+//! is made of the bytes `0, 1, .., n-1` (modulo 256). Hash values are written as the lowercase hexadecimal
+//! little-endian bytes of the value, as in the `out[]` buffer of the reference implementation.
+//!
+//! Three formats are supported:
+//!
+//! * [`Format::CHeader`]: the layout of `vectors.h` from <https://github.com/veorq/SipHash>, the array being named
+//!   `vectors_sip64` or `vectors_sip128`. The `c` and `d` values, the key and the first message length are given
+//!   in a leading comment. If this comment is missing, as in the reference file, `siphash_2_4`, the key `00 01 .. 0f`
+//!   and a first message length of 0 are assumed, so it is only written for other parameters: the reference vectors
+//!   are generated as the arrays of `vectors.h`, byte for byte.
+//! * [`Format::Json`]: an object with the `c`, `d`, `bits` and `key` fields, and the `vectors` array of
+//!   `{ "length", "message", "hash" }` objects.
+//! * [`Format::Csv`]: a header line followed by one `c,d,bits,key,length,message,hash` line per vector.
+//!
+//! When reading JSON or CSV vectors, the message given in the vector is hashed, so vectors for any message can be verified.
+//!
+//! No allocation is made: vectors are written to any [`core::fmt::Write`] and read back from a `&str`.
+//!
+//! ```rust
+//! use siphash_c_d::{
+//!     generator::{self, Format},
+//!     vectors::KEY,
+//!     Hash64,
+//! };
+//!
+//! let mut json = String::new();
+//! generator::generate::<1, 3, Hash64, _>(&mut json, Format::Json, &KEY, 0..16).unwrap();
+//!
+//! let parameters = generator::parameters(&json, Format::Json).unwrap();
+//! assert_eq!((parameters.c, parameters.d, parameters.bits), (1, 3, 64));
+//!
+//! assert_eq!(generator::verify::<1, 3, Hash64>(&json, Format::Json).unwrap(), 16);
+//! ```
+use core::{fmt, ops::Range};
+
+use crate::{
+    hex::{decode_hex, write_hex},
+    siphash::HashOutput,
+    vectors::KEY,
+    SipError, SipHash,
+};
+
+/// The format of a set of test vectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The layout of the reference `vectors.h`.
+    CHeader,
+
+    /// A JSON object.
+    Json,
+
+    /// Comma separated values, with a header line.
+    Csv,
+}
+
+/// The parameters of a set of test vectors, as found by [`parameters`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameters {
+    /// Number of compression rounds.
+    pub c: u8,
+
+    /// Number of finalization rounds.
+    pub d: u8,
+
+    /// Size of the hash value in bits: 64 or 128.
+    pub bits: u16,
+
+    /// The key used for the vectors (of the first vector for CSV).
+    pub key: [u8; 16],
+}
+
+// the message of length n is made of the repeated bytes 0..=255
+const PATTERN: [u8; 256] = pattern();
+
+const fn pattern() -> [u8; 256] {
+    let mut p = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        p[i] = i as u8;
+        i += 1;
+    }
+    p
+}
+
+/// Write the test vectors of `siphash_c_d` for the messages whose lengths are in `lengths`, using the key `key`.
+pub fn generate<const C: u8, const D: u8, T: HashOutput, W: fmt::Write>(
+    out: &mut W,
+    format: Format,
+    key: &[u8; 16],
+    lengths: Range<usize>,
+) -> fmt::Result {
+    match format {
+        Format::CHeader => {
+            if (C, D, key, lengths.start) != (2, 4, &KEY, 0) {
+                writeln!(out, "/* siphash_{}_{} {}-bit test vectors", C, D, T::BITS)?;
+                write!(out, " * key: ")?;
+                write_hex(out, key)?;
+                writeln!(out)?;
+                writeln!(out, " * first length: {}", lengths.start)?;
+                writeln!(out, " */")?;
+            }
+            writeln!(out, "#include <stdint.h>")?;
+            writeln!(out)?;
+            writeln!(
+                out,
+                "const uint8_t vectors_sip{}[{}][{}] = {{",
+                T::BITS,
+                lengths.len(),
+                T::BITS / 8
+            )?;
+            for n in lengths {
+                writeln!(out, "    {{")?;
+                for b in hash_message::<C, D, T>(key, n).as_ref() {
+                    writeln!(out, "        0x{:02x},", b)?;
+                }
+                writeln!(out, "    }},")?;
+            }
+            writeln!(out, "}};")
+        }
+        Format::Json => {
+            writeln!(out, "{{")?;
+            writeln!(out, "  \"c\": {},", C)?;
+            writeln!(out, "  \"d\": {},", D)?;
+            writeln!(out, "  \"bits\": {},", T::BITS)?;
+            write!(out, "  \"key\": \"")?;
+            write_hex(out, key)?;
+            writeln!(out, "\",")?;
+            write!(out, "  \"vectors\": [")?;
+            for (i, n) in lengths.enumerate() {
+                if i != 0 {
+                    write!(out, ",")?;
+                }
+                write!(out, "\n    {{ \"length\": {}, \"message\": \"", n)?;
+                write_message(out, n)?;
+                write!(out, "\", \"hash\": \"")?;
+                write_hex(out, hash_message::<C, D, T>(key, n).as_ref())?;
+                write!(out, "\" }}")?;
+            }
+            writeln!(out, "\n  ]")?;
+            writeln!(out, "}}")
+        }
+        Format::Csv => {
+            writeln!(out, "c,d,bits,key,length,message,hash")?;
+            for n in lengths {
+                write!(out, "{},{},{},", C, D, T::BITS)?;
+                write_hex(out, key)?;
+                write!(out, ",{},", n)?;
+                write_message(out, n)?;
+                write!(out, ",")?;
+                write_hex(out, hash_message::<C, D, T>(key, n).as_ref())?;
+                writeln!(out)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Read the parameters of a set of test vectors.
+///
+/// Returns `SipError::InvalidVectors` with the line number if they can't be found.
+pub fn parameters(input: &str, format: Format) -> Result<Parameters, SipError> {
+    match format {
+        Format::CHeader => {
+            // the comment is optional
+            let (c, d) = match input.find("siphash_") {
+                Some(pos) => {
+                    let (c, next) = number(input, pos + "siphash_".len())?;
+                    let (d, _) = number(input, next + 1)?;
+                    (to_u8(input, pos, c)?, to_u8(input, pos, d)?)
+                }
+                None => (2, 4),
+            };
+            let key = match input.find("key:") {
+                Some(pos) => hex_key(input, pos, rest(input, pos + 4)?.trim_start())?,
+                None => KEY,
+            };
+            let pos = input
+                .find("vectors_sip")
+                .ok_or(SipError::InvalidVectors(line_of(input, input.len())))?;
+            let (bits, _) = number(input, pos + "vectors_sip".len())?;
+
+            Ok(Parameters {
+                c,
+                d,
+                bits: to_bits(input, pos, bits)?,
+                key,
+            })
+        }
+        Format::Json => {
+            // the parameters are before the vectors
+            let header = &input[..input.find("\"vectors\"").unwrap_or(input.len())];
+
+            let (c, pos) = json_field(header, "c")
+                .ok_or(SipError::InvalidVectors(line_of(input, input.len())))?;
+            let c = to_u8(input, pos, json_number(input, pos, c)?)?;
+            let (d, pos) = json_field(header, "d")
+                .ok_or(SipError::InvalidVectors(line_of(input, input.len())))?;
+            let d = to_u8(input, pos, json_number(input, pos, d)?)?;
+            let (bits, pos) = json_field(header, "bits")
+                .ok_or(SipError::InvalidVectors(line_of(input, input.len())))?;
+            let bits = to_bits(input, pos, json_number(input, pos, bits)?)?;
+            let (key, pos) = json_field(header, "key")
+                .ok_or(SipError::InvalidVectors(line_of(input, input.len())))?;
+
+            Ok(Parameters {
+                c,
+                d,
+                bits,
+                key: hex_key(input, pos, key)?,
+            })
+        }
+        Format::Csv => {
+            let (line_number, line) = csv_lines(input)
+                .next()
+                .ok_or(SipError::InvalidVectors(line_of(input, input.len())))?;
+            let row = CsvRow::parse(line_number, line)?;
+
+            Ok(row.parameters)
+        }
+    }
+}
+
+/// Verify that this crate agrees with all the test vectors found in `input`.
+///
+/// Returns the number of verified vectors, `SipError::ParametersMismatch` if the vectors were not computed
+/// for `C`, `D` and `T`, `SipError::VectorMismatch` with the message length on the first wrong hash value,
+/// or `SipError::InvalidVectors` with the line number if the vectors can't be read.
+pub fn verify<const C: u8, const D: u8, T: HashOutput>(
+    input: &str,
+    format: Format,
+) -> Result<usize, SipError> {
+    let parameters = parameters(input, format)?;
+    check_parameters::<C, D, T>(&parameters)?;
+
+    let mut count = 0;
+
+    match format {
+        Format::CHeader => {
+            let first = match input.find("first length:") {
+                Some(pos) => number(input, skip_whitespace(input, pos + 13))?.0,
+                None => 0,
+            };
+
+            // find the array for the hash size
+            let mut start = 0;
+            let pos = loop {
+                let pos = start
+                    + rest(input, start)?
+                        .find("vectors_sip")
+                        .ok_or(SipError::InvalidVectors(line_of(input, start)))?;
+                let (bits, _) = number(input, pos + "vectors_sip".len())?;
+                if bits == T::BITS as usize {
+                    break pos;
+                }
+                start = pos + 1;
+            };
+            let begin = pos
+                + rest(input, pos)?
+                    .find('=')
+                    .ok_or(SipError::InvalidVectors(line_of(input, pos)))?;
+            let end = begin
+                + rest(input, begin)?
+                    .find("};")
+                    .ok_or(SipError::InvalidVectors(line_of(input, begin)))?;
+
+            // all the 0xXX values are the bytes of the hash values
+            let mut expected = T::Bytes::default();
+            let mut i = 0;
+            let mut start = begin;
+            while let Some(offset) = input[start..end].find("0x") {
+                let pos = start + offset + 2;
+                let byte = input
+                    .get(pos..pos + 2)
+                    .and_then(|s| {
+                        let mut b = [0u8; 1];
+                        decode_hex(s, &mut b).map(|_| b[0])
+                    })
+                    .ok_or(SipError::InvalidVectors(line_of(input, pos)))?;
+
+                expected.as_mut()[i] = byte;
+                i += 1;
+
+                if i == expected.as_ref().len() {
+                    let length = first + count;
+                    if hash_message::<C, D, T>(&parameters.key, length) != expected {
+                        return Err(SipError::VectorMismatch(length));
+                    }
+                    count += 1;
+                    i = 0;
+                }
+                start = pos + 2;
+            }
+
+            if i != 0 {
+                return Err(SipError::InvalidVectors(line_of(input, end)));
+            }
+        }
+        Format::Json => {
+            let mut start = input
+                .find("\"vectors\"")
+                .ok_or(SipError::InvalidVectors(line_of(input, input.len())))?;
+
+            while let Some(offset) = input[start..].find('{') {
+                let begin = start + offset;
+                let end = begin
+                    + input[begin..]
+                        .find('}')
+                        .ok_or(SipError::InvalidVectors(line_of(input, begin)))?;
+                let object = &input[begin..end];
+                let invalid = SipError::InvalidVectors(line_of(input, begin));
+
+                let (length, pos) = json_field(object, "length").ok_or(invalid)?;
+                let length = json_number(input, begin + pos, length)?;
+                let (message, pos) = json_field(object, "message").ok_or(invalid)?;
+                let computed = hash_hex::<C, D, T>(&parameters.key, message, length)
+                    .ok_or(SipError::InvalidVectors(line_of(input, begin + pos)))?;
+                let (hash, pos) = json_field(object, "hash").ok_or(invalid)?;
+                let expected = hex_hash::<T>(input, begin + pos, hash)?;
+
+                if computed != expected {
+                    return Err(SipError::VectorMismatch(length));
+                }
+                count += 1;
+                start = end;
+            }
+        }
+        Format::Csv => {
+            for (line_number, line) in csv_lines(input) {
+                let row = CsvRow::parse(line_number, line)?;
+                check_parameters::<C, D, T>(&row.parameters)?;
+
+                let computed = hash_hex::<C, D, T>(&row.parameters.key, row.message, row.length)
+                    .ok_or(SipError::InvalidVectors(line_number))?;
+                let mut expected = T::Bytes::default();
+                decode_hex(row.hash, expected.as_mut())
+                    .ok_or(SipError::InvalidVectors(line_number))?;
+
+                if computed != expected {
+                    return Err(SipError::VectorMismatch(row.length));
+                }
+                count += 1;
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+fn check_parameters<const C: u8, const D: u8, T: HashOutput>(
+    parameters: &Parameters,
+) -> Result<(), SipError> {
+    if parameters.c != C || parameters.d != D || parameters.bits != T::BITS {
+        Err(SipError::ParametersMismatch {
+            c: parameters.c,
+            d: parameters.d,
+            bits: parameters.bits,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+// hash of the message of length n
+fn hash_message<const C: u8, const D: u8, T: HashOutput>(key: &[u8; 16], n: usize) -> T::Bytes {
+    let mut siphash = match SipHash::<C, D, T>::new(key) {
+        Ok(siphash) => siphash,
+        Err(_) => unreachable!("a 16-byte key is always valid"),
+    };
+
+    (0..n / PATTERN.len()).for_each(|_| siphash.update(&PATTERN));
+    siphash.update(&PATTERN[..n % PATTERN.len()]);
+
+    T::to_le_bytes(siphash.finalize())
+}
+
+// hash of the message given as hexadecimal digits, which must be length bytes long
+fn hash_hex<const C: u8, const D: u8, T: HashOutput>(
+    key: &[u8; 16],
+    hex: &str,
+    length: usize,
+) -> Option<T::Bytes> {
+    if hex.len() != 2 * length {
+        return None;
+    }
+
+    let mut siphash = SipHash::<C, D, T>::new(key).ok()?;
+
+    // decode by blocks to avoid any allocation
+    let mut buffer = [0u8; 64];
+    for digits in hex.as_bytes().chunks(2 * buffer.len()) {
+        let bytes = &mut buffer[..digits.len() / 2];
+        decode_hex(core::str::from_utf8(digits).ok()?, bytes)?;
+        siphash.update(bytes);
+    }
+
+    Some(T::to_le_bytes(siphash.finalize()))
+}
+
+fn write_message<W: fmt::Write>(out: &mut W, n: usize) -> fmt::Result {
+    (0..n / PATTERN.len()).try_for_each(|_| write_hex(out, &PATTERN))?;
+    write_hex(out, &PATTERN[..n % PATTERN.len()])
+}
+
+// 1-based line number of the byte at offset pos
+fn line_of(input: &str, pos: usize) -> usize {
+    input.as_bytes()[..pos.min(input.len())]
+        .iter()
+        .filter(|b| **b == b'\n')
+        .count()
+        + 1
+}
+
+// the input from pos, which may be past its end or inside a multi-byte character
+fn rest(input: &str, pos: usize) -> Result<&str, SipError> {
+    input
+        .get(pos..)
+        .ok_or(SipError::InvalidVectors(line_of(input, pos)))
+}
+
+fn skip_whitespace(input: &str, pos: usize) -> usize {
+    match input.get(pos..) {
+        Some(rest) => pos + rest.len() - rest.trim_start().len(),
+        None => pos,
+    }
+}
+
+// decimal number starting at pos, and the position following it
+fn number(input: &str, pos: usize) -> Result<(usize, usize), SipError> {
+    let rest = rest(input, pos)?;
+    let digits = rest.bytes().take_while(|b| b.is_ascii_digit()).count();
+
+    rest[..digits]
+        .parse()
+        .map(|n| (n, pos + digits))
+        .map_err(|_| SipError::InvalidVectors(line_of(input, pos)))
+}
+
+fn to_u8(input: &str, pos: usize, n: usize) -> Result<u8, SipError> {
+    u8::try_from(n).map_err(|_| SipError::InvalidVectors(line_of(input, pos)))
+}
+
+fn to_bits(input: &str, pos: usize, n: usize) -> Result<u16, SipError> {
+    match n {
+        64 | 128 => Ok(n as u16),
+        _ => Err(SipError::InvalidVectors(line_of(input, pos))),
+    }
+}
+
+fn hex_key(input: &str, pos: usize, s: &str) -> Result<[u8; 16], SipError> {
+    let mut key = [0u8; 16];
+    s.get(..32)
+        .and_then(|s| decode_hex(s, &mut key))
+        .map(|_| key)
+        .ok_or(SipError::InvalidVectors(line_of(input, pos)))
+}
+
+fn hex_hash<T: HashOutput>(input: &str, pos: usize, s: &str) -> Result<T::Bytes, SipError> {
+    let mut hash = T::Bytes::default();
+    decode_hex(s, hash.as_mut())
+        .map(|_| hash)
+        .ok_or(SipError::InvalidVectors(line_of(input, pos)))
+}
+
+// raw value of the "name" field in a JSON object: the content of a string or a number, and its position
+fn json_field<'a>(object: &'a str, name: &str) -> Option<(&'a str, usize)> {
+    let mut start = 0;
+
+    // look for "name" followed by a colon
+    let pos = loop {
+        let pos = start + object[start..].find(name)?;
+        let after = pos + name.len();
+        if pos > 0
+            && object.as_bytes()[pos - 1] == b'"'
+            && object[after..].starts_with('"')
+            && object[after + 1..].trim_start().starts_with(':')
+        {
+            break after + 1;
+        }
+        start = after;
+    };
+
+    let pos = pos + object[pos..].find(':')? + 1;
+    let pos = skip_whitespace(object, pos);
+
+    if let Some(value) = object[pos..].strip_prefix('"') {
+        let len = value.find('"')?;
+        Some((&value[..len], pos + 1))
+    } else {
+        let len = object[pos..]
+            .bytes()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        Some((&object[pos..pos + len], pos))
+    }
+}
+
+fn json_number(input: &str, pos: usize, s: &str) -> Result<usize, SipError> {
+    s.parse()
+        .map_err(|_| SipError::InvalidVectors(line_of(input, pos)))
+}
+
+// all the non empty lines except the header, with their line number
+fn csv_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with("c,"))
+}
+
+struct CsvRow<'a> {
+    parameters: Parameters,
+    length: usize,
+    message: &'a str,
+    hash: &'a str,
+}
+
+impl<'a> CsvRow<'a> {
+    fn parse(line_number: usize, line: &'a str) -> Result<Self, SipError> {
+        let invalid = SipError::InvalidVectors(line_number);
+
+        let mut fields = line.split(',');
+        let next = &mut || fields.next().map(str::trim).ok_or(invalid);
+
+        let c = next()?.parse().map_err(|_| invalid)?;
+        let d = next()?.parse().map_err(|_| invalid)?;
+        let bits = match next()? {
+            "64" => 64,
+            "128" => 128,
+            _ => return Err(invalid),
+        };
+        let mut key = [0u8; 16];
+        decode_hex(next()?, &mut key).ok_or(invalid)?;
+        let length = next()?.parse().map_err(|_| invalid)?;
+        let message = next()?;
+        let hash = next()?;
+
+        Ok(Self {
+            parameters: Parameters { c, d, bits, key },
+            length,
+            message,
+            hash,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        vectors::{SIPHASH_2_4_128, SIPHASH_2_4_64},
+        Hash128, Hash64,
+    };
+
+    // no String in no_std
+    struct Buffer {
+        data: [u8; 65536],
+        len: usize,
+    }
+
+    impl Buffer {
+        fn new() -> Self {
+            Self {
+                data: [0; 65536],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.data[..self.len]).unwrap()
+        }
+    }
+
+    impl fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.data[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_c_header() {
+        let mut buf = Buffer::new();
+        generate::<2, 4, Hash64, _>(&mut buf, Format::CHeader, &KEY, 0..64).unwrap();
+        let header = buf.as_str();
+
+        assert!(header.contains("const uint8_t vectors_sip64[64][8] = {"));
+        assert!(
+            header.contains("    {\n        0x31,\n        0x0e,\n        0x0e,\n        0xdd,")
+        );
+
+        let parameters = parameters(header, Format::CHeader).unwrap();
+        assert_eq!(
+            parameters,
+            Parameters {
+                c: 2,
+                d: 4,
+                bits: 64,
+                key: KEY
+            }
+        );
+        assert_eq!(verify::<2, 4, Hash64>(header, Format::CHeader).unwrap(), 64);
+    }
+
+    // the bytes of the generated C header array, in order
+    fn header_bytes(header: &str) -> impl Iterator<Item = u8> + '_ {
+        let array = &header[header.find("= {").unwrap()..];
+        array
+            .split("0x")
+            .skip(1)
+            .map(|digits| u8::from_str_radix(&digits[..2], 16).unwrap())
+    }
+
+    // the hash values of the generated CSV lines
+    fn csv_hashes<const N: usize>(csv: &str) -> impl Iterator<Item = [u8; N]> + '_ {
+        csv.lines().skip(1).map(|line| {
+            let mut bytes = [0u8; N];
+            decode_hex(line.rsplit(',').next().unwrap(), &mut bytes).unwrap();
+            bytes
+        })
+    }
+
+    #[test]
+    fn test_reference_values() {
+        // every generated vector is the one of the reference vectors.h
+        let mut buf = Buffer::new();
+        generate::<2, 4, Hash64, _>(&mut buf, Format::CHeader, &KEY, 0..64).unwrap();
+        let expected = SIPHASH_2_4_64.iter().flat_map(|v| v.to_le_bytes());
+        assert!(header_bytes(buf.as_str()).eq(expected));
+
+        let mut buf = Buffer::new();
+        generate::<2, 4, Hash128, _>(&mut buf, Format::CHeader, &KEY, 0..64).unwrap();
+        let expected = SIPHASH_2_4_128.iter().flatten().copied();
+        assert!(header_bytes(buf.as_str()).eq(expected));
+
+        let mut buf = Buffer::new();
+        generate::<2, 4, Hash64, _>(&mut buf, Format::Csv, &KEY, 0..64).unwrap();
+        let expected = SIPHASH_2_4_64.iter().map(|v| v.to_le_bytes());
+        assert!(csv_hashes::<8>(buf.as_str()).eq(expected));
+
+        let mut buf = Buffer::new();
+        generate::<2, 4, Hash128, _>(&mut buf, Format::Csv, &KEY, 0..64).unwrap();
+        assert!(csv_hashes::<16>(buf.as_str()).eq(SIPHASH_2_4_128.iter().copied()));
+    }
+
+    #[test]
+    fn test_reference_header() {
+        // the include and the vectors_sip64 array of the reference vectors.h, which has no leading comment
+        let header = include_str!("../testdata/vectors.h");
+
+        let mut buf = Buffer::new();
+        generate::<2, 4, Hash64, _>(&mut buf, Format::CHeader, &KEY, 0..64).unwrap();
+        assert_eq!(buf.as_str(), header);
+
+        assert_eq!(verify::<2, 4, Hash64>(header, Format::CHeader).unwrap(), 64);
+        assert!(matches!(
+            verify::<1, 3, Hash64>(header, Format::CHeader),
+            Err(SipError::ParametersMismatch {
+                c: 2,
+                d: 4,
+                bits: 64
+            })
+        ));
+    }
+
+    #[test]
+    fn test_header_comment() {
+        // the parameters which aren't the reference ones are given in the comment
+        let mut buf = Buffer::new();
+        generate::<4, 8, Hash128, _>(&mut buf, Format::CHeader, &KEY, 10..20).unwrap();
+        let header = buf.as_str();
+
+        assert!(header.starts_with("/* siphash_4_8 128-bit test vectors\n * key: 000102030405060708090a0b0c0d0e0f\n * first length: 10\n */\n#include <stdint.h>\n"));
+        assert_eq!(
+            verify::<4, 8, Hash128>(header, Format::CHeader).unwrap(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let mut buf = Buffer::new();
+        generate::<4, 8, Hash128, _>(&mut buf, Format::Json, &KEY, 250..260).unwrap();
+        let json = buf.as_str();
+
+        assert!(json.starts_with("{\n  \"c\": 4,\n  \"d\": 8,\n  \"bits\": 128,\n"));
+        assert_eq!(verify::<4, 8, Hash128>(json, Format::Json).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut buf = Buffer::new();
+        generate::<2, 4, Hash64, _>(&mut buf, Format::Csv, &KEY, 0..3).unwrap();
+        let csv = buf.as_str();
+
+        let mut lines = csv.lines();
+        assert_eq!(lines.next().unwrap(), "c,d,bits,key,length,message,hash");
+        assert_eq!(
+            lines.nth(2).unwrap(),
+            "2,4,64,000102030405060708090a0b0c0d0e0f,2,0001,5a4fa9d909806c0d"
+        );
+        assert_eq!(SIPHASH_2_4_64[2].to_le_bytes()[0], 0x5a);
+
+        assert_eq!(verify::<2, 4, Hash64>(csv, Format::Csv).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let csv = "c,d,bits,key,length,message,hash\n2,4,64,000102030405060708090a0b0c0d0e0f,1,00,fd67dc93c539f874\n2,4,64,000102030405060708090a0b0c0d0e0f,1,01,fd67dc93c539f874\n";
+        assert!(matches!(
+            verify::<2, 4, Hash64>(csv, Format::Csv),
+            Err(SipError::VectorMismatch(1))
+        ));
+
+        let csv = "2,4,64,000102030405060708090a0b0c0d0e0f,2,00,fd67dc93c539f874\n";
+        assert!(matches!(
+            verify::<2, 4, Hash64>(csv, Format::Csv),
+            Err(SipError::InvalidVectors(1))
+        ));
+    }
+
+    #[test]
+    fn test_truncated() {
+        // truncated or non-ASCII input is an error, not a panic
+        for header in [
+            "siphash_2",
+            "/* siphash_2_",
+            "/* siphash_2é4 */",
+            "/* siphash_2_4\n * key:",
+            "/* siphash_2_4\n * key: é",
+            "/* siphash_2_4 */\nconst uint8_t vectors_sip",
+            "/* siphash_2_4 */\nconst uint8_t vectors_sipé",
+        ] {
+            assert!(matches!(
+                parameters(header, Format::CHeader),
+                Err(SipError::InvalidVectors(_))
+            ));
+        }
+
+        let header =
+            "/* siphash_2_4\n * first length: é\n */\nconst uint8_t vectors_sip64[1][8] = {\n};\n";
+        assert!(matches!(
+            verify::<2, 4, Hash64>(header, Format::CHeader),
+            Err(SipError::InvalidVectors(2))
+        ));
+    }
+}
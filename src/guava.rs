@@ -0,0 +1,239 @@
+//! A hasher compatible with Guava's `Hashing.sipHash24()`, so that hash values calculated in Java can be checked.
+//!
+//! The primitive values are added as in Guava's `PrimitiveSink`: integers and floats as little-endian bytes,
+//! chars as UTF-16LE code units, and strings either as UTF-8 bytes or as unencoded chars. Only the hash values of
+//! bytes and strings are checked against the ones of Guava's tests: the other methods are checked against the
+//! encoding documented by `PrimitiveSink`.
+//!
+//! ```rust
+//! use siphash_c_d::guava::GuavaHasher;
+//!
+//! // Hashing.sipHash24(k0, k1).newHasher().putInt(42).putString("id", UTF_8).hash().asLong()
+//! let hash = GuavaHasher::new(0x0706050403020100, 0x0f0e0d0c0b0a0908)
+//!     .put_int(42)
+//!     .put_string("id")
+//!     .hash()
+//!     .as_long();
+//! ```
+use core::fmt;
+
+use crate::{hex, Digest64, Hash64, SipHash, SipHashKey};
+
+/// The key of Guava's `Hashing.sipHash24()` function, when no key is given.
+pub const DEFAULT_KEY: (u64, u64) = (0x0706050403020100, 0x0f0e0d0c0b0a0908);
+
+/// The equivalent of the `Hasher` returned by `Hashing.sipHash24(k0, k1).newHasher()`.
+#[derive(Clone)]
+pub struct GuavaHasher(SipHash<2, 4, Hash64>);
+
+impl Default for GuavaHasher {
+    /// The hasher of `Hashing.sipHash24()`, using [`DEFAULT_KEY`].
+    fn default() -> Self {
+        Self::new(DEFAULT_KEY.0, DEFAULT_KEY.1)
+    }
+}
+
+impl GuavaHasher {
+    /// The hasher of `Hashing.sipHash24(k0, k1)`.
+    pub fn new(k0: u64, k1: u64) -> Self {
+        Self(SipHash::from_key(SipHashKey::new(k0, k1)))
+    }
+
+    /// `putByte(byte)`.
+    pub fn put_byte(&mut self, b: i8) -> &mut Self {
+        self.put_bytes(&b.to_le_bytes())
+    }
+
+    /// `putBytes(byte[])`.
+    pub fn put_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.0.update(bytes);
+        self
+    }
+
+    /// `putShort(short)`.
+    pub fn put_short(&mut self, s: i16) -> &mut Self {
+        self.put_bytes(&s.to_le_bytes())
+    }
+
+    /// `putInt(int)`.
+    pub fn put_int(&mut self, i: i32) -> &mut Self {
+        self.put_bytes(&i.to_le_bytes())
+    }
+
+    /// `putLong(long)`.
+    pub fn put_long(&mut self, l: i64) -> &mut Self {
+        self.put_bytes(&l.to_le_bytes())
+    }
+
+    /// `putFloat(float)`, using the raw bits of the value.
+    pub fn put_float(&mut self, f: f32) -> &mut Self {
+        self.put_bytes(&f.to_bits().to_le_bytes())
+    }
+
+    /// `putDouble(double)`, using the raw bits of the value.
+    pub fn put_double(&mut self, d: f64) -> &mut Self {
+        self.put_bytes(&d.to_bits().to_le_bytes())
+    }
+
+    /// `putBoolean(boolean)`, as a 1 or 0 byte.
+    pub fn put_boolean(&mut self, b: bool) -> &mut Self {
+        self.put_bytes(&[b as u8])
+    }
+
+    /// `putChar(char)`, the Java char being a UTF-16 code unit.
+    pub fn put_char(&mut self, c: u16) -> &mut Self {
+        self.put_bytes(&c.to_le_bytes())
+    }
+
+    /// `putUnencodedChars(CharSequence)`: the UTF-16 code units of `s`.
+    pub fn put_unencoded_chars(&mut self, s: &str) -> &mut Self {
+        s.encode_utf16().for_each(|c| {
+            self.put_char(c);
+        });
+        self
+    }
+
+    /// `putString(CharSequence, UTF_8)`: the UTF-8 bytes of `s`.
+    pub fn put_string(&mut self, s: &str) -> &mut Self {
+        self.put_bytes(s.as_bytes())
+    }
+
+    /// `hash()`: the hash code of the values added so far.
+    pub fn hash(&self) -> HashCode {
+        HashCode(self.0.finalize())
+    }
+}
+
+/// The equivalent of the 64-bit `HashCode` returned by Guava.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HashCode(u64);
+
+impl HashCode {
+    /// `asLong()`.
+    pub fn as_long(&self) -> i64 {
+        self.0 as i64
+    }
+
+    /// `asInt()`: the first 4 bytes of the hash code.
+    pub fn as_int(&self) -> i32 {
+        self.0 as i32
+    }
+
+    /// `padToLong()`, which is the same as `asLong()` for a 64-bit hash code.
+    pub fn pad_to_long(&self) -> i64 {
+        self.as_long()
+    }
+
+    /// `asBytes()`: the little-endian bytes of the hash value.
+    pub fn as_bytes(&self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    /// `bits()`.
+    pub fn bits(&self) -> u16 {
+        64
+    }
+}
+
+impl From<HashCode> for Digest64 {
+    fn from(h: HashCode) -> Self {
+        Digest64::new(h.0)
+    }
+}
+
+/// `toString()`: the lowercase hexadecimal digits of `asBytes()`.
+impl fmt::Display for HashCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        hex::write_hex(f, &self.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SipHash24;
+
+    // hashString(input, UTF_8).asLong(), from Guava's SipHashFunctionTest
+    fn assert_sip(input: &str, expected: u64) {
+        let h = GuavaHasher::default().put_string(input).hash();
+        assert_eq!(h.as_long(), expected as i64);
+    }
+
+    #[test]
+    fn test_known_values() {
+        assert_sip("", 0x726fdb47dd0e0e31);
+        assert_sip("a", 0x2ba3e8e9a71148ca);
+        assert_sip("12345678", 0x02130609caea37eb);
+        assert_sip("abcdef", 0x2a6e77e733c7c05d);
+        assert_sip("SipHash", 0x8325093242a96f60);
+
+        let h = GuavaHasher::default().put_bytes(&[0; 1000000]).hash();
+        assert_eq!(h.as_long(), 0x28205108397aa742);
+    }
+
+    #[test]
+    fn test_15_byte_string_from_siphash_paper() {
+        let msg = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+        let h = GuavaHasher::new(0x0706050403020100, 0x0f0e0d0c0b0a0908)
+            .put_bytes(&msg)
+            .hash();
+        assert_eq!(h.as_long(), 0xa129ca6149be45e5_u64 as i64);
+        assert_eq!(
+            h.as_bytes(),
+            [0xe5, 0x45, 0xbe, 0x49, 0x61, 0xca, 0x29, 0xa1]
+        );
+        assert_eq!(h.as_int(), 0x49be45e5);
+    }
+
+    // the hash of the bytes written by the PrimitiveSink methods
+    fn assert_bytes(hasher: &GuavaHasher, bytes: &[u8]) {
+        let expected = SipHash24::with_key(DEFAULT_KEY, bytes).unwrap();
+        assert_eq!(Digest64::from(hasher.hash()), Digest64::new(expected));
+    }
+
+    #[test]
+    fn test_primitives() {
+        let mut hasher = GuavaHasher::default();
+        hasher
+            .put_int(-2)
+            .put_long(0x0102030405060708)
+            .put_short(0x1234)
+            .put_boolean(true)
+            .put_byte(-1);
+        assert_bytes(
+            &hasher,
+            &[
+                0xfe, 0xff, 0xff, 0xff, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x34, 0x12,
+                0x01, 0xff,
+            ],
+        );
+
+        let mut hasher = GuavaHasher::default();
+        hasher.put_float(1.0).put_double(-2.0);
+        assert_bytes(
+            &hasher,
+            &[
+                0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_unencoded_chars() {
+        let mut hasher = GuavaHasher::default();
+        hasher.put_unencoded_chars("hé€");
+        assert_bytes(&hasher, &[0x68, 0x00, 0xe9, 0x00, 0xac, 0x20]);
+
+        // not the same as the UTF-8 bytes
+        let h = GuavaHasher::default().put_string("hé€").hash();
+        assert_ne!(h, hasher.hash());
+
+        // surrogate pairs are 2 chars
+        let h1 = GuavaHasher::default().put_unencoded_chars("😀").hash();
+        let h2 = GuavaHasher::default()
+            .put_char(0xd83d)
+            .put_char(0xde00)
+            .hash();
+        assert_eq!(h1, h2);
+    }
+}
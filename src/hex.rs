@@ -0,0 +1,81 @@
+// lowercase hexadecimal conversions, as used in the outputs of the reference implementation
+use core::fmt;
+
+const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+// write the bytes as lowercase hexadecimal digits
+pub(crate) fn write_hex<W: fmt::Write + ?Sized>(w: &mut W, bytes: &[u8]) -> fmt::Result {
+    for b in bytes {
+        w.write_char(DIGITS[(b >> 4) as usize] as char)?;
+        w.write_char(DIGITS[(b & 0x0F) as usize] as char)?;
+    }
+    Ok(())
+}
+
+// write the bytes as lowercase hexadecimal digits into out, which needs to be exactly twice the length of bytes
+#[cfg(feature = "serde")]
+pub(crate) fn encode_hex<'a>(bytes: &[u8], out: &'a mut [u8]) -> &'a str {
+    debug_assert_eq!(out.len(), 2 * bytes.len());
+
+    for (pair, b) in out.chunks_exact_mut(2).zip(bytes) {
+        pair[0] = DIGITS[(b >> 4) as usize];
+        pair[1] = DIGITS[(b & 0x0F) as usize];
+    }
+    core::str::from_utf8(out).expect("hexadecimal digits are ASCII")
+}
+
+// value of a single hexadecimal digit, either lowercase or uppercase
+pub(crate) fn digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+// decode the hexadecimal string s into out, which needs to be exactly half the length of s
+pub(crate) fn decode_hex(s: &str, out: &mut [u8]) -> Option<()> {
+    let s = s.as_bytes();
+    if s.len() != 2 * out.len() {
+        return None;
+    }
+
+    for (b, pair) in out.iter_mut().zip(s.chunks_exact(2)) {
+        *b = digit(pair[0])? << 4 | digit(pair[1])?;
+    }
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // no String in no_std
+    struct Buffer([u8; 32], usize);
+
+    impl fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0[self.1..self.1 + s.len()].copy_from_slice(s.as_bytes());
+            self.1 += s.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_hex() {
+        let mut buf = Buffer([0; 32], 0);
+        write_hex(&mut buf, &[0x00, 0x0f, 0xa1, 0xff]).unwrap();
+        assert_eq!(&buf.0[..buf.1], b"000fa1ff");
+    }
+
+    #[test]
+    fn test_decode_hex() {
+        let mut out = [0u8; 4];
+        assert!(decode_hex("000fA1ff", &mut out).is_some());
+        assert_eq!(out, [0x00, 0x0f, 0xa1, 0xff]);
+
+        assert!(decode_hex("000fa1f", &mut out).is_none());
+        assert!(decode_hex("000fa1fg", &mut out).is_none());
+    }
+}
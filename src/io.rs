@@ -0,0 +1,76 @@
+//! `std::io` support, enabled by the `std` feature: hashing of `IoSlice` buffers.
+//!
+//! `SipHash` doesn't implement `std::io::Write`, whose `write` method would be ambiguous with the one of `Hasher`.
+use std::io::IoSlice;
+
+use crate::{HashOutput, SipError, SipHash, SipHashKey};
+
+impl<const C: u8, const D: u8, T: HashOutput> SipHash<C, D, T> {
+    /// Calculate the `siphash_c_d` value of the concatenation of the buffers `bufs` using the key `key`, without
+    /// copying them.
+    ///
+    /// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
+    ///
+    /// ```rust
+    /// use std::io::IoSlice;
+    /// use siphash_c_d::SipHash24;
+    ///
+    /// let key = (0x0706050403020100, 0x0f0e0d0c0b0a0908);
+    /// let bufs = [IoSlice::new(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]), IoSlice::new(&[10, 11, 12, 13, 14])];
+    ///
+    /// assert_eq!(SipHash24::with_key_io_slices(key, &bufs).unwrap(), 0xa129ca6149be45e5);
+    /// ```
+    pub fn with_key_io_slices<K>(key: K, bufs: &[IoSlice<'_>]) -> Result<T::Value, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        let mut siphash = Self::new(key)?;
+        siphash.update_io_slices(bufs);
+        Ok(siphash.finalize())
+    }
+
+    /// Add the concatenation of the buffers `bufs` to the message being hashed.
+    pub fn update_io_slices(&mut self, bufs: &[IoSlice<'_>]) {
+        for buf in bufs {
+            self.update(buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::{vectors, Hash128, SipHash24};
+
+    #[test]
+    fn test_io_slices() {
+        let msg: std::vec::Vec<u8> = (0..64).collect();
+
+        for split in [0, 3, 8, 13, 62, 63] {
+            let bufs = [IoSlice::new(&msg[..split]), IoSlice::new(&msg[split..63])];
+            assert_eq!(
+                SipHash24::with_key_io_slices(&vectors::KEY, &bufs).unwrap(),
+                vectors::SIPHASH_2_4_64[63]
+            );
+            assert_eq!(
+                SipHash::<2, 4, Hash128>::with_key_io_slices(&vectors::KEY, &bufs)
+                    .unwrap()
+                    .to_le_bytes(),
+                vectors::SIPHASH_2_4_128[63]
+            );
+        }
+    }
+
+    #[test]
+    fn test_hasher_write() {
+        use core::hash::Hasher;
+
+        // no ambiguity with io::Write
+        let msg: std::vec::Vec<u8> = (0..64).collect();
+        let mut siphash = SipHash24::new(&vectors::KEY).unwrap();
+        siphash.write(&msg[..50]);
+        assert_eq!(siphash.finish(), vectors::SIPHASH_2_4_64[50]);
+    }
+}
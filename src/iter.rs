@@ -56,6 +56,7 @@ pub(crate) fn slice_to_u64(s: &[u8]) -> u64 {
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow)]
 mod tests {
     use super::*;
 
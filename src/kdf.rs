@@ -0,0 +1,122 @@
+//! Derivation of independent keys from a master key, so that only one secret needs to be protected and rotated.
+//!
+//! A derived key is the 128-bit `SipHash<C, D, Hash128>` value, under the master key, of the fixed encoding:
+//!
+//! * the 16 bytes `siphash_c_d kdf` followed by the version byte `1`;
+//! * the `u64` little-endian length of the label and its UTF-8 bytes;
+//! * the `u64` little-endian length of the context and its bytes.
+//!
+//! Its bytes are the 16 little-endian bytes of the hash value, as given by [`SipHashKey::to_bytes`]. Since both
+//! the label and the context are prefixed by their length, different pairs can't have the same encoding: the label
+//! names the purpose of the key (like `"sessions"`), and the context the item it is used for (like a tenant id).
+//!
+//! ```rust
+//! use siphash_c_d::{kdf::KeyDerivation, SipHash24};
+//!
+//! let kdf = KeyDerivation::<2, 4>::new(&[0u8; 16]).unwrap();
+//! let key = kdf.derive("sessions", b"tenant-42");
+//!
+//! let hash = SipHash24::with_key(&key, b"session id").unwrap();
+//! assert_ne!(key, kdf.derive("sessions", b"tenant-43"));
+//! ```
+use crate::{Hash128, SipError, SipHash, SipHashKey};
+
+// the first 16 bytes of the encoding, separating the derivations from the other uses of the master key
+const PREFIX: &[u8; 16] = b"siphash_c_d kdf\x01";
+
+/// The derivation of keys from a master key.
+#[derive(Clone)]
+pub struct KeyDerivation<const C: u8, const D: u8> {
+    master: SipHashKey,
+}
+
+impl<const C: u8, const D: u8> KeyDerivation<C, D> {
+    /// The derivation from the master key `master`.
+    ///
+    /// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
+    pub fn new<K>(master: K) -> Result<Self, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        Ok(Self {
+            master: master.try_into()?,
+        })
+    }
+
+    /// The key for the purpose `label` and the context `context`.
+    pub fn derive(&self, label: &str, context: &[u8]) -> SipHashKey {
+        let mut siphash = SipHash::<C, D, Hash128>::from_key(self.master);
+        siphash.update(PREFIX);
+        siphash.update(&(label.len() as u64).to_le_bytes());
+        siphash.update(label.as_bytes());
+        siphash.update(&(context.len() as u64).to_le_bytes());
+        siphash.update(context);
+
+        let value = siphash.finalize();
+        SipHashKey::new(value as u64, (value >> 64) as u64)
+    }
+}
+
+/// The key derived from the master key `master` for the purpose `label` and the context `context`.
+///
+/// If the length of the master key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
+pub fn derive_key<const C: u8, const D: u8, K>(
+    master: K,
+    label: &str,
+    context: &[u8],
+) -> Result<SipHashKey, SipError>
+where
+    K: TryInto<SipHashKey, Error = SipError>,
+{
+    Ok(KeyDerivation::<C, D>::new(master)?.derive(label, context))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::KEY;
+
+    #[test]
+    fn test_derive() {
+        // SipHash-2-4-128 of PREFIX and the length-prefixed label and context, computed independently
+        let kdf = KeyDerivation::<2, 4>::new(&KEY).unwrap();
+        assert_eq!(
+            kdf.derive("table", b"tenant-42").to_bytes(),
+            [
+                0x82, 0x66, 0x4a, 0xa4, 0x98, 0x9e, 0x72, 0x75, 0x36, 0x66, 0x96, 0xc3, 0xab, 0x80,
+                0xd6, 0x00
+            ]
+        );
+        assert_eq!(
+            kdf.derive("", b"").to_bytes(),
+            [
+                0xf8, 0x6d, 0xe7, 0x29, 0x3e, 0xd9, 0x08, 0xae, 0x95, 0xcb, 0x7a, 0x74, 0x23, 0xfa,
+                0xd2, 0x71
+            ]
+        );
+        assert_eq!(
+            derive_key::<2, 4, _>(&KEY, "table", b"tenant-42").unwrap(),
+            kdf.derive("table", b"tenant-42")
+        );
+        assert!(derive_key::<2, 4, _>(&KEY[..8], "table", b"").is_err());
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        let kdf = KeyDerivation::<2, 4>::new(&KEY).unwrap();
+        let key = kdf.derive("ab", b"c");
+
+        assert_ne!(key, kdf.derive("a", b"bc"));
+        assert_ne!(key, kdf.derive("ab", b"c\0"));
+        assert_ne!(
+            key,
+            KeyDerivation::<1, 3>::new(&KEY).unwrap().derive("ab", b"c")
+        );
+        assert_ne!(
+            key,
+            KeyDerivation::<2, 4>::new((1, 2))
+                .unwrap()
+                .derive("ab", b"c")
+        );
+    }
+}
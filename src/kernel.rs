@@ -0,0 +1,183 @@
+//! The fixed-word functions of the Linux kernel (`include/linux/siphash.h`), giving bit-identical results.
+//!
+//! The words are hashed as their little-endian bytes, directly compressed without any buffering:
+//!
+//! * `siphash_*` functions are `siphash_2_4`;
+//! * `hsiphash_*` functions are the 64-bit kernel flavor, which is `siphash_1_3` truncated to 32 bits.
+//!   32-bit kernels are using HalfSipHash instead, which is not provided.
+//!
+//! The key is made of the 2 `u64` values of the kernel `siphash_key_t` type.
+//!
+//! ```rust
+//! use siphash_c_d::{kernel, SipHashKey};
+//!
+//! let key = SipHashKey::new(0x0706050403020100, 0x0f0e0d0c0b0a0908);
+//!
+//! assert_eq!(kernel::siphash_1u64(0x0706050403020100, &key), 0x93f5f5799a932462);
+//! assert_eq!(kernel::siphash_2u32(0x03020100, 0x07060504, &key), 0x93f5f5799a932462);
+//! ```
+use crate::{siphashkey::SipHashKey, state::State};
+
+// hash the full blocks and the last block made of the tail bytes and of the total length
+fn words<const C: u8, const D: u8>(
+    key: &SipHashKey,
+    blocks: &[u64],
+    tail: u64,
+    length: u64,
+) -> u64 {
+    let mut state = State::<C, D>::new(key.0, key.1);
+
+    for m_i in blocks {
+        state.compress_chunk(*m_i);
+    }
+    state.compress_chunk(length << 56 | tail);
+
+    state.finalization(2, 0xFF, &mut ())
+}
+
+// 2 u32 values forming a block
+fn combine(first: u32, second: u32) -> u64 {
+    (second as u64) << 32 | first as u64
+}
+
+/// `siphash_2_4` of 1 `u64` value.
+pub fn siphash_1u64(first: u64, key: &SipHashKey) -> u64 {
+    words::<2, 4>(key, &[first], 0, 8)
+}
+
+/// `siphash_2_4` of 2 `u64` values.
+pub fn siphash_2u64(first: u64, second: u64, key: &SipHashKey) -> u64 {
+    words::<2, 4>(key, &[first, second], 0, 16)
+}
+
+/// `siphash_2_4` of 3 `u64` values.
+pub fn siphash_3u64(first: u64, second: u64, third: u64, key: &SipHashKey) -> u64 {
+    words::<2, 4>(key, &[first, second, third], 0, 24)
+}
+
+/// `siphash_2_4` of 4 `u64` values.
+pub fn siphash_4u64(first: u64, second: u64, third: u64, forth: u64, key: &SipHashKey) -> u64 {
+    words::<2, 4>(key, &[first, second, third, forth], 0, 32)
+}
+
+/// `siphash_2_4` of 1 `u32` value.
+pub fn siphash_1u32(first: u32, key: &SipHashKey) -> u64 {
+    words::<2, 4>(key, &[], first as u64, 4)
+}
+
+/// `siphash_2_4` of 2 `u32` values.
+pub fn siphash_2u32(first: u32, second: u32, key: &SipHashKey) -> u64 {
+    siphash_1u64(combine(first, second), key)
+}
+
+/// `siphash_2_4` of 3 `u32` values.
+pub fn siphash_3u32(first: u32, second: u32, third: u32, key: &SipHashKey) -> u64 {
+    words::<2, 4>(key, &[combine(first, second)], third as u64, 12)
+}
+
+/// `siphash_2_4` of 4 `u32` values.
+pub fn siphash_4u32(first: u32, second: u32, third: u32, forth: u32, key: &SipHashKey) -> u64 {
+    siphash_2u64(combine(first, second), combine(third, forth), key)
+}
+
+/// `hsiphash` of 1 `u32` value.
+pub fn hsiphash_1u32(first: u32, key: &SipHashKey) -> u32 {
+    words::<1, 3>(key, &[], first as u64, 4) as u32
+}
+
+/// `hsiphash` of 2 `u32` values.
+pub fn hsiphash_2u32(first: u32, second: u32, key: &SipHashKey) -> u32 {
+    words::<1, 3>(key, &[combine(first, second)], 0, 8) as u32
+}
+
+/// `hsiphash` of 3 `u32` values.
+pub fn hsiphash_3u32(first: u32, second: u32, third: u32, key: &SipHashKey) -> u32 {
+    words::<1, 3>(key, &[combine(first, second)], third as u64, 12) as u32
+}
+
+/// `hsiphash` of 4 `u32` values.
+pub fn hsiphash_4u32(first: u32, second: u32, third: u32, forth: u32, key: &SipHashKey) -> u32 {
+    words::<1, 3>(key, &[combine(first, second), combine(third, forth)], 0, 16) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::SIPHASH_2_4_64;
+
+    // taken from lib/siphash_kunit.c
+    const KEY: SipHashKey = SipHashKey::new(0x0706050403020100, 0x0f0e0d0c0b0a0908);
+
+    // the first values of test_vectors_hsiphash for 64-bit kernels, taken from lib/siphash_kunit.c
+    const HSIPHASH: [u32; 17] = [
+        0x050fc4dc, 0x7d57ca93, 0x4dc7d44d, 0xe7ddf7fb, 0x88d38328, 0x49533b67, 0xc59f22a7,
+        0x9bb11140, 0x8d299a8e, 0x6c063de4, 0x92ff097f, 0xf94dc352, 0x57b4d9a2, 0x1229ffa7,
+        0xc0f95d34, 0x2a519956, 0x7d908b66,
+    ];
+
+    #[test]
+    fn test_siphash_u64() {
+        assert_eq!(siphash_1u64(0x0706050403020100, &KEY), SIPHASH_2_4_64[8]);
+        assert_eq!(
+            siphash_2u64(0x0706050403020100, 0x0f0e0d0c0b0a0908, &KEY),
+            SIPHASH_2_4_64[16]
+        );
+        assert_eq!(
+            siphash_3u64(
+                0x0706050403020100,
+                0x0f0e0d0c0b0a0908,
+                0x1716151413121110,
+                &KEY
+            ),
+            SIPHASH_2_4_64[24]
+        );
+        assert_eq!(
+            siphash_4u64(
+                0x0706050403020100,
+                0x0f0e0d0c0b0a0908,
+                0x1716151413121110,
+                0x1f1e1d1c1b1a1918,
+                &KEY
+            ),
+            SIPHASH_2_4_64[32]
+        );
+    }
+
+    #[test]
+    fn test_siphash_u32() {
+        assert_eq!(siphash_1u32(0x03020100, &KEY), SIPHASH_2_4_64[4]);
+        assert_eq!(
+            siphash_2u32(0x03020100, 0x07060504, &KEY),
+            SIPHASH_2_4_64[8]
+        );
+        assert_eq!(
+            siphash_3u32(0x03020100, 0x07060504, 0x0b0a0908, &KEY),
+            SIPHASH_2_4_64[12]
+        );
+        assert_eq!(
+            siphash_4u32(0x03020100, 0x07060504, 0x0b0a0908, 0x0f0e0d0c, &KEY),
+            SIPHASH_2_4_64[16]
+        );
+    }
+
+    #[test]
+    fn test_hsiphash_u32() {
+        assert_eq!(hsiphash_1u32(0x03020100, &KEY), HSIPHASH[4]);
+        assert_eq!(hsiphash_2u32(0x03020100, 0x07060504, &KEY), HSIPHASH[8]);
+        assert_eq!(
+            hsiphash_3u32(0x03020100, 0x07060504, 0x0b0a0908, &KEY),
+            HSIPHASH[12]
+        );
+        assert_eq!(
+            hsiphash_4u32(0x03020100, 0x07060504, 0x0b0a0908, 0x0f0e0d0c, &KEY),
+            HSIPHASH[16]
+        );
+    }
+
+    #[test]
+    fn test_hsiphash_is_siphash_1_3() {
+        for (i, h) in HSIPHASH.iter().enumerate() {
+            assert_eq!(crate::vectors::SIPHASH_1_3_64[i] as u32, *h);
+        }
+    }
+}
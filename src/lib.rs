@@ -6,7 +6,8 @@
 //!
 //! SipHash was invented by Jean-Philippe Aumasson and Daniel J. Bernstein.
 //!
-//! Using the [`Hash64`] or [`Hash128`] keyword, you can get the `u64` or `u128` bits hash value.
+//! Using the [`Hash64`] or [`Hash128`] keyword, you can get the `u64` or `u128` bits hash value, or a typed
+//! [`Digest64`] or [`Digest128`] value using [`SipHash::digest`].
 //!
 //! The algorithm is made generic for the *c* and *d* `u8`integers. For the most common use, 2 types aliases are defined:
 //!
@@ -96,7 +97,35 @@
 //! let mut siphash_2_4 = SipHash24::new((0x0706050403020100, 0x0f0e0d0c0b0a0908)).unwrap();
 //!
 //! siphash_2_4.write(&[0, 1, 2, 3, 4, 5, 6, 7]);
-//! assert_eq!(siphash_2_4.finish(), 0xdd7a02a58bb1f0ab);
+//! assert_eq!(siphash_2_4.finish(), 0x93f5f5799a932462);
+//! ```
+//!
+//! # Self-test
+//!
+//! The reference test vectors are available in the [`vectors`] module. The [`self_test`] function checks
+//! the `siphash_2_4`, `siphash_1_3` and `siphash_4_8` implementations against them, without any allocation:
+//!
+//! ```rust
+//! assert!(siphash_c_d::self_test().is_ok());
+//! ```
+//!
+//! # Serde
+//!
+//! With the `serde` feature, [`SipHashKey`], [`Digest64`] and [`Digest128`] are serialized as lowercase hexadecimal
+//! strings in human-readable formats like JSON, and as bytes otherwise. A [`SipHash`] is serialized as a snapshot of
+//! its state, which can only be resumed with the same `c`, `d` and output size.
+//!
+//! The [`fingerprint`] module also hashes any `Serialize` value with a canonical encoding, the `alloc` feature being
+//! needed to sort the entries of maps.
+//!
+//! # Tools
+//!
+//! The `tools` feature adds the modules used to validate a variant, which are not needed to hash: the `generator`
+//! of test vectors to share with other implementations, the `analysis` of the diffusion and the `quality` tests in
+//! the style of SMHasher. The `siphash_vectors` binary and the examples need it:
+//!
+//! ```text
+//! cargo run --features tools --bin siphash_vectors -- generate --c 1 --d 3 --format json
 //! ```
 //!
 //!         
@@ -127,12 +156,51 @@
 //!
 
 #![no_std]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "tools")]
+pub mod analysis;
+mod any;
+pub mod compat;
+mod digest;
+#[cfg(feature = "serde")]
+pub mod fingerprint;
+#[cfg(feature = "tools")]
+pub mod generator;
+pub mod guava;
 mod hasher;
+mod hex;
+#[cfg(feature = "std")]
+mod io;
 mod iter;
+pub mod kdf;
+pub mod kernel;
+pub mod multiset;
+pub mod nocase;
+pub mod python;
+#[cfg(feature = "tools")]
+pub mod quality;
+pub mod range;
 mod residue;
+pub mod rng;
+#[cfg(feature = "serde")]
+mod serialize;
+mod sipfingerprint;
 mod siphash;
 mod siphashkey;
 mod state;
+pub mod text;
+pub mod trace;
+mod unicode_tables;
+pub mod vectors;
+
+pub use crate::any::AnySipHash;
+
+pub use crate::digest::Digest128;
+pub use crate::digest::Digest64;
 
 pub use crate::siphash::SipHash;
 pub use crate::siphash::SipHash24;
@@ -140,10 +208,93 @@ pub use crate::siphash::SipHash48;
 
 pub use crate::siphash::Hash128;
 pub use crate::siphash::Hash64;
+pub use crate::siphash::HashOutput;
+
+pub use crate::siphashkey::SipHashKey;
+
+pub use crate::sipfingerprint::SipFingerprint;
+#[cfg(feature = "derive")]
+pub use siphash_c_d_derive::SipFingerprint;
+
+pub use crate::vectors::self_test;
 
-/// The error during conversion to a 16-bit key.
-#[derive(Debug)]
+/// The errors returned by this crate, starting with the conversion to a 16-byte key. New variants can be added
+/// without a breaking change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum SipError {
     /// When the key is less thant 16 bytes, this error is returned during conversion, including the actual data length.
     KeyTooShort(usize),
+
+    /// A known-answer test of [`self_test`] failed: the `c` and `d` values, the output size in bits and
+    /// the length of the message of the failing vector.
+    SelfTestFailed {
+        c: u8,
+        d: u8,
+        bits: u16,
+        length: usize,
+    },
+
+    /// The test vectors can't be read: the line number where the error was found.
+    InvalidVectors(usize),
+
+    /// The test vectors were not computed for the expected `c`, `d` and output size: the values found in the vectors.
+    ParametersMismatch { c: u8, d: u8, bits: u16 },
+
+    /// A test vector doesn't match the calculated hash value: the length of its message.
+    VectorMismatch(usize),
+
+    /// The string is not made of the hexadecimal digits of a digest.
+    InvalidDigest,
+
+    /// The output buffer is not the size of the hash value: its actual length.
+    InvalidOutputLength(usize),
+
+    /// Two values computed with different keys can't be combined.
+    KeyMismatch,
+
+    /// The `c` and `d` values or the output size in bits of [`AnySipHash`] are not available.
+    UnsupportedVariant { c: u8, d: u8, bits: u16 },
+}
+
+impl core::fmt::Display for SipError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SipError::KeyTooShort(length) => {
+                write!(
+                    f,
+                    "the key must be at least 16 bytes long, not {} bytes",
+                    length
+                )
+            }
+            SipError::SelfTestFailed { c, d, bits, length } => write!(
+                f,
+                "the self-test of siphash_{}_{} ({} bits) failed for the message of length {}",
+                c, d, bits, length
+            ),
+            SipError::InvalidVectors(line) => write!(f, "invalid test vectors at line {}", line),
+            SipError::ParametersMismatch { c, d, bits } => write!(
+                f,
+                "the test vectors are for siphash_{}_{} ({} bits)",
+                c, d, bits
+            ),
+            SipError::VectorMismatch(length) => write!(
+                f,
+                "the test vector for the message of length {} doesn't match",
+                length
+            ),
+            SipError::InvalidDigest => f.write_str("the digest is not made of hexadecimal digits"),
+            SipError::InvalidOutputLength(length) => write!(
+                f,
+                "the output must be the size of the hash value, not {} bytes",
+                length
+            ),
+            SipError::KeyMismatch => f.write_str("the values were computed with different keys"),
+            SipError::UnsupportedVariant { c, d, bits } => write!(
+                f,
+                "siphash_{}_{} in {} bits is not available, only siphash_1_3, siphash_2_4 and siphash_4_8 in 64 or 128 bits are",
+                c, d, bits
+            ),
+        }
+    }
 }
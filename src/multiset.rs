@@ -0,0 +1,198 @@
+//! Order-independent hashing of sets and multisets, updated incrementally as elements are added and removed.
+//!
+//! Each element is hashed with a keyed `SipHash<C, D, Hash128>`, and the 128-bit values are added modulo `2^128`
+//! along with the number of elements. Since the addition is commutative, the digest doesn't depend on the order of
+//! the insertions, and removing an element subtracts its value without rehashing the others.
+//!
+//! A set is hashed as a multiset whose elements are inserted once: inserting the same element twice changes the
+//! digest. Removing an element which wasn't inserted is allowed, but the digest is only meaningful again once it is
+//! inserted.
+//!
+//! As with any additive multiset hash, the key must stay secret: an attacker knowing the element values could find
+//! different multisets with the same sum.
+//!
+//! ```rust
+//! use siphash_c_d::multiset::MultisetHash;
+//!
+//! let key = [0u8; 16];
+//!
+//! let mut a = MultisetHash::<2, 4>::new(&key).unwrap();
+//! a.insert(b"read");
+//! a.insert(b"write");
+//!
+//! let mut b = MultisetHash::<2, 4>::new(&key).unwrap();
+//! b.insert(b"write");
+//! b.insert(b"exec");
+//! b.insert(b"read");
+//! b.remove(b"exec");
+//!
+//! assert!(a.equals(&b));
+//! assert_eq!(a.digest(), b.digest());
+//! ```
+use crate::{Digest128, Hash128, SipError, SipHash, SipHashKey};
+
+// the first byte of the hashed messages, so that an element can't have the value of a digest
+const ELEMENT: u8 = 0;
+const DIGEST: u8 = 1;
+
+/// The hash of a multiset of byte strings, using `siphash_c_d` with a 128-bit output for each element.
+#[derive(Clone)]
+pub struct MultisetHash<const C: u8, const D: u8> {
+    key: SipHashKey,
+    sum: u128,
+    count: u64,
+}
+
+impl<const C: u8, const D: u8> MultisetHash<C, D> {
+    /// The hash of the empty multiset, with the key `key`.
+    pub fn new<K>(key: K) -> Result<Self, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        Ok(Self {
+            key: key.try_into()?,
+            sum: 0,
+            count: 0,
+        })
+    }
+
+    // the keyed 128-bit value of an element
+    fn element(&self, element: &[u8]) -> u128 {
+        let mut siphash = SipHash::<C, D, Hash128>::from_key(self.key);
+        siphash.update(&[ELEMENT]);
+        siphash.update(element);
+        siphash.finalize()
+    }
+
+    /// Add an occurrence of `element`.
+    pub fn insert(&mut self, element: &[u8]) {
+        self.sum = self.sum.wrapping_add(self.element(element));
+        self.count = self.count.wrapping_add(1);
+    }
+
+    /// Remove an occurrence of `element`.
+    pub fn remove(&mut self, element: &[u8]) {
+        self.sum = self.sum.wrapping_sub(self.element(element));
+        self.count = self.count.wrapping_sub(1);
+    }
+
+    /// Add all the elements of `other`, which needs to use the same key (`SipError::KeyMismatch`), as if they were
+    /// inserted one by one.
+    pub fn union(&mut self, other: &Self) -> Result<(), SipError> {
+        if self.key != other.key {
+            return Err(SipError::KeyMismatch);
+        }
+        self.sum = self.sum.wrapping_add(other.sum);
+        self.count = self.count.wrapping_add(other.count);
+        Ok(())
+    }
+
+    /// Whether both multisets use the same key and have the same elements, whatever their order.
+    pub fn equals(&self, other: &Self) -> bool {
+        self.key == other.key && self.sum == other.sum && self.count == other.count
+    }
+
+    /// The number of elements, counting each occurrence.
+    pub fn len(&self) -> u64 {
+        self.count
+    }
+
+    /// Whether the multiset has no element.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// The 128-bit digest of the multiset, which hashes the sum of the element values and their number.
+    pub fn digest(&self) -> Digest128 {
+        let mut siphash = SipHash::<C, D, Hash128>::from_key(self.key);
+        siphash.update(&[DIGEST]);
+        siphash.update(&self.sum.to_le_bytes());
+        siphash.update(&self.count.to_le_bytes());
+        Digest128::from(siphash.finalize())
+    }
+}
+
+impl<const C: u8, const D: u8> PartialEq for MultisetHash<C, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals(other)
+    }
+}
+
+impl<const C: u8, const D: u8> Eq for MultisetHash<C, D> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::KEY;
+
+    const ELEMENTS: [&[u8]; 5] = [b"", b"a", b"bin/ls", b"etc/passwd", b"usr/lib/libc.so.6"];
+
+    fn multiset(elements: &[&[u8]]) -> MultisetHash<2, 4> {
+        let mut multiset = MultisetHash::new(&KEY).unwrap();
+        for element in elements {
+            multiset.insert(element);
+        }
+        multiset
+    }
+
+    #[test]
+    fn test_order() {
+        let a = multiset(&ELEMENTS);
+        let mut reversed = ELEMENTS;
+        reversed.reverse();
+        let b = multiset(&reversed);
+
+        assert!(a.equals(&b));
+        assert_eq!(a.digest(), b.digest());
+        assert_eq!(a.len(), 5);
+        assert_ne!(a.digest(), multiset(&ELEMENTS[1..]).digest());
+    }
+
+    #[test]
+    fn test_remove() {
+        let empty = multiset(&[]);
+        assert!(empty.is_empty());
+
+        let mut m = multiset(&ELEMENTS);
+        for element in ELEMENTS.iter().rev() {
+            m.remove(element);
+        }
+        assert!(m.equals(&empty));
+        assert_eq!(m.digest(), empty.digest());
+
+        // a removal before the insertion
+        let mut m = multiset(&ELEMENTS[..2]);
+        m.remove(ELEMENTS[4]);
+        assert!(m != multiset(&ELEMENTS[..2]));
+        m.insert(ELEMENTS[4]);
+        assert!(m == multiset(&ELEMENTS[..2]));
+    }
+
+    #[test]
+    fn test_multiplicity() {
+        let once = multiset(&ELEMENTS[1..2]);
+        let twice = multiset(&[ELEMENTS[1], ELEMENTS[1]]);
+        assert!(once != twice);
+        assert_ne!(once.digest(), twice.digest());
+    }
+
+    #[test]
+    fn test_union() {
+        let mut m = multiset(&ELEMENTS[..2]);
+        m.union(&multiset(&ELEMENTS[2..])).unwrap();
+        assert!(m == multiset(&ELEMENTS));
+
+        let other = MultisetHash::<2, 4>::new((1, 2)).unwrap();
+        assert_eq!(m.union(&other), Err(SipError::KeyMismatch));
+        assert!(other != multiset(&[]));
+        assert_ne!(other.digest(), multiset(&[]).digest());
+    }
+
+    #[test]
+    fn test_element() {
+        let mut siphash = SipHash::<2, 4, Hash128>::new(&KEY).unwrap();
+        siphash.update(&[ELEMENT]);
+        siphash.update(b"a");
+        assert_eq!(multiset(&ELEMENTS[1..2]).sum, siphash.finalize());
+    }
+}
@@ -0,0 +1,137 @@
+//! Case-insensitive hashing of ASCII letters, compatible with the `siphash_nocase()` function of Redis.
+//!
+//! Redis is using `siphash_1_2` to hash the keys of its dictionaries, and `siphash_nocase()` for its case-insensitive
+//! tables like the command table: the `A` to `Z` bytes are hashed as `a` to `z`.
+//!
+//! ```rust
+//! use core::hash::Hasher;
+//! use siphash_c_d::{nocase, nocase::NoCase, SipHash};
+//!
+//! let key = b"1234567812345678";
+//! let h = nocase::siphash_nocase(b"GET", key);
+//!
+//! let mut hasher = NoCase(SipHash::<1, 2, _>::new(key).unwrap());
+//! hasher.write(b"gEt");
+//! assert_eq!(hasher.finish(), h);
+//! ```
+use core::hash::Hasher;
+
+use crate::{Hash64, SipHash, SipHashKey};
+
+/// The `siphash()` function of Redis: `siphash_1_2` of `msg` using the key `key`.
+pub fn siphash(msg: &[u8], key: &[u8; 16]) -> u64 {
+    let mut siphash = SipHash::<1, 2, Hash64>::from_key(SipHashKey::from_bytes(key));
+    siphash.update(msg);
+    siphash.finalize()
+}
+
+/// The `siphash_nocase()` function of Redis: `siphash_1_2` of `msg` using the key `key`, the ASCII uppercase
+/// letters being hashed as lowercase letters.
+pub fn siphash_nocase(msg: &[u8], key: &[u8; 16]) -> u64 {
+    let mut siphash = SipHash::<1, 2, Hash64>::from_key(SipHashKey::from_bytes(key));
+    siphash.update_nocase(msg);
+    siphash.finalize()
+}
+
+/// A [`Hasher`] hashing the ASCII uppercase letters as lowercase letters.
+///
+/// All the bytes written are folded, including the bytes of the integers written by `write_u32()` for example.
+#[derive(Debug, Clone, Default)]
+pub struct NoCase<H>(pub H);
+
+impl<H: Hasher> Hasher for NoCase<H> {
+    fn write(&mut self, bytes: &[u8]) {
+        for_each_lowercase(bytes, |lowercase| self.0.write(lowercase));
+    }
+
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+}
+
+// call f with the lowercase copies of the successive chunks of bytes, without allocating a copy of all of them
+pub(crate) fn for_each_lowercase<F: FnMut(&[u8])>(bytes: &[u8], mut f: F) {
+    let mut lowercase = [0u8; 64];
+
+    for chunk in bytes.chunks(lowercase.len()) {
+        let lowercase = &mut lowercase[..chunk.len()];
+        lowercase.copy_from_slice(chunk);
+        lowercase.make_ascii_lowercase();
+        f(lowercase);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::KEY;
+
+    // siphash_1_2 vectors, for the key and messages of the reference implementation
+    const VECTORS: [(usize, u64); 6] = [
+        (0, 0xcea28b51565c12e2),
+        (1, 0x94aaf38c34ce7ba6),
+        (7, 0xf03c4cbcf492b05a),
+        (8, 0x606845b4d093af74),
+        (15, 0xec8f61bc1c8966a6),
+        (63, 0xff6d07afacbad6d9),
+    ];
+
+    #[test]
+    fn test_vectors() {
+        let mut msg = [0u8; 64];
+        (0..64).for_each(|i| msg[i] = i as u8);
+
+        for (length, expected) in VECTORS {
+            assert_eq!(siphash(&msg[..length], &KEY), expected);
+            // no letter in the message
+            assert_eq!(siphash_nocase(&msg[..length], &KEY), expected);
+        }
+    }
+
+    #[test]
+    // the case-insensitive checks of siphash_test() in Redis's siphash.c
+    fn test_redis() {
+        let key = b"1234567812345678";
+        assert_eq!(siphash(b"hello world", key), 0x89cb5e38dae0f000);
+
+        assert_eq!(
+            siphash(b"hello world", key),
+            siphash_nocase(b"hello world", key)
+        );
+        assert_eq!(
+            siphash(b"hello world", key),
+            siphash_nocase(b"HELLO world", key)
+        );
+        assert_ne!(
+            siphash(b"HELLO world", key),
+            siphash_nocase(b"HELLO world", key)
+        );
+    }
+
+    #[test]
+    fn test_long_message() {
+        let mut upper = [0u8; 200];
+        let mut lower = [0u8; 200];
+        for i in 0..200 {
+            upper[i] = b'A' + (i % 26) as u8;
+            lower[i] = b'a' + (i % 26) as u8;
+        }
+
+        let expected = SipHash::<2, 4, Hash64>::with_key(&KEY, &lower).unwrap();
+        assert_eq!(
+            SipHash::<2, 4, Hash64>::with_key_nocase(&KEY, &upper).unwrap(),
+            expected
+        );
+
+        let mut hasher = NoCase(SipHash::<2, 4, Hash64>::new(&KEY).unwrap());
+        hasher.write(&upper[..3]);
+        hasher.write(&upper[3..]);
+        assert_eq!(hasher.finish(), expected);
+    }
+
+    #[test]
+    fn test_non_letters() {
+        let msg = b"[@`{] 0123456789 \xc3\x89";
+        assert_eq!(siphash_nocase(msg, &KEY), siphash(msg, &KEY));
+    }
+}
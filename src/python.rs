@@ -0,0 +1,252 @@
+//! The `hash()` of `bytes` and `str` objects of CPython (64-bit builds), to reproduce offline the hash values and the
+//! dictionary ordering of a Python process.
+//!
+//! The key is taken from the first 16 bytes of the 24-byte `_Py_HashSecret`, which is either:
+//!
+//! * calculated from the `PYTHONHASHSEED` value by the linear congruential generator of CPython, the value 0
+//!   giving a secret made of zeroes;
+//! * given explicitly.
+//!
+//! CPython is using `siphash_1_3` since Python 3.11, and `siphash_2_4` for Python 3.4 to 3.10.
+//!
+//! ```rust
+//! use siphash_c_d::python::PythonHash;
+//!
+//! // PYTHONHASHSEED=42 python3 -c "print(hash('hello'))"
+//! let python = PythonHash::with_seed(42);
+//! assert_eq!(python.hash_str("hello"), 841626496250501200);
+//! assert_eq!(python.hash_bytes(b"hello"), 841626496250501200);
+//! ```
+use crate::{Hash64, SipHash, SipHashKey};
+
+/// The hash values of CPython for a given `_Py_HashSecret`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PythonHash {
+    key: SipHashKey,
+    siphash24: bool,
+}
+
+impl PythonHash {
+    /// The hash values when the `PYTHONHASHSEED` environment variable is set to `seed`.
+    pub fn with_seed(seed: u32) -> Self {
+        let mut secret = [0u8; 24];
+
+        // lcg_urandom() of CPython, randomization being disabled for 0
+        if seed != 0 {
+            let mut x = seed;
+            for b in secret.iter_mut() {
+                x = x.wrapping_mul(214013).wrapping_add(2531011);
+                *b = (x >> 16) as u8;
+            }
+        }
+
+        Self::with_secret(&secret)
+    }
+
+    /// The hash values for the 24 bytes of `_Py_HashSecret`.
+    pub fn with_secret(secret: &[u8; 24]) -> Self {
+        let mut key = [0u8; 16];
+        key.copy_from_slice(&secret[..16]);
+
+        Self {
+            key: SipHashKey::from_bytes(&key),
+            siphash24: false,
+        }
+    }
+
+    /// Use `siphash_2_4` as Python 3.4 to 3.10.
+    pub fn siphash24(mut self) -> Self {
+        self.siphash24 = true;
+        self
+    }
+
+    /// The `siphash_c_d` key, taken from the secret.
+    pub fn key(&self) -> SipHashKey {
+        self.key
+    }
+
+    /// `hash()` of a `bytes` object.
+    pub fn hash_bytes(&self, bytes: &[u8]) -> i64 {
+        self.hash_with(bytes.len(), |h| h.update(bytes))
+    }
+
+    /// `hash()` of a `str` object, whose representation is chosen from its largest code point as CPython: Latin-1 (one
+    /// byte per code point), UCS-2 or UCS-4 (2 or 4 little-endian bytes per code point).
+    pub fn hash_str(&self, s: &str) -> i64 {
+        let max = s.chars().map(|c| c as u32).max().unwrap_or(0);
+        let width = match max {
+            0..=0xFF => 1,
+            0x100..=0xFFFF => 2,
+            _ => 4,
+        };
+        let length = s.chars().count() * width;
+
+        // no allocation of the representation
+        self.hash_with(length, |h| {
+            let mut buffer = [0u8; 64];
+            let mut used = 0;
+
+            for c in s.chars() {
+                let bytes = (c as u32).to_le_bytes();
+                buffer[used..used + width].copy_from_slice(&bytes[..width]);
+                used += width;
+
+                if used + 4 > buffer.len() {
+                    h.update(&buffer[..used]);
+                    used = 0;
+                }
+            }
+            h.update(&buffer[..used]);
+        })
+    }
+
+    /// `hash()` of a `str` object whose code points are all less than 256.
+    pub fn hash_latin1(&self, s: &[u8]) -> i64 {
+        self.hash_bytes(s)
+    }
+
+    /// `hash()` of a `str` object represented in UCS-2.
+    pub fn hash_ucs2(&self, s: &[u16]) -> i64 {
+        self.hash_with(2 * s.len(), |h| {
+            s.iter().for_each(|c| h.update(&c.to_le_bytes()));
+        })
+    }
+
+    /// `hash()` of a `str` object represented in UCS-4.
+    pub fn hash_ucs4(&self, s: &[u32]) -> i64 {
+        self.hash_with(4 * s.len(), |h| {
+            s.iter().for_each(|c| h.update(&c.to_le_bytes()));
+        })
+    }
+
+    // _Py_HashBytes() of the bytes added by f
+    fn hash_with<F: FnOnce(&mut dyn Update)>(&self, length: usize, f: F) -> i64 {
+        // the hash of an empty object is 0
+        if length == 0 {
+            return 0;
+        }
+
+        let x = if self.siphash24 {
+            let mut siphash = SipHash::<2, 4, Hash64>::from_key(self.key);
+            f(&mut siphash);
+            siphash.finalize()
+        } else {
+            let mut siphash = SipHash::<1, 3, Hash64>::from_key(self.key);
+            f(&mut siphash);
+            siphash.finalize()
+        } as i64;
+
+        // -1 is an error code in CPython
+        if x == -1 {
+            -2
+        } else {
+            x
+        }
+    }
+}
+
+// the siphash_1_3 and siphash_2_4 streaming
+trait Update {
+    fn update(&mut self, bytes: &[u8]);
+}
+
+impl<const C: u8, const D: u8> Update for SipHash<C, D, Hash64> {
+    fn update(&mut self, bytes: &[u8]) {
+        SipHash::update(self, bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // hash() values given by Python 3.11 for PYTHONHASHSEED=0, 1 and 42
+    const HASHES: [(u32, [u64; 5]); 3] = [
+        (
+            0,
+            [
+                0x407448d2b89b1813,
+                0xe2e77b41cb4e1f9e,
+                0x58c0c6757e25cf3c,
+                0x6426c73394e5ba71,
+                0x014963c6bca9b272,
+            ],
+        ),
+        (
+            1,
+            [
+                0xd6300bc9f7cc0e73,
+                0xe83d39dd9f7ed1ce,
+                0x403930d0ed0392f7,
+                0xd2605621977b3ad3,
+                0xc2780f69169f327e,
+            ],
+        ),
+        (
+            42,
+            [
+                0xfe4a47335692551e,
+                0x0bae0ed6d6915850,
+                0xc7ac8db7e5ba8ea7,
+                0xf81d54c5c79bb7c1,
+                0x4471222a9be44057,
+            ],
+        ),
+    ];
+
+    #[test]
+    fn test_python_hashes() {
+        for (seed, expected) in HASHES {
+            let python = PythonHash::with_seed(seed);
+
+            assert_eq!(python.hash_bytes(b""), 0);
+            assert_eq!(python.hash_str(""), 0);
+            assert_eq!(python.hash_bytes(b"a") as u64, expected[0]);
+            assert_eq!(python.hash_bytes(b"hello") as u64, expected[1]);
+            assert_eq!(python.hash_str("hello") as u64, expected[1]);
+
+            // Latin-1, UCS-2 and UCS-4 representations
+            assert_eq!(python.hash_str("héllo") as u64, expected[2]);
+            assert_eq!(python.hash_str("ĀĂ€ monde") as u64, expected[3]);
+            assert_eq!(python.hash_str("emoji 😀 x") as u64, expected[4]);
+        }
+    }
+
+    #[test]
+    fn test_representations() {
+        let python = PythonHash::with_seed(42);
+
+        assert_eq!(python.hash_latin1(b"h\xe9llo"), python.hash_str("héllo"));
+
+        let ucs2 = [0x100, 0x102, 0x20ac, 0x20, 0x6d, 0x6f, 0x6e, 0x64, 0x65];
+        assert_eq!(python.hash_ucs2(&ucs2), python.hash_str("ĀĂ€ monde"));
+
+        let ucs4 = [0x65, 0x6d, 0x6f, 0x6a, 0x69, 0x20, 0x1f600, 0x20, 0x78];
+        assert_eq!(python.hash_ucs4(&ucs4), python.hash_str("emoji 😀 x"));
+
+        // longer than the buffer
+        let s = "😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀😀";
+        assert_eq!(python.hash_ucs4(&[0x1f600; 40]), python.hash_str(s));
+
+        let a = [b'a'; 100];
+        let python = PythonHash::with_seed(0);
+        assert_eq!(python.hash_bytes(&a) as u64, 0xdb4f1f048099e5ee);
+        assert_eq!(
+            python.hash_str(core::str::from_utf8(&a).unwrap()) as u64,
+            0xdb4f1f048099e5ee
+        );
+    }
+
+    #[test]
+    fn test_seed_zero() {
+        assert_eq!(PythonHash::with_seed(0).key(), SipHashKey::new(0, 0));
+        assert_eq!(PythonHash::with_seed(0), PythonHash::with_secret(&[0; 24]));
+    }
+
+    #[test]
+    fn test_siphash24() {
+        let python = PythonHash::with_secret(&[0; 24]).siphash24();
+        let expected = SipHash::<2, 4, Hash64>::with_key(0_u128, b"hello").unwrap();
+        assert_eq!(python.hash_bytes(b"hello"), expected as i64);
+    }
+}
@@ -0,0 +1,579 @@
+//! Statistical quality tests in the style of the SMHasher suite, to validate a reduced round configuration.
+//!
+//! Each test hashes a set of keys and checks:
+//!
+//! * the collisions of the 64-bit hash values, and of their low and high 32 bits. A test fails when there are more
+//!   collisions than twice the expected number plus 6 standard deviations, plus one;
+//! * the distribution of the hash values in buckets, as used by hash tables: for each 64-bit window offset,
+//!   the bits of the window select one of up to 4096 buckets, and a chi-square test is done on the bucket counts.
+//!   A test fails when the worst z-score of the chi-square statistic is greater than [`MAX_Z_SCORE`].
+//!
+//! The key sets are:
+//!
+//! * *Sparse*: all the keys of a given length with a few bits set;
+//! * *Cyclic*: random cycles of bytes repeated several times;
+//! * *TwoBytes*: all the keys of a given length with at most 2 non zero bytes;
+//! * *Zeroes*: keys of zeroes of all lengths;
+//! * *Seed*: the same key hashed with different seeds.
+//!
+//! Any hash function can be tested by implementing the [`Target`] trait. It is implemented by [`SipTarget`] for
+//! [`SipHash`], and by [`HasherTarget`] for any [`Hasher`] built from a seed.
+//!
+//! No allocation is made: the hash values are kept in a buffer given by the caller.
+//!
+//! ```rust
+//! use siphash_c_d::{
+//!     quality::{SipTarget, Suite},
+//!     Hash64,
+//! };
+//!
+//! let target = SipTarget::<1, 3, Hash64>::new((0x0706050403020100, 0x0f0e0d0c0b0a0908)).unwrap();
+//! let mut buffer = vec![0u64; Suite::small().buffer_length()];
+//!
+//! let passed = Suite::small().run(&target, &mut buffer, |result| println!("{}", result));
+//! assert!(passed);
+//! ```
+use core::{fmt, hash::Hasher, marker::PhantomData};
+
+use crate::{analysis::sqrt, siphashkey::SipHashKey, Hash128, HashOutput, SipError, SipHash};
+
+/// The z-score of the chi-square statistic above which a bucket distribution is considered as biased.
+pub const MAX_Z_SCORE: f64 = 6.0;
+
+/// The maximum length of the Cyclic keys and of the Zeroes keys.
+pub const MAX_KEY_LENGTH: usize = 4096;
+
+/// A hash function under test.
+pub trait Target {
+    /// The hash value of `bytes` using `seed`, reduced to 64 bits.
+    fn hash(&self, seed: u64, bytes: &[u8]) -> u64;
+}
+
+/// The `siphash_c_d` target: the seed is xored with the first half of the key.
+///
+/// For a 128-bit hash, the low 64 bits are tested, or the high 64 bits using [`SipTarget::high`].
+#[derive(Debug, Clone)]
+pub struct SipTarget<const C: u8, const D: u8, T> {
+    k0: u64,
+    k1: u64,
+    high: bool,
+    output: PhantomData<T>,
+}
+
+impl<const C: u8, const D: u8, T: HashOutput> SipTarget<C, D, T> {
+    /// A target using the key `key`.
+    ///
+    /// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
+    pub fn new<K>(key: K) -> Result<Self, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        let k = key.try_into()?;
+
+        Ok(Self {
+            k0: k.0,
+            k1: k.1,
+            high: false,
+            output: PhantomData,
+        })
+    }
+}
+
+impl<const C: u8, const D: u8> SipTarget<C, D, Hash128> {
+    /// Test the high 64 bits of the 128-bit hash.
+    pub fn high(mut self) -> Self {
+        self.high = true;
+        self
+    }
+}
+
+impl<const C: u8, const D: u8, T: HashOutput> Target for SipTarget<C, D, T> {
+    fn hash(&self, seed: u64, bytes: &[u8]) -> u64 {
+        let mut siphash = match SipHash::<C, D, T>::new((self.k0 ^ seed, self.k1)) {
+            Ok(siphash) => siphash,
+            Err(_) => unreachable!("a tuple key is always valid"),
+        };
+        siphash.update(bytes);
+
+        let value = T::to_le_bytes(siphash.finalize());
+        let value = value.as_ref();
+        let half = if self.high {
+            &value[8..16]
+        } else {
+            &value[0..8]
+        };
+
+        u64::from_le_bytes([
+            half[0], half[1], half[2], half[3], half[4], half[5], half[6], half[7],
+        ])
+    }
+}
+
+/// A target using the [`Hasher`] returned by a function of the seed.
+///
+/// ```rust
+/// use siphash_c_d::{quality::HasherTarget, SipHash24};
+///
+/// let target = HasherTarget(|seed| SipHash24::new((seed, 0)).unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HasherTarget<F>(pub F);
+
+impl<F, H> Target for HasherTarget<F>
+where
+    F: Fn(u64) -> H,
+    H: Hasher,
+{
+    fn hash(&self, seed: u64, bytes: &[u8]) -> u64 {
+        let mut hasher = (self.0)(seed);
+        hasher.write(bytes);
+        hasher.finish()
+    }
+}
+
+/// The result of a test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TestResult {
+    /// The name of the key set.
+    pub name: &'static str,
+
+    /// Number of hashed keys.
+    pub keys: usize,
+
+    /// Collisions of the 64-bit hash values.
+    pub collisions: usize,
+
+    /// Expected number of collisions of the 64-bit hash values.
+    pub expected_collisions: f64,
+
+    /// Collisions of the low 32 bits of the hash values.
+    pub low_collisions: usize,
+
+    /// Collisions of the high 32 bits of the hash values.
+    pub high_collisions: usize,
+
+    /// Expected number of collisions of 32-bit values.
+    pub expected_collisions_32: f64,
+
+    /// Worst z-score of the bucket distribution, if there are enough keys for at least 256 buckets.
+    pub distribution: Option<f64>,
+
+    /// Whether the test passed.
+    pub passed: bool,
+}
+
+impl fmt::Display for TestResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<9} {:>8} keys, collisions: {} ({:.1} expected), low 32 bits: {}, high 32 bits: {} ({:.1} expected)",
+            self.name,
+            self.keys,
+            self.collisions,
+            self.expected_collisions,
+            self.low_collisions,
+            self.high_collisions,
+            self.expected_collisions_32
+        )?;
+        if let Some(z) = self.distribution {
+            write!(f, ", distribution z-score: {:.2}", z)?;
+        }
+        write!(f, " - {}", if self.passed { "PASS" } else { "FAIL" })
+    }
+}
+
+/// The sizes of the key sets of the tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Suite {
+    /// Length of the Sparse keys in bytes, and maximum number of bits set.
+    pub sparse: (usize, usize),
+
+    /// Length of the cycle in bytes, number of repetitions and number of Cyclic keys.
+    pub cyclic: (usize, usize, usize),
+
+    /// Length of the TwoBytes keys.
+    pub two_bytes: usize,
+
+    /// Number of Zeroes keys, whose lengths are from 0 to this number minus 1.
+    pub zeroes: usize,
+
+    /// Number of seeds.
+    pub seeds: usize,
+}
+
+impl Default for Suite {
+    /// Key sets large enough to find the weaknesses of the very reduced variants.
+    fn default() -> Self {
+        Self {
+            sparse: (8, 3),
+            cyclic: (8, 4, 100_000),
+            two_bytes: 4,
+            zeroes: MAX_KEY_LENGTH + 1,
+            seeds: 100_000,
+        }
+    }
+}
+
+impl Suite {
+    /// Small key sets, for a quick check.
+    pub fn small() -> Self {
+        Self {
+            sparse: (4, 2),
+            cyclic: (4, 2, 4000),
+            two_bytes: 2,
+            zeroes: 1000,
+            seeds: 4000,
+        }
+    }
+
+    /// The length of the buffer needed by [`Suite::run`].
+    pub fn buffer_length(&self) -> usize {
+        let (length, bits) = self.sparse;
+        let two_bytes = 1 + 255 * self.two_bytes + 255 * 255 * binomial(self.two_bytes, 2);
+
+        [
+            (0..=bits).map(|k| binomial(8 * length, k)).sum(),
+            self.cyclic.2,
+            two_bytes,
+            self.zeroes,
+            self.seeds,
+        ]
+        .iter()
+        .copied()
+        .max()
+        .unwrap_or(0)
+    }
+
+    /// Run all the tests on `target`, calling `f` with each result. Returns `true` if all the tests passed.
+    ///
+    /// # Panics
+    ///
+    /// If `buffer` is shorter than [`Suite::buffer_length`] or if a key set exceeds [`MAX_KEY_LENGTH`].
+    pub fn run<G, F>(&self, target: &G, buffer: &mut [u64], mut f: F) -> bool
+    where
+        G: Target,
+        F: FnMut(&TestResult),
+    {
+        let results = [
+            sparse(target, buffer, self.sparse.0, self.sparse.1),
+            cyclic(target, buffer, self.cyclic.0, self.cyclic.1, self.cyclic.2),
+            two_bytes(target, buffer, self.two_bytes),
+            zeroes(target, buffer, self.zeroes),
+            seeds(target, buffer, self.seeds),
+        ];
+
+        results.iter().for_each(&mut f);
+        results.iter().all(|result| result.passed)
+    }
+}
+
+/// All the keys of `length` bytes with at most `max_bits` bits set.
+pub fn sparse<G: Target>(
+    target: &G,
+    buffer: &mut [u64],
+    length: usize,
+    max_bits: usize,
+) -> TestResult {
+    assert!(length <= MAX_KEY_LENGTH && max_bits <= 8 * length);
+
+    let mut keys = Keys::new(buffer);
+    let mut key = [0u8; MAX_KEY_LENGTH];
+    let mut positions = [0usize; 64];
+
+    for bits in 0..=max_bits.min(positions.len()) {
+        // the first combination of bits
+        (0..bits).for_each(|i| positions[i] = i);
+
+        loop {
+            key[..length].iter_mut().for_each(|b| *b = 0);
+            positions[..bits]
+                .iter()
+                .for_each(|p| key[p / 8] |= 1 << (p % 8));
+            keys.push(target.hash(0, &key[..length]));
+
+            // next combination
+            let mut i = bits;
+            while i > 0 && positions[i - 1] == 8 * length - bits + i - 1 {
+                i -= 1;
+            }
+            if i == 0 {
+                break;
+            }
+            positions[i - 1] += 1;
+            (i..bits).for_each(|j| positions[j] = positions[j - 1] + 1);
+        }
+    }
+
+    keys.result("Sparse")
+}
+
+/// `count` keys made of a random cycle of `cycle` bytes repeated `repeats` times.
+pub fn cyclic<G: Target>(
+    target: &G,
+    buffer: &mut [u64],
+    cycle: usize,
+    repeats: usize,
+    count: usize,
+) -> TestResult {
+    let length = cycle * repeats;
+    assert!(length <= MAX_KEY_LENGTH);
+
+    let mut keys = Keys::new(buffer);
+    let mut key = [0u8; MAX_KEY_LENGTH];
+
+    for n in 0..count {
+        for (i, b) in key[..cycle].iter_mut().enumerate() {
+            *b = random(n as u64, i as u64) as u8;
+        }
+        (cycle..length).for_each(|i| key[i] = key[i % cycle]);
+        keys.push(target.hash(0, &key[..length]));
+    }
+
+    keys.result("Cyclic")
+}
+
+/// All the keys of `length` bytes with at most 2 non zero bytes.
+pub fn two_bytes<G: Target>(target: &G, buffer: &mut [u64], length: usize) -> TestResult {
+    assert!(length <= MAX_KEY_LENGTH);
+
+    let mut keys = Keys::new(buffer);
+    let mut key = [0u8; MAX_KEY_LENGTH];
+
+    keys.push(target.hash(0, &key[..length]));
+
+    for i in 0..length {
+        for x in 1..=255 {
+            key[i] = x;
+            keys.push(target.hash(0, &key[..length]));
+
+            for j in i + 1..length {
+                for y in 1..=255 {
+                    key[j] = y;
+                    keys.push(target.hash(0, &key[..length]));
+                }
+                key[j] = 0;
+            }
+        }
+        key[i] = 0;
+    }
+
+    keys.result("TwoBytes")
+}
+
+/// The keys made of 0 to `count - 1` zero bytes.
+pub fn zeroes<G: Target>(target: &G, buffer: &mut [u64], count: usize) -> TestResult {
+    assert!(count <= MAX_KEY_LENGTH + 1);
+
+    let mut keys = Keys::new(buffer);
+    let key = [0u8; MAX_KEY_LENGTH];
+
+    for length in 0..count {
+        keys.push(target.hash(0, &key[..length]));
+    }
+
+    keys.result("Zeroes")
+}
+
+/// The same key hashed with the seeds from 0 to `count - 1`.
+pub fn seeds<G: Target>(target: &G, buffer: &mut [u64], count: usize) -> TestResult {
+    let key = b"The quick brown fox jumps over the lazy dog";
+
+    let mut keys = Keys::new(buffer);
+    for seed in 0..count {
+        keys.push(target.hash(seed as u64, key));
+    }
+
+    keys.result("Seed")
+}
+
+// the hash values of a key set
+struct Keys<'a> {
+    buffer: &'a mut [u64],
+    length: usize,
+}
+
+impl<'a> Keys<'a> {
+    fn new(buffer: &'a mut [u64]) -> Self {
+        Self { buffer, length: 0 }
+    }
+
+    fn push(&mut self, h: u64) {
+        assert!(self.length < self.buffer.len(), "the buffer is too small");
+        self.buffer[self.length] = h;
+        self.length += 1;
+    }
+
+    fn result(self, name: &'static str) -> TestResult {
+        let hashes = &mut self.buffer[..self.length];
+        let n = hashes.len() as f64;
+        let pairs = n * (n - 1.0) / 2.0;
+
+        // before the values are sorted or truncated
+        let distribution = distribution(hashes);
+
+        hashes.sort_unstable();
+        let full_collisions = collisions(hashes, |h| h);
+        // sorting by the full value also sorts the high 32 bits
+        let high_collisions = collisions(hashes, |h| h >> 32);
+
+        hashes.iter_mut().for_each(|h| *h &= 0xFFFF_FFFF);
+        hashes.sort_unstable();
+        let low_collisions = collisions(hashes, |h| h);
+
+        let expected_collisions = pairs / 18446744073709551616.0;
+        let expected_collisions_32 = pairs / 4294967296.0;
+        let max_32 = max_collisions(expected_collisions_32);
+
+        let passed = full_collisions <= max_collisions(expected_collisions)
+            && low_collisions <= max_32
+            && high_collisions <= max_32
+            && distribution.map_or(true, |z| z <= MAX_Z_SCORE);
+
+        TestResult {
+            name,
+            keys: self.length,
+            collisions: full_collisions,
+            expected_collisions,
+            low_collisions,
+            high_collisions,
+            expected_collisions_32,
+            distribution,
+            passed,
+        }
+    }
+}
+
+// number of values equal to the previous one, the values being sorted
+fn collisions<F: Fn(u64) -> u64>(sorted: &[u64], f: F) -> usize {
+    sorted.windows(2).filter(|w| f(w[0]) == f(w[1])).count()
+}
+
+// collisions are following a Poisson distribution
+fn max_collisions(expected: f64) -> usize {
+    (2.0 * expected + 6.0 * sqrt(expected)) as usize + 1
+}
+
+// worst z-score of the chi-square statistic of the bucket counts, for all window offsets
+fn distribution(hashes: &[u64]) -> Option<f64> {
+    // at least 16 keys per bucket on average, from 256 to 4096 buckets
+    let mut width = 12;
+    while width >= 8 && hashes.len() < 16 << width {
+        width -= 1;
+    }
+    if width < 8 {
+        return None;
+    }
+
+    let buckets = 1usize << width;
+    let expected = hashes.len() as f64 / buckets as f64;
+    let freedom = (buckets - 1) as f64;
+    let mut counts = [0u32; 4096];
+    let mut worst = f64::MIN;
+
+    for offset in 0..64 {
+        counts[..buckets].iter_mut().for_each(|c| *c = 0);
+        for h in hashes {
+            counts[(h.rotate_right(offset) as usize) & (buckets - 1)] += 1;
+        }
+
+        let chi2: f64 = counts[..buckets]
+            .iter()
+            .map(|c| (*c as f64 - expected) * (*c as f64 - expected) / expected)
+            .sum();
+        let z = (chi2 - freedom) / sqrt(2.0 * freedom);
+        worst = worst.max(z);
+    }
+
+    Some(worst)
+}
+
+// random bytes for the key sets
+fn random(n: u64, i: u64) -> u64 {
+    match SipHash::<2, 4, crate::Hash64>::with_key((n, i), b"quality") {
+        Ok(h) => h,
+        Err(_) => unreachable!("a tuple key is always valid"),
+    }
+}
+
+fn binomial(n: usize, k: usize) -> usize {
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hash64, SipHash24};
+
+    // a very poor hash: the sum of the bytes
+    struct Sum(u64);
+
+    impl Hasher for Sum {
+        fn write(&mut self, bytes: &[u8]) {
+            bytes.iter().for_each(|b| self.0 += *b as u64);
+        }
+
+        fn finish(&self) -> u64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(32, 0), 1);
+        assert_eq!(binomial(32, 2), 496);
+        assert_eq!(binomial(64, 3), 41664);
+    }
+
+    #[test]
+    fn test_sparse_count() {
+        let target = SipTarget::<2, 4, Hash64>::new(0_u128).unwrap();
+        let mut buffer = [0u64; 600];
+
+        let result = sparse(&target, &mut buffer, 4, 2);
+        assert_eq!(result.keys, 1 + 32 + 496);
+        assert!(result.passed);
+    }
+
+    #[test]
+    fn test_two_bytes_count() {
+        let target = SipTarget::<1, 3, Hash128>::new(0_u128).unwrap().high();
+        let mut buffer = [0u64; 256];
+
+        let result = two_bytes(&target, &mut buffer, 1);
+        assert_eq!(result.keys, 256);
+        assert_eq!(result.collisions, 0);
+        assert!(result.distribution.is_none());
+    }
+
+    #[test]
+    fn test_suite_siphash() {
+        let suite = Suite::small();
+        let mut buffer = [0u64; 65536];
+        assert!(suite.buffer_length() <= buffer.len());
+
+        let target =
+            SipTarget::<2, 4, Hash64>::new((0x0706050403020100, 0x0f0e0d0c0b0a0908)).unwrap();
+        let mut count = 0;
+        assert!(suite.run(&target, &mut buffer, |_| count += 1));
+        assert_eq!(count, 5);
+
+        // same hash values through the Hasher trait
+        let hasher = HasherTarget(|seed| {
+            SipHash24::new((0x0706050403020100 ^ seed, 0x0f0e0d0c0b0a0908)).unwrap()
+        });
+        assert_eq!(hasher.hash(5, b"abc"), target.hash(5, b"abc"));
+    }
+
+    #[test]
+    fn test_suite_poor_hash() {
+        let mut buffer = [0u64; 65536];
+        let target = HasherTarget(Sum);
+
+        let result = sparse(&target, &mut buffer, 4, 2);
+        assert!(!result.passed);
+
+        let result = zeroes(&target, &mut buffer, 100);
+        assert_eq!(result.collisions, 99);
+        assert!(!result.passed);
+    }
+}
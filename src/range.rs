@@ -0,0 +1,216 @@
+//! Mapping of hash values to a range `[0, n)`, like shard or bucket indices, with Lemire's multiply-shift reduction
+//! instead of the biased and slower `% n`.
+//!
+//! [`reduce`] and [`reduce128`] return the high part of the product of the hash value by `n`, a power of two `n`
+//! being a shift of the hash value. Their bias is at most `n / 2^64` for a 64-bit value, and `n / 2^128` for a
+//! 128-bit value.
+//!
+//! [`bucket`] is exactly unbiased: the 64-bit words of the 128-bit value of the message are reduced in turn, and
+//! the ones which would introduce a bias are rejected. When both words are rejected, which happens with a
+//! probability below `(n / 2^64)^2`, the next words are the 128-bit value of the message followed by the `u64`
+//! little-endian counter `1`, then `2`, and so on.
+//!
+//! ```rust
+//! use siphash_c_d::{range, SipHash24};
+//!
+//! let key = [0u8; 16];
+//! let hash = SipHash24::with_key(&key, b"user:42").unwrap();
+//!
+//! let shard = range::reduce(hash, 10);
+//! assert!(shard < 10);
+//!
+//! let bucket = range::bucket::<2, 4, _>(&key, b"user:42", 1000).unwrap();
+//! assert!(bucket < 1000);
+//! ```
+use crate::{Hash128, SipError, SipHash, SipHashKey};
+
+/// The 64-bit hash value `hash` mapped to `[0, n)`.
+///
+/// Panics if `n` is `0`.
+pub fn reduce(hash: u64, n: u64) -> u64 {
+    assert!(n != 0, "empty range");
+
+    if n.is_power_of_two() {
+        hash.checked_shr(64 - n.trailing_zeros()).unwrap_or(0)
+    } else {
+        ((hash as u128 * n as u128) >> 64) as u64
+    }
+}
+
+/// The 128-bit hash value `hash` mapped to `[0, n)`.
+///
+/// Panics if `n` is `0`.
+pub fn reduce128(hash: u128, n: u64) -> u64 {
+    assert!(n != 0, "empty range");
+
+    if n.is_power_of_two() {
+        hash.checked_shr(128 - n.trailing_zeros()).unwrap_or(0) as u64
+    } else {
+        // the high 128 bits of the 192-bit product
+        let high = (hash >> 64) * n as u128;
+        let low = (hash as u64 as u128) * n as u128;
+        ((high + (low >> 64)) >> 64) as u64
+    }
+}
+
+// Lemire's method: the high half of the product is uniform once the low halves below 2^64 mod n are rejected
+pub(crate) fn unbiased<F: FnMut() -> u64>(n: u64, mut next: F) -> u64 {
+    assert!(n != 0, "empty range");
+
+    let mut m = next() as u128 * n as u128;
+    if (m as u64) < n {
+        let threshold = n.wrapping_neg() % n;
+        while (m as u64) < threshold {
+            m = next() as u128 * n as u128;
+        }
+    }
+    (m >> 64) as u64
+}
+
+/// The message `msg` mapped to `[0, n)` without any bias, using the `siphash_c_d` 128-bit values with the key `key`.
+///
+/// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`). Panics if `n` is `0`.
+pub fn bucket<const C: u8, const D: u8, K>(key: K, msg: &[u8], n: u64) -> Result<u64, SipError>
+where
+    K: TryInto<SipHashKey, Error = SipError>,
+{
+    let mut prototype = SipHash::<C, D, Hash128>::new(key)?;
+    prototype.update(msg);
+
+    if n.is_power_of_two() {
+        return Ok(reduce(prototype.finalize() as u64, n));
+    }
+
+    // the message is only hashed once, the counters being added to copies of its state
+    let mut counter = 0u64;
+    let mut words = [0u64; 2];
+    let mut next = 2;
+    Ok(unbiased(n, || {
+        if next == 2 {
+            let mut siphash = prototype.clone();
+            if counter != 0 {
+                siphash.update(&counter.to_le_bytes());
+            }
+            let value = siphash.finalize();
+            words = [value as u64, (value >> 64) as u64];
+            counter += 1;
+            next = 0;
+        }
+        next += 1;
+        words[next - 1]
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::KEY;
+
+    #[test]
+    fn test_reduce() {
+        assert_eq!(reduce(0, 10), 0);
+        assert_eq!(reduce(u64::MAX, 10), 9);
+        assert_eq!(reduce(1 << 63, 10), 5);
+        assert_eq!(reduce(u64::MAX, 1), 0);
+        assert_eq!(reduce(u64::MAX, u64::MAX), u64::MAX - 1);
+
+        // the shifts of the powers of two are the products
+        let hash = 0x5abd8ea184320dd6;
+        for k in 0..64 {
+            let n = 1u64 << k;
+            assert_eq!(reduce(hash, n), ((hash as u128 * n as u128) >> 64) as u64);
+        }
+    }
+
+    #[test]
+    fn test_reduce128() {
+        assert_eq!(reduce128(0, 10), 0);
+        assert_eq!(reduce128(u128::MAX, 10), 9);
+        assert_eq!(reduce128(1 << 127, 10), 5);
+        assert_eq!(reduce128(u128::MAX, 1), 0);
+        assert_eq!(reduce128(u128::MAX, u64::MAX), u64::MAX - 1);
+        assert_eq!(reduce128(1 << 127, 1 << 40), 1 << 39);
+
+        // the high 64 bits give the same value, except for the carry of the low ones
+        let hash = 0xcd0780072af0d0f3_5abd8ea184320dd6;
+        for n in [3, 10, 1000, 1 << 20, u64::MAX / 3] {
+            let high = reduce((hash >> 64) as u64, n);
+            assert!(reduce128(hash, n) - high <= 1);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn test_empty_range() {
+        reduce(1, 0);
+    }
+
+    #[test]
+    fn test_unbiased() {
+        // 2^64 mod 3 = 1: only the low half 0 is rejected
+        let mut words = [0u64, u64::MAX].into_iter();
+        assert_eq!(unbiased(3, || words.next().unwrap()), 2);
+
+        let mut words = [1u64 << 63].into_iter();
+        assert_eq!(unbiased(3, || words.next().unwrap()), 1);
+    }
+
+    // the words drawn by bucket()
+    fn words(msg: &[u8], counter: u64) -> [u64; 2] {
+        let mut siphash = SipHash::<2, 4, Hash128>::new(&KEY).unwrap();
+        siphash.update(msg);
+        if counter != 0 {
+            siphash.update(&counter.to_le_bytes());
+        }
+        let value = siphash.finalize();
+        [value as u64, (value >> 64) as u64]
+    }
+
+    #[test]
+    fn test_bucket() {
+        let hash = SipHash::<2, 4, Hash128>::with_key(&KEY, b"bucket").unwrap();
+        assert_eq!(
+            bucket::<2, 4, _>(&KEY, b"bucket", 1000).unwrap(),
+            reduce(hash as u64, 1000)
+        );
+        assert_eq!(
+            bucket::<2, 4, _>(&KEY, b"bucket", 1 << 10).unwrap(),
+            reduce(hash as u64, 1 << 10)
+        );
+        assert!(bucket::<2, 4, _>(&KEY[..15], b"bucket", 10).is_err());
+
+        // half of the words are rejected: the counters are used
+        let n: u64 = (1 << 63) + 1;
+        let threshold = n.wrapping_neg() % n;
+        let mut counters = 0;
+        for i in 0..64u8 {
+            let msg = [i];
+            let expected = (0..)
+                .flat_map(|counter| words(&msg, counter))
+                .map(|word| word as u128 * n as u128)
+                .find(|m| *m as u64 >= threshold)
+                .unwrap();
+            assert_eq!(
+                bucket::<2, 4, _>(&KEY, &msg, n).unwrap(),
+                (expected >> 64) as u64
+            );
+
+            let [h1, h2] = words(&msg, 0);
+            if ((h1 as u128 * n as u128) as u64) < threshold
+                && ((h2 as u128 * n as u128) as u64) < threshold
+            {
+                counters += 1;
+            }
+        }
+        assert!(counters > 0);
+    }
+
+    #[test]
+    fn test_bucket_distribution() {
+        let mut counts = [0u32; 7];
+        for i in 0..7000u32 {
+            counts[bucket::<1, 3, _>(&KEY, &i.to_le_bytes(), 7).unwrap() as usize] += 1;
+        }
+        assert!(counts.iter().all(|&c| (850..1150).contains(&c)));
+    }
+}
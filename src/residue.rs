@@ -1,6 +1,6 @@
 use core::{iter::Iterator, slice::Iter};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy)]
 pub(crate) struct Residue {
     pub(crate) length: usize,
     pub(crate) data: [u8; 8],
@@ -0,0 +1,293 @@
+//! A deterministic, seekable random number generator using `siphash_c_d` in counter mode.
+//!
+//! The block `n` of the stream `s` is the 128-bit `SipHash<C, D, Hash128>` value of the 16 bytes made of the
+//! little-endian `s` and `n`, under the key of the generator. Each block gives two 64-bit words: its first 8
+//! little-endian bytes, then its last 8. The words are the output of [`SipRng::next_u64`], and the position of the
+//! generator is the index of the next word, so any part of a stream can be read again without going through the
+//! previous ones.
+//!
+//! The streams of the same key are independent: they can be given to threads or to the parts of a simulation. The
+//! output only depends on the key, the stream and the position, and not on the platform.
+//!
+//! With the `rand_core` feature, [`SipRng`] implements `RngCore` and `SeedableRng`, the seed being the key and the
+//! stream being `0`. It is not a cryptographically secure generator.
+//!
+//! ```rust
+//! use siphash_c_d::rng::SipRng;
+//!
+//! let mut rng = SipRng::<2, 4>::new(&[0u8; 16], 0).unwrap();
+//! let first = rng.next_u64();
+//! let die = rng.gen_range(1..7);
+//! assert!((1..7).contains(&die));
+//!
+//! let mut deck: Vec<u8> = (0..52).collect();
+//! rng.shuffle(&mut deck);
+//!
+//! // back to the start of the stream
+//! rng.seek(0);
+//! assert_eq!(rng.next_u64(), first);
+//! ```
+use core::ops::Range;
+
+use crate::{range::unbiased, Hash128, SipError, SipHash, SipHashKey};
+
+/// A generator of the words of a stream, under a key.
+#[derive(Clone)]
+pub struct SipRng<const C: u8, const D: u8> {
+    key: SipHashKey,
+    stream: u64,
+    position: u64,
+
+    // the block of the previous word, when the position is odd
+    block: Option<u128>,
+}
+
+impl<const C: u8, const D: u8> SipRng<C, D> {
+    /// The generator of the stream `stream` with the key `key`, at position `0`.
+    ///
+    /// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
+    pub fn new<K>(key: K, stream: u64) -> Result<Self, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        Ok(Self::from_key(key.try_into()?, stream))
+    }
+
+    fn from_key(key: SipHashKey, stream: u64) -> Self {
+        Self {
+            key,
+            stream,
+            position: 0,
+            block: None,
+        }
+    }
+
+    /// The generator of another stream with the same key, at position `0`.
+    pub fn substream(&self, stream: u64) -> Self {
+        Self::from_key(self.key, stream)
+    }
+
+    /// The stream of the generator.
+    pub fn stream(&self) -> u64 {
+        self.stream
+    }
+
+    /// The index of the next 64-bit word of the stream.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Move to the 64-bit word `position` of the stream.
+    pub fn seek(&mut self, position: u64) {
+        self.position = position;
+        self.block = None;
+    }
+
+    // the hash of the stream and the counter
+    fn block(&self, counter: u64) -> u128 {
+        let mut siphash = SipHash::<C, D, Hash128>::from_key(self.key);
+        siphash.update(&self.stream.to_le_bytes());
+        siphash.update(&counter.to_le_bytes());
+        siphash.finalize()
+    }
+
+    /// The next 64-bit word.
+    pub fn next_u64(&mut self) -> u64 {
+        let block = match self.block.take() {
+            Some(block) => block,
+            None => self.block(self.position / 2),
+        };
+
+        let word = if self.position % 2 == 0 {
+            self.block = Some(block);
+            block as u64
+        } else {
+            (block >> 64) as u64
+        };
+        self.position = self.position.wrapping_add(1);
+        word
+    }
+
+    /// The low 32 bits of the next 64-bit word.
+    pub fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    /// Fill `dest` with the little-endian bytes of the next words, the unused bytes of the last word being dropped.
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes()[..chunk.len()]);
+        }
+    }
+
+    /// A uniformly distributed value of `range`, without modulo bias, using Lemire's multiply-and-reject method.
+    ///
+    /// Panics if the range is empty.
+    pub fn gen_range(&mut self, range: Range<u64>) -> u64 {
+        assert!(range.start < range.end, "empty range");
+        range.start + unbiased(range.end - range.start, || self.next_u64())
+    }
+
+    /// Shuffle `slice` with the Fisher-Yates algorithm: from the last index `i` down to `1`, the element `i` is swapped
+    /// with the element `gen_range(0..i + 1)`.
+    pub fn shuffle<V>(&mut self, slice: &mut [V]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.gen_range(0..i as u64 + 1) as usize;
+            slice.swap(i, j);
+        }
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<const C: u8, const D: u8> rand_core::RngCore for SipRng<C, D> {
+    fn next_u32(&mut self) -> u32 {
+        SipRng::next_u32(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        SipRng::next_u64(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        SipRng::fill_bytes(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        SipRng::fill_bytes(self, dest);
+        Ok(())
+    }
+}
+
+/// The seed is the 16 bytes of the key, and the stream is `0`.
+#[cfg(feature = "rand_core")]
+impl<const C: u8, const D: u8> rand_core::SeedableRng for SipRng<C, D> {
+    type Seed = [u8; 16];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_key(SipHashKey::from_bytes(&seed), 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::KEY;
+
+    fn rng(stream: u64) -> SipRng<2, 4> {
+        SipRng::new(&KEY, stream).unwrap()
+    }
+
+    #[test]
+    fn test_words() {
+        // SipHash-2-4-128 of the little-endian stream and block counter, computed independently
+        let mut r = rng(0);
+        assert_eq!(r.next_u64(), 0x5abd8ea184320dd6);
+        assert_eq!(r.next_u64(), 0xcd0780072af0d0f3);
+        assert_eq!(r.position(), 2);
+
+        let mut r = rng(7);
+        r.seek(6);
+        assert_eq!(r.next_u64(), 0x107420440e21e30a);
+
+        let mut siphash = SipHash::<2, 4, Hash128>::new(&KEY).unwrap();
+        siphash.update(&[7, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0]);
+        let block = siphash.finalize();
+        assert_eq!(r.next_u64(), (block >> 64) as u64);
+    }
+
+    #[test]
+    fn test_seek() {
+        let mut r = rng(1);
+        let mut words = [0u64; 9];
+        for w in words.iter_mut() {
+            *w = r.next_u64();
+        }
+
+        for position in [0, 1, 4, 7, 8] {
+            r.seek(position);
+            for w in &words[position as usize..] {
+                assert_eq!(r.next_u64(), *w);
+            }
+        }
+
+        let mut bytes = [0u8; 13];
+        r.seek(3);
+        r.fill_bytes(&mut bytes);
+        assert_eq!(&bytes[..8], &words[3].to_le_bytes());
+        assert_eq!(&bytes[8..], &words[4].to_le_bytes()[..5]);
+        assert_eq!(r.position(), 5);
+        assert_eq!(r.next_u32(), words[5] as u32);
+    }
+
+    #[test]
+    fn test_substreams() {
+        let mut a = rng(0);
+        let mut b = a.substream(1);
+        assert_eq!(b.stream(), 1);
+        assert_ne!(a.next_u64(), b.next_u64());
+
+        let mut other = SipRng::<2, 4>::new((1, 2), 0).unwrap();
+        assert_ne!(rng(0).next_u64(), other.next_u64());
+        assert!(SipRng::<2, 4>::new(&KEY[..15], 0).is_err());
+    }
+
+    #[test]
+    fn test_gen_range() {
+        let mut r = rng(2);
+        let mut counts = [0u32; 6];
+        for _ in 0..6000 {
+            let v = r.gen_range(10..16);
+            assert!((10..16).contains(&v));
+            counts[(v - 10) as usize] += 1;
+        }
+        assert!(counts.iter().all(|&c| (850..1150).contains(&c)));
+
+        assert_eq!(r.gen_range(5..6), 5);
+        assert!(r.gen_range(0..u64::MAX) < u64::MAX);
+
+        // the high half of the product with the first word
+        let mut r = rng(0);
+        let expected = (0x5abd8ea184320dd6_u128 * 1000) >> 64;
+        assert_eq!(r.gen_range(0..1000), expected as u64);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn test_empty_range() {
+        rng(0).gen_range(3..3);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut values = [0u8; 32];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = i as u8;
+        }
+        let mut shuffled = values;
+        rng(3).shuffle(&mut shuffled);
+        assert_ne!(shuffled, values);
+
+        let mut again = values;
+        rng(3).shuffle(&mut again);
+        assert_eq!(again, shuffled);
+
+        shuffled.sort_unstable();
+        assert_eq!(shuffled, values);
+
+        let mut empty: [u8; 0] = [];
+        rng(3).shuffle(&mut empty);
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_rand_core() {
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut r = SipRng::<2, 4>::from_seed(KEY);
+        assert_eq!(RngCore::next_u64(&mut r), 0x5abd8ea184320dd6);
+
+        let mut bytes = [0u8; 8];
+        r.try_fill_bytes(&mut bytes).unwrap();
+        assert_eq!(u64::from_le_bytes(bytes), 0xcd0780072af0d0f3);
+    }
+}
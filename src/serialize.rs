@@ -0,0 +1,316 @@
+//! Serde support, enabled by the `serde` feature.
+//!
+//! The keys and the digests are serialized as lowercase hexadecimal strings in human-readable formats like JSON,
+//! and as bytes otherwise. A [`SipHash`] is serialized as a snapshot of its state, which can only be resumed with
+//! the same `c`, `d` and output size.
+use core::{convert::TryInto, fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, SeqAccess, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    hex, residue::Residue, state::State, Digest128, Digest64, HashOutput, SipHash, SipHashKey,
+};
+
+// the version of the snapshot format, to be increased when it changes
+const SNAPSHOT_VERSION: u8 = 1;
+
+// hexadecimal string or bytes depending on the format
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        let mut buffer = [0u8; 32];
+        serializer.serialize_str(hex::encode_hex(bytes, &mut buffer[..2 * bytes.len()]))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+fn deserialize_bytes<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor::<N>)
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor::<N>)
+    }
+}
+
+// N bytes from hexadecimal digits, bytes or a sequence of bytes
+struct BytesVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} bytes or {} hexadecimal digits", N, 2 * N)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        let mut bytes = [0u8; N];
+        hex::decode_hex(s, &mut bytes)
+            .ok_or_else(|| E::invalid_value(Unexpected::Str(s), &self))?;
+        Ok(bytes)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        bytes
+            .try_into()
+            .map_err(|_| E::invalid_length(bytes.len(), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0u8; N];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+        Ok(bytes)
+    }
+}
+
+/// The 16 bytes of the key, `k0` and `k1` being little-endian.
+impl Serialize for SipHashKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SipHashKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(deserializer).map(|bytes| SipHashKey::from_bytes(&bytes))
+    }
+}
+
+// the little-endian bytes of the digests, as their Display
+macro_rules! digest {
+    ($name:ident) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_bytes(&self.to_le_bytes(), serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_bytes(deserializer).map($name::from_le_bytes)
+            }
+        }
+    };
+}
+
+digest!(Digest64);
+digest!(Digest128);
+
+// the saved state of a SipHash, with the parameters it was created with
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    version: u8,
+    c: u8,
+    d: u8,
+    bits: u16,
+    state: [u64; 4],
+    residue: [u8; 8],
+    residue_length: u8,
+    length: u64,
+}
+
+/// A snapshot of the state of the algorithm, from which the hash can be resumed. It depends on the key, so it
+/// needs to be kept as secret as the key.
+impl<const C: u8, const D: u8, T: HashOutput> Serialize for SipHash<C, D, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            c: C,
+            d: D,
+            bits: T::BITS,
+            state: *self.state.lanes(),
+            residue: self.residue.data,
+            residue_length: self.residue.length as u8,
+            length: self.residue.total_length as u64,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Resume a snapshot, which fails if it wasn't taken with the same `c`, `d` and output size.
+impl<'de, const C: u8, const D: u8, T: HashOutput> Deserialize<'de> for SipHash<C, D, T> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let s = Snapshot::deserialize(deserializer)?;
+
+        if s.version != SNAPSHOT_VERSION {
+            return Err(de::Error::custom(format_args!(
+                "snapshot version {} is not supported, only {} is",
+                s.version, SNAPSHOT_VERSION
+            )));
+        }
+        if (s.c, s.d, s.bits) != (C, D, T::BITS) {
+            return Err(de::Error::custom(format_args!(
+                "snapshot of siphash_{}_{} ({} bits) can't be resumed as siphash_{}_{} ({} bits)",
+                s.c,
+                s.d,
+                s.bits,
+                C,
+                D,
+                T::BITS
+            )));
+        }
+
+        // the residue bytes are the last ones of the message
+        let length: usize = s
+            .length
+            .try_into()
+            .map_err(|_| de::Error::custom("snapshot message length is too large"))?;
+
+        // a full residue is always compressed, and the bytes after the residue are zeroes
+        let residue_length = s.residue_length as usize;
+        if residue_length >= 8 || residue_length > length || (length - residue_length) % 8 != 0 {
+            return Err(de::Error::custom(
+                "snapshot residue is inconsistent with the message length",
+            ));
+        }
+        if s.residue[residue_length..].iter().any(|b| *b != 0) {
+            return Err(de::Error::custom("snapshot residue padding is not zero"));
+        }
+
+        Ok(Self {
+            state: State::from_lanes(s.state),
+            residue: Residue {
+                length: residue_length,
+                data: s.residue,
+                total_length: length,
+            },
+            output: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::{vectors, Hash128, Hash64, SipHash24};
+    use std::{string::String, vec::Vec};
+
+    const KEY_HEX: &str = "\"000102030405060708090a0b0c0d0e0f\"";
+
+    #[test]
+    fn test_key() {
+        let key = SipHashKey::from_bytes(&vectors::KEY);
+
+        assert_eq!(serde_json::to_string(&key).unwrap(), KEY_HEX);
+        assert_eq!(serde_json::from_str::<SipHashKey>(KEY_HEX).unwrap(), key);
+        assert_eq!(
+            serde_json::from_str::<SipHashKey>(&KEY_HEX.to_uppercase()).unwrap(),
+            key
+        );
+        assert!(serde_json::from_str::<SipHashKey>("\"000102\"").is_err());
+        assert!(
+            serde_json::from_str::<SipHashKey>("\"000102030405060708090a0b0c0d0e0g\"").is_err()
+        );
+
+        // the bytes, after their u64 length
+        let bytes = bincode::serialize(&key).unwrap();
+        assert_eq!(&bytes[8..], &vectors::KEY);
+        assert_eq!(bincode::deserialize::<SipHashKey>(&bytes).unwrap(), key);
+        assert!(bincode::deserialize::<SipHashKey>(&bytes[..20]).is_err());
+    }
+
+    #[test]
+    fn test_digests() {
+        let d64 = SipHash24::digest(&vectors::KEY, b"digest").unwrap();
+        let d128 = SipHash::<2, 4, Hash128>::digest(&vectors::KEY, b"digest").unwrap();
+
+        let json = serde_json::to_string(&d64).unwrap();
+        assert_eq!(json, std::format!("\"{}\"", d64));
+        assert_eq!(serde_json::from_str::<Digest64>(&json).unwrap(), d64);
+
+        let json = serde_json::to_string(&d128).unwrap();
+        assert_eq!(json, std::format!("\"{}\"", d128));
+        assert_eq!(serde_json::from_str::<Digest128>(&json).unwrap(), d128);
+        assert!(serde_json::from_str::<Digest64>(&json).is_err());
+
+        let bytes = bincode::serialize(&d128).unwrap();
+        assert_eq!(&bytes[8..], &d128.to_le_bytes());
+        assert_eq!(bincode::deserialize::<Digest128>(&bytes).unwrap(), d128);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let msg: Vec<u8> = (0..64).collect();
+
+        // with an empty residue, after a compression or not, and a partly filled one
+        for split in [0, 1, 7, 8, 9, 16, 30] {
+            let mut siphash = SipHash::<1, 3, Hash128>::new(&vectors::KEY).unwrap();
+            siphash.update(&msg[..split]);
+
+            let json = serde_json::to_string(&siphash).unwrap();
+            let mut resumed: SipHash<1, 3, Hash128> = serde_json::from_str(&json).unwrap();
+            resumed.update(&msg[split..63]);
+            assert_eq!(
+                resumed.finalize().to_le_bytes(),
+                vectors::SIPHASH_1_3_128[63]
+            );
+
+            let bytes = bincode::serialize(&siphash).unwrap();
+            let mut resumed: SipHash<1, 3, Hash128> = bincode::deserialize(&bytes).unwrap();
+            resumed.update(&msg[split..63]);
+            assert_eq!(
+                resumed.finalize().to_le_bytes(),
+                vectors::SIPHASH_1_3_128[63]
+            );
+        }
+    }
+
+    #[test]
+    fn test_snapshot_checks() {
+        let mut siphash = SipHash24::new(&vectors::KEY).unwrap();
+        siphash.update(b"snapshot");
+        let json = serde_json::to_string(&siphash).unwrap();
+
+        assert!(serde_json::from_str::<SipHash24>(&json).is_ok());
+        assert!(serde_json::from_str::<SipHash<1, 3, Hash64>>(&json).is_err());
+        assert!(serde_json::from_str::<SipHash<2, 4, Hash128>>(&json).is_err());
+
+        let err = serde_json::from_str::<SipHash<2, 4, Hash128>>(&json)
+            .err()
+            .unwrap();
+        assert!(std::format!("{}", err).contains("siphash_2_4 (64 bits)"));
+
+        let replace = |from: &str, to: &str| -> String { json.replacen(from, to, 1) };
+        assert!(
+            serde_json::from_str::<SipHash24>(&replace("\"version\":1", "\"version\":2")).is_err()
+        );
+        assert!(
+            serde_json::from_str::<SipHash24>(&replace("\"length\":8", "\"length\":9")).is_err()
+        );
+        for residue_length in [
+            "\"residue_length\":3",
+            "\"residue_length\":8",
+            "\"residue_length\":9",
+        ] {
+            let json = replace("\"residue_length\":0", residue_length);
+            assert!(serde_json::from_str::<SipHash24>(&json).is_err());
+        }
+        let json = replace(
+            "\"residue\":[0,0,0,0,0,0,0,0]",
+            "\"residue\":[0,0,0,0,0,0,0,1]",
+        );
+        assert!(serde_json::from_str::<SipHash24>(&json).is_err());
+
+        // the padding of a partly filled residue
+        let mut siphash = SipHash24::new(&vectors::KEY).unwrap();
+        siphash.update(b"snap");
+        let json = serde_json::to_string(&siphash).unwrap();
+        assert!(json.contains("\"residue\":[115,110,97,112,0,0,0,0]"));
+        assert!(serde_json::from_str::<SipHash24>(&json).is_ok());
+        let json = json.replacen("112,0,0,0,0]", "112,0,0,1,0]", 1);
+        assert!(serde_json::from_str::<SipHash24>(&json).is_err());
+    }
+}
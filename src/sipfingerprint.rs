@@ -0,0 +1,291 @@
+//! Stable structural fingerprints, the encoding of the `SipFingerprint` derive macro.
+use crate::{HashOutput, SipError, SipHash, SipHash24, SipHashKey};
+
+// the version of the encoding, which is hashed first by sip_fingerprint(): it is only changed when the encoding of
+// a type changes, so that the fingerprints can be persisted
+pub(crate) const ENCODING_VERSION: u8 = 1;
+
+/// A value with a stable and injective encoding, unlike `Hash` whose output can change between Rust versions and
+/// which is not prefix-free:
+///
+/// * the integers are little-endian, `usize` and `isize` being encoded as `u64` and `i64`;
+/// * the floats are their bits, and the chars their `u32` value;
+/// * the strings and slices are prefixed by their `u64` length, but not the arrays;
+/// * `Option` and `Result` are prefixed by a `0` or `1` byte.
+///
+/// With the `derive` feature, `#[derive(SipFingerprint)]` implements it for structs and enums, which are encoded by
+/// their `u32` version, their name, the name of the variant for enums, their `u64` number of fields and the name and
+/// value of each field. The following attributes are available:
+///
+/// * `#[sip(rename = "name")]` on the type, a variant or a field, to keep the fingerprints after a renaming;
+/// * `#[sip(skip)]` on a field, which is not part of the fingerprint;
+/// * `#[sip(version = 1)]` on the type, to change all its fingerprints on purpose (the default version is 0).
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use siphash_c_d::SipFingerprint;
+///
+/// #[derive(SipFingerprint)]
+/// #[sip(rename = "Config")]
+/// struct Settings<'a> {
+///     #[sip(rename = "name")]
+///     service: &'a str,
+///     retries: u8,
+///     #[sip(skip)]
+///     cache: Vec<u8>,
+/// }
+///
+/// let settings = Settings { service: "db", retries: 3, cache: Vec::new() };
+/// let fingerprint = settings.sip_fingerprint(&[0u8; 16]).unwrap();
+/// # }
+/// ```
+pub trait SipFingerprint {
+    /// Add the encoding of the value to the message being hashed by `siphash`.
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>);
+
+    /// The `siphash_2_4` 64-bit fingerprint of the value using the key `key`. The version of the encoding (`1`) is
+    /// hashed first.
+    fn sip_fingerprint<K>(&self, key: K) -> Result<u64, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        let mut siphash = SipHash24::new(key)?;
+        siphash.update(&[ENCODING_VERSION]);
+        self.fingerprint(&mut siphash);
+        Ok(siphash.finalize())
+    }
+}
+
+// the little-endian bytes of the integers and floats
+macro_rules! le_bytes {
+    ($($type:ty $(as $as:ty)?),+) => {
+        $(
+            impl SipFingerprint for $type {
+                fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+                    siphash.update(&(*self $(as $as)?).to_le_bytes());
+                }
+            }
+        )+
+    };
+}
+
+le_bytes!(u8, u16, u32, u64, u128, usize as u64);
+le_bytes!(i8, i16, i32, i64, i128, isize as i64);
+
+impl SipFingerprint for f32 {
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+        self.to_bits().fingerprint(siphash);
+    }
+}
+
+impl SipFingerprint for f64 {
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+        self.to_bits().fingerprint(siphash);
+    }
+}
+
+impl SipFingerprint for bool {
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+        (*self as u8).fingerprint(siphash);
+    }
+}
+
+impl SipFingerprint for char {
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+        (*self as u32).fingerprint(siphash);
+    }
+}
+
+impl SipFingerprint for str {
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+        self.len().fingerprint(siphash);
+        siphash.update(self.as_bytes());
+    }
+}
+
+impl<V: SipFingerprint> SipFingerprint for [V] {
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+        self.len().fingerprint(siphash);
+        for v in self {
+            v.fingerprint(siphash);
+        }
+    }
+}
+
+// the length is part of the type
+impl<V: SipFingerprint, const N: usize> SipFingerprint for [V; N] {
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+        for v in self {
+            v.fingerprint(siphash);
+        }
+    }
+}
+
+impl<V: SipFingerprint> SipFingerprint for Option<V> {
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+        match self {
+            None => 0u8.fingerprint(siphash),
+            Some(v) => {
+                1u8.fingerprint(siphash);
+                v.fingerprint(siphash);
+            }
+        }
+    }
+}
+
+impl<V: SipFingerprint, E: SipFingerprint> SipFingerprint for Result<V, E> {
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+        match self {
+            Ok(v) => {
+                0u8.fingerprint(siphash);
+                v.fingerprint(siphash);
+            }
+            Err(e) => {
+                1u8.fingerprint(siphash);
+                e.fingerprint(siphash);
+            }
+        }
+    }
+}
+
+impl<V: SipFingerprint + ?Sized> SipFingerprint for &V {
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+        (**self).fingerprint(siphash);
+    }
+}
+
+impl<V: SipFingerprint + ?Sized> SipFingerprint for &mut V {
+    fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+        (**self).fingerprint(siphash);
+    }
+}
+
+// the elements in order
+macro_rules! tuple {
+    ($(($($name:ident),*))+) => {
+        $(
+            impl<$($name: SipFingerprint),*> SipFingerprint for ($($name,)*) {
+                #[allow(non_snake_case, unused_variables)]
+                fn fingerprint<const C: u8, const D: u8, T: HashOutput>(&self, siphash: &mut SipHash<C, D, T>) {
+                    let ($($name,)*) = self;
+                    $($name.fingerprint(siphash);)*
+                }
+            }
+        )+
+    };
+}
+
+tuple! {
+    ()
+    (A)
+    (A, B)
+    (A, B, E)
+    (A, B, E, F)
+    (A, B, E, F, G)
+    (A, B, E, F, G, H)
+    (A, B, E, F, G, H, I)
+    (A, B, E, F, G, H, I, J)
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use alloc::{boxed::Box, string::String, vec::Vec};
+
+    use super::SipFingerprint;
+    use crate::{HashOutput, SipHash};
+
+    impl SipFingerprint for String {
+        fn fingerprint<const C: u8, const D: u8, T: HashOutput>(
+            &self,
+            siphash: &mut SipHash<C, D, T>,
+        ) {
+            self.as_str().fingerprint(siphash);
+        }
+    }
+
+    impl<V: SipFingerprint> SipFingerprint for Vec<V> {
+        fn fingerprint<const C: u8, const D: u8, T: HashOutput>(
+            &self,
+            siphash: &mut SipHash<C, D, T>,
+        ) {
+            self.as_slice().fingerprint(siphash);
+        }
+    }
+
+    impl<V: SipFingerprint + ?Sized> SipFingerprint for Box<V> {
+        fn fingerprint<const C: u8, const D: u8, T: HashOutput>(
+            &self,
+            siphash: &mut SipHash<C, D, T>,
+        ) {
+            (**self).fingerprint(siphash);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::KEY;
+
+    // the hash of the encoding version followed by the bytes
+    fn expected(bytes: &[u8]) -> u64 {
+        let mut siphash = SipHash24::new(&KEY).unwrap();
+        siphash.update(&[ENCODING_VERSION]);
+        siphash.update(bytes);
+        siphash.finalize()
+    }
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(0x0102_u16.sip_fingerprint(&KEY).unwrap(), expected(&[2, 1]));
+        assert_eq!(
+            1_usize.sip_fingerprint(&KEY).unwrap(),
+            expected(&[1, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            (-1_isize).sip_fingerprint(&KEY).unwrap(),
+            expected(&[0xFF; 8])
+        );
+        assert_eq!(true.sip_fingerprint(&KEY).unwrap(), expected(&[1]));
+        assert_eq!(
+            'é'.sip_fingerprint(&KEY).unwrap(),
+            expected(&[0xE9, 0, 0, 0])
+        );
+        assert_eq!(
+            1.0_f32.sip_fingerprint(&KEY).unwrap(),
+            expected(&[0, 0, 0x80, 0x3F])
+        );
+        assert_eq!(
+            "ab".sip_fingerprint(&KEY).unwrap(),
+            expected(&[2, 0, 0, 0, 0, 0, 0, 0, b'a', b'b'])
+        );
+        assert_eq!([1u8, 2].sip_fingerprint(&KEY).unwrap(), expected(&[1, 2]));
+        assert_eq!(
+            [1u8, 2][..].sip_fingerprint(&KEY).unwrap(),
+            expected(&[2, 0, 0, 0, 0, 0, 0, 0, 1, 2])
+        );
+        assert_eq!(
+            (Some(3u8), None::<u8>).sip_fingerprint(&KEY).unwrap(),
+            expected(&[1, 3, 0])
+        );
+        assert_eq!(
+            Err::<u8, i8>(-1).sip_fingerprint(&KEY).unwrap(),
+            expected(&[1, 0xFF])
+        );
+        assert_eq!(().sip_fingerprint(&KEY).unwrap(), expected(&[]));
+    }
+
+    #[test]
+    fn test_prefix_free() {
+        assert_ne!(
+            ("ab", "c").sip_fingerprint(&KEY).unwrap(),
+            ("a", "bc").sip_fingerprint(&KEY).unwrap()
+        );
+        assert_ne!(
+            (&[1u8][..], &[2u8, 3][..]).sip_fingerprint(&KEY).unwrap(),
+            (&[1u8, 2][..], &[3u8][..]).sip_fingerprint(&KEY).unwrap()
+        );
+        assert!("".sip_fingerprint(&KEY[..4]).is_err());
+    }
+}
@@ -1,6 +1,14 @@
-use core::marker::PhantomData;
-
-use crate::{iter::MessageChunk, residue::Residue, siphashkey::SipHashKey, state::State, SipError};
+use core::{fmt, hash::Hash, marker::PhantomData, str::FromStr};
+
+use crate::{
+    iter::{slice_to_u64, MessageChunk},
+    nocase,
+    residue::Residue,
+    siphashkey::SipHashKey,
+    state::State,
+    trace::{Observer, Step},
+    Digest128, Digest64, SipError,
+};
 
 /// Defines a 64-bit hash calculation.
 pub struct Hash64;
@@ -8,6 +16,101 @@ pub struct Hash64;
 /// Defines a 128-bit hash calculation.
 pub struct Hash128;
 
+/// The output size of the `siphash_c_d` calculation: either [`Hash64`] or [`Hash128`].
+///
+/// This trait is sealed and can't be implemented outside this crate.
+pub trait HashOutput: private::Sealed + Sized {
+    /// The hash value: `u64` or `u128`.
+    type Value: Copy + Eq + fmt::Debug + fmt::LowerHex;
+
+    /// The little-endian bytes of the hash value: `[u8; 8]` or `[u8; 16]`.
+    type Bytes: Copy + Eq + Default + fmt::Debug + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// The typed hash value: [`Digest64`] or [`Digest128`].
+    type Digest: Copy
+        + Eq
+        + Ord
+        + Hash
+        + Default
+        + fmt::Debug
+        + fmt::Display
+        + FromStr<Err = SipError>
+        + From<Self::Value>;
+
+    /// The number of bits of the hash value.
+    const BITS: u16;
+
+    /// Convert the hash value to its little-endian bytes, as written in the `out[]` buffer of the
+    /// reference implementation.
+    fn to_le_bytes(value: Self::Value) -> Self::Bytes;
+
+    // the 128-bit version is xoring an additional constant to the initial state
+    #[doc(hidden)]
+    fn initialization<const C: u8, const D: u8>(siphash: &mut SipHash<C, D, Self>);
+
+    // called once the last message block has been compressed
+    #[doc(hidden)]
+    fn finalization<const C: u8, const D: u8, O: Observer>(
+        siphash: &mut SipHash<C, D, Self>,
+        observer: &mut O,
+    ) -> Self::Value;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Hash64 {}
+    impl Sealed for super::Hash128 {}
+}
+
+impl HashOutput for Hash64 {
+    type Value = u64;
+    type Bytes = [u8; 8];
+    type Digest = Digest64;
+    const BITS: u16 = 64;
+
+    fn to_le_bytes(value: u64) -> [u8; 8] {
+        value.to_le_bytes()
+    }
+
+    fn initialization<const C: u8, const D: u8>(_siphash: &mut SipHash<C, D, Self>) {}
+
+    fn finalization<const C: u8, const D: u8, O: Observer>(
+        siphash: &mut SipHash<C, D, Self>,
+        observer: &mut O,
+    ) -> u64 {
+        siphash.state.finalization(2, 0xFF, observer)
+    }
+}
+
+impl HashOutput for Hash128 {
+    type Value = u128;
+    type Bytes = [u8; 16];
+    type Digest = Digest128;
+    const BITS: u16 = 128;
+
+    fn to_le_bytes(value: u128) -> [u8; 16] {
+        value.to_le_bytes()
+    }
+
+    fn initialization<const C: u8, const D: u8>(siphash: &mut SipHash<C, D, Self>) {
+        // additional step for 128
+        siphash.state.hash128_additional();
+    }
+
+    fn finalization<const C: u8, const D: u8, O: Observer>(
+        siphash: &mut SipHash<C, D, Self>,
+        observer: &mut O,
+    ) -> u128 {
+        let u0 = siphash.state.finalization(2, 0xEE, observer) as u128;
+
+        // additional step for 128
+        let u1 = siphash.state.finalization(1, 0xDD, observer) as u128;
+
+        u1 << 64_u128 | u0
+    }
+}
+
 //#[derive(Debug, Default)]
 /// The generic `siphash_c_d` structure which is keeping the internal state of the algorithm.
 pub struct SipHash<const C: u8, const D: u8, T> {
@@ -18,7 +121,18 @@ pub struct SipHash<const C: u8, const D: u8, T> {
     pub(crate) residue: Residue,
 
     // need this because no T is passed
-    output: PhantomData<T>,
+    pub(crate) output: PhantomData<T>,
+}
+
+// a derive would require T: Clone
+impl<const C: u8, const D: u8, T> Clone for SipHash<C, D, T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state,
+            residue: self.residue,
+            output: PhantomData,
+        }
+    }
 }
 
 impl<const C: u8, const D: u8> SipHash<C, D, Hash64> {
@@ -31,7 +145,7 @@ impl<const C: u8, const D: u8> SipHash<C, D, Hash64> {
     {
         let mut siphash = SipHash::<C, D, Hash64>::new(key)?;
         siphash.compression(msg);
-        Ok(siphash.state.finalization(2, 0xFF))
+        Ok(Hash64::finalization(&mut siphash, &mut ()))
     }
 }
 
@@ -44,22 +158,12 @@ impl<const C: u8, const D: u8> SipHash<C, D, Hash128> {
         K: TryInto<SipHashKey, Error = SipError>,
     {
         let mut siphash = SipHash::<C, D, Hash128>::new(key)?;
-
-        // additional step for 128
-        siphash.state.hash128_additional();
-
         siphash.compression(msg);
-
-        let u0 = siphash.state.finalization(2, 0xEE) as u128;
-
-        // additional step for 128
-        let u1 = siphash.state.finalization(1, 0xDD) as u128;
-
-        Ok(u1 << 64_u128 | u0)
+        Ok(Hash128::finalization(&mut siphash, &mut ()))
     }
 }
 
-impl<const C: u8, const D: u8, T> SipHash<C, D, T> {
+impl<const C: u8, const D: u8, T: HashOutput> SipHash<C, D, T> {
     /// Assign the key for the `siphash_c_d` calculation.
     ///
     /// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
@@ -67,13 +171,88 @@ impl<const C: u8, const D: u8, T> SipHash<C, D, T> {
     where
         K: TryInto<SipHashKey, Error = SipError>,
     {
-        let k = key.try_into()?;
+        Ok(Self::from_key(key.try_into()?))
+    }
 
-        Ok(Self {
+    /// Assign the key and absorb the personalization string `context`, so that the hash values of different contexts
+    /// are unrelated. The result is a prototype to be cloned for each message.
+    ///
+    /// The constant `0xcc` is xored to `v1` after the initialization, so that no message hashed without a context
+    /// gives the same value. The context is then added as its `u64` little-endian length, its bytes and the zero
+    /// bytes padding them to a multiple of 8 bytes, which are all counted in the message length, so the prototype
+    /// keeps no residue.
+    ///
+    /// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
+    ///
+    /// ```rust
+    /// use siphash_c_d::SipHash24;
+    ///
+    /// let key = [0u8; 16];
+    /// let cache = SipHash24::with_context(&key, b"cache keys").unwrap();
+    /// let signing = SipHash24::with_context(&key, b"request signing").unwrap();
+    ///
+    /// let mut siphash = cache.clone();
+    /// siphash.update(b"/index.html");
+    /// let tag = siphash.finalize();
+    ///
+    /// let mut siphash = signing.clone();
+    /// siphash.update(b"/index.html");
+    /// assert_ne!(siphash.finalize(), tag);
+    /// ```
+    pub fn with_context<K>(key: K, context: &[u8]) -> Result<Self, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        let mut siphash = Self::new(key)?;
+        siphash.state.context_additional();
+        siphash.update(&(context.len() as u64).to_le_bytes());
+        siphash.update(context);
+        siphash.update(&[0u8; 8][..(8 - context.len() % 8) % 8]);
+
+        Ok(siphash)
+    }
+
+    // the conversion to the key can't fail
+    pub(crate) fn from_key(k: SipHashKey) -> Self {
+        let mut siphash = Self {
             state: State::new(k.0, k.1),
             residue: Residue::default(),
             output: PhantomData,
-        })
+        };
+        T::initialization(&mut siphash);
+
+        siphash
+    }
+
+    /// Calculate the `siphash_c_d` value of the message `msg` using the key `key`, as a typed digest.
+    ///
+    /// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
+    pub fn digest<K>(key: K, msg: &[u8]) -> Result<T::Digest, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        let mut siphash = Self::new(key)?;
+        siphash.compression(msg);
+        Ok(T::finalization(&mut siphash, &mut ()).into())
+    }
+
+    /// Calculate the `siphash_c_d` value of the message `msg` using the key `key`, reporting each step of
+    /// the algorithm to `observer`.
+    ///
+    /// See the [`trace`](crate::trace) module.
+    pub fn with_key_traced<K, O>(key: K, msg: &[u8], observer: &mut O) -> Result<T::Value, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+        O: Observer,
+    {
+        let mut siphash = Self::new(key)?;
+        observer.observe(Step::Initialization, siphash.state.lanes());
+
+        for m_i in &MessageChunk(msg) {
+            siphash.state.compress_chunk_observed(m_i, observer);
+        }
+
+        Ok(T::finalization(&mut siphash, observer))
     }
 
     // as described in the paper
@@ -86,6 +265,149 @@ impl<const C: u8, const D: u8, T> SipHash<C, D, T> {
             self.state.compress_chunk(m_i);
         }
     }
+
+    /// Calculate the `siphash_c_d` value of the message `msg` using the key `key`, as the bytes written in the
+    /// `out[]` buffer of the reference implementation: 8 bytes for [`Hash64`], 16 bytes for [`Hash128`].
+    ///
+    /// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
+    ///
+    /// ```rust
+    /// use siphash_c_d::{Hash64, SipHash};
+    ///
+    /// let msg: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+    /// let out = SipHash::<2, 4, Hash64>::with_key_bytes((0x0706050403020100, 0x0f0e0d0c0b0a0908), msg).unwrap();
+    ///
+    /// assert_eq!(out, [0xe5, 0x45, 0xbe, 0x49, 0x61, 0xca, 0x29, 0xa1]);
+    /// ```
+    pub fn with_key_bytes<K>(key: K, msg: &[u8]) -> Result<T::Bytes, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        let mut siphash = Self::new(key)?;
+        siphash.compression(msg);
+        Ok(T::to_le_bytes(T::finalization(&mut siphash, &mut ())))
+    }
+
+    /// Calculate the `siphash_c_d` value of the concatenation of the buffers `bufs` using the key `key`, without
+    /// copying them: the blocks are assembled across the buffer boundaries.
+    ///
+    /// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
+    ///
+    /// ```rust
+    /// use siphash_c_d::SipHash24;
+    ///
+    /// let key = (0x0706050403020100, 0x0f0e0d0c0b0a0908);
+    /// let hash = SipHash24::with_key_vectored(key, &[&[0, 1, 2], &[3, 4, 5, 6, 7, 8, 9], &[10, 11, 12, 13, 14]]).unwrap();
+    ///
+    /// assert_eq!(hash, 0xa129ca6149be45e5);
+    /// ```
+    pub fn with_key_vectored<K>(key: K, bufs: &[&[u8]]) -> Result<T::Value, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        let mut siphash = Self::new(key)?;
+        siphash.update_vectored(bufs);
+        Ok(siphash.finalize())
+    }
+
+    /// Calculate the `siphash_c_d` value of the message `msg` using the key `key`, the ASCII uppercase letters of
+    /// `msg` being hashed as lowercase letters.
+    ///
+    /// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
+    pub fn with_key_nocase<K>(key: K, msg: &[u8]) -> Result<T::Value, SipError>
+    where
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        let mut siphash = Self::new(key)?;
+        siphash.update_nocase(msg);
+        Ok(siphash.finalize())
+    }
+
+    /// Add `bytes` to the message being hashed, the ASCII uppercase letters being added as lowercase letters.
+    pub fn update_nocase(&mut self, bytes: &[u8]) {
+        nocase::for_each_lowercase(bytes, |lowercase| self.update(lowercase));
+    }
+
+    /// Add the concatenation of the buffers `bufs` to the message being hashed.
+    pub fn update_vectored(&mut self, bufs: &[&[u8]]) {
+        for buf in bufs {
+            self.update(buf);
+        }
+    }
+
+    /// Add `bytes` to the message being hashed.
+    pub fn update(&mut self, bytes: &[u8]) {
+        // keep the total length updated
+        self.residue.total_length += bytes.len();
+
+        // try to fill the residue
+        let added = self.residue.push(bytes.iter());
+
+        if self.residue.is_full() {
+            let m_i = slice_to_u64(&self.residue.data);
+            // the total length is kept
+            self.residue.length = 0;
+            self.residue.data = [0; 8];
+            self.state.compress_chunk(m_i);
+
+            // now read exact 8 bytes
+            let mut iter_chunk = bytes[added..].chunks_exact(8);
+            for block_i in iter_chunk.by_ref() {
+                // convert block to little endian u64
+                let m_i = slice_to_u64(block_i);
+                self.state.compress_chunk(m_i);
+            }
+
+            // the remaining bytes (less than 8 bytes) are kept in the residue
+            self.residue.push(iter_chunk.remainder().iter());
+        }
+    }
+
+    /// The hash value of the bytes added so far.
+    pub fn finalize(&self) -> T::Value {
+        // as self is not passed as mutable, need to copy the state to finalize the algorithm
+        let mut siphash = Self {
+            state: self.state,
+            residue: Residue::default(),
+            output: PhantomData,
+        };
+
+        // manage the residue which is the last block
+        let mut last_block = self.residue.data;
+        last_block[7] = (self.residue.total_length % 256) as u8;
+        let m_i = slice_to_u64(&last_block);
+        siphash.state.compress_chunk(m_i);
+
+        T::finalization(&mut siphash, &mut ())
+    }
+
+    /// Write the hash value of the bytes added so far to `out`, as the `out[]` buffer of the reference
+    /// implementation.
+    ///
+    /// If the length of `out` is not 8 bytes for [`Hash64`] or 16 bytes for [`Hash128`], returns an
+    /// error (`SipError::InvalidOutputLength`).
+    ///
+    /// ```rust
+    /// use siphash_c_d::{Hash128, SipHash};
+    ///
+    /// let key: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    /// let siphash = SipHash::<2, 4, Hash128>::new(key).unwrap();
+    ///
+    /// let mut out = [0u8; 16];
+    /// siphash.finalize_into(&mut out).unwrap();
+    /// assert_eq!(out[..4], [0xa3, 0x81, 0x7f, 0x04]);
+    ///
+    /// assert!(siphash.finalize_into(&mut [0u8; 8]).is_err());
+    /// ```
+    pub fn finalize_into(&self, out: &mut [u8]) -> Result<(), SipError> {
+        let bytes = T::to_le_bytes(self.finalize());
+        if out.len() != bytes.as_ref().len() {
+            return Err(SipError::InvalidOutputLength(out.len()));
+        }
+
+        out.copy_from_slice(bytes.as_ref());
+        Ok(())
+    }
 }
 
 /// The `siphash_2_4` 64-bit hash calculation.
@@ -95,6 +417,7 @@ pub type SipHash24 = SipHash<2, 4, Hash64>;
 pub type SipHash48 = SipHash<4, 8, Hash64>;
 
 #[cfg(test)]
+#[allow(clippy::needless_borrow)]
 mod tests {
     use super::*;
 
@@ -138,7 +461,7 @@ mod tests {
         let msg = "\x00\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0A\x0B\x0C\x0D\x0E".as_bytes();
         assert_eq!(msg.len(), 15);
 
-        let siphash_2_4 = SipHash24::with_key(key, msg).unwrap();
+        let siphash_2_4 = SipHash24::with_key(key, &msg).unwrap();
         assert_eq!(siphash_2_4, 0xa129ca6149be45e5);
     }
 
@@ -147,72 +470,7 @@ mod tests {
     fn test_sample2() {
         let key: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
 
-        const EXPECTED: [u64; 64] = [
-            0x726fdb47dd0e0e31,
-            0x74f839c593dc67fd,
-            0x0d6c8009d9a94f5a,
-            0x85676696d7fb7e2d,
-            0xcf2794e0277187b7,
-            0x18765564cd99a68d,
-            0xcbc9466e58fee3ce,
-            0xab0200f58b01d137,
-            0x93f5f5799a932462,
-            0x9e0082df0ba9e4b0,
-            0x7a5dbbc594ddb9f3,
-            0xf4b32f46226bada7,
-            0x751e8fbc860ee5fb,
-            0x14ea5627c0843d90,
-            0xf723ca908e7af2ee,
-            0xa129ca6149be45e5,
-            0x3f2acc7f57c29bdb,
-            0x699ae9f52cbe4794,
-            0x4bc1b3f0968dd39c,
-            0xbb6dc91da77961bd,
-            0xbed65cf21aa2ee98,
-            0xd0f2cbb02e3b67c7,
-            0x93536795e3a33e88,
-            0xa80c038ccd5ccec8,
-            0xb8ad50c6f649af94,
-            0xbce192de8a85b8ea,
-            0x17d835b85bbb15f3,
-            0x2f2e6163076bcfad,
-            0xde4daaaca71dc9a5,
-            0xa6a2506687956571,
-            0xad87a3535c49ef28,
-            0x32d892fad841c342,
-            0x7127512f72f27cce,
-            0xa7f32346f95978e3,
-            0x12e0b01abb051238,
-            0x15e034d40fa197ae,
-            0x314dffbe0815a3b4,
-            0x027990f029623981,
-            0xcadcd4e59ef40c4d,
-            0x9abfd8766a33735c,
-            0x0e3ea96b5304a7d0,
-            0xad0c42d6fc585992,
-            0x187306c89bc215a9,
-            0xd4a60abcf3792b95,
-            0xf935451de4f21df2,
-            0xa9538f0419755787,
-            0xdb9acddff56ca510,
-            0xd06c98cd5c0975eb,
-            0xe612a3cb9ecba951,
-            0xc766e62cfcadaf96,
-            0xee64435a9752fe72,
-            0xa192d576b245165a,
-            0x0a8787bf8ecb74b2,
-            0x81b3e73d20b49b6f,
-            0x7fa8220ba3b2ecea,
-            0x245731c13ca42499,
-            0xb78dbfaf3a8d83bd,
-            0xea1ad565322a1a0b,
-            0x60e61c23a3795013,
-            0x6606d7e446282b93,
-            0x6ca4ecb15c5f91e1,
-            0x9f626da15c9625f3,
-            0xe51b38608ef25f57,
-            0x958a324ceb064572,
-        ];
+        const EXPECTED: [u64; 64] = crate::vectors::SIPHASH_2_4_64;
 
         // no Vec in no_std
         let mut msg = [0u8; 64];
@@ -228,264 +486,7 @@ mod tests {
     fn test_siphash128() {
         let key: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
 
-        const EXPECTED: [[u8; 16]; 64] = [
-            [
-                0xa3, 0x81, 0x7f, 0x04, 0xba, 0x25, 0xa8, 0xe6, 0x6d, 0xf6, 0x72, 0x14, 0xc7, 0x55,
-                0x02, 0x93,
-            ],
-            [
-                0xda, 0x87, 0xc1, 0xd8, 0x6b, 0x99, 0xaf, 0x44, 0x34, 0x76, 0x59, 0x11, 0x9b, 0x22,
-                0xfc, 0x45,
-            ],
-            [
-                0x81, 0x77, 0x22, 0x8d, 0xa4, 0xa4, 0x5d, 0xc7, 0xfc, 0xa3, 0x8b, 0xde, 0xf6, 0x0a,
-                0xff, 0xe4,
-            ],
-            [
-                0x9c, 0x70, 0xb6, 0x0c, 0x52, 0x67, 0xa9, 0x4e, 0x5f, 0x33, 0xb6, 0xb0, 0x29, 0x85,
-                0xed, 0x51,
-            ],
-            [
-                0xf8, 0x81, 0x64, 0xc1, 0x2d, 0x9c, 0x8f, 0xaf, 0x7d, 0x0f, 0x6e, 0x7c, 0x7b, 0xcd,
-                0x55, 0x79,
-            ],
-            [
-                0x13, 0x68, 0x87, 0x59, 0x80, 0x77, 0x6f, 0x88, 0x54, 0x52, 0x7a, 0x07, 0x69, 0x0e,
-                0x96, 0x27,
-            ],
-            [
-                0x14, 0xee, 0xca, 0x33, 0x8b, 0x20, 0x86, 0x13, 0x48, 0x5e, 0xa0, 0x30, 0x8f, 0xd7,
-                0xa1, 0x5e,
-            ],
-            [
-                0xa1, 0xf1, 0xeb, 0xbe, 0xd8, 0xdb, 0xc1, 0x53, 0xc0, 0xb8, 0x4a, 0xa6, 0x1f, 0xf0,
-                0x82, 0x39,
-            ],
-            [
-                0x3b, 0x62, 0xa9, 0xba, 0x62, 0x58, 0xf5, 0x61, 0x0f, 0x83, 0xe2, 0x64, 0xf3, 0x14,
-                0x97, 0xb4,
-            ],
-            [
-                0x26, 0x44, 0x99, 0x06, 0x0a, 0xd9, 0xba, 0xab, 0xc4, 0x7f, 0x8b, 0x02, 0xbb, 0x6d,
-                0x71, 0xed,
-            ],
-            [
-                0x00, 0x11, 0x0d, 0xc3, 0x78, 0x14, 0x69, 0x56, 0xc9, 0x54, 0x47, 0xd3, 0xf3, 0xd0,
-                0xfb, 0xba,
-            ],
-            [
-                0x01, 0x51, 0xc5, 0x68, 0x38, 0x6b, 0x66, 0x77, 0xa2, 0xb4, 0xdc, 0x6f, 0x81, 0xe5,
-                0xdc, 0x18,
-            ],
-            [
-                0xd6, 0x26, 0xb2, 0x66, 0x90, 0x5e, 0xf3, 0x58, 0x82, 0x63, 0x4d, 0xf6, 0x85, 0x32,
-                0xc1, 0x25,
-            ],
-            [
-                0x98, 0x69, 0xe2, 0x47, 0xe9, 0xc0, 0x8b, 0x10, 0xd0, 0x29, 0x93, 0x4f, 0xc4, 0xb9,
-                0x52, 0xf7,
-            ],
-            [
-                0x31, 0xfc, 0xef, 0xac, 0x66, 0xd7, 0xde, 0x9c, 0x7e, 0xc7, 0x48, 0x5f, 0xe4, 0x49,
-                0x49, 0x02,
-            ],
-            [
-                0x54, 0x93, 0xe9, 0x99, 0x33, 0xb0, 0xa8, 0x11, 0x7e, 0x08, 0xec, 0x0f, 0x97, 0xcf,
-                0xc3, 0xd9,
-            ],
-            [
-                0x6e, 0xe2, 0xa4, 0xca, 0x67, 0xb0, 0x54, 0xbb, 0xfd, 0x33, 0x15, 0xbf, 0x85, 0x23,
-                0x05, 0x77,
-            ],
-            [
-                0x47, 0x3d, 0x06, 0xe8, 0x73, 0x8d, 0xb8, 0x98, 0x54, 0xc0, 0x66, 0xc4, 0x7a, 0xe4,
-                0x77, 0x40,
-            ],
-            [
-                0xa4, 0x26, 0xe5, 0xe4, 0x23, 0xbf, 0x48, 0x85, 0x29, 0x4d, 0xa4, 0x81, 0xfe, 0xae,
-                0xf7, 0x23,
-            ],
-            [
-                0x78, 0x01, 0x77, 0x31, 0xcf, 0x65, 0xfa, 0xb0, 0x74, 0xd5, 0x20, 0x89, 0x52, 0x51,
-                0x2e, 0xb1,
-            ],
-            [
-                0x9e, 0x25, 0xfc, 0x83, 0x3f, 0x22, 0x90, 0x73, 0x3e, 0x93, 0x44, 0xa5, 0xe8, 0x38,
-                0x39, 0xeb,
-            ],
-            [
-                0x56, 0x8e, 0x49, 0x5a, 0xbe, 0x52, 0x5a, 0x21, 0x8a, 0x22, 0x14, 0xcd, 0x3e, 0x07,
-                0x1d, 0x12,
-            ],
-            [
-                0x4a, 0x29, 0xb5, 0x45, 0x52, 0xd1, 0x6b, 0x9a, 0x46, 0x9c, 0x10, 0x52, 0x8e, 0xff,
-                0x0a, 0xae,
-            ],
-            [
-                0xc9, 0xd1, 0x84, 0xdd, 0xd5, 0xa9, 0xf5, 0xe0, 0xcf, 0x8c, 0xe2, 0x9a, 0x9a, 0xbf,
-                0x69, 0x1c,
-            ],
-            [
-                0x2d, 0xb4, 0x79, 0xae, 0x78, 0xbd, 0x50, 0xd8, 0x88, 0x2a, 0x8a, 0x17, 0x8a, 0x61,
-                0x32, 0xad,
-            ],
-            [
-                0x8e, 0xce, 0x5f, 0x04, 0x2d, 0x5e, 0x44, 0x7b, 0x50, 0x51, 0xb9, 0xea, 0xcb, 0x8d,
-                0x8f, 0x6f,
-            ],
-            [
-                0x9c, 0x0b, 0x53, 0xb4, 0xb3, 0xc3, 0x07, 0xe8, 0x7e, 0xae, 0xe0, 0x86, 0x78, 0x14,
-                0x1f, 0x66,
-            ],
-            [
-                0xab, 0xf2, 0x48, 0xaf, 0x69, 0xa6, 0xea, 0xe4, 0xbf, 0xd3, 0xeb, 0x2f, 0x12, 0x9e,
-                0xeb, 0x94,
-            ],
-            [
-                0x06, 0x64, 0xda, 0x16, 0x68, 0x57, 0x4b, 0x88, 0xb9, 0x35, 0xf3, 0x02, 0x73, 0x58,
-                0xae, 0xf4,
-            ],
-            [
-                0xaa, 0x4b, 0x9d, 0xc4, 0xbf, 0x33, 0x7d, 0xe9, 0x0c, 0xd4, 0xfd, 0x3c, 0x46, 0x7c,
-                0x6a, 0xb7,
-            ],
-            [
-                0xea, 0x5c, 0x7f, 0x47, 0x1f, 0xaf, 0x6b, 0xde, 0x2b, 0x1a, 0xd7, 0xd4, 0x68, 0x6d,
-                0x22, 0x87,
-            ],
-            [
-                0x29, 0x39, 0xb0, 0x18, 0x32, 0x23, 0xfa, 0xfc, 0x17, 0x23, 0xde, 0x4f, 0x52, 0xc4,
-                0x3d, 0x35,
-            ],
-            [
-                0x7c, 0x39, 0x56, 0xca, 0x5e, 0xea, 0xfc, 0x3e, 0x36, 0x3e, 0x9d, 0x55, 0x65, 0x46,
-                0xeb, 0x68,
-            ],
-            [
-                0x77, 0xc6, 0x07, 0x71, 0x46, 0xf0, 0x1c, 0x32, 0xb6, 0xb6, 0x9d, 0x5f, 0x4e, 0xa9,
-                0xff, 0xcf,
-            ],
-            [
-                0x37, 0xa6, 0x98, 0x6c, 0xb8, 0x84, 0x7e, 0xdf, 0x09, 0x25, 0xf0, 0xf1, 0x30, 0x9b,
-                0x54, 0xde,
-            ],
-            [
-                0xa7, 0x05, 0xf0, 0xe6, 0x9d, 0xa9, 0xa8, 0xf9, 0x07, 0x24, 0x1a, 0x2e, 0x92, 0x3c,
-                0x8c, 0xc8,
-            ],
-            [
-                0x3d, 0xc4, 0x7d, 0x1f, 0x29, 0xc4, 0x48, 0x46, 0x1e, 0x9e, 0x76, 0xed, 0x90, 0x4f,
-                0x67, 0x11,
-            ],
-            [
-                0x0d, 0x62, 0xbf, 0x01, 0xe6, 0xfc, 0x0e, 0x1a, 0x0d, 0x3c, 0x47, 0x51, 0xc5, 0xd3,
-                0x69, 0x2b,
-            ],
-            [
-                0x8c, 0x03, 0x46, 0x8b, 0xca, 0x7c, 0x66, 0x9e, 0xe4, 0xfd, 0x5e, 0x08, 0x4b, 0xbe,
-                0xe7, 0xb5,
-            ],
-            [
-                0x52, 0x8a, 0x5b, 0xb9, 0x3b, 0xaf, 0x2c, 0x9c, 0x44, 0x73, 0xcc, 0xe5, 0xd0, 0xd2,
-                0x2b, 0xd9,
-            ],
-            [
-                0xdf, 0x6a, 0x30, 0x1e, 0x95, 0xc9, 0x5d, 0xad, 0x97, 0xae, 0x0c, 0xc8, 0xc6, 0x91,
-                0x3b, 0xd8,
-            ],
-            [
-                0x80, 0x11, 0x89, 0x90, 0x2c, 0x85, 0x7f, 0x39, 0xe7, 0x35, 0x91, 0x28, 0x5e, 0x70,
-                0xb6, 0xdb,
-            ],
-            [
-                0xe6, 0x17, 0x34, 0x6a, 0xc9, 0xc2, 0x31, 0xbb, 0x36, 0x50, 0xae, 0x34, 0xcc, 0xca,
-                0x0c, 0x5b,
-            ],
-            [
-                0x27, 0xd9, 0x34, 0x37, 0xef, 0xb7, 0x21, 0xaa, 0x40, 0x18, 0x21, 0xdc, 0xec, 0x5a,
-                0xdf, 0x89,
-            ],
-            [
-                0x89, 0x23, 0x7d, 0x9d, 0xed, 0x9c, 0x5e, 0x78, 0xd8, 0xb1, 0xc9, 0xb1, 0x66, 0xcc,
-                0x73, 0x42,
-            ],
-            [
-                0x4a, 0x6d, 0x80, 0x91, 0xbf, 0x5e, 0x7d, 0x65, 0x11, 0x89, 0xfa, 0x94, 0xa2, 0x50,
-                0xb1, 0x4c,
-            ],
-            [
-                0x0e, 0x33, 0xf9, 0x60, 0x55, 0xe7, 0xae, 0x89, 0x3f, 0xfc, 0x0e, 0x3d, 0xcf, 0x49,
-                0x29, 0x02,
-            ],
-            [
-                0xe6, 0x1c, 0x43, 0x2b, 0x72, 0x0b, 0x19, 0xd1, 0x8e, 0xc8, 0xd8, 0x4b, 0xdc, 0x63,
-                0x15, 0x1b,
-            ],
-            [
-                0xf7, 0xe5, 0xae, 0xf5, 0x49, 0xf7, 0x82, 0xcf, 0x37, 0x90, 0x55, 0xa6, 0x08, 0x26,
-                0x9b, 0x16,
-            ],
-            [
-                0x43, 0x8d, 0x03, 0x0f, 0xd0, 0xb7, 0xa5, 0x4f, 0xa8, 0x37, 0xf2, 0xad, 0x20, 0x1a,
-                0x64, 0x03,
-            ],
-            [
-                0xa5, 0x90, 0xd3, 0xee, 0x4f, 0xbf, 0x04, 0xe3, 0x24, 0x7e, 0x0d, 0x27, 0xf2, 0x86,
-                0x42, 0x3f,
-            ],
-            [
-                0x5f, 0xe2, 0xc1, 0xa1, 0x72, 0xfe, 0x93, 0xc4, 0xb1, 0x5c, 0xd3, 0x7c, 0xae, 0xf9,
-                0xf5, 0x38,
-            ],
-            [
-                0x2c, 0x97, 0x32, 0x5c, 0xbd, 0x06, 0xb3, 0x6e, 0xb2, 0x13, 0x3d, 0xd0, 0x8b, 0x3a,
-                0x01, 0x7c,
-            ],
-            [
-                0x92, 0xc8, 0x14, 0x22, 0x7a, 0x6b, 0xca, 0x94, 0x9f, 0xf0, 0x65, 0x9f, 0x00, 0x2a,
-                0xd3, 0x9e,
-            ],
-            [
-                0xdc, 0xe8, 0x50, 0x11, 0x0b, 0xd8, 0x32, 0x8c, 0xfb, 0xd5, 0x08, 0x41, 0xd6, 0x91,
-                0x1d, 0x87,
-            ],
-            [
-                0x67, 0xf1, 0x49, 0x84, 0xc7, 0xda, 0x79, 0x12, 0x48, 0xe3, 0x2b, 0xb5, 0x92, 0x25,
-                0x83, 0xda,
-            ],
-            [
-                0x19, 0x38, 0xf2, 0xcf, 0x72, 0xd5, 0x4e, 0xe9, 0x7e, 0x94, 0x16, 0x6f, 0xa9, 0x1d,
-                0x2a, 0x36,
-            ],
-            [
-                0x74, 0x48, 0x1e, 0x96, 0x46, 0xed, 0x49, 0xfe, 0x0f, 0x62, 0x24, 0x30, 0x16, 0x04,
-                0x69, 0x8e,
-            ],
-            [
-                0x57, 0xfc, 0xa5, 0xde, 0x98, 0xa9, 0xd6, 0xd8, 0x00, 0x64, 0x38, 0xd0, 0x58, 0x3d,
-                0x8a, 0x1d,
-            ],
-            [
-                0x9f, 0xec, 0xde, 0x1c, 0xef, 0xdc, 0x1c, 0xbe, 0xd4, 0x76, 0x36, 0x74, 0xd9, 0x57,
-                0x53, 0x59,
-            ],
-            [
-                0xe3, 0x04, 0x0c, 0x00, 0xeb, 0x28, 0xf1, 0x53, 0x66, 0xca, 0x73, 0xcb, 0xd8, 0x72,
-                0xe7, 0x40,
-            ],
-            [
-                0x76, 0x97, 0x00, 0x9a, 0x6a, 0x83, 0x1d, 0xfe, 0xcc, 0xa9, 0x1c, 0x59, 0x93, 0x67,
-                0x0f, 0x7a,
-            ],
-            [
-                0x58, 0x53, 0x54, 0x23, 0x21, 0xf5, 0x67, 0xa0, 0x05, 0xd5, 0x47, 0xa4, 0xf0, 0x47,
-                0x59, 0xbd,
-            ],
-            [
-                0x51, 0x50, 0xd1, 0x77, 0x2f, 0x50, 0x83, 0x4a, 0x50, 0x3e, 0x06, 0x9a, 0x97, 0x3f,
-                0xbd, 0x7c,
-            ],
-        ];
+        const EXPECTED: [[u8; 16]; 64] = crate::vectors::SIPHASH_2_4_128;
 
         // no Vec in no_std
         let mut msg = [0u8; 64];
@@ -498,6 +499,117 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_output_bytes() {
+        let key: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        let mut msg = [0u8; 64];
+        (0..64).for_each(|i| msg[i] = i as u8);
+
+        for i in 0..64 {
+            let bytes = SipHash::<2, 4, Hash128>::with_key_bytes(key, &msg[..i]).unwrap();
+            assert_eq!(bytes, crate::vectors::SIPHASH_2_4_128[i]);
+
+            // same bytes by streaming, whatever the split of the message
+            let mut siphash = SipHash::<2, 4, Hash128>::new(key).unwrap();
+            siphash.update(&msg[..i / 3]);
+            siphash.update(&msg[i / 3..i]);
+            let mut out = [0u8; 16];
+            siphash.finalize_into(&mut out).unwrap();
+            assert_eq!(out, bytes);
+
+            let bytes = SipHash24::with_key_bytes(key, &msg[..i]).unwrap();
+            assert_eq!(bytes, crate::vectors::SIPHASH_2_4_64[i].to_le_bytes());
+        }
+    }
+
+    #[test]
+    fn test_finalize_into_length() {
+        let siphash = SipHash24::new(0_u128).unwrap();
+        assert_eq!(
+            siphash.finalize_into(&mut [0u8; 16]),
+            Err(SipError::InvalidOutputLength(16))
+        );
+        assert_eq!(
+            siphash.finalize_into(&mut []),
+            Err(SipError::InvalidOutputLength(0))
+        );
+    }
+
+    #[test]
+    fn test_with_context() {
+        let key = crate::vectors::KEY;
+
+        // SipHash-2-4 with 0xcc xored to v1, of the encoded context and the message, computed independently
+        let prototype = SipHash24::with_context(&key, b"cache keys").unwrap();
+        assert_eq!(prototype.residue.length, 0);
+        assert_eq!(prototype.residue.total_length, 24);
+        let mut siphash = prototype.clone();
+        siphash.update(b"message");
+        assert_eq!(siphash.finalize(), 0x1a8033d32af6554f);
+
+        // the encoding of the context followed by the message is not the same message
+        for context in [&b""[..], b"a", b"12345678", b"123456789"] {
+            let mut encoded = SipHash::<1, 3, Hash128>::new(&key).unwrap();
+            encoded.update(&(context.len() as u64).to_le_bytes());
+            encoded.update(context);
+            encoded.update(&[0u8; 8][..(8 - context.len() % 8) % 8]);
+            encoded.update(b"msg");
+
+            let mut siphash = SipHash::<1, 3, Hash128>::with_context(&key, context).unwrap();
+            siphash.update(b"msg");
+            assert_ne!(siphash.finalize(), encoded.finalize());
+
+            // the same as the encoding after the constant
+            let mut expected = SipHash::<1, 3, Hash128>::new(&key).unwrap();
+            expected.state.context_additional();
+            expected.update(&(context.len() as u64).to_le_bytes());
+            expected.update(context);
+            expected.update(&[0u8; 8][..(8 - context.len() % 8) % 8]);
+            expected.update(b"msg");
+            assert_eq!(siphash.finalize(), expected.finalize());
+        }
+
+        let hash = |context: &[u8], msg: &[u8]| {
+            let mut siphash = SipHash24::with_context(&key, context).unwrap();
+            siphash.update(msg);
+            siphash.finalize()
+        };
+        assert_ne!(hash(b"a", b""), hash(b"", b"a"));
+        assert_ne!(hash(b"a", b""), hash(b"a\0", b""));
+        assert_ne!(hash(b"", b""), SipHash24::with_key(&key, b"").unwrap());
+        assert!(SipHash24::with_context(&key[..15], b"").is_err());
+    }
+
+    #[test]
+    fn test_with_key_vectored() {
+        let key = crate::vectors::KEY;
+        let mut msg = [0u8; 40];
+        for (i, b) in msg.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+
+        // all the ways to split the message in 3 buffers
+        for i in 0..=msg.len() {
+            for j in i..=msg.len() {
+                let bufs = [&msg[..i], &msg[i..j], &[][..], &msg[j..]];
+                assert_eq!(
+                    SipHash24::with_key_vectored(&key, &bufs).unwrap(),
+                    SipHash24::with_key(&key, &msg).unwrap()
+                );
+                assert_eq!(
+                    SipHash::<1, 3, Hash128>::with_key_vectored(&key, &bufs).unwrap(),
+                    SipHash::<1, 3, Hash128>::with_key(&key, &msg).unwrap()
+                );
+            }
+        }
+
+        assert_eq!(
+            SipHash24::with_key_vectored(&key, &[]).unwrap(),
+            SipHash24::with_key(&key, &[]).unwrap()
+        );
+        assert!(SipHash24::with_key_vectored(&key[..4], &[]).is_err());
+    }
+
     #[test]
     fn test_send() {
         fn assert_send<T: Send>() {}
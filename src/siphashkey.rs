@@ -2,9 +2,30 @@ use core::convert::TryFrom;
 
 use crate::{iter::slice_to_u64, SipError};
 
-#[derive(Debug)]
+/// The 128-bit key, made of the 2 `u64` values `k0` and `k1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SipHashKey(pub(crate) u64, pub(crate) u64);
 
+impl SipHashKey {
+    /// The key made of `k0` and `k1`, which are the little-endian values of the first and last 8 bytes of the key.
+    pub const fn new(k0: u64, k1: u64) -> Self {
+        Self(k0, k1)
+    }
+
+    /// The 16 bytes of the key: the little-endian bytes of `k0` followed by those of `k1`.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&self.0.to_le_bytes());
+        bytes[8..].copy_from_slice(&self.1.to_le_bytes());
+        bytes
+    }
+
+    // the conversion from 16 bytes can't fail
+    pub(crate) fn from_bytes(key: &[u8; 16]) -> Self {
+        Self(slice_to_u64(&key[0..8]), slice_to_u64(&key[8..]))
+    }
+}
+
 impl TryFrom<&[u8]> for SipHashKey {
     type Error = SipError;
 
@@ -34,10 +55,15 @@ impl TryFrom<&[u8; 16]> for SipHashKey {
     type Error = SipError;
 
     fn try_from(key: &[u8; 16]) -> Result<Self, Self::Error> {
-        Ok(SipHashKey(
-            slice_to_u64(&key[0..8]),
-            slice_to_u64(&key[8..]),
-        ))
+        Ok(SipHashKey::from_bytes(key))
+    }
+}
+
+impl TryFrom<&SipHashKey> for SipHashKey {
+    type Error = SipError;
+
+    fn try_from(key: &SipHashKey) -> Result<Self, Self::Error> {
+        Ok(*key)
     }
 }
 
@@ -81,6 +107,16 @@ mod tests {
         assert!(matches!(err, SipError::KeyTooShort(x) if x == 3));
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_error_display() {
+        let err = SipHashKey::try_from("\x00\x01\x02".as_bytes()).unwrap_err();
+        assert_eq!(
+            alloc::format!("{}", err),
+            "the key must be at least 16 bytes long, not 3 bytes"
+        );
+    }
+
     #[test]
     // taken from Appendix A
     fn test_from_array() {
@@ -101,4 +137,13 @@ mod tests {
         assert_eq!(s.0, 0x0706050403020100);
         assert_eq!(s.1, 0x0f0e0d0c0b0a0908);
     }
+
+    #[test]
+    fn test_to_bytes() {
+        let key: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+        let s = SipHashKey::try_from(&key).unwrap();
+        assert_eq!(s.to_bytes(), key);
+        assert_eq!(SipHashKey::try_from(&s).unwrap(), s);
+    }
 }
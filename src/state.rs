@@ -13,6 +13,8 @@ macro_rules! oper {
     };
 }
 
+use crate::trace::{Observer, Step};
+
 // the internal state keeps all intermediate values for the algorithm
 #[derive(Copy, Clone)]
 pub(crate) struct State<const C: u8, const D: u8> {
@@ -55,33 +57,67 @@ impl<const C: u8, const D: u8> State<C, D> {
 
     // compression algorithm for a message m_i
     pub fn compress_chunk(&mut self, m_i: u64) {
+        self.compress_chunk_observed(m_i, &mut ());
+    }
+
+    // same, reporting each step to the observer
+    #[inline(always)]
+    pub(crate) fn compress_chunk_observed<O: Observer>(&mut self, m_i: u64, observer: &mut O) {
         // The mi’s are iteratively processed by doing
         self.v[3] ^= m_i;
+        observer.observe(Step::Block(m_i), &self.v);
 
         // then C iteration of SipRound
-        (0..C).for_each(|_| self.sip_round());
+        (1..=C).for_each(|n| {
+            self.sip_round();
+            observer.observe(Step::CompressionRound(n), &self.v);
+        });
 
         // followed by
         self.v[0] ^= m_i;
+        observer.observe(Step::Compression(m_i), &self.v);
     }
 
     // finalization step
-    pub(crate) fn finalization(&mut self, i: usize, u: u64) -> u64 {
+    pub(crate) fn finalization<O: Observer>(&mut self, i: usize, u: u64, observer: &mut O) -> u64 {
         // After all the message words have been processed, SipHash-c-d xors the constant u to the state
         //i is the index for which the constant u is xored
         // i = 2, u = 0xFF pour SipHash64
         // i = 1, u = 0xEE pour SipHash128
         self.v[i] ^= u;
+        observer.observe(Step::FinalizationConstant(u), &self.v);
 
         // then does D iterations of SipRound
-        (0..D).for_each(|_| self.sip_round());
+        (1..=D).for_each(|n| {
+            self.sip_round();
+            observer.observe(Step::FinalizationRound(n), &self.v);
+        });
 
         // returns the 64-bit value
-        self.v[0] ^ self.v[1] ^ self.v[2] ^ self.v[3]
+        let value = self.v[0] ^ self.v[1] ^ self.v[2] ^ self.v[3];
+        observer.observe(Step::Finalization(value), &self.v);
+
+        value
+    }
+
+    // the v0..v3 values
+    pub(crate) fn lanes(&self) -> &[u64; 4] {
+        &self.v
+    }
+
+    // the state made of saved v0..v3 values
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_lanes(v: [u64; 4]) -> Self {
+        Self { v }
     }
 
     // this step is just for the Hash128 algo
     pub(crate) fn hash128_additional(&mut self) {
         self.v[1] ^= 0xEE;
     }
+
+    // this step is just for the hashes with a context, which no plain message can reproduce
+    pub(crate) fn context_additional(&mut self) {
+        self.v[1] ^= 0xCC;
+    }
 }
@@ -0,0 +1,624 @@
+//! Hashing of text, so that canonically equivalent strings have the same hash value.
+//!
+//! The text is fed to `siphash_c_d` as a canonical stream:
+//!
+//! * normalized using NFC or NFKC;
+//! * optionally with full Unicode case folding, as the canonical or compatibility caseless matching of the
+//!   Unicode Standard (§3.13);
+//! * encoded in UTF-8, prefixed by its length in bytes as a `u64` little-endian value, so that several strings
+//!   can be hashed without ambiguity.
+//!
+//! No allocation is made: the normalization is done on the fly with fixed-size buffers, and the text is read twice
+//! to calculate the length prefix.
+//!
+//! The Unicode data are built in the crate ([`UNICODE_VERSION`]), so that the hash values don't depend on
+//! the platform or on another crate. The code points unassigned in this version are kept as is.
+//!
+//! A text with more than 30 successive combining marks (which is not in the Stream-Safe Text Format of UAX #15) is
+//! normalized by chunks of 30 combining marks.
+//!
+//! ```rust
+//! use siphash_c_d::{
+//!     text::{Normalization, TextHash},
+//!     Hash64, SipHash,
+//! };
+//!
+//! let key = (0x0706050403020100, 0x0f0e0d0c0b0a0908);
+//! let text = TextHash::new(Normalization::Nfc).case_folding();
+//!
+//! // the precomposed é and e followed by a combining acute accent
+//! let h1 = text.hash::<2, 4, Hash64, _>(key, "\u{e9}t\u{e9}").unwrap();
+//! let h2 = text.hash::<2, 4, Hash64, _>(key, "E\u{301}TE\u{301}").unwrap();
+//! assert_eq!(h1, h2);
+//! ```
+use core::str::Chars;
+
+use crate::{unicode_tables as tables, HashOutput, SipError, SipHash, SipHashKey};
+
+/// The version of the Unicode data used for the normalization and the case folding.
+pub const UNICODE_VERSION: (u8, u8, u8) = tables::UNICODE_VERSION;
+
+/// The normalization form of the text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Canonical decomposition, followed by canonical composition.
+    Nfc,
+
+    /// Compatibility decomposition, followed by canonical composition.
+    Nfkc,
+}
+
+/// The canonical stream of the text to hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextHash {
+    normalization: Normalization,
+    case_folding: bool,
+}
+
+impl TextHash {
+    /// Use the normalization form `normalization`, without case folding.
+    pub fn new(normalization: Normalization) -> Self {
+        Self {
+            normalization,
+            case_folding: false,
+        }
+    }
+
+    /// Add the full case folding.
+    pub fn case_folding(mut self) -> Self {
+        self.case_folding = true;
+        self
+    }
+
+    /// The canonical chars of `s`.
+    pub fn chars<'a>(&self, s: &'a str) -> Canonical<'a> {
+        use Decomposition::{Canonical as Nfd, Compatibility as Nfkd, Disabled};
+
+        // the stages of NFKD(toCasefold(NFKD(toCasefold(NFD(X))))) which are used, as described in §3.13
+        let (first, second, third) = match (self.normalization, self.case_folding) {
+            (Normalization::Nfc, false) => (Nfd, Disabled, Disabled),
+            (Normalization::Nfkc, false) => (Nfkd, Disabled, Disabled),
+            // NFD(toCasefold(NFD(X)))
+            (Normalization::Nfc, true) => (Nfd, Nfd, Disabled),
+            (Normalization::Nfkc, true) => (Nfd, Nfkd, Nfkd),
+        };
+
+        let chars = Decompose::new(s.chars(), first);
+        let chars = Decompose::new(CaseFold::new(chars, self.case_folding), second);
+        let chars = Decompose::new(CaseFold::new(chars, third != Disabled), third);
+
+        Canonical(Compose::new(chars))
+    }
+
+    /// Add the length prefix and the UTF-8 bytes of the canonical chars of `s` to the message being hashed.
+    pub fn update<const C: u8, const D: u8, T: HashOutput>(
+        &self,
+        siphash: &mut SipHash<C, D, T>,
+        s: &str,
+    ) {
+        let length: usize = self.chars(s).map(char::len_utf8).sum();
+        siphash.update(&(length as u64).to_le_bytes());
+
+        let mut buffer = [0u8; 64];
+        let mut used = 0;
+
+        for c in self.chars(s) {
+            used += c.encode_utf8(&mut buffer[used..]).len();
+
+            if used + 4 > buffer.len() {
+                siphash.update(&buffer[..used]);
+                used = 0;
+            }
+        }
+        siphash.update(&buffer[..used]);
+    }
+
+    /// Calculate the `siphash_c_d` value of the canonical stream of `s` using the key `key`.
+    ///
+    /// If the length of the key is less than 16 bytes, returns an error (`SipError::KeyTooShort`).
+    pub fn hash<const C: u8, const D: u8, T, K>(
+        &self,
+        key: K,
+        s: &str,
+    ) -> Result<T::Value, SipError>
+    where
+        T: HashOutput,
+        K: TryInto<SipHashKey, Error = SipError>,
+    {
+        let mut siphash = SipHash::<C, D, T>::new(key)?;
+        self.update(&mut siphash, s);
+        Ok(siphash.finalize())
+    }
+}
+
+/// The iterator over the canonical chars of a text, returned by [`TextHash::chars`].
+#[derive(Debug, Clone)]
+pub struct Canonical<'a>(Compose<Stage<Stage<Decompose<Chars<'a>>>>>);
+
+// the case folding followed by a decomposition
+type Stage<I> = Decompose<CaseFold<I>>;
+
+impl Iterator for Canonical<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.0.next()
+    }
+}
+
+// the longest decomposition (U+FDFA)
+const MAX_DECOMPOSITION: usize = 18;
+
+// the limit of the Stream-Safe Text Format
+const MAX_NON_STARTERS: usize = 30;
+
+// Hangul syllables, as described in §3.12 of the Unicode Standard
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+// the mapped chars of c in one of the generated tables
+fn mapping(table: &[(u32, u16, u8)], chars: &'static [char], c: char) -> Option<&'static [char]> {
+    let i = table
+        .binary_search_by_key(&(c as u32), |entry| entry.0)
+        .ok()?;
+    let (_, offset, length) = table[i];
+
+    Some(&chars[offset as usize..offset as usize + length as usize])
+}
+
+fn combining_class(c: char) -> u8 {
+    let c = c as u32;
+    tables::COMBINING_CLASS
+        .binary_search_by(|&(first, last, _)| {
+            if last < c {
+                core::cmp::Ordering::Less
+            } else if first > c {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .map_or(0, |i| tables::COMBINING_CLASS[i].2)
+}
+
+// the full decomposition of c
+fn decompose(c: char, compatibility: bool, out: &mut [char; MAX_DECOMPOSITION]) -> usize {
+    let s = (c as u32).wrapping_sub(S_BASE);
+    if s < S_COUNT {
+        out[0] = char::from_u32(L_BASE + s / N_COUNT).unwrap_or(c);
+        out[1] = char::from_u32(V_BASE + (s % N_COUNT) / T_COUNT).unwrap_or(c);
+        if s % T_COUNT == 0 {
+            return 2;
+        }
+        out[2] = char::from_u32(T_BASE + s % T_COUNT).unwrap_or(c);
+        return 3;
+    }
+
+    let decomposition = if compatibility {
+        mapping(tables::COMPATIBILITY, tables::COMPATIBILITY_CHARS, c)
+    } else {
+        mapping(tables::CANONICAL, tables::CANONICAL_CHARS, c)
+    };
+
+    match decomposition {
+        Some(chars) => {
+            out[..chars.len()].copy_from_slice(chars);
+            chars.len()
+        }
+        None => {
+            out[0] = c;
+            1
+        }
+    }
+}
+
+// the primary composite of a and b
+fn compose(a: char, b: char) -> Option<char> {
+    let (a, b) = (a as u32, b as u32);
+
+    // L + V and LV + T for Hangul syllables
+    if (L_BASE..L_BASE + L_COUNT).contains(&a) && (V_BASE..V_BASE + V_COUNT).contains(&b) {
+        return char::from_u32(S_BASE + ((a - L_BASE) * V_COUNT + b - V_BASE) * T_COUNT);
+    }
+    let s = a.wrapping_sub(S_BASE);
+    if s < S_COUNT && s % T_COUNT == 0 && (T_BASE + 1..T_BASE + T_COUNT).contains(&b) {
+        return char::from_u32(a + b - T_BASE);
+    }
+
+    let i = tables::COMPOSITION
+        .binary_search_by_key(&(a, b), |&(first, second, _)| (first, second))
+        .ok()?;
+    char::from_u32(tables::COMPOSITION[i].2)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Decomposition {
+    Disabled,
+    Canonical,
+    Compatibility,
+}
+
+// the full decomposition followed by the canonical ordering
+#[derive(Debug, Clone)]
+struct Decompose<I> {
+    iter: I,
+    decomposition: Decomposition,
+
+    // the chars with their combining class: the ones before ready are in their final order
+    buffer: [(u8, char); 64],
+    length: usize,
+    ready: usize,
+    position: usize,
+}
+
+impl<I: Iterator<Item = char>> Decompose<I> {
+    fn new(iter: I, decomposition: Decomposition) -> Self {
+        Self {
+            iter,
+            decomposition,
+            buffer: [(0, '\0'); 64],
+            length: 0,
+            ready: 0,
+            position: 0,
+        }
+    }
+
+    // stable sort of the pending combining marks
+    fn sort_pending(&mut self) {
+        for i in self.ready + 1..self.length {
+            let mut j = i;
+            while j > self.ready && self.buffer[j - 1].0 > self.buffer[j].0 {
+                self.buffer.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+        self.ready = self.length;
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Decompose<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.decomposition == Decomposition::Disabled {
+            return self.iter.next();
+        }
+
+        loop {
+            if self.position < self.ready {
+                self.position += 1;
+                return Some(self.buffer[self.position - 1].1);
+            }
+
+            // only keep the pending combining marks
+            self.buffer.copy_within(self.ready..self.length, 0);
+            self.length -= self.ready;
+            self.ready = 0;
+            self.position = 0;
+
+            match self.iter.next() {
+                Some(c) => {
+                    let mut decomposition = ['\0'; MAX_DECOMPOSITION];
+                    let compatibility = self.decomposition == Decomposition::Compatibility;
+                    let n = decompose(c, compatibility, &mut decomposition);
+
+                    for d in &decomposition[..n] {
+                        let class = combining_class(*d);
+                        if class == 0 {
+                            self.sort_pending();
+                        }
+
+                        self.buffer[self.length] = (class, *d);
+                        self.length += 1;
+
+                        if class == 0 || self.length - self.ready >= MAX_NON_STARTERS {
+                            self.sort_pending();
+                        }
+                    }
+                }
+                None if self.length == 0 => return None,
+                None => self.sort_pending(),
+            }
+        }
+    }
+}
+
+// the canonical composition of a decomposed and ordered stream
+#[derive(Debug, Clone)]
+struct Compose<I> {
+    iter: I,
+
+    // the last starter and the combining marks which were not composed with it
+    starter: Option<char>,
+    pending: [char; MAX_NON_STARTERS],
+    pending_length: usize,
+    last_class: u8,
+
+    // the starter, its combining marks and a combining mark which can't be kept
+    output: [char; MAX_NON_STARTERS + 2],
+    output_length: usize,
+    position: usize,
+}
+
+impl<I: Iterator<Item = char>> Compose<I> {
+    fn new(iter: I) -> Self {
+        Self {
+            iter,
+            starter: None,
+            pending: ['\0'; MAX_NON_STARTERS],
+            pending_length: 0,
+            last_class: 0,
+            output: ['\0'; MAX_NON_STARTERS + 2],
+            output_length: 0,
+            position: 0,
+        }
+    }
+
+    // output the starter and its combining marks
+    fn flush(&mut self) {
+        if let Some(starter) = self.starter.take() {
+            self.push(starter);
+        }
+        for i in 0..self.pending_length {
+            self.push(self.pending[i]);
+        }
+        self.pending_length = 0;
+        self.last_class = 0;
+    }
+
+    fn push(&mut self, c: char) {
+        self.output[self.output_length] = c;
+        self.output_length += 1;
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Compose<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if self.position < self.output_length {
+                self.position += 1;
+                return Some(self.output[self.position - 1]);
+            }
+            self.output_length = 0;
+            self.position = 0;
+
+            let c = match self.iter.next() {
+                Some(c) => c,
+                None if self.starter.is_none() && self.pending_length == 0 => return None,
+                None => {
+                    self.flush();
+                    continue;
+                }
+            };
+            let class = combining_class(c);
+
+            let starter = match self.starter {
+                Some(starter) => starter,
+                // no starter at the beginning of the text
+                None if class == 0 => {
+                    self.starter = Some(c);
+                    continue;
+                }
+                None => {
+                    self.push(c);
+                    continue;
+                }
+            };
+
+            // c is blocked by a previous combining mark of the same or higher class, or by a starter
+            let blocked = self.pending_length > 0 && self.last_class >= class;
+            if !blocked {
+                if let Some(composite) = compose(starter, c) {
+                    self.starter = Some(composite);
+                    continue;
+                }
+            }
+
+            if class == 0 {
+                self.flush();
+                self.starter = Some(c);
+            } else if self.pending_length == self.pending.len() {
+                self.flush();
+                self.push(c);
+            } else {
+                self.pending[self.pending_length] = c;
+                self.pending_length += 1;
+                self.last_class = class;
+            }
+        }
+    }
+}
+
+// the full case folding of each char
+#[derive(Debug, Clone)]
+struct CaseFold<I> {
+    iter: I,
+    enabled: bool,
+    folded: &'static [char],
+}
+
+impl<I: Iterator<Item = char>> CaseFold<I> {
+    fn new(iter: I, enabled: bool) -> Self {
+        Self {
+            iter,
+            enabled,
+            folded: &[],
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for CaseFold<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some((c, rest)) = self.folded.split_first() {
+            self.folded = rest;
+            return Some(*c);
+        }
+
+        let c = self.iter.next()?;
+        if !self.enabled {
+            return Some(c);
+        }
+
+        match mapping(tables::CASE_FOLDING, tables::CASE_FOLDING_CHARS, c) {
+            Some(folded) => {
+                self.folded = &folded[1..];
+                Some(folded[0])
+            }
+            None => Some(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hash128, Hash64};
+
+    const KEY: (u64, u64) = (0x0706050403020100, 0x0f0e0d0c0b0a0908);
+
+    // the text, then its NFC, NFKC, canonical caseless and compatibility caseless forms given by Python's unicodedata
+    const SAMPLES: [(&str, [&str; 4]); 19] = [
+        ("e\u{301}te\u{301}", ["\u{e9}t\u{e9}", "\u{e9}t\u{e9}", "\u{e9}t\u{e9}", "\u{e9}t\u{e9}"]),
+        ("a\u{301}\u{323}", ["\u{1ea1}\u{301}", "\u{1ea1}\u{301}", "\u{1ea1}\u{301}", "\u{1ea1}\u{301}"]),
+        ("\u{1e9b}\u{323}", ["\u{1e9b}\u{323}", "\u{1e69}", "\u{1e69}", "\u{1e69}"]),
+        ("\u{344}x", ["\u{308}\u{301}x", "\u{308}\u{301}x", "\u{308}\u{301}x", "\u{308}\u{301}x"]),
+        (
+            "\u{d55c}\u{ad6d}\u{c5b4}",
+            ["\u{d55c}\u{ad6d}\u{c5b4}", "\u{d55c}\u{ad6d}\u{c5b4}", "\u{d55c}\u{ad6d}\u{c5b4}", "\u{d55c}\u{ad6d}\u{c5b4}"],
+        ),
+        ("\u{1100}\u{1161}\u{11a8}", ["\u{ac01}", "\u{ac01}", "\u{ac01}", "\u{ac01}"]),
+        ("Stra\u{df}e", ["Stra\u{df}e", "Stra\u{df}e", "strasse", "strasse"]),
+        (
+            "\u{3a3}\u{391}\u{3a3}",
+            ["\u{3a3}\u{391}\u{3a3}", "\u{3a3}\u{391}\u{3a3}", "\u{3c3}\u{3b1}\u{3c3}", "\u{3c3}\u{3b1}\u{3c3}"],
+        ),
+        ("\u{1f88}\u{345}", ["\u{1f88}\u{345}", "\u{1f88}\u{345}", "\u{1f00}\u{3b9}\u{3b9}", "\u{1f00}\u{3b9}\u{3b9}"]),
+        ("\u{1c5}", ["\u{1c5}", "D\u{17e}", "\u{1c6}", "d\u{17e}"]),
+        ("\u{fb03}", ["\u{fb03}", "ffi", "ffi", "ffi"]),
+        (
+            "\u{fdfa}",
+            [
+                "\u{fdfa}",
+                "\u{635}\u{644}\u{649} \u{627}\u{644}\u{644}\u{647} \u{639}\u{644}\u{64a}\u{647} \u{648}\u{633}\u{644}\u{645}",
+                "\u{fdfa}",
+                "\u{635}\u{644}\u{649} \u{627}\u{644}\u{644}\u{647} \u{639}\u{644}\u{64a}\u{647} \u{648}\u{633}\u{644}\u{645}",
+            ],
+        ),
+        ("\u{ff21}\u{ff22}\u{ff23}", ["\u{ff21}\u{ff22}\u{ff23}", "ABC", "\u{ff41}\u{ff42}\u{ff43}", "abc"]),
+        ("\u{212a}\u{2126}", ["K\u{3a9}", "K\u{3a9}", "k\u{3c9}", "k\u{3c9}"]),
+        ("\u{130}", ["\u{130}", "\u{130}", "i\u{307}", "i\u{307}"]),
+        ("\u{2460}\u{bd}", ["\u{2460}\u{bd}", "11\u{2044}2", "\u{2460}\u{bd}", "11\u{2044}2"]),
+        ("A\u{308}\u{fb01}", ["\u{c4}\u{fb01}", "\u{c4}fi", "\u{e4}fi", "\u{e4}fi"]),
+        ("\u{915}\u{93c}", ["\u{915}\u{93c}", "\u{915}\u{93c}", "\u{915}\u{93c}", "\u{915}\u{93c}"]),
+        ("q\u{307}\u{323}", ["q\u{323}\u{307}", "q\u{323}\u{307}", "q\u{323}\u{307}", "q\u{323}\u{307}"]),
+    ];
+
+    #[test]
+    fn test_samples() {
+        let modes = [
+            TextHash::new(Normalization::Nfc),
+            TextHash::new(Normalization::Nfkc),
+            TextHash::new(Normalization::Nfc).case_folding(),
+            TextHash::new(Normalization::Nfkc).case_folding(),
+        ];
+
+        for (text, expected) in SAMPLES {
+            for (mode, expected) in modes.iter().zip(expected) {
+                assert!(
+                    mode.chars(text).eq(expected.chars()),
+                    "{:?} {:?}",
+                    mode,
+                    text
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_tables() {
+        let longest =
+            |table: &[(u32, u16, u8)]| table.iter().map(|entry| entry.2).max().unwrap_or(0);
+
+        assert_eq!(longest(tables::COMPATIBILITY) as usize, MAX_DECOMPOSITION);
+        assert!(longest(tables::CANONICAL) as usize <= MAX_DECOMPOSITION);
+        assert!(longest(tables::CASE_FOLDING) <= 3);
+        assert_eq!(UNICODE_VERSION, (14, 0, 0));
+    }
+
+    #[test]
+    fn test_hash() {
+        let nfc = TextHash::new(Normalization::Nfc);
+        let h = nfc.hash::<2, 4, Hash64, _>(KEY, "e\u{301}").unwrap();
+
+        // length prefix and UTF-8 bytes of the canonical text
+        let expected =
+            SipHash::<2, 4, Hash64>::with_key(KEY, b"\x02\0\0\0\0\0\0\0\xc3\xa9").unwrap();
+        assert_eq!(h, expected);
+
+        // the prefix avoids ambiguities between several strings
+        let mut s1 = SipHash::<2, 4, Hash128>::new(KEY).unwrap();
+        nfc.update(&mut s1, "ab");
+        nfc.update(&mut s1, "c");
+        let mut s2 = SipHash::<2, 4, Hash128>::new(KEY).unwrap();
+        nfc.update(&mut s2, "a");
+        nfc.update(&mut s2, "bc");
+        assert_ne!(s1.finalize(), s2.finalize());
+    }
+
+    #[test]
+    fn test_long_text() {
+        // longer than the buffers, with Hangul syllables
+        let text = "\
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             \u{d55c}\u{ad6d}\u{c5b4} a\u{301}\u{323} \u{1100}\u{1161}\u{11a8} \
+             ";
+        let nfc = TextHash::new(Normalization::Nfc);
+        assert_eq!(nfc.chars(text).count(), 20 * 9);
+        assert!(nfc
+            .chars(text)
+            .skip(9 * 19)
+            .eq("\u{d55c}\u{ad6d}\u{c5b4} \u{1ea1}\u{301} \u{ac01} ".chars()));
+    }
+
+    #[test]
+    fn test_many_combining_marks() {
+        // a then 40 combining acute accents
+        let mut text = [0u8; 81];
+        text[0] = b'a';
+        (0..40).for_each(|i| text[1 + 2 * i..3 + 2 * i].copy_from_slice("\u{301}".as_bytes()));
+        let text = core::str::from_utf8(&text).unwrap();
+
+        let mut chars = TextHash::new(Normalization::Nfc).chars(text);
+        assert_eq!(chars.next(), Some('\u{e1}'));
+        assert_eq!(chars.count(), 39);
+    }
+}
@@ -0,0 +1,280 @@
+//! Step by step tracing of the internal state, as listed in Appendix A of the paper.
+//!
+//! An [`Observer`] passed to [`SipHash::with_key_traced`](crate::SipHash::with_key_traced) is called after each step
+//! of the algorithm with the `v0..v3` values. The usual calculations are using the `()` observer which does nothing,
+//! so that tracing has no cost when not used.
+//!
+//! ```rust
+//! use siphash_c_d::{
+//!     trace::{Recorder, Step},
+//!     SipHash24,
+//! };
+//!
+//! let msg: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+//! let mut recorder = Recorder::<32>::default();
+//!
+//! let hash = SipHash24::with_key_traced((0x0706050403020100, 0x0f0e0d0c0b0a0908), msg, &mut recorder).unwrap();
+//! assert_eq!(hash, 0xa129ca6149be45e5);
+//!
+//! // state after the initialization
+//! assert_eq!(
+//!     recorder.steps()[0],
+//!     (
+//!         Step::Initialization,
+//!         [0x7469686173716475, 0x6b617f6d656e6665, 0x6b7f62616d677361, 0x7b6b696e727e6c7b]
+//!     )
+//! );
+//! ```
+
+/// A step of the `siphash_c_d` algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// The state is initialized from the key (§2.1). For a 128-bit hash, this includes the xor of `0xee` to `v1`.
+    Initialization,
+
+    /// The message block `m_i` is xored to `v3`.
+    Block(u64),
+
+    /// The n-th SipRound of the compression of the current block, starting at 1.
+    CompressionRound(u8),
+
+    /// The message block `m_i` is xored to `v0`, ending its compression.
+    Compression(u64),
+
+    /// The finalization constant (`0xff` for a 64-bit hash, `0xee` and `0xdd` for a 128-bit hash) is xored to the state.
+    FinalizationConstant(u64),
+
+    /// The n-th SipRound of the finalization, starting at 1.
+    FinalizationRound(u8),
+
+    /// The end of the finalization with the resulting `v0 ^ v1 ^ v2 ^ v3` value. A 128-bit hash has 2 finalizations.
+    Finalization(u64),
+}
+
+/// Receives the steps of the algorithm.
+pub trait Observer {
+    /// Called after each step with the state `[v0, v1, v2, v3]`.
+    fn observe(&mut self, step: Step, v: &[u64; 4]);
+}
+
+// the default observer: nothing is traced
+impl Observer for () {
+    #[inline(always)]
+    fn observe(&mut self, _step: Step, _v: &[u64; 4]) {}
+}
+
+/// An observer keeping the first `N` steps, without any allocation.
+#[derive(Debug, Clone)]
+pub struct Recorder<const N: usize> {
+    steps: [(Step, [u64; 4]); N],
+    length: usize,
+    total: usize,
+}
+
+impl<const N: usize> Default for Recorder<N> {
+    fn default() -> Self {
+        Self {
+            steps: [(Step::Initialization, [0; 4]); N],
+            length: 0,
+            total: 0,
+        }
+    }
+}
+
+impl<const N: usize> Recorder<N> {
+    /// The recorded steps with their state.
+    pub fn steps(&self) -> &[(Step, [u64; 4])] {
+        &self.steps[..self.length]
+    }
+
+    /// The number of observed steps, including the ones which could not be recorded.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+impl<const N: usize> Observer for Recorder<N> {
+    fn observe(&mut self, step: Step, v: &[u64; 4]) {
+        if self.length < N {
+            self.steps[self.length] = (step, *v);
+            self.length += 1;
+        }
+        self.total += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hash128, SipHash, SipHash24};
+
+    #[test]
+    // taken from Appendix A
+    fn test_appendix_a() {
+        let msg: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+        let mut recorder = Recorder::<32>::default();
+
+        let hash = SipHash24::with_key_traced(
+            (0x0706050403020100, 0x0f0e0d0c0b0a0908),
+            msg,
+            &mut recorder,
+        )
+        .unwrap();
+        assert_eq!(hash, 0xa129ca6149be45e5);
+
+        const EXPECTED: [(Step, [u64; 4]); 15] = [
+            (
+                Step::Initialization,
+                [
+                    0x7469686173716475,
+                    0x6b617f6d656e6665,
+                    0x6b7f62616d677361,
+                    0x7b6b696e727e6c7b,
+                ],
+            ),
+            (
+                Step::Block(0x0706050403020100),
+                [
+                    0x7469686173716475,
+                    0x6b617f6d656e6665,
+                    0x6b7f62616d677361,
+                    0x7c6d6c6a717c6d7b,
+                ],
+            ),
+            (
+                Step::CompressionRound(1),
+                [
+                    0x64668a929363847f,
+                    0x4ed232083d9aa8dc,
+                    0xf2f74892d8141a2e,
+                    0x9290f9810552f4a8,
+                ],
+            ),
+            (
+                Step::CompressionRound(2),
+                [
+                    0x4d07749cdd0858e0,
+                    0x0d52f6f62a4f59a4,
+                    0x634cb3577b01fd3d,
+                    0xa5224d6f55c7d9c8,
+                ],
+            ),
+            (
+                Step::Compression(0x0706050403020100),
+                [
+                    0x4a017198de0a59e0,
+                    0x0d52f6f62a4f59a4,
+                    0x634cb3577b01fd3d,
+                    0xa5224d6f55c7d9c8,
+                ],
+            ),
+            (
+                Step::Block(0x0f0e0d0c0b0a0908),
+                [
+                    0x4a017198de0a59e0,
+                    0x0d52f6f62a4f59a4,
+                    0x634cb3577b01fd3d,
+                    0xaa2c40635ecdd0c0,
+                ],
+            ),
+            (
+                Step::CompressionRound(1),
+                [
+                    0x557560fb6063d060,
+                    0x4c8e675bd961133e,
+                    0xbd3d002b1703a181,
+                    0xfb9441179a4a7315,
+                ],
+            ),
+            (
+                Step::CompressionRound(2),
+                [
+                    0x338bbea7645fb759,
+                    0x414fc3fb98efe374,
+                    0xccf13ea527b9f4bd,
+                    0x5293f5da84008f82,
+                ],
+            ),
+            (
+                Step::Compression(0x0f0e0d0c0b0a0908),
+                [
+                    0x3c85b3ab6f55be51,
+                    0x414fc3fb98efe374,
+                    0xccf13ea527b9f4bd,
+                    0x5293f5da84008f82,
+                ],
+            ),
+            (
+                Step::FinalizationConstant(0xff),
+                [
+                    0x3c85b3ab6f55be51,
+                    0x414fc3fb98efe374,
+                    0xccf13ea527b9f442,
+                    0x5293f5da84008f82,
+                ],
+            ),
+            (
+                Step::FinalizationRound(1),
+                [
+                    0xf2a55244a20e48fe,
+                    0xac5ad16ccc3ca6e4,
+                    0x9fe5adb0a52f393a,
+                    0xfd41d55e88f30308,
+                ],
+            ),
+            (
+                Step::FinalizationRound(2),
+                [
+                    0xb6c3fbdecc2ae4b4,
+                    0x1638c86adc6b3cf0,
+                    0x28b8b6ab62553d46,
+                    0xc9465e86ec43eb95,
+                ],
+            ),
+            (
+                Step::FinalizationRound(3),
+                [
+                    0x58101b167209a5e6,
+                    0xf8792ae75b66e7dd,
+                    0x1da14c3ec7f0b4f7,
+                    0x7624ba8a41bc4ad9,
+                ],
+            ),
+            (
+                Step::FinalizationRound(4),
+                [
+                    0xf6bcd53893fecff1,
+                    0x54b9964c7ea0d937,
+                    0x1b38329c099bb55a,
+                    0x1814bb89ad7be679,
+                ],
+            ),
+            (
+                Step::Finalization(0xa129ca6149be45e5),
+                [
+                    0xf6bcd53893fecff1,
+                    0x54b9964c7ea0d937,
+                    0x1b38329c099bb55a,
+                    0x1814bb89ad7be679,
+                ],
+            ),
+        ];
+
+        assert_eq!(recorder.steps(), &EXPECTED);
+        assert_eq!(recorder.total(), EXPECTED.len());
+    }
+
+    #[test]
+    fn test_same_value() {
+        let key: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        let msg = "The quick brown fox jumps over the lazy dog".as_bytes();
+
+        let mut recorder = Recorder::<4>::default();
+        let h = SipHash::<2, 4, Hash128>::with_key_traced(key, msg, &mut recorder).unwrap();
+        assert_eq!(h, SipHash::<2, 4, Hash128>::with_key(key, msg).unwrap());
+
+        // 6 blocks of 4 steps, and 2 finalizations of 6 steps
+        assert_eq!(recorder.steps().len(), 4);
+        assert_eq!(recorder.total(), 1 + 6 * 4 + 2 * 6);
+    }
+}
@@ -0,0 +1,2880 @@
+// Generated by unicode_tables.py from the Unicode 14.0.0 data: don't edit.
+
+// the version of the Unicode data
+pub(crate) const UNICODE_VERSION: (u8, u8, u8) = (14, 0, 0);
+
+// full canonical decompositions, except Hangul syllables
+#[rustfmt::skip]
+pub(crate) const CANONICAL: &[(u32, u16, u8)] = &[
+    (0xC0, 0, 2), (0xC1, 2, 2), (0xC2, 4, 2), (0xC3, 6, 2), (0xC4, 8, 2), (0xC5, 10, 2), (0xC7, 12, 2), (0xC8, 14, 2),
+    (0xC9, 16, 2), (0xCA, 18, 2), (0xCB, 20, 2), (0xCC, 22, 2), (0xCD, 24, 2), (0xCE, 26, 2), (0xCF, 28, 2), (0xD1, 30, 2),
+    (0xD2, 32, 2), (0xD3, 34, 2), (0xD4, 36, 2), (0xD5, 38, 2), (0xD6, 40, 2), (0xD9, 42, 2), (0xDA, 44, 2), (0xDB, 46, 2),
+    (0xDC, 48, 2), (0xDD, 50, 2), (0xE0, 52, 2), (0xE1, 54, 2), (0xE2, 56, 2), (0xE3, 58, 2), (0xE4, 60, 2), (0xE5, 62, 2),
+    (0xE7, 64, 2), (0xE8, 66, 2), (0xE9, 68, 2), (0xEA, 70, 2), (0xEB, 72, 2), (0xEC, 74, 2), (0xED, 76, 2), (0xEE, 78, 2),
+    (0xEF, 80, 2), (0xF1, 82, 2), (0xF2, 84, 2), (0xF3, 86, 2), (0xF4, 88, 2), (0xF5, 90, 2), (0xF6, 92, 2), (0xF9, 94, 2),
+    (0xFA, 96, 2), (0xFB, 98, 2), (0xFC, 100, 2), (0xFD, 102, 2), (0xFF, 104, 2), (0x100, 106, 2), (0x101, 108, 2), (0x102, 110, 2),
+    (0x103, 112, 2), (0x104, 114, 2), (0x105, 116, 2), (0x106, 118, 2), (0x107, 120, 2), (0x108, 122, 2), (0x109, 124, 2), (0x10A, 126, 2),
+    (0x10B, 128, 2), (0x10C, 130, 2), (0x10D, 132, 2), (0x10E, 134, 2), (0x10F, 136, 2), (0x112, 138, 2), (0x113, 140, 2), (0x114, 142, 2),
+    (0x115, 144, 2), (0x116, 146, 2), (0x117, 148, 2), (0x118, 150, 2), (0x119, 152, 2), (0x11A, 154, 2), (0x11B, 156, 2), (0x11C, 158, 2),
+    (0x11D, 160, 2), (0x11E, 162, 2), (0x11F, 164, 2), (0x120, 166, 2), (0x121, 168, 2), (0x122, 170, 2), (0x123, 172, 2), (0x124, 174, 2),
+    (0x125, 176, 2), (0x128, 178, 2), (0x129, 180, 2), (0x12A, 182, 2), (0x12B, 184, 2), (0x12C, 186, 2), (0x12D, 188, 2), (0x12E, 190, 2),
+    (0x12F, 192, 2), (0x130, 194, 2), (0x134, 196, 2), (0x135, 198, 2), (0x136, 200, 2), (0x137, 202, 2), (0x139, 204, 2), (0x13A, 206, 2),
+    (0x13B, 208, 2), (0x13C, 210, 2), (0x13D, 212, 2), (0x13E, 214, 2), (0x143, 216, 2), (0x144, 218, 2), (0x145, 220, 2), (0x146, 222, 2),
+    (0x147, 224, 2), (0x148, 226, 2), (0x14C, 228, 2), (0x14D, 230, 2), (0x14E, 232, 2), (0x14F, 234, 2), (0x150, 236, 2), (0x151, 238, 2),
+    (0x154, 240, 2), (0x155, 242, 2), (0x156, 244, 2), (0x157, 246, 2), (0x158, 248, 2), (0x159, 250, 2), (0x15A, 252, 2), (0x15B, 254, 2),
+    (0x15C, 256, 2), (0x15D, 258, 2), (0x15E, 260, 2), (0x15F, 262, 2), (0x160, 264, 2), (0x161, 266, 2), (0x162, 268, 2), (0x163, 270, 2),
+    (0x164, 272, 2), (0x165, 274, 2), (0x168, 276, 2), (0x169, 278, 2), (0x16A, 280, 2), (0x16B, 282, 2), (0x16C, 284, 2), (0x16D, 286, 2),
+    (0x16E, 288, 2), (0x16F, 290, 2), (0x170, 292, 2), (0x171, 294, 2), (0x172, 296, 2), (0x173, 298, 2), (0x174, 300, 2), (0x175, 302, 2),
+    (0x176, 304, 2), (0x177, 306, 2), (0x178, 308, 2), (0x179, 310, 2), (0x17A, 312, 2), (0x17B, 314, 2), (0x17C, 316, 2), (0x17D, 318, 2),
+    (0x17E, 320, 2), (0x1A0, 322, 2), (0x1A1, 324, 2), (0x1AF, 326, 2), (0x1B0, 328, 2), (0x1CD, 330, 2), (0x1CE, 332, 2), (0x1CF, 334, 2),
+    (0x1D0, 336, 2), (0x1D1, 338, 2), (0x1D2, 340, 2), (0x1D3, 342, 2), (0x1D4, 344, 2), (0x1D5, 346, 3), (0x1D6, 349, 3), (0x1D7, 352, 3),
+    (0x1D8, 355, 3), (0x1D9, 358, 3), (0x1DA, 361, 3), (0x1DB, 364, 3), (0x1DC, 367, 3), (0x1DE, 370, 3), (0x1DF, 373, 3), (0x1E0, 376, 3),
+    (0x1E1, 379, 3), (0x1E2, 382, 2), (0x1E3, 384, 2), (0x1E6, 386, 2), (0x1E7, 388, 2), (0x1E8, 390, 2), (0x1E9, 392, 2), (0x1EA, 394, 2),
+    (0x1EB, 396, 2), (0x1EC, 398, 3), (0x1ED, 401, 3), (0x1EE, 404, 2), (0x1EF, 406, 2), (0x1F0, 408, 2), (0x1F4, 410, 2), (0x1F5, 412, 2),
+    (0x1F8, 414, 2), (0x1F9, 416, 2), (0x1FA, 418, 3), (0x1FB, 421, 3), (0x1FC, 424, 2), (0x1FD, 426, 2), (0x1FE, 428, 2), (0x1FF, 430, 2),
+    (0x200, 432, 2), (0x201, 434, 2), (0x202, 436, 2), (0x203, 438, 2), (0x204, 440, 2), (0x205, 442, 2), (0x206, 444, 2), (0x207, 446, 2),
+    (0x208, 448, 2), (0x209, 450, 2), (0x20A, 452, 2), (0x20B, 454, 2), (0x20C, 456, 2), (0x20D, 458, 2), (0x20E, 460, 2), (0x20F, 462, 2),
+    (0x210, 464, 2), (0x211, 466, 2), (0x212, 468, 2), (0x213, 470, 2), (0x214, 472, 2), (0x215, 474, 2), (0x216, 476, 2), (0x217, 478, 2),
+    (0x218, 480, 2), (0x219, 482, 2), (0x21A, 484, 2), (0x21B, 486, 2), (0x21E, 488, 2), (0x21F, 490, 2), (0x226, 492, 2), (0x227, 494, 2),
+    (0x228, 496, 2), (0x229, 498, 2), (0x22A, 500, 3), (0x22B, 503, 3), (0x22C, 506, 3), (0x22D, 509, 3), (0x22E, 512, 2), (0x22F, 514, 2),
+    (0x230, 516, 3), (0x231, 519, 3), (0x232, 522, 2), (0x233, 524, 2), (0x340, 526, 1), (0x341, 527, 1), (0x343, 528, 1), (0x344, 529, 2),
+    (0x374, 531, 1), (0x37E, 532, 1), (0x385, 533, 2), (0x386, 535, 2), (0x387, 537, 1), (0x388, 538, 2), (0x389, 540, 2), (0x38A, 542, 2),
+    (0x38C, 544, 2), (0x38E, 546, 2), (0x38F, 548, 2), (0x390, 550, 3), (0x3AA, 553, 2), (0x3AB, 555, 2), (0x3AC, 557, 2), (0x3AD, 559, 2),
+    (0x3AE, 561, 2), (0x3AF, 563, 2), (0x3B0, 565, 3), (0x3CA, 568, 2), (0x3CB, 570, 2), (0x3CC, 572, 2), (0x3CD, 574, 2), (0x3CE, 576, 2),
+    (0x3D3, 578, 2), (0x3D4, 580, 2), (0x400, 582, 2), (0x401, 584, 2), (0x403, 586, 2), (0x407, 588, 2), (0x40C, 590, 2), (0x40D, 592, 2),
+    (0x40E, 594, 2), (0x419, 596, 2), (0x439, 598, 2), (0x450, 600, 2), (0x451, 602, 2), (0x453, 604, 2), (0x457, 606, 2), (0x45C, 608, 2),
+    (0x45D, 610, 2), (0x45E, 612, 2), (0x476, 614, 2), (0x477, 616, 2), (0x4C1, 618, 2), (0x4C2, 620, 2), (0x4D0, 622, 2), (0x4D1, 624, 2),
+    (0x4D2, 626, 2), (0x4D3, 628, 2), (0x4D6, 630, 2), (0x4D7, 632, 2), (0x4DA, 634, 2), (0x4DB, 636, 2), (0x4DC, 638, 2), (0x4DD, 640, 2),
+    (0x4DE, 642, 2), (0x4DF, 644, 2), (0x4E2, 646, 2), (0x4E3, 648, 2), (0x4E4, 650, 2), (0x4E5, 652, 2), (0x4E6, 654, 2), (0x4E7, 656, 2),
+    (0x4EA, 658, 2), (0x4EB, 660, 2), (0x4EC, 662, 2), (0x4ED, 664, 2), (0x4EE, 666, 2), (0x4EF, 668, 2), (0x4F0, 670, 2), (0x4F1, 672, 2),
+    (0x4F2, 674, 2), (0x4F3, 676, 2), (0x4F4, 678, 2), (0x4F5, 680, 2), (0x4F8, 682, 2), (0x4F9, 684, 2), (0x622, 686, 2), (0x623, 688, 2),
+    (0x624, 690, 2), (0x625, 692, 2), (0x626, 694, 2), (0x6C0, 696, 2), (0x6C2, 698, 2), (0x6D3, 700, 2), (0x929, 702, 2), (0x931, 704, 2),
+    (0x934, 706, 2), (0x958, 708, 2), (0x959, 710, 2), (0x95A, 712, 2), (0x95B, 714, 2), (0x95C, 716, 2), (0x95D, 718, 2), (0x95E, 720, 2),
+    (0x95F, 722, 2), (0x9CB, 724, 2), (0x9CC, 726, 2), (0x9DC, 728, 2), (0x9DD, 730, 2), (0x9DF, 732, 2), (0xA33, 734, 2), (0xA36, 736, 2),
+    (0xA59, 738, 2), (0xA5A, 740, 2), (0xA5B, 742, 2), (0xA5E, 744, 2), (0xB48, 746, 2), (0xB4B, 748, 2), (0xB4C, 750, 2), (0xB5C, 752, 2),
+    (0xB5D, 754, 2), (0xB94, 756, 2), (0xBCA, 758, 2), (0xBCB, 760, 2), (0xBCC, 762, 2), (0xC48, 764, 2), (0xCC0, 766, 2), (0xCC7, 768, 2),
+    (0xCC8, 770, 2), (0xCCA, 772, 2), (0xCCB, 774, 3), (0xD4A, 777, 2), (0xD4B, 779, 2), (0xD4C, 781, 2), (0xDDA, 783, 2), (0xDDC, 785, 2),
+    (0xDDD, 787, 3), (0xDDE, 790, 2), (0xF43, 792, 2), (0xF4D, 794, 2), (0xF52, 796, 2), (0xF57, 798, 2), (0xF5C, 800, 2), (0xF69, 802, 2),
+    (0xF73, 804, 2), (0xF75, 806, 2), (0xF76, 808, 2), (0xF78, 810, 2), (0xF81, 812, 2), (0xF93, 814, 2), (0xF9D, 816, 2), (0xFA2, 818, 2),
+    (0xFA7, 820, 2), (0xFAC, 822, 2), (0xFB9, 824, 2), (0x1026, 826, 2), (0x1B06, 828, 2), (0x1B08, 830, 2), (0x1B0A, 832, 2), (0x1B0C, 834, 2),
+    (0x1B0E, 836, 2), (0x1B12, 838, 2), (0x1B3B, 840, 2), (0x1B3D, 842, 2), (0x1B40, 844, 2), (0x1B41, 846, 2), (0x1B43, 848, 2), (0x1E00, 850, 2),
+    (0x1E01, 852, 2), (0x1E02, 854, 2), (0x1E03, 856, 2), (0x1E04, 858, 2), (0x1E05, 860, 2), (0x1E06, 862, 2), (0x1E07, 864, 2), (0x1E08, 866, 3),
+    (0x1E09, 869, 3), (0x1E0A, 872, 2), (0x1E0B, 874, 2), (0x1E0C, 876, 2), (0x1E0D, 878, 2), (0x1E0E, 880, 2), (0x1E0F, 882, 2), (0x1E10, 884, 2),
+    (0x1E11, 886, 2), (0x1E12, 888, 2), (0x1E13, 890, 2), (0x1E14, 892, 3), (0x1E15, 895, 3), (0x1E16, 898, 3), (0x1E17, 901, 3), (0x1E18, 904, 2),
+    (0x1E19, 906, 2), (0x1E1A, 908, 2), (0x1E1B, 910, 2), (0x1E1C, 912, 3), (0x1E1D, 915, 3), (0x1E1E, 918, 2), (0x1E1F, 920, 2), (0x1E20, 922, 2),
+    (0x1E21, 924, 2), (0x1E22, 926, 2), (0x1E23, 928, 2), (0x1E24, 930, 2), (0x1E25, 932, 2), (0x1E26, 934, 2), (0x1E27, 936, 2), (0x1E28, 938, 2),
+    (0x1E29, 940, 2), (0x1E2A, 942, 2), (0x1E2B, 944, 2), (0x1E2C, 946, 2), (0x1E2D, 948, 2), (0x1E2E, 950, 3), (0x1E2F, 953, 3), (0x1E30, 956, 2),
+    (0x1E31, 958, 2), (0x1E32, 960, 2), (0x1E33, 962, 2), (0x1E34, 964, 2), (0x1E35, 966, 2), (0x1E36, 968, 2), (0x1E37, 970, 2), (0x1E38, 972, 3),
+    (0x1E39, 975, 3), (0x1E3A, 978, 2), (0x1E3B, 980, 2), (0x1E3C, 982, 2), (0x1E3D, 984, 2), (0x1E3E, 986, 2), (0x1E3F, 988, 2), (0x1E40, 990, 2),
+    (0x1E41, 992, 2), (0x1E42, 994, 2), (0x1E43, 996, 2), (0x1E44, 998, 2), (0x1E45, 1000, 2), (0x1E46, 1002, 2), (0x1E47, 1004, 2), (0x1E48, 1006, 2),
+    (0x1E49, 1008, 2), (0x1E4A, 1010, 2), (0x1E4B, 1012, 2), (0x1E4C, 1014, 3), (0x1E4D, 1017, 3), (0x1E4E, 1020, 3), (0x1E4F, 1023, 3), (0x1E50, 1026, 3),
+    (0x1E51, 1029, 3), (0x1E52, 1032, 3), (0x1E53, 1035, 3), (0x1E54, 1038, 2), (0x1E55, 1040, 2), (0x1E56, 1042, 2), (0x1E57, 1044, 2), (0x1E58, 1046, 2),
+    (0x1E59, 1048, 2), (0x1E5A, 1050, 2), (0x1E5B, 1052, 2), (0x1E5C, 1054, 3), (0x1E5D, 1057, 3), (0x1E5E, 1060, 2), (0x1E5F, 1062, 2), (0x1E60, 1064, 2),
+    (0x1E61, 1066, 2), (0x1E62, 1068, 2), (0x1E63, 1070, 2), (0x1E64, 1072, 3), (0x1E65, 1075, 3), (0x1E66, 1078, 3), (0x1E67, 1081, 3), (0x1E68, 1084, 3),
+    (0x1E69, 1087, 3), (0x1E6A, 1090, 2), (0x1E6B, 1092, 2), (0x1E6C, 1094, 2), (0x1E6D, 1096, 2), (0x1E6E, 1098, 2), (0x1E6F, 1100, 2), (0x1E70, 1102, 2),
+    (0x1E71, 1104, 2), (0x1E72, 1106, 2), (0x1E73, 1108, 2), (0x1E74, 1110, 2), (0x1E75, 1112, 2), (0x1E76, 1114, 2), (0x1E77, 1116, 2), (0x1E78, 1118, 3),
+    (0x1E79, 1121, 3), (0x1E7A, 1124, 3), (0x1E7B, 1127, 3), (0x1E7C, 1130, 2), (0x1E7D, 1132, 2), (0x1E7E, 1134, 2), (0x1E7F, 1136, 2), (0x1E80, 1138, 2),
+    (0x1E81, 1140, 2), (0x1E82, 1142, 2), (0x1E83, 1144, 2), (0x1E84, 1146, 2), (0x1E85, 1148, 2), (0x1E86, 1150, 2), (0x1E87, 1152, 2), (0x1E88, 1154, 2),
+    (0x1E89, 1156, 2), (0x1E8A, 1158, 2), (0x1E8B, 1160, 2), (0x1E8C, 1162, 2), (0x1E8D, 1164, 2), (0x1E8E, 1166, 2), (0x1E8F, 1168, 2), (0x1E90, 1170, 2),
+    (0x1E91, 1172, 2), (0x1E92, 1174, 2), (0x1E93, 1176, 2), (0x1E94, 1178, 2), (0x1E95, 1180, 2), (0x1E96, 1182, 2), (0x1E97, 1184, 2), (0x1E98, 1186, 2),
+    (0x1E99, 1188, 2), (0x1E9B, 1190, 2), (0x1EA0, 1192, 2), (0x1EA1, 1194, 2), (0x1EA2, 1196, 2), (0x1EA3, 1198, 2), (0x1EA4, 1200, 3), (0x1EA5, 1203, 3),
+    (0x1EA6, 1206, 3), (0x1EA7, 1209, 3), (0x1EA8, 1212, 3), (0x1EA9, 1215, 3), (0x1EAA, 1218, 3), (0x1EAB, 1221, 3), (0x1EAC, 1224, 3), (0x1EAD, 1227, 3),
+    (0x1EAE, 1230, 3), (0x1EAF, 1233, 3), (0x1EB0, 1236, 3), (0x1EB1, 1239, 3), (0x1EB2, 1242, 3), (0x1EB3, 1245, 3), (0x1EB4, 1248, 3), (0x1EB5, 1251, 3),
+    (0x1EB6, 1254, 3), (0x1EB7, 1257, 3), (0x1EB8, 1260, 2), (0x1EB9, 1262, 2), (0x1EBA, 1264, 2), (0x1EBB, 1266, 2), (0x1EBC, 1268, 2), (0x1EBD, 1270, 2),
+    (0x1EBE, 1272, 3), (0x1EBF, 1275, 3), (0x1EC0, 1278, 3), (0x1EC1, 1281, 3), (0x1EC2, 1284, 3), (0x1EC3, 1287, 3), (0x1EC4, 1290, 3), (0x1EC5, 1293, 3),
+    (0x1EC6, 1296, 3), (0x1EC7, 1299, 3), (0x1EC8, 1302, 2), (0x1EC9, 1304, 2), (0x1ECA, 1306, 2), (0x1ECB, 1308, 2), (0x1ECC, 1310, 2), (0x1ECD, 1312, 2),
+    (0x1ECE, 1314, 2), (0x1ECF, 1316, 2), (0x1ED0, 1318, 3), (0x1ED1, 1321, 3), (0x1ED2, 1324, 3), (0x1ED3, 1327, 3), (0x1ED4, 1330, 3), (0x1ED5, 1333, 3),
+    (0x1ED6, 1336, 3), (0x1ED7, 1339, 3), (0x1ED8, 1342, 3), (0x1ED9, 1345, 3), (0x1EDA, 1348, 3), (0x1EDB, 1351, 3), (0x1EDC, 1354, 3), (0x1EDD, 1357, 3),
+    (0x1EDE, 1360, 3), (0x1EDF, 1363, 3), (0x1EE0, 1366, 3), (0x1EE1, 1369, 3), (0x1EE2, 1372, 3), (0x1EE3, 1375, 3), (0x1EE4, 1378, 2), (0x1EE5, 1380, 2),
+    (0x1EE6, 1382, 2), (0x1EE7, 1384, 2), (0x1EE8, 1386, 3), (0x1EE9, 1389, 3), (0x1EEA, 1392, 3), (0x1EEB, 1395, 3), (0x1EEC, 1398, 3), (0x1EED, 1401, 3),
+    (0x1EEE, 1404, 3), (0x1EEF, 1407, 3), (0x1EF0, 1410, 3), (0x1EF1, 1413, 3), (0x1EF2, 1416, 2), (0x1EF3, 1418, 2), (0x1EF4, 1420, 2), (0x1EF5, 1422, 2),
+    (0x1EF6, 1424, 2), (0x1EF7, 1426, 2), (0x1EF8, 1428, 2), (0x1EF9, 1430, 2), (0x1F00, 1432, 2), (0x1F01, 1434, 2), (0x1F02, 1436, 3), (0x1F03, 1439, 3),
+    (0x1F04, 1442, 3), (0x1F05, 1445, 3), (0x1F06, 1448, 3), (0x1F07, 1451, 3), (0x1F08, 1454, 2), (0x1F09, 1456, 2), (0x1F0A, 1458, 3), (0x1F0B, 1461, 3),
+    (0x1F0C, 1464, 3), (0x1F0D, 1467, 3), (0x1F0E, 1470, 3), (0x1F0F, 1473, 3), (0x1F10, 1476, 2), (0x1F11, 1478, 2), (0x1F12, 1480, 3), (0x1F13, 1483, 3),
+    (0x1F14, 1486, 3), (0x1F15, 1489, 3), (0x1F18, 1492, 2), (0x1F19, 1494, 2), (0x1F1A, 1496, 3), (0x1F1B, 1499, 3), (0x1F1C, 1502, 3), (0x1F1D, 1505, 3),
+    (0x1F20, 1508, 2), (0x1F21, 1510, 2), (0x1F22, 1512, 3), (0x1F23, 1515, 3), (0x1F24, 1518, 3), (0x1F25, 1521, 3), (0x1F26, 1524, 3), (0x1F27, 1527, 3),
+    (0x1F28, 1530, 2), (0x1F29, 1532, 2), (0x1F2A, 1534, 3), (0x1F2B, 1537, 3), (0x1F2C, 1540, 3), (0x1F2D, 1543, 3), (0x1F2E, 1546, 3), (0x1F2F, 1549, 3),
+    (0x1F30, 1552, 2), (0x1F31, 1554, 2), (0x1F32, 1556, 3), (0x1F33, 1559, 3), (0x1F34, 1562, 3), (0x1F35, 1565, 3), (0x1F36, 1568, 3), (0x1F37, 1571, 3),
+    (0x1F38, 1574, 2), (0x1F39, 1576, 2), (0x1F3A, 1578, 3), (0x1F3B, 1581, 3), (0x1F3C, 1584, 3), (0x1F3D, 1587, 3), (0x1F3E, 1590, 3), (0x1F3F, 1593, 3),
+    (0x1F40, 1596, 2), (0x1F41, 1598, 2), (0x1F42, 1600, 3), (0x1F43, 1603, 3), (0x1F44, 1606, 3), (0x1F45, 1609, 3), (0x1F48, 1612, 2), (0x1F49, 1614, 2),
+    (0x1F4A, 1616, 3), (0x1F4B, 1619, 3), (0x1F4C, 1622, 3), (0x1F4D, 1625, 3), (0x1F50, 1628, 2), (0x1F51, 1630, 2), (0x1F52, 1632, 3), (0x1F53, 1635, 3),
+    (0x1F54, 1638, 3), (0x1F55, 1641, 3), (0x1F56, 1644, 3), (0x1F57, 1647, 3), (0x1F59, 1650, 2), (0x1F5B, 1652, 3), (0x1F5D, 1655, 3), (0x1F5F, 1658, 3),
+    (0x1F60, 1661, 2), (0x1F61, 1663, 2), (0x1F62, 1665, 3), (0x1F63, 1668, 3), (0x1F64, 1671, 3), (0x1F65, 1674, 3), (0x1F66, 1677, 3), (0x1F67, 1680, 3),
+    (0x1F68, 1683, 2), (0x1F69, 1685, 2), (0x1F6A, 1687, 3), (0x1F6B, 1690, 3), (0x1F6C, 1693, 3), (0x1F6D, 1696, 3), (0x1F6E, 1699, 3), (0x1F6F, 1702, 3),
+    (0x1F70, 1705, 2), (0x1F71, 1707, 2), (0x1F72, 1709, 2), (0x1F73, 1711, 2), (0x1F74, 1713, 2), (0x1F75, 1715, 2), (0x1F76, 1717, 2), (0x1F77, 1719, 2),
+    (0x1F78, 1721, 2), (0x1F79, 1723, 2), (0x1F7A, 1725, 2), (0x1F7B, 1727, 2), (0x1F7C, 1729, 2), (0x1F7D, 1731, 2), (0x1F80, 1733, 3), (0x1F81, 1736, 3),
+    (0x1F82, 1739, 4), (0x1F83, 1743, 4), (0x1F84, 1747, 4), (0x1F85, 1751, 4), (0x1F86, 1755, 4), (0x1F87, 1759, 4), (0x1F88, 1763, 3), (0x1F89, 1766, 3),
+    (0x1F8A, 1769, 4), (0x1F8B, 1773, 4), (0x1F8C, 1777, 4), (0x1F8D, 1781, 4), (0x1F8E, 1785, 4), (0x1F8F, 1789, 4), (0x1F90, 1793, 3), (0x1F91, 1796, 3),
+    (0x1F92, 1799, 4), (0x1F93, 1803, 4), (0x1F94, 1807, 4), (0x1F95, 1811, 4), (0x1F96, 1815, 4), (0x1F97, 1819, 4), (0x1F98, 1823, 3), (0x1F99, 1826, 3),
+    (0x1F9A, 1829, 4), (0x1F9B, 1833, 4), (0x1F9C, 1837, 4), (0x1F9D, 1841, 4), (0x1F9E, 1845, 4), (0x1F9F, 1849, 4), (0x1FA0, 1853, 3), (0x1FA1, 1856, 3),
+    (0x1FA2, 1859, 4), (0x1FA3, 1863, 4), (0x1FA4, 1867, 4), (0x1FA5, 1871, 4), (0x1FA6, 1875, 4), (0x1FA7, 1879, 4), (0x1FA8, 1883, 3), (0x1FA9, 1886, 3),
+    (0x1FAA, 1889, 4), (0x1FAB, 1893, 4), (0x1FAC, 1897, 4), (0x1FAD, 1901, 4), (0x1FAE, 1905, 4), (0x1FAF, 1909, 4), (0x1FB0, 1913, 2), (0x1FB1, 1915, 2),
+    (0x1FB2, 1917, 3), (0x1FB3, 1920, 2), (0x1FB4, 1922, 3), (0x1FB6, 1925, 2), (0x1FB7, 1927, 3), (0x1FB8, 1930, 2), (0x1FB9, 1932, 2), (0x1FBA, 1934, 2),
+    (0x1FBB, 1936, 2), (0x1FBC, 1938, 2), (0x1FBE, 1940, 1), (0x1FC1, 1941, 2), (0x1FC2, 1943, 3), (0x1FC3, 1946, 2), (0x1FC4, 1948, 3), (0x1FC6, 1951, 2),
+    (0x1FC7, 1953, 3), (0x1FC8, 1956, 2), (0x1FC9, 1958, 2), (0x1FCA, 1960, 2), (0x1FCB, 1962, 2), (0x1FCC, 1964, 2), (0x1FCD, 1966, 2), (0x1FCE, 1968, 2),
+    (0x1FCF, 1970, 2), (0x1FD0, 1972, 2), (0x1FD1, 1974, 2), (0x1FD2, 1976, 3), (0x1FD3, 1979, 3), (0x1FD6, 1982, 2), (0x1FD7, 1984, 3), (0x1FD8, 1987, 2),
+    (0x1FD9, 1989, 2), (0x1FDA, 1991, 2), (0x1FDB, 1993, 2), (0x1FDD, 1995, 2), (0x1FDE, 1997, 2), (0x1FDF, 1999, 2), (0x1FE0, 2001, 2), (0x1FE1, 2003, 2),
+    (0x1FE2, 2005, 3), (0x1FE3, 2008, 3), (0x1FE4, 2011, 2), (0x1FE5, 2013, 2), (0x1FE6, 2015, 2), (0x1FE7, 2017, 3), (0x1FE8, 2020, 2), (0x1FE9, 2022, 2),
+    (0x1FEA, 2024, 2), (0x1FEB, 2026, 2), (0x1FEC, 2028, 2), (0x1FED, 2030, 2), (0x1FEE, 2032, 2), (0x1FEF, 2034, 1), (0x1FF2, 2035, 3), (0x1FF3, 2038, 2),
+    (0x1FF4, 2040, 3), (0x1FF6, 2043, 2), (0x1FF7, 2045, 3), (0x1FF8, 2048, 2), (0x1FF9, 2050, 2), (0x1FFA, 2052, 2), (0x1FFB, 2054, 2), (0x1FFC, 2056, 2),
+    (0x1FFD, 2058, 1), (0x2000, 2059, 1), (0x2001, 2060, 1), (0x2126, 2061, 1), (0x212A, 2062, 1), (0x212B, 2063, 2), (0x219A, 2065, 2), (0x219B, 2067, 2),
+    (0x21AE, 2069, 2), (0x21CD, 2071, 2), (0x21CE, 2073, 2), (0x21CF, 2075, 2), (0x2204, 2077, 2), (0x2209, 2079, 2), (0x220C, 2081, 2), (0x2224, 2083, 2),
+    (0x2226, 2085, 2), (0x2241, 2087, 2), (0x2244, 2089, 2), (0x2247, 2091, 2), (0x2249, 2093, 2), (0x2260, 2095, 2), (0x2262, 2097, 2), (0x226D, 2099, 2),
+    (0x226E, 2101, 2), (0x226F, 2103, 2), (0x2270, 2105, 2), (0x2271, 2107, 2), (0x2274, 2109, 2), (0x2275, 2111, 2), (0x2278, 2113, 2), (0x2279, 2115, 2),
+    (0x2280, 2117, 2), (0x2281, 2119, 2), (0x2284, 2121, 2), (0x2285, 2123, 2), (0x2288, 2125, 2), (0x2289, 2127, 2), (0x22AC, 2129, 2), (0x22AD, 2131, 2),
+    (0x22AE, 2133, 2), (0x22AF, 2135, 2), (0x22E0, 2137, 2), (0x22E1, 2139, 2), (0x22E2, 2141, 2), (0x22E3, 2143, 2), (0x22EA, 2145, 2), (0x22EB, 2147, 2),
+    (0x22EC, 2149, 2), (0x22ED, 2151, 2), (0x2329, 2153, 1), (0x232A, 2154, 1), (0x2ADC, 2155, 2), (0x304C, 2157, 2), (0x304E, 2159, 2), (0x3050, 2161, 2),
+    (0x3052, 2163, 2), (0x3054, 2165, 2), (0x3056, 2167, 2), (0x3058, 2169, 2), (0x305A, 2171, 2), (0x305C, 2173, 2), (0x305E, 2175, 2), (0x3060, 2177, 2),
+    (0x3062, 2179, 2), (0x3065, 2181, 2), (0x3067, 2183, 2), (0x3069, 2185, 2), (0x3070, 2187, 2), (0x3071, 2189, 2), (0x3073, 2191, 2), (0x3074, 2193, 2),
+    (0x3076, 2195, 2), (0x3077, 2197, 2), (0x3079, 2199, 2), (0x307A, 2201, 2), (0x307C, 2203, 2), (0x307D, 2205, 2), (0x3094, 2207, 2), (0x309E, 2209, 2),
+    (0x30AC, 2211, 2), (0x30AE, 2213, 2), (0x30B0, 2215, 2), (0x30B2, 2217, 2), (0x30B4, 2219, 2), (0x30B6, 2221, 2), (0x30B8, 2223, 2), (0x30BA, 2225, 2),
+    (0x30BC, 2227, 2), (0x30BE, 2229, 2), (0x30C0, 2231, 2), (0x30C2, 2233, 2), (0x30C5, 2235, 2), (0x30C7, 2237, 2), (0x30C9, 2239, 2), (0x30D0, 2241, 2),
+    (0x30D1, 2243, 2), (0x30D3, 2245, 2), (0x30D4, 2247, 2), (0x30D6, 2249, 2), (0x30D7, 2251, 2), (0x30D9, 2253, 2), (0x30DA, 2255, 2), (0x30DC, 2257, 2),
+    (0x30DD, 2259, 2), (0x30F4, 2261, 2), (0x30F7, 2263, 2), (0x30F8, 2265, 2), (0x30F9, 2267, 2), (0x30FA, 2269, 2), (0x30FE, 2271, 2), (0xF900, 2273, 1),
+    (0xF901, 2274, 1), (0xF902, 2275, 1), (0xF903, 2276, 1), (0xF904, 2277, 1), (0xF905, 2278, 1), (0xF906, 2279, 1), (0xF907, 2280, 1), (0xF908, 2281, 1),
+    (0xF909, 2282, 1), (0xF90A, 2283, 1), (0xF90B, 2284, 1), (0xF90C, 2285, 1), (0xF90D, 2286, 1), (0xF90E, 2287, 1), (0xF90F, 2288, 1), (0xF910, 2289, 1),
+    (0xF911, 2290, 1), (0xF912, 2291, 1), (0xF913, 2292, 1), (0xF914, 2293, 1), (0xF915, 2294, 1), (0xF916, 2295, 1), (0xF917, 2296, 1), (0xF918, 2297, 1),
+    (0xF919, 2298, 1), (0xF91A, 2299, 1), (0xF91B, 2300, 1), (0xF91C, 2301, 1), (0xF91D, 2302, 1), (0xF91E, 2303, 1), (0xF91F, 2304, 1), (0xF920, 2305, 1),
+    (0xF921, 2306, 1), (0xF922, 2307, 1), (0xF923, 2308, 1), (0xF924, 2309, 1), (0xF925, 2310, 1), (0xF926, 2311, 1), (0xF927, 2312, 1), (0xF928, 2313, 1),
+    (0xF929, 2314, 1), (0xF92A, 2315, 1), (0xF92B, 2316, 1), (0xF92C, 2317, 1), (0xF92D, 2318, 1), (0xF92E, 2319, 1), (0xF92F, 2320, 1), (0xF930, 2321, 1),
+    (0xF931, 2322, 1), (0xF932, 2323, 1), (0xF933, 2324, 1), (0xF934, 2325, 1), (0xF935, 2326, 1), (0xF936, 2327, 1), (0xF937, 2328, 1), (0xF938, 2329, 1),
+    (0xF939, 2330, 1), (0xF93A, 2331, 1), (0xF93B, 2332, 1), (0xF93C, 2333, 1), (0xF93D, 2334, 1), (0xF93E, 2335, 1), (0xF93F, 2336, 1), (0xF940, 2337, 1),
+    (0xF941, 2338, 1), (0xF942, 2339, 1), (0xF943, 2340, 1), (0xF944, 2341, 1), (0xF945, 2342, 1), (0xF946, 2343, 1), (0xF947, 2344, 1), (0xF948, 2345, 1),
+    (0xF949, 2346, 1), (0xF94A, 2347, 1), (0xF94B, 2348, 1), (0xF94C, 2349, 1), (0xF94D, 2350, 1), (0xF94E, 2351, 1), (0xF94F, 2352, 1), (0xF950, 2353, 1),
+    (0xF951, 2354, 1), (0xF952, 2355, 1), (0xF953, 2356, 1), (0xF954, 2357, 1), (0xF955, 2358, 1), (0xF956, 2359, 1), (0xF957, 2360, 1), (0xF958, 2361, 1),
+    (0xF959, 2362, 1), (0xF95A, 2363, 1), (0xF95B, 2364, 1), (0xF95C, 2365, 1), (0xF95D, 2366, 1), (0xF95E, 2367, 1), (0xF95F, 2368, 1), (0xF960, 2369, 1),
+    (0xF961, 2370, 1), (0xF962, 2371, 1), (0xF963, 2372, 1), (0xF964, 2373, 1), (0xF965, 2374, 1), (0xF966, 2375, 1), (0xF967, 2376, 1), (0xF968, 2377, 1),
+    (0xF969, 2378, 1), (0xF96A, 2379, 1), (0xF96B, 2380, 1), (0xF96C, 2381, 1), (0xF96D, 2382, 1), (0xF96E, 2383, 1), (0xF96F, 2384, 1), (0xF970, 2385, 1),
+    (0xF971, 2386, 1), (0xF972, 2387, 1), (0xF973, 2388, 1), (0xF974, 2389, 1), (0xF975, 2390, 1), (0xF976, 2391, 1), (0xF977, 2392, 1), (0xF978, 2393, 1),
+    (0xF979, 2394, 1), (0xF97A, 2395, 1), (0xF97B, 2396, 1), (0xF97C, 2397, 1), (0xF97D, 2398, 1), (0xF97E, 2399, 1), (0xF97F, 2400, 1), (0xF980, 2401, 1),
+    (0xF981, 2402, 1), (0xF982, 2403, 1), (0xF983, 2404, 1), (0xF984, 2405, 1), (0xF985, 2406, 1), (0xF986, 2407, 1), (0xF987, 2408, 1), (0xF988, 2409, 1),
+    (0xF989, 2410, 1), (0xF98A, 2411, 1), (0xF98B, 2412, 1), (0xF98C, 2413, 1), (0xF98D, 2414, 1), (0xF98E, 2415, 1), (0xF98F, 2416, 1), (0xF990, 2417, 1),
+    (0xF991, 2418, 1), (0xF992, 2419, 1), (0xF993, 2420, 1), (0xF994, 2421, 1), (0xF995, 2422, 1), (0xF996, 2423, 1), (0xF997, 2424, 1), (0xF998, 2425, 1),
+    (0xF999, 2426, 1), (0xF99A, 2427, 1), (0xF99B, 2428, 1), (0xF99C, 2429, 1), (0xF99D, 2430, 1), (0xF99E, 2431, 1), (0xF99F, 2432, 1), (0xF9A0, 2433, 1),
+    (0xF9A1, 2434, 1), (0xF9A2, 2435, 1), (0xF9A3, 2436, 1), (0xF9A4, 2437, 1), (0xF9A5, 2438, 1), (0xF9A6, 2439, 1), (0xF9A7, 2440, 1), (0xF9A8, 2441, 1),
+    (0xF9A9, 2442, 1), (0xF9AA, 2443, 1), (0xF9AB, 2444, 1), (0xF9AC, 2445, 1), (0xF9AD, 2446, 1), (0xF9AE, 2447, 1), (0xF9AF, 2448, 1), (0xF9B0, 2449, 1),
+    (0xF9B1, 2450, 1), (0xF9B2, 2451, 1), (0xF9B3, 2452, 1), (0xF9B4, 2453, 1), (0xF9B5, 2454, 1), (0xF9B6, 2455, 1), (0xF9B7, 2456, 1), (0xF9B8, 2457, 1),
+    (0xF9B9, 2458, 1), (0xF9BA, 2459, 1), (0xF9BB, 2460, 1), (0xF9BC, 2461, 1), (0xF9BD, 2462, 1), (0xF9BE, 2463, 1), (0xF9BF, 2464, 1), (0xF9C0, 2465, 1),
+    (0xF9C1, 2466, 1), (0xF9C2, 2467, 1), (0xF9C3, 2468, 1), (0xF9C4, 2469, 1), (0xF9C5, 2470, 1), (0xF9C6, 2471, 1), (0xF9C7, 2472, 1), (0xF9C8, 2473, 1),
+    (0xF9C9, 2474, 1), (0xF9CA, 2475, 1), (0xF9CB, 2476, 1), (0xF9CC, 2477, 1), (0xF9CD, 2478, 1), (0xF9CE, 2479, 1), (0xF9CF, 2480, 1), (0xF9D0, 2481, 1),
+    (0xF9D1, 2482, 1), (0xF9D2, 2483, 1), (0xF9D3, 2484, 1), (0xF9D4, 2485, 1), (0xF9D5, 2486, 1), (0xF9D6, 2487, 1), (0xF9D7, 2488, 1), (0xF9D8, 2489, 1),
+    (0xF9D9, 2490, 1), (0xF9DA, 2491, 1), (0xF9DB, 2492, 1), (0xF9DC, 2493, 1), (0xF9DD, 2494, 1), (0xF9DE, 2495, 1), (0xF9DF, 2496, 1), (0xF9E0, 2497, 1),
+    (0xF9E1, 2498, 1), (0xF9E2, 2499, 1), (0xF9E3, 2500, 1), (0xF9E4, 2501, 1), (0xF9E5, 2502, 1), (0xF9E6, 2503, 1), (0xF9E7, 2504, 1), (0xF9E8, 2505, 1),
+    (0xF9E9, 2506, 1), (0xF9EA, 2507, 1), (0xF9EB, 2508, 1), (0xF9EC, 2509, 1), (0xF9ED, 2510, 1), (0xF9EE, 2511, 1), (0xF9EF, 2512, 1), (0xF9F0, 2513, 1),
+    (0xF9F1, 2514, 1), (0xF9F2, 2515, 1), (0xF9F3, 2516, 1), (0xF9F4, 2517, 1), (0xF9F5, 2518, 1), (0xF9F6, 2519, 1), (0xF9F7, 2520, 1), (0xF9F8, 2521, 1),
+    (0xF9F9, 2522, 1), (0xF9FA, 2523, 1), (0xF9FB, 2524, 1), (0xF9FC, 2525, 1), (0xF9FD, 2526, 1), (0xF9FE, 2527, 1), (0xF9FF, 2528, 1), (0xFA00, 2529, 1),
+    (0xFA01, 2530, 1), (0xFA02, 2531, 1), (0xFA03, 2532, 1), (0xFA04, 2533, 1), (0xFA05, 2534, 1), (0xFA06, 2535, 1), (0xFA07, 2536, 1), (0xFA08, 2537, 1),
+    (0xFA09, 2538, 1), (0xFA0A, 2539, 1), (0xFA0B, 2540, 1), (0xFA0C, 2541, 1), (0xFA0D, 2542, 1), (0xFA10, 2543, 1), (0xFA12, 2544, 1), (0xFA15, 2545, 1),
+    (0xFA16, 2546, 1), (0xFA17, 2547, 1), (0xFA18, 2548, 1), (0xFA19, 2549, 1), (0xFA1A, 2550, 1), (0xFA1B, 2551, 1), (0xFA1C, 2552, 1), (0xFA1D, 2553, 1),
+    (0xFA1E, 2554, 1), (0xFA20, 2555, 1), (0xFA22, 2556, 1), (0xFA25, 2557, 1), (0xFA26, 2558, 1), (0xFA2A, 2559, 1), (0xFA2B, 2560, 1), (0xFA2C, 2561, 1),
+    (0xFA2D, 2562, 1), (0xFA2E, 2563, 1), (0xFA2F, 2564, 1), (0xFA30, 2565, 1), (0xFA31, 2566, 1), (0xFA32, 2567, 1), (0xFA33, 2568, 1), (0xFA34, 2569, 1),
+    (0xFA35, 2570, 1), (0xFA36, 2571, 1), (0xFA37, 2572, 1), (0xFA38, 2573, 1), (0xFA39, 2574, 1), (0xFA3A, 2575, 1), (0xFA3B, 2576, 1), (0xFA3C, 2577, 1),
+    (0xFA3D, 2578, 1), (0xFA3E, 2579, 1), (0xFA3F, 2580, 1), (0xFA40, 2581, 1), (0xFA41, 2582, 1), (0xFA42, 2583, 1), (0xFA43, 2584, 1), (0xFA44, 2585, 1),
+    (0xFA45, 2586, 1), (0xFA46, 2587, 1), (0xFA47, 2588, 1), (0xFA48, 2589, 1), (0xFA49, 2590, 1), (0xFA4A, 2591, 1), (0xFA4B, 2592, 1), (0xFA4C, 2593, 1),
+    (0xFA4D, 2594, 1), (0xFA4E, 2595, 1), (0xFA4F, 2596, 1), (0xFA50, 2597, 1), (0xFA51, 2598, 1), (0xFA52, 2599, 1), (0xFA53, 2600, 1), (0xFA54, 2601, 1),
+    (0xFA55, 2602, 1), (0xFA56, 2603, 1), (0xFA57, 2604, 1), (0xFA58, 2605, 1), (0xFA59, 2606, 1), (0xFA5A, 2607, 1), (0xFA5B, 2608, 1), (0xFA5C, 2609, 1),
+    (0xFA5D, 2610, 1), (0xFA5E, 2611, 1), (0xFA5F, 2612, 1), (0xFA60, 2613, 1), (0xFA61, 2614, 1), (0xFA62, 2615, 1), (0xFA63, 2616, 1), (0xFA64, 2617, 1),
+    (0xFA65, 2618, 1), (0xFA66, 2619, 1), (0xFA67, 2620, 1), (0xFA68, 2621, 1), (0xFA69, 2622, 1), (0xFA6A, 2623, 1), (0xFA6B, 2624, 1), (0xFA6C, 2625, 1),
+    (0xFA6D, 2626, 1), (0xFA70, 2627, 1), (0xFA71, 2628, 1), (0xFA72, 2629, 1), (0xFA73, 2630, 1), (0xFA74, 2631, 1), (0xFA75, 2632, 1), (0xFA76, 2633, 1),
+    (0xFA77, 2634, 1), (0xFA78, 2635, 1), (0xFA79, 2636, 1), (0xFA7A, 2637, 1), (0xFA7B, 2638, 1), (0xFA7C, 2639, 1), (0xFA7D, 2640, 1), (0xFA7E, 2641, 1),
+    (0xFA7F, 2642, 1), (0xFA80, 2643, 1), (0xFA81, 2644, 1), (0xFA82, 2645, 1), (0xFA83, 2646, 1), (0xFA84, 2647, 1), (0xFA85, 2648, 1), (0xFA86, 2649, 1),
+    (0xFA87, 2650, 1), (0xFA88, 2651, 1), (0xFA89, 2652, 1), (0xFA8A, 2653, 1), (0xFA8B, 2654, 1), (0xFA8C, 2655, 1), (0xFA8D, 2656, 1), (0xFA8E, 2657, 1),
+    (0xFA8F, 2658, 1), (0xFA90, 2659, 1), (0xFA91, 2660, 1), (0xFA92, 2661, 1), (0xFA93, 2662, 1), (0xFA94, 2663, 1), (0xFA95, 2664, 1), (0xFA96, 2665, 1),
+    (0xFA97, 2666, 1), (0xFA98, 2667, 1), (0xFA99, 2668, 1), (0xFA9A, 2669, 1), (0xFA9B, 2670, 1), (0xFA9C, 2671, 1), (0xFA9D, 2672, 1), (0xFA9E, 2673, 1),
+    (0xFA9F, 2674, 1), (0xFAA0, 2675, 1), (0xFAA1, 2676, 1), (0xFAA2, 2677, 1), (0xFAA3, 2678, 1), (0xFAA4, 2679, 1), (0xFAA5, 2680, 1), (0xFAA6, 2681, 1),
+    (0xFAA7, 2682, 1), (0xFAA8, 2683, 1), (0xFAA9, 2684, 1), (0xFAAA, 2685, 1), (0xFAAB, 2686, 1), (0xFAAC, 2687, 1), (0xFAAD, 2688, 1), (0xFAAE, 2689, 1),
+    (0xFAAF, 2690, 1), (0xFAB0, 2691, 1), (0xFAB1, 2692, 1), (0xFAB2, 2693, 1), (0xFAB3, 2694, 1), (0xFAB4, 2695, 1), (0xFAB5, 2696, 1), (0xFAB6, 2697, 1),
+    (0xFAB7, 2698, 1), (0xFAB8, 2699, 1), (0xFAB9, 2700, 1), (0xFABA, 2701, 1), (0xFABB, 2702, 1), (0xFABC, 2703, 1), (0xFABD, 2704, 1), (0xFABE, 2705, 1),
+    (0xFABF, 2706, 1), (0xFAC0, 2707, 1), (0xFAC1, 2708, 1), (0xFAC2, 2709, 1), (0xFAC3, 2710, 1), (0xFAC4, 2711, 1), (0xFAC5, 2712, 1), (0xFAC6, 2713, 1),
+    (0xFAC7, 2714, 1), (0xFAC8, 2715, 1), (0xFAC9, 2716, 1), (0xFACA, 2717, 1), (0xFACB, 2718, 1), (0xFACC, 2719, 1), (0xFACD, 2720, 1), (0xFACE, 2721, 1),
+    (0xFACF, 2722, 1), (0xFAD0, 2723, 1), (0xFAD1, 2724, 1), (0xFAD2, 2725, 1), (0xFAD3, 2726, 1), (0xFAD4, 2727, 1), (0xFAD5, 2728, 1), (0xFAD6, 2729, 1),
+    (0xFAD7, 2730, 1), (0xFAD8, 2731, 1), (0xFAD9, 2732, 1), (0xFB1D, 2733, 2), (0xFB1F, 2735, 2), (0xFB2A, 2737, 2), (0xFB2B, 2739, 2), (0xFB2C, 2741, 3),
+    (0xFB2D, 2744, 3), (0xFB2E, 2747, 2), (0xFB2F, 2749, 2), (0xFB30, 2751, 2), (0xFB31, 2753, 2), (0xFB32, 2755, 2), (0xFB33, 2757, 2), (0xFB34, 2759, 2),
+    (0xFB35, 2761, 2), (0xFB36, 2763, 2), (0xFB38, 2765, 2), (0xFB39, 2767, 2), (0xFB3A, 2769, 2), (0xFB3B, 2771, 2), (0xFB3C, 2773, 2), (0xFB3E, 2775, 2),
+    (0xFB40, 2777, 2), (0xFB41, 2779, 2), (0xFB43, 2781, 2), (0xFB44, 2783, 2), (0xFB46, 2785, 2), (0xFB47, 2787, 2), (0xFB48, 2789, 2), (0xFB49, 2791, 2),
+    (0xFB4A, 2793, 2), (0xFB4B, 2795, 2), (0xFB4C, 2797, 2), (0xFB4D, 2799, 2), (0xFB4E, 2801, 2), (0x1109A, 2803, 2), (0x1109C, 2805, 2), (0x110AB, 2807, 2),
+    (0x1112E, 2809, 2), (0x1112F, 2811, 2), (0x1134B, 2813, 2), (0x1134C, 2815, 2), (0x114BB, 2817, 2), (0x114BC, 2819, 2), (0x114BE, 2821, 2), (0x115BA, 2823, 2),
+    (0x115BB, 2825, 2), (0x11938, 2827, 2), (0x1D15E, 2829, 2), (0x1D15F, 2831, 2), (0x1D160, 2833, 3), (0x1D161, 2836, 3), (0x1D162, 2839, 3), (0x1D163, 2842, 3),
+    (0x1D164, 2845, 3), (0x1D1BB, 2848, 2), (0x1D1BC, 2850, 2), (0x1D1BD, 2852, 3), (0x1D1BE, 2855, 3), (0x1D1BF, 2858, 3), (0x1D1C0, 2861, 3), (0x2F800, 2864, 1),
+    (0x2F801, 2865, 1), (0x2F802, 2866, 1), (0x2F803, 2867, 1), (0x2F804, 2868, 1), (0x2F805, 2869, 1), (0x2F806, 2870, 1), (0x2F807, 2871, 1), (0x2F808, 2872, 1),
+    (0x2F809, 2873, 1), (0x2F80A, 2874, 1), (0x2F80B, 2875, 1), (0x2F80C, 2876, 1), (0x2F80D, 2877, 1), (0x2F80E, 2878, 1), (0x2F80F, 2879, 1), (0x2F810, 2880, 1),
+    (0x2F811, 2881, 1), (0x2F812, 2882, 1), (0x2F813, 2883, 1), (0x2F814, 2884, 1), (0x2F815, 2885, 1), (0x2F816, 2886, 1), (0x2F817, 2887, 1), (0x2F818, 2888, 1),
+    (0x2F819, 2889, 1), (0x2F81A, 2890, 1), (0x2F81B, 2891, 1), (0x2F81C, 2892, 1), (0x2F81D, 2893, 1), (0x2F81E, 2894, 1), (0x2F81F, 2895, 1), (0x2F820, 2896, 1),
+    (0x2F821, 2897, 1), (0x2F822, 2898, 1), (0x2F823, 2899, 1), (0x2F824, 2900, 1), (0x2F825, 2901, 1), (0x2F826, 2902, 1), (0x2F827, 2903, 1), (0x2F828, 2904, 1),
+    (0x2F829, 2905, 1), (0x2F82A, 2906, 1), (0x2F82B, 2907, 1), (0x2F82C, 2908, 1), (0x2F82D, 2909, 1), (0x2F82E, 2910, 1), (0x2F82F, 2911, 1), (0x2F830, 2912, 1),
+    (0x2F831, 2913, 1), (0x2F832, 2914, 1), (0x2F833, 2915, 1), (0x2F834, 2916, 1), (0x2F835, 2917, 1), (0x2F836, 2918, 1), (0x2F837, 2919, 1), (0x2F838, 2920, 1),
+    (0x2F839, 2921, 1), (0x2F83A, 2922, 1), (0x2F83B, 2923, 1), (0x2F83C, 2924, 1), (0x2F83D, 2925, 1), (0x2F83E, 2926, 1), (0x2F83F, 2927, 1), (0x2F840, 2928, 1),
+    (0x2F841, 2929, 1), (0x2F842, 2930, 1), (0x2F843, 2931, 1), (0x2F844, 2932, 1), (0x2F845, 2933, 1), (0x2F846, 2934, 1), (0x2F847, 2935, 1), (0x2F848, 2936, 1),
+    (0x2F849, 2937, 1), (0x2F84A, 2938, 1), (0x2F84B, 2939, 1), (0x2F84C, 2940, 1), (0x2F84D, 2941, 1), (0x2F84E, 2942, 1), (0x2F84F, 2943, 1), (0x2F850, 2944, 1),
+    (0x2F851, 2945, 1), (0x2F852, 2946, 1), (0x2F853, 2947, 1), (0x2F854, 2948, 1), (0x2F855, 2949, 1), (0x2F856, 2950, 1), (0x2F857, 2951, 1), (0x2F858, 2952, 1),
+    (0x2F859, 2953, 1), (0x2F85A, 2954, 1), (0x2F85B, 2955, 1), (0x2F85C, 2956, 1), (0x2F85D, 2957, 1), (0x2F85E, 2958, 1), (0x2F85F, 2959, 1), (0x2F860, 2960, 1),
+    (0x2F861, 2961, 1), (0x2F862, 2962, 1), (0x2F863, 2963, 1), (0x2F864, 2964, 1), (0x2F865, 2965, 1), (0x2F866, 2966, 1), (0x2F867, 2967, 1), (0x2F868, 2968, 1),
+    (0x2F869, 2969, 1), (0x2F86A, 2970, 1), (0x2F86B, 2971, 1), (0x2F86C, 2972, 1), (0x2F86D, 2973, 1), (0x2F86E, 2974, 1), (0x2F86F, 2975, 1), (0x2F870, 2976, 1),
+    (0x2F871, 2977, 1), (0x2F872, 2978, 1), (0x2F873, 2979, 1), (0x2F874, 2980, 1), (0x2F875, 2981, 1), (0x2F876, 2982, 1), (0x2F877, 2983, 1), (0x2F878, 2984, 1),
+    (0x2F879, 2985, 1), (0x2F87A, 2986, 1), (0x2F87B, 2987, 1), (0x2F87C, 2988, 1), (0x2F87D, 2989, 1), (0x2F87E, 2990, 1), (0x2F87F, 2991, 1), (0x2F880, 2992, 1),
+    (0x2F881, 2993, 1), (0x2F882, 2994, 1), (0x2F883, 2995, 1), (0x2F884, 2996, 1), (0x2F885, 2997, 1), (0x2F886, 2998, 1), (0x2F887, 2999, 1), (0x2F888, 3000, 1),
+    (0x2F889, 3001, 1), (0x2F88A, 3002, 1), (0x2F88B, 3003, 1), (0x2F88C, 3004, 1), (0x2F88D, 3005, 1), (0x2F88E, 3006, 1), (0x2F88F, 3007, 1), (0x2F890, 3008, 1),
+    (0x2F891, 3009, 1), (0x2F892, 3010, 1), (0x2F893, 3011, 1), (0x2F894, 3012, 1), (0x2F895, 3013, 1), (0x2F896, 3014, 1), (0x2F897, 3015, 1), (0x2F898, 3016, 1),
+    (0x2F899, 3017, 1), (0x2F89A, 3018, 1), (0x2F89B, 3019, 1), (0x2F89C, 3020, 1), (0x2F89D, 3021, 1), (0x2F89E, 3022, 1), (0x2F89F, 3023, 1), (0x2F8A0, 3024, 1),
+    (0x2F8A1, 3025, 1), (0x2F8A2, 3026, 1), (0x2F8A3, 3027, 1), (0x2F8A4, 3028, 1), (0x2F8A5, 3029, 1), (0x2F8A6, 3030, 1), (0x2F8A7, 3031, 1), (0x2F8A8, 3032, 1),
+    (0x2F8A9, 3033, 1), (0x2F8AA, 3034, 1), (0x2F8AB, 3035, 1), (0x2F8AC, 3036, 1), (0x2F8AD, 3037, 1), (0x2F8AE, 3038, 1), (0x2F8AF, 3039, 1), (0x2F8B0, 3040, 1),
+    (0x2F8B1, 3041, 1), (0x2F8B2, 3042, 1), (0x2F8B3, 3043, 1), (0x2F8B4, 3044, 1), (0x2F8B5, 3045, 1), (0x2F8B6, 3046, 1), (0x2F8B7, 3047, 1), (0x2F8B8, 3048, 1),
+    (0x2F8B9, 3049, 1), (0x2F8BA, 3050, 1), (0x2F8BB, 3051, 1), (0x2F8BC, 3052, 1), (0x2F8BD, 3053, 1), (0x2F8BE, 3054, 1), (0x2F8BF, 3055, 1), (0x2F8C0, 3056, 1),
+    (0x2F8C1, 3057, 1), (0x2F8C2, 3058, 1), (0x2F8C3, 3059, 1), (0x2F8C4, 3060, 1), (0x2F8C5, 3061, 1), (0x2F8C6, 3062, 1), (0x2F8C7, 3063, 1), (0x2F8C8, 3064, 1),
+    (0x2F8C9, 3065, 1), (0x2F8CA, 3066, 1), (0x2F8CB, 3067, 1), (0x2F8CC, 3068, 1), (0x2F8CD, 3069, 1), (0x2F8CE, 3070, 1), (0x2F8CF, 3071, 1), (0x2F8D0, 3072, 1),
+    (0x2F8D1, 3073, 1), (0x2F8D2, 3074, 1), (0x2F8D3, 3075, 1), (0x2F8D4, 3076, 1), (0x2F8D5, 3077, 1), (0x2F8D6, 3078, 1), (0x2F8D7, 3079, 1), (0x2F8D8, 3080, 1),
+    (0x2F8D9, 3081, 1), (0x2F8DA, 3082, 1), (0x2F8DB, 3083, 1), (0x2F8DC, 3084, 1), (0x2F8DD, 3085, 1), (0x2F8DE, 3086, 1), (0x2F8DF, 3087, 1), (0x2F8E0, 3088, 1),
+    (0x2F8E1, 3089, 1), (0x2F8E2, 3090, 1), (0x2F8E3, 3091, 1), (0x2F8E4, 3092, 1), (0x2F8E5, 3093, 1), (0x2F8E6, 3094, 1), (0x2F8E7, 3095, 1), (0x2F8E8, 3096, 1),
+    (0x2F8E9, 3097, 1), (0x2F8EA, 3098, 1), (0x2F8EB, 3099, 1), (0x2F8EC, 3100, 1), (0x2F8ED, 3101, 1), (0x2F8EE, 3102, 1), (0x2F8EF, 3103, 1), (0x2F8F0, 3104, 1),
+    (0x2F8F1, 3105, 1), (0x2F8F2, 3106, 1), (0x2F8F3, 3107, 1), (0x2F8F4, 3108, 1), (0x2F8F5, 3109, 1), (0x2F8F6, 3110, 1), (0x2F8F7, 3111, 1), (0x2F8F8, 3112, 1),
+    (0x2F8F9, 3113, 1), (0x2F8FA, 3114, 1), (0x2F8FB, 3115, 1), (0x2F8FC, 3116, 1), (0x2F8FD, 3117, 1), (0x2F8FE, 3118, 1), (0x2F8FF, 3119, 1), (0x2F900, 3120, 1),
+    (0x2F901, 3121, 1), (0x2F902, 3122, 1), (0x2F903, 3123, 1), (0x2F904, 3124, 1), (0x2F905, 3125, 1), (0x2F906, 3126, 1), (0x2F907, 3127, 1), (0x2F908, 3128, 1),
+    (0x2F909, 3129, 1), (0x2F90A, 3130, 1), (0x2F90B, 3131, 1), (0x2F90C, 3132, 1), (0x2F90D, 3133, 1), (0x2F90E, 3134, 1), (0x2F90F, 3135, 1), (0x2F910, 3136, 1),
+    (0x2F911, 3137, 1), (0x2F912, 3138, 1), (0x2F913, 3139, 1), (0x2F914, 3140, 1), (0x2F915, 3141, 1), (0x2F916, 3142, 1), (0x2F917, 3143, 1), (0x2F918, 3144, 1),
+    (0x2F919, 3145, 1), (0x2F91A, 3146, 1), (0x2F91B, 3147, 1), (0x2F91C, 3148, 1), (0x2F91D, 3149, 1), (0x2F91E, 3150, 1), (0x2F91F, 3151, 1), (0x2F920, 3152, 1),
+    (0x2F921, 3153, 1), (0x2F922, 3154, 1), (0x2F923, 3155, 1), (0x2F924, 3156, 1), (0x2F925, 3157, 1), (0x2F926, 3158, 1), (0x2F927, 3159, 1), (0x2F928, 3160, 1),
+    (0x2F929, 3161, 1), (0x2F92A, 3162, 1), (0x2F92B, 3163, 1), (0x2F92C, 3164, 1), (0x2F92D, 3165, 1), (0x2F92E, 3166, 1), (0x2F92F, 3167, 1), (0x2F930, 3168, 1),
+    (0x2F931, 3169, 1), (0x2F932, 3170, 1), (0x2F933, 3171, 1), (0x2F934, 3172, 1), (0x2F935, 3173, 1), (0x2F936, 3174, 1), (0x2F937, 3175, 1), (0x2F938, 3176, 1),
+    (0x2F939, 3177, 1), (0x2F93A, 3178, 1), (0x2F93B, 3179, 1), (0x2F93C, 3180, 1), (0x2F93D, 3181, 1), (0x2F93E, 3182, 1), (0x2F93F, 3183, 1), (0x2F940, 3184, 1),
+    (0x2F941, 3185, 1), (0x2F942, 3186, 1), (0x2F943, 3187, 1), (0x2F944, 3188, 1), (0x2F945, 3189, 1), (0x2F946, 3190, 1), (0x2F947, 3191, 1), (0x2F948, 3192, 1),
+    (0x2F949, 3193, 1), (0x2F94A, 3194, 1), (0x2F94B, 3195, 1), (0x2F94C, 3196, 1), (0x2F94D, 3197, 1), (0x2F94E, 3198, 1), (0x2F94F, 3199, 1), (0x2F950, 3200, 1),
+    (0x2F951, 3201, 1), (0x2F952, 3202, 1), (0x2F953, 3203, 1), (0x2F954, 3204, 1), (0x2F955, 3205, 1), (0x2F956, 3206, 1), (0x2F957, 3207, 1), (0x2F958, 3208, 1),
+    (0x2F959, 3209, 1), (0x2F95A, 3210, 1), (0x2F95B, 3211, 1), (0x2F95C, 3212, 1), (0x2F95D, 3213, 1), (0x2F95E, 3214, 1), (0x2F95F, 3215, 1), (0x2F960, 3216, 1),
+    (0x2F961, 3217, 1), (0x2F962, 3218, 1), (0x2F963, 3219, 1), (0x2F964, 3220, 1), (0x2F965, 3221, 1), (0x2F966, 3222, 1), (0x2F967, 3223, 1), (0x2F968, 3224, 1),
+    (0x2F969, 3225, 1), (0x2F96A, 3226, 1), (0x2F96B, 3227, 1), (0x2F96C, 3228, 1), (0x2F96D, 3229, 1), (0x2F96E, 3230, 1), (0x2F96F, 3231, 1), (0x2F970, 3232, 1),
+    (0x2F971, 3233, 1), (0x2F972, 3234, 1), (0x2F973, 3235, 1), (0x2F974, 3236, 1), (0x2F975, 3237, 1), (0x2F976, 3238, 1), (0x2F977, 3239, 1), (0x2F978, 3240, 1),
+    (0x2F979, 3241, 1), (0x2F97A, 3242, 1), (0x2F97B, 3243, 1), (0x2F97C, 3244, 1), (0x2F97D, 3245, 1), (0x2F97E, 3246, 1), (0x2F97F, 3247, 1), (0x2F980, 3248, 1),
+    (0x2F981, 3249, 1), (0x2F982, 3250, 1), (0x2F983, 3251, 1), (0x2F984, 3252, 1), (0x2F985, 3253, 1), (0x2F986, 3254, 1), (0x2F987, 3255, 1), (0x2F988, 3256, 1),
+    (0x2F989, 3257, 1), (0x2F98A, 3258, 1), (0x2F98B, 3259, 1), (0x2F98C, 3260, 1), (0x2F98D, 3261, 1), (0x2F98E, 3262, 1), (0x2F98F, 3263, 1), (0x2F990, 3264, 1),
+    (0x2F991, 3265, 1), (0x2F992, 3266, 1), (0x2F993, 3267, 1), (0x2F994, 3268, 1), (0x2F995, 3269, 1), (0x2F996, 3270, 1), (0x2F997, 3271, 1), (0x2F998, 3272, 1),
+    (0x2F999, 3273, 1), (0x2F99A, 3274, 1), (0x2F99B, 3275, 1), (0x2F99C, 3276, 1), (0x2F99D, 3277, 1), (0x2F99E, 3278, 1), (0x2F99F, 3279, 1), (0x2F9A0, 3280, 1),
+    (0x2F9A1, 3281, 1), (0x2F9A2, 3282, 1), (0x2F9A3, 3283, 1), (0x2F9A4, 3284, 1), (0x2F9A5, 3285, 1), (0x2F9A6, 3286, 1), (0x2F9A7, 3287, 1), (0x2F9A8, 3288, 1),
+    (0x2F9A9, 3289, 1), (0x2F9AA, 3290, 1), (0x2F9AB, 3291, 1), (0x2F9AC, 3292, 1), (0x2F9AD, 3293, 1), (0x2F9AE, 3294, 1), (0x2F9AF, 3295, 1), (0x2F9B0, 3296, 1),
+    (0x2F9B1, 3297, 1), (0x2F9B2, 3298, 1), (0x2F9B3, 3299, 1), (0x2F9B4, 3300, 1), (0x2F9B5, 3301, 1), (0x2F9B6, 3302, 1), (0x2F9B7, 3303, 1), (0x2F9B8, 3304, 1),
+    (0x2F9B9, 3305, 1), (0x2F9BA, 3306, 1), (0x2F9BB, 3307, 1), (0x2F9BC, 3308, 1), (0x2F9BD, 3309, 1), (0x2F9BE, 3310, 1), (0x2F9BF, 3311, 1), (0x2F9C0, 3312, 1),
+    (0x2F9C1, 3313, 1), (0x2F9C2, 3314, 1), (0x2F9C3, 3315, 1), (0x2F9C4, 3316, 1), (0x2F9C5, 3317, 1), (0x2F9C6, 3318, 1), (0x2F9C7, 3319, 1), (0x2F9C8, 3320, 1),
+    (0x2F9C9, 3321, 1), (0x2F9CA, 3322, 1), (0x2F9CB, 3323, 1), (0x2F9CC, 3324, 1), (0x2F9CD, 3325, 1), (0x2F9CE, 3326, 1), (0x2F9CF, 3327, 1), (0x2F9D0, 3328, 1),
+    (0x2F9D1, 3329, 1), (0x2F9D2, 3330, 1), (0x2F9D3, 3331, 1), (0x2F9D4, 3332, 1), (0x2F9D5, 3333, 1), (0x2F9D6, 3334, 1), (0x2F9D7, 3335, 1), (0x2F9D8, 3336, 1),
+    (0x2F9D9, 3337, 1), (0x2F9DA, 3338, 1), (0x2F9DB, 3339, 1), (0x2F9DC, 3340, 1), (0x2F9DD, 3341, 1), (0x2F9DE, 3342, 1), (0x2F9DF, 3343, 1), (0x2F9E0, 3344, 1),
+    (0x2F9E1, 3345, 1), (0x2F9E2, 3346, 1), (0x2F9E3, 3347, 1), (0x2F9E4, 3348, 1), (0x2F9E5, 3349, 1), (0x2F9E6, 3350, 1), (0x2F9E7, 3351, 1), (0x2F9E8, 3352, 1),
+    (0x2F9E9, 3353, 1), (0x2F9EA, 3354, 1), (0x2F9EB, 3355, 1), (0x2F9EC, 3356, 1), (0x2F9ED, 3357, 1), (0x2F9EE, 3358, 1), (0x2F9EF, 3359, 1), (0x2F9F0, 3360, 1),
+    (0x2F9F1, 3361, 1), (0x2F9F2, 3362, 1), (0x2F9F3, 3363, 1), (0x2F9F4, 3364, 1), (0x2F9F5, 3365, 1), (0x2F9F6, 3366, 1), (0x2F9F7, 3367, 1), (0x2F9F8, 3368, 1),
+    (0x2F9F9, 3369, 1), (0x2F9FA, 3370, 1), (0x2F9FB, 3371, 1), (0x2F9FC, 3372, 1), (0x2F9FD, 3373, 1), (0x2F9FE, 3374, 1), (0x2F9FF, 3375, 1), (0x2FA00, 3376, 1),
+    (0x2FA01, 3377, 1), (0x2FA02, 3378, 1), (0x2FA03, 3379, 1), (0x2FA04, 3380, 1), (0x2FA05, 3381, 1), (0x2FA06, 3382, 1), (0x2FA07, 3383, 1), (0x2FA08, 3384, 1),
+    (0x2FA09, 3385, 1), (0x2FA0A, 3386, 1), (0x2FA0B, 3387, 1), (0x2FA0C, 3388, 1), (0x2FA0D, 3389, 1), (0x2FA0E, 3390, 1), (0x2FA0F, 3391, 1), (0x2FA10, 3392, 1),
+    (0x2FA11, 3393, 1), (0x2FA12, 3394, 1), (0x2FA13, 3395, 1), (0x2FA14, 3396, 1), (0x2FA15, 3397, 1), (0x2FA16, 3398, 1), (0x2FA17, 3399, 1), (0x2FA18, 3400, 1),
+    (0x2FA19, 3401, 1), (0x2FA1A, 3402, 1), (0x2FA1B, 3403, 1), (0x2FA1C, 3404, 1), (0x2FA1D, 3405, 1),
+];
+
+#[rustfmt::skip]
+pub(crate) const CANONICAL_CHARS: &[char] = &[
+    '\u{41}', '\u{300}', '\u{41}', '\u{301}', '\u{41}', '\u{302}', '\u{41}', '\u{303}', '\u{41}', '\u{308}',
+    '\u{41}', '\u{30A}', '\u{43}', '\u{327}', '\u{45}', '\u{300}', '\u{45}', '\u{301}', '\u{45}', '\u{302}',
+    '\u{45}', '\u{308}', '\u{49}', '\u{300}', '\u{49}', '\u{301}', '\u{49}', '\u{302}', '\u{49}', '\u{308}',
+    '\u{4E}', '\u{303}', '\u{4F}', '\u{300}', '\u{4F}', '\u{301}', '\u{4F}', '\u{302}', '\u{4F}', '\u{303}',
+    '\u{4F}', '\u{308}', '\u{55}', '\u{300}', '\u{55}', '\u{301}', '\u{55}', '\u{302}', '\u{55}', '\u{308}',
+    '\u{59}', '\u{301}', '\u{61}', '\u{300}', '\u{61}', '\u{301}', '\u{61}', '\u{302}', '\u{61}', '\u{303}',
+    '\u{61}', '\u{308}', '\u{61}', '\u{30A}', '\u{63}', '\u{327}', '\u{65}', '\u{300}', '\u{65}', '\u{301}',
+    '\u{65}', '\u{302}', '\u{65}', '\u{308}', '\u{69}', '\u{300}', '\u{69}', '\u{301}', '\u{69}', '\u{302}',
+    '\u{69}', '\u{308}', '\u{6E}', '\u{303}', '\u{6F}', '\u{300}', '\u{6F}', '\u{301}', '\u{6F}', '\u{302}',
+    '\u{6F}', '\u{303}', '\u{6F}', '\u{308}', '\u{75}', '\u{300}', '\u{75}', '\u{301}', '\u{75}', '\u{302}',
+    '\u{75}', '\u{308}', '\u{79}', '\u{301}', '\u{79}', '\u{308}', '\u{41}', '\u{304}', '\u{61}', '\u{304}',
+    '\u{41}', '\u{306}', '\u{61}', '\u{306}', '\u{41}', '\u{328}', '\u{61}', '\u{328}', '\u{43}', '\u{301}',
+    '\u{63}', '\u{301}', '\u{43}', '\u{302}', '\u{63}', '\u{302}', '\u{43}', '\u{307}', '\u{63}', '\u{307}',
+    '\u{43}', '\u{30C}', '\u{63}', '\u{30C}', '\u{44}', '\u{30C}', '\u{64}', '\u{30C}', '\u{45}', '\u{304}',
+    '\u{65}', '\u{304}', '\u{45}', '\u{306}', '\u{65}', '\u{306}', '\u{45}', '\u{307}', '\u{65}', '\u{307}',
+    '\u{45}', '\u{328}', '\u{65}', '\u{328}', '\u{45}', '\u{30C}', '\u{65}', '\u{30C}', '\u{47}', '\u{302}',
+    '\u{67}', '\u{302}', '\u{47}', '\u{306}', '\u{67}', '\u{306}', '\u{47}', '\u{307}', '\u{67}', '\u{307}',
+    '\u{47}', '\u{327}', '\u{67}', '\u{327}', '\u{48}', '\u{302}', '\u{68}', '\u{302}', '\u{49}', '\u{303}',
+    '\u{69}', '\u{303}', '\u{49}', '\u{304}', '\u{69}', '\u{304}', '\u{49}', '\u{306}', '\u{69}', '\u{306}',
+    '\u{49}', '\u{328}', '\u{69}', '\u{328}', '\u{49}', '\u{307}', '\u{4A}', '\u{302}', '\u{6A}', '\u{302}',
+    '\u{4B}', '\u{327}', '\u{6B}', '\u{327}', '\u{4C}', '\u{301}', '\u{6C}', '\u{301}', '\u{4C}', '\u{327}',
+    '\u{6C}', '\u{327}', '\u{4C}', '\u{30C}', '\u{6C}', '\u{30C}', '\u{4E}', '\u{301}', '\u{6E}', '\u{301}',
+    '\u{4E}', '\u{327}', '\u{6E}', '\u{327}', '\u{4E}', '\u{30C}', '\u{6E}', '\u{30C}', '\u{4F}', '\u{304}',
+    '\u{6F}', '\u{304}', '\u{4F}', '\u{306}', '\u{6F}', '\u{306}', '\u{4F}', '\u{30B}', '\u{6F}', '\u{30B}',
+    '\u{52}', '\u{301}', '\u{72}', '\u{301}', '\u{52}', '\u{327}', '\u{72}', '\u{327}', '\u{52}', '\u{30C}',
+    '\u{72}', '\u{30C}', '\u{53}', '\u{301}', '\u{73}', '\u{301}', '\u{53}', '\u{302}', '\u{73}', '\u{302}',
+    '\u{53}', '\u{327}', '\u{73}', '\u{327}', '\u{53}', '\u{30C}', '\u{73}', '\u{30C}', '\u{54}', '\u{327}',
+    '\u{74}', '\u{327}', '\u{54}', '\u{30C}', '\u{74}', '\u{30C}', '\u{55}', '\u{303}', '\u{75}', '\u{303}',
+    '\u{55}', '\u{304}', '\u{75}', '\u{304}', '\u{55}', '\u{306}', '\u{75}', '\u{306}', '\u{55}', '\u{30A}',
+    '\u{75}', '\u{30A}', '\u{55}', '\u{30B}', '\u{75}', '\u{30B}', '\u{55}', '\u{328}', '\u{75}', '\u{328}',
+    '\u{57}', '\u{302}', '\u{77}', '\u{302}', '\u{59}', '\u{302}', '\u{79}', '\u{302}', '\u{59}', '\u{308}',
+    '\u{5A}', '\u{301}', '\u{7A}', '\u{301}', '\u{5A}', '\u{307}', '\u{7A}', '\u{307}', '\u{5A}', '\u{30C}',
+    '\u{7A}', '\u{30C}', '\u{4F}', '\u{31B}', '\u{6F}', '\u{31B}', '\u{55}', '\u{31B}', '\u{75}', '\u{31B}',
+    '\u{41}', '\u{30C}', '\u{61}', '\u{30C}', '\u{49}', '\u{30C}', '\u{69}', '\u{30C}', '\u{4F}', '\u{30C}',
+    '\u{6F}', '\u{30C}', '\u{55}', '\u{30C}', '\u{75}', '\u{30C}', '\u{55}', '\u{308}', '\u{304}', '\u{75}',
+    '\u{308}', '\u{304}', '\u{55}', '\u{308}', '\u{301}', '\u{75}', '\u{308}', '\u{301}', '\u{55}', '\u{308}',
+    '\u{30C}', '\u{75}', '\u{308}', '\u{30C}', '\u{55}', '\u{308}', '\u{300}', '\u{75}', '\u{308}', '\u{300}',
+    '\u{41}', '\u{308}', '\u{304}', '\u{61}', '\u{308}', '\u{304}', '\u{41}', '\u{307}', '\u{304}', '\u{61}',
+    '\u{307}', '\u{304}', '\u{C6}', '\u{304}', '\u{E6}', '\u{304}', '\u{47}', '\u{30C}', '\u{67}', '\u{30C}',
+    '\u{4B}', '\u{30C}', '\u{6B}', '\u{30C}', '\u{4F}', '\u{328}', '\u{6F}', '\u{328}', '\u{4F}', '\u{328}',
+    '\u{304}', '\u{6F}', '\u{328}', '\u{304}', '\u{1B7}', '\u{30C}', '\u{292}', '\u{30C}', '\u{6A}', '\u{30C}',
+    '\u{47}', '\u{301}', '\u{67}', '\u{301}', '\u{4E}', '\u{300}', '\u{6E}', '\u{300}', '\u{41}', '\u{30A}',
+    '\u{301}', '\u{61}', '\u{30A}', '\u{301}', '\u{C6}', '\u{301}', '\u{E6}', '\u{301}', '\u{D8}', '\u{301}',
+    '\u{F8}', '\u{301}', '\u{41}', '\u{30F}', '\u{61}', '\u{30F}', '\u{41}', '\u{311}', '\u{61}', '\u{311}',
+    '\u{45}', '\u{30F}', '\u{65}', '\u{30F}', '\u{45}', '\u{311}', '\u{65}', '\u{311}', '\u{49}', '\u{30F}',
+    '\u{69}', '\u{30F}', '\u{49}', '\u{311}', '\u{69}', '\u{311}', '\u{4F}', '\u{30F}', '\u{6F}', '\u{30F}',
+    '\u{4F}', '\u{311}', '\u{6F}', '\u{311}', '\u{52}', '\u{30F}', '\u{72}', '\u{30F}', '\u{52}', '\u{311}',
+    '\u{72}', '\u{311}', '\u{55}', '\u{30F}', '\u{75}', '\u{30F}', '\u{55}', '\u{311}', '\u{75}', '\u{311}',
+    '\u{53}', '\u{326}', '\u{73}', '\u{326}', '\u{54}', '\u{326}', '\u{74}', '\u{326}', '\u{48}', '\u{30C}',
+    '\u{68}', '\u{30C}', '\u{41}', '\u{307}', '\u{61}', '\u{307}', '\u{45}', '\u{327}', '\u{65}', '\u{327}',
+    '\u{4F}', '\u{308}', '\u{304}', '\u{6F}', '\u{308}', '\u{304}', '\u{4F}', '\u{303}', '\u{304}', '\u{6F}',
+    '\u{303}', '\u{304}', '\u{4F}', '\u{307}', '\u{6F}', '\u{307}', '\u{4F}', '\u{307}', '\u{304}', '\u{6F}',
+    '\u{307}', '\u{304}', '\u{59}', '\u{304}', '\u{79}', '\u{304}', '\u{300}', '\u{301}', '\u{313}', '\u{308}',
+    '\u{301}', '\u{2B9}', '\u{3B}', '\u{A8}', '\u{301}', '\u{391}', '\u{301}', '\u{B7}', '\u{395}', '\u{301}',
+    '\u{397}', '\u{301}', '\u{399}', '\u{301}', '\u{39F}', '\u{301}', '\u{3A5}', '\u{301}', '\u{3A9}', '\u{301}',
+    '\u{3B9}', '\u{308}', '\u{301}', '\u{399}', '\u{308}', '\u{3A5}', '\u{308}', '\u{3B1}', '\u{301}', '\u{3B5}',
+    '\u{301}', '\u{3B7}', '\u{301}', '\u{3B9}', '\u{301}', '\u{3C5}', '\u{308}', '\u{301}', '\u{3B9}', '\u{308}',
+    '\u{3C5}', '\u{308}', '\u{3BF}', '\u{301}', '\u{3C5}', '\u{301}', '\u{3C9}', '\u{301}', '\u{3D2}', '\u{301}',
+    '\u{3D2}', '\u{308}', '\u{415}', '\u{300}', '\u{415}', '\u{308}', '\u{413}', '\u{301}', '\u{406}', '\u{308}',
+    '\u{41A}', '\u{301}', '\u{418}', '\u{300}', '\u{423}', '\u{306}', '\u{418}', '\u{306}', '\u{438}', '\u{306}',
+    '\u{435}', '\u{300}', '\u{435}', '\u{308}', '\u{433}', '\u{301}', '\u{456}', '\u{308}', '\u{43A}', '\u{301}',
+    '\u{438}', '\u{300}', '\u{443}', '\u{306}', '\u{474}', '\u{30F}', '\u{475}', '\u{30F}', '\u{416}', '\u{306}',
+    '\u{436}', '\u{306}', '\u{410}', '\u{306}', '\u{430}', '\u{306}', '\u{410}', '\u{308}', '\u{430}', '\u{308}',
+    '\u{415}', '\u{306}', '\u{435}', '\u{306}', '\u{4D8}', '\u{308}', '\u{4D9}', '\u{308}', '\u{416}', '\u{308}',
+    '\u{436}', '\u{308}', '\u{417}', '\u{308}', '\u{437}', '\u{308}', '\u{418}', '\u{304}', '\u{438}', '\u{304}',
+    '\u{418}', '\u{308}', '\u{438}', '\u{308}', '\u{41E}', '\u{308}', '\u{43E}', '\u{308}', '\u{4E8}', '\u{308}',
+    '\u{4E9}', '\u{308}', '\u{42D}', '\u{308}', '\u{44D}', '\u{308}', '\u{423}', '\u{304}', '\u{443}', '\u{304}',
+    '\u{423}', '\u{308}', '\u{443}', '\u{308}', '\u{423}', '\u{30B}', '\u{443}', '\u{30B}', '\u{427}', '\u{308}',
+    '\u{447}', '\u{308}', '\u{42B}', '\u{308}', '\u{44B}', '\u{308}', '\u{627}', '\u{653}', '\u{627}', '\u{654}',
+    '\u{648}', '\u{654}', '\u{627}', '\u{655}', '\u{64A}', '\u{654}', '\u{6D5}', '\u{654}', '\u{6C1}', '\u{654}',
+    '\u{6D2}', '\u{654}', '\u{928}', '\u{93C}', '\u{930}', '\u{93C}', '\u{933}', '\u{93C}', '\u{915}', '\u{93C}',
+    '\u{916}', '\u{93C}', '\u{917}', '\u{93C}', '\u{91C}', '\u{93C}', '\u{921}', '\u{93C}', '\u{922}', '\u{93C}',
+    '\u{92B}', '\u{93C}', '\u{92F}', '\u{93C}', '\u{9C7}', '\u{9BE}', '\u{9C7}', '\u{9D7}', '\u{9A1}', '\u{9BC}',
+    '\u{9A2}', '\u{9BC}', '\u{9AF}', '\u{9BC}', '\u{A32}', '\u{A3C}', '\u{A38}', '\u{A3C}', '\u{A16}', '\u{A3C}',
+    '\u{A17}', '\u{A3C}', '\u{A1C}', '\u{A3C}', '\u{A2B}', '\u{A3C}', '\u{B47}', '\u{B56}', '\u{B47}', '\u{B3E}',
+    '\u{B47}', '\u{B57}', '\u{B21}', '\u{B3C}', '\u{B22}', '\u{B3C}', '\u{B92}', '\u{BD7}', '\u{BC6}', '\u{BBE}',
+    '\u{BC7}', '\u{BBE}', '\u{BC6}', '\u{BD7}', '\u{C46}', '\u{C56}', '\u{CBF}', '\u{CD5}', '\u{CC6}', '\u{CD5}',
+    '\u{CC6}', '\u{CD6}', '\u{CC6}', '\u{CC2}', '\u{CC6}', '\u{CC2}', '\u{CD5}', '\u{D46}', '\u{D3E}', '\u{D47}',
+    '\u{D3E}', '\u{D46}', '\u{D57}', '\u{DD9}', '\u{DCA}', '\u{DD9}', '\u{DCF}', '\u{DD9}', '\u{DCF}', '\u{DCA}',
+    '\u{DD9}', '\u{DDF}', '\u{F42}', '\u{FB7}', '\u{F4C}', '\u{FB7}', '\u{F51}', '\u{FB7}', '\u{F56}', '\u{FB7}',
+    '\u{F5B}', '\u{FB7}', '\u{F40}', '\u{FB5}', '\u{F71}', '\u{F72}', '\u{F71}', '\u{F74}', '\u{FB2}', '\u{F80}',
+    '\u{FB3}', '\u{F80}', '\u{F71}', '\u{F80}', '\u{F92}', '\u{FB7}', '\u{F9C}', '\u{FB7}', '\u{FA1}', '\u{FB7}',
+    '\u{FA6}', '\u{FB7}', '\u{FAB}', '\u{FB7}', '\u{F90}', '\u{FB5}', '\u{1025}', '\u{102E}', '\u{1B05}', '\u{1B35}',
+    '\u{1B07}', '\u{1B35}', '\u{1B09}', '\u{1B35}', '\u{1B0B}', '\u{1B35}', '\u{1B0D}', '\u{1B35}', '\u{1B11}', '\u{1B35}',
+    '\u{1B3A}', '\u{1B35}', '\u{1B3C}', '\u{1B35}', '\u{1B3E}', '\u{1B35}', '\u{1B3F}', '\u{1B35}', '\u{1B42}', '\u{1B35}',
+    '\u{41}', '\u{325}', '\u{61}', '\u{325}', '\u{42}', '\u{307}', '\u{62}', '\u{307}', '\u{42}', '\u{323}',
+    '\u{62}', '\u{323}', '\u{42}', '\u{331}', '\u{62}', '\u{331}', '\u{43}', '\u{327}', '\u{301}', '\u{63}',
+    '\u{327}', '\u{301}', '\u{44}', '\u{307}', '\u{64}', '\u{307}', '\u{44}', '\u{323}', '\u{64}', '\u{323}',
+    '\u{44}', '\u{331}', '\u{64}', '\u{331}', '\u{44}', '\u{327}', '\u{64}', '\u{327}', '\u{44}', '\u{32D}',
+    '\u{64}', '\u{32D}', '\u{45}', '\u{304}', '\u{300}', '\u{65}', '\u{304}', '\u{300}', '\u{45}', '\u{304}',
+    '\u{301}', '\u{65}', '\u{304}', '\u{301}', '\u{45}', '\u{32D}', '\u{65}', '\u{32D}', '\u{45}', '\u{330}',
+    '\u{65}', '\u{330}', '\u{45}', '\u{327}', '\u{306}', '\u{65}', '\u{327}', '\u{306}', '\u{46}', '\u{307}',
+    '\u{66}', '\u{307}', '\u{47}', '\u{304}', '\u{67}', '\u{304}', '\u{48}', '\u{307}', '\u{68}', '\u{307}',
+    '\u{48}', '\u{323}', '\u{68}', '\u{323}', '\u{48}', '\u{308}', '\u{68}', '\u{308}', '\u{48}', '\u{327}',
+    '\u{68}', '\u{327}', '\u{48}', '\u{32E}', '\u{68}', '\u{32E}', '\u{49}', '\u{330}', '\u{69}', '\u{330}',
+    '\u{49}', '\u{308}', '\u{301}', '\u{69}', '\u{308}', '\u{301}', '\u{4B}', '\u{301}', '\u{6B}', '\u{301}',
+    '\u{4B}', '\u{323}', '\u{6B}', '\u{323}', '\u{4B}', '\u{331}', '\u{6B}', '\u{331}', '\u{4C}', '\u{323}',
+    '\u{6C}', '\u{323}', '\u{4C}', '\u{323}', '\u{304}', '\u{6C}', '\u{323}', '\u{304}', '\u{4C}', '\u{331}',
+    '\u{6C}', '\u{331}', '\u{4C}', '\u{32D}', '\u{6C}', '\u{32D}', '\u{4D}', '\u{301}', '\u{6D}', '\u{301}',
+    '\u{4D}', '\u{307}', '\u{6D}', '\u{307}', '\u{4D}', '\u{323}', '\u{6D}', '\u{323}', '\u{4E}', '\u{307}',
+    '\u{6E}', '\u{307}', '\u{4E}', '\u{323}', '\u{6E}', '\u{323}', '\u{4E}', '\u{331}', '\u{6E}', '\u{331}',
+    '\u{4E}', '\u{32D}', '\u{6E}', '\u{32D}', '\u{4F}', '\u{303}', '\u{301}', '\u{6F}', '\u{303}', '\u{301}',
+    '\u{4F}', '\u{303}', '\u{308}', '\u{6F}', '\u{303}', '\u{308}', '\u{4F}', '\u{304}', '\u{300}', '\u{6F}',
+    '\u{304}', '\u{300}', '\u{4F}', '\u{304}', '\u{301}', '\u{6F}', '\u{304}', '\u{301}', '\u{50}', '\u{301}',
+    '\u{70}', '\u{301}', '\u{50}', '\u{307}', '\u{70}', '\u{307}', '\u{52}', '\u{307}', '\u{72}', '\u{307}',
+    '\u{52}', '\u{323}', '\u{72}', '\u{323}', '\u{52}', '\u{323}', '\u{304}', '\u{72}', '\u{323}', '\u{304}',
+    '\u{52}', '\u{331}', '\u{72}', '\u{331}', '\u{53}', '\u{307}', '\u{73}', '\u{307}', '\u{53}', '\u{323}',
+    '\u{73}', '\u{323}', '\u{53}', '\u{301}', '\u{307}', '\u{73}', '\u{301}', '\u{307}', '\u{53}', '\u{30C}',
+    '\u{307}', '\u{73}', '\u{30C}', '\u{307}', '\u{53}', '\u{323}', '\u{307}', '\u{73}', '\u{323}', '\u{307}',
+    '\u{54}', '\u{307}', '\u{74}', '\u{307}', '\u{54}', '\u{323}', '\u{74}', '\u{323}', '\u{54}', '\u{331}',
+    '\u{74}', '\u{331}', '\u{54}', '\u{32D}', '\u{74}', '\u{32D}', '\u{55}', '\u{324}', '\u{75}', '\u{324}',
+    '\u{55}', '\u{330}', '\u{75}', '\u{330}', '\u{55}', '\u{32D}', '\u{75}', '\u{32D}', '\u{55}', '\u{303}',
+    '\u{301}', '\u{75}', '\u{303}', '\u{301}', '\u{55}', '\u{304}', '\u{308}', '\u{75}', '\u{304}', '\u{308}',
+    '\u{56}', '\u{303}', '\u{76}', '\u{303}', '\u{56}', '\u{323}', '\u{76}', '\u{323}', '\u{57}', '\u{300}',
+    '\u{77}', '\u{300}', '\u{57}', '\u{301}', '\u{77}', '\u{301}', '\u{57}', '\u{308}', '\u{77}', '\u{308}',
+    '\u{57}', '\u{307}', '\u{77}', '\u{307}', '\u{57}', '\u{323}', '\u{77}', '\u{323}', '\u{58}', '\u{307}',
+    '\u{78}', '\u{307}', '\u{58}', '\u{308}', '\u{78}', '\u{308}', '\u{59}', '\u{307}', '\u{79}', '\u{307}',
+    '\u{5A}', '\u{302}', '\u{7A}', '\u{302}', '\u{5A}', '\u{323}', '\u{7A}', '\u{323}', '\u{5A}', '\u{331}',
+    '\u{7A}', '\u{331}', '\u{68}', '\u{331}', '\u{74}', '\u{308}', '\u{77}', '\u{30A}', '\u{79}', '\u{30A}',
+    '\u{17F}', '\u{307}', '\u{41}', '\u{323}', '\u{61}', '\u{323}', '\u{41}', '\u{309}', '\u{61}', '\u{309}',
+    '\u{41}', '\u{302}', '\u{301}', '\u{61}', '\u{302}', '\u{301}', '\u{41}', '\u{302}', '\u{300}', '\u{61}',
+    '\u{302}', '\u{300}', '\u{41}', '\u{302}', '\u{309}', '\u{61}', '\u{302}', '\u{309}', '\u{41}', '\u{302}',
+    '\u{303}', '\u{61}', '\u{302}', '\u{303}', '\u{41}', '\u{323}', '\u{302}', '\u{61}', '\u{323}', '\u{302}',
+    '\u{41}', '\u{306}', '\u{301}', '\u{61}', '\u{306}', '\u{301}', '\u{41}', '\u{306}', '\u{300}', '\u{61}',
+    '\u{306}', '\u{300}', '\u{41}', '\u{306}', '\u{309}', '\u{61}', '\u{306}', '\u{309}', '\u{41}', '\u{306}',
+    '\u{303}', '\u{61}', '\u{306}', '\u{303}', '\u{41}', '\u{323}', '\u{306}', '\u{61}', '\u{323}', '\u{306}',
+    '\u{45}', '\u{323}', '\u{65}', '\u{323}', '\u{45}', '\u{309}', '\u{65}', '\u{309}', '\u{45}', '\u{303}',
+    '\u{65}', '\u{303}', '\u{45}', '\u{302}', '\u{301}', '\u{65}', '\u{302}', '\u{301}', '\u{45}', '\u{302}',
+    '\u{300}', '\u{65}', '\u{302}', '\u{300}', '\u{45}', '\u{302}', '\u{309}', '\u{65}', '\u{302}', '\u{309}',
+    '\u{45}', '\u{302}', '\u{303}', '\u{65}', '\u{302}', '\u{303}', '\u{45}', '\u{323}', '\u{302}', '\u{65}',
+    '\u{323}', '\u{302}', '\u{49}', '\u{309}', '\u{69}', '\u{309}', '\u{49}', '\u{323}', '\u{69}', '\u{323}',
+    '\u{4F}', '\u{323}', '\u{6F}', '\u{323}', '\u{4F}', '\u{309}', '\u{6F}', '\u{309}', '\u{4F}', '\u{302}',
+    '\u{301}', '\u{6F}', '\u{302}', '\u{301}', '\u{4F}', '\u{302}', '\u{300}', '\u{6F}', '\u{302}', '\u{300}',
+    '\u{4F}', '\u{302}', '\u{309}', '\u{6F}', '\u{302}', '\u{309}', '\u{4F}', '\u{302}', '\u{303}', '\u{6F}',
+    '\u{302}', '\u{303}', '\u{4F}', '\u{323}', '\u{302}', '\u{6F}', '\u{323}', '\u{302}', '\u{4F}', '\u{31B}',
+    '\u{301}', '\u{6F}', '\u{31B}', '\u{301}', '\u{4F}', '\u{31B}', '\u{300}', '\u{6F}', '\u{31B}', '\u{300}',
+    '\u{4F}', '\u{31B}', '\u{309}', '\u{6F}', '\u{31B}', '\u{309}', '\u{4F}', '\u{31B}', '\u{303}', '\u{6F}',
+    '\u{31B}', '\u{303}', '\u{4F}', '\u{31B}', '\u{323}', '\u{6F}', '\u{31B}', '\u{323}', '\u{55}', '\u{323}',
+    '\u{75}', '\u{323}', '\u{55}', '\u{309}', '\u{75}', '\u{309}', '\u{55}', '\u{31B}', '\u{301}', '\u{75}',
+    '\u{31B}', '\u{301}', '\u{55}', '\u{31B}', '\u{300}', '\u{75}', '\u{31B}', '\u{300}', '\u{55}', '\u{31B}',
+    '\u{309}', '\u{75}', '\u{31B}', '\u{309}', '\u{55}', '\u{31B}', '\u{303}', '\u{75}', '\u{31B}', '\u{303}',
+    '\u{55}', '\u{31B}', '\u{323}', '\u{75}', '\u{31B}', '\u{323}', '\u{59}', '\u{300}', '\u{79}', '\u{300}',
+    '\u{59}', '\u{323}', '\u{79}', '\u{323}', '\u{59}', '\u{309}', '\u{79}', '\u{309}', '\u{59}', '\u{303}',
+    '\u{79}', '\u{303}', '\u{3B1}', '\u{313}', '\u{3B1}', '\u{314}', '\u{3B1}', '\u{313}', '\u{300}', '\u{3B1}',
+    '\u{314}', '\u{300}', '\u{3B1}', '\u{313}', '\u{301}', '\u{3B1}', '\u{314}', '\u{301}', '\u{3B1}', '\u{313}',
+    '\u{342}', '\u{3B1}', '\u{314}', '\u{342}', '\u{391}', '\u{313}', '\u{391}', '\u{314}', '\u{391}', '\u{313}',
+    '\u{300}', '\u{391}', '\u{314}', '\u{300}', '\u{391}', '\u{313}', '\u{301}', '\u{391}', '\u{314}', '\u{301}',
+    '\u{391}', '\u{313}', '\u{342}', '\u{391}', '\u{314}', '\u{342}', '\u{3B5}', '\u{313}', '\u{3B5}', '\u{314}',
+    '\u{3B5}', '\u{313}', '\u{300}', '\u{3B5}', '\u{314}', '\u{300}', '\u{3B5}', '\u{313}', '\u{301}', '\u{3B5}',
+    '\u{314}', '\u{301}', '\u{395}', '\u{313}', '\u{395}', '\u{314}', '\u{395}', '\u{313}', '\u{300}', '\u{395}',
+    '\u{314}', '\u{300}', '\u{395}', '\u{313}', '\u{301}', '\u{395}', '\u{314}', '\u{301}', '\u{3B7}', '\u{313}',
+    '\u{3B7}', '\u{314}', '\u{3B7}', '\u{313}', '\u{300}', '\u{3B7}', '\u{314}', '\u{300}', '\u{3B7}', '\u{313}',
+    '\u{301}', '\u{3B7}', '\u{314}', '\u{301}', '\u{3B7}', '\u{313}', '\u{342}', '\u{3B7}', '\u{314}', '\u{342}',
+    '\u{397}', '\u{313}', '\u{397}', '\u{314}', '\u{397}', '\u{313}', '\u{300}', '\u{397}', '\u{314}', '\u{300}',
+    '\u{397}', '\u{313}', '\u{301}', '\u{397}', '\u{314}', '\u{301}', '\u{397}', '\u{313}', '\u{342}', '\u{397}',
+    '\u{314}', '\u{342}', '\u{3B9}', '\u{313}', '\u{3B9}', '\u{314}', '\u{3B9}', '\u{313}', '\u{300}', '\u{3B9}',
+    '\u{314}', '\u{300}', '\u{3B9}', '\u{313}', '\u{301}', '\u{3B9}', '\u{314}', '\u{301}', '\u{3B9}', '\u{313}',
+    '\u{342}', '\u{3B9}', '\u{314}', '\u{342}', '\u{399}', '\u{313}', '\u{399}', '\u{314}', '\u{399}', '\u{313}',
+    '\u{300}', '\u{399}', '\u{314}', '\u{300}', '\u{399}', '\u{313}', '\u{301}', '\u{399}', '\u{314}', '\u{301}',
+    '\u{399}', '\u{313}', '\u{342}', '\u{399}', '\u{314}', '\u{342}', '\u{3BF}', '\u{313}', '\u{3BF}', '\u{314}',
+    '\u{3BF}', '\u{313}', '\u{300}', '\u{3BF}', '\u{314}', '\u{300}', '\u{3BF}', '\u{313}', '\u{301}', '\u{3BF}',
+    '\u{314}', '\u{301}', '\u{39F}', '\u{313}', '\u{39F}', '\u{314}', '\u{39F}', '\u{313}', '\u{300}', '\u{39F}',
+    '\u{314}', '\u{300}', '\u{39F}', '\u{313}', '\u{301}', '\u{39F}', '\u{314}', '\u{301}', '\u{3C5}', '\u{313}',
+    '\u{3C5}', '\u{314}', '\u{3C5}', '\u{313}', '\u{300}', '\u{3C5}', '\u{314}', '\u{300}', '\u{3C5}', '\u{313}',
+    '\u{301}', '\u{3C5}', '\u{314}', '\u{301}', '\u{3C5}', '\u{313}', '\u{342}', '\u{3C5}', '\u{314}', '\u{342}',
+    '\u{3A5}', '\u{314}', '\u{3A5}', '\u{314}', '\u{300}', '\u{3A5}', '\u{314}', '\u{301}', '\u{3A5}', '\u{314}',
+    '\u{342}', '\u{3C9}', '\u{313}', '\u{3C9}', '\u{314}', '\u{3C9}', '\u{313}', '\u{300}', '\u{3C9}', '\u{314}',
+    '\u{300}', '\u{3C9}', '\u{313}', '\u{301}', '\u{3C9}', '\u{314}', '\u{301}', '\u{3C9}', '\u{313}', '\u{342}',
+    '\u{3C9}', '\u{314}', '\u{342}', '\u{3A9}', '\u{313}', '\u{3A9}', '\u{314}', '\u{3A9}', '\u{313}', '\u{300}',
+    '\u{3A9}', '\u{314}', '\u{300}', '\u{3A9}', '\u{313}', '\u{301}', '\u{3A9}', '\u{314}', '\u{301}', '\u{3A9}',
+    '\u{313}', '\u{342}', '\u{3A9}', '\u{314}', '\u{342}', '\u{3B1}', '\u{300}', '\u{3B1}', '\u{301}', '\u{3B5}',
+    '\u{300}', '\u{3B5}', '\u{301}', '\u{3B7}', '\u{300}', '\u{3B7}', '\u{301}', '\u{3B9}', '\u{300}', '\u{3B9}',
+    '\u{301}', '\u{3BF}', '\u{300}', '\u{3BF}', '\u{301}', '\u{3C5}', '\u{300}', '\u{3C5}', '\u{301}', '\u{3C9}',
+    '\u{300}', '\u{3C9}', '\u{301}', '\u{3B1}', '\u{313}', '\u{345}', '\u{3B1}', '\u{314}', '\u{345}', '\u{3B1}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{3B1}', '\u{314}', '\u{300}', '\u{345}', '\u{3B1}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{3B1}', '\u{314}', '\u{301}', '\u{345}', '\u{3B1}', '\u{313}', '\u{342}', '\u{345}', '\u{3B1}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{391}', '\u{313}', '\u{345}', '\u{391}', '\u{314}', '\u{345}', '\u{391}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{391}', '\u{314}', '\u{300}', '\u{345}', '\u{391}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{391}', '\u{314}', '\u{301}', '\u{345}', '\u{391}', '\u{313}', '\u{342}', '\u{345}', '\u{391}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{3B7}', '\u{313}', '\u{345}', '\u{3B7}', '\u{314}', '\u{345}', '\u{3B7}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{3B7}', '\u{314}', '\u{300}', '\u{345}', '\u{3B7}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{3B7}', '\u{314}', '\u{301}', '\u{345}', '\u{3B7}', '\u{313}', '\u{342}', '\u{345}', '\u{3B7}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{397}', '\u{313}', '\u{345}', '\u{397}', '\u{314}', '\u{345}', '\u{397}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{397}', '\u{314}', '\u{300}', '\u{345}', '\u{397}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{397}', '\u{314}', '\u{301}', '\u{345}', '\u{397}', '\u{313}', '\u{342}', '\u{345}', '\u{397}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{3C9}', '\u{313}', '\u{345}', '\u{3C9}', '\u{314}', '\u{345}', '\u{3C9}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{3C9}', '\u{314}', '\u{300}', '\u{345}', '\u{3C9}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{3C9}', '\u{314}', '\u{301}', '\u{345}', '\u{3C9}', '\u{313}', '\u{342}', '\u{345}', '\u{3C9}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{3A9}', '\u{313}', '\u{345}', '\u{3A9}', '\u{314}', '\u{345}', '\u{3A9}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{3A9}', '\u{314}', '\u{300}', '\u{345}', '\u{3A9}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{3A9}', '\u{314}', '\u{301}', '\u{345}', '\u{3A9}', '\u{313}', '\u{342}', '\u{345}', '\u{3A9}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{3B1}', '\u{306}', '\u{3B1}', '\u{304}', '\u{3B1}', '\u{300}', '\u{345}',
+    '\u{3B1}', '\u{345}', '\u{3B1}', '\u{301}', '\u{345}', '\u{3B1}', '\u{342}', '\u{3B1}', '\u{342}', '\u{345}',
+    '\u{391}', '\u{306}', '\u{391}', '\u{304}', '\u{391}', '\u{300}', '\u{391}', '\u{301}', '\u{391}', '\u{345}',
+    '\u{3B9}', '\u{A8}', '\u{342}', '\u{3B7}', '\u{300}', '\u{345}', '\u{3B7}', '\u{345}', '\u{3B7}', '\u{301}',
+    '\u{345}', '\u{3B7}', '\u{342}', '\u{3B7}', '\u{342}', '\u{345}', '\u{395}', '\u{300}', '\u{395}', '\u{301}',
+    '\u{397}', '\u{300}', '\u{397}', '\u{301}', '\u{397}', '\u{345}', '\u{1FBF}', '\u{300}', '\u{1FBF}', '\u{301}',
+    '\u{1FBF}', '\u{342}', '\u{3B9}', '\u{306}', '\u{3B9}', '\u{304}', '\u{3B9}', '\u{308}', '\u{300}', '\u{3B9}',
+    '\u{308}', '\u{301}', '\u{3B9}', '\u{342}', '\u{3B9}', '\u{308}', '\u{342}', '\u{399}', '\u{306}', '\u{399}',
+    '\u{304}', '\u{399}', '\u{300}', '\u{399}', '\u{301}', '\u{1FFE}', '\u{300}', '\u{1FFE}', '\u{301}', '\u{1FFE}',
+    '\u{342}', '\u{3C5}', '\u{306}', '\u{3C5}', '\u{304}', '\u{3C5}', '\u{308}', '\u{300}', '\u{3C5}', '\u{308}',
+    '\u{301}', '\u{3C1}', '\u{313}', '\u{3C1}', '\u{314}', '\u{3C5}', '\u{342}', '\u{3C5}', '\u{308}', '\u{342}',
+    '\u{3A5}', '\u{306}', '\u{3A5}', '\u{304}', '\u{3A5}', '\u{300}', '\u{3A5}', '\u{301}', '\u{3A1}', '\u{314}',
+    '\u{A8}', '\u{300}', '\u{A8}', '\u{301}', '\u{60}', '\u{3C9}', '\u{300}', '\u{345}', '\u{3C9}', '\u{345}',
+    '\u{3C9}', '\u{301}', '\u{345}', '\u{3C9}', '\u{342}', '\u{3C9}', '\u{342}', '\u{345}', '\u{39F}', '\u{300}',
+    '\u{39F}', '\u{301}', '\u{3A9}', '\u{300}', '\u{3A9}', '\u{301}', '\u{3A9}', '\u{345}', '\u{B4}', '\u{2002}',
+    '\u{2003}', '\u{3A9}', '\u{4B}', '\u{41}', '\u{30A}', '\u{2190}', '\u{338}', '\u{2192}', '\u{338}', '\u{2194}',
+    '\u{338}', '\u{21D0}', '\u{338}', '\u{21D4}', '\u{338}', '\u{21D2}', '\u{338}', '\u{2203}', '\u{338}', '\u{2208}',
+    '\u{338}', '\u{220B}', '\u{338}', '\u{2223}', '\u{338}', '\u{2225}', '\u{338}', '\u{223C}', '\u{338}', '\u{2243}',
+    '\u{338}', '\u{2245}', '\u{338}', '\u{2248}', '\u{338}', '\u{3D}', '\u{338}', '\u{2261}', '\u{338}', '\u{224D}',
+    '\u{338}', '\u{3C}', '\u{338}', '\u{3E}', '\u{338}', '\u{2264}', '\u{338}', '\u{2265}', '\u{338}', '\u{2272}',
+    '\u{338}', '\u{2273}', '\u{338}', '\u{2276}', '\u{338}', '\u{2277}', '\u{338}', '\u{227A}', '\u{338}', '\u{227B}',
+    '\u{338}', '\u{2282}', '\u{338}', '\u{2283}', '\u{338}', '\u{2286}', '\u{338}', '\u{2287}', '\u{338}', '\u{22A2}',
+    '\u{338}', '\u{22A8}', '\u{338}', '\u{22A9}', '\u{338}', '\u{22AB}', '\u{338}', '\u{227C}', '\u{338}', '\u{227D}',
+    '\u{338}', '\u{2291}', '\u{338}', '\u{2292}', '\u{338}', '\u{22B2}', '\u{338}', '\u{22B3}', '\u{338}', '\u{22B4}',
+    '\u{338}', '\u{22B5}', '\u{338}', '\u{3008}', '\u{3009}', '\u{2ADD}', '\u{338}', '\u{304B}', '\u{3099}', '\u{304D}',
+    '\u{3099}', '\u{304F}', '\u{3099}', '\u{3051}', '\u{3099}', '\u{3053}', '\u{3099}', '\u{3055}', '\u{3099}', '\u{3057}',
+    '\u{3099}', '\u{3059}', '\u{3099}', '\u{305B}', '\u{3099}', '\u{305D}', '\u{3099}', '\u{305F}', '\u{3099}', '\u{3061}',
+    '\u{3099}', '\u{3064}', '\u{3099}', '\u{3066}', '\u{3099}', '\u{3068}', '\u{3099}', '\u{306F}', '\u{3099}', '\u{306F}',
+    '\u{309A}', '\u{3072}', '\u{3099}', '\u{3072}', '\u{309A}', '\u{3075}', '\u{3099}', '\u{3075}', '\u{309A}', '\u{3078}',
+    '\u{3099}', '\u{3078}', '\u{309A}', '\u{307B}', '\u{3099}', '\u{307B}', '\u{309A}', '\u{3046}', '\u{3099}', '\u{309D}',
+    '\u{3099}', '\u{30AB}', '\u{3099}', '\u{30AD}', '\u{3099}', '\u{30AF}', '\u{3099}', '\u{30B1}', '\u{3099}', '\u{30B3}',
+    '\u{3099}', '\u{30B5}', '\u{3099}', '\u{30B7}', '\u{3099}', '\u{30B9}', '\u{3099}', '\u{30BB}', '\u{3099}', '\u{30BD}',
+    '\u{3099}', '\u{30BF}', '\u{3099}', '\u{30C1}', '\u{3099}', '\u{30C4}', '\u{3099}', '\u{30C6}', '\u{3099}', '\u{30C8}',
+    '\u{3099}', '\u{30CF}', '\u{3099}', '\u{30CF}', '\u{309A}', '\u{30D2}', '\u{3099}', '\u{30D2}', '\u{309A}', '\u{30D5}',
+    '\u{3099}', '\u{30D5}', '\u{309A}', '\u{30D8}', '\u{3099}', '\u{30D8}', '\u{309A}', '\u{30DB}', '\u{3099}', '\u{30DB}',
+    '\u{309A}', '\u{30A6}', '\u{3099}', '\u{30EF}', '\u{3099}', '\u{30F0}', '\u{3099}', '\u{30F1}', '\u{3099}', '\u{30F2}',
+    '\u{3099}', '\u{30FD}', '\u{3099}', '\u{8C48}', '\u{66F4}', '\u{8ECA}', '\u{8CC8}', '\u{6ED1}', '\u{4E32}', '\u{53E5}',
+    '\u{9F9C}', '\u{9F9C}', '\u{5951}', '\u{91D1}', '\u{5587}', '\u{5948}', '\u{61F6}', '\u{7669}', '\u{7F85}', '\u{863F}',
+    '\u{87BA}', '\u{88F8}', '\u{908F}', '\u{6A02}', '\u{6D1B}', '\u{70D9}', '\u{73DE}', '\u{843D}', '\u{916A}', '\u{99F1}',
+    '\u{4E82}', '\u{5375}', '\u{6B04}', '\u{721B}', '\u{862D}', '\u{9E1E}', '\u{5D50}', '\u{6FEB}', '\u{85CD}', '\u{8964}',
+    '\u{62C9}', '\u{81D8}', '\u{881F}', '\u{5ECA}', '\u{6717}', '\u{6D6A}', '\u{72FC}', '\u{90CE}', '\u{4F86}', '\u{51B7}',
+    '\u{52DE}', '\u{64C4}', '\u{6AD3}', '\u{7210}', '\u{76E7}', '\u{8001}', '\u{8606}', '\u{865C}', '\u{8DEF}', '\u{9732}',
+    '\u{9B6F}', '\u{9DFA}', '\u{788C}', '\u{797F}', '\u{7DA0}', '\u{83C9}', '\u{9304}', '\u{9E7F}', '\u{8AD6}', '\u{58DF}',
+    '\u{5F04}', '\u{7C60}', '\u{807E}', '\u{7262}', '\u{78CA}', '\u{8CC2}', '\u{96F7}', '\u{58D8}', '\u{5C62}', '\u{6A13}',
+    '\u{6DDA}', '\u{6F0F}', '\u{7D2F}', '\u{7E37}', '\u{964B}', '\u{52D2}', '\u{808B}', '\u{51DC}', '\u{51CC}', '\u{7A1C}',
+    '\u{7DBE}', '\u{83F1}', '\u{9675}', '\u{8B80}', '\u{62CF}', '\u{6A02}', '\u{8AFE}', '\u{4E39}', '\u{5BE7}', '\u{6012}',
+    '\u{7387}', '\u{7570}', '\u{5317}', '\u{78FB}', '\u{4FBF}', '\u{5FA9}', '\u{4E0D}', '\u{6CCC}', '\u{6578}', '\u{7D22}',
+    '\u{53C3}', '\u{585E}', '\u{7701}', '\u{8449}', '\u{8AAA}', '\u{6BBA}', '\u{8FB0}', '\u{6C88}', '\u{62FE}', '\u{82E5}',
+    '\u{63A0}', '\u{7565}', '\u{4EAE}', '\u{5169}', '\u{51C9}', '\u{6881}', '\u{7CE7}', '\u{826F}', '\u{8AD2}', '\u{91CF}',
+    '\u{52F5}', '\u{5442}', '\u{5973}', '\u{5EEC}', '\u{65C5}', '\u{6FFE}', '\u{792A}', '\u{95AD}', '\u{9A6A}', '\u{9E97}',
+    '\u{9ECE}', '\u{529B}', '\u{66C6}', '\u{6B77}', '\u{8F62}', '\u{5E74}', '\u{6190}', '\u{6200}', '\u{649A}', '\u{6F23}',
+    '\u{7149}', '\u{7489}', '\u{79CA}', '\u{7DF4}', '\u{806F}', '\u{8F26}', '\u{84EE}', '\u{9023}', '\u{934A}', '\u{5217}',
+    '\u{52A3}', '\u{54BD}', '\u{70C8}', '\u{88C2}', '\u{8AAA}', '\u{5EC9}', '\u{5FF5}', '\u{637B}', '\u{6BAE}', '\u{7C3E}',
+    '\u{7375}', '\u{4EE4}', '\u{56F9}', '\u{5BE7}', '\u{5DBA}', '\u{601C}', '\u{73B2}', '\u{7469}', '\u{7F9A}', '\u{8046}',
+    '\u{9234}', '\u{96F6}', '\u{9748}', '\u{9818}', '\u{4F8B}', '\u{79AE}', '\u{91B4}', '\u{96B8}', '\u{60E1}', '\u{4E86}',
+    '\u{50DA}', '\u{5BEE}', '\u{5C3F}', '\u{6599}', '\u{6A02}', '\u{71CE}', '\u{7642}', '\u{84FC}', '\u{907C}', '\u{9F8D}',
+    '\u{6688}', '\u{962E}', '\u{5289}', '\u{677B}', '\u{67F3}', '\u{6D41}', '\u{6E9C}', '\u{7409}', '\u{7559}', '\u{786B}',
+    '\u{7D10}', '\u{985E}', '\u{516D}', '\u{622E}', '\u{9678}', '\u{502B}', '\u{5D19}', '\u{6DEA}', '\u{8F2A}', '\u{5F8B}',
+    '\u{6144}', '\u{6817}', '\u{7387}', '\u{9686}', '\u{5229}', '\u{540F}', '\u{5C65}', '\u{6613}', '\u{674E}', '\u{68A8}',
+    '\u{6CE5}', '\u{7406}', '\u{75E2}', '\u{7F79}', '\u{88CF}', '\u{88E1}', '\u{91CC}', '\u{96E2}', '\u{533F}', '\u{6EBA}',
+    '\u{541D}', '\u{71D0}', '\u{7498}', '\u{85FA}', '\u{96A3}', '\u{9C57}', '\u{9E9F}', '\u{6797}', '\u{6DCB}', '\u{81E8}',
+    '\u{7ACB}', '\u{7B20}', '\u{7C92}', '\u{72C0}', '\u{7099}', '\u{8B58}', '\u{4EC0}', '\u{8336}', '\u{523A}', '\u{5207}',
+    '\u{5EA6}', '\u{62D3}', '\u{7CD6}', '\u{5B85}', '\u{6D1E}', '\u{66B4}', '\u{8F3B}', '\u{884C}', '\u{964D}', '\u{898B}',
+    '\u{5ED3}', '\u{5140}', '\u{55C0}', '\u{585A}', '\u{6674}', '\u{51DE}', '\u{732A}', '\u{76CA}', '\u{793C}', '\u{795E}',
+    '\u{7965}', '\u{798F}', '\u{9756}', '\u{7CBE}', '\u{7FBD}', '\u{8612}', '\u{8AF8}', '\u{9038}', '\u{90FD}', '\u{98EF}',
+    '\u{98FC}', '\u{9928}', '\u{9DB4}', '\u{90DE}', '\u{96B7}', '\u{4FAE}', '\u{50E7}', '\u{514D}', '\u{52C9}', '\u{52E4}',
+    '\u{5351}', '\u{559D}', '\u{5606}', '\u{5668}', '\u{5840}', '\u{58A8}', '\u{5C64}', '\u{5C6E}', '\u{6094}', '\u{6168}',
+    '\u{618E}', '\u{61F2}', '\u{654F}', '\u{65E2}', '\u{6691}', '\u{6885}', '\u{6D77}', '\u{6E1A}', '\u{6F22}', '\u{716E}',
+    '\u{722B}', '\u{7422}', '\u{7891}', '\u{793E}', '\u{7949}', '\u{7948}', '\u{7950}', '\u{7956}', '\u{795D}', '\u{798D}',
+    '\u{798E}', '\u{7A40}', '\u{7A81}', '\u{7BC0}', '\u{7DF4}', '\u{7E09}', '\u{7E41}', '\u{7F72}', '\u{8005}', '\u{81ED}',
+    '\u{8279}', '\u{8279}', '\u{8457}', '\u{8910}', '\u{8996}', '\u{8B01}', '\u{8B39}', '\u{8CD3}', '\u{8D08}', '\u{8FB6}',
+    '\u{9038}', '\u{96E3}', '\u{97FF}', '\u{983B}', '\u{6075}', '\u{242EE}', '\u{8218}', '\u{4E26}', '\u{51B5}', '\u{5168}',
+    '\u{4F80}', '\u{5145}', '\u{5180}', '\u{52C7}', '\u{52FA}', '\u{559D}', '\u{5555}', '\u{5599}', '\u{55E2}', '\u{585A}',
+    '\u{58B3}', '\u{5944}', '\u{5954}', '\u{5A62}', '\u{5B28}', '\u{5ED2}', '\u{5ED9}', '\u{5F69}', '\u{5FAD}', '\u{60D8}',
+    '\u{614E}', '\u{6108}', '\u{618E}', '\u{6160}', '\u{61F2}', '\u{6234}', '\u{63C4}', '\u{641C}', '\u{6452}', '\u{6556}',
+    '\u{6674}', '\u{6717}', '\u{671B}', '\u{6756}', '\u{6B79}', '\u{6BBA}', '\u{6D41}', '\u{6EDB}', '\u{6ECB}', '\u{6F22}',
+    '\u{701E}', '\u{716E}', '\u{77A7}', '\u{7235}', '\u{72AF}', '\u{732A}', '\u{7471}', '\u{7506}', '\u{753B}', '\u{761D}',
+    '\u{761F}', '\u{76CA}', '\u{76DB}', '\u{76F4}', '\u{774A}', '\u{7740}', '\u{78CC}', '\u{7AB1}', '\u{7BC0}', '\u{7C7B}',
+    '\u{7D5B}', '\u{7DF4}', '\u{7F3E}', '\u{8005}', '\u{8352}', '\u{83EF}', '\u{8779}', '\u{8941}', '\u{8986}', '\u{8996}',
+    '\u{8ABF}', '\u{8AF8}', '\u{8ACB}', '\u{8B01}', '\u{8AFE}', '\u{8AED}', '\u{8B39}', '\u{8B8A}', '\u{8D08}', '\u{8F38}',
+    '\u{9072}', '\u{9199}', '\u{9276}', '\u{967C}', '\u{96E3}', '\u{9756}', '\u{97DB}', '\u{97FF}', '\u{980B}', '\u{983B}',
+    '\u{9B12}', '\u{9F9C}', '\u{2284A}', '\u{22844}', '\u{233D5}', '\u{3B9D}', '\u{4018}', '\u{4039}', '\u{25249}', '\u{25CD0}',
+    '\u{27ED3}', '\u{9F43}', '\u{9F8E}', '\u{5D9}', '\u{5B4}', '\u{5F2}', '\u{5B7}', '\u{5E9}', '\u{5C1}', '\u{5E9}',
+    '\u{5C2}', '\u{5E9}', '\u{5BC}', '\u{5C1}', '\u{5E9}', '\u{5BC}', '\u{5C2}', '\u{5D0}', '\u{5B7}', '\u{5D0}',
+    '\u{5B8}', '\u{5D0}', '\u{5BC}', '\u{5D1}', '\u{5BC}', '\u{5D2}', '\u{5BC}', '\u{5D3}', '\u{5BC}', '\u{5D4}',
+    '\u{5BC}', '\u{5D5}', '\u{5BC}', '\u{5D6}', '\u{5BC}', '\u{5D8}', '\u{5BC}', '\u{5D9}', '\u{5BC}', '\u{5DA}',
+    '\u{5BC}', '\u{5DB}', '\u{5BC}', '\u{5DC}', '\u{5BC}', '\u{5DE}', '\u{5BC}', '\u{5E0}', '\u{5BC}', '\u{5E1}',
+    '\u{5BC}', '\u{5E3}', '\u{5BC}', '\u{5E4}', '\u{5BC}', '\u{5E6}', '\u{5BC}', '\u{5E7}', '\u{5BC}', '\u{5E8}',
+    '\u{5BC}', '\u{5E9}', '\u{5BC}', '\u{5EA}', '\u{5BC}', '\u{5D5}', '\u{5B9}', '\u{5D1}', '\u{5BF}', '\u{5DB}',
+    '\u{5BF}', '\u{5E4}', '\u{5BF}', '\u{11099}', '\u{110BA}', '\u{1109B}', '\u{110BA}', '\u{110A5}', '\u{110BA}', '\u{11131}',
+    '\u{11127}', '\u{11132}', '\u{11127}', '\u{11347}', '\u{1133E}', '\u{11347}', '\u{11357}', '\u{114B9}', '\u{114BA}', '\u{114B9}',
+    '\u{114B0}', '\u{114B9}', '\u{114BD}', '\u{115B8}', '\u{115AF}', '\u{115B9}', '\u{115AF}', '\u{11935}', '\u{11930}', '\u{1D157}',
+    '\u{1D165}', '\u{1D158}', '\u{1D165}', '\u{1D158}', '\u{1D165}', '\u{1D16E}', '\u{1D158}', '\u{1D165}', '\u{1D16F}', '\u{1D158}',
+    '\u{1D165}', '\u{1D170}', '\u{1D158}', '\u{1D165}', '\u{1D171}', '\u{1D158}', '\u{1D165}', '\u{1D172}', '\u{1D1B9}', '\u{1D165}',
+    '\u{1D1BA}', '\u{1D165}', '\u{1D1B9}', '\u{1D165}', '\u{1D16E}', '\u{1D1BA}', '\u{1D165}', '\u{1D16E}', '\u{1D1B9}', '\u{1D165}',
+    '\u{1D16F}', '\u{1D1BA}', '\u{1D165}', '\u{1D16F}', '\u{4E3D}', '\u{4E38}', '\u{4E41}', '\u{20122}', '\u{4F60}', '\u{4FAE}',
+    '\u{4FBB}', '\u{5002}', '\u{507A}', '\u{5099}', '\u{50E7}', '\u{50CF}', '\u{349E}', '\u{2063A}', '\u{514D}', '\u{5154}',
+    '\u{5164}', '\u{5177}', '\u{2051C}', '\u{34B9}', '\u{5167}', '\u{518D}', '\u{2054B}', '\u{5197}', '\u{51A4}', '\u{4ECC}',
+    '\u{51AC}', '\u{51B5}', '\u{291DF}', '\u{51F5}', '\u{5203}', '\u{34DF}', '\u{523B}', '\u{5246}', '\u{5272}', '\u{5277}',
+    '\u{3515}', '\u{52C7}', '\u{52C9}', '\u{52E4}', '\u{52FA}', '\u{5305}', '\u{5306}', '\u{5317}', '\u{5349}', '\u{5351}',
+    '\u{535A}', '\u{5373}', '\u{537D}', '\u{537F}', '\u{537F}', '\u{537F}', '\u{20A2C}', '\u{7070}', '\u{53CA}', '\u{53DF}',
+    '\u{20B63}', '\u{53EB}', '\u{53F1}', '\u{5406}', '\u{549E}', '\u{5438}', '\u{5448}', '\u{5468}', '\u{54A2}', '\u{54F6}',
+    '\u{5510}', '\u{5553}', '\u{5563}', '\u{5584}', '\u{5584}', '\u{5599}', '\u{55AB}', '\u{55B3}', '\u{55C2}', '\u{5716}',
+    '\u{5606}', '\u{5717}', '\u{5651}', '\u{5674}', '\u{5207}', '\u{58EE}', '\u{57CE}', '\u{57F4}', '\u{580D}', '\u{578B}',
+    '\u{5832}', '\u{5831}', '\u{58AC}', '\u{214E4}', '\u{58F2}', '\u{58F7}', '\u{5906}', '\u{591A}', '\u{5922}', '\u{5962}',
+    '\u{216A8}', '\u{216EA}', '\u{59EC}', '\u{5A1B}', '\u{5A27}', '\u{59D8}', '\u{5A66}', '\u{36EE}', '\u{36FC}', '\u{5B08}',
+    '\u{5B3E}', '\u{5B3E}', '\u{219C8}', '\u{5BC3}', '\u{5BD8}', '\u{5BE7}', '\u{5BF3}', '\u{21B18}', '\u{5BFF}', '\u{5C06}',
+    '\u{5F53}', '\u{5C22}', '\u{3781}', '\u{5C60}', '\u{5C6E}', '\u{5CC0}', '\u{5C8D}', '\u{21DE4}', '\u{5D43}', '\u{21DE6}',
+    '\u{5D6E}', '\u{5D6B}', '\u{5D7C}', '\u{5DE1}', '\u{5DE2}', '\u{382F}', '\u{5DFD}', '\u{5E28}', '\u{5E3D}', '\u{5E69}',
+    '\u{3862}', '\u{22183}', '\u{387C}', '\u{5EB0}', '\u{5EB3}', '\u{5EB6}', '\u{5ECA}', '\u{2A392}', '\u{5EFE}', '\u{22331}',
+    '\u{22331}', '\u{8201}', '\u{5F22}', '\u{5F22}', '\u{38C7}', '\u{232B8}', '\u{261DA}', '\u{5F62}', '\u{5F6B}', '\u{38E3}',
+    '\u{5F9A}', '\u{5FCD}', '\u{5FD7}', '\u{5FF9}', '\u{6081}', '\u{393A}', '\u{391C}', '\u{6094}', '\u{226D4}', '\u{60C7}',
+    '\u{6148}', '\u{614C}', '\u{614E}', '\u{614C}', '\u{617A}', '\u{618E}', '\u{61B2}', '\u{61A4}', '\u{61AF}', '\u{61DE}',
+    '\u{61F2}', '\u{61F6}', '\u{6210}', '\u{621B}', '\u{625D}', '\u{62B1}', '\u{62D4}', '\u{6350}', '\u{22B0C}', '\u{633D}',
+    '\u{62FC}', '\u{6368}', '\u{6383}', '\u{63E4}', '\u{22BF1}', '\u{6422}', '\u{63C5}', '\u{63A9}', '\u{3A2E}', '\u{6469}',
+    '\u{647E}', '\u{649D}', '\u{6477}', '\u{3A6C}', '\u{654F}', '\u{656C}', '\u{2300A}', '\u{65E3}', '\u{66F8}', '\u{6649}',
+    '\u{3B19}', '\u{6691}', '\u{3B08}', '\u{3AE4}', '\u{5192}', '\u{5195}', '\u{6700}', '\u{669C}', '\u{80AD}', '\u{43D9}',
+    '\u{6717}', '\u{671B}', '\u{6721}', '\u{675E}', '\u{6753}', '\u{233C3}', '\u{3B49}', '\u{67FA}', '\u{6785}', '\u{6852}',
+    '\u{6885}', '\u{2346D}', '\u{688E}', '\u{681F}', '\u{6914}', '\u{3B9D}', '\u{6942}', '\u{69A3}', '\u{69EA}', '\u{6AA8}',
+    '\u{236A3}', '\u{6ADB}', '\u{3C18}', '\u{6B21}', '\u{238A7}', '\u{6B54}', '\u{3C4E}', '\u{6B72}', '\u{6B9F}', '\u{6BBA}',
+    '\u{6BBB}', '\u{23A8D}', '\u{21D0B}', '\u{23AFA}', '\u{6C4E}', '\u{23CBC}', '\u{6CBF}', '\u{6CCD}', '\u{6C67}', '\u{6D16}',
+    '\u{6D3E}', '\u{6D77}', '\u{6D41}', '\u{6D69}', '\u{6D78}', '\u{6D85}', '\u{23D1E}', '\u{6D34}', '\u{6E2F}', '\u{6E6E}',
+    '\u{3D33}', '\u{6ECB}', '\u{6EC7}', '\u{23ED1}', '\u{6DF9}', '\u{6F6E}', '\u{23F5E}', '\u{23F8E}', '\u{6FC6}', '\u{7039}',
+    '\u{701E}', '\u{701B}', '\u{3D96}', '\u{704A}', '\u{707D}', '\u{7077}', '\u{70AD}', '\u{20525}', '\u{7145}', '\u{24263}',
+    '\u{719C}', '\u{243AB}', '\u{7228}', '\u{7235}', '\u{7250}', '\u{24608}', '\u{7280}', '\u{7295}', '\u{24735}', '\u{24814}',
+    '\u{737A}', '\u{738B}', '\u{3EAC}', '\u{73A5}', '\u{3EB8}', '\u{3EB8}', '\u{7447}', '\u{745C}', '\u{7471}', '\u{7485}',
+    '\u{74CA}', '\u{3F1B}', '\u{7524}', '\u{24C36}', '\u{753E}', '\u{24C92}', '\u{7570}', '\u{2219F}', '\u{7610}', '\u{24FA1}',
+    '\u{24FB8}', '\u{25044}', '\u{3FFC}', '\u{4008}', '\u{76F4}', '\u{250F3}', '\u{250F2}', '\u{25119}', '\u{25133}', '\u{771E}',
+    '\u{771F}', '\u{771F}', '\u{774A}', '\u{4039}', '\u{778B}', '\u{4046}', '\u{4096}', '\u{2541D}', '\u{784E}', '\u{788C}',
+    '\u{78CC}', '\u{40E3}', '\u{25626}', '\u{7956}', '\u{2569A}', '\u{256C5}', '\u{798F}', '\u{79EB}', '\u{412F}', '\u{7A40}',
+    '\u{7A4A}', '\u{7A4F}', '\u{2597C}', '\u{25AA7}', '\u{25AA7}', '\u{7AEE}', '\u{4202}', '\u{25BAB}', '\u{7BC6}', '\u{7BC9}',
+    '\u{4227}', '\u{25C80}', '\u{7CD2}', '\u{42A0}', '\u{7CE8}', '\u{7CE3}', '\u{7D00}', '\u{25F86}', '\u{7D63}', '\u{4301}',
+    '\u{7DC7}', '\u{7E02}', '\u{7E45}', '\u{4334}', '\u{26228}', '\u{26247}', '\u{4359}', '\u{262D9}', '\u{7F7A}', '\u{2633E}',
+    '\u{7F95}', '\u{7FFA}', '\u{8005}', '\u{264DA}', '\u{26523}', '\u{8060}', '\u{265A8}', '\u{8070}', '\u{2335F}', '\u{43D5}',
+    '\u{80B2}', '\u{8103}', '\u{440B}', '\u{813E}', '\u{5AB5}', '\u{267A7}', '\u{267B5}', '\u{23393}', '\u{2339C}', '\u{8201}',
+    '\u{8204}', '\u{8F9E}', '\u{446B}', '\u{8291}', '\u{828B}', '\u{829D}', '\u{52B3}', '\u{82B1}', '\u{82B3}', '\u{82BD}',
+    '\u{82E6}', '\u{26B3C}', '\u{82E5}', '\u{831D}', '\u{8363}', '\u{83AD}', '\u{8323}', '\u{83BD}', '\u{83E7}', '\u{8457}',
+    '\u{8353}', '\u{83CA}', '\u{83CC}', '\u{83DC}', '\u{26C36}', '\u{26D6B}', '\u{26CD5}', '\u{452B}', '\u{84F1}', '\u{84F3}',
+    '\u{8516}', '\u{273CA}', '\u{8564}', '\u{26F2C}', '\u{455D}', '\u{4561}', '\u{26FB1}', '\u{270D2}', '\u{456B}', '\u{8650}',
+    '\u{865C}', '\u{8667}', '\u{8669}', '\u{86A9}', '\u{8688}', '\u{870E}', '\u{86E2}', '\u{8779}', '\u{8728}', '\u{876B}',
+    '\u{8786}', '\u{45D7}', '\u{87E1}', '\u{8801}', '\u{45F9}', '\u{8860}', '\u{8863}', '\u{27667}', '\u{88D7}', '\u{88DE}',
+    '\u{4635}', '\u{88FA}', '\u{34BB}', '\u{278AE}', '\u{27966}', '\u{46BE}', '\u{46C7}', '\u{8AA0}', '\u{8AED}', '\u{8B8A}',
+    '\u{8C55}', '\u{27CA8}', '\u{8CAB}', '\u{8CC1}', '\u{8D1B}', '\u{8D77}', '\u{27F2F}', '\u{20804}', '\u{8DCB}', '\u{8DBC}',
+    '\u{8DF0}', '\u{208DE}', '\u{8ED4}', '\u{8F38}', '\u{285D2}', '\u{285ED}', '\u{9094}', '\u{90F1}', '\u{9111}', '\u{2872E}',
+    '\u{911B}', '\u{9238}', '\u{92D7}', '\u{92D8}', '\u{927C}', '\u{93F9}', '\u{9415}', '\u{28BFA}', '\u{958B}', '\u{4995}',
+    '\u{95B7}', '\u{28D77}', '\u{49E6}', '\u{96C3}', '\u{5DB2}', '\u{9723}', '\u{29145}', '\u{2921A}', '\u{4A6E}', '\u{4A76}',
+    '\u{97E0}', '\u{2940A}', '\u{4AB2}', '\u{29496}', '\u{980B}', '\u{980B}', '\u{9829}', '\u{295B6}', '\u{98E2}', '\u{4B33}',
+    '\u{9929}', '\u{99A7}', '\u{99C2}', '\u{99FE}', '\u{4BCE}', '\u{29B30}', '\u{9B12}', '\u{9C40}', '\u{9CFD}', '\u{4CCE}',
+    '\u{4CED}', '\u{9D67}', '\u{2A0CE}', '\u{4CF8}', '\u{2A105}', '\u{2A20E}', '\u{2A291}', '\u{9EBB}', '\u{4D56}', '\u{9EF9}',
+    '\u{9EFE}', '\u{9F05}', '\u{9F0F}', '\u{9F16}', '\u{9F3B}', '\u{2A600}',
+];
+
+// full compatibility decompositions, except Hangul syllables
+#[rustfmt::skip]
+pub(crate) const COMPATIBILITY: &[(u32, u16, u8)] = &[
+    (0xA0, 0, 1), (0xA8, 1, 2), (0xAA, 3, 1), (0xAF, 4, 2), (0xB2, 6, 1), (0xB3, 7, 1), (0xB4, 8, 2), (0xB5, 10, 1),
+    (0xB8, 11, 2), (0xB9, 13, 1), (0xBA, 14, 1), (0xBC, 15, 3), (0xBD, 18, 3), (0xBE, 21, 3), (0xC0, 24, 2), (0xC1, 26, 2),
+    (0xC2, 28, 2), (0xC3, 30, 2), (0xC4, 32, 2), (0xC5, 34, 2), (0xC7, 36, 2), (0xC8, 38, 2), (0xC9, 40, 2), (0xCA, 42, 2),
+    (0xCB, 44, 2), (0xCC, 46, 2), (0xCD, 48, 2), (0xCE, 50, 2), (0xCF, 52, 2), (0xD1, 54, 2), (0xD2, 56, 2), (0xD3, 58, 2),
+    (0xD4, 60, 2), (0xD5, 62, 2), (0xD6, 64, 2), (0xD9, 66, 2), (0xDA, 68, 2), (0xDB, 70, 2), (0xDC, 72, 2), (0xDD, 74, 2),
+    (0xE0, 76, 2), (0xE1, 78, 2), (0xE2, 80, 2), (0xE3, 82, 2), (0xE4, 84, 2), (0xE5, 86, 2), (0xE7, 88, 2), (0xE8, 90, 2),
+    (0xE9, 92, 2), (0xEA, 94, 2), (0xEB, 96, 2), (0xEC, 98, 2), (0xED, 100, 2), (0xEE, 102, 2), (0xEF, 104, 2), (0xF1, 106, 2),
+    (0xF2, 108, 2), (0xF3, 110, 2), (0xF4, 112, 2), (0xF5, 114, 2), (0xF6, 116, 2), (0xF9, 118, 2), (0xFA, 120, 2), (0xFB, 122, 2),
+    (0xFC, 124, 2), (0xFD, 126, 2), (0xFF, 128, 2), (0x100, 130, 2), (0x101, 132, 2), (0x102, 134, 2), (0x103, 136, 2), (0x104, 138, 2),
+    (0x105, 140, 2), (0x106, 142, 2), (0x107, 144, 2), (0x108, 146, 2), (0x109, 148, 2), (0x10A, 150, 2), (0x10B, 152, 2), (0x10C, 154, 2),
+    (0x10D, 156, 2), (0x10E, 158, 2), (0x10F, 160, 2), (0x112, 162, 2), (0x113, 164, 2), (0x114, 166, 2), (0x115, 168, 2), (0x116, 170, 2),
+    (0x117, 172, 2), (0x118, 174, 2), (0x119, 176, 2), (0x11A, 178, 2), (0x11B, 180, 2), (0x11C, 182, 2), (0x11D, 184, 2), (0x11E, 186, 2),
+    (0x11F, 188, 2), (0x120, 190, 2), (0x121, 192, 2), (0x122, 194, 2), (0x123, 196, 2), (0x124, 198, 2), (0x125, 200, 2), (0x128, 202, 2),
+    (0x129, 204, 2), (0x12A, 206, 2), (0x12B, 208, 2), (0x12C, 210, 2), (0x12D, 212, 2), (0x12E, 214, 2), (0x12F, 216, 2), (0x130, 218, 2),
+    (0x132, 220, 2), (0x133, 222, 2), (0x134, 224, 2), (0x135, 226, 2), (0x136, 228, 2), (0x137, 230, 2), (0x139, 232, 2), (0x13A, 234, 2),
+    (0x13B, 236, 2), (0x13C, 238, 2), (0x13D, 240, 2), (0x13E, 242, 2), (0x13F, 244, 2), (0x140, 246, 2), (0x143, 248, 2), (0x144, 250, 2),
+    (0x145, 252, 2), (0x146, 254, 2), (0x147, 256, 2), (0x148, 258, 2), (0x149, 260, 2), (0x14C, 262, 2), (0x14D, 264, 2), (0x14E, 266, 2),
+    (0x14F, 268, 2), (0x150, 270, 2), (0x151, 272, 2), (0x154, 274, 2), (0x155, 276, 2), (0x156, 278, 2), (0x157, 280, 2), (0x158, 282, 2),
+    (0x159, 284, 2), (0x15A, 286, 2), (0x15B, 288, 2), (0x15C, 290, 2), (0x15D, 292, 2), (0x15E, 294, 2), (0x15F, 296, 2), (0x160, 298, 2),
+    (0x161, 300, 2), (0x162, 302, 2), (0x163, 304, 2), (0x164, 306, 2), (0x165, 308, 2), (0x168, 310, 2), (0x169, 312, 2), (0x16A, 314, 2),
+    (0x16B, 316, 2), (0x16C, 318, 2), (0x16D, 320, 2), (0x16E, 322, 2), (0x16F, 324, 2), (0x170, 326, 2), (0x171, 328, 2), (0x172, 330, 2),
+    (0x173, 332, 2), (0x174, 334, 2), (0x175, 336, 2), (0x176, 338, 2), (0x177, 340, 2), (0x178, 342, 2), (0x179, 344, 2), (0x17A, 346, 2),
+    (0x17B, 348, 2), (0x17C, 350, 2), (0x17D, 352, 2), (0x17E, 354, 2), (0x17F, 356, 1), (0x1A0, 357, 2), (0x1A1, 359, 2), (0x1AF, 361, 2),
+    (0x1B0, 363, 2), (0x1C4, 365, 3), (0x1C5, 368, 3), (0x1C6, 371, 3), (0x1C7, 374, 2), (0x1C8, 376, 2), (0x1C9, 378, 2), (0x1CA, 380, 2),
+    (0x1CB, 382, 2), (0x1CC, 384, 2), (0x1CD, 386, 2), (0x1CE, 388, 2), (0x1CF, 390, 2), (0x1D0, 392, 2), (0x1D1, 394, 2), (0x1D2, 396, 2),
+    (0x1D3, 398, 2), (0x1D4, 400, 2), (0x1D5, 402, 3), (0x1D6, 405, 3), (0x1D7, 408, 3), (0x1D8, 411, 3), (0x1D9, 414, 3), (0x1DA, 417, 3),
+    (0x1DB, 420, 3), (0x1DC, 423, 3), (0x1DE, 426, 3), (0x1DF, 429, 3), (0x1E0, 432, 3), (0x1E1, 435, 3), (0x1E2, 438, 2), (0x1E3, 440, 2),
+    (0x1E6, 442, 2), (0x1E7, 444, 2), (0x1E8, 446, 2), (0x1E9, 448, 2), (0x1EA, 450, 2), (0x1EB, 452, 2), (0x1EC, 454, 3), (0x1ED, 457, 3),
+    (0x1EE, 460, 2), (0x1EF, 462, 2), (0x1F0, 464, 2), (0x1F1, 466, 2), (0x1F2, 468, 2), (0x1F3, 470, 2), (0x1F4, 472, 2), (0x1F5, 474, 2),
+    (0x1F8, 476, 2), (0x1F9, 478, 2), (0x1FA, 480, 3), (0x1FB, 483, 3), (0x1FC, 486, 2), (0x1FD, 488, 2), (0x1FE, 490, 2), (0x1FF, 492, 2),
+    (0x200, 494, 2), (0x201, 496, 2), (0x202, 498, 2), (0x203, 500, 2), (0x204, 502, 2), (0x205, 504, 2), (0x206, 506, 2), (0x207, 508, 2),
+    (0x208, 510, 2), (0x209, 512, 2), (0x20A, 514, 2), (0x20B, 516, 2), (0x20C, 518, 2), (0x20D, 520, 2), (0x20E, 522, 2), (0x20F, 524, 2),
+    (0x210, 526, 2), (0x211, 528, 2), (0x212, 530, 2), (0x213, 532, 2), (0x214, 534, 2), (0x215, 536, 2), (0x216, 538, 2), (0x217, 540, 2),
+    (0x218, 542, 2), (0x219, 544, 2), (0x21A, 546, 2), (0x21B, 548, 2), (0x21E, 550, 2), (0x21F, 552, 2), (0x226, 554, 2), (0x227, 556, 2),
+    (0x228, 558, 2), (0x229, 560, 2), (0x22A, 562, 3), (0x22B, 565, 3), (0x22C, 568, 3), (0x22D, 571, 3), (0x22E, 574, 2), (0x22F, 576, 2),
+    (0x230, 578, 3), (0x231, 581, 3), (0x232, 584, 2), (0x233, 586, 2), (0x2B0, 588, 1), (0x2B1, 589, 1), (0x2B2, 590, 1), (0x2B3, 591, 1),
+    (0x2B4, 592, 1), (0x2B5, 593, 1), (0x2B6, 594, 1), (0x2B7, 595, 1), (0x2B8, 596, 1), (0x2D8, 597, 2), (0x2D9, 599, 2), (0x2DA, 601, 2),
+    (0x2DB, 603, 2), (0x2DC, 605, 2), (0x2DD, 607, 2), (0x2E0, 609, 1), (0x2E1, 610, 1), (0x2E2, 611, 1), (0x2E3, 612, 1), (0x2E4, 613, 1),
+    (0x340, 614, 1), (0x341, 615, 1), (0x343, 616, 1), (0x344, 617, 2), (0x374, 619, 1), (0x37A, 620, 2), (0x37E, 622, 1), (0x384, 623, 2),
+    (0x385, 625, 3), (0x386, 628, 2), (0x387, 630, 1), (0x388, 631, 2), (0x389, 633, 2), (0x38A, 635, 2), (0x38C, 637, 2), (0x38E, 639, 2),
+    (0x38F, 641, 2), (0x390, 643, 3), (0x3AA, 646, 2), (0x3AB, 648, 2), (0x3AC, 650, 2), (0x3AD, 652, 2), (0x3AE, 654, 2), (0x3AF, 656, 2),
+    (0x3B0, 658, 3), (0x3CA, 661, 2), (0x3CB, 663, 2), (0x3CC, 665, 2), (0x3CD, 667, 2), (0x3CE, 669, 2), (0x3D0, 671, 1), (0x3D1, 672, 1),
+    (0x3D2, 673, 1), (0x3D3, 674, 2), (0x3D4, 676, 2), (0x3D5, 678, 1), (0x3D6, 679, 1), (0x3F0, 680, 1), (0x3F1, 681, 1), (0x3F2, 682, 1),
+    (0x3F4, 683, 1), (0x3F5, 684, 1), (0x3F9, 685, 1), (0x400, 686, 2), (0x401, 688, 2), (0x403, 690, 2), (0x407, 692, 2), (0x40C, 694, 2),
+    (0x40D, 696, 2), (0x40E, 698, 2), (0x419, 700, 2), (0x439, 702, 2), (0x450, 704, 2), (0x451, 706, 2), (0x453, 708, 2), (0x457, 710, 2),
+    (0x45C, 712, 2), (0x45D, 714, 2), (0x45E, 716, 2), (0x476, 718, 2), (0x477, 720, 2), (0x4C1, 722, 2), (0x4C2, 724, 2), (0x4D0, 726, 2),
+    (0x4D1, 728, 2), (0x4D2, 730, 2), (0x4D3, 732, 2), (0x4D6, 734, 2), (0x4D7, 736, 2), (0x4DA, 738, 2), (0x4DB, 740, 2), (0x4DC, 742, 2),
+    (0x4DD, 744, 2), (0x4DE, 746, 2), (0x4DF, 748, 2), (0x4E2, 750, 2), (0x4E3, 752, 2), (0x4E4, 754, 2), (0x4E5, 756, 2), (0x4E6, 758, 2),
+    (0x4E7, 760, 2), (0x4EA, 762, 2), (0x4EB, 764, 2), (0x4EC, 766, 2), (0x4ED, 768, 2), (0x4EE, 770, 2), (0x4EF, 772, 2), (0x4F0, 774, 2),
+    (0x4F1, 776, 2), (0x4F2, 778, 2), (0x4F3, 780, 2), (0x4F4, 782, 2), (0x4F5, 784, 2), (0x4F8, 786, 2), (0x4F9, 788, 2), (0x587, 790, 2),
+    (0x622, 792, 2), (0x623, 794, 2), (0x624, 796, 2), (0x625, 798, 2), (0x626, 800, 2), (0x675, 802, 2), (0x676, 804, 2), (0x677, 806, 2),
+    (0x678, 808, 2), (0x6C0, 810, 2), (0x6C2, 812, 2), (0x6D3, 814, 2), (0x929, 816, 2), (0x931, 818, 2), (0x934, 820, 2), (0x958, 822, 2),
+    (0x959, 824, 2), (0x95A, 826, 2), (0x95B, 828, 2), (0x95C, 830, 2), (0x95D, 832, 2), (0x95E, 834, 2), (0x95F, 836, 2), (0x9CB, 838, 2),
+    (0x9CC, 840, 2), (0x9DC, 842, 2), (0x9DD, 844, 2), (0x9DF, 846, 2), (0xA33, 848, 2), (0xA36, 850, 2), (0xA59, 852, 2), (0xA5A, 854, 2),
+    (0xA5B, 856, 2), (0xA5E, 858, 2), (0xB48, 860, 2), (0xB4B, 862, 2), (0xB4C, 864, 2), (0xB5C, 866, 2), (0xB5D, 868, 2), (0xB94, 870, 2),
+    (0xBCA, 872, 2), (0xBCB, 874, 2), (0xBCC, 876, 2), (0xC48, 878, 2), (0xCC0, 880, 2), (0xCC7, 882, 2), (0xCC8, 884, 2), (0xCCA, 886, 2),
+    (0xCCB, 888, 3), (0xD4A, 891, 2), (0xD4B, 893, 2), (0xD4C, 895, 2), (0xDDA, 897, 2), (0xDDC, 899, 2), (0xDDD, 901, 3), (0xDDE, 904, 2),
+    (0xE33, 906, 2), (0xEB3, 908, 2), (0xEDC, 910, 2), (0xEDD, 912, 2), (0xF0C, 914, 1), (0xF43, 915, 2), (0xF4D, 917, 2), (0xF52, 919, 2),
+    (0xF57, 921, 2), (0xF5C, 923, 2), (0xF69, 925, 2), (0xF73, 927, 2), (0xF75, 929, 2), (0xF76, 931, 2), (0xF77, 933, 3), (0xF78, 936, 2),
+    (0xF79, 938, 3), (0xF81, 941, 2), (0xF93, 943, 2), (0xF9D, 945, 2), (0xFA2, 947, 2), (0xFA7, 949, 2), (0xFAC, 951, 2), (0xFB9, 953, 2),
+    (0x1026, 955, 2), (0x10FC, 957, 1), (0x1B06, 958, 2), (0x1B08, 960, 2), (0x1B0A, 962, 2), (0x1B0C, 964, 2), (0x1B0E, 966, 2), (0x1B12, 968, 2),
+    (0x1B3B, 970, 2), (0x1B3D, 972, 2), (0x1B40, 974, 2), (0x1B41, 976, 2), (0x1B43, 978, 2), (0x1D2C, 980, 1), (0x1D2D, 981, 1), (0x1D2E, 982, 1),
+    (0x1D30, 983, 1), (0x1D31, 984, 1), (0x1D32, 985, 1), (0x1D33, 986, 1), (0x1D34, 987, 1), (0x1D35, 988, 1), (0x1D36, 989, 1), (0x1D37, 990, 1),
+    (0x1D38, 991, 1), (0x1D39, 992, 1), (0x1D3A, 993, 1), (0x1D3C, 994, 1), (0x1D3D, 995, 1), (0x1D3E, 996, 1), (0x1D3F, 997, 1), (0x1D40, 998, 1),
+    (0x1D41, 999, 1), (0x1D42, 1000, 1), (0x1D43, 1001, 1), (0x1D44, 1002, 1), (0x1D45, 1003, 1), (0x1D46, 1004, 1), (0x1D47, 1005, 1), (0x1D48, 1006, 1),
+    (0x1D49, 1007, 1), (0x1D4A, 1008, 1), (0x1D4B, 1009, 1), (0x1D4C, 1010, 1), (0x1D4D, 1011, 1), (0x1D4F, 1012, 1), (0x1D50, 1013, 1), (0x1D51, 1014, 1),
+    (0x1D52, 1015, 1), (0x1D53, 1016, 1), (0x1D54, 1017, 1), (0x1D55, 1018, 1), (0x1D56, 1019, 1), (0x1D57, 1020, 1), (0x1D58, 1021, 1), (0x1D59, 1022, 1),
+    (0x1D5A, 1023, 1), (0x1D5B, 1024, 1), (0x1D5C, 1025, 1), (0x1D5D, 1026, 1), (0x1D5E, 1027, 1), (0x1D5F, 1028, 1), (0x1D60, 1029, 1), (0x1D61, 1030, 1),
+    (0x1D62, 1031, 1), (0x1D63, 1032, 1), (0x1D64, 1033, 1), (0x1D65, 1034, 1), (0x1D66, 1035, 1), (0x1D67, 1036, 1), (0x1D68, 1037, 1), (0x1D69, 1038, 1),
+    (0x1D6A, 1039, 1), (0x1D78, 1040, 1), (0x1D9B, 1041, 1), (0x1D9C, 1042, 1), (0x1D9D, 1043, 1), (0x1D9E, 1044, 1), (0x1D9F, 1045, 1), (0x1DA0, 1046, 1),
+    (0x1DA1, 1047, 1), (0x1DA2, 1048, 1), (0x1DA3, 1049, 1), (0x1DA4, 1050, 1), (0x1DA5, 1051, 1), (0x1DA6, 1052, 1), (0x1DA7, 1053, 1), (0x1DA8, 1054, 1),
+    (0x1DA9, 1055, 1), (0x1DAA, 1056, 1), (0x1DAB, 1057, 1), (0x1DAC, 1058, 1), (0x1DAD, 1059, 1), (0x1DAE, 1060, 1), (0x1DAF, 1061, 1), (0x1DB0, 1062, 1),
+    (0x1DB1, 1063, 1), (0x1DB2, 1064, 1), (0x1DB3, 1065, 1), (0x1DB4, 1066, 1), (0x1DB5, 1067, 1), (0x1DB6, 1068, 1), (0x1DB7, 1069, 1), (0x1DB8, 1070, 1),
+    (0x1DB9, 1071, 1), (0x1DBA, 1072, 1), (0x1DBB, 1073, 1), (0x1DBC, 1074, 1), (0x1DBD, 1075, 1), (0x1DBE, 1076, 1), (0x1DBF, 1077, 1), (0x1E00, 1078, 2),
+    (0x1E01, 1080, 2), (0x1E02, 1082, 2), (0x1E03, 1084, 2), (0x1E04, 1086, 2), (0x1E05, 1088, 2), (0x1E06, 1090, 2), (0x1E07, 1092, 2), (0x1E08, 1094, 3),
+    (0x1E09, 1097, 3), (0x1E0A, 1100, 2), (0x1E0B, 1102, 2), (0x1E0C, 1104, 2), (0x1E0D, 1106, 2), (0x1E0E, 1108, 2), (0x1E0F, 1110, 2), (0x1E10, 1112, 2),
+    (0x1E11, 1114, 2), (0x1E12, 1116, 2), (0x1E13, 1118, 2), (0x1E14, 1120, 3), (0x1E15, 1123, 3), (0x1E16, 1126, 3), (0x1E17, 1129, 3), (0x1E18, 1132, 2),
+    (0x1E19, 1134, 2), (0x1E1A, 1136, 2), (0x1E1B, 1138, 2), (0x1E1C, 1140, 3), (0x1E1D, 1143, 3), (0x1E1E, 1146, 2), (0x1E1F, 1148, 2), (0x1E20, 1150, 2),
+    (0x1E21, 1152, 2), (0x1E22, 1154, 2), (0x1E23, 1156, 2), (0x1E24, 1158, 2), (0x1E25, 1160, 2), (0x1E26, 1162, 2), (0x1E27, 1164, 2), (0x1E28, 1166, 2),
+    (0x1E29, 1168, 2), (0x1E2A, 1170, 2), (0x1E2B, 1172, 2), (0x1E2C, 1174, 2), (0x1E2D, 1176, 2), (0x1E2E, 1178, 3), (0x1E2F, 1181, 3), (0x1E30, 1184, 2),
+    (0x1E31, 1186, 2), (0x1E32, 1188, 2), (0x1E33, 1190, 2), (0x1E34, 1192, 2), (0x1E35, 1194, 2), (0x1E36, 1196, 2), (0x1E37, 1198, 2), (0x1E38, 1200, 3),
+    (0x1E39, 1203, 3), (0x1E3A, 1206, 2), (0x1E3B, 1208, 2), (0x1E3C, 1210, 2), (0x1E3D, 1212, 2), (0x1E3E, 1214, 2), (0x1E3F, 1216, 2), (0x1E40, 1218, 2),
+    (0x1E41, 1220, 2), (0x1E42, 1222, 2), (0x1E43, 1224, 2), (0x1E44, 1226, 2), (0x1E45, 1228, 2), (0x1E46, 1230, 2), (0x1E47, 1232, 2), (0x1E48, 1234, 2),
+    (0x1E49, 1236, 2), (0x1E4A, 1238, 2), (0x1E4B, 1240, 2), (0x1E4C, 1242, 3), (0x1E4D, 1245, 3), (0x1E4E, 1248, 3), (0x1E4F, 1251, 3), (0x1E50, 1254, 3),
+    (0x1E51, 1257, 3), (0x1E52, 1260, 3), (0x1E53, 1263, 3), (0x1E54, 1266, 2), (0x1E55, 1268, 2), (0x1E56, 1270, 2), (0x1E57, 1272, 2), (0x1E58, 1274, 2),
+    (0x1E59, 1276, 2), (0x1E5A, 1278, 2), (0x1E5B, 1280, 2), (0x1E5C, 1282, 3), (0x1E5D, 1285, 3), (0x1E5E, 1288, 2), (0x1E5F, 1290, 2), (0x1E60, 1292, 2),
+    (0x1E61, 1294, 2), (0x1E62, 1296, 2), (0x1E63, 1298, 2), (0x1E64, 1300, 3), (0x1E65, 1303, 3), (0x1E66, 1306, 3), (0x1E67, 1309, 3), (0x1E68, 1312, 3),
+    (0x1E69, 1315, 3), (0x1E6A, 1318, 2), (0x1E6B, 1320, 2), (0x1E6C, 1322, 2), (0x1E6D, 1324, 2), (0x1E6E, 1326, 2), (0x1E6F, 1328, 2), (0x1E70, 1330, 2),
+    (0x1E71, 1332, 2), (0x1E72, 1334, 2), (0x1E73, 1336, 2), (0x1E74, 1338, 2), (0x1E75, 1340, 2), (0x1E76, 1342, 2), (0x1E77, 1344, 2), (0x1E78, 1346, 3),
+    (0x1E79, 1349, 3), (0x1E7A, 1352, 3), (0x1E7B, 1355, 3), (0x1E7C, 1358, 2), (0x1E7D, 1360, 2), (0x1E7E, 1362, 2), (0x1E7F, 1364, 2), (0x1E80, 1366, 2),
+    (0x1E81, 1368, 2), (0x1E82, 1370, 2), (0x1E83, 1372, 2), (0x1E84, 1374, 2), (0x1E85, 1376, 2), (0x1E86, 1378, 2), (0x1E87, 1380, 2), (0x1E88, 1382, 2),
+    (0x1E89, 1384, 2), (0x1E8A, 1386, 2), (0x1E8B, 1388, 2), (0x1E8C, 1390, 2), (0x1E8D, 1392, 2), (0x1E8E, 1394, 2), (0x1E8F, 1396, 2), (0x1E90, 1398, 2),
+    (0x1E91, 1400, 2), (0x1E92, 1402, 2), (0x1E93, 1404, 2), (0x1E94, 1406, 2), (0x1E95, 1408, 2), (0x1E96, 1410, 2), (0x1E97, 1412, 2), (0x1E98, 1414, 2),
+    (0x1E99, 1416, 2), (0x1E9A, 1418, 2), (0x1E9B, 1420, 2), (0x1EA0, 1422, 2), (0x1EA1, 1424, 2), (0x1EA2, 1426, 2), (0x1EA3, 1428, 2), (0x1EA4, 1430, 3),
+    (0x1EA5, 1433, 3), (0x1EA6, 1436, 3), (0x1EA7, 1439, 3), (0x1EA8, 1442, 3), (0x1EA9, 1445, 3), (0x1EAA, 1448, 3), (0x1EAB, 1451, 3), (0x1EAC, 1454, 3),
+    (0x1EAD, 1457, 3), (0x1EAE, 1460, 3), (0x1EAF, 1463, 3), (0x1EB0, 1466, 3), (0x1EB1, 1469, 3), (0x1EB2, 1472, 3), (0x1EB3, 1475, 3), (0x1EB4, 1478, 3),
+    (0x1EB5, 1481, 3), (0x1EB6, 1484, 3), (0x1EB7, 1487, 3), (0x1EB8, 1490, 2), (0x1EB9, 1492, 2), (0x1EBA, 1494, 2), (0x1EBB, 1496, 2), (0x1EBC, 1498, 2),
+    (0x1EBD, 1500, 2), (0x1EBE, 1502, 3), (0x1EBF, 1505, 3), (0x1EC0, 1508, 3), (0x1EC1, 1511, 3), (0x1EC2, 1514, 3), (0x1EC3, 1517, 3), (0x1EC4, 1520, 3),
+    (0x1EC5, 1523, 3), (0x1EC6, 1526, 3), (0x1EC7, 1529, 3), (0x1EC8, 1532, 2), (0x1EC9, 1534, 2), (0x1ECA, 1536, 2), (0x1ECB, 1538, 2), (0x1ECC, 1540, 2),
+    (0x1ECD, 1542, 2), (0x1ECE, 1544, 2), (0x1ECF, 1546, 2), (0x1ED0, 1548, 3), (0x1ED1, 1551, 3), (0x1ED2, 1554, 3), (0x1ED3, 1557, 3), (0x1ED4, 1560, 3),
+    (0x1ED5, 1563, 3), (0x1ED6, 1566, 3), (0x1ED7, 1569, 3), (0x1ED8, 1572, 3), (0x1ED9, 1575, 3), (0x1EDA, 1578, 3), (0x1EDB, 1581, 3), (0x1EDC, 1584, 3),
+    (0x1EDD, 1587, 3), (0x1EDE, 1590, 3), (0x1EDF, 1593, 3), (0x1EE0, 1596, 3), (0x1EE1, 1599, 3), (0x1EE2, 1602, 3), (0x1EE3, 1605, 3), (0x1EE4, 1608, 2),
+    (0x1EE5, 1610, 2), (0x1EE6, 1612, 2), (0x1EE7, 1614, 2), (0x1EE8, 1616, 3), (0x1EE9, 1619, 3), (0x1EEA, 1622, 3), (0x1EEB, 1625, 3), (0x1EEC, 1628, 3),
+    (0x1EED, 1631, 3), (0x1EEE, 1634, 3), (0x1EEF, 1637, 3), (0x1EF0, 1640, 3), (0x1EF1, 1643, 3), (0x1EF2, 1646, 2), (0x1EF3, 1648, 2), (0x1EF4, 1650, 2),
+    (0x1EF5, 1652, 2), (0x1EF6, 1654, 2), (0x1EF7, 1656, 2), (0x1EF8, 1658, 2), (0x1EF9, 1660, 2), (0x1F00, 1662, 2), (0x1F01, 1664, 2), (0x1F02, 1666, 3),
+    (0x1F03, 1669, 3), (0x1F04, 1672, 3), (0x1F05, 1675, 3), (0x1F06, 1678, 3), (0x1F07, 1681, 3), (0x1F08, 1684, 2), (0x1F09, 1686, 2), (0x1F0A, 1688, 3),
+    (0x1F0B, 1691, 3), (0x1F0C, 1694, 3), (0x1F0D, 1697, 3), (0x1F0E, 1700, 3), (0x1F0F, 1703, 3), (0x1F10, 1706, 2), (0x1F11, 1708, 2), (0x1F12, 1710, 3),
+    (0x1F13, 1713, 3), (0x1F14, 1716, 3), (0x1F15, 1719, 3), (0x1F18, 1722, 2), (0x1F19, 1724, 2), (0x1F1A, 1726, 3), (0x1F1B, 1729, 3), (0x1F1C, 1732, 3),
+    (0x1F1D, 1735, 3), (0x1F20, 1738, 2), (0x1F21, 1740, 2), (0x1F22, 1742, 3), (0x1F23, 1745, 3), (0x1F24, 1748, 3), (0x1F25, 1751, 3), (0x1F26, 1754, 3),
+    (0x1F27, 1757, 3), (0x1F28, 1760, 2), (0x1F29, 1762, 2), (0x1F2A, 1764, 3), (0x1F2B, 1767, 3), (0x1F2C, 1770, 3), (0x1F2D, 1773, 3), (0x1F2E, 1776, 3),
+    (0x1F2F, 1779, 3), (0x1F30, 1782, 2), (0x1F31, 1784, 2), (0x1F32, 1786, 3), (0x1F33, 1789, 3), (0x1F34, 1792, 3), (0x1F35, 1795, 3), (0x1F36, 1798, 3),
+    (0x1F37, 1801, 3), (0x1F38, 1804, 2), (0x1F39, 1806, 2), (0x1F3A, 1808, 3), (0x1F3B, 1811, 3), (0x1F3C, 1814, 3), (0x1F3D, 1817, 3), (0x1F3E, 1820, 3),
+    (0x1F3F, 1823, 3), (0x1F40, 1826, 2), (0x1F41, 1828, 2), (0x1F42, 1830, 3), (0x1F43, 1833, 3), (0x1F44, 1836, 3), (0x1F45, 1839, 3), (0x1F48, 1842, 2),
+    (0x1F49, 1844, 2), (0x1F4A, 1846, 3), (0x1F4B, 1849, 3), (0x1F4C, 1852, 3), (0x1F4D, 1855, 3), (0x1F50, 1858, 2), (0x1F51, 1860, 2), (0x1F52, 1862, 3),
+    (0x1F53, 1865, 3), (0x1F54, 1868, 3), (0x1F55, 1871, 3), (0x1F56, 1874, 3), (0x1F57, 1877, 3), (0x1F59, 1880, 2), (0x1F5B, 1882, 3), (0x1F5D, 1885, 3),
+    (0x1F5F, 1888, 3), (0x1F60, 1891, 2), (0x1F61, 1893, 2), (0x1F62, 1895, 3), (0x1F63, 1898, 3), (0x1F64, 1901, 3), (0x1F65, 1904, 3), (0x1F66, 1907, 3),
+    (0x1F67, 1910, 3), (0x1F68, 1913, 2), (0x1F69, 1915, 2), (0x1F6A, 1917, 3), (0x1F6B, 1920, 3), (0x1F6C, 1923, 3), (0x1F6D, 1926, 3), (0x1F6E, 1929, 3),
+    (0x1F6F, 1932, 3), (0x1F70, 1935, 2), (0x1F71, 1937, 2), (0x1F72, 1939, 2), (0x1F73, 1941, 2), (0x1F74, 1943, 2), (0x1F75, 1945, 2), (0x1F76, 1947, 2),
+    (0x1F77, 1949, 2), (0x1F78, 1951, 2), (0x1F79, 1953, 2), (0x1F7A, 1955, 2), (0x1F7B, 1957, 2), (0x1F7C, 1959, 2), (0x1F7D, 1961, 2), (0x1F80, 1963, 3),
+    (0x1F81, 1966, 3), (0x1F82, 1969, 4), (0x1F83, 1973, 4), (0x1F84, 1977, 4), (0x1F85, 1981, 4), (0x1F86, 1985, 4), (0x1F87, 1989, 4), (0x1F88, 1993, 3),
+    (0x1F89, 1996, 3), (0x1F8A, 1999, 4), (0x1F8B, 2003, 4), (0x1F8C, 2007, 4), (0x1F8D, 2011, 4), (0x1F8E, 2015, 4), (0x1F8F, 2019, 4), (0x1F90, 2023, 3),
+    (0x1F91, 2026, 3), (0x1F92, 2029, 4), (0x1F93, 2033, 4), (0x1F94, 2037, 4), (0x1F95, 2041, 4), (0x1F96, 2045, 4), (0x1F97, 2049, 4), (0x1F98, 2053, 3),
+    (0x1F99, 2056, 3), (0x1F9A, 2059, 4), (0x1F9B, 2063, 4), (0x1F9C, 2067, 4), (0x1F9D, 2071, 4), (0x1F9E, 2075, 4), (0x1F9F, 2079, 4), (0x1FA0, 2083, 3),
+    (0x1FA1, 2086, 3), (0x1FA2, 2089, 4), (0x1FA3, 2093, 4), (0x1FA4, 2097, 4), (0x1FA5, 2101, 4), (0x1FA6, 2105, 4), (0x1FA7, 2109, 4), (0x1FA8, 2113, 3),
+    (0x1FA9, 2116, 3), (0x1FAA, 2119, 4), (0x1FAB, 2123, 4), (0x1FAC, 2127, 4), (0x1FAD, 2131, 4), (0x1FAE, 2135, 4), (0x1FAF, 2139, 4), (0x1FB0, 2143, 2),
+    (0x1FB1, 2145, 2), (0x1FB2, 2147, 3), (0x1FB3, 2150, 2), (0x1FB4, 2152, 3), (0x1FB6, 2155, 2), (0x1FB7, 2157, 3), (0x1FB8, 2160, 2), (0x1FB9, 2162, 2),
+    (0x1FBA, 2164, 2), (0x1FBB, 2166, 2), (0x1FBC, 2168, 2), (0x1FBD, 2170, 2), (0x1FBE, 2172, 1), (0x1FBF, 2173, 2), (0x1FC0, 2175, 2), (0x1FC1, 2177, 3),
+    (0x1FC2, 2180, 3), (0x1FC3, 2183, 2), (0x1FC4, 2185, 3), (0x1FC6, 2188, 2), (0x1FC7, 2190, 3), (0x1FC8, 2193, 2), (0x1FC9, 2195, 2), (0x1FCA, 2197, 2),
+    (0x1FCB, 2199, 2), (0x1FCC, 2201, 2), (0x1FCD, 2203, 3), (0x1FCE, 2206, 3), (0x1FCF, 2209, 3), (0x1FD0, 2212, 2), (0x1FD1, 2214, 2), (0x1FD2, 2216, 3),
+    (0x1FD3, 2219, 3), (0x1FD6, 2222, 2), (0x1FD7, 2224, 3), (0x1FD8, 2227, 2), (0x1FD9, 2229, 2), (0x1FDA, 2231, 2), (0x1FDB, 2233, 2), (0x1FDD, 2235, 3),
+    (0x1FDE, 2238, 3), (0x1FDF, 2241, 3), (0x1FE0, 2244, 2), (0x1FE1, 2246, 2), (0x1FE2, 2248, 3), (0x1FE3, 2251, 3), (0x1FE4, 2254, 2), (0x1FE5, 2256, 2),
+    (0x1FE6, 2258, 2), (0x1FE7, 2260, 3), (0x1FE8, 2263, 2), (0x1FE9, 2265, 2), (0x1FEA, 2267, 2), (0x1FEB, 2269, 2), (0x1FEC, 2271, 2), (0x1FED, 2273, 3),
+    (0x1FEE, 2276, 3), (0x1FEF, 2279, 1), (0x1FF2, 2280, 3), (0x1FF3, 2283, 2), (0x1FF4, 2285, 3), (0x1FF6, 2288, 2), (0x1FF7, 2290, 3), (0x1FF8, 2293, 2),
+    (0x1FF9, 2295, 2), (0x1FFA, 2297, 2), (0x1FFB, 2299, 2), (0x1FFC, 2301, 2), (0x1FFD, 2303, 2), (0x1FFE, 2305, 2), (0x2000, 2307, 1), (0x2001, 2308, 1),
+    (0x2002, 2309, 1), (0x2003, 2310, 1), (0x2004, 2311, 1), (0x2005, 2312, 1), (0x2006, 2313, 1), (0x2007, 2314, 1), (0x2008, 2315, 1), (0x2009, 2316, 1),
+    (0x200A, 2317, 1), (0x2011, 2318, 1), (0x2017, 2319, 2), (0x2024, 2321, 1), (0x2025, 2322, 2), (0x2026, 2324, 3), (0x202F, 2327, 1), (0x2033, 2328, 2),
+    (0x2034, 2330, 3), (0x2036, 2333, 2), (0x2037, 2335, 3), (0x203C, 2338, 2), (0x203E, 2340, 2), (0x2047, 2342, 2), (0x2048, 2344, 2), (0x2049, 2346, 2),
+    (0x2057, 2348, 4), (0x205F, 2352, 1), (0x2070, 2353, 1), (0x2071, 2354, 1), (0x2074, 2355, 1), (0x2075, 2356, 1), (0x2076, 2357, 1), (0x2077, 2358, 1),
+    (0x2078, 2359, 1), (0x2079, 2360, 1), (0x207A, 2361, 1), (0x207B, 2362, 1), (0x207C, 2363, 1), (0x207D, 2364, 1), (0x207E, 2365, 1), (0x207F, 2366, 1),
+    (0x2080, 2367, 1), (0x2081, 2368, 1), (0x2082, 2369, 1), (0x2083, 2370, 1), (0x2084, 2371, 1), (0x2085, 2372, 1), (0x2086, 2373, 1), (0x2087, 2374, 1),
+    (0x2088, 2375, 1), (0x2089, 2376, 1), (0x208A, 2377, 1), (0x208B, 2378, 1), (0x208C, 2379, 1), (0x208D, 2380, 1), (0x208E, 2381, 1), (0x2090, 2382, 1),
+    (0x2091, 2383, 1), (0x2092, 2384, 1), (0x2093, 2385, 1), (0x2094, 2386, 1), (0x2095, 2387, 1), (0x2096, 2388, 1), (0x2097, 2389, 1), (0x2098, 2390, 1),
+    (0x2099, 2391, 1), (0x209A, 2392, 1), (0x209B, 2393, 1), (0x209C, 2394, 1), (0x20A8, 2395, 2), (0x2100, 2397, 3), (0x2101, 2400, 3), (0x2102, 2403, 1),
+    (0x2103, 2404, 2), (0x2105, 2406, 3), (0x2106, 2409, 3), (0x2107, 2412, 1), (0x2109, 2413, 2), (0x210A, 2415, 1), (0x210B, 2416, 1), (0x210C, 2417, 1),
+    (0x210D, 2418, 1), (0x210E, 2419, 1), (0x210F, 2420, 1), (0x2110, 2421, 1), (0x2111, 2422, 1), (0x2112, 2423, 1), (0x2113, 2424, 1), (0x2115, 2425, 1),
+    (0x2116, 2426, 2), (0x2119, 2428, 1), (0x211A, 2429, 1), (0x211B, 2430, 1), (0x211C, 2431, 1), (0x211D, 2432, 1), (0x2120, 2433, 2), (0x2121, 2435, 3),
+    (0x2122, 2438, 2), (0x2124, 2440, 1), (0x2126, 2441, 1), (0x2128, 2442, 1), (0x212A, 2443, 1), (0x212B, 2444, 2), (0x212C, 2446, 1), (0x212D, 2447, 1),
+    (0x212F, 2448, 1), (0x2130, 2449, 1), (0x2131, 2450, 1), (0x2133, 2451, 1), (0x2134, 2452, 1), (0x2135, 2453, 1), (0x2136, 2454, 1), (0x2137, 2455, 1),
+    (0x2138, 2456, 1), (0x2139, 2457, 1), (0x213B, 2458, 3), (0x213C, 2461, 1), (0x213D, 2462, 1), (0x213E, 2463, 1), (0x213F, 2464, 1), (0x2140, 2465, 1),
+    (0x2145, 2466, 1), (0x2146, 2467, 1), (0x2147, 2468, 1), (0x2148, 2469, 1), (0x2149, 2470, 1), (0x2150, 2471, 3), (0x2151, 2474, 3), (0x2152, 2477, 4),
+    (0x2153, 2481, 3), (0x2154, 2484, 3), (0x2155, 2487, 3), (0x2156, 2490, 3), (0x2157, 2493, 3), (0x2158, 2496, 3), (0x2159, 2499, 3), (0x215A, 2502, 3),
+    (0x215B, 2505, 3), (0x215C, 2508, 3), (0x215D, 2511, 3), (0x215E, 2514, 3), (0x215F, 2517, 2), (0x2160, 2519, 1), (0x2161, 2520, 2), (0x2162, 2522, 3),
+    (0x2163, 2525, 2), (0x2164, 2527, 1), (0x2165, 2528, 2), (0x2166, 2530, 3), (0x2167, 2533, 4), (0x2168, 2537, 2), (0x2169, 2539, 1), (0x216A, 2540, 2),
+    (0x216B, 2542, 3), (0x216C, 2545, 1), (0x216D, 2546, 1), (0x216E, 2547, 1), (0x216F, 2548, 1), (0x2170, 2549, 1), (0x2171, 2550, 2), (0x2172, 2552, 3),
+    (0x2173, 2555, 2), (0x2174, 2557, 1), (0x2175, 2558, 2), (0x2176, 2560, 3), (0x2177, 2563, 4), (0x2178, 2567, 2), (0x2179, 2569, 1), (0x217A, 2570, 2),
+    (0x217B, 2572, 3), (0x217C, 2575, 1), (0x217D, 2576, 1), (0x217E, 2577, 1), (0x217F, 2578, 1), (0x2189, 2579, 3), (0x219A, 2582, 2), (0x219B, 2584, 2),
+    (0x21AE, 2586, 2), (0x21CD, 2588, 2), (0x21CE, 2590, 2), (0x21CF, 2592, 2), (0x2204, 2594, 2), (0x2209, 2596, 2), (0x220C, 2598, 2), (0x2224, 2600, 2),
+    (0x2226, 2602, 2), (0x222C, 2604, 2), (0x222D, 2606, 3), (0x222F, 2609, 2), (0x2230, 2611, 3), (0x2241, 2614, 2), (0x2244, 2616, 2), (0x2247, 2618, 2),
+    (0x2249, 2620, 2), (0x2260, 2622, 2), (0x2262, 2624, 2), (0x226D, 2626, 2), (0x226E, 2628, 2), (0x226F, 2630, 2), (0x2270, 2632, 2), (0x2271, 2634, 2),
+    (0x2274, 2636, 2), (0x2275, 2638, 2), (0x2278, 2640, 2), (0x2279, 2642, 2), (0x2280, 2644, 2), (0x2281, 2646, 2), (0x2284, 2648, 2), (0x2285, 2650, 2),
+    (0x2288, 2652, 2), (0x2289, 2654, 2), (0x22AC, 2656, 2), (0x22AD, 2658, 2), (0x22AE, 2660, 2), (0x22AF, 2662, 2), (0x22E0, 2664, 2), (0x22E1, 2666, 2),
+    (0x22E2, 2668, 2), (0x22E3, 2670, 2), (0x22EA, 2672, 2), (0x22EB, 2674, 2), (0x22EC, 2676, 2), (0x22ED, 2678, 2), (0x2329, 2680, 1), (0x232A, 2681, 1),
+    (0x2460, 2682, 1), (0x2461, 2683, 1), (0x2462, 2684, 1), (0x2463, 2685, 1), (0x2464, 2686, 1), (0x2465, 2687, 1), (0x2466, 2688, 1), (0x2467, 2689, 1),
+    (0x2468, 2690, 1), (0x2469, 2691, 2), (0x246A, 2693, 2), (0x246B, 2695, 2), (0x246C, 2697, 2), (0x246D, 2699, 2), (0x246E, 2701, 2), (0x246F, 2703, 2),
+    (0x2470, 2705, 2), (0x2471, 2707, 2), (0x2472, 2709, 2), (0x2473, 2711, 2), (0x2474, 2713, 3), (0x2475, 2716, 3), (0x2476, 2719, 3), (0x2477, 2722, 3),
+    (0x2478, 2725, 3), (0x2479, 2728, 3), (0x247A, 2731, 3), (0x247B, 2734, 3), (0x247C, 2737, 3), (0x247D, 2740, 4), (0x247E, 2744, 4), (0x247F, 2748, 4),
+    (0x2480, 2752, 4), (0x2481, 2756, 4), (0x2482, 2760, 4), (0x2483, 2764, 4), (0x2484, 2768, 4), (0x2485, 2772, 4), (0x2486, 2776, 4), (0x2487, 2780, 4),
+    (0x2488, 2784, 2), (0x2489, 2786, 2), (0x248A, 2788, 2), (0x248B, 2790, 2), (0x248C, 2792, 2), (0x248D, 2794, 2), (0x248E, 2796, 2), (0x248F, 2798, 2),
+    (0x2490, 2800, 2), (0x2491, 2802, 3), (0x2492, 2805, 3), (0x2493, 2808, 3), (0x2494, 2811, 3), (0x2495, 2814, 3), (0x2496, 2817, 3), (0x2497, 2820, 3),
+    (0x2498, 2823, 3), (0x2499, 2826, 3), (0x249A, 2829, 3), (0x249B, 2832, 3), (0x249C, 2835, 3), (0x249D, 2838, 3), (0x249E, 2841, 3), (0x249F, 2844, 3),
+    (0x24A0, 2847, 3), (0x24A1, 2850, 3), (0x24A2, 2853, 3), (0x24A3, 2856, 3), (0x24A4, 2859, 3), (0x24A5, 2862, 3), (0x24A6, 2865, 3), (0x24A7, 2868, 3),
+    (0x24A8, 2871, 3), (0x24A9, 2874, 3), (0x24AA, 2877, 3), (0x24AB, 2880, 3), (0x24AC, 2883, 3), (0x24AD, 2886, 3), (0x24AE, 2889, 3), (0x24AF, 2892, 3),
+    (0x24B0, 2895, 3), (0x24B1, 2898, 3), (0x24B2, 2901, 3), (0x24B3, 2904, 3), (0x24B4, 2907, 3), (0x24B5, 2910, 3), (0x24B6, 2913, 1), (0x24B7, 2914, 1),
+    (0x24B8, 2915, 1), (0x24B9, 2916, 1), (0x24BA, 2917, 1), (0x24BB, 2918, 1), (0x24BC, 2919, 1), (0x24BD, 2920, 1), (0x24BE, 2921, 1), (0x24BF, 2922, 1),
+    (0x24C0, 2923, 1), (0x24C1, 2924, 1), (0x24C2, 2925, 1), (0x24C3, 2926, 1), (0x24C4, 2927, 1), (0x24C5, 2928, 1), (0x24C6, 2929, 1), (0x24C7, 2930, 1),
+    (0x24C8, 2931, 1), (0x24C9, 2932, 1), (0x24CA, 2933, 1), (0x24CB, 2934, 1), (0x24CC, 2935, 1), (0x24CD, 2936, 1), (0x24CE, 2937, 1), (0x24CF, 2938, 1),
+    (0x24D0, 2939, 1), (0x24D1, 2940, 1), (0x24D2, 2941, 1), (0x24D3, 2942, 1), (0x24D4, 2943, 1), (0x24D5, 2944, 1), (0x24D6, 2945, 1), (0x24D7, 2946, 1),
+    (0x24D8, 2947, 1), (0x24D9, 2948, 1), (0x24DA, 2949, 1), (0x24DB, 2950, 1), (0x24DC, 2951, 1), (0x24DD, 2952, 1), (0x24DE, 2953, 1), (0x24DF, 2954, 1),
+    (0x24E0, 2955, 1), (0x24E1, 2956, 1), (0x24E2, 2957, 1), (0x24E3, 2958, 1), (0x24E4, 2959, 1), (0x24E5, 2960, 1), (0x24E6, 2961, 1), (0x24E7, 2962, 1),
+    (0x24E8, 2963, 1), (0x24E9, 2964, 1), (0x24EA, 2965, 1), (0x2A0C, 2966, 4), (0x2A74, 2970, 3), (0x2A75, 2973, 2), (0x2A76, 2975, 3), (0x2ADC, 2978, 2),
+    (0x2C7C, 2980, 1), (0x2C7D, 2981, 1), (0x2D6F, 2982, 1), (0x2E9F, 2983, 1), (0x2EF3, 2984, 1), (0x2F00, 2985, 1), (0x2F01, 2986, 1), (0x2F02, 2987, 1),
+    (0x2F03, 2988, 1), (0x2F04, 2989, 1), (0x2F05, 2990, 1), (0x2F06, 2991, 1), (0x2F07, 2992, 1), (0x2F08, 2993, 1), (0x2F09, 2994, 1), (0x2F0A, 2995, 1),
+    (0x2F0B, 2996, 1), (0x2F0C, 2997, 1), (0x2F0D, 2998, 1), (0x2F0E, 2999, 1), (0x2F0F, 3000, 1), (0x2F10, 3001, 1), (0x2F11, 3002, 1), (0x2F12, 3003, 1),
+    (0x2F13, 3004, 1), (0x2F14, 3005, 1), (0x2F15, 3006, 1), (0x2F16, 3007, 1), (0x2F17, 3008, 1), (0x2F18, 3009, 1), (0x2F19, 3010, 1), (0x2F1A, 3011, 1),
+    (0x2F1B, 3012, 1), (0x2F1C, 3013, 1), (0x2F1D, 3014, 1), (0x2F1E, 3015, 1), (0x2F1F, 3016, 1), (0x2F20, 3017, 1), (0x2F21, 3018, 1), (0x2F22, 3019, 1),
+    (0x2F23, 3020, 1), (0x2F24, 3021, 1), (0x2F25, 3022, 1), (0x2F26, 3023, 1), (0x2F27, 3024, 1), (0x2F28, 3025, 1), (0x2F29, 3026, 1), (0x2F2A, 3027, 1),
+    (0x2F2B, 3028, 1), (0x2F2C, 3029, 1), (0x2F2D, 3030, 1), (0x2F2E, 3031, 1), (0x2F2F, 3032, 1), (0x2F30, 3033, 1), (0x2F31, 3034, 1), (0x2F32, 3035, 1),
+    (0x2F33, 3036, 1), (0x2F34, 3037, 1), (0x2F35, 3038, 1), (0x2F36, 3039, 1), (0x2F37, 3040, 1), (0x2F38, 3041, 1), (0x2F39, 3042, 1), (0x2F3A, 3043, 1),
+    (0x2F3B, 3044, 1), (0x2F3C, 3045, 1), (0x2F3D, 3046, 1), (0x2F3E, 3047, 1), (0x2F3F, 3048, 1), (0x2F40, 3049, 1), (0x2F41, 3050, 1), (0x2F42, 3051, 1),
+    (0x2F43, 3052, 1), (0x2F44, 3053, 1), (0x2F45, 3054, 1), (0x2F46, 3055, 1), (0x2F47, 3056, 1), (0x2F48, 3057, 1), (0x2F49, 3058, 1), (0x2F4A, 3059, 1),
+    (0x2F4B, 3060, 1), (0x2F4C, 3061, 1), (0x2F4D, 3062, 1), (0x2F4E, 3063, 1), (0x2F4F, 3064, 1), (0x2F50, 3065, 1), (0x2F51, 3066, 1), (0x2F52, 3067, 1),
+    (0x2F53, 3068, 1), (0x2F54, 3069, 1), (0x2F55, 3070, 1), (0x2F56, 3071, 1), (0x2F57, 3072, 1), (0x2F58, 3073, 1), (0x2F59, 3074, 1), (0x2F5A, 3075, 1),
+    (0x2F5B, 3076, 1), (0x2F5C, 3077, 1), (0x2F5D, 3078, 1), (0x2F5E, 3079, 1), (0x2F5F, 3080, 1), (0x2F60, 3081, 1), (0x2F61, 3082, 1), (0x2F62, 3083, 1),
+    (0x2F63, 3084, 1), (0x2F64, 3085, 1), (0x2F65, 3086, 1), (0x2F66, 3087, 1), (0x2F67, 3088, 1), (0x2F68, 3089, 1), (0x2F69, 3090, 1), (0x2F6A, 3091, 1),
+    (0x2F6B, 3092, 1), (0x2F6C, 3093, 1), (0x2F6D, 3094, 1), (0x2F6E, 3095, 1), (0x2F6F, 3096, 1), (0x2F70, 3097, 1), (0x2F71, 3098, 1), (0x2F72, 3099, 1),
+    (0x2F73, 3100, 1), (0x2F74, 3101, 1), (0x2F75, 3102, 1), (0x2F76, 3103, 1), (0x2F77, 3104, 1), (0x2F78, 3105, 1), (0x2F79, 3106, 1), (0x2F7A, 3107, 1),
+    (0x2F7B, 3108, 1), (0x2F7C, 3109, 1), (0x2F7D, 3110, 1), (0x2F7E, 3111, 1), (0x2F7F, 3112, 1), (0x2F80, 3113, 1), (0x2F81, 3114, 1), (0x2F82, 3115, 1),
+    (0x2F83, 3116, 1), (0x2F84, 3117, 1), (0x2F85, 3118, 1), (0x2F86, 3119, 1), (0x2F87, 3120, 1), (0x2F88, 3121, 1), (0x2F89, 3122, 1), (0x2F8A, 3123, 1),
+    (0x2F8B, 3124, 1), (0x2F8C, 3125, 1), (0x2F8D, 3126, 1), (0x2F8E, 3127, 1), (0x2F8F, 3128, 1), (0x2F90, 3129, 1), (0x2F91, 3130, 1), (0x2F92, 3131, 1),
+    (0x2F93, 3132, 1), (0x2F94, 3133, 1), (0x2F95, 3134, 1), (0x2F96, 3135, 1), (0x2F97, 3136, 1), (0x2F98, 3137, 1), (0x2F99, 3138, 1), (0x2F9A, 3139, 1),
+    (0x2F9B, 3140, 1), (0x2F9C, 3141, 1), (0x2F9D, 3142, 1), (0x2F9E, 3143, 1), (0x2F9F, 3144, 1), (0x2FA0, 3145, 1), (0x2FA1, 3146, 1), (0x2FA2, 3147, 1),
+    (0x2FA3, 3148, 1), (0x2FA4, 3149, 1), (0x2FA5, 3150, 1), (0x2FA6, 3151, 1), (0x2FA7, 3152, 1), (0x2FA8, 3153, 1), (0x2FA9, 3154, 1), (0x2FAA, 3155, 1),
+    (0x2FAB, 3156, 1), (0x2FAC, 3157, 1), (0x2FAD, 3158, 1), (0x2FAE, 3159, 1), (0x2FAF, 3160, 1), (0x2FB0, 3161, 1), (0x2FB1, 3162, 1), (0x2FB2, 3163, 1),
+    (0x2FB3, 3164, 1), (0x2FB4, 3165, 1), (0x2FB5, 3166, 1), (0x2FB6, 3167, 1), (0x2FB7, 3168, 1), (0x2FB8, 3169, 1), (0x2FB9, 3170, 1), (0x2FBA, 3171, 1),
+    (0x2FBB, 3172, 1), (0x2FBC, 3173, 1), (0x2FBD, 3174, 1), (0x2FBE, 3175, 1), (0x2FBF, 3176, 1), (0x2FC0, 3177, 1), (0x2FC1, 3178, 1), (0x2FC2, 3179, 1),
+    (0x2FC3, 3180, 1), (0x2FC4, 3181, 1), (0x2FC5, 3182, 1), (0x2FC6, 3183, 1), (0x2FC7, 3184, 1), (0x2FC8, 3185, 1), (0x2FC9, 3186, 1), (0x2FCA, 3187, 1),
+    (0x2FCB, 3188, 1), (0x2FCC, 3189, 1), (0x2FCD, 3190, 1), (0x2FCE, 3191, 1), (0x2FCF, 3192, 1), (0x2FD0, 3193, 1), (0x2FD1, 3194, 1), (0x2FD2, 3195, 1),
+    (0x2FD3, 3196, 1), (0x2FD4, 3197, 1), (0x2FD5, 3198, 1), (0x3000, 3199, 1), (0x3036, 3200, 1), (0x3038, 3201, 1), (0x3039, 3202, 1), (0x303A, 3203, 1),
+    (0x304C, 3204, 2), (0x304E, 3206, 2), (0x3050, 3208, 2), (0x3052, 3210, 2), (0x3054, 3212, 2), (0x3056, 3214, 2), (0x3058, 3216, 2), (0x305A, 3218, 2),
+    (0x305C, 3220, 2), (0x305E, 3222, 2), (0x3060, 3224, 2), (0x3062, 3226, 2), (0x3065, 3228, 2), (0x3067, 3230, 2), (0x3069, 3232, 2), (0x3070, 3234, 2),
+    (0x3071, 3236, 2), (0x3073, 3238, 2), (0x3074, 3240, 2), (0x3076, 3242, 2), (0x3077, 3244, 2), (0x3079, 3246, 2), (0x307A, 3248, 2), (0x307C, 3250, 2),
+    (0x307D, 3252, 2), (0x3094, 3254, 2), (0x309B, 3256, 2), (0x309C, 3258, 2), (0x309E, 3260, 2), (0x309F, 3262, 2), (0x30AC, 3264, 2), (0x30AE, 3266, 2),
+    (0x30B0, 3268, 2), (0x30B2, 3270, 2), (0x30B4, 3272, 2), (0x30B6, 3274, 2), (0x30B8, 3276, 2), (0x30BA, 3278, 2), (0x30BC, 3280, 2), (0x30BE, 3282, 2),
+    (0x30C0, 3284, 2), (0x30C2, 3286, 2), (0x30C5, 3288, 2), (0x30C7, 3290, 2), (0x30C9, 3292, 2), (0x30D0, 3294, 2), (0x30D1, 3296, 2), (0x30D3, 3298, 2),
+    (0x30D4, 3300, 2), (0x30D6, 3302, 2), (0x30D7, 3304, 2), (0x30D9, 3306, 2), (0x30DA, 3308, 2), (0x30DC, 3310, 2), (0x30DD, 3312, 2), (0x30F4, 3314, 2),
+    (0x30F7, 3316, 2), (0x30F8, 3318, 2), (0x30F9, 3320, 2), (0x30FA, 3322, 2), (0x30FE, 3324, 2), (0x30FF, 3326, 2), (0x3131, 3328, 1), (0x3132, 3329, 1),
+    (0x3133, 3330, 1), (0x3134, 3331, 1), (0x3135, 3332, 1), (0x3136, 3333, 1), (0x3137, 3334, 1), (0x3138, 3335, 1), (0x3139, 3336, 1), (0x313A, 3337, 1),
+    (0x313B, 3338, 1), (0x313C, 3339, 1), (0x313D, 3340, 1), (0x313E, 3341, 1), (0x313F, 3342, 1), (0x3140, 3343, 1), (0x3141, 3344, 1), (0x3142, 3345, 1),
+    (0x3143, 3346, 1), (0x3144, 3347, 1), (0x3145, 3348, 1), (0x3146, 3349, 1), (0x3147, 3350, 1), (0x3148, 3351, 1), (0x3149, 3352, 1), (0x314A, 3353, 1),
+    (0x314B, 3354, 1), (0x314C, 3355, 1), (0x314D, 3356, 1), (0x314E, 3357, 1), (0x314F, 3358, 1), (0x3150, 3359, 1), (0x3151, 3360, 1), (0x3152, 3361, 1),
+    (0x3153, 3362, 1), (0x3154, 3363, 1), (0x3155, 3364, 1), (0x3156, 3365, 1), (0x3157, 3366, 1), (0x3158, 3367, 1), (0x3159, 3368, 1), (0x315A, 3369, 1),
+    (0x315B, 3370, 1), (0x315C, 3371, 1), (0x315D, 3372, 1), (0x315E, 3373, 1), (0x315F, 3374, 1), (0x3160, 3375, 1), (0x3161, 3376, 1), (0x3162, 3377, 1),
+    (0x3163, 3378, 1), (0x3164, 3379, 1), (0x3165, 3380, 1), (0x3166, 3381, 1), (0x3167, 3382, 1), (0x3168, 3383, 1), (0x3169, 3384, 1), (0x316A, 3385, 1),
+    (0x316B, 3386, 1), (0x316C, 3387, 1), (0x316D, 3388, 1), (0x316E, 3389, 1), (0x316F, 3390, 1), (0x3170, 3391, 1), (0x3171, 3392, 1), (0x3172, 3393, 1),
+    (0x3173, 3394, 1), (0x3174, 3395, 1), (0x3175, 3396, 1), (0x3176, 3397, 1), (0x3177, 3398, 1), (0x3178, 3399, 1), (0x3179, 3400, 1), (0x317A, 3401, 1),
+    (0x317B, 3402, 1), (0x317C, 3403, 1), (0x317D, 3404, 1), (0x317E, 3405, 1), (0x317F, 3406, 1), (0x3180, 3407, 1), (0x3181, 3408, 1), (0x3182, 3409, 1),
+    (0x3183, 3410, 1), (0x3184, 3411, 1), (0x3185, 3412, 1), (0x3186, 3413, 1), (0x3187, 3414, 1), (0x3188, 3415, 1), (0x3189, 3416, 1), (0x318A, 3417, 1),
+    (0x318B, 3418, 1), (0x318C, 3419, 1), (0x318D, 3420, 1), (0x318E, 3421, 1), (0x3192, 3422, 1), (0x3193, 3423, 1), (0x3194, 3424, 1), (0x3195, 3425, 1),
+    (0x3196, 3426, 1), (0x3197, 3427, 1), (0x3198, 3428, 1), (0x3199, 3429, 1), (0x319A, 3430, 1), (0x319B, 3431, 1), (0x319C, 3432, 1), (0x319D, 3433, 1),
+    (0x319E, 3434, 1), (0x319F, 3435, 1), (0x3200, 3436, 3), (0x3201, 3439, 3), (0x3202, 3442, 3), (0x3203, 3445, 3), (0x3204, 3448, 3), (0x3205, 3451, 3),
+    (0x3206, 3454, 3), (0x3207, 3457, 3), (0x3208, 3460, 3), (0x3209, 3463, 3), (0x320A, 3466, 3), (0x320B, 3469, 3), (0x320C, 3472, 3), (0x320D, 3475, 3),
+    (0x320E, 3478, 4), (0x320F, 3482, 4), (0x3210, 3486, 4), (0x3211, 3490, 4), (0x3212, 3494, 4), (0x3213, 3498, 4), (0x3214, 3502, 4), (0x3215, 3506, 4),
+    (0x3216, 3510, 4), (0x3217, 3514, 4), (0x3218, 3518, 4), (0x3219, 3522, 4), (0x321A, 3526, 4), (0x321B, 3530, 4), (0x321C, 3534, 4), (0x321D, 3538, 7),
+    (0x321E, 3545, 6), (0x3220, 3551, 3), (0x3221, 3554, 3), (0x3222, 3557, 3), (0x3223, 3560, 3), (0x3224, 3563, 3), (0x3225, 3566, 3), (0x3226, 3569, 3),
+    (0x3227, 3572, 3), (0x3228, 3575, 3), (0x3229, 3578, 3), (0x322A, 3581, 3), (0x322B, 3584, 3), (0x322C, 3587, 3), (0x322D, 3590, 3), (0x322E, 3593, 3),
+    (0x322F, 3596, 3), (0x3230, 3599, 3), (0x3231, 3602, 3), (0x3232, 3605, 3), (0x3233, 3608, 3), (0x3234, 3611, 3), (0x3235, 3614, 3), (0x3236, 3617, 3),
+    (0x3237, 3620, 3), (0x3238, 3623, 3), (0x3239, 3626, 3), (0x323A, 3629, 3), (0x323B, 3632, 3), (0x323C, 3635, 3), (0x323D, 3638, 3), (0x323E, 3641, 3),
+    (0x323F, 3644, 3), (0x3240, 3647, 3), (0x3241, 3650, 3), (0x3242, 3653, 3), (0x3243, 3656, 3), (0x3244, 3659, 1), (0x3245, 3660, 1), (0x3246, 3661, 1),
+    (0x3247, 3662, 1), (0x3250, 3663, 3), (0x3251, 3666, 2), (0x3252, 3668, 2), (0x3253, 3670, 2), (0x3254, 3672, 2), (0x3255, 3674, 2), (0x3256, 3676, 2),
+    (0x3257, 3678, 2), (0x3258, 3680, 2), (0x3259, 3682, 2), (0x325A, 3684, 2), (0x325B, 3686, 2), (0x325C, 3688, 2), (0x325D, 3690, 2), (0x325E, 3692, 2),
+    (0x325F, 3694, 2), (0x3260, 3696, 1), (0x3261, 3697, 1), (0x3262, 3698, 1), (0x3263, 3699, 1), (0x3264, 3700, 1), (0x3265, 3701, 1), (0x3266, 3702, 1),
+    (0x3267, 3703, 1), (0x3268, 3704, 1), (0x3269, 3705, 1), (0x326A, 3706, 1), (0x326B, 3707, 1), (0x326C, 3708, 1), (0x326D, 3709, 1), (0x326E, 3710, 2),
+    (0x326F, 3712, 2), (0x3270, 3714, 2), (0x3271, 3716, 2), (0x3272, 3718, 2), (0x3273, 3720, 2), (0x3274, 3722, 2), (0x3275, 3724, 2), (0x3276, 3726, 2),
+    (0x3277, 3728, 2), (0x3278, 3730, 2), (0x3279, 3732, 2), (0x327A, 3734, 2), (0x327B, 3736, 2), (0x327C, 3738, 5), (0x327D, 3743, 4), (0x327E, 3747, 2),
+    (0x3280, 3749, 1), (0x3281, 3750, 1), (0x3282, 3751, 1), (0x3283, 3752, 1), (0x3284, 3753, 1), (0x3285, 3754, 1), (0x3286, 3755, 1), (0x3287, 3756, 1),
+    (0x3288, 3757, 1), (0x3289, 3758, 1), (0x328A, 3759, 1), (0x328B, 3760, 1), (0x328C, 3761, 1), (0x328D, 3762, 1), (0x328E, 3763, 1), (0x328F, 3764, 1),
+    (0x3290, 3765, 1), (0x3291, 3766, 1), (0x3292, 3767, 1), (0x3293, 3768, 1), (0x3294, 3769, 1), (0x3295, 3770, 1), (0x3296, 3771, 1), (0x3297, 3772, 1),
+    (0x3298, 3773, 1), (0x3299, 3774, 1), (0x329A, 3775, 1), (0x329B, 3776, 1), (0x329C, 3777, 1), (0x329D, 3778, 1), (0x329E, 3779, 1), (0x329F, 3780, 1),
+    (0x32A0, 3781, 1), (0x32A1, 3782, 1), (0x32A2, 3783, 1), (0x32A3, 3784, 1), (0x32A4, 3785, 1), (0x32A5, 3786, 1), (0x32A6, 3787, 1), (0x32A7, 3788, 1),
+    (0x32A8, 3789, 1), (0x32A9, 3790, 1), (0x32AA, 3791, 1), (0x32AB, 3792, 1), (0x32AC, 3793, 1), (0x32AD, 3794, 1), (0x32AE, 3795, 1), (0x32AF, 3796, 1),
+    (0x32B0, 3797, 1), (0x32B1, 3798, 2), (0x32B2, 3800, 2), (0x32B3, 3802, 2), (0x32B4, 3804, 2), (0x32B5, 3806, 2), (0x32B6, 3808, 2), (0x32B7, 3810, 2),
+    (0x32B8, 3812, 2), (0x32B9, 3814, 2), (0x32BA, 3816, 2), (0x32BB, 3818, 2), (0x32BC, 3820, 2), (0x32BD, 3822, 2), (0x32BE, 3824, 2), (0x32BF, 3826, 2),
+    (0x32C0, 3828, 2), (0x32C1, 3830, 2), (0x32C2, 3832, 2), (0x32C3, 3834, 2), (0x32C4, 3836, 2), (0x32C5, 3838, 2), (0x32C6, 3840, 2), (0x32C7, 3842, 2),
+    (0x32C8, 3844, 2), (0x32C9, 3846, 3), (0x32CA, 3849, 3), (0x32CB, 3852, 3), (0x32CC, 3855, 2), (0x32CD, 3857, 3), (0x32CE, 3860, 2), (0x32CF, 3862, 3),
+    (0x32D0, 3865, 1), (0x32D1, 3866, 1), (0x32D2, 3867, 1), (0x32D3, 3868, 1), (0x32D4, 3869, 1), (0x32D5, 3870, 1), (0x32D6, 3871, 1), (0x32D7, 3872, 1),
+    (0x32D8, 3873, 1), (0x32D9, 3874, 1), (0x32DA, 3875, 1), (0x32DB, 3876, 1), (0x32DC, 3877, 1), (0x32DD, 3878, 1), (0x32DE, 3879, 1), (0x32DF, 3880, 1),
+    (0x32E0, 3881, 1), (0x32E1, 3882, 1), (0x32E2, 3883, 1), (0x32E3, 3884, 1), (0x32E4, 3885, 1), (0x32E5, 3886, 1), (0x32E6, 3887, 1), (0x32E7, 3888, 1),
+    (0x32E8, 3889, 1), (0x32E9, 3890, 1), (0x32EA, 3891, 1), (0x32EB, 3892, 1), (0x32EC, 3893, 1), (0x32ED, 3894, 1), (0x32EE, 3895, 1), (0x32EF, 3896, 1),
+    (0x32F0, 3897, 1), (0x32F1, 3898, 1), (0x32F2, 3899, 1), (0x32F3, 3900, 1), (0x32F4, 3901, 1), (0x32F5, 3902, 1), (0x32F6, 3903, 1), (0x32F7, 3904, 1),
+    (0x32F8, 3905, 1), (0x32F9, 3906, 1), (0x32FA, 3907, 1), (0x32FB, 3908, 1), (0x32FC, 3909, 1), (0x32FD, 3910, 1), (0x32FE, 3911, 1), (0x32FF, 3912, 2),
+    (0x3300, 3914, 5), (0x3301, 3919, 4), (0x3302, 3923, 5), (0x3303, 3928, 3), (0x3304, 3931, 5), (0x3305, 3936, 3), (0x3306, 3939, 3), (0x3307, 3942, 6),
+    (0x3308, 3948, 4), (0x3309, 3952, 3), (0x330A, 3955, 3), (0x330B, 3958, 3), (0x330C, 3961, 4), (0x330D, 3965, 4), (0x330E, 3969, 4), (0x330F, 3973, 4),
+    (0x3310, 3977, 4), (0x3311, 3981, 4), (0x3312, 3985, 4), (0x3313, 3989, 6), (0x3314, 3995, 2), (0x3315, 3997, 6), (0x3316, 4003, 6), (0x3317, 4009, 5),
+    (0x3318, 4014, 4), (0x3319, 4018, 6), (0x331A, 4024, 6), (0x331B, 4030, 4), (0x331C, 4034, 3), (0x331D, 4037, 3), (0x331E, 4040, 4), (0x331F, 4044, 4),
+    (0x3320, 4048, 5), (0x3321, 4053, 5), (0x3322, 4058, 3), (0x3323, 4061, 3), (0x3324, 4064, 4), (0x3325, 4068, 3), (0x3326, 4071, 3), (0x3327, 4074, 2),
+    (0x3328, 4076, 2), (0x3329, 4078, 3), (0x332A, 4081, 3), (0x332B, 4084, 6), (0x332C, 4090, 4), (0x332D, 4094, 5), (0x332E, 4099, 6), (0x332F, 4105, 4),
+    (0x3330, 4109, 3), (0x3331, 4112, 3), (0x3332, 4115, 6), (0x3333, 4121, 4), (0x3334, 4125, 6), (0x3335, 4131, 3), (0x3336, 4134, 5), (0x3337, 4139, 3),
+    (0x3338, 4142, 4), (0x3339, 4146, 3), (0x333A, 4149, 4), (0x333B, 4153, 5), (0x333C, 4158, 4), (0x333D, 4162, 5), (0x333E, 4167, 4), (0x333F, 4171, 2),
+    (0x3340, 4173, 5), (0x3341, 4178, 3), (0x3342, 4181, 3), (0x3343, 4184, 4), (0x3344, 4188, 3), (0x3345, 4191, 3), (0x3346, 4194, 3), (0x3347, 4197, 5),
+    (0x3348, 4202, 4), (0x3349, 4206, 2), (0x334A, 4208, 6), (0x334B, 4214, 3), (0x334C, 4217, 5), (0x334D, 4222, 4), (0x334E, 4226, 4), (0x334F, 4230, 3),
+    (0x3350, 4233, 3), (0x3351, 4236, 4), (0x3352, 4240, 2), (0x3353, 4242, 4), (0x3354, 4246, 5), (0x3355, 4251, 2), (0x3356, 4253, 6), (0x3357, 4259, 3),
+    (0x3358, 4262, 2), (0x3359, 4264, 2), (0x335A, 4266, 2), (0x335B, 4268, 2), (0x335C, 4270, 2), (0x335D, 4272, 2), (0x335E, 4274, 2), (0x335F, 4276, 2),
+    (0x3360, 4278, 2), (0x3361, 4280, 2), (0x3362, 4282, 3), (0x3363, 4285, 3), (0x3364, 4288, 3), (0x3365, 4291, 3), (0x3366, 4294, 3), (0x3367, 4297, 3),
+    (0x3368, 4300, 3), (0x3369, 4303, 3), (0x336A, 4306, 3), (0x336B, 4309, 3), (0x336C, 4312, 3), (0x336D, 4315, 3), (0x336E, 4318, 3), (0x336F, 4321, 3),
+    (0x3370, 4324, 3), (0x3371, 4327, 3), (0x3372, 4330, 2), (0x3373, 4332, 2), (0x3374, 4334, 3), (0x3375, 4337, 2), (0x3376, 4339, 2), (0x3377, 4341, 2),
+    (0x3378, 4343, 3), (0x3379, 4346, 3), (0x337A, 4349, 2), (0x337B, 4351, 2), (0x337C, 4353, 2), (0x337D, 4355, 2), (0x337E, 4357, 2), (0x337F, 4359, 4),
+    (0x3380, 4363, 2), (0x3381, 4365, 2), (0x3382, 4367, 2), (0x3383, 4369, 2), (0x3384, 4371, 2), (0x3385, 4373, 2), (0x3386, 4375, 2), (0x3387, 4377, 2),
+    (0x3388, 4379, 3), (0x3389, 4382, 4), (0x338A, 4386, 2), (0x338B, 4388, 2), (0x338C, 4390, 2), (0x338D, 4392, 2), (0x338E, 4394, 2), (0x338F, 4396, 2),
+    (0x3390, 4398, 2), (0x3391, 4400, 3), (0x3392, 4403, 3), (0x3393, 4406, 3), (0x3394, 4409, 3), (0x3395, 4412, 2), (0x3396, 4414, 2), (0x3397, 4416, 2),
+    (0x3398, 4418, 2), (0x3399, 4420, 2), (0x339A, 4422, 2), (0x339B, 4424, 2), (0x339C, 4426, 2), (0x339D, 4428, 2), (0x339E, 4430, 2), (0x339F, 4432, 3),
+    (0x33A0, 4435, 3), (0x33A1, 4438, 2), (0x33A2, 4440, 3), (0x33A3, 4443, 3), (0x33A4, 4446, 3), (0x33A5, 4449, 2), (0x33A6, 4451, 3), (0x33A7, 4454, 3),
+    (0x33A8, 4457, 4), (0x33A9, 4461, 2), (0x33AA, 4463, 3), (0x33AB, 4466, 3), (0x33AC, 4469, 3), (0x33AD, 4472, 3), (0x33AE, 4475, 5), (0x33AF, 4480, 6),
+    (0x33B0, 4486, 2), (0x33B1, 4488, 2), (0x33B2, 4490, 2), (0x33B3, 4492, 2), (0x33B4, 4494, 2), (0x33B5, 4496, 2), (0x33B6, 4498, 2), (0x33B7, 4500, 2),
+    (0x33B8, 4502, 2), (0x33B9, 4504, 2), (0x33BA, 4506, 2), (0x33BB, 4508, 2), (0x33BC, 4510, 2), (0x33BD, 4512, 2), (0x33BE, 4514, 2), (0x33BF, 4516, 2),
+    (0x33C0, 4518, 2), (0x33C1, 4520, 2), (0x33C2, 4522, 4), (0x33C3, 4526, 2), (0x33C4, 4528, 2), (0x33C5, 4530, 2), (0x33C6, 4532, 4), (0x33C7, 4536, 3),
+    (0x33C8, 4539, 2), (0x33C9, 4541, 2), (0x33CA, 4543, 2), (0x33CB, 4545, 2), (0x33CC, 4547, 2), (0x33CD, 4549, 2), (0x33CE, 4551, 2), (0x33CF, 4553, 2),
+    (0x33D0, 4555, 2), (0x33D1, 4557, 2), (0x33D2, 4559, 3), (0x33D3, 4562, 2), (0x33D4, 4564, 2), (0x33D5, 4566, 3), (0x33D6, 4569, 3), (0x33D7, 4572, 2),
+    (0x33D8, 4574, 4), (0x33D9, 4578, 3), (0x33DA, 4581, 2), (0x33DB, 4583, 2), (0x33DC, 4585, 2), (0x33DD, 4587, 2), (0x33DE, 4589, 3), (0x33DF, 4592, 3),
+    (0x33E0, 4595, 2), (0x33E1, 4597, 2), (0x33E2, 4599, 2), (0x33E3, 4601, 2), (0x33E4, 4603, 2), (0x33E5, 4605, 2), (0x33E6, 4607, 2), (0x33E7, 4609, 2),
+    (0x33E8, 4611, 2), (0x33E9, 4613, 3), (0x33EA, 4616, 3), (0x33EB, 4619, 3), (0x33EC, 4622, 3), (0x33ED, 4625, 3), (0x33EE, 4628, 3), (0x33EF, 4631, 3),
+    (0x33F0, 4634, 3), (0x33F1, 4637, 3), (0x33F2, 4640, 3), (0x33F3, 4643, 3), (0x33F4, 4646, 3), (0x33F5, 4649, 3), (0x33F6, 4652, 3), (0x33F7, 4655, 3),
+    (0x33F8, 4658, 3), (0x33F9, 4661, 3), (0x33FA, 4664, 3), (0x33FB, 4667, 3), (0x33FC, 4670, 3), (0x33FD, 4673, 3), (0x33FE, 4676, 3), (0x33FF, 4679, 3),
+    (0xA69C, 4682, 1), (0xA69D, 4683, 1), (0xA770, 4684, 1), (0xA7F2, 4685, 1), (0xA7F3, 4686, 1), (0xA7F4, 4687, 1), (0xA7F8, 4688, 1), (0xA7F9, 4689, 1),
+    (0xAB5C, 4690, 1), (0xAB5D, 4691, 1), (0xAB5E, 4692, 1), (0xAB5F, 4693, 1), (0xAB69, 4694, 1), (0xF900, 4695, 1), (0xF901, 4696, 1), (0xF902, 4697, 1),
+    (0xF903, 4698, 1), (0xF904, 4699, 1), (0xF905, 4700, 1), (0xF906, 4701, 1), (0xF907, 4702, 1), (0xF908, 4703, 1), (0xF909, 4704, 1), (0xF90A, 4705, 1),
+    (0xF90B, 4706, 1), (0xF90C, 4707, 1), (0xF90D, 4708, 1), (0xF90E, 4709, 1), (0xF90F, 4710, 1), (0xF910, 4711, 1), (0xF911, 4712, 1), (0xF912, 4713, 1),
+    (0xF913, 4714, 1), (0xF914, 4715, 1), (0xF915, 4716, 1), (0xF916, 4717, 1), (0xF917, 4718, 1), (0xF918, 4719, 1), (0xF919, 4720, 1), (0xF91A, 4721, 1),
+    (0xF91B, 4722, 1), (0xF91C, 4723, 1), (0xF91D, 4724, 1), (0xF91E, 4725, 1), (0xF91F, 4726, 1), (0xF920, 4727, 1), (0xF921, 4728, 1), (0xF922, 4729, 1),
+    (0xF923, 4730, 1), (0xF924, 4731, 1), (0xF925, 4732, 1), (0xF926, 4733, 1), (0xF927, 4734, 1), (0xF928, 4735, 1), (0xF929, 4736, 1), (0xF92A, 4737, 1),
+    (0xF92B, 4738, 1), (0xF92C, 4739, 1), (0xF92D, 4740, 1), (0xF92E, 4741, 1), (0xF92F, 4742, 1), (0xF930, 4743, 1), (0xF931, 4744, 1), (0xF932, 4745, 1),
+    (0xF933, 4746, 1), (0xF934, 4747, 1), (0xF935, 4748, 1), (0xF936, 4749, 1), (0xF937, 4750, 1), (0xF938, 4751, 1), (0xF939, 4752, 1), (0xF93A, 4753, 1),
+    (0xF93B, 4754, 1), (0xF93C, 4755, 1), (0xF93D, 4756, 1), (0xF93E, 4757, 1), (0xF93F, 4758, 1), (0xF940, 4759, 1), (0xF941, 4760, 1), (0xF942, 4761, 1),
+    (0xF943, 4762, 1), (0xF944, 4763, 1), (0xF945, 4764, 1), (0xF946, 4765, 1), (0xF947, 4766, 1), (0xF948, 4767, 1), (0xF949, 4768, 1), (0xF94A, 4769, 1),
+    (0xF94B, 4770, 1), (0xF94C, 4771, 1), (0xF94D, 4772, 1), (0xF94E, 4773, 1), (0xF94F, 4774, 1), (0xF950, 4775, 1), (0xF951, 4776, 1), (0xF952, 4777, 1),
+    (0xF953, 4778, 1), (0xF954, 4779, 1), (0xF955, 4780, 1), (0xF956, 4781, 1), (0xF957, 4782, 1), (0xF958, 4783, 1), (0xF959, 4784, 1), (0xF95A, 4785, 1),
+    (0xF95B, 4786, 1), (0xF95C, 4787, 1), (0xF95D, 4788, 1), (0xF95E, 4789, 1), (0xF95F, 4790, 1), (0xF960, 4791, 1), (0xF961, 4792, 1), (0xF962, 4793, 1),
+    (0xF963, 4794, 1), (0xF964, 4795, 1), (0xF965, 4796, 1), (0xF966, 4797, 1), (0xF967, 4798, 1), (0xF968, 4799, 1), (0xF969, 4800, 1), (0xF96A, 4801, 1),
+    (0xF96B, 4802, 1), (0xF96C, 4803, 1), (0xF96D, 4804, 1), (0xF96E, 4805, 1), (0xF96F, 4806, 1), (0xF970, 4807, 1), (0xF971, 4808, 1), (0xF972, 4809, 1),
+    (0xF973, 4810, 1), (0xF974, 4811, 1), (0xF975, 4812, 1), (0xF976, 4813, 1), (0xF977, 4814, 1), (0xF978, 4815, 1), (0xF979, 4816, 1), (0xF97A, 4817, 1),
+    (0xF97B, 4818, 1), (0xF97C, 4819, 1), (0xF97D, 4820, 1), (0xF97E, 4821, 1), (0xF97F, 4822, 1), (0xF980, 4823, 1), (0xF981, 4824, 1), (0xF982, 4825, 1),
+    (0xF983, 4826, 1), (0xF984, 4827, 1), (0xF985, 4828, 1), (0xF986, 4829, 1), (0xF987, 4830, 1), (0xF988, 4831, 1), (0xF989, 4832, 1), (0xF98A, 4833, 1),
+    (0xF98B, 4834, 1), (0xF98C, 4835, 1), (0xF98D, 4836, 1), (0xF98E, 4837, 1), (0xF98F, 4838, 1), (0xF990, 4839, 1), (0xF991, 4840, 1), (0xF992, 4841, 1),
+    (0xF993, 4842, 1), (0xF994, 4843, 1), (0xF995, 4844, 1), (0xF996, 4845, 1), (0xF997, 4846, 1), (0xF998, 4847, 1), (0xF999, 4848, 1), (0xF99A, 4849, 1),
+    (0xF99B, 4850, 1), (0xF99C, 4851, 1), (0xF99D, 4852, 1), (0xF99E, 4853, 1), (0xF99F, 4854, 1), (0xF9A0, 4855, 1), (0xF9A1, 4856, 1), (0xF9A2, 4857, 1),
+    (0xF9A3, 4858, 1), (0xF9A4, 4859, 1), (0xF9A5, 4860, 1), (0xF9A6, 4861, 1), (0xF9A7, 4862, 1), (0xF9A8, 4863, 1), (0xF9A9, 4864, 1), (0xF9AA, 4865, 1),
+    (0xF9AB, 4866, 1), (0xF9AC, 4867, 1), (0xF9AD, 4868, 1), (0xF9AE, 4869, 1), (0xF9AF, 4870, 1), (0xF9B0, 4871, 1), (0xF9B1, 4872, 1), (0xF9B2, 4873, 1),
+    (0xF9B3, 4874, 1), (0xF9B4, 4875, 1), (0xF9B5, 4876, 1), (0xF9B6, 4877, 1), (0xF9B7, 4878, 1), (0xF9B8, 4879, 1), (0xF9B9, 4880, 1), (0xF9BA, 4881, 1),
+    (0xF9BB, 4882, 1), (0xF9BC, 4883, 1), (0xF9BD, 4884, 1), (0xF9BE, 4885, 1), (0xF9BF, 4886, 1), (0xF9C0, 4887, 1), (0xF9C1, 4888, 1), (0xF9C2, 4889, 1),
+    (0xF9C3, 4890, 1), (0xF9C4, 4891, 1), (0xF9C5, 4892, 1), (0xF9C6, 4893, 1), (0xF9C7, 4894, 1), (0xF9C8, 4895, 1), (0xF9C9, 4896, 1), (0xF9CA, 4897, 1),
+    (0xF9CB, 4898, 1), (0xF9CC, 4899, 1), (0xF9CD, 4900, 1), (0xF9CE, 4901, 1), (0xF9CF, 4902, 1), (0xF9D0, 4903, 1), (0xF9D1, 4904, 1), (0xF9D2, 4905, 1),
+    (0xF9D3, 4906, 1), (0xF9D4, 4907, 1), (0xF9D5, 4908, 1), (0xF9D6, 4909, 1), (0xF9D7, 4910, 1), (0xF9D8, 4911, 1), (0xF9D9, 4912, 1), (0xF9DA, 4913, 1),
+    (0xF9DB, 4914, 1), (0xF9DC, 4915, 1), (0xF9DD, 4916, 1), (0xF9DE, 4917, 1), (0xF9DF, 4918, 1), (0xF9E0, 4919, 1), (0xF9E1, 4920, 1), (0xF9E2, 4921, 1),
+    (0xF9E3, 4922, 1), (0xF9E4, 4923, 1), (0xF9E5, 4924, 1), (0xF9E6, 4925, 1), (0xF9E7, 4926, 1), (0xF9E8, 4927, 1), (0xF9E9, 4928, 1), (0xF9EA, 4929, 1),
+    (0xF9EB, 4930, 1), (0xF9EC, 4931, 1), (0xF9ED, 4932, 1), (0xF9EE, 4933, 1), (0xF9EF, 4934, 1), (0xF9F0, 4935, 1), (0xF9F1, 4936, 1), (0xF9F2, 4937, 1),
+    (0xF9F3, 4938, 1), (0xF9F4, 4939, 1), (0xF9F5, 4940, 1), (0xF9F6, 4941, 1), (0xF9F7, 4942, 1), (0xF9F8, 4943, 1), (0xF9F9, 4944, 1), (0xF9FA, 4945, 1),
+    (0xF9FB, 4946, 1), (0xF9FC, 4947, 1), (0xF9FD, 4948, 1), (0xF9FE, 4949, 1), (0xF9FF, 4950, 1), (0xFA00, 4951, 1), (0xFA01, 4952, 1), (0xFA02, 4953, 1),
+    (0xFA03, 4954, 1), (0xFA04, 4955, 1), (0xFA05, 4956, 1), (0xFA06, 4957, 1), (0xFA07, 4958, 1), (0xFA08, 4959, 1), (0xFA09, 4960, 1), (0xFA0A, 4961, 1),
+    (0xFA0B, 4962, 1), (0xFA0C, 4963, 1), (0xFA0D, 4964, 1), (0xFA10, 4965, 1), (0xFA12, 4966, 1), (0xFA15, 4967, 1), (0xFA16, 4968, 1), (0xFA17, 4969, 1),
+    (0xFA18, 4970, 1), (0xFA19, 4971, 1), (0xFA1A, 4972, 1), (0xFA1B, 4973, 1), (0xFA1C, 4974, 1), (0xFA1D, 4975, 1), (0xFA1E, 4976, 1), (0xFA20, 4977, 1),
+    (0xFA22, 4978, 1), (0xFA25, 4979, 1), (0xFA26, 4980, 1), (0xFA2A, 4981, 1), (0xFA2B, 4982, 1), (0xFA2C, 4983, 1), (0xFA2D, 4984, 1), (0xFA2E, 4985, 1),
+    (0xFA2F, 4986, 1), (0xFA30, 4987, 1), (0xFA31, 4988, 1), (0xFA32, 4989, 1), (0xFA33, 4990, 1), (0xFA34, 4991, 1), (0xFA35, 4992, 1), (0xFA36, 4993, 1),
+    (0xFA37, 4994, 1), (0xFA38, 4995, 1), (0xFA39, 4996, 1), (0xFA3A, 4997, 1), (0xFA3B, 4998, 1), (0xFA3C, 4999, 1), (0xFA3D, 5000, 1), (0xFA3E, 5001, 1),
+    (0xFA3F, 5002, 1), (0xFA40, 5003, 1), (0xFA41, 5004, 1), (0xFA42, 5005, 1), (0xFA43, 5006, 1), (0xFA44, 5007, 1), (0xFA45, 5008, 1), (0xFA46, 5009, 1),
+    (0xFA47, 5010, 1), (0xFA48, 5011, 1), (0xFA49, 5012, 1), (0xFA4A, 5013, 1), (0xFA4B, 5014, 1), (0xFA4C, 5015, 1), (0xFA4D, 5016, 1), (0xFA4E, 5017, 1),
+    (0xFA4F, 5018, 1), (0xFA50, 5019, 1), (0xFA51, 5020, 1), (0xFA52, 5021, 1), (0xFA53, 5022, 1), (0xFA54, 5023, 1), (0xFA55, 5024, 1), (0xFA56, 5025, 1),
+    (0xFA57, 5026, 1), (0xFA58, 5027, 1), (0xFA59, 5028, 1), (0xFA5A, 5029, 1), (0xFA5B, 5030, 1), (0xFA5C, 5031, 1), (0xFA5D, 5032, 1), (0xFA5E, 5033, 1),
+    (0xFA5F, 5034, 1), (0xFA60, 5035, 1), (0xFA61, 5036, 1), (0xFA62, 5037, 1), (0xFA63, 5038, 1), (0xFA64, 5039, 1), (0xFA65, 5040, 1), (0xFA66, 5041, 1),
+    (0xFA67, 5042, 1), (0xFA68, 5043, 1), (0xFA69, 5044, 1), (0xFA6A, 5045, 1), (0xFA6B, 5046, 1), (0xFA6C, 5047, 1), (0xFA6D, 5048, 1), (0xFA70, 5049, 1),
+    (0xFA71, 5050, 1), (0xFA72, 5051, 1), (0xFA73, 5052, 1), (0xFA74, 5053, 1), (0xFA75, 5054, 1), (0xFA76, 5055, 1), (0xFA77, 5056, 1), (0xFA78, 5057, 1),
+    (0xFA79, 5058, 1), (0xFA7A, 5059, 1), (0xFA7B, 5060, 1), (0xFA7C, 5061, 1), (0xFA7D, 5062, 1), (0xFA7E, 5063, 1), (0xFA7F, 5064, 1), (0xFA80, 5065, 1),
+    (0xFA81, 5066, 1), (0xFA82, 5067, 1), (0xFA83, 5068, 1), (0xFA84, 5069, 1), (0xFA85, 5070, 1), (0xFA86, 5071, 1), (0xFA87, 5072, 1), (0xFA88, 5073, 1),
+    (0xFA89, 5074, 1), (0xFA8A, 5075, 1), (0xFA8B, 5076, 1), (0xFA8C, 5077, 1), (0xFA8D, 5078, 1), (0xFA8E, 5079, 1), (0xFA8F, 5080, 1), (0xFA90, 5081, 1),
+    (0xFA91, 5082, 1), (0xFA92, 5083, 1), (0xFA93, 5084, 1), (0xFA94, 5085, 1), (0xFA95, 5086, 1), (0xFA96, 5087, 1), (0xFA97, 5088, 1), (0xFA98, 5089, 1),
+    (0xFA99, 5090, 1), (0xFA9A, 5091, 1), (0xFA9B, 5092, 1), (0xFA9C, 5093, 1), (0xFA9D, 5094, 1), (0xFA9E, 5095, 1), (0xFA9F, 5096, 1), (0xFAA0, 5097, 1),
+    (0xFAA1, 5098, 1), (0xFAA2, 5099, 1), (0xFAA3, 5100, 1), (0xFAA4, 5101, 1), (0xFAA5, 5102, 1), (0xFAA6, 5103, 1), (0xFAA7, 5104, 1), (0xFAA8, 5105, 1),
+    (0xFAA9, 5106, 1), (0xFAAA, 5107, 1), (0xFAAB, 5108, 1), (0xFAAC, 5109, 1), (0xFAAD, 5110, 1), (0xFAAE, 5111, 1), (0xFAAF, 5112, 1), (0xFAB0, 5113, 1),
+    (0xFAB1, 5114, 1), (0xFAB2, 5115, 1), (0xFAB3, 5116, 1), (0xFAB4, 5117, 1), (0xFAB5, 5118, 1), (0xFAB6, 5119, 1), (0xFAB7, 5120, 1), (0xFAB8, 5121, 1),
+    (0xFAB9, 5122, 1), (0xFABA, 5123, 1), (0xFABB, 5124, 1), (0xFABC, 5125, 1), (0xFABD, 5126, 1), (0xFABE, 5127, 1), (0xFABF, 5128, 1), (0xFAC0, 5129, 1),
+    (0xFAC1, 5130, 1), (0xFAC2, 5131, 1), (0xFAC3, 5132, 1), (0xFAC4, 5133, 1), (0xFAC5, 5134, 1), (0xFAC6, 5135, 1), (0xFAC7, 5136, 1), (0xFAC8, 5137, 1),
+    (0xFAC9, 5138, 1), (0xFACA, 5139, 1), (0xFACB, 5140, 1), (0xFACC, 5141, 1), (0xFACD, 5142, 1), (0xFACE, 5143, 1), (0xFACF, 5144, 1), (0xFAD0, 5145, 1),
+    (0xFAD1, 5146, 1), (0xFAD2, 5147, 1), (0xFAD3, 5148, 1), (0xFAD4, 5149, 1), (0xFAD5, 5150, 1), (0xFAD6, 5151, 1), (0xFAD7, 5152, 1), (0xFAD8, 5153, 1),
+    (0xFAD9, 5154, 1), (0xFB00, 5155, 2), (0xFB01, 5157, 2), (0xFB02, 5159, 2), (0xFB03, 5161, 3), (0xFB04, 5164, 3), (0xFB05, 5167, 2), (0xFB06, 5169, 2),
+    (0xFB13, 5171, 2), (0xFB14, 5173, 2), (0xFB15, 5175, 2), (0xFB16, 5177, 2), (0xFB17, 5179, 2), (0xFB1D, 5181, 2), (0xFB1F, 5183, 2), (0xFB20, 5185, 1),
+    (0xFB21, 5186, 1), (0xFB22, 5187, 1), (0xFB23, 5188, 1), (0xFB24, 5189, 1), (0xFB25, 5190, 1), (0xFB26, 5191, 1), (0xFB27, 5192, 1), (0xFB28, 5193, 1),
+    (0xFB29, 5194, 1), (0xFB2A, 5195, 2), (0xFB2B, 5197, 2), (0xFB2C, 5199, 3), (0xFB2D, 5202, 3), (0xFB2E, 5205, 2), (0xFB2F, 5207, 2), (0xFB30, 5209, 2),
+    (0xFB31, 5211, 2), (0xFB32, 5213, 2), (0xFB33, 5215, 2), (0xFB34, 5217, 2), (0xFB35, 5219, 2), (0xFB36, 5221, 2), (0xFB38, 5223, 2), (0xFB39, 5225, 2),
+    (0xFB3A, 5227, 2), (0xFB3B, 5229, 2), (0xFB3C, 5231, 2), (0xFB3E, 5233, 2), (0xFB40, 5235, 2), (0xFB41, 5237, 2), (0xFB43, 5239, 2), (0xFB44, 5241, 2),
+    (0xFB46, 5243, 2), (0xFB47, 5245, 2), (0xFB48, 5247, 2), (0xFB49, 5249, 2), (0xFB4A, 5251, 2), (0xFB4B, 5253, 2), (0xFB4C, 5255, 2), (0xFB4D, 5257, 2),
+    (0xFB4E, 5259, 2), (0xFB4F, 5261, 2), (0xFB50, 5263, 1), (0xFB51, 5264, 1), (0xFB52, 5265, 1), (0xFB53, 5266, 1), (0xFB54, 5267, 1), (0xFB55, 5268, 1),
+    (0xFB56, 5269, 1), (0xFB57, 5270, 1), (0xFB58, 5271, 1), (0xFB59, 5272, 1), (0xFB5A, 5273, 1), (0xFB5B, 5274, 1), (0xFB5C, 5275, 1), (0xFB5D, 5276, 1),
+    (0xFB5E, 5277, 1), (0xFB5F, 5278, 1), (0xFB60, 5279, 1), (0xFB61, 5280, 1), (0xFB62, 5281, 1), (0xFB63, 5282, 1), (0xFB64, 5283, 1), (0xFB65, 5284, 1),
+    (0xFB66, 5285, 1), (0xFB67, 5286, 1), (0xFB68, 5287, 1), (0xFB69, 5288, 1), (0xFB6A, 5289, 1), (0xFB6B, 5290, 1), (0xFB6C, 5291, 1), (0xFB6D, 5292, 1),
+    (0xFB6E, 5293, 1), (0xFB6F, 5294, 1), (0xFB70, 5295, 1), (0xFB71, 5296, 1), (0xFB72, 5297, 1), (0xFB73, 5298, 1), (0xFB74, 5299, 1), (0xFB75, 5300, 1),
+    (0xFB76, 5301, 1), (0xFB77, 5302, 1), (0xFB78, 5303, 1), (0xFB79, 5304, 1), (0xFB7A, 5305, 1), (0xFB7B, 5306, 1), (0xFB7C, 5307, 1), (0xFB7D, 5308, 1),
+    (0xFB7E, 5309, 1), (0xFB7F, 5310, 1), (0xFB80, 5311, 1), (0xFB81, 5312, 1), (0xFB82, 5313, 1), (0xFB83, 5314, 1), (0xFB84, 5315, 1), (0xFB85, 5316, 1),
+    (0xFB86, 5317, 1), (0xFB87, 5318, 1), (0xFB88, 5319, 1), (0xFB89, 5320, 1), (0xFB8A, 5321, 1), (0xFB8B, 5322, 1), (0xFB8C, 5323, 1), (0xFB8D, 5324, 1),
+    (0xFB8E, 5325, 1), (0xFB8F, 5326, 1), (0xFB90, 5327, 1), (0xFB91, 5328, 1), (0xFB92, 5329, 1), (0xFB93, 5330, 1), (0xFB94, 5331, 1), (0xFB95, 5332, 1),
+    (0xFB96, 5333, 1), (0xFB97, 5334, 1), (0xFB98, 5335, 1), (0xFB99, 5336, 1), (0xFB9A, 5337, 1), (0xFB9B, 5338, 1), (0xFB9C, 5339, 1), (0xFB9D, 5340, 1),
+    (0xFB9E, 5341, 1), (0xFB9F, 5342, 1), (0xFBA0, 5343, 1), (0xFBA1, 5344, 1), (0xFBA2, 5345, 1), (0xFBA3, 5346, 1), (0xFBA4, 5347, 2), (0xFBA5, 5349, 2),
+    (0xFBA6, 5351, 1), (0xFBA7, 5352, 1), (0xFBA8, 5353, 1), (0xFBA9, 5354, 1), (0xFBAA, 5355, 1), (0xFBAB, 5356, 1), (0xFBAC, 5357, 1), (0xFBAD, 5358, 1),
+    (0xFBAE, 5359, 1), (0xFBAF, 5360, 1), (0xFBB0, 5361, 2), (0xFBB1, 5363, 2), (0xFBD3, 5365, 1), (0xFBD4, 5366, 1), (0xFBD5, 5367, 1), (0xFBD6, 5368, 1),
+    (0xFBD7, 5369, 1), (0xFBD8, 5370, 1), (0xFBD9, 5371, 1), (0xFBDA, 5372, 1), (0xFBDB, 5373, 1), (0xFBDC, 5374, 1), (0xFBDD, 5375, 2), (0xFBDE, 5377, 1),
+    (0xFBDF, 5378, 1), (0xFBE0, 5379, 1), (0xFBE1, 5380, 1), (0xFBE2, 5381, 1), (0xFBE3, 5382, 1), (0xFBE4, 5383, 1), (0xFBE5, 5384, 1), (0xFBE6, 5385, 1),
+    (0xFBE7, 5386, 1), (0xFBE8, 5387, 1), (0xFBE9, 5388, 1), (0xFBEA, 5389, 3), (0xFBEB, 5392, 3), (0xFBEC, 5395, 3), (0xFBED, 5398, 3), (0xFBEE, 5401, 3),
+    (0xFBEF, 5404, 3), (0xFBF0, 5407, 3), (0xFBF1, 5410, 3), (0xFBF2, 5413, 3), (0xFBF3, 5416, 3), (0xFBF4, 5419, 3), (0xFBF5, 5422, 3), (0xFBF6, 5425, 3),
+    (0xFBF7, 5428, 3), (0xFBF8, 5431, 3), (0xFBF9, 5434, 3), (0xFBFA, 5437, 3), (0xFBFB, 5440, 3), (0xFBFC, 5443, 1), (0xFBFD, 5444, 1), (0xFBFE, 5445, 1),
+    (0xFBFF, 5446, 1), (0xFC00, 5447, 3), (0xFC01, 5450, 3), (0xFC02, 5453, 3), (0xFC03, 5456, 3), (0xFC04, 5459, 3), (0xFC05, 5462, 2), (0xFC06, 5464, 2),
+    (0xFC07, 5466, 2), (0xFC08, 5468, 2), (0xFC09, 5470, 2), (0xFC0A, 5472, 2), (0xFC0B, 5474, 2), (0xFC0C, 5476, 2), (0xFC0D, 5478, 2), (0xFC0E, 5480, 2),
+    (0xFC0F, 5482, 2), (0xFC10, 5484, 2), (0xFC11, 5486, 2), (0xFC12, 5488, 2), (0xFC13, 5490, 2), (0xFC14, 5492, 2), (0xFC15, 5494, 2), (0xFC16, 5496, 2),
+    (0xFC17, 5498, 2), (0xFC18, 5500, 2), (0xFC19, 5502, 2), (0xFC1A, 5504, 2), (0xFC1B, 5506, 2), (0xFC1C, 5508, 2), (0xFC1D, 5510, 2), (0xFC1E, 5512, 2),
+    (0xFC1F, 5514, 2), (0xFC20, 5516, 2), (0xFC21, 5518, 2), (0xFC22, 5520, 2), (0xFC23, 5522, 2), (0xFC24, 5524, 2), (0xFC25, 5526, 2), (0xFC26, 5528, 2),
+    (0xFC27, 5530, 2), (0xFC28, 5532, 2), (0xFC29, 5534, 2), (0xFC2A, 5536, 2), (0xFC2B, 5538, 2), (0xFC2C, 5540, 2), (0xFC2D, 5542, 2), (0xFC2E, 5544, 2),
+    (0xFC2F, 5546, 2), (0xFC30, 5548, 2), (0xFC31, 5550, 2), (0xFC32, 5552, 2), (0xFC33, 5554, 2), (0xFC34, 5556, 2), (0xFC35, 5558, 2), (0xFC36, 5560, 2),
+    (0xFC37, 5562, 2), (0xFC38, 5564, 2), (0xFC39, 5566, 2), (0xFC3A, 5568, 2), (0xFC3B, 5570, 2), (0xFC3C, 5572, 2), (0xFC3D, 5574, 2), (0xFC3E, 5576, 2),
+    (0xFC3F, 5578, 2), (0xFC40, 5580, 2), (0xFC41, 5582, 2), (0xFC42, 5584, 2), (0xFC43, 5586, 2), (0xFC44, 5588, 2), (0xFC45, 5590, 2), (0xFC46, 5592, 2),
+    (0xFC47, 5594, 2), (0xFC48, 5596, 2), (0xFC49, 5598, 2), (0xFC4A, 5600, 2), (0xFC4B, 5602, 2), (0xFC4C, 5604, 2), (0xFC4D, 5606, 2), (0xFC4E, 5608, 2),
+    (0xFC4F, 5610, 2), (0xFC50, 5612, 2), (0xFC51, 5614, 2), (0xFC52, 5616, 2), (0xFC53, 5618, 2), (0xFC54, 5620, 2), (0xFC55, 5622, 2), (0xFC56, 5624, 2),
+    (0xFC57, 5626, 2), (0xFC58, 5628, 2), (0xFC59, 5630, 2), (0xFC5A, 5632, 2), (0xFC5B, 5634, 2), (0xFC5C, 5636, 2), (0xFC5D, 5638, 2), (0xFC5E, 5640, 3),
+    (0xFC5F, 5643, 3), (0xFC60, 5646, 3), (0xFC61, 5649, 3), (0xFC62, 5652, 3), (0xFC63, 5655, 3), (0xFC64, 5658, 3), (0xFC65, 5661, 3), (0xFC66, 5664, 3),
+    (0xFC67, 5667, 3), (0xFC68, 5670, 3), (0xFC69, 5673, 3), (0xFC6A, 5676, 2), (0xFC6B, 5678, 2), (0xFC6C, 5680, 2), (0xFC6D, 5682, 2), (0xFC6E, 5684, 2),
+    (0xFC6F, 5686, 2), (0xFC70, 5688, 2), (0xFC71, 5690, 2), (0xFC72, 5692, 2), (0xFC73, 5694, 2), (0xFC74, 5696, 2), (0xFC75, 5698, 2), (0xFC76, 5700, 2),
+    (0xFC77, 5702, 2), (0xFC78, 5704, 2), (0xFC79, 5706, 2), (0xFC7A, 5708, 2), (0xFC7B, 5710, 2), (0xFC7C, 5712, 2), (0xFC7D, 5714, 2), (0xFC7E, 5716, 2),
+    (0xFC7F, 5718, 2), (0xFC80, 5720, 2), (0xFC81, 5722, 2), (0xFC82, 5724, 2), (0xFC83, 5726, 2), (0xFC84, 5728, 2), (0xFC85, 5730, 2), (0xFC86, 5732, 2),
+    (0xFC87, 5734, 2), (0xFC88, 5736, 2), (0xFC89, 5738, 2), (0xFC8A, 5740, 2), (0xFC8B, 5742, 2), (0xFC8C, 5744, 2), (0xFC8D, 5746, 2), (0xFC8E, 5748, 2),
+    (0xFC8F, 5750, 2), (0xFC90, 5752, 2), (0xFC91, 5754, 2), (0xFC92, 5756, 2), (0xFC93, 5758, 2), (0xFC94, 5760, 2), (0xFC95, 5762, 2), (0xFC96, 5764, 2),
+    (0xFC97, 5766, 3), (0xFC98, 5769, 3), (0xFC99, 5772, 3), (0xFC9A, 5775, 3), (0xFC9B, 5778, 3), (0xFC9C, 5781, 2), (0xFC9D, 5783, 2), (0xFC9E, 5785, 2),
+    (0xFC9F, 5787, 2), (0xFCA0, 5789, 2), (0xFCA1, 5791, 2), (0xFCA2, 5793, 2), (0xFCA3, 5795, 2), (0xFCA4, 5797, 2), (0xFCA5, 5799, 2), (0xFCA6, 5801, 2),
+    (0xFCA7, 5803, 2), (0xFCA8, 5805, 2), (0xFCA9, 5807, 2), (0xFCAA, 5809, 2), (0xFCAB, 5811, 2), (0xFCAC, 5813, 2), (0xFCAD, 5815, 2), (0xFCAE, 5817, 2),
+    (0xFCAF, 5819, 2), (0xFCB0, 5821, 2), (0xFCB1, 5823, 2), (0xFCB2, 5825, 2), (0xFCB3, 5827, 2), (0xFCB4, 5829, 2), (0xFCB5, 5831, 2), (0xFCB6, 5833, 2),
+    (0xFCB7, 5835, 2), (0xFCB8, 5837, 2), (0xFCB9, 5839, 2), (0xFCBA, 5841, 2), (0xFCBB, 5843, 2), (0xFCBC, 5845, 2), (0xFCBD, 5847, 2), (0xFCBE, 5849, 2),
+    (0xFCBF, 5851, 2), (0xFCC0, 5853, 2), (0xFCC1, 5855, 2), (0xFCC2, 5857, 2), (0xFCC3, 5859, 2), (0xFCC4, 5861, 2), (0xFCC5, 5863, 2), (0xFCC6, 5865, 2),
+    (0xFCC7, 5867, 2), (0xFCC8, 5869, 2), (0xFCC9, 5871, 2), (0xFCCA, 5873, 2), (0xFCCB, 5875, 2), (0xFCCC, 5877, 2), (0xFCCD, 5879, 2), (0xFCCE, 5881, 2),
+    (0xFCCF, 5883, 2), (0xFCD0, 5885, 2), (0xFCD1, 5887, 2), (0xFCD2, 5889, 2), (0xFCD3, 5891, 2), (0xFCD4, 5893, 2), (0xFCD5, 5895, 2), (0xFCD6, 5897, 2),
+    (0xFCD7, 5899, 2), (0xFCD8, 5901, 2), (0xFCD9, 5903, 2), (0xFCDA, 5905, 2), (0xFCDB, 5907, 2), (0xFCDC, 5909, 2), (0xFCDD, 5911, 2), (0xFCDE, 5913, 2),
+    (0xFCDF, 5915, 3), (0xFCE0, 5918, 3), (0xFCE1, 5921, 2), (0xFCE2, 5923, 2), (0xFCE3, 5925, 2), (0xFCE4, 5927, 2), (0xFCE5, 5929, 2), (0xFCE6, 5931, 2),
+    (0xFCE7, 5933, 2), (0xFCE8, 5935, 2), (0xFCE9, 5937, 2), (0xFCEA, 5939, 2), (0xFCEB, 5941, 2), (0xFCEC, 5943, 2), (0xFCED, 5945, 2), (0xFCEE, 5947, 2),
+    (0xFCEF, 5949, 2), (0xFCF0, 5951, 2), (0xFCF1, 5953, 2), (0xFCF2, 5955, 3), (0xFCF3, 5958, 3), (0xFCF4, 5961, 3), (0xFCF5, 5964, 2), (0xFCF6, 5966, 2),
+    (0xFCF7, 5968, 2), (0xFCF8, 5970, 2), (0xFCF9, 5972, 2), (0xFCFA, 5974, 2), (0xFCFB, 5976, 2), (0xFCFC, 5978, 2), (0xFCFD, 5980, 2), (0xFCFE, 5982, 2),
+    (0xFCFF, 5984, 2), (0xFD00, 5986, 2), (0xFD01, 5988, 2), (0xFD02, 5990, 2), (0xFD03, 5992, 2), (0xFD04, 5994, 2), (0xFD05, 5996, 2), (0xFD06, 5998, 2),
+    (0xFD07, 6000, 2), (0xFD08, 6002, 2), (0xFD09, 6004, 2), (0xFD0A, 6006, 2), (0xFD0B, 6008, 2), (0xFD0C, 6010, 2), (0xFD0D, 6012, 2), (0xFD0E, 6014, 2),
+    (0xFD0F, 6016, 2), (0xFD10, 6018, 2), (0xFD11, 6020, 2), (0xFD12, 6022, 2), (0xFD13, 6024, 2), (0xFD14, 6026, 2), (0xFD15, 6028, 2), (0xFD16, 6030, 2),
+    (0xFD17, 6032, 2), (0xFD18, 6034, 2), (0xFD19, 6036, 2), (0xFD1A, 6038, 2), (0xFD1B, 6040, 2), (0xFD1C, 6042, 2), (0xFD1D, 6044, 2), (0xFD1E, 6046, 2),
+    (0xFD1F, 6048, 2), (0xFD20, 6050, 2), (0xFD21, 6052, 2), (0xFD22, 6054, 2), (0xFD23, 6056, 2), (0xFD24, 6058, 2), (0xFD25, 6060, 2), (0xFD26, 6062, 2),
+    (0xFD27, 6064, 2), (0xFD28, 6066, 2), (0xFD29, 6068, 2), (0xFD2A, 6070, 2), (0xFD2B, 6072, 2), (0xFD2C, 6074, 2), (0xFD2D, 6076, 2), (0xFD2E, 6078, 2),
+    (0xFD2F, 6080, 2), (0xFD30, 6082, 2), (0xFD31, 6084, 2), (0xFD32, 6086, 2), (0xFD33, 6088, 2), (0xFD34, 6090, 2), (0xFD35, 6092, 2), (0xFD36, 6094, 2),
+    (0xFD37, 6096, 2), (0xFD38, 6098, 2), (0xFD39, 6100, 2), (0xFD3A, 6102, 2), (0xFD3B, 6104, 2), (0xFD3C, 6106, 2), (0xFD3D, 6108, 2), (0xFD50, 6110, 3),
+    (0xFD51, 6113, 3), (0xFD52, 6116, 3), (0xFD53, 6119, 3), (0xFD54, 6122, 3), (0xFD55, 6125, 3), (0xFD56, 6128, 3), (0xFD57, 6131, 3), (0xFD58, 6134, 3),
+    (0xFD59, 6137, 3), (0xFD5A, 6140, 3), (0xFD5B, 6143, 3), (0xFD5C, 6146, 3), (0xFD5D, 6149, 3), (0xFD5E, 6152, 3), (0xFD5F, 6155, 3), (0xFD60, 6158, 3),
+    (0xFD61, 6161, 3), (0xFD62, 6164, 3), (0xFD63, 6167, 3), (0xFD64, 6170, 3), (0xFD65, 6173, 3), (0xFD66, 6176, 3), (0xFD67, 6179, 3), (0xFD68, 6182, 3),
+    (0xFD69, 6185, 3), (0xFD6A, 6188, 3), (0xFD6B, 6191, 3), (0xFD6C, 6194, 3), (0xFD6D, 6197, 3), (0xFD6E, 6200, 3), (0xFD6F, 6203, 3), (0xFD70, 6206, 3),
+    (0xFD71, 6209, 3), (0xFD72, 6212, 3), (0xFD73, 6215, 3), (0xFD74, 6218, 3), (0xFD75, 6221, 3), (0xFD76, 6224, 3), (0xFD77, 6227, 3), (0xFD78, 6230, 3),
+    (0xFD79, 6233, 3), (0xFD7A, 6236, 3), (0xFD7B, 6239, 3), (0xFD7C, 6242, 3), (0xFD7D, 6245, 3), (0xFD7E, 6248, 3), (0xFD7F, 6251, 3), (0xFD80, 6254, 3),
+    (0xFD81, 6257, 3), (0xFD82, 6260, 3), (0xFD83, 6263, 3), (0xFD84, 6266, 3), (0xFD85, 6269, 3), (0xFD86, 6272, 3), (0xFD87, 6275, 3), (0xFD88, 6278, 3),
+    (0xFD89, 6281, 3), (0xFD8A, 6284, 3), (0xFD8B, 6287, 3), (0xFD8C, 6290, 3), (0xFD8D, 6293, 3), (0xFD8E, 6296, 3), (0xFD8F, 6299, 3), (0xFD92, 6302, 3),
+    (0xFD93, 6305, 3), (0xFD94, 6308, 3), (0xFD95, 6311, 3), (0xFD96, 6314, 3), (0xFD97, 6317, 3), (0xFD98, 6320, 3), (0xFD99, 6323, 3), (0xFD9A, 6326, 3),
+    (0xFD9B, 6329, 3), (0xFD9C, 6332, 3), (0xFD9D, 6335, 3), (0xFD9E, 6338, 3), (0xFD9F, 6341, 3), (0xFDA0, 6344, 3), (0xFDA1, 6347, 3), (0xFDA2, 6350, 3),
+    (0xFDA3, 6353, 3), (0xFDA4, 6356, 3), (0xFDA5, 6359, 3), (0xFDA6, 6362, 3), (0xFDA7, 6365, 3), (0xFDA8, 6368, 3), (0xFDA9, 6371, 3), (0xFDAA, 6374, 3),
+    (0xFDAB, 6377, 3), (0xFDAC, 6380, 3), (0xFDAD, 6383, 3), (0xFDAE, 6386, 3), (0xFDAF, 6389, 3), (0xFDB0, 6392, 3), (0xFDB1, 6395, 3), (0xFDB2, 6398, 3),
+    (0xFDB3, 6401, 3), (0xFDB4, 6404, 3), (0xFDB5, 6407, 3), (0xFDB6, 6410, 3), (0xFDB7, 6413, 3), (0xFDB8, 6416, 3), (0xFDB9, 6419, 3), (0xFDBA, 6422, 3),
+    (0xFDBB, 6425, 3), (0xFDBC, 6428, 3), (0xFDBD, 6431, 3), (0xFDBE, 6434, 3), (0xFDBF, 6437, 3), (0xFDC0, 6440, 3), (0xFDC1, 6443, 3), (0xFDC2, 6446, 3),
+    (0xFDC3, 6449, 3), (0xFDC4, 6452, 3), (0xFDC5, 6455, 3), (0xFDC6, 6458, 3), (0xFDC7, 6461, 3), (0xFDF0, 6464, 3), (0xFDF1, 6467, 3), (0xFDF2, 6470, 4),
+    (0xFDF3, 6474, 4), (0xFDF4, 6478, 4), (0xFDF5, 6482, 4), (0xFDF6, 6486, 4), (0xFDF7, 6490, 4), (0xFDF8, 6494, 4), (0xFDF9, 6498, 3), (0xFDFA, 6501, 18),
+    (0xFDFB, 6519, 8), (0xFDFC, 6527, 4), (0xFE10, 6531, 1), (0xFE11, 6532, 1), (0xFE12, 6533, 1), (0xFE13, 6534, 1), (0xFE14, 6535, 1), (0xFE15, 6536, 1),
+    (0xFE16, 6537, 1), (0xFE17, 6538, 1), (0xFE18, 6539, 1), (0xFE19, 6540, 3), (0xFE30, 6543, 2), (0xFE31, 6545, 1), (0xFE32, 6546, 1), (0xFE33, 6547, 1),
+    (0xFE34, 6548, 1), (0xFE35, 6549, 1), (0xFE36, 6550, 1), (0xFE37, 6551, 1), (0xFE38, 6552, 1), (0xFE39, 6553, 1), (0xFE3A, 6554, 1), (0xFE3B, 6555, 1),
+    (0xFE3C, 6556, 1), (0xFE3D, 6557, 1), (0xFE3E, 6558, 1), (0xFE3F, 6559, 1), (0xFE40, 6560, 1), (0xFE41, 6561, 1), (0xFE42, 6562, 1), (0xFE43, 6563, 1),
+    (0xFE44, 6564, 1), (0xFE47, 6565, 1), (0xFE48, 6566, 1), (0xFE49, 6567, 2), (0xFE4A, 6569, 2), (0xFE4B, 6571, 2), (0xFE4C, 6573, 2), (0xFE4D, 6575, 1),
+    (0xFE4E, 6576, 1), (0xFE4F, 6577, 1), (0xFE50, 6578, 1), (0xFE51, 6579, 1), (0xFE52, 6580, 1), (0xFE54, 6581, 1), (0xFE55, 6582, 1), (0xFE56, 6583, 1),
+    (0xFE57, 6584, 1), (0xFE58, 6585, 1), (0xFE59, 6586, 1), (0xFE5A, 6587, 1), (0xFE5B, 6588, 1), (0xFE5C, 6589, 1), (0xFE5D, 6590, 1), (0xFE5E, 6591, 1),
+    (0xFE5F, 6592, 1), (0xFE60, 6593, 1), (0xFE61, 6594, 1), (0xFE62, 6595, 1), (0xFE63, 6596, 1), (0xFE64, 6597, 1), (0xFE65, 6598, 1), (0xFE66, 6599, 1),
+    (0xFE68, 6600, 1), (0xFE69, 6601, 1), (0xFE6A, 6602, 1), (0xFE6B, 6603, 1), (0xFE70, 6604, 2), (0xFE71, 6606, 2), (0xFE72, 6608, 2), (0xFE74, 6610, 2),
+    (0xFE76, 6612, 2), (0xFE77, 6614, 2), (0xFE78, 6616, 2), (0xFE79, 6618, 2), (0xFE7A, 6620, 2), (0xFE7B, 6622, 2), (0xFE7C, 6624, 2), (0xFE7D, 6626, 2),
+    (0xFE7E, 6628, 2), (0xFE7F, 6630, 2), (0xFE80, 6632, 1), (0xFE81, 6633, 2), (0xFE82, 6635, 2), (0xFE83, 6637, 2), (0xFE84, 6639, 2), (0xFE85, 6641, 2),
+    (0xFE86, 6643, 2), (0xFE87, 6645, 2), (0xFE88, 6647, 2), (0xFE89, 6649, 2), (0xFE8A, 6651, 2), (0xFE8B, 6653, 2), (0xFE8C, 6655, 2), (0xFE8D, 6657, 1),
+    (0xFE8E, 6658, 1), (0xFE8F, 6659, 1), (0xFE90, 6660, 1), (0xFE91, 6661, 1), (0xFE92, 6662, 1), (0xFE93, 6663, 1), (0xFE94, 6664, 1), (0xFE95, 6665, 1),
+    (0xFE96, 6666, 1), (0xFE97, 6667, 1), (0xFE98, 6668, 1), (0xFE99, 6669, 1), (0xFE9A, 6670, 1), (0xFE9B, 6671, 1), (0xFE9C, 6672, 1), (0xFE9D, 6673, 1),
+    (0xFE9E, 6674, 1), (0xFE9F, 6675, 1), (0xFEA0, 6676, 1), (0xFEA1, 6677, 1), (0xFEA2, 6678, 1), (0xFEA3, 6679, 1), (0xFEA4, 6680, 1), (0xFEA5, 6681, 1),
+    (0xFEA6, 6682, 1), (0xFEA7, 6683, 1), (0xFEA8, 6684, 1), (0xFEA9, 6685, 1), (0xFEAA, 6686, 1), (0xFEAB, 6687, 1), (0xFEAC, 6688, 1), (0xFEAD, 6689, 1),
+    (0xFEAE, 6690, 1), (0xFEAF, 6691, 1), (0xFEB0, 6692, 1), (0xFEB1, 6693, 1), (0xFEB2, 6694, 1), (0xFEB3, 6695, 1), (0xFEB4, 6696, 1), (0xFEB5, 6697, 1),
+    (0xFEB6, 6698, 1), (0xFEB7, 6699, 1), (0xFEB8, 6700, 1), (0xFEB9, 6701, 1), (0xFEBA, 6702, 1), (0xFEBB, 6703, 1), (0xFEBC, 6704, 1), (0xFEBD, 6705, 1),
+    (0xFEBE, 6706, 1), (0xFEBF, 6707, 1), (0xFEC0, 6708, 1), (0xFEC1, 6709, 1), (0xFEC2, 6710, 1), (0xFEC3, 6711, 1), (0xFEC4, 6712, 1), (0xFEC5, 6713, 1),
+    (0xFEC6, 6714, 1), (0xFEC7, 6715, 1), (0xFEC8, 6716, 1), (0xFEC9, 6717, 1), (0xFECA, 6718, 1), (0xFECB, 6719, 1), (0xFECC, 6720, 1), (0xFECD, 6721, 1),
+    (0xFECE, 6722, 1), (0xFECF, 6723, 1), (0xFED0, 6724, 1), (0xFED1, 6725, 1), (0xFED2, 6726, 1), (0xFED3, 6727, 1), (0xFED4, 6728, 1), (0xFED5, 6729, 1),
+    (0xFED6, 6730, 1), (0xFED7, 6731, 1), (0xFED8, 6732, 1), (0xFED9, 6733, 1), (0xFEDA, 6734, 1), (0xFEDB, 6735, 1), (0xFEDC, 6736, 1), (0xFEDD, 6737, 1),
+    (0xFEDE, 6738, 1), (0xFEDF, 6739, 1), (0xFEE0, 6740, 1), (0xFEE1, 6741, 1), (0xFEE2, 6742, 1), (0xFEE3, 6743, 1), (0xFEE4, 6744, 1), (0xFEE5, 6745, 1),
+    (0xFEE6, 6746, 1), (0xFEE7, 6747, 1), (0xFEE8, 6748, 1), (0xFEE9, 6749, 1), (0xFEEA, 6750, 1), (0xFEEB, 6751, 1), (0xFEEC, 6752, 1), (0xFEED, 6753, 1),
+    (0xFEEE, 6754, 1), (0xFEEF, 6755, 1), (0xFEF0, 6756, 1), (0xFEF1, 6757, 1), (0xFEF2, 6758, 1), (0xFEF3, 6759, 1), (0xFEF4, 6760, 1), (0xFEF5, 6761, 3),
+    (0xFEF6, 6764, 3), (0xFEF7, 6767, 3), (0xFEF8, 6770, 3), (0xFEF9, 6773, 3), (0xFEFA, 6776, 3), (0xFEFB, 6779, 2), (0xFEFC, 6781, 2), (0xFF01, 6783, 1),
+    (0xFF02, 6784, 1), (0xFF03, 6785, 1), (0xFF04, 6786, 1), (0xFF05, 6787, 1), (0xFF06, 6788, 1), (0xFF07, 6789, 1), (0xFF08, 6790, 1), (0xFF09, 6791, 1),
+    (0xFF0A, 6792, 1), (0xFF0B, 6793, 1), (0xFF0C, 6794, 1), (0xFF0D, 6795, 1), (0xFF0E, 6796, 1), (0xFF0F, 6797, 1), (0xFF10, 6798, 1), (0xFF11, 6799, 1),
+    (0xFF12, 6800, 1), (0xFF13, 6801, 1), (0xFF14, 6802, 1), (0xFF15, 6803, 1), (0xFF16, 6804, 1), (0xFF17, 6805, 1), (0xFF18, 6806, 1), (0xFF19, 6807, 1),
+    (0xFF1A, 6808, 1), (0xFF1B, 6809, 1), (0xFF1C, 6810, 1), (0xFF1D, 6811, 1), (0xFF1E, 6812, 1), (0xFF1F, 6813, 1), (0xFF20, 6814, 1), (0xFF21, 6815, 1),
+    (0xFF22, 6816, 1), (0xFF23, 6817, 1), (0xFF24, 6818, 1), (0xFF25, 6819, 1), (0xFF26, 6820, 1), (0xFF27, 6821, 1), (0xFF28, 6822, 1), (0xFF29, 6823, 1),
+    (0xFF2A, 6824, 1), (0xFF2B, 6825, 1), (0xFF2C, 6826, 1), (0xFF2D, 6827, 1), (0xFF2E, 6828, 1), (0xFF2F, 6829, 1), (0xFF30, 6830, 1), (0xFF31, 6831, 1),
+    (0xFF32, 6832, 1), (0xFF33, 6833, 1), (0xFF34, 6834, 1), (0xFF35, 6835, 1), (0xFF36, 6836, 1), (0xFF37, 6837, 1), (0xFF38, 6838, 1), (0xFF39, 6839, 1),
+    (0xFF3A, 6840, 1), (0xFF3B, 6841, 1), (0xFF3C, 6842, 1), (0xFF3D, 6843, 1), (0xFF3E, 6844, 1), (0xFF3F, 6845, 1), (0xFF40, 6846, 1), (0xFF41, 6847, 1),
+    (0xFF42, 6848, 1), (0xFF43, 6849, 1), (0xFF44, 6850, 1), (0xFF45, 6851, 1), (0xFF46, 6852, 1), (0xFF47, 6853, 1), (0xFF48, 6854, 1), (0xFF49, 6855, 1),
+    (0xFF4A, 6856, 1), (0xFF4B, 6857, 1), (0xFF4C, 6858, 1), (0xFF4D, 6859, 1), (0xFF4E, 6860, 1), (0xFF4F, 6861, 1), (0xFF50, 6862, 1), (0xFF51, 6863, 1),
+    (0xFF52, 6864, 1), (0xFF53, 6865, 1), (0xFF54, 6866, 1), (0xFF55, 6867, 1), (0xFF56, 6868, 1), (0xFF57, 6869, 1), (0xFF58, 6870, 1), (0xFF59, 6871, 1),
+    (0xFF5A, 6872, 1), (0xFF5B, 6873, 1), (0xFF5C, 6874, 1), (0xFF5D, 6875, 1), (0xFF5E, 6876, 1), (0xFF5F, 6877, 1), (0xFF60, 6878, 1), (0xFF61, 6879, 1),
+    (0xFF62, 6880, 1), (0xFF63, 6881, 1), (0xFF64, 6882, 1), (0xFF65, 6883, 1), (0xFF66, 6884, 1), (0xFF67, 6885, 1), (0xFF68, 6886, 1), (0xFF69, 6887, 1),
+    (0xFF6A, 6888, 1), (0xFF6B, 6889, 1), (0xFF6C, 6890, 1), (0xFF6D, 6891, 1), (0xFF6E, 6892, 1), (0xFF6F, 6893, 1), (0xFF70, 6894, 1), (0xFF71, 6895, 1),
+    (0xFF72, 6896, 1), (0xFF73, 6897, 1), (0xFF74, 6898, 1), (0xFF75, 6899, 1), (0xFF76, 6900, 1), (0xFF77, 6901, 1), (0xFF78, 6902, 1), (0xFF79, 6903, 1),
+    (0xFF7A, 6904, 1), (0xFF7B, 6905, 1), (0xFF7C, 6906, 1), (0xFF7D, 6907, 1), (0xFF7E, 6908, 1), (0xFF7F, 6909, 1), (0xFF80, 6910, 1), (0xFF81, 6911, 1),
+    (0xFF82, 6912, 1), (0xFF83, 6913, 1), (0xFF84, 6914, 1), (0xFF85, 6915, 1), (0xFF86, 6916, 1), (0xFF87, 6917, 1), (0xFF88, 6918, 1), (0xFF89, 6919, 1),
+    (0xFF8A, 6920, 1), (0xFF8B, 6921, 1), (0xFF8C, 6922, 1), (0xFF8D, 6923, 1), (0xFF8E, 6924, 1), (0xFF8F, 6925, 1), (0xFF90, 6926, 1), (0xFF91, 6927, 1),
+    (0xFF92, 6928, 1), (0xFF93, 6929, 1), (0xFF94, 6930, 1), (0xFF95, 6931, 1), (0xFF96, 6932, 1), (0xFF97, 6933, 1), (0xFF98, 6934, 1), (0xFF99, 6935, 1),
+    (0xFF9A, 6936, 1), (0xFF9B, 6937, 1), (0xFF9C, 6938, 1), (0xFF9D, 6939, 1), (0xFF9E, 6940, 1), (0xFF9F, 6941, 1), (0xFFA0, 6942, 1), (0xFFA1, 6943, 1),
+    (0xFFA2, 6944, 1), (0xFFA3, 6945, 1), (0xFFA4, 6946, 1), (0xFFA5, 6947, 1), (0xFFA6, 6948, 1), (0xFFA7, 6949, 1), (0xFFA8, 6950, 1), (0xFFA9, 6951, 1),
+    (0xFFAA, 6952, 1), (0xFFAB, 6953, 1), (0xFFAC, 6954, 1), (0xFFAD, 6955, 1), (0xFFAE, 6956, 1), (0xFFAF, 6957, 1), (0xFFB0, 6958, 1), (0xFFB1, 6959, 1),
+    (0xFFB2, 6960, 1), (0xFFB3, 6961, 1), (0xFFB4, 6962, 1), (0xFFB5, 6963, 1), (0xFFB6, 6964, 1), (0xFFB7, 6965, 1), (0xFFB8, 6966, 1), (0xFFB9, 6967, 1),
+    (0xFFBA, 6968, 1), (0xFFBB, 6969, 1), (0xFFBC, 6970, 1), (0xFFBD, 6971, 1), (0xFFBE, 6972, 1), (0xFFC2, 6973, 1), (0xFFC3, 6974, 1), (0xFFC4, 6975, 1),
+    (0xFFC5, 6976, 1), (0xFFC6, 6977, 1), (0xFFC7, 6978, 1), (0xFFCA, 6979, 1), (0xFFCB, 6980, 1), (0xFFCC, 6981, 1), (0xFFCD, 6982, 1), (0xFFCE, 6983, 1),
+    (0xFFCF, 6984, 1), (0xFFD2, 6985, 1), (0xFFD3, 6986, 1), (0xFFD4, 6987, 1), (0xFFD5, 6988, 1), (0xFFD6, 6989, 1), (0xFFD7, 6990, 1), (0xFFDA, 6991, 1),
+    (0xFFDB, 6992, 1), (0xFFDC, 6993, 1), (0xFFE0, 6994, 1), (0xFFE1, 6995, 1), (0xFFE2, 6996, 1), (0xFFE3, 6997, 2), (0xFFE4, 6999, 1), (0xFFE5, 7000, 1),
+    (0xFFE6, 7001, 1), (0xFFE8, 7002, 1), (0xFFE9, 7003, 1), (0xFFEA, 7004, 1), (0xFFEB, 7005, 1), (0xFFEC, 7006, 1), (0xFFED, 7007, 1), (0xFFEE, 7008, 1),
+    (0x10781, 7009, 1), (0x10782, 7010, 1), (0x10783, 7011, 1), (0x10784, 7012, 1), (0x10785, 7013, 1), (0x10787, 7014, 1), (0x10788, 7015, 1), (0x10789, 7016, 1),
+    (0x1078A, 7017, 1), (0x1078B, 7018, 1), (0x1078C, 7019, 1), (0x1078D, 7020, 1), (0x1078E, 7021, 1), (0x1078F, 7022, 1), (0x10790, 7023, 1), (0x10791, 7024, 1),
+    (0x10792, 7025, 1), (0x10793, 7026, 1), (0x10794, 7027, 1), (0x10795, 7028, 1), (0x10796, 7029, 1), (0x10797, 7030, 1), (0x10798, 7031, 1), (0x10799, 7032, 1),
+    (0x1079A, 7033, 1), (0x1079B, 7034, 1), (0x1079C, 7035, 1), (0x1079D, 7036, 1), (0x1079E, 7037, 1), (0x1079F, 7038, 1), (0x107A0, 7039, 1), (0x107A1, 7040, 1),
+    (0x107A2, 7041, 1), (0x107A3, 7042, 1), (0x107A4, 7043, 1), (0x107A5, 7044, 1), (0x107A6, 7045, 1), (0x107A7, 7046, 1), (0x107A8, 7047, 1), (0x107A9, 7048, 1),
+    (0x107AA, 7049, 1), (0x107AB, 7050, 1), (0x107AC, 7051, 1), (0x107AD, 7052, 1), (0x107AE, 7053, 1), (0x107AF, 7054, 1), (0x107B0, 7055, 1), (0x107B2, 7056, 1),
+    (0x107B3, 7057, 1), (0x107B4, 7058, 1), (0x107B5, 7059, 1), (0x107B6, 7060, 1), (0x107B7, 7061, 1), (0x107B8, 7062, 1), (0x107B9, 7063, 1), (0x107BA, 7064, 1),
+    (0x1109A, 7065, 2), (0x1109C, 7067, 2), (0x110AB, 7069, 2), (0x1112E, 7071, 2), (0x1112F, 7073, 2), (0x1134B, 7075, 2), (0x1134C, 7077, 2), (0x114BB, 7079, 2),
+    (0x114BC, 7081, 2), (0x114BE, 7083, 2), (0x115BA, 7085, 2), (0x115BB, 7087, 2), (0x11938, 7089, 2), (0x1D15E, 7091, 2), (0x1D15F, 7093, 2), (0x1D160, 7095, 3),
+    (0x1D161, 7098, 3), (0x1D162, 7101, 3), (0x1D163, 7104, 3), (0x1D164, 7107, 3), (0x1D1BB, 7110, 2), (0x1D1BC, 7112, 2), (0x1D1BD, 7114, 3), (0x1D1BE, 7117, 3),
+    (0x1D1BF, 7120, 3), (0x1D1C0, 7123, 3), (0x1D400, 7126, 1), (0x1D401, 7127, 1), (0x1D402, 7128, 1), (0x1D403, 7129, 1), (0x1D404, 7130, 1), (0x1D405, 7131, 1),
+    (0x1D406, 7132, 1), (0x1D407, 7133, 1), (0x1D408, 7134, 1), (0x1D409, 7135, 1), (0x1D40A, 7136, 1), (0x1D40B, 7137, 1), (0x1D40C, 7138, 1), (0x1D40D, 7139, 1),
+    (0x1D40E, 7140, 1), (0x1D40F, 7141, 1), (0x1D410, 7142, 1), (0x1D411, 7143, 1), (0x1D412, 7144, 1), (0x1D413, 7145, 1), (0x1D414, 7146, 1), (0x1D415, 7147, 1),
+    (0x1D416, 7148, 1), (0x1D417, 7149, 1), (0x1D418, 7150, 1), (0x1D419, 7151, 1), (0x1D41A, 7152, 1), (0x1D41B, 7153, 1), (0x1D41C, 7154, 1), (0x1D41D, 7155, 1),
+    (0x1D41E, 7156, 1), (0x1D41F, 7157, 1), (0x1D420, 7158, 1), (0x1D421, 7159, 1), (0x1D422, 7160, 1), (0x1D423, 7161, 1), (0x1D424, 7162, 1), (0x1D425, 7163, 1),
+    (0x1D426, 7164, 1), (0x1D427, 7165, 1), (0x1D428, 7166, 1), (0x1D429, 7167, 1), (0x1D42A, 7168, 1), (0x1D42B, 7169, 1), (0x1D42C, 7170, 1), (0x1D42D, 7171, 1),
+    (0x1D42E, 7172, 1), (0x1D42F, 7173, 1), (0x1D430, 7174, 1), (0x1D431, 7175, 1), (0x1D432, 7176, 1), (0x1D433, 7177, 1), (0x1D434, 7178, 1), (0x1D435, 7179, 1),
+    (0x1D436, 7180, 1), (0x1D437, 7181, 1), (0x1D438, 7182, 1), (0x1D439, 7183, 1), (0x1D43A, 7184, 1), (0x1D43B, 7185, 1), (0x1D43C, 7186, 1), (0x1D43D, 7187, 1),
+    (0x1D43E, 7188, 1), (0x1D43F, 7189, 1), (0x1D440, 7190, 1), (0x1D441, 7191, 1), (0x1D442, 7192, 1), (0x1D443, 7193, 1), (0x1D444, 7194, 1), (0x1D445, 7195, 1),
+    (0x1D446, 7196, 1), (0x1D447, 7197, 1), (0x1D448, 7198, 1), (0x1D449, 7199, 1), (0x1D44A, 7200, 1), (0x1D44B, 7201, 1), (0x1D44C, 7202, 1), (0x1D44D, 7203, 1),
+    (0x1D44E, 7204, 1), (0x1D44F, 7205, 1), (0x1D450, 7206, 1), (0x1D451, 7207, 1), (0x1D452, 7208, 1), (0x1D453, 7209, 1), (0x1D454, 7210, 1), (0x1D456, 7211, 1),
+    (0x1D457, 7212, 1), (0x1D458, 7213, 1), (0x1D459, 7214, 1), (0x1D45A, 7215, 1), (0x1D45B, 7216, 1), (0x1D45C, 7217, 1), (0x1D45D, 7218, 1), (0x1D45E, 7219, 1),
+    (0x1D45F, 7220, 1), (0x1D460, 7221, 1), (0x1D461, 7222, 1), (0x1D462, 7223, 1), (0x1D463, 7224, 1), (0x1D464, 7225, 1), (0x1D465, 7226, 1), (0x1D466, 7227, 1),
+    (0x1D467, 7228, 1), (0x1D468, 7229, 1), (0x1D469, 7230, 1), (0x1D46A, 7231, 1), (0x1D46B, 7232, 1), (0x1D46C, 7233, 1), (0x1D46D, 7234, 1), (0x1D46E, 7235, 1),
+    (0x1D46F, 7236, 1), (0x1D470, 7237, 1), (0x1D471, 7238, 1), (0x1D472, 7239, 1), (0x1D473, 7240, 1), (0x1D474, 7241, 1), (0x1D475, 7242, 1), (0x1D476, 7243, 1),
+    (0x1D477, 7244, 1), (0x1D478, 7245, 1), (0x1D479, 7246, 1), (0x1D47A, 7247, 1), (0x1D47B, 7248, 1), (0x1D47C, 7249, 1), (0x1D47D, 7250, 1), (0x1D47E, 7251, 1),
+    (0x1D47F, 7252, 1), (0x1D480, 7253, 1), (0x1D481, 7254, 1), (0x1D482, 7255, 1), (0x1D483, 7256, 1), (0x1D484, 7257, 1), (0x1D485, 7258, 1), (0x1D486, 7259, 1),
+    (0x1D487, 7260, 1), (0x1D488, 7261, 1), (0x1D489, 7262, 1), (0x1D48A, 7263, 1), (0x1D48B, 7264, 1), (0x1D48C, 7265, 1), (0x1D48D, 7266, 1), (0x1D48E, 7267, 1),
+    (0x1D48F, 7268, 1), (0x1D490, 7269, 1), (0x1D491, 7270, 1), (0x1D492, 7271, 1), (0x1D493, 7272, 1), (0x1D494, 7273, 1), (0x1D495, 7274, 1), (0x1D496, 7275, 1),
+    (0x1D497, 7276, 1), (0x1D498, 7277, 1), (0x1D499, 7278, 1), (0x1D49A, 7279, 1), (0x1D49B, 7280, 1), (0x1D49C, 7281, 1), (0x1D49E, 7282, 1), (0x1D49F, 7283, 1),
+    (0x1D4A2, 7284, 1), (0x1D4A5, 7285, 1), (0x1D4A6, 7286, 1), (0x1D4A9, 7287, 1), (0x1D4AA, 7288, 1), (0x1D4AB, 7289, 1), (0x1D4AC, 7290, 1), (0x1D4AE, 7291, 1),
+    (0x1D4AF, 7292, 1), (0x1D4B0, 7293, 1), (0x1D4B1, 7294, 1), (0x1D4B2, 7295, 1), (0x1D4B3, 7296, 1), (0x1D4B4, 7297, 1), (0x1D4B5, 7298, 1), (0x1D4B6, 7299, 1),
+    (0x1D4B7, 7300, 1), (0x1D4B8, 7301, 1), (0x1D4B9, 7302, 1), (0x1D4BB, 7303, 1), (0x1D4BD, 7304, 1), (0x1D4BE, 7305, 1), (0x1D4BF, 7306, 1), (0x1D4C0, 7307, 1),
+    (0x1D4C1, 7308, 1), (0x1D4C2, 7309, 1), (0x1D4C3, 7310, 1), (0x1D4C5, 7311, 1), (0x1D4C6, 7312, 1), (0x1D4C7, 7313, 1), (0x1D4C8, 7314, 1), (0x1D4C9, 7315, 1),
+    (0x1D4CA, 7316, 1), (0x1D4CB, 7317, 1), (0x1D4CC, 7318, 1), (0x1D4CD, 7319, 1), (0x1D4CE, 7320, 1), (0x1D4CF, 7321, 1), (0x1D4D0, 7322, 1), (0x1D4D1, 7323, 1),
+    (0x1D4D2, 7324, 1), (0x1D4D3, 7325, 1), (0x1D4D4, 7326, 1), (0x1D4D5, 7327, 1), (0x1D4D6, 7328, 1), (0x1D4D7, 7329, 1), (0x1D4D8, 7330, 1), (0x1D4D9, 7331, 1),
+    (0x1D4DA, 7332, 1), (0x1D4DB, 7333, 1), (0x1D4DC, 7334, 1), (0x1D4DD, 7335, 1), (0x1D4DE, 7336, 1), (0x1D4DF, 7337, 1), (0x1D4E0, 7338, 1), (0x1D4E1, 7339, 1),
+    (0x1D4E2, 7340, 1), (0x1D4E3, 7341, 1), (0x1D4E4, 7342, 1), (0x1D4E5, 7343, 1), (0x1D4E6, 7344, 1), (0x1D4E7, 7345, 1), (0x1D4E8, 7346, 1), (0x1D4E9, 7347, 1),
+    (0x1D4EA, 7348, 1), (0x1D4EB, 7349, 1), (0x1D4EC, 7350, 1), (0x1D4ED, 7351, 1), (0x1D4EE, 7352, 1), (0x1D4EF, 7353, 1), (0x1D4F0, 7354, 1), (0x1D4F1, 7355, 1),
+    (0x1D4F2, 7356, 1), (0x1D4F3, 7357, 1), (0x1D4F4, 7358, 1), (0x1D4F5, 7359, 1), (0x1D4F6, 7360, 1), (0x1D4F7, 7361, 1), (0x1D4F8, 7362, 1), (0x1D4F9, 7363, 1),
+    (0x1D4FA, 7364, 1), (0x1D4FB, 7365, 1), (0x1D4FC, 7366, 1), (0x1D4FD, 7367, 1), (0x1D4FE, 7368, 1), (0x1D4FF, 7369, 1), (0x1D500, 7370, 1), (0x1D501, 7371, 1),
+    (0x1D502, 7372, 1), (0x1D503, 7373, 1), (0x1D504, 7374, 1), (0x1D505, 7375, 1), (0x1D507, 7376, 1), (0x1D508, 7377, 1), (0x1D509, 7378, 1), (0x1D50A, 7379, 1),
+    (0x1D50D, 7380, 1), (0x1D50E, 7381, 1), (0x1D50F, 7382, 1), (0x1D510, 7383, 1), (0x1D511, 7384, 1), (0x1D512, 7385, 1), (0x1D513, 7386, 1), (0x1D514, 7387, 1),
+    (0x1D516, 7388, 1), (0x1D517, 7389, 1), (0x1D518, 7390, 1), (0x1D519, 7391, 1), (0x1D51A, 7392, 1), (0x1D51B, 7393, 1), (0x1D51C, 7394, 1), (0x1D51E, 7395, 1),
+    (0x1D51F, 7396, 1), (0x1D520, 7397, 1), (0x1D521, 7398, 1), (0x1D522, 7399, 1), (0x1D523, 7400, 1), (0x1D524, 7401, 1), (0x1D525, 7402, 1), (0x1D526, 7403, 1),
+    (0x1D527, 7404, 1), (0x1D528, 7405, 1), (0x1D529, 7406, 1), (0x1D52A, 7407, 1), (0x1D52B, 7408, 1), (0x1D52C, 7409, 1), (0x1D52D, 7410, 1), (0x1D52E, 7411, 1),
+    (0x1D52F, 7412, 1), (0x1D530, 7413, 1), (0x1D531, 7414, 1), (0x1D532, 7415, 1), (0x1D533, 7416, 1), (0x1D534, 7417, 1), (0x1D535, 7418, 1), (0x1D536, 7419, 1),
+    (0x1D537, 7420, 1), (0x1D538, 7421, 1), (0x1D539, 7422, 1), (0x1D53B, 7423, 1), (0x1D53C, 7424, 1), (0x1D53D, 7425, 1), (0x1D53E, 7426, 1), (0x1D540, 7427, 1),
+    (0x1D541, 7428, 1), (0x1D542, 7429, 1), (0x1D543, 7430, 1), (0x1D544, 7431, 1), (0x1D546, 7432, 1), (0x1D54A, 7433, 1), (0x1D54B, 7434, 1), (0x1D54C, 7435, 1),
+    (0x1D54D, 7436, 1), (0x1D54E, 7437, 1), (0x1D54F, 7438, 1), (0x1D550, 7439, 1), (0x1D552, 7440, 1), (0x1D553, 7441, 1), (0x1D554, 7442, 1), (0x1D555, 7443, 1),
+    (0x1D556, 7444, 1), (0x1D557, 7445, 1), (0x1D558, 7446, 1), (0x1D559, 7447, 1), (0x1D55A, 7448, 1), (0x1D55B, 7449, 1), (0x1D55C, 7450, 1), (0x1D55D, 7451, 1),
+    (0x1D55E, 7452, 1), (0x1D55F, 7453, 1), (0x1D560, 7454, 1), (0x1D561, 7455, 1), (0x1D562, 7456, 1), (0x1D563, 7457, 1), (0x1D564, 7458, 1), (0x1D565, 7459, 1),
+    (0x1D566, 7460, 1), (0x1D567, 7461, 1), (0x1D568, 7462, 1), (0x1D569, 7463, 1), (0x1D56A, 7464, 1), (0x1D56B, 7465, 1), (0x1D56C, 7466, 1), (0x1D56D, 7467, 1),
+    (0x1D56E, 7468, 1), (0x1D56F, 7469, 1), (0x1D570, 7470, 1), (0x1D571, 7471, 1), (0x1D572, 7472, 1), (0x1D573, 7473, 1), (0x1D574, 7474, 1), (0x1D575, 7475, 1),
+    (0x1D576, 7476, 1), (0x1D577, 7477, 1), (0x1D578, 7478, 1), (0x1D579, 7479, 1), (0x1D57A, 7480, 1), (0x1D57B, 7481, 1), (0x1D57C, 7482, 1), (0x1D57D, 7483, 1),
+    (0x1D57E, 7484, 1), (0x1D57F, 7485, 1), (0x1D580, 7486, 1), (0x1D581, 7487, 1), (0x1D582, 7488, 1), (0x1D583, 7489, 1), (0x1D584, 7490, 1), (0x1D585, 7491, 1),
+    (0x1D586, 7492, 1), (0x1D587, 7493, 1), (0x1D588, 7494, 1), (0x1D589, 7495, 1), (0x1D58A, 7496, 1), (0x1D58B, 7497, 1), (0x1D58C, 7498, 1), (0x1D58D, 7499, 1),
+    (0x1D58E, 7500, 1), (0x1D58F, 7501, 1), (0x1D590, 7502, 1), (0x1D591, 7503, 1), (0x1D592, 7504, 1), (0x1D593, 7505, 1), (0x1D594, 7506, 1), (0x1D595, 7507, 1),
+    (0x1D596, 7508, 1), (0x1D597, 7509, 1), (0x1D598, 7510, 1), (0x1D599, 7511, 1), (0x1D59A, 7512, 1), (0x1D59B, 7513, 1), (0x1D59C, 7514, 1), (0x1D59D, 7515, 1),
+    (0x1D59E, 7516, 1), (0x1D59F, 7517, 1), (0x1D5A0, 7518, 1), (0x1D5A1, 7519, 1), (0x1D5A2, 7520, 1), (0x1D5A3, 7521, 1), (0x1D5A4, 7522, 1), (0x1D5A5, 7523, 1),
+    (0x1D5A6, 7524, 1), (0x1D5A7, 7525, 1), (0x1D5A8, 7526, 1), (0x1D5A9, 7527, 1), (0x1D5AA, 7528, 1), (0x1D5AB, 7529, 1), (0x1D5AC, 7530, 1), (0x1D5AD, 7531, 1),
+    (0x1D5AE, 7532, 1), (0x1D5AF, 7533, 1), (0x1D5B0, 7534, 1), (0x1D5B1, 7535, 1), (0x1D5B2, 7536, 1), (0x1D5B3, 7537, 1), (0x1D5B4, 7538, 1), (0x1D5B5, 7539, 1),
+    (0x1D5B6, 7540, 1), (0x1D5B7, 7541, 1), (0x1D5B8, 7542, 1), (0x1D5B9, 7543, 1), (0x1D5BA, 7544, 1), (0x1D5BB, 7545, 1), (0x1D5BC, 7546, 1), (0x1D5BD, 7547, 1),
+    (0x1D5BE, 7548, 1), (0x1D5BF, 7549, 1), (0x1D5C0, 7550, 1), (0x1D5C1, 7551, 1), (0x1D5C2, 7552, 1), (0x1D5C3, 7553, 1), (0x1D5C4, 7554, 1), (0x1D5C5, 7555, 1),
+    (0x1D5C6, 7556, 1), (0x1D5C7, 7557, 1), (0x1D5C8, 7558, 1), (0x1D5C9, 7559, 1), (0x1D5CA, 7560, 1), (0x1D5CB, 7561, 1), (0x1D5CC, 7562, 1), (0x1D5CD, 7563, 1),
+    (0x1D5CE, 7564, 1), (0x1D5CF, 7565, 1), (0x1D5D0, 7566, 1), (0x1D5D1, 7567, 1), (0x1D5D2, 7568, 1), (0x1D5D3, 7569, 1), (0x1D5D4, 7570, 1), (0x1D5D5, 7571, 1),
+    (0x1D5D6, 7572, 1), (0x1D5D7, 7573, 1), (0x1D5D8, 7574, 1), (0x1D5D9, 7575, 1), (0x1D5DA, 7576, 1), (0x1D5DB, 7577, 1), (0x1D5DC, 7578, 1), (0x1D5DD, 7579, 1),
+    (0x1D5DE, 7580, 1), (0x1D5DF, 7581, 1), (0x1D5E0, 7582, 1), (0x1D5E1, 7583, 1), (0x1D5E2, 7584, 1), (0x1D5E3, 7585, 1), (0x1D5E4, 7586, 1), (0x1D5E5, 7587, 1),
+    (0x1D5E6, 7588, 1), (0x1D5E7, 7589, 1), (0x1D5E8, 7590, 1), (0x1D5E9, 7591, 1), (0x1D5EA, 7592, 1), (0x1D5EB, 7593, 1), (0x1D5EC, 7594, 1), (0x1D5ED, 7595, 1),
+    (0x1D5EE, 7596, 1), (0x1D5EF, 7597, 1), (0x1D5F0, 7598, 1), (0x1D5F1, 7599, 1), (0x1D5F2, 7600, 1), (0x1D5F3, 7601, 1), (0x1D5F4, 7602, 1), (0x1D5F5, 7603, 1),
+    (0x1D5F6, 7604, 1), (0x1D5F7, 7605, 1), (0x1D5F8, 7606, 1), (0x1D5F9, 7607, 1), (0x1D5FA, 7608, 1), (0x1D5FB, 7609, 1), (0x1D5FC, 7610, 1), (0x1D5FD, 7611, 1),
+    (0x1D5FE, 7612, 1), (0x1D5FF, 7613, 1), (0x1D600, 7614, 1), (0x1D601, 7615, 1), (0x1D602, 7616, 1), (0x1D603, 7617, 1), (0x1D604, 7618, 1), (0x1D605, 7619, 1),
+    (0x1D606, 7620, 1), (0x1D607, 7621, 1), (0x1D608, 7622, 1), (0x1D609, 7623, 1), (0x1D60A, 7624, 1), (0x1D60B, 7625, 1), (0x1D60C, 7626, 1), (0x1D60D, 7627, 1),
+    (0x1D60E, 7628, 1), (0x1D60F, 7629, 1), (0x1D610, 7630, 1), (0x1D611, 7631, 1), (0x1D612, 7632, 1), (0x1D613, 7633, 1), (0x1D614, 7634, 1), (0x1D615, 7635, 1),
+    (0x1D616, 7636, 1), (0x1D617, 7637, 1), (0x1D618, 7638, 1), (0x1D619, 7639, 1), (0x1D61A, 7640, 1), (0x1D61B, 7641, 1), (0x1D61C, 7642, 1), (0x1D61D, 7643, 1),
+    (0x1D61E, 7644, 1), (0x1D61F, 7645, 1), (0x1D620, 7646, 1), (0x1D621, 7647, 1), (0x1D622, 7648, 1), (0x1D623, 7649, 1), (0x1D624, 7650, 1), (0x1D625, 7651, 1),
+    (0x1D626, 7652, 1), (0x1D627, 7653, 1), (0x1D628, 7654, 1), (0x1D629, 7655, 1), (0x1D62A, 7656, 1), (0x1D62B, 7657, 1), (0x1D62C, 7658, 1), (0x1D62D, 7659, 1),
+    (0x1D62E, 7660, 1), (0x1D62F, 7661, 1), (0x1D630, 7662, 1), (0x1D631, 7663, 1), (0x1D632, 7664, 1), (0x1D633, 7665, 1), (0x1D634, 7666, 1), (0x1D635, 7667, 1),
+    (0x1D636, 7668, 1), (0x1D637, 7669, 1), (0x1D638, 7670, 1), (0x1D639, 7671, 1), (0x1D63A, 7672, 1), (0x1D63B, 7673, 1), (0x1D63C, 7674, 1), (0x1D63D, 7675, 1),
+    (0x1D63E, 7676, 1), (0x1D63F, 7677, 1), (0x1D640, 7678, 1), (0x1D641, 7679, 1), (0x1D642, 7680, 1), (0x1D643, 7681, 1), (0x1D644, 7682, 1), (0x1D645, 7683, 1),
+    (0x1D646, 7684, 1), (0x1D647, 7685, 1), (0x1D648, 7686, 1), (0x1D649, 7687, 1), (0x1D64A, 7688, 1), (0x1D64B, 7689, 1), (0x1D64C, 7690, 1), (0x1D64D, 7691, 1),
+    (0x1D64E, 7692, 1), (0x1D64F, 7693, 1), (0x1D650, 7694, 1), (0x1D651, 7695, 1), (0x1D652, 7696, 1), (0x1D653, 7697, 1), (0x1D654, 7698, 1), (0x1D655, 7699, 1),
+    (0x1D656, 7700, 1), (0x1D657, 7701, 1), (0x1D658, 7702, 1), (0x1D659, 7703, 1), (0x1D65A, 7704, 1), (0x1D65B, 7705, 1), (0x1D65C, 7706, 1), (0x1D65D, 7707, 1),
+    (0x1D65E, 7708, 1), (0x1D65F, 7709, 1), (0x1D660, 7710, 1), (0x1D661, 7711, 1), (0x1D662, 7712, 1), (0x1D663, 7713, 1), (0x1D664, 7714, 1), (0x1D665, 7715, 1),
+    (0x1D666, 7716, 1), (0x1D667, 7717, 1), (0x1D668, 7718, 1), (0x1D669, 7719, 1), (0x1D66A, 7720, 1), (0x1D66B, 7721, 1), (0x1D66C, 7722, 1), (0x1D66D, 7723, 1),
+    (0x1D66E, 7724, 1), (0x1D66F, 7725, 1), (0x1D670, 7726, 1), (0x1D671, 7727, 1), (0x1D672, 7728, 1), (0x1D673, 7729, 1), (0x1D674, 7730, 1), (0x1D675, 7731, 1),
+    (0x1D676, 7732, 1), (0x1D677, 7733, 1), (0x1D678, 7734, 1), (0x1D679, 7735, 1), (0x1D67A, 7736, 1), (0x1D67B, 7737, 1), (0x1D67C, 7738, 1), (0x1D67D, 7739, 1),
+    (0x1D67E, 7740, 1), (0x1D67F, 7741, 1), (0x1D680, 7742, 1), (0x1D681, 7743, 1), (0x1D682, 7744, 1), (0x1D683, 7745, 1), (0x1D684, 7746, 1), (0x1D685, 7747, 1),
+    (0x1D686, 7748, 1), (0x1D687, 7749, 1), (0x1D688, 7750, 1), (0x1D689, 7751, 1), (0x1D68A, 7752, 1), (0x1D68B, 7753, 1), (0x1D68C, 7754, 1), (0x1D68D, 7755, 1),
+    (0x1D68E, 7756, 1), (0x1D68F, 7757, 1), (0x1D690, 7758, 1), (0x1D691, 7759, 1), (0x1D692, 7760, 1), (0x1D693, 7761, 1), (0x1D694, 7762, 1), (0x1D695, 7763, 1),
+    (0x1D696, 7764, 1), (0x1D697, 7765, 1), (0x1D698, 7766, 1), (0x1D699, 7767, 1), (0x1D69A, 7768, 1), (0x1D69B, 7769, 1), (0x1D69C, 7770, 1), (0x1D69D, 7771, 1),
+    (0x1D69E, 7772, 1), (0x1D69F, 7773, 1), (0x1D6A0, 7774, 1), (0x1D6A1, 7775, 1), (0x1D6A2, 7776, 1), (0x1D6A3, 7777, 1), (0x1D6A4, 7778, 1), (0x1D6A5, 7779, 1),
+    (0x1D6A8, 7780, 1), (0x1D6A9, 7781, 1), (0x1D6AA, 7782, 1), (0x1D6AB, 7783, 1), (0x1D6AC, 7784, 1), (0x1D6AD, 7785, 1), (0x1D6AE, 7786, 1), (0x1D6AF, 7787, 1),
+    (0x1D6B0, 7788, 1), (0x1D6B1, 7789, 1), (0x1D6B2, 7790, 1), (0x1D6B3, 7791, 1), (0x1D6B4, 7792, 1), (0x1D6B5, 7793, 1), (0x1D6B6, 7794, 1), (0x1D6B7, 7795, 1),
+    (0x1D6B8, 7796, 1), (0x1D6B9, 7797, 1), (0x1D6BA, 7798, 1), (0x1D6BB, 7799, 1), (0x1D6BC, 7800, 1), (0x1D6BD, 7801, 1), (0x1D6BE, 7802, 1), (0x1D6BF, 7803, 1),
+    (0x1D6C0, 7804, 1), (0x1D6C1, 7805, 1), (0x1D6C2, 7806, 1), (0x1D6C3, 7807, 1), (0x1D6C4, 7808, 1), (0x1D6C5, 7809, 1), (0x1D6C6, 7810, 1), (0x1D6C7, 7811, 1),
+    (0x1D6C8, 7812, 1), (0x1D6C9, 7813, 1), (0x1D6CA, 7814, 1), (0x1D6CB, 7815, 1), (0x1D6CC, 7816, 1), (0x1D6CD, 7817, 1), (0x1D6CE, 7818, 1), (0x1D6CF, 7819, 1),
+    (0x1D6D0, 7820, 1), (0x1D6D1, 7821, 1), (0x1D6D2, 7822, 1), (0x1D6D3, 7823, 1), (0x1D6D4, 7824, 1), (0x1D6D5, 7825, 1), (0x1D6D6, 7826, 1), (0x1D6D7, 7827, 1),
+    (0x1D6D8, 7828, 1), (0x1D6D9, 7829, 1), (0x1D6DA, 7830, 1), (0x1D6DB, 7831, 1), (0x1D6DC, 7832, 1), (0x1D6DD, 7833, 1), (0x1D6DE, 7834, 1), (0x1D6DF, 7835, 1),
+    (0x1D6E0, 7836, 1), (0x1D6E1, 7837, 1), (0x1D6E2, 7838, 1), (0x1D6E3, 7839, 1), (0x1D6E4, 7840, 1), (0x1D6E5, 7841, 1), (0x1D6E6, 7842, 1), (0x1D6E7, 7843, 1),
+    (0x1D6E8, 7844, 1), (0x1D6E9, 7845, 1), (0x1D6EA, 7846, 1), (0x1D6EB, 7847, 1), (0x1D6EC, 7848, 1), (0x1D6ED, 7849, 1), (0x1D6EE, 7850, 1), (0x1D6EF, 7851, 1),
+    (0x1D6F0, 7852, 1), (0x1D6F1, 7853, 1), (0x1D6F2, 7854, 1), (0x1D6F3, 7855, 1), (0x1D6F4, 7856, 1), (0x1D6F5, 7857, 1), (0x1D6F6, 7858, 1), (0x1D6F7, 7859, 1),
+    (0x1D6F8, 7860, 1), (0x1D6F9, 7861, 1), (0x1D6FA, 7862, 1), (0x1D6FB, 7863, 1), (0x1D6FC, 7864, 1), (0x1D6FD, 7865, 1), (0x1D6FE, 7866, 1), (0x1D6FF, 7867, 1),
+    (0x1D700, 7868, 1), (0x1D701, 7869, 1), (0x1D702, 7870, 1), (0x1D703, 7871, 1), (0x1D704, 7872, 1), (0x1D705, 7873, 1), (0x1D706, 7874, 1), (0x1D707, 7875, 1),
+    (0x1D708, 7876, 1), (0x1D709, 7877, 1), (0x1D70A, 7878, 1), (0x1D70B, 7879, 1), (0x1D70C, 7880, 1), (0x1D70D, 7881, 1), (0x1D70E, 7882, 1), (0x1D70F, 7883, 1),
+    (0x1D710, 7884, 1), (0x1D711, 7885, 1), (0x1D712, 7886, 1), (0x1D713, 7887, 1), (0x1D714, 7888, 1), (0x1D715, 7889, 1), (0x1D716, 7890, 1), (0x1D717, 7891, 1),
+    (0x1D718, 7892, 1), (0x1D719, 7893, 1), (0x1D71A, 7894, 1), (0x1D71B, 7895, 1), (0x1D71C, 7896, 1), (0x1D71D, 7897, 1), (0x1D71E, 7898, 1), (0x1D71F, 7899, 1),
+    (0x1D720, 7900, 1), (0x1D721, 7901, 1), (0x1D722, 7902, 1), (0x1D723, 7903, 1), (0x1D724, 7904, 1), (0x1D725, 7905, 1), (0x1D726, 7906, 1), (0x1D727, 7907, 1),
+    (0x1D728, 7908, 1), (0x1D729, 7909, 1), (0x1D72A, 7910, 1), (0x1D72B, 7911, 1), (0x1D72C, 7912, 1), (0x1D72D, 7913, 1), (0x1D72E, 7914, 1), (0x1D72F, 7915, 1),
+    (0x1D730, 7916, 1), (0x1D731, 7917, 1), (0x1D732, 7918, 1), (0x1D733, 7919, 1), (0x1D734, 7920, 1), (0x1D735, 7921, 1), (0x1D736, 7922, 1), (0x1D737, 7923, 1),
+    (0x1D738, 7924, 1), (0x1D739, 7925, 1), (0x1D73A, 7926, 1), (0x1D73B, 7927, 1), (0x1D73C, 7928, 1), (0x1D73D, 7929, 1), (0x1D73E, 7930, 1), (0x1D73F, 7931, 1),
+    (0x1D740, 7932, 1), (0x1D741, 7933, 1), (0x1D742, 7934, 1), (0x1D743, 7935, 1), (0x1D744, 7936, 1), (0x1D745, 7937, 1), (0x1D746, 7938, 1), (0x1D747, 7939, 1),
+    (0x1D748, 7940, 1), (0x1D749, 7941, 1), (0x1D74A, 7942, 1), (0x1D74B, 7943, 1), (0x1D74C, 7944, 1), (0x1D74D, 7945, 1), (0x1D74E, 7946, 1), (0x1D74F, 7947, 1),
+    (0x1D750, 7948, 1), (0x1D751, 7949, 1), (0x1D752, 7950, 1), (0x1D753, 7951, 1), (0x1D754, 7952, 1), (0x1D755, 7953, 1), (0x1D756, 7954, 1), (0x1D757, 7955, 1),
+    (0x1D758, 7956, 1), (0x1D759, 7957, 1), (0x1D75A, 7958, 1), (0x1D75B, 7959, 1), (0x1D75C, 7960, 1), (0x1D75D, 7961, 1), (0x1D75E, 7962, 1), (0x1D75F, 7963, 1),
+    (0x1D760, 7964, 1), (0x1D761, 7965, 1), (0x1D762, 7966, 1), (0x1D763, 7967, 1), (0x1D764, 7968, 1), (0x1D765, 7969, 1), (0x1D766, 7970, 1), (0x1D767, 7971, 1),
+    (0x1D768, 7972, 1), (0x1D769, 7973, 1), (0x1D76A, 7974, 1), (0x1D76B, 7975, 1), (0x1D76C, 7976, 1), (0x1D76D, 7977, 1), (0x1D76E, 7978, 1), (0x1D76F, 7979, 1),
+    (0x1D770, 7980, 1), (0x1D771, 7981, 1), (0x1D772, 7982, 1), (0x1D773, 7983, 1), (0x1D774, 7984, 1), (0x1D775, 7985, 1), (0x1D776, 7986, 1), (0x1D777, 7987, 1),
+    (0x1D778, 7988, 1), (0x1D779, 7989, 1), (0x1D77A, 7990, 1), (0x1D77B, 7991, 1), (0x1D77C, 7992, 1), (0x1D77D, 7993, 1), (0x1D77E, 7994, 1), (0x1D77F, 7995, 1),
+    (0x1D780, 7996, 1), (0x1D781, 7997, 1), (0x1D782, 7998, 1), (0x1D783, 7999, 1), (0x1D784, 8000, 1), (0x1D785, 8001, 1), (0x1D786, 8002, 1), (0x1D787, 8003, 1),
+    (0x1D788, 8004, 1), (0x1D789, 8005, 1), (0x1D78A, 8006, 1), (0x1D78B, 8007, 1), (0x1D78C, 8008, 1), (0x1D78D, 8009, 1), (0x1D78E, 8010, 1), (0x1D78F, 8011, 1),
+    (0x1D790, 8012, 1), (0x1D791, 8013, 1), (0x1D792, 8014, 1), (0x1D793, 8015, 1), (0x1D794, 8016, 1), (0x1D795, 8017, 1), (0x1D796, 8018, 1), (0x1D797, 8019, 1),
+    (0x1D798, 8020, 1), (0x1D799, 8021, 1), (0x1D79A, 8022, 1), (0x1D79B, 8023, 1), (0x1D79C, 8024, 1), (0x1D79D, 8025, 1), (0x1D79E, 8026, 1), (0x1D79F, 8027, 1),
+    (0x1D7A0, 8028, 1), (0x1D7A1, 8029, 1), (0x1D7A2, 8030, 1), (0x1D7A3, 8031, 1), (0x1D7A4, 8032, 1), (0x1D7A5, 8033, 1), (0x1D7A6, 8034, 1), (0x1D7A7, 8035, 1),
+    (0x1D7A8, 8036, 1), (0x1D7A9, 8037, 1), (0x1D7AA, 8038, 1), (0x1D7AB, 8039, 1), (0x1D7AC, 8040, 1), (0x1D7AD, 8041, 1), (0x1D7AE, 8042, 1), (0x1D7AF, 8043, 1),
+    (0x1D7B0, 8044, 1), (0x1D7B1, 8045, 1), (0x1D7B2, 8046, 1), (0x1D7B3, 8047, 1), (0x1D7B4, 8048, 1), (0x1D7B5, 8049, 1), (0x1D7B6, 8050, 1), (0x1D7B7, 8051, 1),
+    (0x1D7B8, 8052, 1), (0x1D7B9, 8053, 1), (0x1D7BA, 8054, 1), (0x1D7BB, 8055, 1), (0x1D7BC, 8056, 1), (0x1D7BD, 8057, 1), (0x1D7BE, 8058, 1), (0x1D7BF, 8059, 1),
+    (0x1D7C0, 8060, 1), (0x1D7C1, 8061, 1), (0x1D7C2, 8062, 1), (0x1D7C3, 8063, 1), (0x1D7C4, 8064, 1), (0x1D7C5, 8065, 1), (0x1D7C6, 8066, 1), (0x1D7C7, 8067, 1),
+    (0x1D7C8, 8068, 1), (0x1D7C9, 8069, 1), (0x1D7CA, 8070, 1), (0x1D7CB, 8071, 1), (0x1D7CE, 8072, 1), (0x1D7CF, 8073, 1), (0x1D7D0, 8074, 1), (0x1D7D1, 8075, 1),
+    (0x1D7D2, 8076, 1), (0x1D7D3, 8077, 1), (0x1D7D4, 8078, 1), (0x1D7D5, 8079, 1), (0x1D7D6, 8080, 1), (0x1D7D7, 8081, 1), (0x1D7D8, 8082, 1), (0x1D7D9, 8083, 1),
+    (0x1D7DA, 8084, 1), (0x1D7DB, 8085, 1), (0x1D7DC, 8086, 1), (0x1D7DD, 8087, 1), (0x1D7DE, 8088, 1), (0x1D7DF, 8089, 1), (0x1D7E0, 8090, 1), (0x1D7E1, 8091, 1),
+    (0x1D7E2, 8092, 1), (0x1D7E3, 8093, 1), (0x1D7E4, 8094, 1), (0x1D7E5, 8095, 1), (0x1D7E6, 8096, 1), (0x1D7E7, 8097, 1), (0x1D7E8, 8098, 1), (0x1D7E9, 8099, 1),
+    (0x1D7EA, 8100, 1), (0x1D7EB, 8101, 1), (0x1D7EC, 8102, 1), (0x1D7ED, 8103, 1), (0x1D7EE, 8104, 1), (0x1D7EF, 8105, 1), (0x1D7F0, 8106, 1), (0x1D7F1, 8107, 1),
+    (0x1D7F2, 8108, 1), (0x1D7F3, 8109, 1), (0x1D7F4, 8110, 1), (0x1D7F5, 8111, 1), (0x1D7F6, 8112, 1), (0x1D7F7, 8113, 1), (0x1D7F8, 8114, 1), (0x1D7F9, 8115, 1),
+    (0x1D7FA, 8116, 1), (0x1D7FB, 8117, 1), (0x1D7FC, 8118, 1), (0x1D7FD, 8119, 1), (0x1D7FE, 8120, 1), (0x1D7FF, 8121, 1), (0x1EE00, 8122, 1), (0x1EE01, 8123, 1),
+    (0x1EE02, 8124, 1), (0x1EE03, 8125, 1), (0x1EE05, 8126, 1), (0x1EE06, 8127, 1), (0x1EE07, 8128, 1), (0x1EE08, 8129, 1), (0x1EE09, 8130, 1), (0x1EE0A, 8131, 1),
+    (0x1EE0B, 8132, 1), (0x1EE0C, 8133, 1), (0x1EE0D, 8134, 1), (0x1EE0E, 8135, 1), (0x1EE0F, 8136, 1), (0x1EE10, 8137, 1), (0x1EE11, 8138, 1), (0x1EE12, 8139, 1),
+    (0x1EE13, 8140, 1), (0x1EE14, 8141, 1), (0x1EE15, 8142, 1), (0x1EE16, 8143, 1), (0x1EE17, 8144, 1), (0x1EE18, 8145, 1), (0x1EE19, 8146, 1), (0x1EE1A, 8147, 1),
+    (0x1EE1B, 8148, 1), (0x1EE1C, 8149, 1), (0x1EE1D, 8150, 1), (0x1EE1E, 8151, 1), (0x1EE1F, 8152, 1), (0x1EE21, 8153, 1), (0x1EE22, 8154, 1), (0x1EE24, 8155, 1),
+    (0x1EE27, 8156, 1), (0x1EE29, 8157, 1), (0x1EE2A, 8158, 1), (0x1EE2B, 8159, 1), (0x1EE2C, 8160, 1), (0x1EE2D, 8161, 1), (0x1EE2E, 8162, 1), (0x1EE2F, 8163, 1),
+    (0x1EE30, 8164, 1), (0x1EE31, 8165, 1), (0x1EE32, 8166, 1), (0x1EE34, 8167, 1), (0x1EE35, 8168, 1), (0x1EE36, 8169, 1), (0x1EE37, 8170, 1), (0x1EE39, 8171, 1),
+    (0x1EE3B, 8172, 1), (0x1EE42, 8173, 1), (0x1EE47, 8174, 1), (0x1EE49, 8175, 1), (0x1EE4B, 8176, 1), (0x1EE4D, 8177, 1), (0x1EE4E, 8178, 1), (0x1EE4F, 8179, 1),
+    (0x1EE51, 8180, 1), (0x1EE52, 8181, 1), (0x1EE54, 8182, 1), (0x1EE57, 8183, 1), (0x1EE59, 8184, 1), (0x1EE5B, 8185, 1), (0x1EE5D, 8186, 1), (0x1EE5F, 8187, 1),
+    (0x1EE61, 8188, 1), (0x1EE62, 8189, 1), (0x1EE64, 8190, 1), (0x1EE67, 8191, 1), (0x1EE68, 8192, 1), (0x1EE69, 8193, 1), (0x1EE6A, 8194, 1), (0x1EE6C, 8195, 1),
+    (0x1EE6D, 8196, 1), (0x1EE6E, 8197, 1), (0x1EE6F, 8198, 1), (0x1EE70, 8199, 1), (0x1EE71, 8200, 1), (0x1EE72, 8201, 1), (0x1EE74, 8202, 1), (0x1EE75, 8203, 1),
+    (0x1EE76, 8204, 1), (0x1EE77, 8205, 1), (0x1EE79, 8206, 1), (0x1EE7A, 8207, 1), (0x1EE7B, 8208, 1), (0x1EE7C, 8209, 1), (0x1EE7E, 8210, 1), (0x1EE80, 8211, 1),
+    (0x1EE81, 8212, 1), (0x1EE82, 8213, 1), (0x1EE83, 8214, 1), (0x1EE84, 8215, 1), (0x1EE85, 8216, 1), (0x1EE86, 8217, 1), (0x1EE87, 8218, 1), (0x1EE88, 8219, 1),
+    (0x1EE89, 8220, 1), (0x1EE8B, 8221, 1), (0x1EE8C, 8222, 1), (0x1EE8D, 8223, 1), (0x1EE8E, 8224, 1), (0x1EE8F, 8225, 1), (0x1EE90, 8226, 1), (0x1EE91, 8227, 1),
+    (0x1EE92, 8228, 1), (0x1EE93, 8229, 1), (0x1EE94, 8230, 1), (0x1EE95, 8231, 1), (0x1EE96, 8232, 1), (0x1EE97, 8233, 1), (0x1EE98, 8234, 1), (0x1EE99, 8235, 1),
+    (0x1EE9A, 8236, 1), (0x1EE9B, 8237, 1), (0x1EEA1, 8238, 1), (0x1EEA2, 8239, 1), (0x1EEA3, 8240, 1), (0x1EEA5, 8241, 1), (0x1EEA6, 8242, 1), (0x1EEA7, 8243, 1),
+    (0x1EEA8, 8244, 1), (0x1EEA9, 8245, 1), (0x1EEAB, 8246, 1), (0x1EEAC, 8247, 1), (0x1EEAD, 8248, 1), (0x1EEAE, 8249, 1), (0x1EEAF, 8250, 1), (0x1EEB0, 8251, 1),
+    (0x1EEB1, 8252, 1), (0x1EEB2, 8253, 1), (0x1EEB3, 8254, 1), (0x1EEB4, 8255, 1), (0x1EEB5, 8256, 1), (0x1EEB6, 8257, 1), (0x1EEB7, 8258, 1), (0x1EEB8, 8259, 1),
+    (0x1EEB9, 8260, 1), (0x1EEBA, 8261, 1), (0x1EEBB, 8262, 1), (0x1F100, 8263, 2), (0x1F101, 8265, 2), (0x1F102, 8267, 2), (0x1F103, 8269, 2), (0x1F104, 8271, 2),
+    (0x1F105, 8273, 2), (0x1F106, 8275, 2), (0x1F107, 8277, 2), (0x1F108, 8279, 2), (0x1F109, 8281, 2), (0x1F10A, 8283, 2), (0x1F110, 8285, 3), (0x1F111, 8288, 3),
+    (0x1F112, 8291, 3), (0x1F113, 8294, 3), (0x1F114, 8297, 3), (0x1F115, 8300, 3), (0x1F116, 8303, 3), (0x1F117, 8306, 3), (0x1F118, 8309, 3), (0x1F119, 8312, 3),
+    (0x1F11A, 8315, 3), (0x1F11B, 8318, 3), (0x1F11C, 8321, 3), (0x1F11D, 8324, 3), (0x1F11E, 8327, 3), (0x1F11F, 8330, 3), (0x1F120, 8333, 3), (0x1F121, 8336, 3),
+    (0x1F122, 8339, 3), (0x1F123, 8342, 3), (0x1F124, 8345, 3), (0x1F125, 8348, 3), (0x1F126, 8351, 3), (0x1F127, 8354, 3), (0x1F128, 8357, 3), (0x1F129, 8360, 3),
+    (0x1F12A, 8363, 3), (0x1F12B, 8366, 1), (0x1F12C, 8367, 1), (0x1F12D, 8368, 2), (0x1F12E, 8370, 2), (0x1F130, 8372, 1), (0x1F131, 8373, 1), (0x1F132, 8374, 1),
+    (0x1F133, 8375, 1), (0x1F134, 8376, 1), (0x1F135, 8377, 1), (0x1F136, 8378, 1), (0x1F137, 8379, 1), (0x1F138, 8380, 1), (0x1F139, 8381, 1), (0x1F13A, 8382, 1),
+    (0x1F13B, 8383, 1), (0x1F13C, 8384, 1), (0x1F13D, 8385, 1), (0x1F13E, 8386, 1), (0x1F13F, 8387, 1), (0x1F140, 8388, 1), (0x1F141, 8389, 1), (0x1F142, 8390, 1),
+    (0x1F143, 8391, 1), (0x1F144, 8392, 1), (0x1F145, 8393, 1), (0x1F146, 8394, 1), (0x1F147, 8395, 1), (0x1F148, 8396, 1), (0x1F149, 8397, 1), (0x1F14A, 8398, 2),
+    (0x1F14B, 8400, 2), (0x1F14C, 8402, 2), (0x1F14D, 8404, 2), (0x1F14E, 8406, 3), (0x1F14F, 8409, 2), (0x1F16A, 8411, 2), (0x1F16B, 8413, 2), (0x1F16C, 8415, 2),
+    (0x1F190, 8417, 2), (0x1F200, 8419, 2), (0x1F201, 8421, 2), (0x1F202, 8423, 1), (0x1F210, 8424, 1), (0x1F211, 8425, 1), (0x1F212, 8426, 1), (0x1F213, 8427, 2),
+    (0x1F214, 8429, 1), (0x1F215, 8430, 1), (0x1F216, 8431, 1), (0x1F217, 8432, 1), (0x1F218, 8433, 1), (0x1F219, 8434, 1), (0x1F21A, 8435, 1), (0x1F21B, 8436, 1),
+    (0x1F21C, 8437, 1), (0x1F21D, 8438, 1), (0x1F21E, 8439, 1), (0x1F21F, 8440, 1), (0x1F220, 8441, 1), (0x1F221, 8442, 1), (0x1F222, 8443, 1), (0x1F223, 8444, 1),
+    (0x1F224, 8445, 1), (0x1F225, 8446, 1), (0x1F226, 8447, 1), (0x1F227, 8448, 1), (0x1F228, 8449, 1), (0x1F229, 8450, 1), (0x1F22A, 8451, 1), (0x1F22B, 8452, 1),
+    (0x1F22C, 8453, 1), (0x1F22D, 8454, 1), (0x1F22E, 8455, 1), (0x1F22F, 8456, 1), (0x1F230, 8457, 1), (0x1F231, 8458, 1), (0x1F232, 8459, 1), (0x1F233, 8460, 1),
+    (0x1F234, 8461, 1), (0x1F235, 8462, 1), (0x1F236, 8463, 1), (0x1F237, 8464, 1), (0x1F238, 8465, 1), (0x1F239, 8466, 1), (0x1F23A, 8467, 1), (0x1F23B, 8468, 1),
+    (0x1F240, 8469, 3), (0x1F241, 8472, 3), (0x1F242, 8475, 3), (0x1F243, 8478, 3), (0x1F244, 8481, 3), (0x1F245, 8484, 3), (0x1F246, 8487, 3), (0x1F247, 8490, 3),
+    (0x1F248, 8493, 3), (0x1F250, 8496, 1), (0x1F251, 8497, 1), (0x1FBF0, 8498, 1), (0x1FBF1, 8499, 1), (0x1FBF2, 8500, 1), (0x1FBF3, 8501, 1), (0x1FBF4, 8502, 1),
+    (0x1FBF5, 8503, 1), (0x1FBF6, 8504, 1), (0x1FBF7, 8505, 1), (0x1FBF8, 8506, 1), (0x1FBF9, 8507, 1), (0x2F800, 8508, 1), (0x2F801, 8509, 1), (0x2F802, 8510, 1),
+    (0x2F803, 8511, 1), (0x2F804, 8512, 1), (0x2F805, 8513, 1), (0x2F806, 8514, 1), (0x2F807, 8515, 1), (0x2F808, 8516, 1), (0x2F809, 8517, 1), (0x2F80A, 8518, 1),
+    (0x2F80B, 8519, 1), (0x2F80C, 8520, 1), (0x2F80D, 8521, 1), (0x2F80E, 8522, 1), (0x2F80F, 8523, 1), (0x2F810, 8524, 1), (0x2F811, 8525, 1), (0x2F812, 8526, 1),
+    (0x2F813, 8527, 1), (0x2F814, 8528, 1), (0x2F815, 8529, 1), (0x2F816, 8530, 1), (0x2F817, 8531, 1), (0x2F818, 8532, 1), (0x2F819, 8533, 1), (0x2F81A, 8534, 1),
+    (0x2F81B, 8535, 1), (0x2F81C, 8536, 1), (0x2F81D, 8537, 1), (0x2F81E, 8538, 1), (0x2F81F, 8539, 1), (0x2F820, 8540, 1), (0x2F821, 8541, 1), (0x2F822, 8542, 1),
+    (0x2F823, 8543, 1), (0x2F824, 8544, 1), (0x2F825, 8545, 1), (0x2F826, 8546, 1), (0x2F827, 8547, 1), (0x2F828, 8548, 1), (0x2F829, 8549, 1), (0x2F82A, 8550, 1),
+    (0x2F82B, 8551, 1), (0x2F82C, 8552, 1), (0x2F82D, 8553, 1), (0x2F82E, 8554, 1), (0x2F82F, 8555, 1), (0x2F830, 8556, 1), (0x2F831, 8557, 1), (0x2F832, 8558, 1),
+    (0x2F833, 8559, 1), (0x2F834, 8560, 1), (0x2F835, 8561, 1), (0x2F836, 8562, 1), (0x2F837, 8563, 1), (0x2F838, 8564, 1), (0x2F839, 8565, 1), (0x2F83A, 8566, 1),
+    (0x2F83B, 8567, 1), (0x2F83C, 8568, 1), (0x2F83D, 8569, 1), (0x2F83E, 8570, 1), (0x2F83F, 8571, 1), (0x2F840, 8572, 1), (0x2F841, 8573, 1), (0x2F842, 8574, 1),
+    (0x2F843, 8575, 1), (0x2F844, 8576, 1), (0x2F845, 8577, 1), (0x2F846, 8578, 1), (0x2F847, 8579, 1), (0x2F848, 8580, 1), (0x2F849, 8581, 1), (0x2F84A, 8582, 1),
+    (0x2F84B, 8583, 1), (0x2F84C, 8584, 1), (0x2F84D, 8585, 1), (0x2F84E, 8586, 1), (0x2F84F, 8587, 1), (0x2F850, 8588, 1), (0x2F851, 8589, 1), (0x2F852, 8590, 1),
+    (0x2F853, 8591, 1), (0x2F854, 8592, 1), (0x2F855, 8593, 1), (0x2F856, 8594, 1), (0x2F857, 8595, 1), (0x2F858, 8596, 1), (0x2F859, 8597, 1), (0x2F85A, 8598, 1),
+    (0x2F85B, 8599, 1), (0x2F85C, 8600, 1), (0x2F85D, 8601, 1), (0x2F85E, 8602, 1), (0x2F85F, 8603, 1), (0x2F860, 8604, 1), (0x2F861, 8605, 1), (0x2F862, 8606, 1),
+    (0x2F863, 8607, 1), (0x2F864, 8608, 1), (0x2F865, 8609, 1), (0x2F866, 8610, 1), (0x2F867, 8611, 1), (0x2F868, 8612, 1), (0x2F869, 8613, 1), (0x2F86A, 8614, 1),
+    (0x2F86B, 8615, 1), (0x2F86C, 8616, 1), (0x2F86D, 8617, 1), (0x2F86E, 8618, 1), (0x2F86F, 8619, 1), (0x2F870, 8620, 1), (0x2F871, 8621, 1), (0x2F872, 8622, 1),
+    (0x2F873, 8623, 1), (0x2F874, 8624, 1), (0x2F875, 8625, 1), (0x2F876, 8626, 1), (0x2F877, 8627, 1), (0x2F878, 8628, 1), (0x2F879, 8629, 1), (0x2F87A, 8630, 1),
+    (0x2F87B, 8631, 1), (0x2F87C, 8632, 1), (0x2F87D, 8633, 1), (0x2F87E, 8634, 1), (0x2F87F, 8635, 1), (0x2F880, 8636, 1), (0x2F881, 8637, 1), (0x2F882, 8638, 1),
+    (0x2F883, 8639, 1), (0x2F884, 8640, 1), (0x2F885, 8641, 1), (0x2F886, 8642, 1), (0x2F887, 8643, 1), (0x2F888, 8644, 1), (0x2F889, 8645, 1), (0x2F88A, 8646, 1),
+    (0x2F88B, 8647, 1), (0x2F88C, 8648, 1), (0x2F88D, 8649, 1), (0x2F88E, 8650, 1), (0x2F88F, 8651, 1), (0x2F890, 8652, 1), (0x2F891, 8653, 1), (0x2F892, 8654, 1),
+    (0x2F893, 8655, 1), (0x2F894, 8656, 1), (0x2F895, 8657, 1), (0x2F896, 8658, 1), (0x2F897, 8659, 1), (0x2F898, 8660, 1), (0x2F899, 8661, 1), (0x2F89A, 8662, 1),
+    (0x2F89B, 8663, 1), (0x2F89C, 8664, 1), (0x2F89D, 8665, 1), (0x2F89E, 8666, 1), (0x2F89F, 8667, 1), (0x2F8A0, 8668, 1), (0x2F8A1, 8669, 1), (0x2F8A2, 8670, 1),
+    (0x2F8A3, 8671, 1), (0x2F8A4, 8672, 1), (0x2F8A5, 8673, 1), (0x2F8A6, 8674, 1), (0x2F8A7, 8675, 1), (0x2F8A8, 8676, 1), (0x2F8A9, 8677, 1), (0x2F8AA, 8678, 1),
+    (0x2F8AB, 8679, 1), (0x2F8AC, 8680, 1), (0x2F8AD, 8681, 1), (0x2F8AE, 8682, 1), (0x2F8AF, 8683, 1), (0x2F8B0, 8684, 1), (0x2F8B1, 8685, 1), (0x2F8B2, 8686, 1),
+    (0x2F8B3, 8687, 1), (0x2F8B4, 8688, 1), (0x2F8B5, 8689, 1), (0x2F8B6, 8690, 1), (0x2F8B7, 8691, 1), (0x2F8B8, 8692, 1), (0x2F8B9, 8693, 1), (0x2F8BA, 8694, 1),
+    (0x2F8BB, 8695, 1), (0x2F8BC, 8696, 1), (0x2F8BD, 8697, 1), (0x2F8BE, 8698, 1), (0x2F8BF, 8699, 1), (0x2F8C0, 8700, 1), (0x2F8C1, 8701, 1), (0x2F8C2, 8702, 1),
+    (0x2F8C3, 8703, 1), (0x2F8C4, 8704, 1), (0x2F8C5, 8705, 1), (0x2F8C6, 8706, 1), (0x2F8C7, 8707, 1), (0x2F8C8, 8708, 1), (0x2F8C9, 8709, 1), (0x2F8CA, 8710, 1),
+    (0x2F8CB, 8711, 1), (0x2F8CC, 8712, 1), (0x2F8CD, 8713, 1), (0x2F8CE, 8714, 1), (0x2F8CF, 8715, 1), (0x2F8D0, 8716, 1), (0x2F8D1, 8717, 1), (0x2F8D2, 8718, 1),
+    (0x2F8D3, 8719, 1), (0x2F8D4, 8720, 1), (0x2F8D5, 8721, 1), (0x2F8D6, 8722, 1), (0x2F8D7, 8723, 1), (0x2F8D8, 8724, 1), (0x2F8D9, 8725, 1), (0x2F8DA, 8726, 1),
+    (0x2F8DB, 8727, 1), (0x2F8DC, 8728, 1), (0x2F8DD, 8729, 1), (0x2F8DE, 8730, 1), (0x2F8DF, 8731, 1), (0x2F8E0, 8732, 1), (0x2F8E1, 8733, 1), (0x2F8E2, 8734, 1),
+    (0x2F8E3, 8735, 1), (0x2F8E4, 8736, 1), (0x2F8E5, 8737, 1), (0x2F8E6, 8738, 1), (0x2F8E7, 8739, 1), (0x2F8E8, 8740, 1), (0x2F8E9, 8741, 1), (0x2F8EA, 8742, 1),
+    (0x2F8EB, 8743, 1), (0x2F8EC, 8744, 1), (0x2F8ED, 8745, 1), (0x2F8EE, 8746, 1), (0x2F8EF, 8747, 1), (0x2F8F0, 8748, 1), (0x2F8F1, 8749, 1), (0x2F8F2, 8750, 1),
+    (0x2F8F3, 8751, 1), (0x2F8F4, 8752, 1), (0x2F8F5, 8753, 1), (0x2F8F6, 8754, 1), (0x2F8F7, 8755, 1), (0x2F8F8, 8756, 1), (0x2F8F9, 8757, 1), (0x2F8FA, 8758, 1),
+    (0x2F8FB, 8759, 1), (0x2F8FC, 8760, 1), (0x2F8FD, 8761, 1), (0x2F8FE, 8762, 1), (0x2F8FF, 8763, 1), (0x2F900, 8764, 1), (0x2F901, 8765, 1), (0x2F902, 8766, 1),
+    (0x2F903, 8767, 1), (0x2F904, 8768, 1), (0x2F905, 8769, 1), (0x2F906, 8770, 1), (0x2F907, 8771, 1), (0x2F908, 8772, 1), (0x2F909, 8773, 1), (0x2F90A, 8774, 1),
+    (0x2F90B, 8775, 1), (0x2F90C, 8776, 1), (0x2F90D, 8777, 1), (0x2F90E, 8778, 1), (0x2F90F, 8779, 1), (0x2F910, 8780, 1), (0x2F911, 8781, 1), (0x2F912, 8782, 1),
+    (0x2F913, 8783, 1), (0x2F914, 8784, 1), (0x2F915, 8785, 1), (0x2F916, 8786, 1), (0x2F917, 8787, 1), (0x2F918, 8788, 1), (0x2F919, 8789, 1), (0x2F91A, 8790, 1),
+    (0x2F91B, 8791, 1), (0x2F91C, 8792, 1), (0x2F91D, 8793, 1), (0x2F91E, 8794, 1), (0x2F91F, 8795, 1), (0x2F920, 8796, 1), (0x2F921, 8797, 1), (0x2F922, 8798, 1),
+    (0x2F923, 8799, 1), (0x2F924, 8800, 1), (0x2F925, 8801, 1), (0x2F926, 8802, 1), (0x2F927, 8803, 1), (0x2F928, 8804, 1), (0x2F929, 8805, 1), (0x2F92A, 8806, 1),
+    (0x2F92B, 8807, 1), (0x2F92C, 8808, 1), (0x2F92D, 8809, 1), (0x2F92E, 8810, 1), (0x2F92F, 8811, 1), (0x2F930, 8812, 1), (0x2F931, 8813, 1), (0x2F932, 8814, 1),
+    (0x2F933, 8815, 1), (0x2F934, 8816, 1), (0x2F935, 8817, 1), (0x2F936, 8818, 1), (0x2F937, 8819, 1), (0x2F938, 8820, 1), (0x2F939, 8821, 1), (0x2F93A, 8822, 1),
+    (0x2F93B, 8823, 1), (0x2F93C, 8824, 1), (0x2F93D, 8825, 1), (0x2F93E, 8826, 1), (0x2F93F, 8827, 1), (0x2F940, 8828, 1), (0x2F941, 8829, 1), (0x2F942, 8830, 1),
+    (0x2F943, 8831, 1), (0x2F944, 8832, 1), (0x2F945, 8833, 1), (0x2F946, 8834, 1), (0x2F947, 8835, 1), (0x2F948, 8836, 1), (0x2F949, 8837, 1), (0x2F94A, 8838, 1),
+    (0x2F94B, 8839, 1), (0x2F94C, 8840, 1), (0x2F94D, 8841, 1), (0x2F94E, 8842, 1), (0x2F94F, 8843, 1), (0x2F950, 8844, 1), (0x2F951, 8845, 1), (0x2F952, 8846, 1),
+    (0x2F953, 8847, 1), (0x2F954, 8848, 1), (0x2F955, 8849, 1), (0x2F956, 8850, 1), (0x2F957, 8851, 1), (0x2F958, 8852, 1), (0x2F959, 8853, 1), (0x2F95A, 8854, 1),
+    (0x2F95B, 8855, 1), (0x2F95C, 8856, 1), (0x2F95D, 8857, 1), (0x2F95E, 8858, 1), (0x2F95F, 8859, 1), (0x2F960, 8860, 1), (0x2F961, 8861, 1), (0x2F962, 8862, 1),
+    (0x2F963, 8863, 1), (0x2F964, 8864, 1), (0x2F965, 8865, 1), (0x2F966, 8866, 1), (0x2F967, 8867, 1), (0x2F968, 8868, 1), (0x2F969, 8869, 1), (0x2F96A, 8870, 1),
+    (0x2F96B, 8871, 1), (0x2F96C, 8872, 1), (0x2F96D, 8873, 1), (0x2F96E, 8874, 1), (0x2F96F, 8875, 1), (0x2F970, 8876, 1), (0x2F971, 8877, 1), (0x2F972, 8878, 1),
+    (0x2F973, 8879, 1), (0x2F974, 8880, 1), (0x2F975, 8881, 1), (0x2F976, 8882, 1), (0x2F977, 8883, 1), (0x2F978, 8884, 1), (0x2F979, 8885, 1), (0x2F97A, 8886, 1),
+    (0x2F97B, 8887, 1), (0x2F97C, 8888, 1), (0x2F97D, 8889, 1), (0x2F97E, 8890, 1), (0x2F97F, 8891, 1), (0x2F980, 8892, 1), (0x2F981, 8893, 1), (0x2F982, 8894, 1),
+    (0x2F983, 8895, 1), (0x2F984, 8896, 1), (0x2F985, 8897, 1), (0x2F986, 8898, 1), (0x2F987, 8899, 1), (0x2F988, 8900, 1), (0x2F989, 8901, 1), (0x2F98A, 8902, 1),
+    (0x2F98B, 8903, 1), (0x2F98C, 8904, 1), (0x2F98D, 8905, 1), (0x2F98E, 8906, 1), (0x2F98F, 8907, 1), (0x2F990, 8908, 1), (0x2F991, 8909, 1), (0x2F992, 8910, 1),
+    (0x2F993, 8911, 1), (0x2F994, 8912, 1), (0x2F995, 8913, 1), (0x2F996, 8914, 1), (0x2F997, 8915, 1), (0x2F998, 8916, 1), (0x2F999, 8917, 1), (0x2F99A, 8918, 1),
+    (0x2F99B, 8919, 1), (0x2F99C, 8920, 1), (0x2F99D, 8921, 1), (0x2F99E, 8922, 1), (0x2F99F, 8923, 1), (0x2F9A0, 8924, 1), (0x2F9A1, 8925, 1), (0x2F9A2, 8926, 1),
+    (0x2F9A3, 8927, 1), (0x2F9A4, 8928, 1), (0x2F9A5, 8929, 1), (0x2F9A6, 8930, 1), (0x2F9A7, 8931, 1), (0x2F9A8, 8932, 1), (0x2F9A9, 8933, 1), (0x2F9AA, 8934, 1),
+    (0x2F9AB, 8935, 1), (0x2F9AC, 8936, 1), (0x2F9AD, 8937, 1), (0x2F9AE, 8938, 1), (0x2F9AF, 8939, 1), (0x2F9B0, 8940, 1), (0x2F9B1, 8941, 1), (0x2F9B2, 8942, 1),
+    (0x2F9B3, 8943, 1), (0x2F9B4, 8944, 1), (0x2F9B5, 8945, 1), (0x2F9B6, 8946, 1), (0x2F9B7, 8947, 1), (0x2F9B8, 8948, 1), (0x2F9B9, 8949, 1), (0x2F9BA, 8950, 1),
+    (0x2F9BB, 8951, 1), (0x2F9BC, 8952, 1), (0x2F9BD, 8953, 1), (0x2F9BE, 8954, 1), (0x2F9BF, 8955, 1), (0x2F9C0, 8956, 1), (0x2F9C1, 8957, 1), (0x2F9C2, 8958, 1),
+    (0x2F9C3, 8959, 1), (0x2F9C4, 8960, 1), (0x2F9C5, 8961, 1), (0x2F9C6, 8962, 1), (0x2F9C7, 8963, 1), (0x2F9C8, 8964, 1), (0x2F9C9, 8965, 1), (0x2F9CA, 8966, 1),
+    (0x2F9CB, 8967, 1), (0x2F9CC, 8968, 1), (0x2F9CD, 8969, 1), (0x2F9CE, 8970, 1), (0x2F9CF, 8971, 1), (0x2F9D0, 8972, 1), (0x2F9D1, 8973, 1), (0x2F9D2, 8974, 1),
+    (0x2F9D3, 8975, 1), (0x2F9D4, 8976, 1), (0x2F9D5, 8977, 1), (0x2F9D6, 8978, 1), (0x2F9D7, 8979, 1), (0x2F9D8, 8980, 1), (0x2F9D9, 8981, 1), (0x2F9DA, 8982, 1),
+    (0x2F9DB, 8983, 1), (0x2F9DC, 8984, 1), (0x2F9DD, 8985, 1), (0x2F9DE, 8986, 1), (0x2F9DF, 8987, 1), (0x2F9E0, 8988, 1), (0x2F9E1, 8989, 1), (0x2F9E2, 8990, 1),
+    (0x2F9E3, 8991, 1), (0x2F9E4, 8992, 1), (0x2F9E5, 8993, 1), (0x2F9E6, 8994, 1), (0x2F9E7, 8995, 1), (0x2F9E8, 8996, 1), (0x2F9E9, 8997, 1), (0x2F9EA, 8998, 1),
+    (0x2F9EB, 8999, 1), (0x2F9EC, 9000, 1), (0x2F9ED, 9001, 1), (0x2F9EE, 9002, 1), (0x2F9EF, 9003, 1), (0x2F9F0, 9004, 1), (0x2F9F1, 9005, 1), (0x2F9F2, 9006, 1),
+    (0x2F9F3, 9007, 1), (0x2F9F4, 9008, 1), (0x2F9F5, 9009, 1), (0x2F9F6, 9010, 1), (0x2F9F7, 9011, 1), (0x2F9F8, 9012, 1), (0x2F9F9, 9013, 1), (0x2F9FA, 9014, 1),
+    (0x2F9FB, 9015, 1), (0x2F9FC, 9016, 1), (0x2F9FD, 9017, 1), (0x2F9FE, 9018, 1), (0x2F9FF, 9019, 1), (0x2FA00, 9020, 1), (0x2FA01, 9021, 1), (0x2FA02, 9022, 1),
+    (0x2FA03, 9023, 1), (0x2FA04, 9024, 1), (0x2FA05, 9025, 1), (0x2FA06, 9026, 1), (0x2FA07, 9027, 1), (0x2FA08, 9028, 1), (0x2FA09, 9029, 1), (0x2FA0A, 9030, 1),
+    (0x2FA0B, 9031, 1), (0x2FA0C, 9032, 1), (0x2FA0D, 9033, 1), (0x2FA0E, 9034, 1), (0x2FA0F, 9035, 1), (0x2FA10, 9036, 1), (0x2FA11, 9037, 1), (0x2FA12, 9038, 1),
+    (0x2FA13, 9039, 1), (0x2FA14, 9040, 1), (0x2FA15, 9041, 1), (0x2FA16, 9042, 1), (0x2FA17, 9043, 1), (0x2FA18, 9044, 1), (0x2FA19, 9045, 1), (0x2FA1A, 9046, 1),
+    (0x2FA1B, 9047, 1), (0x2FA1C, 9048, 1), (0x2FA1D, 9049, 1),
+];
+
+#[rustfmt::skip]
+pub(crate) const COMPATIBILITY_CHARS: &[char] = &[
+    '\u{20}', '\u{20}', '\u{308}', '\u{61}', '\u{20}', '\u{304}', '\u{32}', '\u{33}', '\u{20}', '\u{301}',
+    '\u{3BC}', '\u{20}', '\u{327}', '\u{31}', '\u{6F}', '\u{31}', '\u{2044}', '\u{34}', '\u{31}', '\u{2044}',
+    '\u{32}', '\u{33}', '\u{2044}', '\u{34}', '\u{41}', '\u{300}', '\u{41}', '\u{301}', '\u{41}', '\u{302}',
+    '\u{41}', '\u{303}', '\u{41}', '\u{308}', '\u{41}', '\u{30A}', '\u{43}', '\u{327}', '\u{45}', '\u{300}',
+    '\u{45}', '\u{301}', '\u{45}', '\u{302}', '\u{45}', '\u{308}', '\u{49}', '\u{300}', '\u{49}', '\u{301}',
+    '\u{49}', '\u{302}', '\u{49}', '\u{308}', '\u{4E}', '\u{303}', '\u{4F}', '\u{300}', '\u{4F}', '\u{301}',
+    '\u{4F}', '\u{302}', '\u{4F}', '\u{303}', '\u{4F}', '\u{308}', '\u{55}', '\u{300}', '\u{55}', '\u{301}',
+    '\u{55}', '\u{302}', '\u{55}', '\u{308}', '\u{59}', '\u{301}', '\u{61}', '\u{300}', '\u{61}', '\u{301}',
+    '\u{61}', '\u{302}', '\u{61}', '\u{303}', '\u{61}', '\u{308}', '\u{61}', '\u{30A}', '\u{63}', '\u{327}',
+    '\u{65}', '\u{300}', '\u{65}', '\u{301}', '\u{65}', '\u{302}', '\u{65}', '\u{308}', '\u{69}', '\u{300}',
+    '\u{69}', '\u{301}', '\u{69}', '\u{302}', '\u{69}', '\u{308}', '\u{6E}', '\u{303}', '\u{6F}', '\u{300}',
+    '\u{6F}', '\u{301}', '\u{6F}', '\u{302}', '\u{6F}', '\u{303}', '\u{6F}', '\u{308}', '\u{75}', '\u{300}',
+    '\u{75}', '\u{301}', '\u{75}', '\u{302}', '\u{75}', '\u{308}', '\u{79}', '\u{301}', '\u{79}', '\u{308}',
+    '\u{41}', '\u{304}', '\u{61}', '\u{304}', '\u{41}', '\u{306}', '\u{61}', '\u{306}', '\u{41}', '\u{328}',
+    '\u{61}', '\u{328}', '\u{43}', '\u{301}', '\u{63}', '\u{301}', '\u{43}', '\u{302}', '\u{63}', '\u{302}',
+    '\u{43}', '\u{307}', '\u{63}', '\u{307}', '\u{43}', '\u{30C}', '\u{63}', '\u{30C}', '\u{44}', '\u{30C}',
+    '\u{64}', '\u{30C}', '\u{45}', '\u{304}', '\u{65}', '\u{304}', '\u{45}', '\u{306}', '\u{65}', '\u{306}',
+    '\u{45}', '\u{307}', '\u{65}', '\u{307}', '\u{45}', '\u{328}', '\u{65}', '\u{328}', '\u{45}', '\u{30C}',
+    '\u{65}', '\u{30C}', '\u{47}', '\u{302}', '\u{67}', '\u{302}', '\u{47}', '\u{306}', '\u{67}', '\u{306}',
+    '\u{47}', '\u{307}', '\u{67}', '\u{307}', '\u{47}', '\u{327}', '\u{67}', '\u{327}', '\u{48}', '\u{302}',
+    '\u{68}', '\u{302}', '\u{49}', '\u{303}', '\u{69}', '\u{303}', '\u{49}', '\u{304}', '\u{69}', '\u{304}',
+    '\u{49}', '\u{306}', '\u{69}', '\u{306}', '\u{49}', '\u{328}', '\u{69}', '\u{328}', '\u{49}', '\u{307}',
+    '\u{49}', '\u{4A}', '\u{69}', '\u{6A}', '\u{4A}', '\u{302}', '\u{6A}', '\u{302}', '\u{4B}', '\u{327}',
+    '\u{6B}', '\u{327}', '\u{4C}', '\u{301}', '\u{6C}', '\u{301}', '\u{4C}', '\u{327}', '\u{6C}', '\u{327}',
+    '\u{4C}', '\u{30C}', '\u{6C}', '\u{30C}', '\u{4C}', '\u{B7}', '\u{6C}', '\u{B7}', '\u{4E}', '\u{301}',
+    '\u{6E}', '\u{301}', '\u{4E}', '\u{327}', '\u{6E}', '\u{327}', '\u{4E}', '\u{30C}', '\u{6E}', '\u{30C}',
+    '\u{2BC}', '\u{6E}', '\u{4F}', '\u{304}', '\u{6F}', '\u{304}', '\u{4F}', '\u{306}', '\u{6F}', '\u{306}',
+    '\u{4F}', '\u{30B}', '\u{6F}', '\u{30B}', '\u{52}', '\u{301}', '\u{72}', '\u{301}', '\u{52}', '\u{327}',
+    '\u{72}', '\u{327}', '\u{52}', '\u{30C}', '\u{72}', '\u{30C}', '\u{53}', '\u{301}', '\u{73}', '\u{301}',
+    '\u{53}', '\u{302}', '\u{73}', '\u{302}', '\u{53}', '\u{327}', '\u{73}', '\u{327}', '\u{53}', '\u{30C}',
+    '\u{73}', '\u{30C}', '\u{54}', '\u{327}', '\u{74}', '\u{327}', '\u{54}', '\u{30C}', '\u{74}', '\u{30C}',
+    '\u{55}', '\u{303}', '\u{75}', '\u{303}', '\u{55}', '\u{304}', '\u{75}', '\u{304}', '\u{55}', '\u{306}',
+    '\u{75}', '\u{306}', '\u{55}', '\u{30A}', '\u{75}', '\u{30A}', '\u{55}', '\u{30B}', '\u{75}', '\u{30B}',
+    '\u{55}', '\u{328}', '\u{75}', '\u{328}', '\u{57}', '\u{302}', '\u{77}', '\u{302}', '\u{59}', '\u{302}',
+    '\u{79}', '\u{302}', '\u{59}', '\u{308}', '\u{5A}', '\u{301}', '\u{7A}', '\u{301}', '\u{5A}', '\u{307}',
+    '\u{7A}', '\u{307}', '\u{5A}', '\u{30C}', '\u{7A}', '\u{30C}', '\u{73}', '\u{4F}', '\u{31B}', '\u{6F}',
+    '\u{31B}', '\u{55}', '\u{31B}', '\u{75}', '\u{31B}', '\u{44}', '\u{5A}', '\u{30C}', '\u{44}', '\u{7A}',
+    '\u{30C}', '\u{64}', '\u{7A}', '\u{30C}', '\u{4C}', '\u{4A}', '\u{4C}', '\u{6A}', '\u{6C}', '\u{6A}',
+    '\u{4E}', '\u{4A}', '\u{4E}', '\u{6A}', '\u{6E}', '\u{6A}', '\u{41}', '\u{30C}', '\u{61}', '\u{30C}',
+    '\u{49}', '\u{30C}', '\u{69}', '\u{30C}', '\u{4F}', '\u{30C}', '\u{6F}', '\u{30C}', '\u{55}', '\u{30C}',
+    '\u{75}', '\u{30C}', '\u{55}', '\u{308}', '\u{304}', '\u{75}', '\u{308}', '\u{304}', '\u{55}', '\u{308}',
+    '\u{301}', '\u{75}', '\u{308}', '\u{301}', '\u{55}', '\u{308}', '\u{30C}', '\u{75}', '\u{308}', '\u{30C}',
+    '\u{55}', '\u{308}', '\u{300}', '\u{75}', '\u{308}', '\u{300}', '\u{41}', '\u{308}', '\u{304}', '\u{61}',
+    '\u{308}', '\u{304}', '\u{41}', '\u{307}', '\u{304}', '\u{61}', '\u{307}', '\u{304}', '\u{C6}', '\u{304}',
+    '\u{E6}', '\u{304}', '\u{47}', '\u{30C}', '\u{67}', '\u{30C}', '\u{4B}', '\u{30C}', '\u{6B}', '\u{30C}',
+    '\u{4F}', '\u{328}', '\u{6F}', '\u{328}', '\u{4F}', '\u{328}', '\u{304}', '\u{6F}', '\u{328}', '\u{304}',
+    '\u{1B7}', '\u{30C}', '\u{292}', '\u{30C}', '\u{6A}', '\u{30C}', '\u{44}', '\u{5A}', '\u{44}', '\u{7A}',
+    '\u{64}', '\u{7A}', '\u{47}', '\u{301}', '\u{67}', '\u{301}', '\u{4E}', '\u{300}', '\u{6E}', '\u{300}',
+    '\u{41}', '\u{30A}', '\u{301}', '\u{61}', '\u{30A}', '\u{301}', '\u{C6}', '\u{301}', '\u{E6}', '\u{301}',
+    '\u{D8}', '\u{301}', '\u{F8}', '\u{301}', '\u{41}', '\u{30F}', '\u{61}', '\u{30F}', '\u{41}', '\u{311}',
+    '\u{61}', '\u{311}', '\u{45}', '\u{30F}', '\u{65}', '\u{30F}', '\u{45}', '\u{311}', '\u{65}', '\u{311}',
+    '\u{49}', '\u{30F}', '\u{69}', '\u{30F}', '\u{49}', '\u{311}', '\u{69}', '\u{311}', '\u{4F}', '\u{30F}',
+    '\u{6F}', '\u{30F}', '\u{4F}', '\u{311}', '\u{6F}', '\u{311}', '\u{52}', '\u{30F}', '\u{72}', '\u{30F}',
+    '\u{52}', '\u{311}', '\u{72}', '\u{311}', '\u{55}', '\u{30F}', '\u{75}', '\u{30F}', '\u{55}', '\u{311}',
+    '\u{75}', '\u{311}', '\u{53}', '\u{326}', '\u{73}', '\u{326}', '\u{54}', '\u{326}', '\u{74}', '\u{326}',
+    '\u{48}', '\u{30C}', '\u{68}', '\u{30C}', '\u{41}', '\u{307}', '\u{61}', '\u{307}', '\u{45}', '\u{327}',
+    '\u{65}', '\u{327}', '\u{4F}', '\u{308}', '\u{304}', '\u{6F}', '\u{308}', '\u{304}', '\u{4F}', '\u{303}',
+    '\u{304}', '\u{6F}', '\u{303}', '\u{304}', '\u{4F}', '\u{307}', '\u{6F}', '\u{307}', '\u{4F}', '\u{307}',
+    '\u{304}', '\u{6F}', '\u{307}', '\u{304}', '\u{59}', '\u{304}', '\u{79}', '\u{304}', '\u{68}', '\u{266}',
+    '\u{6A}', '\u{72}', '\u{279}', '\u{27B}', '\u{281}', '\u{77}', '\u{79}', '\u{20}', '\u{306}', '\u{20}',
+    '\u{307}', '\u{20}', '\u{30A}', '\u{20}', '\u{328}', '\u{20}', '\u{303}', '\u{20}', '\u{30B}', '\u{263}',
+    '\u{6C}', '\u{73}', '\u{78}', '\u{295}', '\u{300}', '\u{301}', '\u{313}', '\u{308}', '\u{301}', '\u{2B9}',
+    '\u{20}', '\u{345}', '\u{3B}', '\u{20}', '\u{301}', '\u{20}', '\u{308}', '\u{301}', '\u{391}', '\u{301}',
+    '\u{B7}', '\u{395}', '\u{301}', '\u{397}', '\u{301}', '\u{399}', '\u{301}', '\u{39F}', '\u{301}', '\u{3A5}',
+    '\u{301}', '\u{3A9}', '\u{301}', '\u{3B9}', '\u{308}', '\u{301}', '\u{399}', '\u{308}', '\u{3A5}', '\u{308}',
+    '\u{3B1}', '\u{301}', '\u{3B5}', '\u{301}', '\u{3B7}', '\u{301}', '\u{3B9}', '\u{301}', '\u{3C5}', '\u{308}',
+    '\u{301}', '\u{3B9}', '\u{308}', '\u{3C5}', '\u{308}', '\u{3BF}', '\u{301}', '\u{3C5}', '\u{301}', '\u{3C9}',
+    '\u{301}', '\u{3B2}', '\u{3B8}', '\u{3A5}', '\u{3A5}', '\u{301}', '\u{3A5}', '\u{308}', '\u{3C6}', '\u{3C0}',
+    '\u{3BA}', '\u{3C1}', '\u{3C2}', '\u{398}', '\u{3B5}', '\u{3A3}', '\u{415}', '\u{300}', '\u{415}', '\u{308}',
+    '\u{413}', '\u{301}', '\u{406}', '\u{308}', '\u{41A}', '\u{301}', '\u{418}', '\u{300}', '\u{423}', '\u{306}',
+    '\u{418}', '\u{306}', '\u{438}', '\u{306}', '\u{435}', '\u{300}', '\u{435}', '\u{308}', '\u{433}', '\u{301}',
+    '\u{456}', '\u{308}', '\u{43A}', '\u{301}', '\u{438}', '\u{300}', '\u{443}', '\u{306}', '\u{474}', '\u{30F}',
+    '\u{475}', '\u{30F}', '\u{416}', '\u{306}', '\u{436}', '\u{306}', '\u{410}', '\u{306}', '\u{430}', '\u{306}',
+    '\u{410}', '\u{308}', '\u{430}', '\u{308}', '\u{415}', '\u{306}', '\u{435}', '\u{306}', '\u{4D8}', '\u{308}',
+    '\u{4D9}', '\u{308}', '\u{416}', '\u{308}', '\u{436}', '\u{308}', '\u{417}', '\u{308}', '\u{437}', '\u{308}',
+    '\u{418}', '\u{304}', '\u{438}', '\u{304}', '\u{418}', '\u{308}', '\u{438}', '\u{308}', '\u{41E}', '\u{308}',
+    '\u{43E}', '\u{308}', '\u{4E8}', '\u{308}', '\u{4E9}', '\u{308}', '\u{42D}', '\u{308}', '\u{44D}', '\u{308}',
+    '\u{423}', '\u{304}', '\u{443}', '\u{304}', '\u{423}', '\u{308}', '\u{443}', '\u{308}', '\u{423}', '\u{30B}',
+    '\u{443}', '\u{30B}', '\u{427}', '\u{308}', '\u{447}', '\u{308}', '\u{42B}', '\u{308}', '\u{44B}', '\u{308}',
+    '\u{565}', '\u{582}', '\u{627}', '\u{653}', '\u{627}', '\u{654}', '\u{648}', '\u{654}', '\u{627}', '\u{655}',
+    '\u{64A}', '\u{654}', '\u{627}', '\u{674}', '\u{648}', '\u{674}', '\u{6C7}', '\u{674}', '\u{64A}', '\u{674}',
+    '\u{6D5}', '\u{654}', '\u{6C1}', '\u{654}', '\u{6D2}', '\u{654}', '\u{928}', '\u{93C}', '\u{930}', '\u{93C}',
+    '\u{933}', '\u{93C}', '\u{915}', '\u{93C}', '\u{916}', '\u{93C}', '\u{917}', '\u{93C}', '\u{91C}', '\u{93C}',
+    '\u{921}', '\u{93C}', '\u{922}', '\u{93C}', '\u{92B}', '\u{93C}', '\u{92F}', '\u{93C}', '\u{9C7}', '\u{9BE}',
+    '\u{9C7}', '\u{9D7}', '\u{9A1}', '\u{9BC}', '\u{9A2}', '\u{9BC}', '\u{9AF}', '\u{9BC}', '\u{A32}', '\u{A3C}',
+    '\u{A38}', '\u{A3C}', '\u{A16}', '\u{A3C}', '\u{A17}', '\u{A3C}', '\u{A1C}', '\u{A3C}', '\u{A2B}', '\u{A3C}',
+    '\u{B47}', '\u{B56}', '\u{B47}', '\u{B3E}', '\u{B47}', '\u{B57}', '\u{B21}', '\u{B3C}', '\u{B22}', '\u{B3C}',
+    '\u{B92}', '\u{BD7}', '\u{BC6}', '\u{BBE}', '\u{BC7}', '\u{BBE}', '\u{BC6}', '\u{BD7}', '\u{C46}', '\u{C56}',
+    '\u{CBF}', '\u{CD5}', '\u{CC6}', '\u{CD5}', '\u{CC6}', '\u{CD6}', '\u{CC6}', '\u{CC2}', '\u{CC6}', '\u{CC2}',
+    '\u{CD5}', '\u{D46}', '\u{D3E}', '\u{D47}', '\u{D3E}', '\u{D46}', '\u{D57}', '\u{DD9}', '\u{DCA}', '\u{DD9}',
+    '\u{DCF}', '\u{DD9}', '\u{DCF}', '\u{DCA}', '\u{DD9}', '\u{DDF}', '\u{E4D}', '\u{E32}', '\u{ECD}', '\u{EB2}',
+    '\u{EAB}', '\u{E99}', '\u{EAB}', '\u{EA1}', '\u{F0B}', '\u{F42}', '\u{FB7}', '\u{F4C}', '\u{FB7}', '\u{F51}',
+    '\u{FB7}', '\u{F56}', '\u{FB7}', '\u{F5B}', '\u{FB7}', '\u{F40}', '\u{FB5}', '\u{F71}', '\u{F72}', '\u{F71}',
+    '\u{F74}', '\u{FB2}', '\u{F80}', '\u{FB2}', '\u{F71}', '\u{F80}', '\u{FB3}', '\u{F80}', '\u{FB3}', '\u{F71}',
+    '\u{F80}', '\u{F71}', '\u{F80}', '\u{F92}', '\u{FB7}', '\u{F9C}', '\u{FB7}', '\u{FA1}', '\u{FB7}', '\u{FA6}',
+    '\u{FB7}', '\u{FAB}', '\u{FB7}', '\u{F90}', '\u{FB5}', '\u{1025}', '\u{102E}', '\u{10DC}', '\u{1B05}', '\u{1B35}',
+    '\u{1B07}', '\u{1B35}', '\u{1B09}', '\u{1B35}', '\u{1B0B}', '\u{1B35}', '\u{1B0D}', '\u{1B35}', '\u{1B11}', '\u{1B35}',
+    '\u{1B3A}', '\u{1B35}', '\u{1B3C}', '\u{1B35}', '\u{1B3E}', '\u{1B35}', '\u{1B3F}', '\u{1B35}', '\u{1B42}', '\u{1B35}',
+    '\u{41}', '\u{C6}', '\u{42}', '\u{44}', '\u{45}', '\u{18E}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}',
+    '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{222}', '\u{50}', '\u{52}', '\u{54}', '\u{55}',
+    '\u{57}', '\u{61}', '\u{250}', '\u{251}', '\u{1D02}', '\u{62}', '\u{64}', '\u{65}', '\u{259}', '\u{25B}',
+    '\u{25C}', '\u{67}', '\u{6B}', '\u{6D}', '\u{14B}', '\u{6F}', '\u{254}', '\u{1D16}', '\u{1D17}', '\u{70}',
+    '\u{74}', '\u{75}', '\u{1D1D}', '\u{26F}', '\u{76}', '\u{1D25}', '\u{3B2}', '\u{3B3}', '\u{3B4}', '\u{3C6}',
+    '\u{3C7}', '\u{69}', '\u{72}', '\u{75}', '\u{76}', '\u{3B2}', '\u{3B3}', '\u{3C1}', '\u{3C6}', '\u{3C7}',
+    '\u{43D}', '\u{252}', '\u{63}', '\u{255}', '\u{F0}', '\u{25C}', '\u{66}', '\u{25F}', '\u{261}', '\u{265}',
+    '\u{268}', '\u{269}', '\u{26A}', '\u{1D7B}', '\u{29D}', '\u{26D}', '\u{1D85}', '\u{29F}', '\u{271}', '\u{270}',
+    '\u{272}', '\u{273}', '\u{274}', '\u{275}', '\u{278}', '\u{282}', '\u{283}', '\u{1AB}', '\u{289}', '\u{28A}',
+    '\u{1D1C}', '\u{28B}', '\u{28C}', '\u{7A}', '\u{290}', '\u{291}', '\u{292}', '\u{3B8}', '\u{41}', '\u{325}',
+    '\u{61}', '\u{325}', '\u{42}', '\u{307}', '\u{62}', '\u{307}', '\u{42}', '\u{323}', '\u{62}', '\u{323}',
+    '\u{42}', '\u{331}', '\u{62}', '\u{331}', '\u{43}', '\u{327}', '\u{301}', '\u{63}', '\u{327}', '\u{301}',
+    '\u{44}', '\u{307}', '\u{64}', '\u{307}', '\u{44}', '\u{323}', '\u{64}', '\u{323}', '\u{44}', '\u{331}',
+    '\u{64}', '\u{331}', '\u{44}', '\u{327}', '\u{64}', '\u{327}', '\u{44}', '\u{32D}', '\u{64}', '\u{32D}',
+    '\u{45}', '\u{304}', '\u{300}', '\u{65}', '\u{304}', '\u{300}', '\u{45}', '\u{304}', '\u{301}', '\u{65}',
+    '\u{304}', '\u{301}', '\u{45}', '\u{32D}', '\u{65}', '\u{32D}', '\u{45}', '\u{330}', '\u{65}', '\u{330}',
+    '\u{45}', '\u{327}', '\u{306}', '\u{65}', '\u{327}', '\u{306}', '\u{46}', '\u{307}', '\u{66}', '\u{307}',
+    '\u{47}', '\u{304}', '\u{67}', '\u{304}', '\u{48}', '\u{307}', '\u{68}', '\u{307}', '\u{48}', '\u{323}',
+    '\u{68}', '\u{323}', '\u{48}', '\u{308}', '\u{68}', '\u{308}', '\u{48}', '\u{327}', '\u{68}', '\u{327}',
+    '\u{48}', '\u{32E}', '\u{68}', '\u{32E}', '\u{49}', '\u{330}', '\u{69}', '\u{330}', '\u{49}', '\u{308}',
+    '\u{301}', '\u{69}', '\u{308}', '\u{301}', '\u{4B}', '\u{301}', '\u{6B}', '\u{301}', '\u{4B}', '\u{323}',
+    '\u{6B}', '\u{323}', '\u{4B}', '\u{331}', '\u{6B}', '\u{331}', '\u{4C}', '\u{323}', '\u{6C}', '\u{323}',
+    '\u{4C}', '\u{323}', '\u{304}', '\u{6C}', '\u{323}', '\u{304}', '\u{4C}', '\u{331}', '\u{6C}', '\u{331}',
+    '\u{4C}', '\u{32D}', '\u{6C}', '\u{32D}', '\u{4D}', '\u{301}', '\u{6D}', '\u{301}', '\u{4D}', '\u{307}',
+    '\u{6D}', '\u{307}', '\u{4D}', '\u{323}', '\u{6D}', '\u{323}', '\u{4E}', '\u{307}', '\u{6E}', '\u{307}',
+    '\u{4E}', '\u{323}', '\u{6E}', '\u{323}', '\u{4E}', '\u{331}', '\u{6E}', '\u{331}', '\u{4E}', '\u{32D}',
+    '\u{6E}', '\u{32D}', '\u{4F}', '\u{303}', '\u{301}', '\u{6F}', '\u{303}', '\u{301}', '\u{4F}', '\u{303}',
+    '\u{308}', '\u{6F}', '\u{303}', '\u{308}', '\u{4F}', '\u{304}', '\u{300}', '\u{6F}', '\u{304}', '\u{300}',
+    '\u{4F}', '\u{304}', '\u{301}', '\u{6F}', '\u{304}', '\u{301}', '\u{50}', '\u{301}', '\u{70}', '\u{301}',
+    '\u{50}', '\u{307}', '\u{70}', '\u{307}', '\u{52}', '\u{307}', '\u{72}', '\u{307}', '\u{52}', '\u{323}',
+    '\u{72}', '\u{323}', '\u{52}', '\u{323}', '\u{304}', '\u{72}', '\u{323}', '\u{304}', '\u{52}', '\u{331}',
+    '\u{72}', '\u{331}', '\u{53}', '\u{307}', '\u{73}', '\u{307}', '\u{53}', '\u{323}', '\u{73}', '\u{323}',
+    '\u{53}', '\u{301}', '\u{307}', '\u{73}', '\u{301}', '\u{307}', '\u{53}', '\u{30C}', '\u{307}', '\u{73}',
+    '\u{30C}', '\u{307}', '\u{53}', '\u{323}', '\u{307}', '\u{73}', '\u{323}', '\u{307}', '\u{54}', '\u{307}',
+    '\u{74}', '\u{307}', '\u{54}', '\u{323}', '\u{74}', '\u{323}', '\u{54}', '\u{331}', '\u{74}', '\u{331}',
+    '\u{54}', '\u{32D}', '\u{74}', '\u{32D}', '\u{55}', '\u{324}', '\u{75}', '\u{324}', '\u{55}', '\u{330}',
+    '\u{75}', '\u{330}', '\u{55}', '\u{32D}', '\u{75}', '\u{32D}', '\u{55}', '\u{303}', '\u{301}', '\u{75}',
+    '\u{303}', '\u{301}', '\u{55}', '\u{304}', '\u{308}', '\u{75}', '\u{304}', '\u{308}', '\u{56}', '\u{303}',
+    '\u{76}', '\u{303}', '\u{56}', '\u{323}', '\u{76}', '\u{323}', '\u{57}', '\u{300}', '\u{77}', '\u{300}',
+    '\u{57}', '\u{301}', '\u{77}', '\u{301}', '\u{57}', '\u{308}', '\u{77}', '\u{308}', '\u{57}', '\u{307}',
+    '\u{77}', '\u{307}', '\u{57}', '\u{323}', '\u{77}', '\u{323}', '\u{58}', '\u{307}', '\u{78}', '\u{307}',
+    '\u{58}', '\u{308}', '\u{78}', '\u{308}', '\u{59}', '\u{307}', '\u{79}', '\u{307}', '\u{5A}', '\u{302}',
+    '\u{7A}', '\u{302}', '\u{5A}', '\u{323}', '\u{7A}', '\u{323}', '\u{5A}', '\u{331}', '\u{7A}', '\u{331}',
+    '\u{68}', '\u{331}', '\u{74}', '\u{308}', '\u{77}', '\u{30A}', '\u{79}', '\u{30A}', '\u{61}', '\u{2BE}',
+    '\u{73}', '\u{307}', '\u{41}', '\u{323}', '\u{61}', '\u{323}', '\u{41}', '\u{309}', '\u{61}', '\u{309}',
+    '\u{41}', '\u{302}', '\u{301}', '\u{61}', '\u{302}', '\u{301}', '\u{41}', '\u{302}', '\u{300}', '\u{61}',
+    '\u{302}', '\u{300}', '\u{41}', '\u{302}', '\u{309}', '\u{61}', '\u{302}', '\u{309}', '\u{41}', '\u{302}',
+    '\u{303}', '\u{61}', '\u{302}', '\u{303}', '\u{41}', '\u{323}', '\u{302}', '\u{61}', '\u{323}', '\u{302}',
+    '\u{41}', '\u{306}', '\u{301}', '\u{61}', '\u{306}', '\u{301}', '\u{41}', '\u{306}', '\u{300}', '\u{61}',
+    '\u{306}', '\u{300}', '\u{41}', '\u{306}', '\u{309}', '\u{61}', '\u{306}', '\u{309}', '\u{41}', '\u{306}',
+    '\u{303}', '\u{61}', '\u{306}', '\u{303}', '\u{41}', '\u{323}', '\u{306}', '\u{61}', '\u{323}', '\u{306}',
+    '\u{45}', '\u{323}', '\u{65}', '\u{323}', '\u{45}', '\u{309}', '\u{65}', '\u{309}', '\u{45}', '\u{303}',
+    '\u{65}', '\u{303}', '\u{45}', '\u{302}', '\u{301}', '\u{65}', '\u{302}', '\u{301}', '\u{45}', '\u{302}',
+    '\u{300}', '\u{65}', '\u{302}', '\u{300}', '\u{45}', '\u{302}', '\u{309}', '\u{65}', '\u{302}', '\u{309}',
+    '\u{45}', '\u{302}', '\u{303}', '\u{65}', '\u{302}', '\u{303}', '\u{45}', '\u{323}', '\u{302}', '\u{65}',
+    '\u{323}', '\u{302}', '\u{49}', '\u{309}', '\u{69}', '\u{309}', '\u{49}', '\u{323}', '\u{69}', '\u{323}',
+    '\u{4F}', '\u{323}', '\u{6F}', '\u{323}', '\u{4F}', '\u{309}', '\u{6F}', '\u{309}', '\u{4F}', '\u{302}',
+    '\u{301}', '\u{6F}', '\u{302}', '\u{301}', '\u{4F}', '\u{302}', '\u{300}', '\u{6F}', '\u{302}', '\u{300}',
+    '\u{4F}', '\u{302}', '\u{309}', '\u{6F}', '\u{302}', '\u{309}', '\u{4F}', '\u{302}', '\u{303}', '\u{6F}',
+    '\u{302}', '\u{303}', '\u{4F}', '\u{323}', '\u{302}', '\u{6F}', '\u{323}', '\u{302}', '\u{4F}', '\u{31B}',
+    '\u{301}', '\u{6F}', '\u{31B}', '\u{301}', '\u{4F}', '\u{31B}', '\u{300}', '\u{6F}', '\u{31B}', '\u{300}',
+    '\u{4F}', '\u{31B}', '\u{309}', '\u{6F}', '\u{31B}', '\u{309}', '\u{4F}', '\u{31B}', '\u{303}', '\u{6F}',
+    '\u{31B}', '\u{303}', '\u{4F}', '\u{31B}', '\u{323}', '\u{6F}', '\u{31B}', '\u{323}', '\u{55}', '\u{323}',
+    '\u{75}', '\u{323}', '\u{55}', '\u{309}', '\u{75}', '\u{309}', '\u{55}', '\u{31B}', '\u{301}', '\u{75}',
+    '\u{31B}', '\u{301}', '\u{55}', '\u{31B}', '\u{300}', '\u{75}', '\u{31B}', '\u{300}', '\u{55}', '\u{31B}',
+    '\u{309}', '\u{75}', '\u{31B}', '\u{309}', '\u{55}', '\u{31B}', '\u{303}', '\u{75}', '\u{31B}', '\u{303}',
+    '\u{55}', '\u{31B}', '\u{323}', '\u{75}', '\u{31B}', '\u{323}', '\u{59}', '\u{300}', '\u{79}', '\u{300}',
+    '\u{59}', '\u{323}', '\u{79}', '\u{323}', '\u{59}', '\u{309}', '\u{79}', '\u{309}', '\u{59}', '\u{303}',
+    '\u{79}', '\u{303}', '\u{3B1}', '\u{313}', '\u{3B1}', '\u{314}', '\u{3B1}', '\u{313}', '\u{300}', '\u{3B1}',
+    '\u{314}', '\u{300}', '\u{3B1}', '\u{313}', '\u{301}', '\u{3B1}', '\u{314}', '\u{301}', '\u{3B1}', '\u{313}',
+    '\u{342}', '\u{3B1}', '\u{314}', '\u{342}', '\u{391}', '\u{313}', '\u{391}', '\u{314}', '\u{391}', '\u{313}',
+    '\u{300}', '\u{391}', '\u{314}', '\u{300}', '\u{391}', '\u{313}', '\u{301}', '\u{391}', '\u{314}', '\u{301}',
+    '\u{391}', '\u{313}', '\u{342}', '\u{391}', '\u{314}', '\u{342}', '\u{3B5}', '\u{313}', '\u{3B5}', '\u{314}',
+    '\u{3B5}', '\u{313}', '\u{300}', '\u{3B5}', '\u{314}', '\u{300}', '\u{3B5}', '\u{313}', '\u{301}', '\u{3B5}',
+    '\u{314}', '\u{301}', '\u{395}', '\u{313}', '\u{395}', '\u{314}', '\u{395}', '\u{313}', '\u{300}', '\u{395}',
+    '\u{314}', '\u{300}', '\u{395}', '\u{313}', '\u{301}', '\u{395}', '\u{314}', '\u{301}', '\u{3B7}', '\u{313}',
+    '\u{3B7}', '\u{314}', '\u{3B7}', '\u{313}', '\u{300}', '\u{3B7}', '\u{314}', '\u{300}', '\u{3B7}', '\u{313}',
+    '\u{301}', '\u{3B7}', '\u{314}', '\u{301}', '\u{3B7}', '\u{313}', '\u{342}', '\u{3B7}', '\u{314}', '\u{342}',
+    '\u{397}', '\u{313}', '\u{397}', '\u{314}', '\u{397}', '\u{313}', '\u{300}', '\u{397}', '\u{314}', '\u{300}',
+    '\u{397}', '\u{313}', '\u{301}', '\u{397}', '\u{314}', '\u{301}', '\u{397}', '\u{313}', '\u{342}', '\u{397}',
+    '\u{314}', '\u{342}', '\u{3B9}', '\u{313}', '\u{3B9}', '\u{314}', '\u{3B9}', '\u{313}', '\u{300}', '\u{3B9}',
+    '\u{314}', '\u{300}', '\u{3B9}', '\u{313}', '\u{301}', '\u{3B9}', '\u{314}', '\u{301}', '\u{3B9}', '\u{313}',
+    '\u{342}', '\u{3B9}', '\u{314}', '\u{342}', '\u{399}', '\u{313}', '\u{399}', '\u{314}', '\u{399}', '\u{313}',
+    '\u{300}', '\u{399}', '\u{314}', '\u{300}', '\u{399}', '\u{313}', '\u{301}', '\u{399}', '\u{314}', '\u{301}',
+    '\u{399}', '\u{313}', '\u{342}', '\u{399}', '\u{314}', '\u{342}', '\u{3BF}', '\u{313}', '\u{3BF}', '\u{314}',
+    '\u{3BF}', '\u{313}', '\u{300}', '\u{3BF}', '\u{314}', '\u{300}', '\u{3BF}', '\u{313}', '\u{301}', '\u{3BF}',
+    '\u{314}', '\u{301}', '\u{39F}', '\u{313}', '\u{39F}', '\u{314}', '\u{39F}', '\u{313}', '\u{300}', '\u{39F}',
+    '\u{314}', '\u{300}', '\u{39F}', '\u{313}', '\u{301}', '\u{39F}', '\u{314}', '\u{301}', '\u{3C5}', '\u{313}',
+    '\u{3C5}', '\u{314}', '\u{3C5}', '\u{313}', '\u{300}', '\u{3C5}', '\u{314}', '\u{300}', '\u{3C5}', '\u{313}',
+    '\u{301}', '\u{3C5}', '\u{314}', '\u{301}', '\u{3C5}', '\u{313}', '\u{342}', '\u{3C5}', '\u{314}', '\u{342}',
+    '\u{3A5}', '\u{314}', '\u{3A5}', '\u{314}', '\u{300}', '\u{3A5}', '\u{314}', '\u{301}', '\u{3A5}', '\u{314}',
+    '\u{342}', '\u{3C9}', '\u{313}', '\u{3C9}', '\u{314}', '\u{3C9}', '\u{313}', '\u{300}', '\u{3C9}', '\u{314}',
+    '\u{300}', '\u{3C9}', '\u{313}', '\u{301}', '\u{3C9}', '\u{314}', '\u{301}', '\u{3C9}', '\u{313}', '\u{342}',
+    '\u{3C9}', '\u{314}', '\u{342}', '\u{3A9}', '\u{313}', '\u{3A9}', '\u{314}', '\u{3A9}', '\u{313}', '\u{300}',
+    '\u{3A9}', '\u{314}', '\u{300}', '\u{3A9}', '\u{313}', '\u{301}', '\u{3A9}', '\u{314}', '\u{301}', '\u{3A9}',
+    '\u{313}', '\u{342}', '\u{3A9}', '\u{314}', '\u{342}', '\u{3B1}', '\u{300}', '\u{3B1}', '\u{301}', '\u{3B5}',
+    '\u{300}', '\u{3B5}', '\u{301}', '\u{3B7}', '\u{300}', '\u{3B7}', '\u{301}', '\u{3B9}', '\u{300}', '\u{3B9}',
+    '\u{301}', '\u{3BF}', '\u{300}', '\u{3BF}', '\u{301}', '\u{3C5}', '\u{300}', '\u{3C5}', '\u{301}', '\u{3C9}',
+    '\u{300}', '\u{3C9}', '\u{301}', '\u{3B1}', '\u{313}', '\u{345}', '\u{3B1}', '\u{314}', '\u{345}', '\u{3B1}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{3B1}', '\u{314}', '\u{300}', '\u{345}', '\u{3B1}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{3B1}', '\u{314}', '\u{301}', '\u{345}', '\u{3B1}', '\u{313}', '\u{342}', '\u{345}', '\u{3B1}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{391}', '\u{313}', '\u{345}', '\u{391}', '\u{314}', '\u{345}', '\u{391}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{391}', '\u{314}', '\u{300}', '\u{345}', '\u{391}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{391}', '\u{314}', '\u{301}', '\u{345}', '\u{391}', '\u{313}', '\u{342}', '\u{345}', '\u{391}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{3B7}', '\u{313}', '\u{345}', '\u{3B7}', '\u{314}', '\u{345}', '\u{3B7}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{3B7}', '\u{314}', '\u{300}', '\u{345}', '\u{3B7}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{3B7}', '\u{314}', '\u{301}', '\u{345}', '\u{3B7}', '\u{313}', '\u{342}', '\u{345}', '\u{3B7}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{397}', '\u{313}', '\u{345}', '\u{397}', '\u{314}', '\u{345}', '\u{397}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{397}', '\u{314}', '\u{300}', '\u{345}', '\u{397}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{397}', '\u{314}', '\u{301}', '\u{345}', '\u{397}', '\u{313}', '\u{342}', '\u{345}', '\u{397}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{3C9}', '\u{313}', '\u{345}', '\u{3C9}', '\u{314}', '\u{345}', '\u{3C9}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{3C9}', '\u{314}', '\u{300}', '\u{345}', '\u{3C9}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{3C9}', '\u{314}', '\u{301}', '\u{345}', '\u{3C9}', '\u{313}', '\u{342}', '\u{345}', '\u{3C9}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{3A9}', '\u{313}', '\u{345}', '\u{3A9}', '\u{314}', '\u{345}', '\u{3A9}',
+    '\u{313}', '\u{300}', '\u{345}', '\u{3A9}', '\u{314}', '\u{300}', '\u{345}', '\u{3A9}', '\u{313}', '\u{301}',
+    '\u{345}', '\u{3A9}', '\u{314}', '\u{301}', '\u{345}', '\u{3A9}', '\u{313}', '\u{342}', '\u{345}', '\u{3A9}',
+    '\u{314}', '\u{342}', '\u{345}', '\u{3B1}', '\u{306}', '\u{3B1}', '\u{304}', '\u{3B1}', '\u{300}', '\u{345}',
+    '\u{3B1}', '\u{345}', '\u{3B1}', '\u{301}', '\u{345}', '\u{3B1}', '\u{342}', '\u{3B1}', '\u{342}', '\u{345}',
+    '\u{391}', '\u{306}', '\u{391}', '\u{304}', '\u{391}', '\u{300}', '\u{391}', '\u{301}', '\u{391}', '\u{345}',
+    '\u{20}', '\u{313}', '\u{3B9}', '\u{20}', '\u{313}', '\u{20}', '\u{342}', '\u{20}', '\u{308}', '\u{342}',
+    '\u{3B7}', '\u{300}', '\u{345}', '\u{3B7}', '\u{345}', '\u{3B7}', '\u{301}', '\u{345}', '\u{3B7}', '\u{342}',
+    '\u{3B7}', '\u{342}', '\u{345}', '\u{395}', '\u{300}', '\u{395}', '\u{301}', '\u{397}', '\u{300}', '\u{397}',
+    '\u{301}', '\u{397}', '\u{345}', '\u{20}', '\u{313}', '\u{300}', '\u{20}', '\u{313}', '\u{301}', '\u{20}',
+    '\u{313}', '\u{342}', '\u{3B9}', '\u{306}', '\u{3B9}', '\u{304}', '\u{3B9}', '\u{308}', '\u{300}', '\u{3B9}',
+    '\u{308}', '\u{301}', '\u{3B9}', '\u{342}', '\u{3B9}', '\u{308}', '\u{342}', '\u{399}', '\u{306}', '\u{399}',
+    '\u{304}', '\u{399}', '\u{300}', '\u{399}', '\u{301}', '\u{20}', '\u{314}', '\u{300}', '\u{20}', '\u{314}',
+    '\u{301}', '\u{20}', '\u{314}', '\u{342}', '\u{3C5}', '\u{306}', '\u{3C5}', '\u{304}', '\u{3C5}', '\u{308}',
+    '\u{300}', '\u{3C5}', '\u{308}', '\u{301}', '\u{3C1}', '\u{313}', '\u{3C1}', '\u{314}', '\u{3C5}', '\u{342}',
+    '\u{3C5}', '\u{308}', '\u{342}', '\u{3A5}', '\u{306}', '\u{3A5}', '\u{304}', '\u{3A5}', '\u{300}', '\u{3A5}',
+    '\u{301}', '\u{3A1}', '\u{314}', '\u{20}', '\u{308}', '\u{300}', '\u{20}', '\u{308}', '\u{301}', '\u{60}',
+    '\u{3C9}', '\u{300}', '\u{345}', '\u{3C9}', '\u{345}', '\u{3C9}', '\u{301}', '\u{345}', '\u{3C9}', '\u{342}',
+    '\u{3C9}', '\u{342}', '\u{345}', '\u{39F}', '\u{300}', '\u{39F}', '\u{301}', '\u{3A9}', '\u{300}', '\u{3A9}',
+    '\u{301}', '\u{3A9}', '\u{345}', '\u{20}', '\u{301}', '\u{20}', '\u{314}', '\u{20}', '\u{20}', '\u{20}',
+    '\u{20}', '\u{20}', '\u{20}', '\u{20}', '\u{20}', '\u{20}', '\u{20}', '\u{20}', '\u{2010}', '\u{20}',
+    '\u{333}', '\u{2E}', '\u{2E}', '\u{2E}', '\u{2E}', '\u{2E}', '\u{2E}', '\u{20}', '\u{2032}', '\u{2032}',
+    '\u{2032}', '\u{2032}', '\u{2032}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{2035}', '\u{21}', '\u{21}',
+    '\u{20}', '\u{305}', '\u{3F}', '\u{3F}', '\u{3F}', '\u{21}', '\u{21}', '\u{3F}', '\u{2032}', '\u{2032}',
+    '\u{2032}', '\u{2032}', '\u{20}', '\u{30}', '\u{69}', '\u{34}', '\u{35}', '\u{36}', '\u{37}', '\u{38}',
+    '\u{39}', '\u{2B}', '\u{2212}', '\u{3D}', '\u{28}', '\u{29}', '\u{6E}', '\u{30}', '\u{31}', '\u{32}',
+    '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}', '\u{38}', '\u{39}', '\u{2B}', '\u{2212}', '\u{3D}',
+    '\u{28}', '\u{29}', '\u{61}', '\u{65}', '\u{6F}', '\u{78}', '\u{259}', '\u{68}', '\u{6B}', '\u{6C}',
+    '\u{6D}', '\u{6E}', '\u{70}', '\u{73}', '\u{74}', '\u{52}', '\u{73}', '\u{61}', '\u{2F}', '\u{63}',
+    '\u{61}', '\u{2F}', '\u{73}', '\u{43}', '\u{B0}', '\u{43}', '\u{63}', '\u{2F}', '\u{6F}', '\u{63}',
+    '\u{2F}', '\u{75}', '\u{190}', '\u{B0}', '\u{46}', '\u{67}', '\u{48}', '\u{48}', '\u{48}', '\u{68}',
+    '\u{127}', '\u{49}', '\u{49}', '\u{4C}', '\u{6C}', '\u{4E}', '\u{4E}', '\u{6F}', '\u{50}', '\u{51}',
+    '\u{52}', '\u{52}', '\u{52}', '\u{53}', '\u{4D}', '\u{54}', '\u{45}', '\u{4C}', '\u{54}', '\u{4D}',
+    '\u{5A}', '\u{3A9}', '\u{5A}', '\u{4B}', '\u{41}', '\u{30A}', '\u{42}', '\u{43}', '\u{65}', '\u{45}',
+    '\u{46}', '\u{4D}', '\u{6F}', '\u{5D0}', '\u{5D1}', '\u{5D2}', '\u{5D3}', '\u{69}', '\u{46}', '\u{41}',
+    '\u{58}', '\u{3C0}', '\u{3B3}', '\u{393}', '\u{3A0}', '\u{2211}', '\u{44}', '\u{64}', '\u{65}', '\u{69}',
+    '\u{6A}', '\u{31}', '\u{2044}', '\u{37}', '\u{31}', '\u{2044}', '\u{39}', '\u{31}', '\u{2044}', '\u{31}',
+    '\u{30}', '\u{31}', '\u{2044}', '\u{33}', '\u{32}', '\u{2044}', '\u{33}', '\u{31}', '\u{2044}', '\u{35}',
+    '\u{32}', '\u{2044}', '\u{35}', '\u{33}', '\u{2044}', '\u{35}', '\u{34}', '\u{2044}', '\u{35}', '\u{31}',
+    '\u{2044}', '\u{36}', '\u{35}', '\u{2044}', '\u{36}', '\u{31}', '\u{2044}', '\u{38}', '\u{33}', '\u{2044}',
+    '\u{38}', '\u{35}', '\u{2044}', '\u{38}', '\u{37}', '\u{2044}', '\u{38}', '\u{31}', '\u{2044}', '\u{49}',
+    '\u{49}', '\u{49}', '\u{49}', '\u{49}', '\u{49}', '\u{49}', '\u{56}', '\u{56}', '\u{56}', '\u{49}',
+    '\u{56}', '\u{49}', '\u{49}', '\u{56}', '\u{49}', '\u{49}', '\u{49}', '\u{49}', '\u{58}', '\u{58}',
+    '\u{58}', '\u{49}', '\u{58}', '\u{49}', '\u{49}', '\u{4C}', '\u{43}', '\u{44}', '\u{4D}', '\u{69}',
+    '\u{69}', '\u{69}', '\u{69}', '\u{69}', '\u{69}', '\u{69}', '\u{76}', '\u{76}', '\u{76}', '\u{69}',
+    '\u{76}', '\u{69}', '\u{69}', '\u{76}', '\u{69}', '\u{69}', '\u{69}', '\u{69}', '\u{78}', '\u{78}',
+    '\u{78}', '\u{69}', '\u{78}', '\u{69}', '\u{69}', '\u{6C}', '\u{63}', '\u{64}', '\u{6D}', '\u{30}',
+    '\u{2044}', '\u{33}', '\u{2190}', '\u{338}', '\u{2192}', '\u{338}', '\u{2194}', '\u{338}', '\u{21D0}', '\u{338}',
+    '\u{21D4}', '\u{338}', '\u{21D2}', '\u{338}', '\u{2203}', '\u{338}', '\u{2208}', '\u{338}', '\u{220B}', '\u{338}',
+    '\u{2223}', '\u{338}', '\u{2225}', '\u{338}', '\u{222B}', '\u{222B}', '\u{222B}', '\u{222B}', '\u{222B}', '\u{222E}',
+    '\u{222E}', '\u{222E}', '\u{222E}', '\u{222E}', '\u{223C}', '\u{338}', '\u{2243}', '\u{338}', '\u{2245}', '\u{338}',
+    '\u{2248}', '\u{338}', '\u{3D}', '\u{338}', '\u{2261}', '\u{338}', '\u{224D}', '\u{338}', '\u{3C}', '\u{338}',
+    '\u{3E}', '\u{338}', '\u{2264}', '\u{338}', '\u{2265}', '\u{338}', '\u{2272}', '\u{338}', '\u{2273}', '\u{338}',
+    '\u{2276}', '\u{338}', '\u{2277}', '\u{338}', '\u{227A}', '\u{338}', '\u{227B}', '\u{338}', '\u{2282}', '\u{338}',
+    '\u{2283}', '\u{338}', '\u{2286}', '\u{338}', '\u{2287}', '\u{338}', '\u{22A2}', '\u{338}', '\u{22A8}', '\u{338}',
+    '\u{22A9}', '\u{338}', '\u{22AB}', '\u{338}', '\u{227C}', '\u{338}', '\u{227D}', '\u{338}', '\u{2291}', '\u{338}',
+    '\u{2292}', '\u{338}', '\u{22B2}', '\u{338}', '\u{22B3}', '\u{338}', '\u{22B4}', '\u{338}', '\u{22B5}', '\u{338}',
+    '\u{3008}', '\u{3009}', '\u{31}', '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}', '\u{38}',
+    '\u{39}', '\u{31}', '\u{30}', '\u{31}', '\u{31}', '\u{31}', '\u{32}', '\u{31}', '\u{33}', '\u{31}',
+    '\u{34}', '\u{31}', '\u{35}', '\u{31}', '\u{36}', '\u{31}', '\u{37}', '\u{31}', '\u{38}', '\u{31}',
+    '\u{39}', '\u{32}', '\u{30}', '\u{28}', '\u{31}', '\u{29}', '\u{28}', '\u{32}', '\u{29}', '\u{28}',
+    '\u{33}', '\u{29}', '\u{28}', '\u{34}', '\u{29}', '\u{28}', '\u{35}', '\u{29}', '\u{28}', '\u{36}',
+    '\u{29}', '\u{28}', '\u{37}', '\u{29}', '\u{28}', '\u{38}', '\u{29}', '\u{28}', '\u{39}', '\u{29}',
+    '\u{28}', '\u{31}', '\u{30}', '\u{29}', '\u{28}', '\u{31}', '\u{31}', '\u{29}', '\u{28}', '\u{31}',
+    '\u{32}', '\u{29}', '\u{28}', '\u{31}', '\u{33}', '\u{29}', '\u{28}', '\u{31}', '\u{34}', '\u{29}',
+    '\u{28}', '\u{31}', '\u{35}', '\u{29}', '\u{28}', '\u{31}', '\u{36}', '\u{29}', '\u{28}', '\u{31}',
+    '\u{37}', '\u{29}', '\u{28}', '\u{31}', '\u{38}', '\u{29}', '\u{28}', '\u{31}', '\u{39}', '\u{29}',
+    '\u{28}', '\u{32}', '\u{30}', '\u{29}', '\u{31}', '\u{2E}', '\u{32}', '\u{2E}', '\u{33}', '\u{2E}',
+    '\u{34}', '\u{2E}', '\u{35}', '\u{2E}', '\u{36}', '\u{2E}', '\u{37}', '\u{2E}', '\u{38}', '\u{2E}',
+    '\u{39}', '\u{2E}', '\u{31}', '\u{30}', '\u{2E}', '\u{31}', '\u{31}', '\u{2E}', '\u{31}', '\u{32}',
+    '\u{2E}', '\u{31}', '\u{33}', '\u{2E}', '\u{31}', '\u{34}', '\u{2E}', '\u{31}', '\u{35}', '\u{2E}',
+    '\u{31}', '\u{36}', '\u{2E}', '\u{31}', '\u{37}', '\u{2E}', '\u{31}', '\u{38}', '\u{2E}', '\u{31}',
+    '\u{39}', '\u{2E}', '\u{32}', '\u{30}', '\u{2E}', '\u{28}', '\u{61}', '\u{29}', '\u{28}', '\u{62}',
+    '\u{29}', '\u{28}', '\u{63}', '\u{29}', '\u{28}', '\u{64}', '\u{29}', '\u{28}', '\u{65}', '\u{29}',
+    '\u{28}', '\u{66}', '\u{29}', '\u{28}', '\u{67}', '\u{29}', '\u{28}', '\u{68}', '\u{29}', '\u{28}',
+    '\u{69}', '\u{29}', '\u{28}', '\u{6A}', '\u{29}', '\u{28}', '\u{6B}', '\u{29}', '\u{28}', '\u{6C}',
+    '\u{29}', '\u{28}', '\u{6D}', '\u{29}', '\u{28}', '\u{6E}', '\u{29}', '\u{28}', '\u{6F}', '\u{29}',
+    '\u{28}', '\u{70}', '\u{29}', '\u{28}', '\u{71}', '\u{29}', '\u{28}', '\u{72}', '\u{29}', '\u{28}',
+    '\u{73}', '\u{29}', '\u{28}', '\u{74}', '\u{29}', '\u{28}', '\u{75}', '\u{29}', '\u{28}', '\u{76}',
+    '\u{29}', '\u{28}', '\u{77}', '\u{29}', '\u{28}', '\u{78}', '\u{29}', '\u{28}', '\u{79}', '\u{29}',
+    '\u{28}', '\u{7A}', '\u{29}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}',
+    '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}',
+    '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}',
+    '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}',
+    '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}',
+    '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{30}', '\u{222B}', '\u{222B}', '\u{222B}', '\u{222B}',
+    '\u{3A}', '\u{3A}', '\u{3D}', '\u{3D}', '\u{3D}', '\u{3D}', '\u{3D}', '\u{3D}', '\u{2ADD}', '\u{338}',
+    '\u{6A}', '\u{56}', '\u{2D61}', '\u{6BCD}', '\u{9F9F}', '\u{4E00}', '\u{4E28}', '\u{4E36}', '\u{4E3F}', '\u{4E59}',
+    '\u{4E85}', '\u{4E8C}', '\u{4EA0}', '\u{4EBA}', '\u{513F}', '\u{5165}', '\u{516B}', '\u{5182}', '\u{5196}', '\u{51AB}',
+    '\u{51E0}', '\u{51F5}', '\u{5200}', '\u{529B}', '\u{52F9}', '\u{5315}', '\u{531A}', '\u{5338}', '\u{5341}', '\u{535C}',
+    '\u{5369}', '\u{5382}', '\u{53B6}', '\u{53C8}', '\u{53E3}', '\u{56D7}', '\u{571F}', '\u{58EB}', '\u{5902}', '\u{590A}',
+    '\u{5915}', '\u{5927}', '\u{5973}', '\u{5B50}', '\u{5B80}', '\u{5BF8}', '\u{5C0F}', '\u{5C22}', '\u{5C38}', '\u{5C6E}',
+    '\u{5C71}', '\u{5DDB}', '\u{5DE5}', '\u{5DF1}', '\u{5DFE}', '\u{5E72}', '\u{5E7A}', '\u{5E7F}', '\u{5EF4}', '\u{5EFE}',
+    '\u{5F0B}', '\u{5F13}', '\u{5F50}', '\u{5F61}', '\u{5F73}', '\u{5FC3}', '\u{6208}', '\u{6236}', '\u{624B}', '\u{652F}',
+    '\u{6534}', '\u{6587}', '\u{6597}', '\u{65A4}', '\u{65B9}', '\u{65E0}', '\u{65E5}', '\u{66F0}', '\u{6708}', '\u{6728}',
+    '\u{6B20}', '\u{6B62}', '\u{6B79}', '\u{6BB3}', '\u{6BCB}', '\u{6BD4}', '\u{6BDB}', '\u{6C0F}', '\u{6C14}', '\u{6C34}',
+    '\u{706B}', '\u{722A}', '\u{7236}', '\u{723B}', '\u{723F}', '\u{7247}', '\u{7259}', '\u{725B}', '\u{72AC}', '\u{7384}',
+    '\u{7389}', '\u{74DC}', '\u{74E6}', '\u{7518}', '\u{751F}', '\u{7528}', '\u{7530}', '\u{758B}', '\u{7592}', '\u{7676}',
+    '\u{767D}', '\u{76AE}', '\u{76BF}', '\u{76EE}', '\u{77DB}', '\u{77E2}', '\u{77F3}', '\u{793A}', '\u{79B8}', '\u{79BE}',
+    '\u{7A74}', '\u{7ACB}', '\u{7AF9}', '\u{7C73}', '\u{7CF8}', '\u{7F36}', '\u{7F51}', '\u{7F8A}', '\u{7FBD}', '\u{8001}',
+    '\u{800C}', '\u{8012}', '\u{8033}', '\u{807F}', '\u{8089}', '\u{81E3}', '\u{81EA}', '\u{81F3}', '\u{81FC}', '\u{820C}',
+    '\u{821B}', '\u{821F}', '\u{826E}', '\u{8272}', '\u{8278}', '\u{864D}', '\u{866B}', '\u{8840}', '\u{884C}', '\u{8863}',
+    '\u{897E}', '\u{898B}', '\u{89D2}', '\u{8A00}', '\u{8C37}', '\u{8C46}', '\u{8C55}', '\u{8C78}', '\u{8C9D}', '\u{8D64}',
+    '\u{8D70}', '\u{8DB3}', '\u{8EAB}', '\u{8ECA}', '\u{8F9B}', '\u{8FB0}', '\u{8FB5}', '\u{9091}', '\u{9149}', '\u{91C6}',
+    '\u{91CC}', '\u{91D1}', '\u{9577}', '\u{9580}', '\u{961C}', '\u{96B6}', '\u{96B9}', '\u{96E8}', '\u{9751}', '\u{975E}',
+    '\u{9762}', '\u{9769}', '\u{97CB}', '\u{97ED}', '\u{97F3}', '\u{9801}', '\u{98A8}', '\u{98DB}', '\u{98DF}', '\u{9996}',
+    '\u{9999}', '\u{99AC}', '\u{9AA8}', '\u{9AD8}', '\u{9ADF}', '\u{9B25}', '\u{9B2F}', '\u{9B32}', '\u{9B3C}', '\u{9B5A}',
+    '\u{9CE5}', '\u{9E75}', '\u{9E7F}', '\u{9EA5}', '\u{9EBB}', '\u{9EC3}', '\u{9ECD}', '\u{9ED1}', '\u{9EF9}', '\u{9EFD}',
+    '\u{9F0E}', '\u{9F13}', '\u{9F20}', '\u{9F3B}', '\u{9F4A}', '\u{9F52}', '\u{9F8D}', '\u{9F9C}', '\u{9FA0}', '\u{20}',
+    '\u{3012}', '\u{5341}', '\u{5344}', '\u{5345}', '\u{304B}', '\u{3099}', '\u{304D}', '\u{3099}', '\u{304F}', '\u{3099}',
+    '\u{3051}', '\u{3099}', '\u{3053}', '\u{3099}', '\u{3055}', '\u{3099}', '\u{3057}', '\u{3099}', '\u{3059}', '\u{3099}',
+    '\u{305B}', '\u{3099}', '\u{305D}', '\u{3099}', '\u{305F}', '\u{3099}', '\u{3061}', '\u{3099}', '\u{3064}', '\u{3099}',
+    '\u{3066}', '\u{3099}', '\u{3068}', '\u{3099}', '\u{306F}', '\u{3099}', '\u{306F}', '\u{309A}', '\u{3072}', '\u{3099}',
+    '\u{3072}', '\u{309A}', '\u{3075}', '\u{3099}', '\u{3075}', '\u{309A}', '\u{3078}', '\u{3099}', '\u{3078}', '\u{309A}',
+    '\u{307B}', '\u{3099}', '\u{307B}', '\u{309A}', '\u{3046}', '\u{3099}', '\u{20}', '\u{3099}', '\u{20}', '\u{309A}',
+    '\u{309D}', '\u{3099}', '\u{3088}', '\u{308A}', '\u{30AB}', '\u{3099}', '\u{30AD}', '\u{3099}', '\u{30AF}', '\u{3099}',
+    '\u{30B1}', '\u{3099}', '\u{30B3}', '\u{3099}', '\u{30B5}', '\u{3099}', '\u{30B7}', '\u{3099}', '\u{30B9}', '\u{3099}',
+    '\u{30BB}', '\u{3099}', '\u{30BD}', '\u{3099}', '\u{30BF}', '\u{3099}', '\u{30C1}', '\u{3099}', '\u{30C4}', '\u{3099}',
+    '\u{30C6}', '\u{3099}', '\u{30C8}', '\u{3099}', '\u{30CF}', '\u{3099}', '\u{30CF}', '\u{309A}', '\u{30D2}', '\u{3099}',
+    '\u{30D2}', '\u{309A}', '\u{30D5}', '\u{3099}', '\u{30D5}', '\u{309A}', '\u{30D8}', '\u{3099}', '\u{30D8}', '\u{309A}',
+    '\u{30DB}', '\u{3099}', '\u{30DB}', '\u{309A}', '\u{30A6}', '\u{3099}', '\u{30EF}', '\u{3099}', '\u{30F0}', '\u{3099}',
+    '\u{30F1}', '\u{3099}', '\u{30F2}', '\u{3099}', '\u{30FD}', '\u{3099}', '\u{30B3}', '\u{30C8}', '\u{1100}', '\u{1101}',
+    '\u{11AA}', '\u{1102}', '\u{11AC}', '\u{11AD}', '\u{1103}', '\u{1104}', '\u{1105}', '\u{11B0}', '\u{11B1}', '\u{11B2}',
+    '\u{11B3}', '\u{11B4}', '\u{11B5}', '\u{111A}', '\u{1106}', '\u{1107}', '\u{1108}', '\u{1121}', '\u{1109}', '\u{110A}',
+    '\u{110B}', '\u{110C}', '\u{110D}', '\u{110E}', '\u{110F}', '\u{1110}', '\u{1111}', '\u{1112}', '\u{1161}', '\u{1162}',
+    '\u{1163}', '\u{1164}', '\u{1165}', '\u{1166}', '\u{1167}', '\u{1168}', '\u{1169}', '\u{116A}', '\u{116B}', '\u{116C}',
+    '\u{116D}', '\u{116E}', '\u{116F}', '\u{1170}', '\u{1171}', '\u{1172}', '\u{1173}', '\u{1174}', '\u{1175}', '\u{1160}',
+    '\u{1114}', '\u{1115}', '\u{11C7}', '\u{11C8}', '\u{11CC}', '\u{11CE}', '\u{11D3}', '\u{11D7}', '\u{11D9}', '\u{111C}',
+    '\u{11DD}', '\u{11DF}', '\u{111D}', '\u{111E}', '\u{1120}', '\u{1122}', '\u{1123}', '\u{1127}', '\u{1129}', '\u{112B}',
+    '\u{112C}', '\u{112D}', '\u{112E}', '\u{112F}', '\u{1132}', '\u{1136}', '\u{1140}', '\u{1147}', '\u{114C}', '\u{11F1}',
+    '\u{11F2}', '\u{1157}', '\u{1158}', '\u{1159}', '\u{1184}', '\u{1185}', '\u{1188}', '\u{1191}', '\u{1192}', '\u{1194}',
+    '\u{119E}', '\u{11A1}', '\u{4E00}', '\u{4E8C}', '\u{4E09}', '\u{56DB}', '\u{4E0A}', '\u{4E2D}', '\u{4E0B}', '\u{7532}',
+    '\u{4E59}', '\u{4E19}', '\u{4E01}', '\u{5929}', '\u{5730}', '\u{4EBA}', '\u{28}', '\u{1100}', '\u{29}', '\u{28}',
+    '\u{1102}', '\u{29}', '\u{28}', '\u{1103}', '\u{29}', '\u{28}', '\u{1105}', '\u{29}', '\u{28}', '\u{1106}',
+    '\u{29}', '\u{28}', '\u{1107}', '\u{29}', '\u{28}', '\u{1109}', '\u{29}', '\u{28}', '\u{110B}', '\u{29}',
+    '\u{28}', '\u{110C}', '\u{29}', '\u{28}', '\u{110E}', '\u{29}', '\u{28}', '\u{110F}', '\u{29}', '\u{28}',
+    '\u{1110}', '\u{29}', '\u{28}', '\u{1111}', '\u{29}', '\u{28}', '\u{1112}', '\u{29}', '\u{28}', '\u{1100}',
+    '\u{1161}', '\u{29}', '\u{28}', '\u{1102}', '\u{1161}', '\u{29}', '\u{28}', '\u{1103}', '\u{1161}', '\u{29}',
+    '\u{28}', '\u{1105}', '\u{1161}', '\u{29}', '\u{28}', '\u{1106}', '\u{1161}', '\u{29}', '\u{28}', '\u{1107}',
+    '\u{1161}', '\u{29}', '\u{28}', '\u{1109}', '\u{1161}', '\u{29}', '\u{28}', '\u{110B}', '\u{1161}', '\u{29}',
+    '\u{28}', '\u{110C}', '\u{1161}', '\u{29}', '\u{28}', '\u{110E}', '\u{1161}', '\u{29}', '\u{28}', '\u{110F}',
+    '\u{1161}', '\u{29}', '\u{28}', '\u{1110}', '\u{1161}', '\u{29}', '\u{28}', '\u{1111}', '\u{1161}', '\u{29}',
+    '\u{28}', '\u{1112}', '\u{1161}', '\u{29}', '\u{28}', '\u{110C}', '\u{116E}', '\u{29}', '\u{28}', '\u{110B}',
+    '\u{1169}', '\u{110C}', '\u{1165}', '\u{11AB}', '\u{29}', '\u{28}', '\u{110B}', '\u{1169}', '\u{1112}', '\u{116E}',
+    '\u{29}', '\u{28}', '\u{4E00}', '\u{29}', '\u{28}', '\u{4E8C}', '\u{29}', '\u{28}', '\u{4E09}', '\u{29}',
+    '\u{28}', '\u{56DB}', '\u{29}', '\u{28}', '\u{4E94}', '\u{29}', '\u{28}', '\u{516D}', '\u{29}', '\u{28}',
+    '\u{4E03}', '\u{29}', '\u{28}', '\u{516B}', '\u{29}', '\u{28}', '\u{4E5D}', '\u{29}', '\u{28}', '\u{5341}',
+    '\u{29}', '\u{28}', '\u{6708}', '\u{29}', '\u{28}', '\u{706B}', '\u{29}', '\u{28}', '\u{6C34}', '\u{29}',
+    '\u{28}', '\u{6728}', '\u{29}', '\u{28}', '\u{91D1}', '\u{29}', '\u{28}', '\u{571F}', '\u{29}', '\u{28}',
+    '\u{65E5}', '\u{29}', '\u{28}', '\u{682A}', '\u{29}', '\u{28}', '\u{6709}', '\u{29}', '\u{28}', '\u{793E}',
+    '\u{29}', '\u{28}', '\u{540D}', '\u{29}', '\u{28}', '\u{7279}', '\u{29}', '\u{28}', '\u{8CA1}', '\u{29}',
+    '\u{28}', '\u{795D}', '\u{29}', '\u{28}', '\u{52B4}', '\u{29}', '\u{28}', '\u{4EE3}', '\u{29}', '\u{28}',
+    '\u{547C}', '\u{29}', '\u{28}', '\u{5B66}', '\u{29}', '\u{28}', '\u{76E3}', '\u{29}', '\u{28}', '\u{4F01}',
+    '\u{29}', '\u{28}', '\u{8CC7}', '\u{29}', '\u{28}', '\u{5354}', '\u{29}', '\u{28}', '\u{796D}', '\u{29}',
+    '\u{28}', '\u{4F11}', '\u{29}', '\u{28}', '\u{81EA}', '\u{29}', '\u{28}', '\u{81F3}', '\u{29}', '\u{554F}',
+    '\u{5E7C}', '\u{6587}', '\u{7B8F}', '\u{50}', '\u{54}', '\u{45}', '\u{32}', '\u{31}', '\u{32}', '\u{32}',
+    '\u{32}', '\u{33}', '\u{32}', '\u{34}', '\u{32}', '\u{35}', '\u{32}', '\u{36}', '\u{32}', '\u{37}',
+    '\u{32}', '\u{38}', '\u{32}', '\u{39}', '\u{33}', '\u{30}', '\u{33}', '\u{31}', '\u{33}', '\u{32}',
+    '\u{33}', '\u{33}', '\u{33}', '\u{34}', '\u{33}', '\u{35}', '\u{1100}', '\u{1102}', '\u{1103}', '\u{1105}',
+    '\u{1106}', '\u{1107}', '\u{1109}', '\u{110B}', '\u{110C}', '\u{110E}', '\u{110F}', '\u{1110}', '\u{1111}', '\u{1112}',
+    '\u{1100}', '\u{1161}', '\u{1102}', '\u{1161}', '\u{1103}', '\u{1161}', '\u{1105}', '\u{1161}', '\u{1106}', '\u{1161}',
+    '\u{1107}', '\u{1161}', '\u{1109}', '\u{1161}', '\u{110B}', '\u{1161}', '\u{110C}', '\u{1161}', '\u{110E}', '\u{1161}',
+    '\u{110F}', '\u{1161}', '\u{1110}', '\u{1161}', '\u{1111}', '\u{1161}', '\u{1112}', '\u{1161}', '\u{110E}', '\u{1161}',
+    '\u{11B7}', '\u{1100}', '\u{1169}', '\u{110C}', '\u{116E}', '\u{110B}', '\u{1174}', '\u{110B}', '\u{116E}', '\u{4E00}',
+    '\u{4E8C}', '\u{4E09}', '\u{56DB}', '\u{4E94}', '\u{516D}', '\u{4E03}', '\u{516B}', '\u{4E5D}', '\u{5341}', '\u{6708}',
+    '\u{706B}', '\u{6C34}', '\u{6728}', '\u{91D1}', '\u{571F}', '\u{65E5}', '\u{682A}', '\u{6709}', '\u{793E}', '\u{540D}',
+    '\u{7279}', '\u{8CA1}', '\u{795D}', '\u{52B4}', '\u{79D8}', '\u{7537}', '\u{5973}', '\u{9069}', '\u{512A}', '\u{5370}',
+    '\u{6CE8}', '\u{9805}', '\u{4F11}', '\u{5199}', '\u{6B63}', '\u{4E0A}', '\u{4E2D}', '\u{4E0B}', '\u{5DE6}', '\u{53F3}',
+    '\u{533B}', '\u{5B97}', '\u{5B66}', '\u{76E3}', '\u{4F01}', '\u{8CC7}', '\u{5354}', '\u{591C}', '\u{33}', '\u{36}',
+    '\u{33}', '\u{37}', '\u{33}', '\u{38}', '\u{33}', '\u{39}', '\u{34}', '\u{30}', '\u{34}', '\u{31}',
+    '\u{34}', '\u{32}', '\u{34}', '\u{33}', '\u{34}', '\u{34}', '\u{34}', '\u{35}', '\u{34}', '\u{36}',
+    '\u{34}', '\u{37}', '\u{34}', '\u{38}', '\u{34}', '\u{39}', '\u{35}', '\u{30}', '\u{31}', '\u{6708}',
+    '\u{32}', '\u{6708}', '\u{33}', '\u{6708}', '\u{34}', '\u{6708}', '\u{35}', '\u{6708}', '\u{36}', '\u{6708}',
+    '\u{37}', '\u{6708}', '\u{38}', '\u{6708}', '\u{39}', '\u{6708}', '\u{31}', '\u{30}', '\u{6708}', '\u{31}',
+    '\u{31}', '\u{6708}', '\u{31}', '\u{32}', '\u{6708}', '\u{48}', '\u{67}', '\u{65}', '\u{72}', '\u{67}',
+    '\u{65}', '\u{56}', '\u{4C}', '\u{54}', '\u{44}', '\u{30A2}', '\u{30A4}', '\u{30A6}', '\u{30A8}', '\u{30AA}',
+    '\u{30AB}', '\u{30AD}', '\u{30AF}', '\u{30B1}', '\u{30B3}', '\u{30B5}', '\u{30B7}', '\u{30B9}', '\u{30BB}', '\u{30BD}',
+    '\u{30BF}', '\u{30C1}', '\u{30C4}', '\u{30C6}', '\u{30C8}', '\u{30CA}', '\u{30CB}', '\u{30CC}', '\u{30CD}', '\u{30CE}',
+    '\u{30CF}', '\u{30D2}', '\u{30D5}', '\u{30D8}', '\u{30DB}', '\u{30DE}', '\u{30DF}', '\u{30E0}', '\u{30E1}', '\u{30E2}',
+    '\u{30E4}', '\u{30E6}', '\u{30E8}', '\u{30E9}', '\u{30EA}', '\u{30EB}', '\u{30EC}', '\u{30ED}', '\u{30EF}', '\u{30F0}',
+    '\u{30F1}', '\u{30F2}', '\u{4EE4}', '\u{548C}', '\u{30A2}', '\u{30CF}', '\u{309A}', '\u{30FC}', '\u{30C8}', '\u{30A2}',
+    '\u{30EB}', '\u{30D5}', '\u{30A1}', '\u{30A2}', '\u{30F3}', '\u{30D8}', '\u{309A}', '\u{30A2}', '\u{30A2}', '\u{30FC}',
+    '\u{30EB}', '\u{30A4}', '\u{30CB}', '\u{30F3}', '\u{30AF}', '\u{3099}', '\u{30A4}', '\u{30F3}', '\u{30C1}', '\u{30A6}',
+    '\u{30A9}', '\u{30F3}', '\u{30A8}', '\u{30B9}', '\u{30AF}', '\u{30FC}', '\u{30C8}', '\u{3099}', '\u{30A8}', '\u{30FC}',
+    '\u{30AB}', '\u{30FC}', '\u{30AA}', '\u{30F3}', '\u{30B9}', '\u{30AA}', '\u{30FC}', '\u{30E0}', '\u{30AB}', '\u{30A4}',
+    '\u{30EA}', '\u{30AB}', '\u{30E9}', '\u{30C3}', '\u{30C8}', '\u{30AB}', '\u{30ED}', '\u{30EA}', '\u{30FC}', '\u{30AB}',
+    '\u{3099}', '\u{30ED}', '\u{30F3}', '\u{30AB}', '\u{3099}', '\u{30F3}', '\u{30DE}', '\u{30AD}', '\u{3099}', '\u{30AB}',
+    '\u{3099}', '\u{30AD}', '\u{3099}', '\u{30CB}', '\u{30FC}', '\u{30AD}', '\u{30E5}', '\u{30EA}', '\u{30FC}', '\u{30AD}',
+    '\u{3099}', '\u{30EB}', '\u{30BF}', '\u{3099}', '\u{30FC}', '\u{30AD}', '\u{30ED}', '\u{30AD}', '\u{30ED}', '\u{30AF}',
+    '\u{3099}', '\u{30E9}', '\u{30E0}', '\u{30AD}', '\u{30ED}', '\u{30E1}', '\u{30FC}', '\u{30C8}', '\u{30EB}', '\u{30AD}',
+    '\u{30ED}', '\u{30EF}', '\u{30C3}', '\u{30C8}', '\u{30AF}', '\u{3099}', '\u{30E9}', '\u{30E0}', '\u{30AF}', '\u{3099}',
+    '\u{30E9}', '\u{30E0}', '\u{30C8}', '\u{30F3}', '\u{30AF}', '\u{30EB}', '\u{30BB}', '\u{3099}', '\u{30A4}', '\u{30ED}',
+    '\u{30AF}', '\u{30ED}', '\u{30FC}', '\u{30CD}', '\u{30B1}', '\u{30FC}', '\u{30B9}', '\u{30B3}', '\u{30EB}', '\u{30CA}',
+    '\u{30B3}', '\u{30FC}', '\u{30DB}', '\u{309A}', '\u{30B5}', '\u{30A4}', '\u{30AF}', '\u{30EB}', '\u{30B5}', '\u{30F3}',
+    '\u{30C1}', '\u{30FC}', '\u{30E0}', '\u{30B7}', '\u{30EA}', '\u{30F3}', '\u{30AF}', '\u{3099}', '\u{30BB}', '\u{30F3}',
+    '\u{30C1}', '\u{30BB}', '\u{30F3}', '\u{30C8}', '\u{30BF}', '\u{3099}', '\u{30FC}', '\u{30B9}', '\u{30C6}', '\u{3099}',
+    '\u{30B7}', '\u{30C8}', '\u{3099}', '\u{30EB}', '\u{30C8}', '\u{30F3}', '\u{30CA}', '\u{30CE}', '\u{30CE}', '\u{30C3}',
+    '\u{30C8}', '\u{30CF}', '\u{30A4}', '\u{30C4}', '\u{30CF}', '\u{309A}', '\u{30FC}', '\u{30BB}', '\u{30F3}', '\u{30C8}',
+    '\u{30CF}', '\u{309A}', '\u{30FC}', '\u{30C4}', '\u{30CF}', '\u{3099}', '\u{30FC}', '\u{30EC}', '\u{30EB}', '\u{30D2}',
+    '\u{309A}', '\u{30A2}', '\u{30B9}', '\u{30C8}', '\u{30EB}', '\u{30D2}', '\u{309A}', '\u{30AF}', '\u{30EB}', '\u{30D2}',
+    '\u{309A}', '\u{30B3}', '\u{30D2}', '\u{3099}', '\u{30EB}', '\u{30D5}', '\u{30A1}', '\u{30E9}', '\u{30C3}', '\u{30C8}',
+    '\u{3099}', '\u{30D5}', '\u{30A3}', '\u{30FC}', '\u{30C8}', '\u{30D5}', '\u{3099}', '\u{30C3}', '\u{30B7}', '\u{30A7}',
+    '\u{30EB}', '\u{30D5}', '\u{30E9}', '\u{30F3}', '\u{30D8}', '\u{30AF}', '\u{30BF}', '\u{30FC}', '\u{30EB}', '\u{30D8}',
+    '\u{309A}', '\u{30BD}', '\u{30D8}', '\u{309A}', '\u{30CB}', '\u{30D2}', '\u{30D8}', '\u{30EB}', '\u{30C4}', '\u{30D8}',
+    '\u{309A}', '\u{30F3}', '\u{30B9}', '\u{30D8}', '\u{309A}', '\u{30FC}', '\u{30B7}', '\u{3099}', '\u{30D8}', '\u{3099}',
+    '\u{30FC}', '\u{30BF}', '\u{30DB}', '\u{309A}', '\u{30A4}', '\u{30F3}', '\u{30C8}', '\u{30DB}', '\u{3099}', '\u{30EB}',
+    '\u{30C8}', '\u{30DB}', '\u{30F3}', '\u{30DB}', '\u{309A}', '\u{30F3}', '\u{30C8}', '\u{3099}', '\u{30DB}', '\u{30FC}',
+    '\u{30EB}', '\u{30DB}', '\u{30FC}', '\u{30F3}', '\u{30DE}', '\u{30A4}', '\u{30AF}', '\u{30ED}', '\u{30DE}', '\u{30A4}',
+    '\u{30EB}', '\u{30DE}', '\u{30C3}', '\u{30CF}', '\u{30DE}', '\u{30EB}', '\u{30AF}', '\u{30DE}', '\u{30F3}', '\u{30B7}',
+    '\u{30E7}', '\u{30F3}', '\u{30DF}', '\u{30AF}', '\u{30ED}', '\u{30F3}', '\u{30DF}', '\u{30EA}', '\u{30DF}', '\u{30EA}',
+    '\u{30CF}', '\u{3099}', '\u{30FC}', '\u{30EB}', '\u{30E1}', '\u{30AB}', '\u{3099}', '\u{30E1}', '\u{30AB}', '\u{3099}',
+    '\u{30C8}', '\u{30F3}', '\u{30E1}', '\u{30FC}', '\u{30C8}', '\u{30EB}', '\u{30E4}', '\u{30FC}', '\u{30C8}', '\u{3099}',
+    '\u{30E4}', '\u{30FC}', '\u{30EB}', '\u{30E6}', '\u{30A2}', '\u{30F3}', '\u{30EA}', '\u{30C3}', '\u{30C8}', '\u{30EB}',
+    '\u{30EA}', '\u{30E9}', '\u{30EB}', '\u{30D2}', '\u{309A}', '\u{30FC}', '\u{30EB}', '\u{30FC}', '\u{30D5}', '\u{3099}',
+    '\u{30EB}', '\u{30EC}', '\u{30E0}', '\u{30EC}', '\u{30F3}', '\u{30C8}', '\u{30B1}', '\u{3099}', '\u{30F3}', '\u{30EF}',
+    '\u{30C3}', '\u{30C8}', '\u{30}', '\u{70B9}', '\u{31}', '\u{70B9}', '\u{32}', '\u{70B9}', '\u{33}', '\u{70B9}',
+    '\u{34}', '\u{70B9}', '\u{35}', '\u{70B9}', '\u{36}', '\u{70B9}', '\u{37}', '\u{70B9}', '\u{38}', '\u{70B9}',
+    '\u{39}', '\u{70B9}', '\u{31}', '\u{30}', '\u{70B9}', '\u{31}', '\u{31}', '\u{70B9}', '\u{31}', '\u{32}',
+    '\u{70B9}', '\u{31}', '\u{33}', '\u{70B9}', '\u{31}', '\u{34}', '\u{70B9}', '\u{31}', '\u{35}', '\u{70B9}',
+    '\u{31}', '\u{36}', '\u{70B9}', '\u{31}', '\u{37}', '\u{70B9}', '\u{31}', '\u{38}', '\u{70B9}', '\u{31}',
+    '\u{39}', '\u{70B9}', '\u{32}', '\u{30}', '\u{70B9}', '\u{32}', '\u{31}', '\u{70B9}', '\u{32}', '\u{32}',
+    '\u{70B9}', '\u{32}', '\u{33}', '\u{70B9}', '\u{32}', '\u{34}', '\u{70B9}', '\u{68}', '\u{50}', '\u{61}',
+    '\u{64}', '\u{61}', '\u{41}', '\u{55}', '\u{62}', '\u{61}', '\u{72}', '\u{6F}', '\u{56}', '\u{70}',
+    '\u{63}', '\u{64}', '\u{6D}', '\u{64}', '\u{6D}', '\u{32}', '\u{64}', '\u{6D}', '\u{33}', '\u{49}',
+    '\u{55}', '\u{5E73}', '\u{6210}', '\u{662D}', '\u{548C}', '\u{5927}', '\u{6B63}', '\u{660E}', '\u{6CBB}', '\u{682A}',
+    '\u{5F0F}', '\u{4F1A}', '\u{793E}', '\u{70}', '\u{41}', '\u{6E}', '\u{41}', '\u{3BC}', '\u{41}', '\u{6D}',
+    '\u{41}', '\u{6B}', '\u{41}', '\u{4B}', '\u{42}', '\u{4D}', '\u{42}', '\u{47}', '\u{42}', '\u{63}',
+    '\u{61}', '\u{6C}', '\u{6B}', '\u{63}', '\u{61}', '\u{6C}', '\u{70}', '\u{46}', '\u{6E}', '\u{46}',
+    '\u{3BC}', '\u{46}', '\u{3BC}', '\u{67}', '\u{6D}', '\u{67}', '\u{6B}', '\u{67}', '\u{48}', '\u{7A}',
+    '\u{6B}', '\u{48}', '\u{7A}', '\u{4D}', '\u{48}', '\u{7A}', '\u{47}', '\u{48}', '\u{7A}', '\u{54}',
+    '\u{48}', '\u{7A}', '\u{3BC}', '\u{6C}', '\u{6D}', '\u{6C}', '\u{64}', '\u{6C}', '\u{6B}', '\u{6C}',
+    '\u{66}', '\u{6D}', '\u{6E}', '\u{6D}', '\u{3BC}', '\u{6D}', '\u{6D}', '\u{6D}', '\u{63}', '\u{6D}',
+    '\u{6B}', '\u{6D}', '\u{6D}', '\u{6D}', '\u{32}', '\u{63}', '\u{6D}', '\u{32}', '\u{6D}', '\u{32}',
+    '\u{6B}', '\u{6D}', '\u{32}', '\u{6D}', '\u{6D}', '\u{33}', '\u{63}', '\u{6D}', '\u{33}', '\u{6D}',
+    '\u{33}', '\u{6B}', '\u{6D}', '\u{33}', '\u{6D}', '\u{2215}', '\u{73}', '\u{6D}', '\u{2215}', '\u{73}',
+    '\u{32}', '\u{50}', '\u{61}', '\u{6B}', '\u{50}', '\u{61}', '\u{4D}', '\u{50}', '\u{61}', '\u{47}',
+    '\u{50}', '\u{61}', '\u{72}', '\u{61}', '\u{64}', '\u{72}', '\u{61}', '\u{64}', '\u{2215}', '\u{73}',
+    '\u{72}', '\u{61}', '\u{64}', '\u{2215}', '\u{73}', '\u{32}', '\u{70}', '\u{73}', '\u{6E}', '\u{73}',
+    '\u{3BC}', '\u{73}', '\u{6D}', '\u{73}', '\u{70}', '\u{56}', '\u{6E}', '\u{56}', '\u{3BC}', '\u{56}',
+    '\u{6D}', '\u{56}', '\u{6B}', '\u{56}', '\u{4D}', '\u{56}', '\u{70}', '\u{57}', '\u{6E}', '\u{57}',
+    '\u{3BC}', '\u{57}', '\u{6D}', '\u{57}', '\u{6B}', '\u{57}', '\u{4D}', '\u{57}', '\u{6B}', '\u{3A9}',
+    '\u{4D}', '\u{3A9}', '\u{61}', '\u{2E}', '\u{6D}', '\u{2E}', '\u{42}', '\u{71}', '\u{63}', '\u{63}',
+    '\u{63}', '\u{64}', '\u{43}', '\u{2215}', '\u{6B}', '\u{67}', '\u{43}', '\u{6F}', '\u{2E}', '\u{64}',
+    '\u{42}', '\u{47}', '\u{79}', '\u{68}', '\u{61}', '\u{48}', '\u{50}', '\u{69}', '\u{6E}', '\u{4B}',
+    '\u{4B}', '\u{4B}', '\u{4D}', '\u{6B}', '\u{74}', '\u{6C}', '\u{6D}', '\u{6C}', '\u{6E}', '\u{6C}',
+    '\u{6F}', '\u{67}', '\u{6C}', '\u{78}', '\u{6D}', '\u{62}', '\u{6D}', '\u{69}', '\u{6C}', '\u{6D}',
+    '\u{6F}', '\u{6C}', '\u{50}', '\u{48}', '\u{70}', '\u{2E}', '\u{6D}', '\u{2E}', '\u{50}', '\u{50}',
+    '\u{4D}', '\u{50}', '\u{52}', '\u{73}', '\u{72}', '\u{53}', '\u{76}', '\u{57}', '\u{62}', '\u{56}',
+    '\u{2215}', '\u{6D}', '\u{41}', '\u{2215}', '\u{6D}', '\u{31}', '\u{65E5}', '\u{32}', '\u{65E5}', '\u{33}',
+    '\u{65E5}', '\u{34}', '\u{65E5}', '\u{35}', '\u{65E5}', '\u{36}', '\u{65E5}', '\u{37}', '\u{65E5}', '\u{38}',
+    '\u{65E5}', '\u{39}', '\u{65E5}', '\u{31}', '\u{30}', '\u{65E5}', '\u{31}', '\u{31}', '\u{65E5}', '\u{31}',
+    '\u{32}', '\u{65E5}', '\u{31}', '\u{33}', '\u{65E5}', '\u{31}', '\u{34}', '\u{65E5}', '\u{31}', '\u{35}',
+    '\u{65E5}', '\u{31}', '\u{36}', '\u{65E5}', '\u{31}', '\u{37}', '\u{65E5}', '\u{31}', '\u{38}', '\u{65E5}',
+    '\u{31}', '\u{39}', '\u{65E5}', '\u{32}', '\u{30}', '\u{65E5}', '\u{32}', '\u{31}', '\u{65E5}', '\u{32}',
+    '\u{32}', '\u{65E5}', '\u{32}', '\u{33}', '\u{65E5}', '\u{32}', '\u{34}', '\u{65E5}', '\u{32}', '\u{35}',
+    '\u{65E5}', '\u{32}', '\u{36}', '\u{65E5}', '\u{32}', '\u{37}', '\u{65E5}', '\u{32}', '\u{38}', '\u{65E5}',
+    '\u{32}', '\u{39}', '\u{65E5}', '\u{33}', '\u{30}', '\u{65E5}', '\u{33}', '\u{31}', '\u{65E5}', '\u{67}',
+    '\u{61}', '\u{6C}', '\u{44A}', '\u{44C}', '\u{A76F}', '\u{43}', '\u{46}', '\u{51}', '\u{126}', '\u{153}',
+    '\u{A727}', '\u{AB37}', '\u{26B}', '\u{AB52}', '\u{28D}', '\u{8C48}', '\u{66F4}', '\u{8ECA}', '\u{8CC8}', '\u{6ED1}',
+    '\u{4E32}', '\u{53E5}', '\u{9F9C}', '\u{9F9C}', '\u{5951}', '\u{91D1}', '\u{5587}', '\u{5948}', '\u{61F6}', '\u{7669}',
+    '\u{7F85}', '\u{863F}', '\u{87BA}', '\u{88F8}', '\u{908F}', '\u{6A02}', '\u{6D1B}', '\u{70D9}', '\u{73DE}', '\u{843D}',
+    '\u{916A}', '\u{99F1}', '\u{4E82}', '\u{5375}', '\u{6B04}', '\u{721B}', '\u{862D}', '\u{9E1E}', '\u{5D50}', '\u{6FEB}',
+    '\u{85CD}', '\u{8964}', '\u{62C9}', '\u{81D8}', '\u{881F}', '\u{5ECA}', '\u{6717}', '\u{6D6A}', '\u{72FC}', '\u{90CE}',
+    '\u{4F86}', '\u{51B7}', '\u{52DE}', '\u{64C4}', '\u{6AD3}', '\u{7210}', '\u{76E7}', '\u{8001}', '\u{8606}', '\u{865C}',
+    '\u{8DEF}', '\u{9732}', '\u{9B6F}', '\u{9DFA}', '\u{788C}', '\u{797F}', '\u{7DA0}', '\u{83C9}', '\u{9304}', '\u{9E7F}',
+    '\u{8AD6}', '\u{58DF}', '\u{5F04}', '\u{7C60}', '\u{807E}', '\u{7262}', '\u{78CA}', '\u{8CC2}', '\u{96F7}', '\u{58D8}',
+    '\u{5C62}', '\u{6A13}', '\u{6DDA}', '\u{6F0F}', '\u{7D2F}', '\u{7E37}', '\u{964B}', '\u{52D2}', '\u{808B}', '\u{51DC}',
+    '\u{51CC}', '\u{7A1C}', '\u{7DBE}', '\u{83F1}', '\u{9675}', '\u{8B80}', '\u{62CF}', '\u{6A02}', '\u{8AFE}', '\u{4E39}',
+    '\u{5BE7}', '\u{6012}', '\u{7387}', '\u{7570}', '\u{5317}', '\u{78FB}', '\u{4FBF}', '\u{5FA9}', '\u{4E0D}', '\u{6CCC}',
+    '\u{6578}', '\u{7D22}', '\u{53C3}', '\u{585E}', '\u{7701}', '\u{8449}', '\u{8AAA}', '\u{6BBA}', '\u{8FB0}', '\u{6C88}',
+    '\u{62FE}', '\u{82E5}', '\u{63A0}', '\u{7565}', '\u{4EAE}', '\u{5169}', '\u{51C9}', '\u{6881}', '\u{7CE7}', '\u{826F}',
+    '\u{8AD2}', '\u{91CF}', '\u{52F5}', '\u{5442}', '\u{5973}', '\u{5EEC}', '\u{65C5}', '\u{6FFE}', '\u{792A}', '\u{95AD}',
+    '\u{9A6A}', '\u{9E97}', '\u{9ECE}', '\u{529B}', '\u{66C6}', '\u{6B77}', '\u{8F62}', '\u{5E74}', '\u{6190}', '\u{6200}',
+    '\u{649A}', '\u{6F23}', '\u{7149}', '\u{7489}', '\u{79CA}', '\u{7DF4}', '\u{806F}', '\u{8F26}', '\u{84EE}', '\u{9023}',
+    '\u{934A}', '\u{5217}', '\u{52A3}', '\u{54BD}', '\u{70C8}', '\u{88C2}', '\u{8AAA}', '\u{5EC9}', '\u{5FF5}', '\u{637B}',
+    '\u{6BAE}', '\u{7C3E}', '\u{7375}', '\u{4EE4}', '\u{56F9}', '\u{5BE7}', '\u{5DBA}', '\u{601C}', '\u{73B2}', '\u{7469}',
+    '\u{7F9A}', '\u{8046}', '\u{9234}', '\u{96F6}', '\u{9748}', '\u{9818}', '\u{4F8B}', '\u{79AE}', '\u{91B4}', '\u{96B8}',
+    '\u{60E1}', '\u{4E86}', '\u{50DA}', '\u{5BEE}', '\u{5C3F}', '\u{6599}', '\u{6A02}', '\u{71CE}', '\u{7642}', '\u{84FC}',
+    '\u{907C}', '\u{9F8D}', '\u{6688}', '\u{962E}', '\u{5289}', '\u{677B}', '\u{67F3}', '\u{6D41}', '\u{6E9C}', '\u{7409}',
+    '\u{7559}', '\u{786B}', '\u{7D10}', '\u{985E}', '\u{516D}', '\u{622E}', '\u{9678}', '\u{502B}', '\u{5D19}', '\u{6DEA}',
+    '\u{8F2A}', '\u{5F8B}', '\u{6144}', '\u{6817}', '\u{7387}', '\u{9686}', '\u{5229}', '\u{540F}', '\u{5C65}', '\u{6613}',
+    '\u{674E}', '\u{68A8}', '\u{6CE5}', '\u{7406}', '\u{75E2}', '\u{7F79}', '\u{88CF}', '\u{88E1}', '\u{91CC}', '\u{96E2}',
+    '\u{533F}', '\u{6EBA}', '\u{541D}', '\u{71D0}', '\u{7498}', '\u{85FA}', '\u{96A3}', '\u{9C57}', '\u{9E9F}', '\u{6797}',
+    '\u{6DCB}', '\u{81E8}', '\u{7ACB}', '\u{7B20}', '\u{7C92}', '\u{72C0}', '\u{7099}', '\u{8B58}', '\u{4EC0}', '\u{8336}',
+    '\u{523A}', '\u{5207}', '\u{5EA6}', '\u{62D3}', '\u{7CD6}', '\u{5B85}', '\u{6D1E}', '\u{66B4}', '\u{8F3B}', '\u{884C}',
+    '\u{964D}', '\u{898B}', '\u{5ED3}', '\u{5140}', '\u{55C0}', '\u{585A}', '\u{6674}', '\u{51DE}', '\u{732A}', '\u{76CA}',
+    '\u{793C}', '\u{795E}', '\u{7965}', '\u{798F}', '\u{9756}', '\u{7CBE}', '\u{7FBD}', '\u{8612}', '\u{8AF8}', '\u{9038}',
+    '\u{90FD}', '\u{98EF}', '\u{98FC}', '\u{9928}', '\u{9DB4}', '\u{90DE}', '\u{96B7}', '\u{4FAE}', '\u{50E7}', '\u{514D}',
+    '\u{52C9}', '\u{52E4}', '\u{5351}', '\u{559D}', '\u{5606}', '\u{5668}', '\u{5840}', '\u{58A8}', '\u{5C64}', '\u{5C6E}',
+    '\u{6094}', '\u{6168}', '\u{618E}', '\u{61F2}', '\u{654F}', '\u{65E2}', '\u{6691}', '\u{6885}', '\u{6D77}', '\u{6E1A}',
+    '\u{6F22}', '\u{716E}', '\u{722B}', '\u{7422}', '\u{7891}', '\u{793E}', '\u{7949}', '\u{7948}', '\u{7950}', '\u{7956}',
+    '\u{795D}', '\u{798D}', '\u{798E}', '\u{7A40}', '\u{7A81}', '\u{7BC0}', '\u{7DF4}', '\u{7E09}', '\u{7E41}', '\u{7F72}',
+    '\u{8005}', '\u{81ED}', '\u{8279}', '\u{8279}', '\u{8457}', '\u{8910}', '\u{8996}', '\u{8B01}', '\u{8B39}', '\u{8CD3}',
+    '\u{8D08}', '\u{8FB6}', '\u{9038}', '\u{96E3}', '\u{97FF}', '\u{983B}', '\u{6075}', '\u{242EE}', '\u{8218}', '\u{4E26}',
+    '\u{51B5}', '\u{5168}', '\u{4F80}', '\u{5145}', '\u{5180}', '\u{52C7}', '\u{52FA}', '\u{559D}', '\u{5555}', '\u{5599}',
+    '\u{55E2}', '\u{585A}', '\u{58B3}', '\u{5944}', '\u{5954}', '\u{5A62}', '\u{5B28}', '\u{5ED2}', '\u{5ED9}', '\u{5F69}',
+    '\u{5FAD}', '\u{60D8}', '\u{614E}', '\u{6108}', '\u{618E}', '\u{6160}', '\u{61F2}', '\u{6234}', '\u{63C4}', '\u{641C}',
+    '\u{6452}', '\u{6556}', '\u{6674}', '\u{6717}', '\u{671B}', '\u{6756}', '\u{6B79}', '\u{6BBA}', '\u{6D41}', '\u{6EDB}',
+    '\u{6ECB}', '\u{6F22}', '\u{701E}', '\u{716E}', '\u{77A7}', '\u{7235}', '\u{72AF}', '\u{732A}', '\u{7471}', '\u{7506}',
+    '\u{753B}', '\u{761D}', '\u{761F}', '\u{76CA}', '\u{76DB}', '\u{76F4}', '\u{774A}', '\u{7740}', '\u{78CC}', '\u{7AB1}',
+    '\u{7BC0}', '\u{7C7B}', '\u{7D5B}', '\u{7DF4}', '\u{7F3E}', '\u{8005}', '\u{8352}', '\u{83EF}', '\u{8779}', '\u{8941}',
+    '\u{8986}', '\u{8996}', '\u{8ABF}', '\u{8AF8}', '\u{8ACB}', '\u{8B01}', '\u{8AFE}', '\u{8AED}', '\u{8B39}', '\u{8B8A}',
+    '\u{8D08}', '\u{8F38}', '\u{9072}', '\u{9199}', '\u{9276}', '\u{967C}', '\u{96E3}', '\u{9756}', '\u{97DB}', '\u{97FF}',
+    '\u{980B}', '\u{983B}', '\u{9B12}', '\u{9F9C}', '\u{2284A}', '\u{22844}', '\u{233D5}', '\u{3B9D}', '\u{4018}', '\u{4039}',
+    '\u{25249}', '\u{25CD0}', '\u{27ED3}', '\u{9F43}', '\u{9F8E}', '\u{66}', '\u{66}', '\u{66}', '\u{69}', '\u{66}',
+    '\u{6C}', '\u{66}', '\u{66}', '\u{69}', '\u{66}', '\u{66}', '\u{6C}', '\u{73}', '\u{74}', '\u{73}',
+    '\u{74}', '\u{574}', '\u{576}', '\u{574}', '\u{565}', '\u{574}', '\u{56B}', '\u{57E}', '\u{576}', '\u{574}',
+    '\u{56D}', '\u{5D9}', '\u{5B4}', '\u{5F2}', '\u{5B7}', '\u{5E2}', '\u{5D0}', '\u{5D3}', '\u{5D4}', '\u{5DB}',
+    '\u{5DC}', '\u{5DD}', '\u{5E8}', '\u{5EA}', '\u{2B}', '\u{5E9}', '\u{5C1}', '\u{5E9}', '\u{5C2}', '\u{5E9}',
+    '\u{5BC}', '\u{5C1}', '\u{5E9}', '\u{5BC}', '\u{5C2}', '\u{5D0}', '\u{5B7}', '\u{5D0}', '\u{5B8}', '\u{5D0}',
+    '\u{5BC}', '\u{5D1}', '\u{5BC}', '\u{5D2}', '\u{5BC}', '\u{5D3}', '\u{5BC}', '\u{5D4}', '\u{5BC}', '\u{5D5}',
+    '\u{5BC}', '\u{5D6}', '\u{5BC}', '\u{5D8}', '\u{5BC}', '\u{5D9}', '\u{5BC}', '\u{5DA}', '\u{5BC}', '\u{5DB}',
+    '\u{5BC}', '\u{5DC}', '\u{5BC}', '\u{5DE}', '\u{5BC}', '\u{5E0}', '\u{5BC}', '\u{5E1}', '\u{5BC}', '\u{5E3}',
+    '\u{5BC}', '\u{5E4}', '\u{5BC}', '\u{5E6}', '\u{5BC}', '\u{5E7}', '\u{5BC}', '\u{5E8}', '\u{5BC}', '\u{5E9}',
+    '\u{5BC}', '\u{5EA}', '\u{5BC}', '\u{5D5}', '\u{5B9}', '\u{5D1}', '\u{5BF}', '\u{5DB}', '\u{5BF}', '\u{5E4}',
+    '\u{5BF}', '\u{5D0}', '\u{5DC}', '\u{671}', '\u{671}', '\u{67B}', '\u{67B}', '\u{67B}', '\u{67B}', '\u{67E}',
+    '\u{67E}', '\u{67E}', '\u{67E}', '\u{680}', '\u{680}', '\u{680}', '\u{680}', '\u{67A}', '\u{67A}', '\u{67A}',
+    '\u{67A}', '\u{67F}', '\u{67F}', '\u{67F}', '\u{67F}', '\u{679}', '\u{679}', '\u{679}', '\u{679}', '\u{6A4}',
+    '\u{6A4}', '\u{6A4}', '\u{6A4}', '\u{6A6}', '\u{6A6}', '\u{6A6}', '\u{6A6}', '\u{684}', '\u{684}', '\u{684}',
+    '\u{684}', '\u{683}', '\u{683}', '\u{683}', '\u{683}', '\u{686}', '\u{686}', '\u{686}', '\u{686}', '\u{687}',
+    '\u{687}', '\u{687}', '\u{687}', '\u{68D}', '\u{68D}', '\u{68C}', '\u{68C}', '\u{68E}', '\u{68E}', '\u{688}',
+    '\u{688}', '\u{698}', '\u{698}', '\u{691}', '\u{691}', '\u{6A9}', '\u{6A9}', '\u{6A9}', '\u{6A9}', '\u{6AF}',
+    '\u{6AF}', '\u{6AF}', '\u{6AF}', '\u{6B3}', '\u{6B3}', '\u{6B3}', '\u{6B3}', '\u{6B1}', '\u{6B1}', '\u{6B1}',
+    '\u{6B1}', '\u{6BA}', '\u{6BA}', '\u{6BB}', '\u{6BB}', '\u{6BB}', '\u{6BB}', '\u{6D5}', '\u{654}', '\u{6D5}',
+    '\u{654}', '\u{6C1}', '\u{6C1}', '\u{6C1}', '\u{6C1}', '\u{6BE}', '\u{6BE}', '\u{6BE}', '\u{6BE}', '\u{6D2}',
+    '\u{6D2}', '\u{6D2}', '\u{654}', '\u{6D2}', '\u{654}', '\u{6AD}', '\u{6AD}', '\u{6AD}', '\u{6AD}', '\u{6C7}',
+    '\u{6C7}', '\u{6C6}', '\u{6C6}', '\u{6C8}', '\u{6C8}', '\u{6C7}', '\u{674}', '\u{6CB}', '\u{6CB}', '\u{6C5}',
+    '\u{6C5}', '\u{6C9}', '\u{6C9}', '\u{6D0}', '\u{6D0}', '\u{6D0}', '\u{6D0}', '\u{649}', '\u{649}', '\u{64A}',
+    '\u{654}', '\u{627}', '\u{64A}', '\u{654}', '\u{627}', '\u{64A}', '\u{654}', '\u{6D5}', '\u{64A}', '\u{654}',
+    '\u{6D5}', '\u{64A}', '\u{654}', '\u{648}', '\u{64A}', '\u{654}', '\u{648}', '\u{64A}', '\u{654}', '\u{6C7}',
+    '\u{64A}', '\u{654}', '\u{6C7}', '\u{64A}', '\u{654}', '\u{6C6}', '\u{64A}', '\u{654}', '\u{6C6}', '\u{64A}',
+    '\u{654}', '\u{6C8}', '\u{64A}', '\u{654}', '\u{6C8}', '\u{64A}', '\u{654}', '\u{6D0}', '\u{64A}', '\u{654}',
+    '\u{6D0}', '\u{64A}', '\u{654}', '\u{6D0}', '\u{64A}', '\u{654}', '\u{649}', '\u{64A}', '\u{654}', '\u{649}',
+    '\u{64A}', '\u{654}', '\u{649}', '\u{6CC}', '\u{6CC}', '\u{6CC}', '\u{6CC}', '\u{64A}', '\u{654}', '\u{62C}',
+    '\u{64A}', '\u{654}', '\u{62D}', '\u{64A}', '\u{654}', '\u{645}', '\u{64A}', '\u{654}', '\u{649}', '\u{64A}',
+    '\u{654}', '\u{64A}', '\u{628}', '\u{62C}', '\u{628}', '\u{62D}', '\u{628}', '\u{62E}', '\u{628}', '\u{645}',
+    '\u{628}', '\u{649}', '\u{628}', '\u{64A}', '\u{62A}', '\u{62C}', '\u{62A}', '\u{62D}', '\u{62A}', '\u{62E}',
+    '\u{62A}', '\u{645}', '\u{62A}', '\u{649}', '\u{62A}', '\u{64A}', '\u{62B}', '\u{62C}', '\u{62B}', '\u{645}',
+    '\u{62B}', '\u{649}', '\u{62B}', '\u{64A}', '\u{62C}', '\u{62D}', '\u{62C}', '\u{645}', '\u{62D}', '\u{62C}',
+    '\u{62D}', '\u{645}', '\u{62E}', '\u{62C}', '\u{62E}', '\u{62D}', '\u{62E}', '\u{645}', '\u{633}', '\u{62C}',
+    '\u{633}', '\u{62D}', '\u{633}', '\u{62E}', '\u{633}', '\u{645}', '\u{635}', '\u{62D}', '\u{635}', '\u{645}',
+    '\u{636}', '\u{62C}', '\u{636}', '\u{62D}', '\u{636}', '\u{62E}', '\u{636}', '\u{645}', '\u{637}', '\u{62D}',
+    '\u{637}', '\u{645}', '\u{638}', '\u{645}', '\u{639}', '\u{62C}', '\u{639}', '\u{645}', '\u{63A}', '\u{62C}',
+    '\u{63A}', '\u{645}', '\u{641}', '\u{62C}', '\u{641}', '\u{62D}', '\u{641}', '\u{62E}', '\u{641}', '\u{645}',
+    '\u{641}', '\u{649}', '\u{641}', '\u{64A}', '\u{642}', '\u{62D}', '\u{642}', '\u{645}', '\u{642}', '\u{649}',
+    '\u{642}', '\u{64A}', '\u{643}', '\u{627}', '\u{643}', '\u{62C}', '\u{643}', '\u{62D}', '\u{643}', '\u{62E}',
+    '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{643}', '\u{649}', '\u{643}', '\u{64A}', '\u{644}', '\u{62C}',
+    '\u{644}', '\u{62D}', '\u{644}', '\u{62E}', '\u{644}', '\u{645}', '\u{644}', '\u{649}', '\u{644}', '\u{64A}',
+    '\u{645}', '\u{62C}', '\u{645}', '\u{62D}', '\u{645}', '\u{62E}', '\u{645}', '\u{645}', '\u{645}', '\u{649}',
+    '\u{645}', '\u{64A}', '\u{646}', '\u{62C}', '\u{646}', '\u{62D}', '\u{646}', '\u{62E}', '\u{646}', '\u{645}',
+    '\u{646}', '\u{649}', '\u{646}', '\u{64A}', '\u{647}', '\u{62C}', '\u{647}', '\u{645}', '\u{647}', '\u{649}',
+    '\u{647}', '\u{64A}', '\u{64A}', '\u{62C}', '\u{64A}', '\u{62D}', '\u{64A}', '\u{62E}', '\u{64A}', '\u{645}',
+    '\u{64A}', '\u{649}', '\u{64A}', '\u{64A}', '\u{630}', '\u{670}', '\u{631}', '\u{670}', '\u{649}', '\u{670}',
+    '\u{20}', '\u{64C}', '\u{651}', '\u{20}', '\u{64D}', '\u{651}', '\u{20}', '\u{64E}', '\u{651}', '\u{20}',
+    '\u{64F}', '\u{651}', '\u{20}', '\u{650}', '\u{651}', '\u{20}', '\u{651}', '\u{670}', '\u{64A}', '\u{654}',
+    '\u{631}', '\u{64A}', '\u{654}', '\u{632}', '\u{64A}', '\u{654}', '\u{645}', '\u{64A}', '\u{654}', '\u{646}',
+    '\u{64A}', '\u{654}', '\u{649}', '\u{64A}', '\u{654}', '\u{64A}', '\u{628}', '\u{631}', '\u{628}', '\u{632}',
+    '\u{628}', '\u{645}', '\u{628}', '\u{646}', '\u{628}', '\u{649}', '\u{628}', '\u{64A}', '\u{62A}', '\u{631}',
+    '\u{62A}', '\u{632}', '\u{62A}', '\u{645}', '\u{62A}', '\u{646}', '\u{62A}', '\u{649}', '\u{62A}', '\u{64A}',
+    '\u{62B}', '\u{631}', '\u{62B}', '\u{632}', '\u{62B}', '\u{645}', '\u{62B}', '\u{646}', '\u{62B}', '\u{649}',
+    '\u{62B}', '\u{64A}', '\u{641}', '\u{649}', '\u{641}', '\u{64A}', '\u{642}', '\u{649}', '\u{642}', '\u{64A}',
+    '\u{643}', '\u{627}', '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{643}', '\u{649}', '\u{643}', '\u{64A}',
+    '\u{644}', '\u{645}', '\u{644}', '\u{649}', '\u{644}', '\u{64A}', '\u{645}', '\u{627}', '\u{645}', '\u{645}',
+    '\u{646}', '\u{631}', '\u{646}', '\u{632}', '\u{646}', '\u{645}', '\u{646}', '\u{646}', '\u{646}', '\u{649}',
+    '\u{646}', '\u{64A}', '\u{649}', '\u{670}', '\u{64A}', '\u{631}', '\u{64A}', '\u{632}', '\u{64A}', '\u{645}',
+    '\u{64A}', '\u{646}', '\u{64A}', '\u{649}', '\u{64A}', '\u{64A}', '\u{64A}', '\u{654}', '\u{62C}', '\u{64A}',
+    '\u{654}', '\u{62D}', '\u{64A}', '\u{654}', '\u{62E}', '\u{64A}', '\u{654}', '\u{645}', '\u{64A}', '\u{654}',
+    '\u{647}', '\u{628}', '\u{62C}', '\u{628}', '\u{62D}', '\u{628}', '\u{62E}', '\u{628}', '\u{645}', '\u{628}',
+    '\u{647}', '\u{62A}', '\u{62C}', '\u{62A}', '\u{62D}', '\u{62A}', '\u{62E}', '\u{62A}', '\u{645}', '\u{62A}',
+    '\u{647}', '\u{62B}', '\u{645}', '\u{62C}', '\u{62D}', '\u{62C}', '\u{645}', '\u{62D}', '\u{62C}', '\u{62D}',
+    '\u{645}', '\u{62E}', '\u{62C}', '\u{62E}', '\u{645}', '\u{633}', '\u{62C}', '\u{633}', '\u{62D}', '\u{633}',
+    '\u{62E}', '\u{633}', '\u{645}', '\u{635}', '\u{62D}', '\u{635}', '\u{62E}', '\u{635}', '\u{645}', '\u{636}',
+    '\u{62C}', '\u{636}', '\u{62D}', '\u{636}', '\u{62E}', '\u{636}', '\u{645}', '\u{637}', '\u{62D}', '\u{638}',
+    '\u{645}', '\u{639}', '\u{62C}', '\u{639}', '\u{645}', '\u{63A}', '\u{62C}', '\u{63A}', '\u{645}', '\u{641}',
+    '\u{62C}', '\u{641}', '\u{62D}', '\u{641}', '\u{62E}', '\u{641}', '\u{645}', '\u{642}', '\u{62D}', '\u{642}',
+    '\u{645}', '\u{643}', '\u{62C}', '\u{643}', '\u{62D}', '\u{643}', '\u{62E}', '\u{643}', '\u{644}', '\u{643}',
+    '\u{645}', '\u{644}', '\u{62C}', '\u{644}', '\u{62D}', '\u{644}', '\u{62E}', '\u{644}', '\u{645}', '\u{644}',
+    '\u{647}', '\u{645}', '\u{62C}', '\u{645}', '\u{62D}', '\u{645}', '\u{62E}', '\u{645}', '\u{645}', '\u{646}',
+    '\u{62C}', '\u{646}', '\u{62D}', '\u{646}', '\u{62E}', '\u{646}', '\u{645}', '\u{646}', '\u{647}', '\u{647}',
+    '\u{62C}', '\u{647}', '\u{645}', '\u{647}', '\u{670}', '\u{64A}', '\u{62C}', '\u{64A}', '\u{62D}', '\u{64A}',
+    '\u{62E}', '\u{64A}', '\u{645}', '\u{64A}', '\u{647}', '\u{64A}', '\u{654}', '\u{645}', '\u{64A}', '\u{654}',
+    '\u{647}', '\u{628}', '\u{645}', '\u{628}', '\u{647}', '\u{62A}', '\u{645}', '\u{62A}', '\u{647}', '\u{62B}',
+    '\u{645}', '\u{62B}', '\u{647}', '\u{633}', '\u{645}', '\u{633}', '\u{647}', '\u{634}', '\u{645}', '\u{634}',
+    '\u{647}', '\u{643}', '\u{644}', '\u{643}', '\u{645}', '\u{644}', '\u{645}', '\u{646}', '\u{645}', '\u{646}',
+    '\u{647}', '\u{64A}', '\u{645}', '\u{64A}', '\u{647}', '\u{640}', '\u{64E}', '\u{651}', '\u{640}', '\u{64F}',
+    '\u{651}', '\u{640}', '\u{650}', '\u{651}', '\u{637}', '\u{649}', '\u{637}', '\u{64A}', '\u{639}', '\u{649}',
+    '\u{639}', '\u{64A}', '\u{63A}', '\u{649}', '\u{63A}', '\u{64A}', '\u{633}', '\u{649}', '\u{633}', '\u{64A}',
+    '\u{634}', '\u{649}', '\u{634}', '\u{64A}', '\u{62D}', '\u{649}', '\u{62D}', '\u{64A}', '\u{62C}', '\u{649}',
+    '\u{62C}', '\u{64A}', '\u{62E}', '\u{649}', '\u{62E}', '\u{64A}', '\u{635}', '\u{649}', '\u{635}', '\u{64A}',
+    '\u{636}', '\u{649}', '\u{636}', '\u{64A}', '\u{634}', '\u{62C}', '\u{634}', '\u{62D}', '\u{634}', '\u{62E}',
+    '\u{634}', '\u{645}', '\u{634}', '\u{631}', '\u{633}', '\u{631}', '\u{635}', '\u{631}', '\u{636}', '\u{631}',
+    '\u{637}', '\u{649}', '\u{637}', '\u{64A}', '\u{639}', '\u{649}', '\u{639}', '\u{64A}', '\u{63A}', '\u{649}',
+    '\u{63A}', '\u{64A}', '\u{633}', '\u{649}', '\u{633}', '\u{64A}', '\u{634}', '\u{649}', '\u{634}', '\u{64A}',
+    '\u{62D}', '\u{649}', '\u{62D}', '\u{64A}', '\u{62C}', '\u{649}', '\u{62C}', '\u{64A}', '\u{62E}', '\u{649}',
+    '\u{62E}', '\u{64A}', '\u{635}', '\u{649}', '\u{635}', '\u{64A}', '\u{636}', '\u{649}', '\u{636}', '\u{64A}',
+    '\u{634}', '\u{62C}', '\u{634}', '\u{62D}', '\u{634}', '\u{62E}', '\u{634}', '\u{645}', '\u{634}', '\u{631}',
+    '\u{633}', '\u{631}', '\u{635}', '\u{631}', '\u{636}', '\u{631}', '\u{634}', '\u{62C}', '\u{634}', '\u{62D}',
+    '\u{634}', '\u{62E}', '\u{634}', '\u{645}', '\u{633}', '\u{647}', '\u{634}', '\u{647}', '\u{637}', '\u{645}',
+    '\u{633}', '\u{62C}', '\u{633}', '\u{62D}', '\u{633}', '\u{62E}', '\u{634}', '\u{62C}', '\u{634}', '\u{62D}',
+    '\u{634}', '\u{62E}', '\u{637}', '\u{645}', '\u{638}', '\u{645}', '\u{627}', '\u{64B}', '\u{627}', '\u{64B}',
+    '\u{62A}', '\u{62C}', '\u{645}', '\u{62A}', '\u{62D}', '\u{62C}', '\u{62A}', '\u{62D}', '\u{62C}', '\u{62A}',
+    '\u{62D}', '\u{645}', '\u{62A}', '\u{62E}', '\u{645}', '\u{62A}', '\u{645}', '\u{62C}', '\u{62A}', '\u{645}',
+    '\u{62D}', '\u{62A}', '\u{645}', '\u{62E}', '\u{62C}', '\u{645}', '\u{62D}', '\u{62C}', '\u{645}', '\u{62D}',
+    '\u{62D}', '\u{645}', '\u{64A}', '\u{62D}', '\u{645}', '\u{649}', '\u{633}', '\u{62D}', '\u{62C}', '\u{633}',
+    '\u{62C}', '\u{62D}', '\u{633}', '\u{62C}', '\u{649}', '\u{633}', '\u{645}', '\u{62D}', '\u{633}', '\u{645}',
+    '\u{62D}', '\u{633}', '\u{645}', '\u{62C}', '\u{633}', '\u{645}', '\u{645}', '\u{633}', '\u{645}', '\u{645}',
+    '\u{635}', '\u{62D}', '\u{62D}', '\u{635}', '\u{62D}', '\u{62D}', '\u{635}', '\u{645}', '\u{645}', '\u{634}',
+    '\u{62D}', '\u{645}', '\u{634}', '\u{62D}', '\u{645}', '\u{634}', '\u{62C}', '\u{64A}', '\u{634}', '\u{645}',
+    '\u{62E}', '\u{634}', '\u{645}', '\u{62E}', '\u{634}', '\u{645}', '\u{645}', '\u{634}', '\u{645}', '\u{645}',
+    '\u{636}', '\u{62D}', '\u{649}', '\u{636}', '\u{62E}', '\u{645}', '\u{636}', '\u{62E}', '\u{645}', '\u{637}',
+    '\u{645}', '\u{62D}', '\u{637}', '\u{645}', '\u{62D}', '\u{637}', '\u{645}', '\u{645}', '\u{637}', '\u{645}',
+    '\u{64A}', '\u{639}', '\u{62C}', '\u{645}', '\u{639}', '\u{645}', '\u{645}', '\u{639}', '\u{645}', '\u{645}',
+    '\u{639}', '\u{645}', '\u{649}', '\u{63A}', '\u{645}', '\u{645}', '\u{63A}', '\u{645}', '\u{64A}', '\u{63A}',
+    '\u{645}', '\u{649}', '\u{641}', '\u{62E}', '\u{645}', '\u{641}', '\u{62E}', '\u{645}', '\u{642}', '\u{645}',
+    '\u{62D}', '\u{642}', '\u{645}', '\u{645}', '\u{644}', '\u{62D}', '\u{645}', '\u{644}', '\u{62D}', '\u{64A}',
+    '\u{644}', '\u{62D}', '\u{649}', '\u{644}', '\u{62C}', '\u{62C}', '\u{644}', '\u{62C}', '\u{62C}', '\u{644}',
+    '\u{62E}', '\u{645}', '\u{644}', '\u{62E}', '\u{645}', '\u{644}', '\u{645}', '\u{62D}', '\u{644}', '\u{645}',
+    '\u{62D}', '\u{645}', '\u{62D}', '\u{62C}', '\u{645}', '\u{62D}', '\u{645}', '\u{645}', '\u{62D}', '\u{64A}',
+    '\u{645}', '\u{62C}', '\u{62D}', '\u{645}', '\u{62C}', '\u{645}', '\u{645}', '\u{62E}', '\u{62C}', '\u{645}',
+    '\u{62E}', '\u{645}', '\u{645}', '\u{62C}', '\u{62E}', '\u{647}', '\u{645}', '\u{62C}', '\u{647}', '\u{645}',
+    '\u{645}', '\u{646}', '\u{62D}', '\u{645}', '\u{646}', '\u{62D}', '\u{649}', '\u{646}', '\u{62C}', '\u{645}',
+    '\u{646}', '\u{62C}', '\u{645}', '\u{646}', '\u{62C}', '\u{649}', '\u{646}', '\u{645}', '\u{64A}', '\u{646}',
+    '\u{645}', '\u{649}', '\u{64A}', '\u{645}', '\u{645}', '\u{64A}', '\u{645}', '\u{645}', '\u{628}', '\u{62E}',
+    '\u{64A}', '\u{62A}', '\u{62C}', '\u{64A}', '\u{62A}', '\u{62C}', '\u{649}', '\u{62A}', '\u{62E}', '\u{64A}',
+    '\u{62A}', '\u{62E}', '\u{649}', '\u{62A}', '\u{645}', '\u{64A}', '\u{62A}', '\u{645}', '\u{649}', '\u{62C}',
+    '\u{645}', '\u{64A}', '\u{62C}', '\u{62D}', '\u{649}', '\u{62C}', '\u{645}', '\u{649}', '\u{633}', '\u{62E}',
+    '\u{649}', '\u{635}', '\u{62D}', '\u{64A}', '\u{634}', '\u{62D}', '\u{64A}', '\u{636}', '\u{62D}', '\u{64A}',
+    '\u{644}', '\u{62C}', '\u{64A}', '\u{644}', '\u{645}', '\u{64A}', '\u{64A}', '\u{62D}', '\u{64A}', '\u{64A}',
+    '\u{62C}', '\u{64A}', '\u{64A}', '\u{645}', '\u{64A}', '\u{645}', '\u{645}', '\u{64A}', '\u{642}', '\u{645}',
+    '\u{64A}', '\u{646}', '\u{62D}', '\u{64A}', '\u{642}', '\u{645}', '\u{62D}', '\u{644}', '\u{62D}', '\u{645}',
+    '\u{639}', '\u{645}', '\u{64A}', '\u{643}', '\u{645}', '\u{64A}', '\u{646}', '\u{62C}', '\u{62D}', '\u{645}',
+    '\u{62E}', '\u{64A}', '\u{644}', '\u{62C}', '\u{645}', '\u{643}', '\u{645}', '\u{645}', '\u{644}', '\u{62C}',
+    '\u{645}', '\u{646}', '\u{62C}', '\u{62D}', '\u{62C}', '\u{62D}', '\u{64A}', '\u{62D}', '\u{62C}', '\u{64A}',
+    '\u{645}', '\u{62C}', '\u{64A}', '\u{641}', '\u{645}', '\u{64A}', '\u{628}', '\u{62D}', '\u{64A}', '\u{643}',
+    '\u{645}', '\u{645}', '\u{639}', '\u{62C}', '\u{645}', '\u{635}', '\u{645}', '\u{645}', '\u{633}', '\u{62E}',
+    '\u{64A}', '\u{646}', '\u{62C}', '\u{64A}', '\u{635}', '\u{644}', '\u{6D2}', '\u{642}', '\u{644}', '\u{6D2}',
+    '\u{627}', '\u{644}', '\u{644}', '\u{647}', '\u{627}', '\u{643}', '\u{628}', '\u{631}', '\u{645}', '\u{62D}',
+    '\u{645}', '\u{62F}', '\u{635}', '\u{644}', '\u{639}', '\u{645}', '\u{631}', '\u{633}', '\u{648}', '\u{644}',
+    '\u{639}', '\u{644}', '\u{64A}', '\u{647}', '\u{648}', '\u{633}', '\u{644}', '\u{645}', '\u{635}', '\u{644}',
+    '\u{649}', '\u{635}', '\u{644}', '\u{649}', '\u{20}', '\u{627}', '\u{644}', '\u{644}', '\u{647}', '\u{20}',
+    '\u{639}', '\u{644}', '\u{64A}', '\u{647}', '\u{20}', '\u{648}', '\u{633}', '\u{644}', '\u{645}', '\u{62C}',
+    '\u{644}', '\u{20}', '\u{62C}', '\u{644}', '\u{627}', '\u{644}', '\u{647}', '\u{631}', '\u{6CC}', '\u{627}',
+    '\u{644}', '\u{2C}', '\u{3001}', '\u{3002}', '\u{3A}', '\u{3B}', '\u{21}', '\u{3F}', '\u{3016}', '\u{3017}',
+    '\u{2E}', '\u{2E}', '\u{2E}', '\u{2E}', '\u{2E}', '\u{2014}', '\u{2013}', '\u{5F}', '\u{5F}', '\u{28}',
+    '\u{29}', '\u{7B}', '\u{7D}', '\u{3014}', '\u{3015}', '\u{3010}', '\u{3011}', '\u{300A}', '\u{300B}', '\u{3008}',
+    '\u{3009}', '\u{300C}', '\u{300D}', '\u{300E}', '\u{300F}', '\u{5B}', '\u{5D}', '\u{20}', '\u{305}', '\u{20}',
+    '\u{305}', '\u{20}', '\u{305}', '\u{20}', '\u{305}', '\u{5F}', '\u{5F}', '\u{5F}', '\u{2C}', '\u{3001}',
+    '\u{2E}', '\u{3B}', '\u{3A}', '\u{3F}', '\u{21}', '\u{2014}', '\u{28}', '\u{29}', '\u{7B}', '\u{7D}',
+    '\u{3014}', '\u{3015}', '\u{23}', '\u{26}', '\u{2A}', '\u{2B}', '\u{2D}', '\u{3C}', '\u{3E}', '\u{3D}',
+    '\u{5C}', '\u{24}', '\u{25}', '\u{40}', '\u{20}', '\u{64B}', '\u{640}', '\u{64B}', '\u{20}', '\u{64C}',
+    '\u{20}', '\u{64D}', '\u{20}', '\u{64E}', '\u{640}', '\u{64E}', '\u{20}', '\u{64F}', '\u{640}', '\u{64F}',
+    '\u{20}', '\u{650}', '\u{640}', '\u{650}', '\u{20}', '\u{651}', '\u{640}', '\u{651}', '\u{20}', '\u{652}',
+    '\u{640}', '\u{652}', '\u{621}', '\u{627}', '\u{653}', '\u{627}', '\u{653}', '\u{627}', '\u{654}', '\u{627}',
+    '\u{654}', '\u{648}', '\u{654}', '\u{648}', '\u{654}', '\u{627}', '\u{655}', '\u{627}', '\u{655}', '\u{64A}',
+    '\u{654}', '\u{64A}', '\u{654}', '\u{64A}', '\u{654}', '\u{64A}', '\u{654}', '\u{627}', '\u{627}', '\u{628}',
+    '\u{628}', '\u{628}', '\u{628}', '\u{629}', '\u{629}', '\u{62A}', '\u{62A}', '\u{62A}', '\u{62A}', '\u{62B}',
+    '\u{62B}', '\u{62B}', '\u{62B}', '\u{62C}', '\u{62C}', '\u{62C}', '\u{62C}', '\u{62D}', '\u{62D}', '\u{62D}',
+    '\u{62D}', '\u{62E}', '\u{62E}', '\u{62E}', '\u{62E}', '\u{62F}', '\u{62F}', '\u{630}', '\u{630}', '\u{631}',
+    '\u{631}', '\u{632}', '\u{632}', '\u{633}', '\u{633}', '\u{633}', '\u{633}', '\u{634}', '\u{634}', '\u{634}',
+    '\u{634}', '\u{635}', '\u{635}', '\u{635}', '\u{635}', '\u{636}', '\u{636}', '\u{636}', '\u{636}', '\u{637}',
+    '\u{637}', '\u{637}', '\u{637}', '\u{638}', '\u{638}', '\u{638}', '\u{638}', '\u{639}', '\u{639}', '\u{639}',
+    '\u{639}', '\u{63A}', '\u{63A}', '\u{63A}', '\u{63A}', '\u{641}', '\u{641}', '\u{641}', '\u{641}', '\u{642}',
+    '\u{642}', '\u{642}', '\u{642}', '\u{643}', '\u{643}', '\u{643}', '\u{643}', '\u{644}', '\u{644}', '\u{644}',
+    '\u{644}', '\u{645}', '\u{645}', '\u{645}', '\u{645}', '\u{646}', '\u{646}', '\u{646}', '\u{646}', '\u{647}',
+    '\u{647}', '\u{647}', '\u{647}', '\u{648}', '\u{648}', '\u{649}', '\u{649}', '\u{64A}', '\u{64A}', '\u{64A}',
+    '\u{64A}', '\u{644}', '\u{627}', '\u{653}', '\u{644}', '\u{627}', '\u{653}', '\u{644}', '\u{627}', '\u{654}',
+    '\u{644}', '\u{627}', '\u{654}', '\u{644}', '\u{627}', '\u{655}', '\u{644}', '\u{627}', '\u{655}', '\u{644}',
+    '\u{627}', '\u{644}', '\u{627}', '\u{21}', '\u{22}', '\u{23}', '\u{24}', '\u{25}', '\u{26}', '\u{27}',
+    '\u{28}', '\u{29}', '\u{2A}', '\u{2B}', '\u{2C}', '\u{2D}', '\u{2E}', '\u{2F}', '\u{30}', '\u{31}',
+    '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}', '\u{38}', '\u{39}', '\u{3A}', '\u{3B}',
+    '\u{3C}', '\u{3D}', '\u{3E}', '\u{3F}', '\u{40}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}',
+    '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}',
+    '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}',
+    '\u{5A}', '\u{5B}', '\u{5C}', '\u{5D}', '\u{5E}', '\u{5F}', '\u{60}', '\u{61}', '\u{62}', '\u{63}',
+    '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}',
+    '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}',
+    '\u{78}', '\u{79}', '\u{7A}', '\u{7B}', '\u{7C}', '\u{7D}', '\u{7E}', '\u{2985}', '\u{2986}', '\u{3002}',
+    '\u{300C}', '\u{300D}', '\u{3001}', '\u{30FB}', '\u{30F2}', '\u{30A1}', '\u{30A3}', '\u{30A5}', '\u{30A7}', '\u{30A9}',
+    '\u{30E3}', '\u{30E5}', '\u{30E7}', '\u{30C3}', '\u{30FC}', '\u{30A2}', '\u{30A4}', '\u{30A6}', '\u{30A8}', '\u{30AA}',
+    '\u{30AB}', '\u{30AD}', '\u{30AF}', '\u{30B1}', '\u{30B3}', '\u{30B5}', '\u{30B7}', '\u{30B9}', '\u{30BB}', '\u{30BD}',
+    '\u{30BF}', '\u{30C1}', '\u{30C4}', '\u{30C6}', '\u{30C8}', '\u{30CA}', '\u{30CB}', '\u{30CC}', '\u{30CD}', '\u{30CE}',
+    '\u{30CF}', '\u{30D2}', '\u{30D5}', '\u{30D8}', '\u{30DB}', '\u{30DE}', '\u{30DF}', '\u{30E0}', '\u{30E1}', '\u{30E2}',
+    '\u{30E4}', '\u{30E6}', '\u{30E8}', '\u{30E9}', '\u{30EA}', '\u{30EB}', '\u{30EC}', '\u{30ED}', '\u{30EF}', '\u{30F3}',
+    '\u{3099}', '\u{309A}', '\u{1160}', '\u{1100}', '\u{1101}', '\u{11AA}', '\u{1102}', '\u{11AC}', '\u{11AD}', '\u{1103}',
+    '\u{1104}', '\u{1105}', '\u{11B0}', '\u{11B1}', '\u{11B2}', '\u{11B3}', '\u{11B4}', '\u{11B5}', '\u{111A}', '\u{1106}',
+    '\u{1107}', '\u{1108}', '\u{1121}', '\u{1109}', '\u{110A}', '\u{110B}', '\u{110C}', '\u{110D}', '\u{110E}', '\u{110F}',
+    '\u{1110}', '\u{1111}', '\u{1112}', '\u{1161}', '\u{1162}', '\u{1163}', '\u{1164}', '\u{1165}', '\u{1166}', '\u{1167}',
+    '\u{1168}', '\u{1169}', '\u{116A}', '\u{116B}', '\u{116C}', '\u{116D}', '\u{116E}', '\u{116F}', '\u{1170}', '\u{1171}',
+    '\u{1172}', '\u{1173}', '\u{1174}', '\u{1175}', '\u{A2}', '\u{A3}', '\u{AC}', '\u{20}', '\u{304}', '\u{A6}',
+    '\u{A5}', '\u{20A9}', '\u{2502}', '\u{2190}', '\u{2191}', '\u{2192}', '\u{2193}', '\u{25A0}', '\u{25CB}', '\u{2D0}',
+    '\u{2D1}', '\u{E6}', '\u{299}', '\u{253}', '\u{2A3}', '\u{AB66}', '\u{2A5}', '\u{2A4}', '\u{256}', '\u{257}',
+    '\u{1D91}', '\u{258}', '\u{25E}', '\u{2A9}', '\u{264}', '\u{262}', '\u{260}', '\u{29B}', '\u{127}', '\u{29C}',
+    '\u{267}', '\u{284}', '\u{2AA}', '\u{2AB}', '\u{26C}', '\u{1DF04}', '\u{A78E}', '\u{26E}', '\u{1DF05}', '\u{28E}',
+    '\u{1DF06}', '\u{F8}', '\u{276}', '\u{277}', '\u{71}', '\u{27A}', '\u{1DF08}', '\u{27D}', '\u{27E}', '\u{280}',
+    '\u{2A8}', '\u{2A6}', '\u{AB67}', '\u{2A7}', '\u{288}', '\u{2C71}', '\u{28F}', '\u{2A1}', '\u{2A2}', '\u{298}',
+    '\u{1C0}', '\u{1C1}', '\u{1C2}', '\u{1DF0A}', '\u{1DF1E}', '\u{11099}', '\u{110BA}', '\u{1109B}', '\u{110BA}', '\u{110A5}',
+    '\u{110BA}', '\u{11131}', '\u{11127}', '\u{11132}', '\u{11127}', '\u{11347}', '\u{1133E}', '\u{11347}', '\u{11357}', '\u{114B9}',
+    '\u{114BA}', '\u{114B9}', '\u{114B0}', '\u{114B9}', '\u{114BD}', '\u{115B8}', '\u{115AF}', '\u{115B9}', '\u{115AF}', '\u{11935}',
+    '\u{11930}', '\u{1D157}', '\u{1D165}', '\u{1D158}', '\u{1D165}', '\u{1D158}', '\u{1D165}', '\u{1D16E}', '\u{1D158}', '\u{1D165}',
+    '\u{1D16F}', '\u{1D158}', '\u{1D165}', '\u{1D170}', '\u{1D158}', '\u{1D165}', '\u{1D171}', '\u{1D158}', '\u{1D165}', '\u{1D172}',
+    '\u{1D1B9}', '\u{1D165}', '\u{1D1BA}', '\u{1D165}', '\u{1D1B9}', '\u{1D165}', '\u{1D16E}', '\u{1D1BA}', '\u{1D165}', '\u{1D16E}',
+    '\u{1D1B9}', '\u{1D165}', '\u{1D16F}', '\u{1D1BA}', '\u{1D165}', '\u{1D16F}', '\u{41}', '\u{42}', '\u{43}', '\u{44}',
+    '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}',
+    '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}',
+    '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}',
+    '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}',
+    '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{42}',
+    '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}',
+    '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}',
+    '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}',
+    '\u{67}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}',
+    '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}',
+    '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}',
+    '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}',
+    '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}',
+    '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}',
+    '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}',
+    '\u{7A}', '\u{41}', '\u{43}', '\u{44}', '\u{47}', '\u{4A}', '\u{4B}', '\u{4E}', '\u{4F}', '\u{50}',
+    '\u{51}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}',
+    '\u{62}', '\u{63}', '\u{64}', '\u{66}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}',
+    '\u{6E}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}',
+    '\u{79}', '\u{7A}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}',
+    '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}',
+    '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}',
+    '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}',
+    '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}',
+    '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{42}', '\u{44}', '\u{45}', '\u{46}', '\u{47}',
+    '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{53}', '\u{54}',
+    '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}',
+    '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}',
+    '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}',
+    '\u{7A}', '\u{41}', '\u{42}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{49}', '\u{4A}', '\u{4B}',
+    '\u{4C}', '\u{4D}', '\u{4F}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}',
+    '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}',
+    '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}',
+    '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{42}', '\u{43}', '\u{44}',
+    '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}',
+    '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}',
+    '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}',
+    '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}',
+    '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{42}',
+    '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}',
+    '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}',
+    '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}',
+    '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}',
+    '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}',
+    '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}',
+    '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}',
+    '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}',
+    '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}',
+    '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}',
+    '\u{79}', '\u{7A}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}',
+    '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}',
+    '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{61}', '\u{62}',
+    '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}',
+    '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}', '\u{75}', '\u{76}',
+    '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}',
+    '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}',
+    '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}',
+    '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}',
+    '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}',
+    '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{41}', '\u{42}', '\u{43}', '\u{44}',
+    '\u{45}', '\u{46}', '\u{47}', '\u{48}', '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}',
+    '\u{4F}', '\u{50}', '\u{51}', '\u{52}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}',
+    '\u{59}', '\u{5A}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}',
+    '\u{69}', '\u{6A}', '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}',
+    '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{131}', '\u{237}',
+    '\u{391}', '\u{392}', '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39A}',
+    '\u{39B}', '\u{39C}', '\u{39D}', '\u{39E}', '\u{39F}', '\u{3A0}', '\u{3A1}', '\u{398}', '\u{3A3}', '\u{3A4}',
+    '\u{3A5}', '\u{3A6}', '\u{3A7}', '\u{3A8}', '\u{3A9}', '\u{2207}', '\u{3B1}', '\u{3B2}', '\u{3B3}', '\u{3B4}',
+    '\u{3B5}', '\u{3B6}', '\u{3B7}', '\u{3B8}', '\u{3B9}', '\u{3BA}', '\u{3BB}', '\u{3BC}', '\u{3BD}', '\u{3BE}',
+    '\u{3BF}', '\u{3C0}', '\u{3C1}', '\u{3C2}', '\u{3C3}', '\u{3C4}', '\u{3C5}', '\u{3C6}', '\u{3C7}', '\u{3C8}',
+    '\u{3C9}', '\u{2202}', '\u{3B5}', '\u{3B8}', '\u{3BA}', '\u{3C6}', '\u{3C1}', '\u{3C0}', '\u{391}', '\u{392}',
+    '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39A}', '\u{39B}', '\u{39C}',
+    '\u{39D}', '\u{39E}', '\u{39F}', '\u{3A0}', '\u{3A1}', '\u{398}', '\u{3A3}', '\u{3A4}', '\u{3A5}', '\u{3A6}',
+    '\u{3A7}', '\u{3A8}', '\u{3A9}', '\u{2207}', '\u{3B1}', '\u{3B2}', '\u{3B3}', '\u{3B4}', '\u{3B5}', '\u{3B6}',
+    '\u{3B7}', '\u{3B8}', '\u{3B9}', '\u{3BA}', '\u{3BB}', '\u{3BC}', '\u{3BD}', '\u{3BE}', '\u{3BF}', '\u{3C0}',
+    '\u{3C1}', '\u{3C2}', '\u{3C3}', '\u{3C4}', '\u{3C5}', '\u{3C6}', '\u{3C7}', '\u{3C8}', '\u{3C9}', '\u{2202}',
+    '\u{3B5}', '\u{3B8}', '\u{3BA}', '\u{3C6}', '\u{3C1}', '\u{3C0}', '\u{391}', '\u{392}', '\u{393}', '\u{394}',
+    '\u{395}', '\u{396}', '\u{397}', '\u{398}', '\u{399}', '\u{39A}', '\u{39B}', '\u{39C}', '\u{39D}', '\u{39E}',
+    '\u{39F}', '\u{3A0}', '\u{3A1}', '\u{398}', '\u{3A3}', '\u{3A4}', '\u{3A5}', '\u{3A6}', '\u{3A7}', '\u{3A8}',
+    '\u{3A9}', '\u{2207}', '\u{3B1}', '\u{3B2}', '\u{3B3}', '\u{3B4}', '\u{3B5}', '\u{3B6}', '\u{3B7}', '\u{3B8}',
+    '\u{3B9}', '\u{3BA}', '\u{3BB}', '\u{3BC}', '\u{3BD}', '\u{3BE}', '\u{3BF}', '\u{3C0}', '\u{3C1}', '\u{3C2}',
+    '\u{3C3}', '\u{3C4}', '\u{3C5}', '\u{3C6}', '\u{3C7}', '\u{3C8}', '\u{3C9}', '\u{2202}', '\u{3B5}', '\u{3B8}',
+    '\u{3BA}', '\u{3C6}', '\u{3C1}', '\u{3C0}', '\u{391}', '\u{392}', '\u{393}', '\u{394}', '\u{395}', '\u{396}',
+    '\u{397}', '\u{398}', '\u{399}', '\u{39A}', '\u{39B}', '\u{39C}', '\u{39D}', '\u{39E}', '\u{39F}', '\u{3A0}',
+    '\u{3A1}', '\u{398}', '\u{3A3}', '\u{3A4}', '\u{3A5}', '\u{3A6}', '\u{3A7}', '\u{3A8}', '\u{3A9}', '\u{2207}',
+    '\u{3B1}', '\u{3B2}', '\u{3B3}', '\u{3B4}', '\u{3B5}', '\u{3B6}', '\u{3B7}', '\u{3B8}', '\u{3B9}', '\u{3BA}',
+    '\u{3BB}', '\u{3BC}', '\u{3BD}', '\u{3BE}', '\u{3BF}', '\u{3C0}', '\u{3C1}', '\u{3C2}', '\u{3C3}', '\u{3C4}',
+    '\u{3C5}', '\u{3C6}', '\u{3C7}', '\u{3C8}', '\u{3C9}', '\u{2202}', '\u{3B5}', '\u{3B8}', '\u{3BA}', '\u{3C6}',
+    '\u{3C1}', '\u{3C0}', '\u{391}', '\u{392}', '\u{393}', '\u{394}', '\u{395}', '\u{396}', '\u{397}', '\u{398}',
+    '\u{399}', '\u{39A}', '\u{39B}', '\u{39C}', '\u{39D}', '\u{39E}', '\u{39F}', '\u{3A0}', '\u{3A1}', '\u{398}',
+    '\u{3A3}', '\u{3A4}', '\u{3A5}', '\u{3A6}', '\u{3A7}', '\u{3A8}', '\u{3A9}', '\u{2207}', '\u{3B1}', '\u{3B2}',
+    '\u{3B3}', '\u{3B4}', '\u{3B5}', '\u{3B6}', '\u{3B7}', '\u{3B8}', '\u{3B9}', '\u{3BA}', '\u{3BB}', '\u{3BC}',
+    '\u{3BD}', '\u{3BE}', '\u{3BF}', '\u{3C0}', '\u{3C1}', '\u{3C2}', '\u{3C3}', '\u{3C4}', '\u{3C5}', '\u{3C6}',
+    '\u{3C7}', '\u{3C8}', '\u{3C9}', '\u{2202}', '\u{3B5}', '\u{3B8}', '\u{3BA}', '\u{3C6}', '\u{3C1}', '\u{3C0}',
+    '\u{3DC}', '\u{3DD}', '\u{30}', '\u{31}', '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}',
+    '\u{38}', '\u{39}', '\u{30}', '\u{31}', '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}',
+    '\u{38}', '\u{39}', '\u{30}', '\u{31}', '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}',
+    '\u{38}', '\u{39}', '\u{30}', '\u{31}', '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}',
+    '\u{38}', '\u{39}', '\u{30}', '\u{31}', '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}',
+    '\u{38}', '\u{39}', '\u{627}', '\u{628}', '\u{62C}', '\u{62F}', '\u{648}', '\u{632}', '\u{62D}', '\u{637}',
+    '\u{64A}', '\u{643}', '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}', '\u{642}',
+    '\u{631}', '\u{634}', '\u{62A}', '\u{62B}', '\u{62E}', '\u{630}', '\u{636}', '\u{638}', '\u{63A}', '\u{66E}',
+    '\u{6BA}', '\u{6A1}', '\u{66F}', '\u{628}', '\u{62C}', '\u{647}', '\u{62D}', '\u{64A}', '\u{643}', '\u{644}',
+    '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}', '\u{642}', '\u{634}', '\u{62A}', '\u{62B}',
+    '\u{62E}', '\u{636}', '\u{63A}', '\u{62C}', '\u{62D}', '\u{64A}', '\u{644}', '\u{646}', '\u{633}', '\u{639}',
+    '\u{635}', '\u{642}', '\u{634}', '\u{62E}', '\u{636}', '\u{63A}', '\u{6BA}', '\u{66F}', '\u{628}', '\u{62C}',
+    '\u{647}', '\u{62D}', '\u{637}', '\u{64A}', '\u{643}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}',
+    '\u{635}', '\u{642}', '\u{634}', '\u{62A}', '\u{62B}', '\u{62E}', '\u{636}', '\u{638}', '\u{63A}', '\u{66E}',
+    '\u{6A1}', '\u{627}', '\u{628}', '\u{62C}', '\u{62F}', '\u{647}', '\u{648}', '\u{632}', '\u{62D}', '\u{637}',
+    '\u{64A}', '\u{644}', '\u{645}', '\u{646}', '\u{633}', '\u{639}', '\u{641}', '\u{635}', '\u{642}', '\u{631}',
+    '\u{634}', '\u{62A}', '\u{62B}', '\u{62E}', '\u{630}', '\u{636}', '\u{638}', '\u{63A}', '\u{628}', '\u{62C}',
+    '\u{62F}', '\u{648}', '\u{632}', '\u{62D}', '\u{637}', '\u{64A}', '\u{644}', '\u{645}', '\u{646}', '\u{633}',
+    '\u{639}', '\u{641}', '\u{635}', '\u{642}', '\u{631}', '\u{634}', '\u{62A}', '\u{62B}', '\u{62E}', '\u{630}',
+    '\u{636}', '\u{638}', '\u{63A}', '\u{30}', '\u{2E}', '\u{30}', '\u{2C}', '\u{31}', '\u{2C}', '\u{32}',
+    '\u{2C}', '\u{33}', '\u{2C}', '\u{34}', '\u{2C}', '\u{35}', '\u{2C}', '\u{36}', '\u{2C}', '\u{37}',
+    '\u{2C}', '\u{38}', '\u{2C}', '\u{39}', '\u{2C}', '\u{28}', '\u{41}', '\u{29}', '\u{28}', '\u{42}',
+    '\u{29}', '\u{28}', '\u{43}', '\u{29}', '\u{28}', '\u{44}', '\u{29}', '\u{28}', '\u{45}', '\u{29}',
+    '\u{28}', '\u{46}', '\u{29}', '\u{28}', '\u{47}', '\u{29}', '\u{28}', '\u{48}', '\u{29}', '\u{28}',
+    '\u{49}', '\u{29}', '\u{28}', '\u{4A}', '\u{29}', '\u{28}', '\u{4B}', '\u{29}', '\u{28}', '\u{4C}',
+    '\u{29}', '\u{28}', '\u{4D}', '\u{29}', '\u{28}', '\u{4E}', '\u{29}', '\u{28}', '\u{4F}', '\u{29}',
+    '\u{28}', '\u{50}', '\u{29}', '\u{28}', '\u{51}', '\u{29}', '\u{28}', '\u{52}', '\u{29}', '\u{28}',
+    '\u{53}', '\u{29}', '\u{28}', '\u{54}', '\u{29}', '\u{28}', '\u{55}', '\u{29}', '\u{28}', '\u{56}',
+    '\u{29}', '\u{28}', '\u{57}', '\u{29}', '\u{28}', '\u{58}', '\u{29}', '\u{28}', '\u{59}', '\u{29}',
+    '\u{28}', '\u{5A}', '\u{29}', '\u{3014}', '\u{53}', '\u{3015}', '\u{43}', '\u{52}', '\u{43}', '\u{44}',
+    '\u{57}', '\u{5A}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}', '\u{48}',
+    '\u{49}', '\u{4A}', '\u{4B}', '\u{4C}', '\u{4D}', '\u{4E}', '\u{4F}', '\u{50}', '\u{51}', '\u{52}',
+    '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}', '\u{59}', '\u{5A}', '\u{48}', '\u{56}',
+    '\u{4D}', '\u{56}', '\u{53}', '\u{44}', '\u{53}', '\u{53}', '\u{50}', '\u{50}', '\u{56}', '\u{57}',
+    '\u{43}', '\u{4D}', '\u{43}', '\u{4D}', '\u{44}', '\u{4D}', '\u{52}', '\u{44}', '\u{4A}', '\u{307B}',
+    '\u{304B}', '\u{30B3}', '\u{30B3}', '\u{30B5}', '\u{624B}', '\u{5B57}', '\u{53CC}', '\u{30C6}', '\u{3099}', '\u{4E8C}',
+    '\u{591A}', '\u{89E3}', '\u{5929}', '\u{4EA4}', '\u{6620}', '\u{7121}', '\u{6599}', '\u{524D}', '\u{5F8C}', '\u{518D}',
+    '\u{65B0}', '\u{521D}', '\u{7D42}', '\u{751F}', '\u{8CA9}', '\u{58F0}', '\u{5439}', '\u{6F14}', '\u{6295}', '\u{6355}',
+    '\u{4E00}', '\u{4E09}', '\u{904A}', '\u{5DE6}', '\u{4E2D}', '\u{53F3}', '\u{6307}', '\u{8D70}', '\u{6253}', '\u{7981}',
+    '\u{7A7A}', '\u{5408}', '\u{6E80}', '\u{6709}', '\u{6708}', '\u{7533}', '\u{5272}', '\u{55B6}', '\u{914D}', '\u{3014}',
+    '\u{672C}', '\u{3015}', '\u{3014}', '\u{4E09}', '\u{3015}', '\u{3014}', '\u{4E8C}', '\u{3015}', '\u{3014}', '\u{5B89}',
+    '\u{3015}', '\u{3014}', '\u{70B9}', '\u{3015}', '\u{3014}', '\u{6253}', '\u{3015}', '\u{3014}', '\u{76D7}', '\u{3015}',
+    '\u{3014}', '\u{52DD}', '\u{3015}', '\u{3014}', '\u{6557}', '\u{3015}', '\u{5F97}', '\u{53EF}', '\u{30}', '\u{31}',
+    '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}', '\u{38}', '\u{39}', '\u{4E3D}', '\u{4E38}',
+    '\u{4E41}', '\u{20122}', '\u{4F60}', '\u{4FAE}', '\u{4FBB}', '\u{5002}', '\u{507A}', '\u{5099}', '\u{50E7}', '\u{50CF}',
+    '\u{349E}', '\u{2063A}', '\u{514D}', '\u{5154}', '\u{5164}', '\u{5177}', '\u{2051C}', '\u{34B9}', '\u{5167}', '\u{518D}',
+    '\u{2054B}', '\u{5197}', '\u{51A4}', '\u{4ECC}', '\u{51AC}', '\u{51B5}', '\u{291DF}', '\u{51F5}', '\u{5203}', '\u{34DF}',
+    '\u{523B}', '\u{5246}', '\u{5272}', '\u{5277}', '\u{3515}', '\u{52C7}', '\u{52C9}', '\u{52E4}', '\u{52FA}', '\u{5305}',
+    '\u{5306}', '\u{5317}', '\u{5349}', '\u{5351}', '\u{535A}', '\u{5373}', '\u{537D}', '\u{537F}', '\u{537F}', '\u{537F}',
+    '\u{20A2C}', '\u{7070}', '\u{53CA}', '\u{53DF}', '\u{20B63}', '\u{53EB}', '\u{53F1}', '\u{5406}', '\u{549E}', '\u{5438}',
+    '\u{5448}', '\u{5468}', '\u{54A2}', '\u{54F6}', '\u{5510}', '\u{5553}', '\u{5563}', '\u{5584}', '\u{5584}', '\u{5599}',
+    '\u{55AB}', '\u{55B3}', '\u{55C2}', '\u{5716}', '\u{5606}', '\u{5717}', '\u{5651}', '\u{5674}', '\u{5207}', '\u{58EE}',
+    '\u{57CE}', '\u{57F4}', '\u{580D}', '\u{578B}', '\u{5832}', '\u{5831}', '\u{58AC}', '\u{214E4}', '\u{58F2}', '\u{58F7}',
+    '\u{5906}', '\u{591A}', '\u{5922}', '\u{5962}', '\u{216A8}', '\u{216EA}', '\u{59EC}', '\u{5A1B}', '\u{5A27}', '\u{59D8}',
+    '\u{5A66}', '\u{36EE}', '\u{36FC}', '\u{5B08}', '\u{5B3E}', '\u{5B3E}', '\u{219C8}', '\u{5BC3}', '\u{5BD8}', '\u{5BE7}',
+    '\u{5BF3}', '\u{21B18}', '\u{5BFF}', '\u{5C06}', '\u{5F53}', '\u{5C22}', '\u{3781}', '\u{5C60}', '\u{5C6E}', '\u{5CC0}',
+    '\u{5C8D}', '\u{21DE4}', '\u{5D43}', '\u{21DE6}', '\u{5D6E}', '\u{5D6B}', '\u{5D7C}', '\u{5DE1}', '\u{5DE2}', '\u{382F}',
+    '\u{5DFD}', '\u{5E28}', '\u{5E3D}', '\u{5E69}', '\u{3862}', '\u{22183}', '\u{387C}', '\u{5EB0}', '\u{5EB3}', '\u{5EB6}',
+    '\u{5ECA}', '\u{2A392}', '\u{5EFE}', '\u{22331}', '\u{22331}', '\u{8201}', '\u{5F22}', '\u{5F22}', '\u{38C7}', '\u{232B8}',
+    '\u{261DA}', '\u{5F62}', '\u{5F6B}', '\u{38E3}', '\u{5F9A}', '\u{5FCD}', '\u{5FD7}', '\u{5FF9}', '\u{6081}', '\u{393A}',
+    '\u{391C}', '\u{6094}', '\u{226D4}', '\u{60C7}', '\u{6148}', '\u{614C}', '\u{614E}', '\u{614C}', '\u{617A}', '\u{618E}',
+    '\u{61B2}', '\u{61A4}', '\u{61AF}', '\u{61DE}', '\u{61F2}', '\u{61F6}', '\u{6210}', '\u{621B}', '\u{625D}', '\u{62B1}',
+    '\u{62D4}', '\u{6350}', '\u{22B0C}', '\u{633D}', '\u{62FC}', '\u{6368}', '\u{6383}', '\u{63E4}', '\u{22BF1}', '\u{6422}',
+    '\u{63C5}', '\u{63A9}', '\u{3A2E}', '\u{6469}', '\u{647E}', '\u{649D}', '\u{6477}', '\u{3A6C}', '\u{654F}', '\u{656C}',
+    '\u{2300A}', '\u{65E3}', '\u{66F8}', '\u{6649}', '\u{3B19}', '\u{6691}', '\u{3B08}', '\u{3AE4}', '\u{5192}', '\u{5195}',
+    '\u{6700}', '\u{669C}', '\u{80AD}', '\u{43D9}', '\u{6717}', '\u{671B}', '\u{6721}', '\u{675E}', '\u{6753}', '\u{233C3}',
+    '\u{3B49}', '\u{67FA}', '\u{6785}', '\u{6852}', '\u{6885}', '\u{2346D}', '\u{688E}', '\u{681F}', '\u{6914}', '\u{3B9D}',
+    '\u{6942}', '\u{69A3}', '\u{69EA}', '\u{6AA8}', '\u{236A3}', '\u{6ADB}', '\u{3C18}', '\u{6B21}', '\u{238A7}', '\u{6B54}',
+    '\u{3C4E}', '\u{6B72}', '\u{6B9F}', '\u{6BBA}', '\u{6BBB}', '\u{23A8D}', '\u{21D0B}', '\u{23AFA}', '\u{6C4E}', '\u{23CBC}',
+    '\u{6CBF}', '\u{6CCD}', '\u{6C67}', '\u{6D16}', '\u{6D3E}', '\u{6D77}', '\u{6D41}', '\u{6D69}', '\u{6D78}', '\u{6D85}',
+    '\u{23D1E}', '\u{6D34}', '\u{6E2F}', '\u{6E6E}', '\u{3D33}', '\u{6ECB}', '\u{6EC7}', '\u{23ED1}', '\u{6DF9}', '\u{6F6E}',
+    '\u{23F5E}', '\u{23F8E}', '\u{6FC6}', '\u{7039}', '\u{701E}', '\u{701B}', '\u{3D96}', '\u{704A}', '\u{707D}', '\u{7077}',
+    '\u{70AD}', '\u{20525}', '\u{7145}', '\u{24263}', '\u{719C}', '\u{243AB}', '\u{7228}', '\u{7235}', '\u{7250}', '\u{24608}',
+    '\u{7280}', '\u{7295}', '\u{24735}', '\u{24814}', '\u{737A}', '\u{738B}', '\u{3EAC}', '\u{73A5}', '\u{3EB8}', '\u{3EB8}',
+    '\u{7447}', '\u{745C}', '\u{7471}', '\u{7485}', '\u{74CA}', '\u{3F1B}', '\u{7524}', '\u{24C36}', '\u{753E}', '\u{24C92}',
+    '\u{7570}', '\u{2219F}', '\u{7610}', '\u{24FA1}', '\u{24FB8}', '\u{25044}', '\u{3FFC}', '\u{4008}', '\u{76F4}', '\u{250F3}',
+    '\u{250F2}', '\u{25119}', '\u{25133}', '\u{771E}', '\u{771F}', '\u{771F}', '\u{774A}', '\u{4039}', '\u{778B}', '\u{4046}',
+    '\u{4096}', '\u{2541D}', '\u{784E}', '\u{788C}', '\u{78CC}', '\u{40E3}', '\u{25626}', '\u{7956}', '\u{2569A}', '\u{256C5}',
+    '\u{798F}', '\u{79EB}', '\u{412F}', '\u{7A40}', '\u{7A4A}', '\u{7A4F}', '\u{2597C}', '\u{25AA7}', '\u{25AA7}', '\u{7AEE}',
+    '\u{4202}', '\u{25BAB}', '\u{7BC6}', '\u{7BC9}', '\u{4227}', '\u{25C80}', '\u{7CD2}', '\u{42A0}', '\u{7CE8}', '\u{7CE3}',
+    '\u{7D00}', '\u{25F86}', '\u{7D63}', '\u{4301}', '\u{7DC7}', '\u{7E02}', '\u{7E45}', '\u{4334}', '\u{26228}', '\u{26247}',
+    '\u{4359}', '\u{262D9}', '\u{7F7A}', '\u{2633E}', '\u{7F95}', '\u{7FFA}', '\u{8005}', '\u{264DA}', '\u{26523}', '\u{8060}',
+    '\u{265A8}', '\u{8070}', '\u{2335F}', '\u{43D5}', '\u{80B2}', '\u{8103}', '\u{440B}', '\u{813E}', '\u{5AB5}', '\u{267A7}',
+    '\u{267B5}', '\u{23393}', '\u{2339C}', '\u{8201}', '\u{8204}', '\u{8F9E}', '\u{446B}', '\u{8291}', '\u{828B}', '\u{829D}',
+    '\u{52B3}', '\u{82B1}', '\u{82B3}', '\u{82BD}', '\u{82E6}', '\u{26B3C}', '\u{82E5}', '\u{831D}', '\u{8363}', '\u{83AD}',
+    '\u{8323}', '\u{83BD}', '\u{83E7}', '\u{8457}', '\u{8353}', '\u{83CA}', '\u{83CC}', '\u{83DC}', '\u{26C36}', '\u{26D6B}',
+    '\u{26CD5}', '\u{452B}', '\u{84F1}', '\u{84F3}', '\u{8516}', '\u{273CA}', '\u{8564}', '\u{26F2C}', '\u{455D}', '\u{4561}',
+    '\u{26FB1}', '\u{270D2}', '\u{456B}', '\u{8650}', '\u{865C}', '\u{8667}', '\u{8669}', '\u{86A9}', '\u{8688}', '\u{870E}',
+    '\u{86E2}', '\u{8779}', '\u{8728}', '\u{876B}', '\u{8786}', '\u{45D7}', '\u{87E1}', '\u{8801}', '\u{45F9}', '\u{8860}',
+    '\u{8863}', '\u{27667}', '\u{88D7}', '\u{88DE}', '\u{4635}', '\u{88FA}', '\u{34BB}', '\u{278AE}', '\u{27966}', '\u{46BE}',
+    '\u{46C7}', '\u{8AA0}', '\u{8AED}', '\u{8B8A}', '\u{8C55}', '\u{27CA8}', '\u{8CAB}', '\u{8CC1}', '\u{8D1B}', '\u{8D77}',
+    '\u{27F2F}', '\u{20804}', '\u{8DCB}', '\u{8DBC}', '\u{8DF0}', '\u{208DE}', '\u{8ED4}', '\u{8F38}', '\u{285D2}', '\u{285ED}',
+    '\u{9094}', '\u{90F1}', '\u{9111}', '\u{2872E}', '\u{911B}', '\u{9238}', '\u{92D7}', '\u{92D8}', '\u{927C}', '\u{93F9}',
+    '\u{9415}', '\u{28BFA}', '\u{958B}', '\u{4995}', '\u{95B7}', '\u{28D77}', '\u{49E6}', '\u{96C3}', '\u{5DB2}', '\u{9723}',
+    '\u{29145}', '\u{2921A}', '\u{4A6E}', '\u{4A76}', '\u{97E0}', '\u{2940A}', '\u{4AB2}', '\u{29496}', '\u{980B}', '\u{980B}',
+    '\u{9829}', '\u{295B6}', '\u{98E2}', '\u{4B33}', '\u{9929}', '\u{99A7}', '\u{99C2}', '\u{99FE}', '\u{4BCE}', '\u{29B30}',
+    '\u{9B12}', '\u{9C40}', '\u{9CFD}', '\u{4CCE}', '\u{4CED}', '\u{9D67}', '\u{2A0CE}', '\u{4CF8}', '\u{2A105}', '\u{2A20E}',
+    '\u{2A291}', '\u{9EBB}', '\u{4D56}', '\u{9EF9}', '\u{9EFE}', '\u{9F05}', '\u{9F0F}', '\u{9F16}', '\u{9F3B}', '\u{2A600}',
+];
+
+// full case folding (C and F status of CaseFolding.txt)
+#[rustfmt::skip]
+pub(crate) const CASE_FOLDING: &[(u32, u16, u8)] = &[
+    (0x41, 0, 1), (0x42, 1, 1), (0x43, 2, 1), (0x44, 3, 1), (0x45, 4, 1), (0x46, 5, 1), (0x47, 6, 1), (0x48, 7, 1),
+    (0x49, 8, 1), (0x4A, 9, 1), (0x4B, 10, 1), (0x4C, 11, 1), (0x4D, 12, 1), (0x4E, 13, 1), (0x4F, 14, 1), (0x50, 15, 1),
+    (0x51, 16, 1), (0x52, 17, 1), (0x53, 18, 1), (0x54, 19, 1), (0x55, 20, 1), (0x56, 21, 1), (0x57, 22, 1), (0x58, 23, 1),
+    (0x59, 24, 1), (0x5A, 25, 1), (0xB5, 26, 1), (0xC0, 27, 1), (0xC1, 28, 1), (0xC2, 29, 1), (0xC3, 30, 1), (0xC4, 31, 1),
+    (0xC5, 32, 1), (0xC6, 33, 1), (0xC7, 34, 1), (0xC8, 35, 1), (0xC9, 36, 1), (0xCA, 37, 1), (0xCB, 38, 1), (0xCC, 39, 1),
+    (0xCD, 40, 1), (0xCE, 41, 1), (0xCF, 42, 1), (0xD0, 43, 1), (0xD1, 44, 1), (0xD2, 45, 1), (0xD3, 46, 1), (0xD4, 47, 1),
+    (0xD5, 48, 1), (0xD6, 49, 1), (0xD8, 50, 1), (0xD9, 51, 1), (0xDA, 52, 1), (0xDB, 53, 1), (0xDC, 54, 1), (0xDD, 55, 1),
+    (0xDE, 56, 1), (0xDF, 57, 2), (0x100, 59, 1), (0x102, 60, 1), (0x104, 61, 1), (0x106, 62, 1), (0x108, 63, 1), (0x10A, 64, 1),
+    (0x10C, 65, 1), (0x10E, 66, 1), (0x110, 67, 1), (0x112, 68, 1), (0x114, 69, 1), (0x116, 70, 1), (0x118, 71, 1), (0x11A, 72, 1),
+    (0x11C, 73, 1), (0x11E, 74, 1), (0x120, 75, 1), (0x122, 76, 1), (0x124, 77, 1), (0x126, 78, 1), (0x128, 79, 1), (0x12A, 80, 1),
+    (0x12C, 81, 1), (0x12E, 82, 1), (0x130, 83, 2), (0x132, 85, 1), (0x134, 86, 1), (0x136, 87, 1), (0x139, 88, 1), (0x13B, 89, 1),
+    (0x13D, 90, 1), (0x13F, 91, 1), (0x141, 92, 1), (0x143, 93, 1), (0x145, 94, 1), (0x147, 95, 1), (0x149, 96, 2), (0x14A, 98, 1),
+    (0x14C, 99, 1), (0x14E, 100, 1), (0x150, 101, 1), (0x152, 102, 1), (0x154, 103, 1), (0x156, 104, 1), (0x158, 105, 1), (0x15A, 106, 1),
+    (0x15C, 107, 1), (0x15E, 108, 1), (0x160, 109, 1), (0x162, 110, 1), (0x164, 111, 1), (0x166, 112, 1), (0x168, 113, 1), (0x16A, 114, 1),
+    (0x16C, 115, 1), (0x16E, 116, 1), (0x170, 117, 1), (0x172, 118, 1), (0x174, 119, 1), (0x176, 120, 1), (0x178, 121, 1), (0x179, 122, 1),
+    (0x17B, 123, 1), (0x17D, 124, 1), (0x17F, 125, 1), (0x181, 126, 1), (0x182, 127, 1), (0x184, 128, 1), (0x186, 129, 1), (0x187, 130, 1),
+    (0x189, 131, 1), (0x18A, 132, 1), (0x18B, 133, 1), (0x18E, 134, 1), (0x18F, 135, 1), (0x190, 136, 1), (0x191, 137, 1), (0x193, 138, 1),
+    (0x194, 139, 1), (0x196, 140, 1), (0x197, 141, 1), (0x198, 142, 1), (0x19C, 143, 1), (0x19D, 144, 1), (0x19F, 145, 1), (0x1A0, 146, 1),
+    (0x1A2, 147, 1), (0x1A4, 148, 1), (0x1A6, 149, 1), (0x1A7, 150, 1), (0x1A9, 151, 1), (0x1AC, 152, 1), (0x1AE, 153, 1), (0x1AF, 154, 1),
+    (0x1B1, 155, 1), (0x1B2, 156, 1), (0x1B3, 157, 1), (0x1B5, 158, 1), (0x1B7, 159, 1), (0x1B8, 160, 1), (0x1BC, 161, 1), (0x1C4, 162, 1),
+    (0x1C5, 163, 1), (0x1C7, 164, 1), (0x1C8, 165, 1), (0x1CA, 166, 1), (0x1CB, 167, 1), (0x1CD, 168, 1), (0x1CF, 169, 1), (0x1D1, 170, 1),
+    (0x1D3, 171, 1), (0x1D5, 172, 1), (0x1D7, 173, 1), (0x1D9, 174, 1), (0x1DB, 175, 1), (0x1DE, 176, 1), (0x1E0, 177, 1), (0x1E2, 178, 1),
+    (0x1E4, 179, 1), (0x1E6, 180, 1), (0x1E8, 181, 1), (0x1EA, 182, 1), (0x1EC, 183, 1), (0x1EE, 184, 1), (0x1F0, 185, 2), (0x1F1, 187, 1),
+    (0x1F2, 188, 1), (0x1F4, 189, 1), (0x1F6, 190, 1), (0x1F7, 191, 1), (0x1F8, 192, 1), (0x1FA, 193, 1), (0x1FC, 194, 1), (0x1FE, 195, 1),
+    (0x200, 196, 1), (0x202, 197, 1), (0x204, 198, 1), (0x206, 199, 1), (0x208, 200, 1), (0x20A, 201, 1), (0x20C, 202, 1), (0x20E, 203, 1),
+    (0x210, 204, 1), (0x212, 205, 1), (0x214, 206, 1), (0x216, 207, 1), (0x218, 208, 1), (0x21A, 209, 1), (0x21C, 210, 1), (0x21E, 211, 1),
+    (0x220, 212, 1), (0x222, 213, 1), (0x224, 214, 1), (0x226, 215, 1), (0x228, 216, 1), (0x22A, 217, 1), (0x22C, 218, 1), (0x22E, 219, 1),
+    (0x230, 220, 1), (0x232, 221, 1), (0x23A, 222, 1), (0x23B, 223, 1), (0x23D, 224, 1), (0x23E, 225, 1), (0x241, 226, 1), (0x243, 227, 1),
+    (0x244, 228, 1), (0x245, 229, 1), (0x246, 230, 1), (0x248, 231, 1), (0x24A, 232, 1), (0x24C, 233, 1), (0x24E, 234, 1), (0x345, 235, 1),
+    (0x370, 236, 1), (0x372, 237, 1), (0x376, 238, 1), (0x37F, 239, 1), (0x386, 240, 1), (0x388, 241, 1), (0x389, 242, 1), (0x38A, 243, 1),
+    (0x38C, 244, 1), (0x38E, 245, 1), (0x38F, 246, 1), (0x390, 247, 3), (0x391, 250, 1), (0x392, 251, 1), (0x393, 252, 1), (0x394, 253, 1),
+    (0x395, 254, 1), (0x396, 255, 1), (0x397, 256, 1), (0x398, 257, 1), (0x399, 258, 1), (0x39A, 259, 1), (0x39B, 260, 1), (0x39C, 261, 1),
+    (0x39D, 262, 1), (0x39E, 263, 1), (0x39F, 264, 1), (0x3A0, 265, 1), (0x3A1, 266, 1), (0x3A3, 267, 1), (0x3A4, 268, 1), (0x3A5, 269, 1),
+    (0x3A6, 270, 1), (0x3A7, 271, 1), (0x3A8, 272, 1), (0x3A9, 273, 1), (0x3AA, 274, 1), (0x3AB, 275, 1), (0x3B0, 276, 3), (0x3C2, 279, 1),
+    (0x3CF, 280, 1), (0x3D0, 281, 1), (0x3D1, 282, 1), (0x3D5, 283, 1), (0x3D6, 284, 1), (0x3D8, 285, 1), (0x3DA, 286, 1), (0x3DC, 287, 1),
+    (0x3DE, 288, 1), (0x3E0, 289, 1), (0x3E2, 290, 1), (0x3E4, 291, 1), (0x3E6, 292, 1), (0x3E8, 293, 1), (0x3EA, 294, 1), (0x3EC, 295, 1),
+    (0x3EE, 296, 1), (0x3F0, 297, 1), (0x3F1, 298, 1), (0x3F4, 299, 1), (0x3F5, 300, 1), (0x3F7, 301, 1), (0x3F9, 302, 1), (0x3FA, 303, 1),
+    (0x3FD, 304, 1), (0x3FE, 305, 1), (0x3FF, 306, 1), (0x400, 307, 1), (0x401, 308, 1), (0x402, 309, 1), (0x403, 310, 1), (0x404, 311, 1),
+    (0x405, 312, 1), (0x406, 313, 1), (0x407, 314, 1), (0x408, 315, 1), (0x409, 316, 1), (0x40A, 317, 1), (0x40B, 318, 1), (0x40C, 319, 1),
+    (0x40D, 320, 1), (0x40E, 321, 1), (0x40F, 322, 1), (0x410, 323, 1), (0x411, 324, 1), (0x412, 325, 1), (0x413, 326, 1), (0x414, 327, 1),
+    (0x415, 328, 1), (0x416, 329, 1), (0x417, 330, 1), (0x418, 331, 1), (0x419, 332, 1), (0x41A, 333, 1), (0x41B, 334, 1), (0x41C, 335, 1),
+    (0x41D, 336, 1), (0x41E, 337, 1), (0x41F, 338, 1), (0x420, 339, 1), (0x421, 340, 1), (0x422, 341, 1), (0x423, 342, 1), (0x424, 343, 1),
+    (0x425, 344, 1), (0x426, 345, 1), (0x427, 346, 1), (0x428, 347, 1), (0x429, 348, 1), (0x42A, 349, 1), (0x42B, 350, 1), (0x42C, 351, 1),
+    (0x42D, 352, 1), (0x42E, 353, 1), (0x42F, 354, 1), (0x460, 355, 1), (0x462, 356, 1), (0x464, 357, 1), (0x466, 358, 1), (0x468, 359, 1),
+    (0x46A, 360, 1), (0x46C, 361, 1), (0x46E, 362, 1), (0x470, 363, 1), (0x472, 364, 1), (0x474, 365, 1), (0x476, 366, 1), (0x478, 367, 1),
+    (0x47A, 368, 1), (0x47C, 369, 1), (0x47E, 370, 1), (0x480, 371, 1), (0x48A, 372, 1), (0x48C, 373, 1), (0x48E, 374, 1), (0x490, 375, 1),
+    (0x492, 376, 1), (0x494, 377, 1), (0x496, 378, 1), (0x498, 379, 1), (0x49A, 380, 1), (0x49C, 381, 1), (0x49E, 382, 1), (0x4A0, 383, 1),
+    (0x4A2, 384, 1), (0x4A4, 385, 1), (0x4A6, 386, 1), (0x4A8, 387, 1), (0x4AA, 388, 1), (0x4AC, 389, 1), (0x4AE, 390, 1), (0x4B0, 391, 1),
+    (0x4B2, 392, 1), (0x4B4, 393, 1), (0x4B6, 394, 1), (0x4B8, 395, 1), (0x4BA, 396, 1), (0x4BC, 397, 1), (0x4BE, 398, 1), (0x4C0, 399, 1),
+    (0x4C1, 400, 1), (0x4C3, 401, 1), (0x4C5, 402, 1), (0x4C7, 403, 1), (0x4C9, 404, 1), (0x4CB, 405, 1), (0x4CD, 406, 1), (0x4D0, 407, 1),
+    (0x4D2, 408, 1), (0x4D4, 409, 1), (0x4D6, 410, 1), (0x4D8, 411, 1), (0x4DA, 412, 1), (0x4DC, 413, 1), (0x4DE, 414, 1), (0x4E0, 415, 1),
+    (0x4E2, 416, 1), (0x4E4, 417, 1), (0x4E6, 418, 1), (0x4E8, 419, 1), (0x4EA, 420, 1), (0x4EC, 421, 1), (0x4EE, 422, 1), (0x4F0, 423, 1),
+    (0x4F2, 424, 1), (0x4F4, 425, 1), (0x4F6, 426, 1), (0x4F8, 427, 1), (0x4FA, 428, 1), (0x4FC, 429, 1), (0x4FE, 430, 1), (0x500, 431, 1),
+    (0x502, 432, 1), (0x504, 433, 1), (0x506, 434, 1), (0x508, 435, 1), (0x50A, 436, 1), (0x50C, 437, 1), (0x50E, 438, 1), (0x510, 439, 1),
+    (0x512, 440, 1), (0x514, 441, 1), (0x516, 442, 1), (0x518, 443, 1), (0x51A, 444, 1), (0x51C, 445, 1), (0x51E, 446, 1), (0x520, 447, 1),
+    (0x522, 448, 1), (0x524, 449, 1), (0x526, 450, 1), (0x528, 451, 1), (0x52A, 452, 1), (0x52C, 453, 1), (0x52E, 454, 1), (0x531, 455, 1),
+    (0x532, 456, 1), (0x533, 457, 1), (0x534, 458, 1), (0x535, 459, 1), (0x536, 460, 1), (0x537, 461, 1), (0x538, 462, 1), (0x539, 463, 1),
+    (0x53A, 464, 1), (0x53B, 465, 1), (0x53C, 466, 1), (0x53D, 467, 1), (0x53E, 468, 1), (0x53F, 469, 1), (0x540, 470, 1), (0x541, 471, 1),
+    (0x542, 472, 1), (0x543, 473, 1), (0x544, 474, 1), (0x545, 475, 1), (0x546, 476, 1), (0x547, 477, 1), (0x548, 478, 1), (0x549, 479, 1),
+    (0x54A, 480, 1), (0x54B, 481, 1), (0x54C, 482, 1), (0x54D, 483, 1), (0x54E, 484, 1), (0x54F, 485, 1), (0x550, 486, 1), (0x551, 487, 1),
+    (0x552, 488, 1), (0x553, 489, 1), (0x554, 490, 1), (0x555, 491, 1), (0x556, 492, 1), (0x587, 493, 2), (0x10A0, 495, 1), (0x10A1, 496, 1),
+    (0x10A2, 497, 1), (0x10A3, 498, 1), (0x10A4, 499, 1), (0x10A5, 500, 1), (0x10A6, 501, 1), (0x10A7, 502, 1), (0x10A8, 503, 1), (0x10A9, 504, 1),
+    (0x10AA, 505, 1), (0x10AB, 506, 1), (0x10AC, 507, 1), (0x10AD, 508, 1), (0x10AE, 509, 1), (0x10AF, 510, 1), (0x10B0, 511, 1), (0x10B1, 512, 1),
+    (0x10B2, 513, 1), (0x10B3, 514, 1), (0x10B4, 515, 1), (0x10B5, 516, 1), (0x10B6, 517, 1), (0x10B7, 518, 1), (0x10B8, 519, 1), (0x10B9, 520, 1),
+    (0x10BA, 521, 1), (0x10BB, 522, 1), (0x10BC, 523, 1), (0x10BD, 524, 1), (0x10BE, 525, 1), (0x10BF, 526, 1), (0x10C0, 527, 1), (0x10C1, 528, 1),
+    (0x10C2, 529, 1), (0x10C3, 530, 1), (0x10C4, 531, 1), (0x10C5, 532, 1), (0x10C7, 533, 1), (0x10CD, 534, 1), (0x13F8, 535, 1), (0x13F9, 536, 1),
+    (0x13FA, 537, 1), (0x13FB, 538, 1), (0x13FC, 539, 1), (0x13FD, 540, 1), (0x1C80, 541, 1), (0x1C81, 542, 1), (0x1C82, 543, 1), (0x1C83, 544, 1),
+    (0x1C84, 545, 1), (0x1C85, 546, 1), (0x1C86, 547, 1), (0x1C87, 548, 1), (0x1C88, 549, 1), (0x1C90, 550, 1), (0x1C91, 551, 1), (0x1C92, 552, 1),
+    (0x1C93, 553, 1), (0x1C94, 554, 1), (0x1C95, 555, 1), (0x1C96, 556, 1), (0x1C97, 557, 1), (0x1C98, 558, 1), (0x1C99, 559, 1), (0x1C9A, 560, 1),
+    (0x1C9B, 561, 1), (0x1C9C, 562, 1), (0x1C9D, 563, 1), (0x1C9E, 564, 1), (0x1C9F, 565, 1), (0x1CA0, 566, 1), (0x1CA1, 567, 1), (0x1CA2, 568, 1),
+    (0x1CA3, 569, 1), (0x1CA4, 570, 1), (0x1CA5, 571, 1), (0x1CA6, 572, 1), (0x1CA7, 573, 1), (0x1CA8, 574, 1), (0x1CA9, 575, 1), (0x1CAA, 576, 1),
+    (0x1CAB, 577, 1), (0x1CAC, 578, 1), (0x1CAD, 579, 1), (0x1CAE, 580, 1), (0x1CAF, 581, 1), (0x1CB0, 582, 1), (0x1CB1, 583, 1), (0x1CB2, 584, 1),
+    (0x1CB3, 585, 1), (0x1CB4, 586, 1), (0x1CB5, 587, 1), (0x1CB6, 588, 1), (0x1CB7, 589, 1), (0x1CB8, 590, 1), (0x1CB9, 591, 1), (0x1CBA, 592, 1),
+    (0x1CBD, 593, 1), (0x1CBE, 594, 1), (0x1CBF, 595, 1), (0x1E00, 596, 1), (0x1E02, 597, 1), (0x1E04, 598, 1), (0x1E06, 599, 1), (0x1E08, 600, 1),
+    (0x1E0A, 601, 1), (0x1E0C, 602, 1), (0x1E0E, 603, 1), (0x1E10, 604, 1), (0x1E12, 605, 1), (0x1E14, 606, 1), (0x1E16, 607, 1), (0x1E18, 608, 1),
+    (0x1E1A, 609, 1), (0x1E1C, 610, 1), (0x1E1E, 611, 1), (0x1E20, 612, 1), (0x1E22, 613, 1), (0x1E24, 614, 1), (0x1E26, 615, 1), (0x1E28, 616, 1),
+    (0x1E2A, 617, 1), (0x1E2C, 618, 1), (0x1E2E, 619, 1), (0x1E30, 620, 1), (0x1E32, 621, 1), (0x1E34, 622, 1), (0x1E36, 623, 1), (0x1E38, 624, 1),
+    (0x1E3A, 625, 1), (0x1E3C, 626, 1), (0x1E3E, 627, 1), (0x1E40, 628, 1), (0x1E42, 629, 1), (0x1E44, 630, 1), (0x1E46, 631, 1), (0x1E48, 632, 1),
+    (0x1E4A, 633, 1), (0x1E4C, 634, 1), (0x1E4E, 635, 1), (0x1E50, 636, 1), (0x1E52, 637, 1), (0x1E54, 638, 1), (0x1E56, 639, 1), (0x1E58, 640, 1),
+    (0x1E5A, 641, 1), (0x1E5C, 642, 1), (0x1E5E, 643, 1), (0x1E60, 644, 1), (0x1E62, 645, 1), (0x1E64, 646, 1), (0x1E66, 647, 1), (0x1E68, 648, 1),
+    (0x1E6A, 649, 1), (0x1E6C, 650, 1), (0x1E6E, 651, 1), (0x1E70, 652, 1), (0x1E72, 653, 1), (0x1E74, 654, 1), (0x1E76, 655, 1), (0x1E78, 656, 1),
+    (0x1E7A, 657, 1), (0x1E7C, 658, 1), (0x1E7E, 659, 1), (0x1E80, 660, 1), (0x1E82, 661, 1), (0x1E84, 662, 1), (0x1E86, 663, 1), (0x1E88, 664, 1),
+    (0x1E8A, 665, 1), (0x1E8C, 666, 1), (0x1E8E, 667, 1), (0x1E90, 668, 1), (0x1E92, 669, 1), (0x1E94, 670, 1), (0x1E96, 671, 2), (0x1E97, 673, 2),
+    (0x1E98, 675, 2), (0x1E99, 677, 2), (0x1E9A, 679, 2), (0x1E9B, 681, 1), (0x1E9E, 682, 2), (0x1EA0, 684, 1), (0x1EA2, 685, 1), (0x1EA4, 686, 1),
+    (0x1EA6, 687, 1), (0x1EA8, 688, 1), (0x1EAA, 689, 1), (0x1EAC, 690, 1), (0x1EAE, 691, 1), (0x1EB0, 692, 1), (0x1EB2, 693, 1), (0x1EB4, 694, 1),
+    (0x1EB6, 695, 1), (0x1EB8, 696, 1), (0x1EBA, 697, 1), (0x1EBC, 698, 1), (0x1EBE, 699, 1), (0x1EC0, 700, 1), (0x1EC2, 701, 1), (0x1EC4, 702, 1),
+    (0x1EC6, 703, 1), (0x1EC8, 704, 1), (0x1ECA, 705, 1), (0x1ECC, 706, 1), (0x1ECE, 707, 1), (0x1ED0, 708, 1), (0x1ED2, 709, 1), (0x1ED4, 710, 1),
+    (0x1ED6, 711, 1), (0x1ED8, 712, 1), (0x1EDA, 713, 1), (0x1EDC, 714, 1), (0x1EDE, 715, 1), (0x1EE0, 716, 1), (0x1EE2, 717, 1), (0x1EE4, 718, 1),
+    (0x1EE6, 719, 1), (0x1EE8, 720, 1), (0x1EEA, 721, 1), (0x1EEC, 722, 1), (0x1EEE, 723, 1), (0x1EF0, 724, 1), (0x1EF2, 725, 1), (0x1EF4, 726, 1),
+    (0x1EF6, 727, 1), (0x1EF8, 728, 1), (0x1EFA, 729, 1), (0x1EFC, 730, 1), (0x1EFE, 731, 1), (0x1F08, 732, 1), (0x1F09, 733, 1), (0x1F0A, 734, 1),
+    (0x1F0B, 735, 1), (0x1F0C, 736, 1), (0x1F0D, 737, 1), (0x1F0E, 738, 1), (0x1F0F, 739, 1), (0x1F18, 740, 1), (0x1F19, 741, 1), (0x1F1A, 742, 1),
+    (0x1F1B, 743, 1), (0x1F1C, 744, 1), (0x1F1D, 745, 1), (0x1F28, 746, 1), (0x1F29, 747, 1), (0x1F2A, 748, 1), (0x1F2B, 749, 1), (0x1F2C, 750, 1),
+    (0x1F2D, 751, 1), (0x1F2E, 752, 1), (0x1F2F, 753, 1), (0x1F38, 754, 1), (0x1F39, 755, 1), (0x1F3A, 756, 1), (0x1F3B, 757, 1), (0x1F3C, 758, 1),
+    (0x1F3D, 759, 1), (0x1F3E, 760, 1), (0x1F3F, 761, 1), (0x1F48, 762, 1), (0x1F49, 763, 1), (0x1F4A, 764, 1), (0x1F4B, 765, 1), (0x1F4C, 766, 1),
+    (0x1F4D, 767, 1), (0x1F50, 768, 2), (0x1F52, 770, 3), (0x1F54, 773, 3), (0x1F56, 776, 3), (0x1F59, 779, 1), (0x1F5B, 780, 1), (0x1F5D, 781, 1),
+    (0x1F5F, 782, 1), (0x1F68, 783, 1), (0x1F69, 784, 1), (0x1F6A, 785, 1), (0x1F6B, 786, 1), (0x1F6C, 787, 1), (0x1F6D, 788, 1), (0x1F6E, 789, 1),
+    (0x1F6F, 790, 1), (0x1F80, 791, 2), (0x1F81, 793, 2), (0x1F82, 795, 2), (0x1F83, 797, 2), (0x1F84, 799, 2), (0x1F85, 801, 2), (0x1F86, 803, 2),
+    (0x1F87, 805, 2), (0x1F88, 807, 2), (0x1F89, 809, 2), (0x1F8A, 811, 2), (0x1F8B, 813, 2), (0x1F8C, 815, 2), (0x1F8D, 817, 2), (0x1F8E, 819, 2),
+    (0x1F8F, 821, 2), (0x1F90, 823, 2), (0x1F91, 825, 2), (0x1F92, 827, 2), (0x1F93, 829, 2), (0x1F94, 831, 2), (0x1F95, 833, 2), (0x1F96, 835, 2),
+    (0x1F97, 837, 2), (0x1F98, 839, 2), (0x1F99, 841, 2), (0x1F9A, 843, 2), (0x1F9B, 845, 2), (0x1F9C, 847, 2), (0x1F9D, 849, 2), (0x1F9E, 851, 2),
+    (0x1F9F, 853, 2), (0x1FA0, 855, 2), (0x1FA1, 857, 2), (0x1FA2, 859, 2), (0x1FA3, 861, 2), (0x1FA4, 863, 2), (0x1FA5, 865, 2), (0x1FA6, 867, 2),
+    (0x1FA7, 869, 2), (0x1FA8, 871, 2), (0x1FA9, 873, 2), (0x1FAA, 875, 2), (0x1FAB, 877, 2), (0x1FAC, 879, 2), (0x1FAD, 881, 2), (0x1FAE, 883, 2),
+    (0x1FAF, 885, 2), (0x1FB2, 887, 2), (0x1FB3, 889, 2), (0x1FB4, 891, 2), (0x1FB6, 893, 2), (0x1FB7, 895, 3), (0x1FB8, 898, 1), (0x1FB9, 899, 1),
+    (0x1FBA, 900, 1), (0x1FBB, 901, 1), (0x1FBC, 902, 2), (0x1FBE, 904, 1), (0x1FC2, 905, 2), (0x1FC3, 907, 2), (0x1FC4, 909, 2), (0x1FC6, 911, 2),
+    (0x1FC7, 913, 3), (0x1FC8, 916, 1), (0x1FC9, 917, 1), (0x1FCA, 918, 1), (0x1FCB, 919, 1), (0x1FCC, 920, 2), (0x1FD2, 922, 3), (0x1FD3, 925, 3),
+    (0x1FD6, 928, 2), (0x1FD7, 930, 3), (0x1FD8, 933, 1), (0x1FD9, 934, 1), (0x1FDA, 935, 1), (0x1FDB, 936, 1), (0x1FE2, 937, 3), (0x1FE3, 940, 3),
+    (0x1FE4, 943, 2), (0x1FE6, 945, 2), (0x1FE7, 947, 3), (0x1FE8, 950, 1), (0x1FE9, 951, 1), (0x1FEA, 952, 1), (0x1FEB, 953, 1), (0x1FEC, 954, 1),
+    (0x1FF2, 955, 2), (0x1FF3, 957, 2), (0x1FF4, 959, 2), (0x1FF6, 961, 2), (0x1FF7, 963, 3), (0x1FF8, 966, 1), (0x1FF9, 967, 1), (0x1FFA, 968, 1),
+    (0x1FFB, 969, 1), (0x1FFC, 970, 2), (0x2126, 972, 1), (0x212A, 973, 1), (0x212B, 974, 1), (0x2132, 975, 1), (0x2160, 976, 1), (0x2161, 977, 1),
+    (0x2162, 978, 1), (0x2163, 979, 1), (0x2164, 980, 1), (0x2165, 981, 1), (0x2166, 982, 1), (0x2167, 983, 1), (0x2168, 984, 1), (0x2169, 985, 1),
+    (0x216A, 986, 1), (0x216B, 987, 1), (0x216C, 988, 1), (0x216D, 989, 1), (0x216E, 990, 1), (0x216F, 991, 1), (0x2183, 992, 1), (0x24B6, 993, 1),
+    (0x24B7, 994, 1), (0x24B8, 995, 1), (0x24B9, 996, 1), (0x24BA, 997, 1), (0x24BB, 998, 1), (0x24BC, 999, 1), (0x24BD, 1000, 1), (0x24BE, 1001, 1),
+    (0x24BF, 1002, 1), (0x24C0, 1003, 1), (0x24C1, 1004, 1), (0x24C2, 1005, 1), (0x24C3, 1006, 1), (0x24C4, 1007, 1), (0x24C5, 1008, 1), (0x24C6, 1009, 1),
+    (0x24C7, 1010, 1), (0x24C8, 1011, 1), (0x24C9, 1012, 1), (0x24CA, 1013, 1), (0x24CB, 1014, 1), (0x24CC, 1015, 1), (0x24CD, 1016, 1), (0x24CE, 1017, 1),
+    (0x24CF, 1018, 1), (0x2C00, 1019, 1), (0x2C01, 1020, 1), (0x2C02, 1021, 1), (0x2C03, 1022, 1), (0x2C04, 1023, 1), (0x2C05, 1024, 1), (0x2C06, 1025, 1),
+    (0x2C07, 1026, 1), (0x2C08, 1027, 1), (0x2C09, 1028, 1), (0x2C0A, 1029, 1), (0x2C0B, 1030, 1), (0x2C0C, 1031, 1), (0x2C0D, 1032, 1), (0x2C0E, 1033, 1),
+    (0x2C0F, 1034, 1), (0x2C10, 1035, 1), (0x2C11, 1036, 1), (0x2C12, 1037, 1), (0x2C13, 1038, 1), (0x2C14, 1039, 1), (0x2C15, 1040, 1), (0x2C16, 1041, 1),
+    (0x2C17, 1042, 1), (0x2C18, 1043, 1), (0x2C19, 1044, 1), (0x2C1A, 1045, 1), (0x2C1B, 1046, 1), (0x2C1C, 1047, 1), (0x2C1D, 1048, 1), (0x2C1E, 1049, 1),
+    (0x2C1F, 1050, 1), (0x2C20, 1051, 1), (0x2C21, 1052, 1), (0x2C22, 1053, 1), (0x2C23, 1054, 1), (0x2C24, 1055, 1), (0x2C25, 1056, 1), (0x2C26, 1057, 1),
+    (0x2C27, 1058, 1), (0x2C28, 1059, 1), (0x2C29, 1060, 1), (0x2C2A, 1061, 1), (0x2C2B, 1062, 1), (0x2C2C, 1063, 1), (0x2C2D, 1064, 1), (0x2C2E, 1065, 1),
+    (0x2C2F, 1066, 1), (0x2C60, 1067, 1), (0x2C62, 1068, 1), (0x2C63, 1069, 1), (0x2C64, 1070, 1), (0x2C67, 1071, 1), (0x2C69, 1072, 1), (0x2C6B, 1073, 1),
+    (0x2C6D, 1074, 1), (0x2C6E, 1075, 1), (0x2C6F, 1076, 1), (0x2C70, 1077, 1), (0x2C72, 1078, 1), (0x2C75, 1079, 1), (0x2C7E, 1080, 1), (0x2C7F, 1081, 1),
+    (0x2C80, 1082, 1), (0x2C82, 1083, 1), (0x2C84, 1084, 1), (0x2C86, 1085, 1), (0x2C88, 1086, 1), (0x2C8A, 1087, 1), (0x2C8C, 1088, 1), (0x2C8E, 1089, 1),
+    (0x2C90, 1090, 1), (0x2C92, 1091, 1), (0x2C94, 1092, 1), (0x2C96, 1093, 1), (0x2C98, 1094, 1), (0x2C9A, 1095, 1), (0x2C9C, 1096, 1), (0x2C9E, 1097, 1),
+    (0x2CA0, 1098, 1), (0x2CA2, 1099, 1), (0x2CA4, 1100, 1), (0x2CA6, 1101, 1), (0x2CA8, 1102, 1), (0x2CAA, 1103, 1), (0x2CAC, 1104, 1), (0x2CAE, 1105, 1),
+    (0x2CB0, 1106, 1), (0x2CB2, 1107, 1), (0x2CB4, 1108, 1), (0x2CB6, 1109, 1), (0x2CB8, 1110, 1), (0x2CBA, 1111, 1), (0x2CBC, 1112, 1), (0x2CBE, 1113, 1),
+    (0x2CC0, 1114, 1), (0x2CC2, 1115, 1), (0x2CC4, 1116, 1), (0x2CC6, 1117, 1), (0x2CC8, 1118, 1), (0x2CCA, 1119, 1), (0x2CCC, 1120, 1), (0x2CCE, 1121, 1),
+    (0x2CD0, 1122, 1), (0x2CD2, 1123, 1), (0x2CD4, 1124, 1), (0x2CD6, 1125, 1), (0x2CD8, 1126, 1), (0x2CDA, 1127, 1), (0x2CDC, 1128, 1), (0x2CDE, 1129, 1),
+    (0x2CE0, 1130, 1), (0x2CE2, 1131, 1), (0x2CEB, 1132, 1), (0x2CED, 1133, 1), (0x2CF2, 1134, 1), (0xA640, 1135, 1), (0xA642, 1136, 1), (0xA644, 1137, 1),
+    (0xA646, 1138, 1), (0xA648, 1139, 1), (0xA64A, 1140, 1), (0xA64C, 1141, 1), (0xA64E, 1142, 1), (0xA650, 1143, 1), (0xA652, 1144, 1), (0xA654, 1145, 1),
+    (0xA656, 1146, 1), (0xA658, 1147, 1), (0xA65A, 1148, 1), (0xA65C, 1149, 1), (0xA65E, 1150, 1), (0xA660, 1151, 1), (0xA662, 1152, 1), (0xA664, 1153, 1),
+    (0xA666, 1154, 1), (0xA668, 1155, 1), (0xA66A, 1156, 1), (0xA66C, 1157, 1), (0xA680, 1158, 1), (0xA682, 1159, 1), (0xA684, 1160, 1), (0xA686, 1161, 1),
+    (0xA688, 1162, 1), (0xA68A, 1163, 1), (0xA68C, 1164, 1), (0xA68E, 1165, 1), (0xA690, 1166, 1), (0xA692, 1167, 1), (0xA694, 1168, 1), (0xA696, 1169, 1),
+    (0xA698, 1170, 1), (0xA69A, 1171, 1), (0xA722, 1172, 1), (0xA724, 1173, 1), (0xA726, 1174, 1), (0xA728, 1175, 1), (0xA72A, 1176, 1), (0xA72C, 1177, 1),
+    (0xA72E, 1178, 1), (0xA732, 1179, 1), (0xA734, 1180, 1), (0xA736, 1181, 1), (0xA738, 1182, 1), (0xA73A, 1183, 1), (0xA73C, 1184, 1), (0xA73E, 1185, 1),
+    (0xA740, 1186, 1), (0xA742, 1187, 1), (0xA744, 1188, 1), (0xA746, 1189, 1), (0xA748, 1190, 1), (0xA74A, 1191, 1), (0xA74C, 1192, 1), (0xA74E, 1193, 1),
+    (0xA750, 1194, 1), (0xA752, 1195, 1), (0xA754, 1196, 1), (0xA756, 1197, 1), (0xA758, 1198, 1), (0xA75A, 1199, 1), (0xA75C, 1200, 1), (0xA75E, 1201, 1),
+    (0xA760, 1202, 1), (0xA762, 1203, 1), (0xA764, 1204, 1), (0xA766, 1205, 1), (0xA768, 1206, 1), (0xA76A, 1207, 1), (0xA76C, 1208, 1), (0xA76E, 1209, 1),
+    (0xA779, 1210, 1), (0xA77B, 1211, 1), (0xA77D, 1212, 1), (0xA77E, 1213, 1), (0xA780, 1214, 1), (0xA782, 1215, 1), (0xA784, 1216, 1), (0xA786, 1217, 1),
+    (0xA78B, 1218, 1), (0xA78D, 1219, 1), (0xA790, 1220, 1), (0xA792, 1221, 1), (0xA796, 1222, 1), (0xA798, 1223, 1), (0xA79A, 1224, 1), (0xA79C, 1225, 1),
+    (0xA79E, 1226, 1), (0xA7A0, 1227, 1), (0xA7A2, 1228, 1), (0xA7A4, 1229, 1), (0xA7A6, 1230, 1), (0xA7A8, 1231, 1), (0xA7AA, 1232, 1), (0xA7AB, 1233, 1),
+    (0xA7AC, 1234, 1), (0xA7AD, 1235, 1), (0xA7AE, 1236, 1), (0xA7B0, 1237, 1), (0xA7B1, 1238, 1), (0xA7B2, 1239, 1), (0xA7B3, 1240, 1), (0xA7B4, 1241, 1),
+    (0xA7B6, 1242, 1), (0xA7B8, 1243, 1), (0xA7BA, 1244, 1), (0xA7BC, 1245, 1), (0xA7BE, 1246, 1), (0xA7C0, 1247, 1), (0xA7C2, 1248, 1), (0xA7C4, 1249, 1),
+    (0xA7C5, 1250, 1), (0xA7C6, 1251, 1), (0xA7C7, 1252, 1), (0xA7C9, 1253, 1), (0xA7D0, 1254, 1), (0xA7D6, 1255, 1), (0xA7D8, 1256, 1), (0xA7F5, 1257, 1),
+    (0xAB70, 1258, 1), (0xAB71, 1259, 1), (0xAB72, 1260, 1), (0xAB73, 1261, 1), (0xAB74, 1262, 1), (0xAB75, 1263, 1), (0xAB76, 1264, 1), (0xAB77, 1265, 1),
+    (0xAB78, 1266, 1), (0xAB79, 1267, 1), (0xAB7A, 1268, 1), (0xAB7B, 1269, 1), (0xAB7C, 1270, 1), (0xAB7D, 1271, 1), (0xAB7E, 1272, 1), (0xAB7F, 1273, 1),
+    (0xAB80, 1274, 1), (0xAB81, 1275, 1), (0xAB82, 1276, 1), (0xAB83, 1277, 1), (0xAB84, 1278, 1), (0xAB85, 1279, 1), (0xAB86, 1280, 1), (0xAB87, 1281, 1),
+    (0xAB88, 1282, 1), (0xAB89, 1283, 1), (0xAB8A, 1284, 1), (0xAB8B, 1285, 1), (0xAB8C, 1286, 1), (0xAB8D, 1287, 1), (0xAB8E, 1288, 1), (0xAB8F, 1289, 1),
+    (0xAB90, 1290, 1), (0xAB91, 1291, 1), (0xAB92, 1292, 1), (0xAB93, 1293, 1), (0xAB94, 1294, 1), (0xAB95, 1295, 1), (0xAB96, 1296, 1), (0xAB97, 1297, 1),
+    (0xAB98, 1298, 1), (0xAB99, 1299, 1), (0xAB9A, 1300, 1), (0xAB9B, 1301, 1), (0xAB9C, 1302, 1), (0xAB9D, 1303, 1), (0xAB9E, 1304, 1), (0xAB9F, 1305, 1),
+    (0xABA0, 1306, 1), (0xABA1, 1307, 1), (0xABA2, 1308, 1), (0xABA3, 1309, 1), (0xABA4, 1310, 1), (0xABA5, 1311, 1), (0xABA6, 1312, 1), (0xABA7, 1313, 1),
+    (0xABA8, 1314, 1), (0xABA9, 1315, 1), (0xABAA, 1316, 1), (0xABAB, 1317, 1), (0xABAC, 1318, 1), (0xABAD, 1319, 1), (0xABAE, 1320, 1), (0xABAF, 1321, 1),
+    (0xABB0, 1322, 1), (0xABB1, 1323, 1), (0xABB2, 1324, 1), (0xABB3, 1325, 1), (0xABB4, 1326, 1), (0xABB5, 1327, 1), (0xABB6, 1328, 1), (0xABB7, 1329, 1),
+    (0xABB8, 1330, 1), (0xABB9, 1331, 1), (0xABBA, 1332, 1), (0xABBB, 1333, 1), (0xABBC, 1334, 1), (0xABBD, 1335, 1), (0xABBE, 1336, 1), (0xABBF, 1337, 1),
+    (0xFB00, 1338, 2), (0xFB01, 1340, 2), (0xFB02, 1342, 2), (0xFB03, 1344, 3), (0xFB04, 1347, 3), (0xFB05, 1350, 2), (0xFB06, 1352, 2), (0xFB13, 1354, 2),
+    (0xFB14, 1356, 2), (0xFB15, 1358, 2), (0xFB16, 1360, 2), (0xFB17, 1362, 2), (0xFF21, 1364, 1), (0xFF22, 1365, 1), (0xFF23, 1366, 1), (0xFF24, 1367, 1),
+    (0xFF25, 1368, 1), (0xFF26, 1369, 1), (0xFF27, 1370, 1), (0xFF28, 1371, 1), (0xFF29, 1372, 1), (0xFF2A, 1373, 1), (0xFF2B, 1374, 1), (0xFF2C, 1375, 1),
+    (0xFF2D, 1376, 1), (0xFF2E, 1377, 1), (0xFF2F, 1378, 1), (0xFF30, 1379, 1), (0xFF31, 1380, 1), (0xFF32, 1381, 1), (0xFF33, 1382, 1), (0xFF34, 1383, 1),
+    (0xFF35, 1384, 1), (0xFF36, 1385, 1), (0xFF37, 1386, 1), (0xFF38, 1387, 1), (0xFF39, 1388, 1), (0xFF3A, 1389, 1), (0x10400, 1390, 1), (0x10401, 1391, 1),
+    (0x10402, 1392, 1), (0x10403, 1393, 1), (0x10404, 1394, 1), (0x10405, 1395, 1), (0x10406, 1396, 1), (0x10407, 1397, 1), (0x10408, 1398, 1), (0x10409, 1399, 1),
+    (0x1040A, 1400, 1), (0x1040B, 1401, 1), (0x1040C, 1402, 1), (0x1040D, 1403, 1), (0x1040E, 1404, 1), (0x1040F, 1405, 1), (0x10410, 1406, 1), (0x10411, 1407, 1),
+    (0x10412, 1408, 1), (0x10413, 1409, 1), (0x10414, 1410, 1), (0x10415, 1411, 1), (0x10416, 1412, 1), (0x10417, 1413, 1), (0x10418, 1414, 1), (0x10419, 1415, 1),
+    (0x1041A, 1416, 1), (0x1041B, 1417, 1), (0x1041C, 1418, 1), (0x1041D, 1419, 1), (0x1041E, 1420, 1), (0x1041F, 1421, 1), (0x10420, 1422, 1), (0x10421, 1423, 1),
+    (0x10422, 1424, 1), (0x10423, 1425, 1), (0x10424, 1426, 1), (0x10425, 1427, 1), (0x10426, 1428, 1), (0x10427, 1429, 1), (0x104B0, 1430, 1), (0x104B1, 1431, 1),
+    (0x104B2, 1432, 1), (0x104B3, 1433, 1), (0x104B4, 1434, 1), (0x104B5, 1435, 1), (0x104B6, 1436, 1), (0x104B7, 1437, 1), (0x104B8, 1438, 1), (0x104B9, 1439, 1),
+    (0x104BA, 1440, 1), (0x104BB, 1441, 1), (0x104BC, 1442, 1), (0x104BD, 1443, 1), (0x104BE, 1444, 1), (0x104BF, 1445, 1), (0x104C0, 1446, 1), (0x104C1, 1447, 1),
+    (0x104C2, 1448, 1), (0x104C3, 1449, 1), (0x104C4, 1450, 1), (0x104C5, 1451, 1), (0x104C6, 1452, 1), (0x104C7, 1453, 1), (0x104C8, 1454, 1), (0x104C9, 1455, 1),
+    (0x104CA, 1456, 1), (0x104CB, 1457, 1), (0x104CC, 1458, 1), (0x104CD, 1459, 1), (0x104CE, 1460, 1), (0x104CF, 1461, 1), (0x104D0, 1462, 1), (0x104D1, 1463, 1),
+    (0x104D2, 1464, 1), (0x104D3, 1465, 1), (0x10570, 1466, 1), (0x10571, 1467, 1), (0x10572, 1468, 1), (0x10573, 1469, 1), (0x10574, 1470, 1), (0x10575, 1471, 1),
+    (0x10576, 1472, 1), (0x10577, 1473, 1), (0x10578, 1474, 1), (0x10579, 1475, 1), (0x1057A, 1476, 1), (0x1057C, 1477, 1), (0x1057D, 1478, 1), (0x1057E, 1479, 1),
+    (0x1057F, 1480, 1), (0x10580, 1481, 1), (0x10581, 1482, 1), (0x10582, 1483, 1), (0x10583, 1484, 1), (0x10584, 1485, 1), (0x10585, 1486, 1), (0x10586, 1487, 1),
+    (0x10587, 1488, 1), (0x10588, 1489, 1), (0x10589, 1490, 1), (0x1058A, 1491, 1), (0x1058C, 1492, 1), (0x1058D, 1493, 1), (0x1058E, 1494, 1), (0x1058F, 1495, 1),
+    (0x10590, 1496, 1), (0x10591, 1497, 1), (0x10592, 1498, 1), (0x10594, 1499, 1), (0x10595, 1500, 1), (0x10C80, 1501, 1), (0x10C81, 1502, 1), (0x10C82, 1503, 1),
+    (0x10C83, 1504, 1), (0x10C84, 1505, 1), (0x10C85, 1506, 1), (0x10C86, 1507, 1), (0x10C87, 1508, 1), (0x10C88, 1509, 1), (0x10C89, 1510, 1), (0x10C8A, 1511, 1),
+    (0x10C8B, 1512, 1), (0x10C8C, 1513, 1), (0x10C8D, 1514, 1), (0x10C8E, 1515, 1), (0x10C8F, 1516, 1), (0x10C90, 1517, 1), (0x10C91, 1518, 1), (0x10C92, 1519, 1),
+    (0x10C93, 1520, 1), (0x10C94, 1521, 1), (0x10C95, 1522, 1), (0x10C96, 1523, 1), (0x10C97, 1524, 1), (0x10C98, 1525, 1), (0x10C99, 1526, 1), (0x10C9A, 1527, 1),
+    (0x10C9B, 1528, 1), (0x10C9C, 1529, 1), (0x10C9D, 1530, 1), (0x10C9E, 1531, 1), (0x10C9F, 1532, 1), (0x10CA0, 1533, 1), (0x10CA1, 1534, 1), (0x10CA2, 1535, 1),
+    (0x10CA3, 1536, 1), (0x10CA4, 1537, 1), (0x10CA5, 1538, 1), (0x10CA6, 1539, 1), (0x10CA7, 1540, 1), (0x10CA8, 1541, 1), (0x10CA9, 1542, 1), (0x10CAA, 1543, 1),
+    (0x10CAB, 1544, 1), (0x10CAC, 1545, 1), (0x10CAD, 1546, 1), (0x10CAE, 1547, 1), (0x10CAF, 1548, 1), (0x10CB0, 1549, 1), (0x10CB1, 1550, 1), (0x10CB2, 1551, 1),
+    (0x118A0, 1552, 1), (0x118A1, 1553, 1), (0x118A2, 1554, 1), (0x118A3, 1555, 1), (0x118A4, 1556, 1), (0x118A5, 1557, 1), (0x118A6, 1558, 1), (0x118A7, 1559, 1),
+    (0x118A8, 1560, 1), (0x118A9, 1561, 1), (0x118AA, 1562, 1), (0x118AB, 1563, 1), (0x118AC, 1564, 1), (0x118AD, 1565, 1), (0x118AE, 1566, 1), (0x118AF, 1567, 1),
+    (0x118B0, 1568, 1), (0x118B1, 1569, 1), (0x118B2, 1570, 1), (0x118B3, 1571, 1), (0x118B4, 1572, 1), (0x118B5, 1573, 1), (0x118B6, 1574, 1), (0x118B7, 1575, 1),
+    (0x118B8, 1576, 1), (0x118B9, 1577, 1), (0x118BA, 1578, 1), (0x118BB, 1579, 1), (0x118BC, 1580, 1), (0x118BD, 1581, 1), (0x118BE, 1582, 1), (0x118BF, 1583, 1),
+    (0x16E40, 1584, 1), (0x16E41, 1585, 1), (0x16E42, 1586, 1), (0x16E43, 1587, 1), (0x16E44, 1588, 1), (0x16E45, 1589, 1), (0x16E46, 1590, 1), (0x16E47, 1591, 1),
+    (0x16E48, 1592, 1), (0x16E49, 1593, 1), (0x16E4A, 1594, 1), (0x16E4B, 1595, 1), (0x16E4C, 1596, 1), (0x16E4D, 1597, 1), (0x16E4E, 1598, 1), (0x16E4F, 1599, 1),
+    (0x16E50, 1600, 1), (0x16E51, 1601, 1), (0x16E52, 1602, 1), (0x16E53, 1603, 1), (0x16E54, 1604, 1), (0x16E55, 1605, 1), (0x16E56, 1606, 1), (0x16E57, 1607, 1),
+    (0x16E58, 1608, 1), (0x16E59, 1609, 1), (0x16E5A, 1610, 1), (0x16E5B, 1611, 1), (0x16E5C, 1612, 1), (0x16E5D, 1613, 1), (0x16E5E, 1614, 1), (0x16E5F, 1615, 1),
+    (0x1E900, 1616, 1), (0x1E901, 1617, 1), (0x1E902, 1618, 1), (0x1E903, 1619, 1), (0x1E904, 1620, 1), (0x1E905, 1621, 1), (0x1E906, 1622, 1), (0x1E907, 1623, 1),
+    (0x1E908, 1624, 1), (0x1E909, 1625, 1), (0x1E90A, 1626, 1), (0x1E90B, 1627, 1), (0x1E90C, 1628, 1), (0x1E90D, 1629, 1), (0x1E90E, 1630, 1), (0x1E90F, 1631, 1),
+    (0x1E910, 1632, 1), (0x1E911, 1633, 1), (0x1E912, 1634, 1), (0x1E913, 1635, 1), (0x1E914, 1636, 1), (0x1E915, 1637, 1), (0x1E916, 1638, 1), (0x1E917, 1639, 1),
+    (0x1E918, 1640, 1), (0x1E919, 1641, 1), (0x1E91A, 1642, 1), (0x1E91B, 1643, 1), (0x1E91C, 1644, 1), (0x1E91D, 1645, 1), (0x1E91E, 1646, 1), (0x1E91F, 1647, 1),
+    (0x1E920, 1648, 1), (0x1E921, 1649, 1),
+];
+
+#[rustfmt::skip]
+pub(crate) const CASE_FOLDING_CHARS: &[char] = &[
+    '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}', '\u{68}', '\u{69}', '\u{6A}',
+    '\u{6B}', '\u{6C}', '\u{6D}', '\u{6E}', '\u{6F}', '\u{70}', '\u{71}', '\u{72}', '\u{73}', '\u{74}',
+    '\u{75}', '\u{76}', '\u{77}', '\u{78}', '\u{79}', '\u{7A}', '\u{3BC}', '\u{E0}', '\u{E1}', '\u{E2}',
+    '\u{E3}', '\u{E4}', '\u{E5}', '\u{E6}', '\u{E7}', '\u{E8}', '\u{E9}', '\u{EA}', '\u{EB}', '\u{EC}',
+    '\u{ED}', '\u{EE}', '\u{EF}', '\u{F0}', '\u{F1}', '\u{F2}', '\u{F3}', '\u{F4}', '\u{F5}', '\u{F6}',
+    '\u{F8}', '\u{F9}', '\u{FA}', '\u{FB}', '\u{FC}', '\u{FD}', '\u{FE}', '\u{73}', '\u{73}', '\u{101}',
+    '\u{103}', '\u{105}', '\u{107}', '\u{109}', '\u{10B}', '\u{10D}', '\u{10F}', '\u{111}', '\u{113}', '\u{115}',
+    '\u{117}', '\u{119}', '\u{11B}', '\u{11D}', '\u{11F}', '\u{121}', '\u{123}', '\u{125}', '\u{127}', '\u{129}',
+    '\u{12B}', '\u{12D}', '\u{12F}', '\u{69}', '\u{307}', '\u{133}', '\u{135}', '\u{137}', '\u{13A}', '\u{13C}',
+    '\u{13E}', '\u{140}', '\u{142}', '\u{144}', '\u{146}', '\u{148}', '\u{2BC}', '\u{6E}', '\u{14B}', '\u{14D}',
+    '\u{14F}', '\u{151}', '\u{153}', '\u{155}', '\u{157}', '\u{159}', '\u{15B}', '\u{15D}', '\u{15F}', '\u{161}',
+    '\u{163}', '\u{165}', '\u{167}', '\u{169}', '\u{16B}', '\u{16D}', '\u{16F}', '\u{171}', '\u{173}', '\u{175}',
+    '\u{177}', '\u{FF}', '\u{17A}', '\u{17C}', '\u{17E}', '\u{73}', '\u{253}', '\u{183}', '\u{185}', '\u{254}',
+    '\u{188}', '\u{256}', '\u{257}', '\u{18C}', '\u{1DD}', '\u{259}', '\u{25B}', '\u{192}', '\u{260}', '\u{263}',
+    '\u{269}', '\u{268}', '\u{199}', '\u{26F}', '\u{272}', '\u{275}', '\u{1A1}', '\u{1A3}', '\u{1A5}', '\u{280}',
+    '\u{1A8}', '\u{283}', '\u{1AD}', '\u{288}', '\u{1B0}', '\u{28A}', '\u{28B}', '\u{1B4}', '\u{1B6}', '\u{292}',
+    '\u{1B9}', '\u{1BD}', '\u{1C6}', '\u{1C6}', '\u{1C9}', '\u{1C9}', '\u{1CC}', '\u{1CC}', '\u{1CE}', '\u{1D0}',
+    '\u{1D2}', '\u{1D4}', '\u{1D6}', '\u{1D8}', '\u{1DA}', '\u{1DC}', '\u{1DF}', '\u{1E1}', '\u{1E3}', '\u{1E5}',
+    '\u{1E7}', '\u{1E9}', '\u{1EB}', '\u{1ED}', '\u{1EF}', '\u{6A}', '\u{30C}', '\u{1F3}', '\u{1F3}', '\u{1F5}',
+    '\u{195}', '\u{1BF}', '\u{1F9}', '\u{1FB}', '\u{1FD}', '\u{1FF}', '\u{201}', '\u{203}', '\u{205}', '\u{207}',
+    '\u{209}', '\u{20B}', '\u{20D}', '\u{20F}', '\u{211}', '\u{213}', '\u{215}', '\u{217}', '\u{219}', '\u{21B}',
+    '\u{21D}', '\u{21F}', '\u{19E}', '\u{223}', '\u{225}', '\u{227}', '\u{229}', '\u{22B}', '\u{22D}', '\u{22F}',
+    '\u{231}', '\u{233}', '\u{2C65}', '\u{23C}', '\u{19A}', '\u{2C66}', '\u{242}', '\u{180}', '\u{289}', '\u{28C}',
+    '\u{247}', '\u{249}', '\u{24B}', '\u{24D}', '\u{24F}', '\u{3B9}', '\u{371}', '\u{373}', '\u{377}', '\u{3F3}',
+    '\u{3AC}', '\u{3AD}', '\u{3AE}', '\u{3AF}', '\u{3CC}', '\u{3CD}', '\u{3CE}', '\u{3B9}', '\u{308}', '\u{301}',
+    '\u{3B1}', '\u{3B2}', '\u{3B3}', '\u{3B4}', '\u{3B5}', '\u{3B6}', '\u{3B7}', '\u{3B8}', '\u{3B9}', '\u{3BA}',
+    '\u{3BB}', '\u{3BC}', '\u{3BD}', '\u{3BE}', '\u{3BF}', '\u{3C0}', '\u{3C1}', '\u{3C3}', '\u{3C4}', '\u{3C5}',
+    '\u{3C6}', '\u{3C7}', '\u{3C8}', '\u{3C9}', '\u{3CA}', '\u{3CB}', '\u{3C5}', '\u{308}', '\u{301}', '\u{3C3}',
+    '\u{3D7}', '\u{3B2}', '\u{3B8}', '\u{3C6}', '\u{3C0}', '\u{3D9}', '\u{3DB}', '\u{3DD}', '\u{3DF}', '\u{3E1}',
+    '\u{3E3}', '\u{3E5}', '\u{3E7}', '\u{3E9}', '\u{3EB}', '\u{3ED}', '\u{3EF}', '\u{3BA}', '\u{3C1}', '\u{3B8}',
+    '\u{3B5}', '\u{3F8}', '\u{3F2}', '\u{3FB}', '\u{37B}', '\u{37C}', '\u{37D}', '\u{450}', '\u{451}', '\u{452}',
+    '\u{453}', '\u{454}', '\u{455}', '\u{456}', '\u{457}', '\u{458}', '\u{459}', '\u{45A}', '\u{45B}', '\u{45C}',
+    '\u{45D}', '\u{45E}', '\u{45F}', '\u{430}', '\u{431}', '\u{432}', '\u{433}', '\u{434}', '\u{435}', '\u{436}',
+    '\u{437}', '\u{438}', '\u{439}', '\u{43A}', '\u{43B}', '\u{43C}', '\u{43D}', '\u{43E}', '\u{43F}', '\u{440}',
+    '\u{441}', '\u{442}', '\u{443}', '\u{444}', '\u{445}', '\u{446}', '\u{447}', '\u{448}', '\u{449}', '\u{44A}',
+    '\u{44B}', '\u{44C}', '\u{44D}', '\u{44E}', '\u{44F}', '\u{461}', '\u{463}', '\u{465}', '\u{467}', '\u{469}',
+    '\u{46B}', '\u{46D}', '\u{46F}', '\u{471}', '\u{473}', '\u{475}', '\u{477}', '\u{479}', '\u{47B}', '\u{47D}',
+    '\u{47F}', '\u{481}', '\u{48B}', '\u{48D}', '\u{48F}', '\u{491}', '\u{493}', '\u{495}', '\u{497}', '\u{499}',
+    '\u{49B}', '\u{49D}', '\u{49F}', '\u{4A1}', '\u{4A3}', '\u{4A5}', '\u{4A7}', '\u{4A9}', '\u{4AB}', '\u{4AD}',
+    '\u{4AF}', '\u{4B1}', '\u{4B3}', '\u{4B5}', '\u{4B7}', '\u{4B9}', '\u{4BB}', '\u{4BD}', '\u{4BF}', '\u{4CF}',
+    '\u{4C2}', '\u{4C4}', '\u{4C6}', '\u{4C8}', '\u{4CA}', '\u{4CC}', '\u{4CE}', '\u{4D1}', '\u{4D3}', '\u{4D5}',
+    '\u{4D7}', '\u{4D9}', '\u{4DB}', '\u{4DD}', '\u{4DF}', '\u{4E1}', '\u{4E3}', '\u{4E5}', '\u{4E7}', '\u{4E9}',
+    '\u{4EB}', '\u{4ED}', '\u{4EF}', '\u{4F1}', '\u{4F3}', '\u{4F5}', '\u{4F7}', '\u{4F9}', '\u{4FB}', '\u{4FD}',
+    '\u{4FF}', '\u{501}', '\u{503}', '\u{505}', '\u{507}', '\u{509}', '\u{50B}', '\u{50D}', '\u{50F}', '\u{511}',
+    '\u{513}', '\u{515}', '\u{517}', '\u{519}', '\u{51B}', '\u{51D}', '\u{51F}', '\u{521}', '\u{523}', '\u{525}',
+    '\u{527}', '\u{529}', '\u{52B}', '\u{52D}', '\u{52F}', '\u{561}', '\u{562}', '\u{563}', '\u{564}', '\u{565}',
+    '\u{566}', '\u{567}', '\u{568}', '\u{569}', '\u{56A}', '\u{56B}', '\u{56C}', '\u{56D}', '\u{56E}', '\u{56F}',
+    '\u{570}', '\u{571}', '\u{572}', '\u{573}', '\u{574}', '\u{575}', '\u{576}', '\u{577}', '\u{578}', '\u{579}',
+    '\u{57A}', '\u{57B}', '\u{57C}', '\u{57D}', '\u{57E}', '\u{57F}', '\u{580}', '\u{581}', '\u{582}', '\u{583}',
+    '\u{584}', '\u{585}', '\u{586}', '\u{565}', '\u{582}', '\u{2D00}', '\u{2D01}', '\u{2D02}', '\u{2D03}', '\u{2D04}',
+    '\u{2D05}', '\u{2D06}', '\u{2D07}', '\u{2D08}', '\u{2D09}', '\u{2D0A}', '\u{2D0B}', '\u{2D0C}', '\u{2D0D}', '\u{2D0E}',
+    '\u{2D0F}', '\u{2D10}', '\u{2D11}', '\u{2D12}', '\u{2D13}', '\u{2D14}', '\u{2D15}', '\u{2D16}', '\u{2D17}', '\u{2D18}',
+    '\u{2D19}', '\u{2D1A}', '\u{2D1B}', '\u{2D1C}', '\u{2D1D}', '\u{2D1E}', '\u{2D1F}', '\u{2D20}', '\u{2D21}', '\u{2D22}',
+    '\u{2D23}', '\u{2D24}', '\u{2D25}', '\u{2D27}', '\u{2D2D}', '\u{13F0}', '\u{13F1}', '\u{13F2}', '\u{13F3}', '\u{13F4}',
+    '\u{13F5}', '\u{432}', '\u{434}', '\u{43E}', '\u{441}', '\u{442}', '\u{442}', '\u{44A}', '\u{463}', '\u{A64B}',
+    '\u{10D0}', '\u{10D1}', '\u{10D2}', '\u{10D3}', '\u{10D4}', '\u{10D5}', '\u{10D6}', '\u{10D7}', '\u{10D8}', '\u{10D9}',
+    '\u{10DA}', '\u{10DB}', '\u{10DC}', '\u{10DD}', '\u{10DE}', '\u{10DF}', '\u{10E0}', '\u{10E1}', '\u{10E2}', '\u{10E3}',
+    '\u{10E4}', '\u{10E5}', '\u{10E6}', '\u{10E7}', '\u{10E8}', '\u{10E9}', '\u{10EA}', '\u{10EB}', '\u{10EC}', '\u{10ED}',
+    '\u{10EE}', '\u{10EF}', '\u{10F0}', '\u{10F1}', '\u{10F2}', '\u{10F3}', '\u{10F4}', '\u{10F5}', '\u{10F6}', '\u{10F7}',
+    '\u{10F8}', '\u{10F9}', '\u{10FA}', '\u{10FD}', '\u{10FE}', '\u{10FF}', '\u{1E01}', '\u{1E03}', '\u{1E05}', '\u{1E07}',
+    '\u{1E09}', '\u{1E0B}', '\u{1E0D}', '\u{1E0F}', '\u{1E11}', '\u{1E13}', '\u{1E15}', '\u{1E17}', '\u{1E19}', '\u{1E1B}',
+    '\u{1E1D}', '\u{1E1F}', '\u{1E21}', '\u{1E23}', '\u{1E25}', '\u{1E27}', '\u{1E29}', '\u{1E2B}', '\u{1E2D}', '\u{1E2F}',
+    '\u{1E31}', '\u{1E33}', '\u{1E35}', '\u{1E37}', '\u{1E39}', '\u{1E3B}', '\u{1E3D}', '\u{1E3F}', '\u{1E41}', '\u{1E43}',
+    '\u{1E45}', '\u{1E47}', '\u{1E49}', '\u{1E4B}', '\u{1E4D}', '\u{1E4F}', '\u{1E51}', '\u{1E53}', '\u{1E55}', '\u{1E57}',
+    '\u{1E59}', '\u{1E5B}', '\u{1E5D}', '\u{1E5F}', '\u{1E61}', '\u{1E63}', '\u{1E65}', '\u{1E67}', '\u{1E69}', '\u{1E6B}',
+    '\u{1E6D}', '\u{1E6F}', '\u{1E71}', '\u{1E73}', '\u{1E75}', '\u{1E77}', '\u{1E79}', '\u{1E7B}', '\u{1E7D}', '\u{1E7F}',
+    '\u{1E81}', '\u{1E83}', '\u{1E85}', '\u{1E87}', '\u{1E89}', '\u{1E8B}', '\u{1E8D}', '\u{1E8F}', '\u{1E91}', '\u{1E93}',
+    '\u{1E95}', '\u{68}', '\u{331}', '\u{74}', '\u{308}', '\u{77}', '\u{30A}', '\u{79}', '\u{30A}', '\u{61}',
+    '\u{2BE}', '\u{1E61}', '\u{73}', '\u{73}', '\u{1EA1}', '\u{1EA3}', '\u{1EA5}', '\u{1EA7}', '\u{1EA9}', '\u{1EAB}',
+    '\u{1EAD}', '\u{1EAF}', '\u{1EB1}', '\u{1EB3}', '\u{1EB5}', '\u{1EB7}', '\u{1EB9}', '\u{1EBB}', '\u{1EBD}', '\u{1EBF}',
+    '\u{1EC1}', '\u{1EC3}', '\u{1EC5}', '\u{1EC7}', '\u{1EC9}', '\u{1ECB}', '\u{1ECD}', '\u{1ECF}', '\u{1ED1}', '\u{1ED3}',
+    '\u{1ED5}', '\u{1ED7}', '\u{1ED9}', '\u{1EDB}', '\u{1EDD}', '\u{1EDF}', '\u{1EE1}', '\u{1EE3}', '\u{1EE5}', '\u{1EE7}',
+    '\u{1EE9}', '\u{1EEB}', '\u{1EED}', '\u{1EEF}', '\u{1EF1}', '\u{1EF3}', '\u{1EF5}', '\u{1EF7}', '\u{1EF9}', '\u{1EFB}',
+    '\u{1EFD}', '\u{1EFF}', '\u{1F00}', '\u{1F01}', '\u{1F02}', '\u{1F03}', '\u{1F04}', '\u{1F05}', '\u{1F06}', '\u{1F07}',
+    '\u{1F10}', '\u{1F11}', '\u{1F12}', '\u{1F13}', '\u{1F14}', '\u{1F15}', '\u{1F20}', '\u{1F21}', '\u{1F22}', '\u{1F23}',
+    '\u{1F24}', '\u{1F25}', '\u{1F26}', '\u{1F27}', '\u{1F30}', '\u{1F31}', '\u{1F32}', '\u{1F33}', '\u{1F34}', '\u{1F35}',
+    '\u{1F36}', '\u{1F37}', '\u{1F40}', '\u{1F41}', '\u{1F42}', '\u{1F43}', '\u{1F44}', '\u{1F45}', '\u{3C5}', '\u{313}',
+    '\u{3C5}', '\u{313}', '\u{300}', '\u{3C5}', '\u{313}', '\u{301}', '\u{3C5}', '\u{313}', '\u{342}', '\u{1F51}',
+    '\u{1F53}', '\u{1F55}', '\u{1F57}', '\u{1F60}', '\u{1F61}', '\u{1F62}', '\u{1F63}', '\u{1F64}', '\u{1F65}', '\u{1F66}',
+    '\u{1F67}', '\u{1F00}', '\u{3B9}', '\u{1F01}', '\u{3B9}', '\u{1F02}', '\u{3B9}', '\u{1F03}', '\u{3B9}', '\u{1F04}',
+    '\u{3B9}', '\u{1F05}', '\u{3B9}', '\u{1F06}', '\u{3B9}', '\u{1F07}', '\u{3B9}', '\u{1F00}', '\u{3B9}', '\u{1F01}',
+    '\u{3B9}', '\u{1F02}', '\u{3B9}', '\u{1F03}', '\u{3B9}', '\u{1F04}', '\u{3B9}', '\u{1F05}', '\u{3B9}', '\u{1F06}',
+    '\u{3B9}', '\u{1F07}', '\u{3B9}', '\u{1F20}', '\u{3B9}', '\u{1F21}', '\u{3B9}', '\u{1F22}', '\u{3B9}', '\u{1F23}',
+    '\u{3B9}', '\u{1F24}', '\u{3B9}', '\u{1F25}', '\u{3B9}', '\u{1F26}', '\u{3B9}', '\u{1F27}', '\u{3B9}', '\u{1F20}',
+    '\u{3B9}', '\u{1F21}', '\u{3B9}', '\u{1F22}', '\u{3B9}', '\u{1F23}', '\u{3B9}', '\u{1F24}', '\u{3B9}', '\u{1F25}',
+    '\u{3B9}', '\u{1F26}', '\u{3B9}', '\u{1F27}', '\u{3B9}', '\u{1F60}', '\u{3B9}', '\u{1F61}', '\u{3B9}', '\u{1F62}',
+    '\u{3B9}', '\u{1F63}', '\u{3B9}', '\u{1F64}', '\u{3B9}', '\u{1F65}', '\u{3B9}', '\u{1F66}', '\u{3B9}', '\u{1F67}',
+    '\u{3B9}', '\u{1F60}', '\u{3B9}', '\u{1F61}', '\u{3B9}', '\u{1F62}', '\u{3B9}', '\u{1F63}', '\u{3B9}', '\u{1F64}',
+    '\u{3B9}', '\u{1F65}', '\u{3B9}', '\u{1F66}', '\u{3B9}', '\u{1F67}', '\u{3B9}', '\u{1F70}', '\u{3B9}', '\u{3B1}',
+    '\u{3B9}', '\u{3AC}', '\u{3B9}', '\u{3B1}', '\u{342}', '\u{3B1}', '\u{342}', '\u{3B9}', '\u{1FB0}', '\u{1FB1}',
+    '\u{1F70}', '\u{1F71}', '\u{3B1}', '\u{3B9}', '\u{3B9}', '\u{1F74}', '\u{3B9}', '\u{3B7}', '\u{3B9}', '\u{3AE}',
+    '\u{3B9}', '\u{3B7}', '\u{342}', '\u{3B7}', '\u{342}', '\u{3B9}', '\u{1F72}', '\u{1F73}', '\u{1F74}', '\u{1F75}',
+    '\u{3B7}', '\u{3B9}', '\u{3B9}', '\u{308}', '\u{300}', '\u{3B9}', '\u{308}', '\u{301}', '\u{3B9}', '\u{342}',
+    '\u{3B9}', '\u{308}', '\u{342}', '\u{1FD0}', '\u{1FD1}', '\u{1F76}', '\u{1F77}', '\u{3C5}', '\u{308}', '\u{300}',
+    '\u{3C5}', '\u{308}', '\u{301}', '\u{3C1}', '\u{313}', '\u{3C5}', '\u{342}', '\u{3C5}', '\u{308}', '\u{342}',
+    '\u{1FE0}', '\u{1FE1}', '\u{1F7A}', '\u{1F7B}', '\u{1FE5}', '\u{1F7C}', '\u{3B9}', '\u{3C9}', '\u{3B9}', '\u{3CE}',
+    '\u{3B9}', '\u{3C9}', '\u{342}', '\u{3C9}', '\u{342}', '\u{3B9}', '\u{1F78}', '\u{1F79}', '\u{1F7C}', '\u{1F7D}',
+    '\u{3C9}', '\u{3B9}', '\u{3C9}', '\u{6B}', '\u{E5}', '\u{214E}', '\u{2170}', '\u{2171}', '\u{2172}', '\u{2173}',
+    '\u{2174}', '\u{2175}', '\u{2176}', '\u{2177}', '\u{2178}', '\u{2179}', '\u{217A}', '\u{217B}', '\u{217C}', '\u{217D}',
+    '\u{217E}', '\u{217F}', '\u{2184}', '\u{24D0}', '\u{24D1}', '\u{24D2}', '\u{24D3}', '\u{24D4}', '\u{24D5}', '\u{24D6}',
+    '\u{24D7}', '\u{24D8}', '\u{24D9}', '\u{24DA}', '\u{24DB}', '\u{24DC}', '\u{24DD}', '\u{24DE}', '\u{24DF}', '\u{24E0}',
+    '\u{24E1}', '\u{24E2}', '\u{24E3}', '\u{24E4}', '\u{24E5}', '\u{24E6}', '\u{24E7}', '\u{24E8}', '\u{24E9}', '\u{2C30}',
+    '\u{2C31}', '\u{2C32}', '\u{2C33}', '\u{2C34}', '\u{2C35}', '\u{2C36}', '\u{2C37}', '\u{2C38}', '\u{2C39}', '\u{2C3A}',
+    '\u{2C3B}', '\u{2C3C}', '\u{2C3D}', '\u{2C3E}', '\u{2C3F}', '\u{2C40}', '\u{2C41}', '\u{2C42}', '\u{2C43}', '\u{2C44}',
+    '\u{2C45}', '\u{2C46}', '\u{2C47}', '\u{2C48}', '\u{2C49}', '\u{2C4A}', '\u{2C4B}', '\u{2C4C}', '\u{2C4D}', '\u{2C4E}',
+    '\u{2C4F}', '\u{2C50}', '\u{2C51}', '\u{2C52}', '\u{2C53}', '\u{2C54}', '\u{2C55}', '\u{2C56}', '\u{2C57}', '\u{2C58}',
+    '\u{2C59}', '\u{2C5A}', '\u{2C5B}', '\u{2C5C}', '\u{2C5D}', '\u{2C5E}', '\u{2C5F}', '\u{2C61}', '\u{26B}', '\u{1D7D}',
+    '\u{27D}', '\u{2C68}', '\u{2C6A}', '\u{2C6C}', '\u{251}', '\u{271}', '\u{250}', '\u{252}', '\u{2C73}', '\u{2C76}',
+    '\u{23F}', '\u{240}', '\u{2C81}', '\u{2C83}', '\u{2C85}', '\u{2C87}', '\u{2C89}', '\u{2C8B}', '\u{2C8D}', '\u{2C8F}',
+    '\u{2C91}', '\u{2C93}', '\u{2C95}', '\u{2C97}', '\u{2C99}', '\u{2C9B}', '\u{2C9D}', '\u{2C9F}', '\u{2CA1}', '\u{2CA3}',
+    '\u{2CA5}', '\u{2CA7}', '\u{2CA9}', '\u{2CAB}', '\u{2CAD}', '\u{2CAF}', '\u{2CB1}', '\u{2CB3}', '\u{2CB5}', '\u{2CB7}',
+    '\u{2CB9}', '\u{2CBB}', '\u{2CBD}', '\u{2CBF}', '\u{2CC1}', '\u{2CC3}', '\u{2CC5}', '\u{2CC7}', '\u{2CC9}', '\u{2CCB}',
+    '\u{2CCD}', '\u{2CCF}', '\u{2CD1}', '\u{2CD3}', '\u{2CD5}', '\u{2CD7}', '\u{2CD9}', '\u{2CDB}', '\u{2CDD}', '\u{2CDF}',
+    '\u{2CE1}', '\u{2CE3}', '\u{2CEC}', '\u{2CEE}', '\u{2CF3}', '\u{A641}', '\u{A643}', '\u{A645}', '\u{A647}', '\u{A649}',
+    '\u{A64B}', '\u{A64D}', '\u{A64F}', '\u{A651}', '\u{A653}', '\u{A655}', '\u{A657}', '\u{A659}', '\u{A65B}', '\u{A65D}',
+    '\u{A65F}', '\u{A661}', '\u{A663}', '\u{A665}', '\u{A667}', '\u{A669}', '\u{A66B}', '\u{A66D}', '\u{A681}', '\u{A683}',
+    '\u{A685}', '\u{A687}', '\u{A689}', '\u{A68B}', '\u{A68D}', '\u{A68F}', '\u{A691}', '\u{A693}', '\u{A695}', '\u{A697}',
+    '\u{A699}', '\u{A69B}', '\u{A723}', '\u{A725}', '\u{A727}', '\u{A729}', '\u{A72B}', '\u{A72D}', '\u{A72F}', '\u{A733}',
+    '\u{A735}', '\u{A737}', '\u{A739}', '\u{A73B}', '\u{A73D}', '\u{A73F}', '\u{A741}', '\u{A743}', '\u{A745}', '\u{A747}',
+    '\u{A749}', '\u{A74B}', '\u{A74D}', '\u{A74F}', '\u{A751}', '\u{A753}', '\u{A755}', '\u{A757}', '\u{A759}', '\u{A75B}',
+    '\u{A75D}', '\u{A75F}', '\u{A761}', '\u{A763}', '\u{A765}', '\u{A767}', '\u{A769}', '\u{A76B}', '\u{A76D}', '\u{A76F}',
+    '\u{A77A}', '\u{A77C}', '\u{1D79}', '\u{A77F}', '\u{A781}', '\u{A783}', '\u{A785}', '\u{A787}', '\u{A78C}', '\u{265}',
+    '\u{A791}', '\u{A793}', '\u{A797}', '\u{A799}', '\u{A79B}', '\u{A79D}', '\u{A79F}', '\u{A7A1}', '\u{A7A3}', '\u{A7A5}',
+    '\u{A7A7}', '\u{A7A9}', '\u{266}', '\u{25C}', '\u{261}', '\u{26C}', '\u{26A}', '\u{29E}', '\u{287}', '\u{29D}',
+    '\u{AB53}', '\u{A7B5}', '\u{A7B7}', '\u{A7B9}', '\u{A7BB}', '\u{A7BD}', '\u{A7BF}', '\u{A7C1}', '\u{A7C3}', '\u{A794}',
+    '\u{282}', '\u{1D8E}', '\u{A7C8}', '\u{A7CA}', '\u{A7D1}', '\u{A7D7}', '\u{A7D9}', '\u{A7F6}', '\u{13A0}', '\u{13A1}',
+    '\u{13A2}', '\u{13A3}', '\u{13A4}', '\u{13A5}', '\u{13A6}', '\u{13A7}', '\u{13A8}', '\u{13A9}', '\u{13AA}', '\u{13AB}',
+    '\u{13AC}', '\u{13AD}', '\u{13AE}', '\u{13AF}', '\u{13B0}', '\u{13B1}', '\u{13B2}', '\u{13B3}', '\u{13B4}', '\u{13B5}',
+    '\u{13B6}', '\u{13B7}', '\u{13B8}', '\u{13B9}', '\u{13BA}', '\u{13BB}', '\u{13BC}', '\u{13BD}', '\u{13BE}', '\u{13BF}',
+    '\u{13C0}', '\u{13C1}', '\u{13C2}', '\u{13C3}', '\u{13C4}', '\u{13C5}', '\u{13C6}', '\u{13C7}', '\u{13C8}', '\u{13C9}',
+    '\u{13CA}', '\u{13CB}', '\u{13CC}', '\u{13CD}', '\u{13CE}', '\u{13CF}', '\u{13D0}', '\u{13D1}', '\u{13D2}', '\u{13D3}',
+    '\u{13D4}', '\u{13D5}', '\u{13D6}', '\u{13D7}', '\u{13D8}', '\u{13D9}', '\u{13DA}', '\u{13DB}', '\u{13DC}', '\u{13DD}',
+    '\u{13DE}', '\u{13DF}', '\u{13E0}', '\u{13E1}', '\u{13E2}', '\u{13E3}', '\u{13E4}', '\u{13E5}', '\u{13E6}', '\u{13E7}',
+    '\u{13E8}', '\u{13E9}', '\u{13EA}', '\u{13EB}', '\u{13EC}', '\u{13ED}', '\u{13EE}', '\u{13EF}', '\u{66}', '\u{66}',
+    '\u{66}', '\u{69}', '\u{66}', '\u{6C}', '\u{66}', '\u{66}', '\u{69}', '\u{66}', '\u{66}', '\u{6C}',
+    '\u{73}', '\u{74}', '\u{73}', '\u{74}', '\u{574}', '\u{576}', '\u{574}', '\u{565}', '\u{574}', '\u{56B}',
+    '\u{57E}', '\u{576}', '\u{574}', '\u{56D}', '\u{FF41}', '\u{FF42}', '\u{FF43}', '\u{FF44}', '\u{FF45}', '\u{FF46}',
+    '\u{FF47}', '\u{FF48}', '\u{FF49}', '\u{FF4A}', '\u{FF4B}', '\u{FF4C}', '\u{FF4D}', '\u{FF4E}', '\u{FF4F}', '\u{FF50}',
+    '\u{FF51}', '\u{FF52}', '\u{FF53}', '\u{FF54}', '\u{FF55}', '\u{FF56}', '\u{FF57}', '\u{FF58}', '\u{FF59}', '\u{FF5A}',
+    '\u{10428}', '\u{10429}', '\u{1042A}', '\u{1042B}', '\u{1042C}', '\u{1042D}', '\u{1042E}', '\u{1042F}', '\u{10430}', '\u{10431}',
+    '\u{10432}', '\u{10433}', '\u{10434}', '\u{10435}', '\u{10436}', '\u{10437}', '\u{10438}', '\u{10439}', '\u{1043A}', '\u{1043B}',
+    '\u{1043C}', '\u{1043D}', '\u{1043E}', '\u{1043F}', '\u{10440}', '\u{10441}', '\u{10442}', '\u{10443}', '\u{10444}', '\u{10445}',
+    '\u{10446}', '\u{10447}', '\u{10448}', '\u{10449}', '\u{1044A}', '\u{1044B}', '\u{1044C}', '\u{1044D}', '\u{1044E}', '\u{1044F}',
+    '\u{104D8}', '\u{104D9}', '\u{104DA}', '\u{104DB}', '\u{104DC}', '\u{104DD}', '\u{104DE}', '\u{104DF}', '\u{104E0}', '\u{104E1}',
+    '\u{104E2}', '\u{104E3}', '\u{104E4}', '\u{104E5}', '\u{104E6}', '\u{104E7}', '\u{104E8}', '\u{104E9}', '\u{104EA}', '\u{104EB}',
+    '\u{104EC}', '\u{104ED}', '\u{104EE}', '\u{104EF}', '\u{104F0}', '\u{104F1}', '\u{104F2}', '\u{104F3}', '\u{104F4}', '\u{104F5}',
+    '\u{104F6}', '\u{104F7}', '\u{104F8}', '\u{104F9}', '\u{104FA}', '\u{104FB}', '\u{10597}', '\u{10598}', '\u{10599}', '\u{1059A}',
+    '\u{1059B}', '\u{1059C}', '\u{1059D}', '\u{1059E}', '\u{1059F}', '\u{105A0}', '\u{105A1}', '\u{105A3}', '\u{105A4}', '\u{105A5}',
+    '\u{105A6}', '\u{105A7}', '\u{105A8}', '\u{105A9}', '\u{105AA}', '\u{105AB}', '\u{105AC}', '\u{105AD}', '\u{105AE}', '\u{105AF}',
+    '\u{105B0}', '\u{105B1}', '\u{105B3}', '\u{105B4}', '\u{105B5}', '\u{105B6}', '\u{105B7}', '\u{105B8}', '\u{105B9}', '\u{105BB}',
+    '\u{105BC}', '\u{10CC0}', '\u{10CC1}', '\u{10CC2}', '\u{10CC3}', '\u{10CC4}', '\u{10CC5}', '\u{10CC6}', '\u{10CC7}', '\u{10CC8}',
+    '\u{10CC9}', '\u{10CCA}', '\u{10CCB}', '\u{10CCC}', '\u{10CCD}', '\u{10CCE}', '\u{10CCF}', '\u{10CD0}', '\u{10CD1}', '\u{10CD2}',
+    '\u{10CD3}', '\u{10CD4}', '\u{10CD5}', '\u{10CD6}', '\u{10CD7}', '\u{10CD8}', '\u{10CD9}', '\u{10CDA}', '\u{10CDB}', '\u{10CDC}',
+    '\u{10CDD}', '\u{10CDE}', '\u{10CDF}', '\u{10CE0}', '\u{10CE1}', '\u{10CE2}', '\u{10CE3}', '\u{10CE4}', '\u{10CE5}', '\u{10CE6}',
+    '\u{10CE7}', '\u{10CE8}', '\u{10CE9}', '\u{10CEA}', '\u{10CEB}', '\u{10CEC}', '\u{10CED}', '\u{10CEE}', '\u{10CEF}', '\u{10CF0}',
+    '\u{10CF1}', '\u{10CF2}', '\u{118C0}', '\u{118C1}', '\u{118C2}', '\u{118C3}', '\u{118C4}', '\u{118C5}', '\u{118C6}', '\u{118C7}',
+    '\u{118C8}', '\u{118C9}', '\u{118CA}', '\u{118CB}', '\u{118CC}', '\u{118CD}', '\u{118CE}', '\u{118CF}', '\u{118D0}', '\u{118D1}',
+    '\u{118D2}', '\u{118D3}', '\u{118D4}', '\u{118D5}', '\u{118D6}', '\u{118D7}', '\u{118D8}', '\u{118D9}', '\u{118DA}', '\u{118DB}',
+    '\u{118DC}', '\u{118DD}', '\u{118DE}', '\u{118DF}', '\u{16E60}', '\u{16E61}', '\u{16E62}', '\u{16E63}', '\u{16E64}', '\u{16E65}',
+    '\u{16E66}', '\u{16E67}', '\u{16E68}', '\u{16E69}', '\u{16E6A}', '\u{16E6B}', '\u{16E6C}', '\u{16E6D}', '\u{16E6E}', '\u{16E6F}',
+    '\u{16E70}', '\u{16E71}', '\u{16E72}', '\u{16E73}', '\u{16E74}', '\u{16E75}', '\u{16E76}', '\u{16E77}', '\u{16E78}', '\u{16E79}',
+    '\u{16E7A}', '\u{16E7B}', '\u{16E7C}', '\u{16E7D}', '\u{16E7E}', '\u{16E7F}', '\u{1E922}', '\u{1E923}', '\u{1E924}', '\u{1E925}',
+    '\u{1E926}', '\u{1E927}', '\u{1E928}', '\u{1E929}', '\u{1E92A}', '\u{1E92B}', '\u{1E92C}', '\u{1E92D}', '\u{1E92E}', '\u{1E92F}',
+    '\u{1E930}', '\u{1E931}', '\u{1E932}', '\u{1E933}', '\u{1E934}', '\u{1E935}', '\u{1E936}', '\u{1E937}', '\u{1E938}', '\u{1E939}',
+    '\u{1E93A}', '\u{1E93B}', '\u{1E93C}', '\u{1E93D}', '\u{1E93E}', '\u{1E93F}', '\u{1E940}', '\u{1E941}', '\u{1E942}', '\u{1E943}',
+];
+
+// canonical combining classes which are not 0: first and last chars of the range, class
+#[rustfmt::skip]
+pub(crate) const COMBINING_CLASS: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31A, 0x31A, 232), (0x31B, 0x31B, 216), (0x31C, 0x320, 220),
+    (0x321, 0x322, 202), (0x323, 0x326, 220), (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33C, 220),
+    (0x33D, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220), (0x34A, 0x34C, 230), (0x34D, 0x34E, 220),
+    (0x350, 0x352, 230), (0x353, 0x356, 220), (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35A, 220), (0x35B, 0x35B, 230),
+    (0x35C, 0x35C, 233), (0x35D, 0x35E, 234), (0x35F, 0x35F, 233), (0x360, 0x361, 234), (0x362, 0x362, 233), (0x363, 0x36F, 230),
+    (0x483, 0x487, 230), (0x591, 0x591, 220), (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59A, 0x59A, 222),
+    (0x59B, 0x59B, 220), (0x59C, 0x5A1, 230), (0x5A2, 0x5A7, 220), (0x5A8, 0x5A9, 230), (0x5AA, 0x5AA, 220), (0x5AB, 0x5AC, 230),
+    (0x5AD, 0x5AD, 222), (0x5AE, 0x5AE, 228), (0x5AF, 0x5AF, 230), (0x5B0, 0x5B0, 10), (0x5B1, 0x5B1, 11), (0x5B2, 0x5B2, 12),
+    (0x5B3, 0x5B3, 13), (0x5B4, 0x5B4, 14), (0x5B5, 0x5B5, 15), (0x5B6, 0x5B6, 16), (0x5B7, 0x5B7, 17), (0x5B8, 0x5B8, 18),
+    (0x5B9, 0x5BA, 19), (0x5BB, 0x5BB, 20), (0x5BC, 0x5BC, 21), (0x5BD, 0x5BD, 22), (0x5BF, 0x5BF, 23), (0x5C1, 0x5C1, 24),
+    (0x5C2, 0x5C2, 25), (0x5C4, 0x5C4, 230), (0x5C5, 0x5C5, 220), (0x5C7, 0x5C7, 18), (0x610, 0x617, 230), (0x618, 0x618, 30),
+    (0x619, 0x619, 31), (0x61A, 0x61A, 32), (0x64B, 0x64B, 27), (0x64C, 0x64C, 28), (0x64D, 0x64D, 29), (0x64E, 0x64E, 30),
+    (0x64F, 0x64F, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34), (0x653, 0x654, 230), (0x655, 0x656, 220),
+    (0x657, 0x65B, 230), (0x65C, 0x65C, 220), (0x65D, 0x65E, 230), (0x65F, 0x65F, 220), (0x670, 0x670, 35), (0x6D6, 0x6DC, 230),
+    (0x6DF, 0x6E2, 230), (0x6E3, 0x6E3, 220), (0x6E4, 0x6E4, 230), (0x6E7, 0x6E8, 230), (0x6EA, 0x6EA, 220), (0x6EB, 0x6EC, 230),
+    (0x6ED, 0x6ED, 220), (0x711, 0x711, 36), (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73A, 0x73A, 230), (0x73B, 0x73C, 220), (0x73D, 0x73D, 230), (0x73E, 0x73E, 220),
+    (0x73F, 0x741, 230), (0x742, 0x742, 220), (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74A, 230), (0x7EB, 0x7F1, 230), (0x7F2, 0x7F2, 220), (0x7F3, 0x7F3, 230),
+    (0x7FD, 0x7FD, 220), (0x816, 0x819, 230), (0x81B, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82D, 230), (0x859, 0x85B, 220),
+    (0x898, 0x898, 230), (0x899, 0x89B, 220), (0x89C, 0x89F, 230), (0x8CA, 0x8CE, 230), (0x8CF, 0x8D3, 220), (0x8D4, 0x8E1, 230),
+    (0x8E3, 0x8E3, 220), (0x8E4, 0x8E5, 230), (0x8E6, 0x8E6, 220), (0x8E7, 0x8E8, 230), (0x8E9, 0x8E9, 220), (0x8EA, 0x8EC, 230),
+    (0x8ED, 0x8EF, 220), (0x8F0, 0x8F0, 27), (0x8F1, 0x8F1, 28), (0x8F2, 0x8F2, 29), (0x8F3, 0x8F5, 230), (0x8F6, 0x8F6, 220),
+    (0x8F7, 0x8F8, 230), (0x8F9, 0x8FA, 220), (0x8FB, 0x8FF, 230), (0x93C, 0x93C, 7), (0x94D, 0x94D, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9BC, 0x9BC, 7), (0x9CD, 0x9CD, 9), (0x9FE, 0x9FE, 230), (0xA3C, 0xA3C, 7),
+    (0xA4D, 0xA4D, 9), (0xABC, 0xABC, 7), (0xACD, 0xACD, 9), (0xB3C, 0xB3C, 7), (0xB4D, 0xB4D, 9), (0xBCD, 0xBCD, 9),
+    (0xC3C, 0xC3C, 7), (0xC4D, 0xC4D, 9), (0xC55, 0xC55, 84), (0xC56, 0xC56, 91), (0xCBC, 0xCBC, 7), (0xCCD, 0xCCD, 9),
+    (0xD3B, 0xD3C, 9), (0xD4D, 0xD4D, 9), (0xDCA, 0xDCA, 9), (0xE38, 0xE39, 103), (0xE3A, 0xE3A, 9), (0xE48, 0xE4B, 107),
+    (0xEB8, 0xEB9, 118), (0xEBA, 0xEBA, 9), (0xEC8, 0xECB, 122), (0xF18, 0xF19, 220), (0xF35, 0xF35, 220), (0xF37, 0xF37, 220),
+    (0xF39, 0xF39, 216), (0xF71, 0xF71, 129), (0xF72, 0xF72, 130), (0xF74, 0xF74, 132), (0xF7A, 0xF7D, 130), (0xF80, 0xF80, 130),
+    (0xF82, 0xF83, 230), (0xF84, 0xF84, 9), (0xF86, 0xF87, 230), (0xFC6, 0xFC6, 220), (0x1037, 0x1037, 7), (0x1039, 0x103A, 9),
+    (0x108D, 0x108D, 220), (0x135D, 0x135F, 230), (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9), (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228), (0x1939, 0x1939, 222), (0x193A, 0x193A, 230), (0x193B, 0x193B, 220), (0x1A17, 0x1A17, 230), (0x1A18, 0x1A18, 220),
+    (0x1A60, 0x1A60, 9), (0x1A75, 0x1A7C, 230), (0x1A7F, 0x1A7F, 220), (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220), (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220), (0x1ABF, 0x1AC0, 220), (0x1AC1, 0x1AC2, 230), (0x1AC3, 0x1AC4, 220), (0x1AC5, 0x1AC9, 230), (0x1ACA, 0x1ACA, 220),
+    (0x1ACB, 0x1ACE, 230), (0x1B34, 0x1B34, 7), (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230), (0x1B6C, 0x1B6C, 220), (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9), (0x1BE6, 0x1BE6, 7), (0x1BF2, 0x1BF3, 9), (0x1C37, 0x1C37, 7), (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1),
+    (0x1CD5, 0x1CD9, 220), (0x1CDA, 0x1CDB, 230), (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230), (0x1CE2, 0x1CE8, 1), (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230), (0x1CF8, 0x1CF9, 230), (0x1DC0, 0x1DC1, 230), (0x1DC2, 0x1DC2, 220), (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220),
+    (0x1DCB, 0x1DCC, 230), (0x1DCD, 0x1DCD, 234), (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220), (0x1DD0, 0x1DD0, 202), (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232), (0x1DF7, 0x1DF8, 228), (0x1DF9, 0x1DF9, 220), (0x1DFA, 0x1DFA, 218), (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233),
+    (0x1DFD, 0x1DFD, 220), (0x1DFE, 0x1DFE, 230), (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230), (0x20D2, 0x20D3, 1), (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1), (0x20DB, 0x20DC, 230), (0x20E1, 0x20E1, 230), (0x20E5, 0x20E6, 1), (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220),
+    (0x20E9, 0x20E9, 230), (0x20EA, 0x20EB, 1), (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230), (0x2CEF, 0x2CF1, 230), (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230), (0x302A, 0x302A, 218), (0x302B, 0x302B, 228), (0x302C, 0x302C, 232), (0x302D, 0x302D, 222), (0x302E, 0x302F, 224),
+    (0x3099, 0x309A, 8), (0xA66F, 0xA66F, 230), (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230), (0xA6F0, 0xA6F1, 230), (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9), (0xA8C4, 0xA8C4, 9), (0xA8E0, 0xA8F1, 230), (0xA92B, 0xA92D, 220), (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7),
+    (0xA9C0, 0xA9C0, 9), (0xAAB0, 0xAAB0, 230), (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220), (0xAAB7, 0xAAB8, 230), (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230), (0xAAF6, 0xAAF6, 9), (0xABED, 0xABED, 9), (0xFB1E, 0xFB1E, 26), (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220),
+    (0xFE2E, 0xFE2F, 230), (0x101FD, 0x101FD, 220), (0x102E0, 0x102E0, 220), (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220), (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230), (0x10A39, 0x10A39, 1), (0x10A3A, 0x10A3A, 220), (0x10A3F, 0x10A3F, 9), (0x10AE5, 0x10AE5, 230), (0x10AE6, 0x10AE6, 220),
+    (0x10D24, 0x10D27, 230), (0x10EAB, 0x10EAC, 230), (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230), (0x10F4B, 0x10F4B, 220), (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220), (0x10F82, 0x10F82, 230), (0x10F83, 0x10F83, 220), (0x10F84, 0x10F84, 230), (0x10F85, 0x10F85, 220), (0x11046, 0x11046, 9),
+    (0x11070, 0x11070, 9), (0x1107F, 0x1107F, 9), (0x110B9, 0x110B9, 9), (0x110BA, 0x110BA, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7), (0x11235, 0x11235, 9), (0x11236, 0x11236, 7), (0x112E9, 0x112E9, 7),
+    (0x112EA, 0x112EA, 9), (0x1133B, 0x1133C, 7), (0x1134D, 0x1134D, 9), (0x11366, 0x1136C, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7), (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9), (0x114C3, 0x114C3, 7), (0x115BF, 0x115BF, 9), (0x115C0, 0x115C0, 7),
+    (0x1163F, 0x1163F, 9), (0x116B6, 0x116B6, 9), (0x116B7, 0x116B7, 7), (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9), (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9), (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9), (0x11A34, 0x11A34, 9), (0x11A47, 0x11A47, 9), (0x11A99, 0x11A99, 9),
+    (0x11C3F, 0x11C3F, 9), (0x11D42, 0x11D42, 7), (0x11D44, 0x11D45, 9), (0x11D97, 0x11D97, 9), (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6), (0x1BC9E, 0x1BC9E, 1), (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1), (0x1D16D, 0x1D16D, 226), (0x1D16E, 0x1D172, 216),
+    (0x1D17B, 0x1D182, 220), (0x1D185, 0x1D189, 230), (0x1D18A, 0x1D18B, 220), (0x1D1AA, 0x1D1AD, 230), (0x1D242, 0x1D244, 230), (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230), (0x1E01B, 0x1E021, 230), (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230), (0x1E130, 0x1E136, 230), (0x1E2AE, 0x1E2AE, 230),
+    (0x1E2EC, 0x1E2EF, 230), (0x1E8D0, 0x1E8D6, 220), (0x1E944, 0x1E949, 230), (0x1E94A, 0x1E94A, 7),
+];
+
+// primary composites, except Hangul syllables: first char, second char, composite
+#[rustfmt::skip]
+pub(crate) const COMPOSITION: &[(u32, u32, u32)] = &[
+    (0x3C, 0x338, 0x226E), (0x3D, 0x338, 0x2260), (0x3E, 0x338, 0x226F), (0x41, 0x300, 0xC0), (0x41, 0x301, 0xC1),
+    (0x41, 0x302, 0xC2), (0x41, 0x303, 0xC3), (0x41, 0x304, 0x100), (0x41, 0x306, 0x102), (0x41, 0x307, 0x226),
+    (0x41, 0x308, 0xC4), (0x41, 0x309, 0x1EA2), (0x41, 0x30A, 0xC5), (0x41, 0x30C, 0x1CD), (0x41, 0x30F, 0x200),
+    (0x41, 0x311, 0x202), (0x41, 0x323, 0x1EA0), (0x41, 0x325, 0x1E00), (0x41, 0x328, 0x104), (0x42, 0x307, 0x1E02),
+    (0x42, 0x323, 0x1E04), (0x42, 0x331, 0x1E06), (0x43, 0x301, 0x106), (0x43, 0x302, 0x108), (0x43, 0x307, 0x10A),
+    (0x43, 0x30C, 0x10C), (0x43, 0x327, 0xC7), (0x44, 0x307, 0x1E0A), (0x44, 0x30C, 0x10E), (0x44, 0x323, 0x1E0C),
+    (0x44, 0x327, 0x1E10), (0x44, 0x32D, 0x1E12), (0x44, 0x331, 0x1E0E), (0x45, 0x300, 0xC8), (0x45, 0x301, 0xC9),
+    (0x45, 0x302, 0xCA), (0x45, 0x303, 0x1EBC), (0x45, 0x304, 0x112), (0x45, 0x306, 0x114), (0x45, 0x307, 0x116),
+    (0x45, 0x308, 0xCB), (0x45, 0x309, 0x1EBA), (0x45, 0x30C, 0x11A), (0x45, 0x30F, 0x204), (0x45, 0x311, 0x206),
+    (0x45, 0x323, 0x1EB8), (0x45, 0x327, 0x228), (0x45, 0x328, 0x118), (0x45, 0x32D, 0x1E18), (0x45, 0x330, 0x1E1A),
+    (0x46, 0x307, 0x1E1E), (0x47, 0x301, 0x1F4), (0x47, 0x302, 0x11C), (0x47, 0x304, 0x1E20), (0x47, 0x306, 0x11E),
+    (0x47, 0x307, 0x120), (0x47, 0x30C, 0x1E6), (0x47, 0x327, 0x122), (0x48, 0x302, 0x124), (0x48, 0x307, 0x1E22),
+    (0x48, 0x308, 0x1E26), (0x48, 0x30C, 0x21E), (0x48, 0x323, 0x1E24), (0x48, 0x327, 0x1E28), (0x48, 0x32E, 0x1E2A),
+    (0x49, 0x300, 0xCC), (0x49, 0x301, 0xCD), (0x49, 0x302, 0xCE), (0x49, 0x303, 0x128), (0x49, 0x304, 0x12A),
+    (0x49, 0x306, 0x12C), (0x49, 0x307, 0x130), (0x49, 0x308, 0xCF), (0x49, 0x309, 0x1EC8), (0x49, 0x30C, 0x1CF),
+    (0x49, 0x30F, 0x208), (0x49, 0x311, 0x20A), (0x49, 0x323, 0x1ECA), (0x49, 0x328, 0x12E), (0x49, 0x330, 0x1E2C),
+    (0x4A, 0x302, 0x134), (0x4B, 0x301, 0x1E30), (0x4B, 0x30C, 0x1E8), (0x4B, 0x323, 0x1E32), (0x4B, 0x327, 0x136),
+    (0x4B, 0x331, 0x1E34), (0x4C, 0x301, 0x139), (0x4C, 0x30C, 0x13D), (0x4C, 0x323, 0x1E36), (0x4C, 0x327, 0x13B),
+    (0x4C, 0x32D, 0x1E3C), (0x4C, 0x331, 0x1E3A), (0x4D, 0x301, 0x1E3E), (0x4D, 0x307, 0x1E40), (0x4D, 0x323, 0x1E42),
+    (0x4E, 0x300, 0x1F8), (0x4E, 0x301, 0x143), (0x4E, 0x303, 0xD1), (0x4E, 0x307, 0x1E44), (0x4E, 0x30C, 0x147),
+    (0x4E, 0x323, 0x1E46), (0x4E, 0x327, 0x145), (0x4E, 0x32D, 0x1E4A), (0x4E, 0x331, 0x1E48), (0x4F, 0x300, 0xD2),
+    (0x4F, 0x301, 0xD3), (0x4F, 0x302, 0xD4), (0x4F, 0x303, 0xD5), (0x4F, 0x304, 0x14C), (0x4F, 0x306, 0x14E),
+    (0x4F, 0x307, 0x22E), (0x4F, 0x308, 0xD6), (0x4F, 0x309, 0x1ECE), (0x4F, 0x30B, 0x150), (0x4F, 0x30C, 0x1D1),
+    (0x4F, 0x30F, 0x20C), (0x4F, 0x311, 0x20E), (0x4F, 0x31B, 0x1A0), (0x4F, 0x323, 0x1ECC), (0x4F, 0x328, 0x1EA),
+    (0x50, 0x301, 0x1E54), (0x50, 0x307, 0x1E56), (0x52, 0x301, 0x154), (0x52, 0x307, 0x1E58), (0x52, 0x30C, 0x158),
+    (0x52, 0x30F, 0x210), (0x52, 0x311, 0x212), (0x52, 0x323, 0x1E5A), (0x52, 0x327, 0x156), (0x52, 0x331, 0x1E5E),
+    (0x53, 0x301, 0x15A), (0x53, 0x302, 0x15C), (0x53, 0x307, 0x1E60), (0x53, 0x30C, 0x160), (0x53, 0x323, 0x1E62),
+    (0x53, 0x326, 0x218), (0x53, 0x327, 0x15E), (0x54, 0x307, 0x1E6A), (0x54, 0x30C, 0x164), (0x54, 0x323, 0x1E6C),
+    (0x54, 0x326, 0x21A), (0x54, 0x327, 0x162), (0x54, 0x32D, 0x1E70), (0x54, 0x331, 0x1E6E), (0x55, 0x300, 0xD9),
+    (0x55, 0x301, 0xDA), (0x55, 0x302, 0xDB), (0x55, 0x303, 0x168), (0x55, 0x304, 0x16A), (0x55, 0x306, 0x16C),
+    (0x55, 0x308, 0xDC), (0x55, 0x309, 0x1EE6), (0x55, 0x30A, 0x16E), (0x55, 0x30B, 0x170), (0x55, 0x30C, 0x1D3),
+    (0x55, 0x30F, 0x214), (0x55, 0x311, 0x216), (0x55, 0x31B, 0x1AF), (0x55, 0x323, 0x1EE4), (0x55, 0x324, 0x1E72),
+    (0x55, 0x328, 0x172), (0x55, 0x32D, 0x1E76), (0x55, 0x330, 0x1E74), (0x56, 0x303, 0x1E7C), (0x56, 0x323, 0x1E7E),
+    (0x57, 0x300, 0x1E80), (0x57, 0x301, 0x1E82), (0x57, 0x302, 0x174), (0x57, 0x307, 0x1E86), (0x57, 0x308, 0x1E84),
+    (0x57, 0x323, 0x1E88), (0x58, 0x307, 0x1E8A), (0x58, 0x308, 0x1E8C), (0x59, 0x300, 0x1EF2), (0x59, 0x301, 0xDD),
+    (0x59, 0x302, 0x176), (0x59, 0x303, 0x1EF8), (0x59, 0x304, 0x232), (0x59, 0x307, 0x1E8E), (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1EF6), (0x59, 0x323, 0x1EF4), (0x5A, 0x301, 0x179), (0x5A, 0x302, 0x1E90), (0x5A, 0x307, 0x17B),
+    (0x5A, 0x30C, 0x17D), (0x5A, 0x323, 0x1E92), (0x5A, 0x331, 0x1E94), (0x61, 0x300, 0xE0), (0x61, 0x301, 0xE1),
+    (0x61, 0x302, 0xE2), (0x61, 0x303, 0xE3), (0x61, 0x304, 0x101), (0x61, 0x306, 0x103), (0x61, 0x307, 0x227),
+    (0x61, 0x308, 0xE4), (0x61, 0x309, 0x1EA3), (0x61, 0x30A, 0xE5), (0x61, 0x30C, 0x1CE), (0x61, 0x30F, 0x201),
+    (0x61, 0x311, 0x203), (0x61, 0x323, 0x1EA1), (0x61, 0x325, 0x1E01), (0x61, 0x328, 0x105), (0x62, 0x307, 0x1E03),
+    (0x62, 0x323, 0x1E05), (0x62, 0x331, 0x1E07), (0x63, 0x301, 0x107), (0x63, 0x302, 0x109), (0x63, 0x307, 0x10B),
+    (0x63, 0x30C, 0x10D), (0x63, 0x327, 0xE7), (0x64, 0x307, 0x1E0B), (0x64, 0x30C, 0x10F), (0x64, 0x323, 0x1E0D),
+    (0x64, 0x327, 0x1E11), (0x64, 0x32D, 0x1E13), (0x64, 0x331, 0x1E0F), (0x65, 0x300, 0xE8), (0x65, 0x301, 0xE9),
+    (0x65, 0x302, 0xEA), (0x65, 0x303, 0x1EBD), (0x65, 0x304, 0x113), (0x65, 0x306, 0x115), (0x65, 0x307, 0x117),
+    (0x65, 0x308, 0xEB), (0x65, 0x309, 0x1EBB), (0x65, 0x30C, 0x11B), (0x65, 0x30F, 0x205), (0x65, 0x311, 0x207),
+    (0x65, 0x323, 0x1EB9), (0x65, 0x327, 0x229), (0x65, 0x328, 0x119), (0x65, 0x32D, 0x1E19), (0x65, 0x330, 0x1E1B),
+    (0x66, 0x307, 0x1E1F), (0x67, 0x301, 0x1F5), (0x67, 0x302, 0x11D), (0x67, 0x304, 0x1E21), (0x67, 0x306, 0x11F),
+    (0x67, 0x307, 0x121), (0x67, 0x30C, 0x1E7), (0x67, 0x327, 0x123), (0x68, 0x302, 0x125), (0x68, 0x307, 0x1E23),
+    (0x68, 0x308, 0x1E27), (0x68, 0x30C, 0x21F), (0x68, 0x323, 0x1E25), (0x68, 0x327, 0x1E29), (0x68, 0x32E, 0x1E2B),
+    (0x68, 0x331, 0x1E96), (0x69, 0x300, 0xEC), (0x69, 0x301, 0xED), (0x69, 0x302, 0xEE), (0x69, 0x303, 0x129),
+    (0x69, 0x304, 0x12B), (0x69, 0x306, 0x12D), (0x69, 0x308, 0xEF), (0x69, 0x309, 0x1EC9), (0x69, 0x30C, 0x1D0),
+    (0x69, 0x30F, 0x209), (0x69, 0x311, 0x20B), (0x69, 0x323, 0x1ECB), (0x69, 0x328, 0x12F), (0x69, 0x330, 0x1E2D),
+    (0x6A, 0x302, 0x135), (0x6A, 0x30C, 0x1F0), (0x6B, 0x301, 0x1E31), (0x6B, 0x30C, 0x1E9), (0x6B, 0x323, 0x1E33),
+    (0x6B, 0x327, 0x137), (0x6B, 0x331, 0x1E35), (0x6C, 0x301, 0x13A), (0x6C, 0x30C, 0x13E), (0x6C, 0x323, 0x1E37),
+    (0x6C, 0x327, 0x13C), (0x6C, 0x32D, 0x1E3D), (0x6C, 0x331, 0x1E3B), (0x6D, 0x301, 0x1E3F), (0x6D, 0x307, 0x1E41),
+    (0x6D, 0x323, 0x1E43), (0x6E, 0x300, 0x1F9), (0x6E, 0x301, 0x144), (0x6E, 0x303, 0xF1), (0x6E, 0x307, 0x1E45),
+    (0x6E, 0x30C, 0x148), (0x6E, 0x323, 0x1E47), (0x6E, 0x327, 0x146), (0x6E, 0x32D, 0x1E4B), (0x6E, 0x331, 0x1E49),
+    (0x6F, 0x300, 0xF2), (0x6F, 0x301, 0xF3), (0x6F, 0x302, 0xF4), (0x6F, 0x303, 0xF5), (0x6F, 0x304, 0x14D),
+    (0x6F, 0x306, 0x14F), (0x6F, 0x307, 0x22F), (0x6F, 0x308, 0xF6), (0x6F, 0x309, 0x1ECF), (0x6F, 0x30B, 0x151),
+    (0x6F, 0x30C, 0x1D2), (0x6F, 0x30F, 0x20D), (0x6F, 0x311, 0x20F), (0x6F, 0x31B, 0x1A1), (0x6F, 0x323, 0x1ECD),
+    (0x6F, 0x328, 0x1EB), (0x70, 0x301, 0x1E55), (0x70, 0x307, 0x1E57), (0x72, 0x301, 0x155), (0x72, 0x307, 0x1E59),
+    (0x72, 0x30C, 0x159), (0x72, 0x30F, 0x211), (0x72, 0x311, 0x213), (0x72, 0x323, 0x1E5B), (0x72, 0x327, 0x157),
+    (0x72, 0x331, 0x1E5F), (0x73, 0x301, 0x15B), (0x73, 0x302, 0x15D), (0x73, 0x307, 0x1E61), (0x73, 0x30C, 0x161),
+    (0x73, 0x323, 0x1E63), (0x73, 0x326, 0x219), (0x73, 0x327, 0x15F), (0x74, 0x307, 0x1E6B), (0x74, 0x308, 0x1E97),
+    (0x74, 0x30C, 0x165), (0x74, 0x323, 0x1E6D), (0x74, 0x326, 0x21B), (0x74, 0x327, 0x163), (0x74, 0x32D, 0x1E71),
+    (0x74, 0x331, 0x1E6F), (0x75, 0x300, 0xF9), (0x75, 0x301, 0xFA), (0x75, 0x302, 0xFB), (0x75, 0x303, 0x169),
+    (0x75, 0x304, 0x16B), (0x75, 0x306, 0x16D), (0x75, 0x308, 0xFC), (0x75, 0x309, 0x1EE7), (0x75, 0x30A, 0x16F),
+    (0x75, 0x30B, 0x171), (0x75, 0x30C, 0x1D4), (0x75, 0x30F, 0x215), (0x75, 0x311, 0x217), (0x75, 0x31B, 0x1B0),
+    (0x75, 0x323, 0x1EE5), (0x75, 0x324, 0x1E73), (0x75, 0x328, 0x173), (0x75, 0x32D, 0x1E77), (0x75, 0x330, 0x1E75),
+    (0x76, 0x303, 0x1E7D), (0x76, 0x323, 0x1E7F), (0x77, 0x300, 0x1E81), (0x77, 0x301, 0x1E83), (0x77, 0x302, 0x175),
+    (0x77, 0x307, 0x1E87), (0x77, 0x308, 0x1E85), (0x77, 0x30A, 0x1E98), (0x77, 0x323, 0x1E89), (0x78, 0x307, 0x1E8B),
+    (0x78, 0x308, 0x1E8D), (0x79, 0x300, 0x1EF3), (0x79, 0x301, 0xFD), (0x79, 0x302, 0x177), (0x79, 0x303, 0x1EF9),
+    (0x79, 0x304, 0x233), (0x79, 0x307, 0x1E8F), (0x79, 0x308, 0xFF), (0x79, 0x309, 0x1EF7), (0x79, 0x30A, 0x1E99),
+    (0x79, 0x323, 0x1EF5), (0x7A, 0x301, 0x17A), (0x7A, 0x302, 0x1E91), (0x7A, 0x307, 0x17C), (0x7A, 0x30C, 0x17E),
+    (0x7A, 0x323, 0x1E93), (0x7A, 0x331, 0x1E95), (0xA8, 0x300, 0x1FED), (0xA8, 0x301, 0x385), (0xA8, 0x342, 0x1FC1),
+    (0xC2, 0x300, 0x1EA6), (0xC2, 0x301, 0x1EA4), (0xC2, 0x303, 0x1EAA), (0xC2, 0x309, 0x1EA8), (0xC4, 0x304, 0x1DE),
+    (0xC5, 0x301, 0x1FA), (0xC6, 0x301, 0x1FC), (0xC6, 0x304, 0x1E2), (0xC7, 0x301, 0x1E08), (0xCA, 0x300, 0x1EC0),
+    (0xCA, 0x301, 0x1EBE), (0xCA, 0x303, 0x1EC4), (0xCA, 0x309, 0x1EC2), (0xCF, 0x301, 0x1E2E), (0xD4, 0x300, 0x1ED2),
+    (0xD4, 0x301, 0x1ED0), (0xD4, 0x303, 0x1ED6), (0xD4, 0x309, 0x1ED4), (0xD5, 0x301, 0x1E4C), (0xD5, 0x304, 0x22C),
+    (0xD5, 0x308, 0x1E4E), (0xD6, 0x304, 0x22A), (0xD8, 0x301, 0x1FE), (0xDC, 0x300, 0x1DB), (0xDC, 0x301, 0x1D7),
+    (0xDC, 0x304, 0x1D5), (0xDC, 0x30C, 0x1D9), (0xE2, 0x300, 0x1EA7), (0xE2, 0x301, 0x1EA5), (0xE2, 0x303, 0x1EAB),
+    (0xE2, 0x309, 0x1EA9), (0xE4, 0x304, 0x1DF), (0xE5, 0x301, 0x1FB), (0xE6, 0x301, 0x1FD), (0xE6, 0x304, 0x1E3),
+    (0xE7, 0x301, 0x1E09), (0xEA, 0x300, 0x1EC1), (0xEA, 0x301, 0x1EBF), (0xEA, 0x303, 0x1EC5), (0xEA, 0x309, 0x1EC3),
+    (0xEF, 0x301, 0x1E2F), (0xF4, 0x300, 0x1ED3), (0xF4, 0x301, 0x1ED1), (0xF4, 0x303, 0x1ED7), (0xF4, 0x309, 0x1ED5),
+    (0xF5, 0x301, 0x1E4D), (0xF5, 0x304, 0x22D), (0xF5, 0x308, 0x1E4F), (0xF6, 0x304, 0x22B), (0xF8, 0x301, 0x1FF),
+    (0xFC, 0x300, 0x1DC), (0xFC, 0x301, 0x1D8), (0xFC, 0x304, 0x1D6), (0xFC, 0x30C, 0x1DA), (0x102, 0x300, 0x1EB0),
+    (0x102, 0x301, 0x1EAE), (0x102, 0x303, 0x1EB4), (0x102, 0x309, 0x1EB2), (0x103, 0x300, 0x1EB1), (0x103, 0x301, 0x1EAF),
+    (0x103, 0x303, 0x1EB5), (0x103, 0x309, 0x1EB3), (0x112, 0x300, 0x1E14), (0x112, 0x301, 0x1E16), (0x113, 0x300, 0x1E15),
+    (0x113, 0x301, 0x1E17), (0x14C, 0x300, 0x1E50), (0x14C, 0x301, 0x1E52), (0x14D, 0x300, 0x1E51), (0x14D, 0x301, 0x1E53),
+    (0x15A, 0x307, 0x1E64), (0x15B, 0x307, 0x1E65), (0x160, 0x307, 0x1E66), (0x161, 0x307, 0x1E67), (0x168, 0x301, 0x1E78),
+    (0x169, 0x301, 0x1E79), (0x16A, 0x308, 0x1E7A), (0x16B, 0x308, 0x1E7B), (0x17F, 0x307, 0x1E9B), (0x1A0, 0x300, 0x1EDC),
+    (0x1A0, 0x301, 0x1EDA), (0x1A0, 0x303, 0x1EE0), (0x1A0, 0x309, 0x1EDE), (0x1A0, 0x323, 0x1EE2), (0x1A1, 0x300, 0x1EDD),
+    (0x1A1, 0x301, 0x1EDB), (0x1A1, 0x303, 0x1EE1), (0x1A1, 0x309, 0x1EDF), (0x1A1, 0x323, 0x1EE3), (0x1AF, 0x300, 0x1EEA),
+    (0x1AF, 0x301, 0x1EE8), (0x1AF, 0x303, 0x1EEE), (0x1AF, 0x309, 0x1EEC), (0x1AF, 0x323, 0x1EF0), (0x1B0, 0x300, 0x1EEB),
+    (0x1B0, 0x301, 0x1EE9), (0x1B0, 0x303, 0x1EEF), (0x1B0, 0x309, 0x1EED), (0x1B0, 0x323, 0x1EF1), (0x1B7, 0x30C, 0x1EE),
+    (0x1EA, 0x304, 0x1EC), (0x1EB, 0x304, 0x1ED), (0x226, 0x304, 0x1E0), (0x227, 0x304, 0x1E1), (0x228, 0x306, 0x1E1C),
+    (0x229, 0x306, 0x1E1D), (0x22E, 0x304, 0x230), (0x22F, 0x304, 0x231), (0x292, 0x30C, 0x1EF), (0x391, 0x300, 0x1FBA),
+    (0x391, 0x301, 0x386), (0x391, 0x304, 0x1FB9), (0x391, 0x306, 0x1FB8), (0x391, 0x313, 0x1F08), (0x391, 0x314, 0x1F09),
+    (0x391, 0x345, 0x1FBC), (0x395, 0x300, 0x1FC8), (0x395, 0x301, 0x388), (0x395, 0x313, 0x1F18), (0x395, 0x314, 0x1F19),
+    (0x397, 0x300, 0x1FCA), (0x397, 0x301, 0x389), (0x397, 0x313, 0x1F28), (0x397, 0x314, 0x1F29), (0x397, 0x345, 0x1FCC),
+    (0x399, 0x300, 0x1FDA), (0x399, 0x301, 0x38A), (0x399, 0x304, 0x1FD9), (0x399, 0x306, 0x1FD8), (0x399, 0x308, 0x3AA),
+    (0x399, 0x313, 0x1F38), (0x399, 0x314, 0x1F39), (0x39F, 0x300, 0x1FF8), (0x39F, 0x301, 0x38C), (0x39F, 0x313, 0x1F48),
+    (0x39F, 0x314, 0x1F49), (0x3A1, 0x314, 0x1FEC), (0x3A5, 0x300, 0x1FEA), (0x3A5, 0x301, 0x38E), (0x3A5, 0x304, 0x1FE9),
+    (0x3A5, 0x306, 0x1FE8), (0x3A5, 0x308, 0x3AB), (0x3A5, 0x314, 0x1F59), (0x3A9, 0x300, 0x1FFA), (0x3A9, 0x301, 0x38F),
+    (0x3A9, 0x313, 0x1F68), (0x3A9, 0x314, 0x1F69), (0x3A9, 0x345, 0x1FFC), (0x3AC, 0x345, 0x1FB4), (0x3AE, 0x345, 0x1FC4),
+    (0x3B1, 0x300, 0x1F70), (0x3B1, 0x301, 0x3AC), (0x3B1, 0x304, 0x1FB1), (0x3B1, 0x306, 0x1FB0), (0x3B1, 0x313, 0x1F00),
+    (0x3B1, 0x314, 0x1F01), (0x3B1, 0x342, 0x1FB6), (0x3B1, 0x345, 0x1FB3), (0x3B5, 0x300, 0x1F72), (0x3B5, 0x301, 0x3AD),
+    (0x3B5, 0x313, 0x1F10), (0x3B5, 0x314, 0x1F11), (0x3B7, 0x300, 0x1F74), (0x3B7, 0x301, 0x3AE), (0x3B7, 0x313, 0x1F20),
+    (0x3B7, 0x314, 0x1F21), (0x3B7, 0x342, 0x1FC6), (0x3B7, 0x345, 0x1FC3), (0x3B9, 0x300, 0x1F76), (0x3B9, 0x301, 0x3AF),
+    (0x3B9, 0x304, 0x1FD1), (0x3B9, 0x306, 0x1FD0), (0x3B9, 0x308, 0x3CA), (0x3B9, 0x313, 0x1F30), (0x3B9, 0x314, 0x1F31),
+    (0x3B9, 0x342, 0x1FD6), (0x3BF, 0x300, 0x1F78), (0x3BF, 0x301, 0x3CC), (0x3BF, 0x313, 0x1F40), (0x3BF, 0x314, 0x1F41),
+    (0x3C1, 0x313, 0x1FE4), (0x3C1, 0x314, 0x1FE5), (0x3C5, 0x300, 0x1F7A), (0x3C5, 0x301, 0x3CD), (0x3C5, 0x304, 0x1FE1),
+    (0x3C5, 0x306, 0x1FE0), (0x3C5, 0x308, 0x3CB), (0x3C5, 0x313, 0x1F50), (0x3C5, 0x314, 0x1F51), (0x3C5, 0x342, 0x1FE6),
+    (0x3C9, 0x300, 0x1F7C), (0x3C9, 0x301, 0x3CE), (0x3C9, 0x313, 0x1F60), (0x3C9, 0x314, 0x1F61), (0x3C9, 0x342, 0x1FF6),
+    (0x3C9, 0x345, 0x1FF3), (0x3CA, 0x300, 0x1FD2), (0x3CA, 0x301, 0x390), (0x3CA, 0x342, 0x1FD7), (0x3CB, 0x300, 0x1FE2),
+    (0x3CB, 0x301, 0x3B0), (0x3CB, 0x342, 0x1FE7), (0x3CE, 0x345, 0x1FF4), (0x3D2, 0x301, 0x3D3), (0x3D2, 0x308, 0x3D4),
+    (0x406, 0x308, 0x407), (0x410, 0x306, 0x4D0), (0x410, 0x308, 0x4D2), (0x413, 0x301, 0x403), (0x415, 0x300, 0x400),
+    (0x415, 0x306, 0x4D6), (0x415, 0x308, 0x401), (0x416, 0x306, 0x4C1), (0x416, 0x308, 0x4DC), (0x417, 0x308, 0x4DE),
+    (0x418, 0x300, 0x40D), (0x418, 0x304, 0x4E2), (0x418, 0x306, 0x419), (0x418, 0x308, 0x4E4), (0x41A, 0x301, 0x40C),
+    (0x41E, 0x308, 0x4E6), (0x423, 0x304, 0x4EE), (0x423, 0x306, 0x40E), (0x423, 0x308, 0x4F0), (0x423, 0x30B, 0x4F2),
+    (0x427, 0x308, 0x4F4), (0x42B, 0x308, 0x4F8), (0x42D, 0x308, 0x4EC), (0x430, 0x306, 0x4D1), (0x430, 0x308, 0x4D3),
+    (0x433, 0x301, 0x453), (0x435, 0x300, 0x450), (0x435, 0x306, 0x4D7), (0x435, 0x308, 0x451), (0x436, 0x306, 0x4C2),
+    (0x436, 0x308, 0x4DD), (0x437, 0x308, 0x4DF), (0x438, 0x300, 0x45D), (0x438, 0x304, 0x4E3), (0x438, 0x306, 0x439),
+    (0x438, 0x308, 0x4E5), (0x43A, 0x301, 0x45C), (0x43E, 0x308, 0x4E7), (0x443, 0x304, 0x4EF), (0x443, 0x306, 0x45E),
+    (0x443, 0x308, 0x4F1), (0x443, 0x30B, 0x4F3), (0x447, 0x308, 0x4F5), (0x44B, 0x308, 0x4F9), (0x44D, 0x308, 0x4ED),
+    (0x456, 0x308, 0x457), (0x474, 0x30F, 0x476), (0x475, 0x30F, 0x477), (0x4D8, 0x308, 0x4DA), (0x4D9, 0x308, 0x4DB),
+    (0x4E8, 0x308, 0x4EA), (0x4E9, 0x308, 0x4EB), (0x627, 0x653, 0x622), (0x627, 0x654, 0x623), (0x627, 0x655, 0x625),
+    (0x648, 0x654, 0x624), (0x64A, 0x654, 0x626), (0x6C1, 0x654, 0x6C2), (0x6D2, 0x654, 0x6D3), (0x6D5, 0x654, 0x6C0),
+    (0x928, 0x93C, 0x929), (0x930, 0x93C, 0x931), (0x933, 0x93C, 0x934), (0x9C7, 0x9BE, 0x9CB), (0x9C7, 0x9D7, 0x9CC),
+    (0xB47, 0xB3E, 0xB4B), (0xB47, 0xB56, 0xB48), (0xB47, 0xB57, 0xB4C), (0xB92, 0xBD7, 0xB94), (0xBC6, 0xBBE, 0xBCA),
+    (0xBC6, 0xBD7, 0xBCC), (0xBC7, 0xBBE, 0xBCB), (0xC46, 0xC56, 0xC48), (0xCBF, 0xCD5, 0xCC0), (0xCC6, 0xCC2, 0xCCA),
+    (0xCC6, 0xCD5, 0xCC7), (0xCC6, 0xCD6, 0xCC8), (0xCCA, 0xCD5, 0xCCB), (0xD46, 0xD3E, 0xD4A), (0xD46, 0xD57, 0xD4C),
+    (0xD47, 0xD3E, 0xD4B), (0xDD9, 0xDCA, 0xDDA), (0xDD9, 0xDCF, 0xDDC), (0xDD9, 0xDDF, 0xDDE), (0xDDC, 0xDCA, 0xDDD),
+    (0x1025, 0x102E, 0x1026), (0x1B05, 0x1B35, 0x1B06), (0x1B07, 0x1B35, 0x1B08), (0x1B09, 0x1B35, 0x1B0A), (0x1B0B, 0x1B35, 0x1B0C),
+    (0x1B0D, 0x1B35, 0x1B0E), (0x1B11, 0x1B35, 0x1B12), (0x1B3A, 0x1B35, 0x1B3B), (0x1B3C, 0x1B35, 0x1B3D), (0x1B3E, 0x1B35, 0x1B40),
+    (0x1B3F, 0x1B35, 0x1B41), (0x1B42, 0x1B35, 0x1B43), (0x1E36, 0x304, 0x1E38), (0x1E37, 0x304, 0x1E39), (0x1E5A, 0x304, 0x1E5C),
+    (0x1E5B, 0x304, 0x1E5D), (0x1E62, 0x307, 0x1E68), (0x1E63, 0x307, 0x1E69), (0x1EA0, 0x302, 0x1EAC), (0x1EA0, 0x306, 0x1EB6),
+    (0x1EA1, 0x302, 0x1EAD), (0x1EA1, 0x306, 0x1EB7), (0x1EB8, 0x302, 0x1EC6), (0x1EB9, 0x302, 0x1EC7), (0x1ECC, 0x302, 0x1ED8),
+    (0x1ECD, 0x302, 0x1ED9), (0x1F00, 0x300, 0x1F02), (0x1F00, 0x301, 0x1F04), (0x1F00, 0x342, 0x1F06), (0x1F00, 0x345, 0x1F80),
+    (0x1F01, 0x300, 0x1F03), (0x1F01, 0x301, 0x1F05), (0x1F01, 0x342, 0x1F07), (0x1F01, 0x345, 0x1F81), (0x1F02, 0x345, 0x1F82),
+    (0x1F03, 0x345, 0x1F83), (0x1F04, 0x345, 0x1F84), (0x1F05, 0x345, 0x1F85), (0x1F06, 0x345, 0x1F86), (0x1F07, 0x345, 0x1F87),
+    (0x1F08, 0x300, 0x1F0A), (0x1F08, 0x301, 0x1F0C), (0x1F08, 0x342, 0x1F0E), (0x1F08, 0x345, 0x1F88), (0x1F09, 0x300, 0x1F0B),
+    (0x1F09, 0x301, 0x1F0D), (0x1F09, 0x342, 0x1F0F), (0x1F09, 0x345, 0x1F89), (0x1F0A, 0x345, 0x1F8A), (0x1F0B, 0x345, 0x1F8B),
+    (0x1F0C, 0x345, 0x1F8C), (0x1F0D, 0x345, 0x1F8D), (0x1F0E, 0x345, 0x1F8E), (0x1F0F, 0x345, 0x1F8F), (0x1F10, 0x300, 0x1F12),
+    (0x1F10, 0x301, 0x1F14), (0x1F11, 0x300, 0x1F13), (0x1F11, 0x301, 0x1F15), (0x1F18, 0x300, 0x1F1A), (0x1F18, 0x301, 0x1F1C),
+    (0x1F19, 0x300, 0x1F1B), (0x1F19, 0x301, 0x1F1D), (0x1F20, 0x300, 0x1F22), (0x1F20, 0x301, 0x1F24), (0x1F20, 0x342, 0x1F26),
+    (0x1F20, 0x345, 0x1F90), (0x1F21, 0x300, 0x1F23), (0x1F21, 0x301, 0x1F25), (0x1F21, 0x342, 0x1F27), (0x1F21, 0x345, 0x1F91),
+    (0x1F22, 0x345, 0x1F92), (0x1F23, 0x345, 0x1F93), (0x1F24, 0x345, 0x1F94), (0x1F25, 0x345, 0x1F95), (0x1F26, 0x345, 0x1F96),
+    (0x1F27, 0x345, 0x1F97), (0x1F28, 0x300, 0x1F2A), (0x1F28, 0x301, 0x1F2C), (0x1F28, 0x342, 0x1F2E), (0x1F28, 0x345, 0x1F98),
+    (0x1F29, 0x300, 0x1F2B), (0x1F29, 0x301, 0x1F2D), (0x1F29, 0x342, 0x1F2F), (0x1F29, 0x345, 0x1F99), (0x1F2A, 0x345, 0x1F9A),
+    (0x1F2B, 0x345, 0x1F9B), (0x1F2C, 0x345, 0x1F9C), (0x1F2D, 0x345, 0x1F9D), (0x1F2E, 0x345, 0x1F9E), (0x1F2F, 0x345, 0x1F9F),
+    (0x1F30, 0x300, 0x1F32), (0x1F30, 0x301, 0x1F34), (0x1F30, 0x342, 0x1F36), (0x1F31, 0x300, 0x1F33), (0x1F31, 0x301, 0x1F35),
+    (0x1F31, 0x342, 0x1F37), (0x1F38, 0x300, 0x1F3A), (0x1F38, 0x301, 0x1F3C), (0x1F38, 0x342, 0x1F3E), (0x1F39, 0x300, 0x1F3B),
+    (0x1F39, 0x301, 0x1F3D), (0x1F39, 0x342, 0x1F3F), (0x1F40, 0x300, 0x1F42), (0x1F40, 0x301, 0x1F44), (0x1F41, 0x300, 0x1F43),
+    (0x1F41, 0x301, 0x1F45), (0x1F48, 0x300, 0x1F4A), (0x1F48, 0x301, 0x1F4C), (0x1F49, 0x300, 0x1F4B), (0x1F49, 0x301, 0x1F4D),
+    (0x1F50, 0x300, 0x1F52), (0x1F50, 0x301, 0x1F54), (0x1F50, 0x342, 0x1F56), (0x1F51, 0x300, 0x1F53), (0x1F51, 0x301, 0x1F55),
+    (0x1F51, 0x342, 0x1F57), (0x1F59, 0x300, 0x1F5B), (0x1F59, 0x301, 0x1F5D), (0x1F59, 0x342, 0x1F5F), (0x1F60, 0x300, 0x1F62),
+    (0x1F60, 0x301, 0x1F64), (0x1F60, 0x342, 0x1F66), (0x1F60, 0x345, 0x1FA0), (0x1F61, 0x300, 0x1F63), (0x1F61, 0x301, 0x1F65),
+    (0x1F61, 0x342, 0x1F67), (0x1F61, 0x345, 0x1FA1), (0x1F62, 0x345, 0x1FA2), (0x1F63, 0x345, 0x1FA3), (0x1F64, 0x345, 0x1FA4),
+    (0x1F65, 0x345, 0x1FA5), (0x1F66, 0x345, 0x1FA6), (0x1F67, 0x345, 0x1FA7), (0x1F68, 0x300, 0x1F6A), (0x1F68, 0x301, 0x1F6C),
+    (0x1F68, 0x342, 0x1F6E), (0x1F68, 0x345, 0x1FA8), (0x1F69, 0x300, 0x1F6B), (0x1F69, 0x301, 0x1F6D), (0x1F69, 0x342, 0x1F6F),
+    (0x1F69, 0x345, 0x1FA9), (0x1F6A, 0x345, 0x1FAA), (0x1F6B, 0x345, 0x1FAB), (0x1F6C, 0x345, 0x1FAC), (0x1F6D, 0x345, 0x1FAD),
+    (0x1F6E, 0x345, 0x1FAE), (0x1F6F, 0x345, 0x1FAF), (0x1F70, 0x345, 0x1FB2), (0x1F74, 0x345, 0x1FC2), (0x1F7C, 0x345, 0x1FF2),
+    (0x1FB6, 0x345, 0x1FB7), (0x1FBF, 0x300, 0x1FCD), (0x1FBF, 0x301, 0x1FCE), (0x1FBF, 0x342, 0x1FCF), (0x1FC6, 0x345, 0x1FC7),
+    (0x1FF6, 0x345, 0x1FF7), (0x1FFE, 0x300, 0x1FDD), (0x1FFE, 0x301, 0x1FDE), (0x1FFE, 0x342, 0x1FDF), (0x2190, 0x338, 0x219A),
+    (0x2192, 0x338, 0x219B), (0x2194, 0x338, 0x21AE), (0x21D0, 0x338, 0x21CD), (0x21D2, 0x338, 0x21CF), (0x21D4, 0x338, 0x21CE),
+    (0x2203, 0x338, 0x2204), (0x2208, 0x338, 0x2209), (0x220B, 0x338, 0x220C), (0x2223, 0x338, 0x2224), (0x2225, 0x338, 0x2226),
+    (0x223C, 0x338, 0x2241), (0x2243, 0x338, 0x2244), (0x2245, 0x338, 0x2247), (0x2248, 0x338, 0x2249), (0x224D, 0x338, 0x226D),
+    (0x2261, 0x338, 0x2262), (0x2264, 0x338, 0x2270), (0x2265, 0x338, 0x2271), (0x2272, 0x338, 0x2274), (0x2273, 0x338, 0x2275),
+    (0x2276, 0x338, 0x2278), (0x2277, 0x338, 0x2279), (0x227A, 0x338, 0x2280), (0x227B, 0x338, 0x2281), (0x227C, 0x338, 0x22E0),
+    (0x227D, 0x338, 0x22E1), (0x2282, 0x338, 0x2284), (0x2283, 0x338, 0x2285), (0x2286, 0x338, 0x2288), (0x2287, 0x338, 0x2289),
+    (0x2291, 0x338, 0x22E2), (0x2292, 0x338, 0x22E3), (0x22A2, 0x338, 0x22AC), (0x22A8, 0x338, 0x22AD), (0x22A9, 0x338, 0x22AE),
+    (0x22AB, 0x338, 0x22AF), (0x22B2, 0x338, 0x22EA), (0x22B3, 0x338, 0x22EB), (0x22B4, 0x338, 0x22EC), (0x22B5, 0x338, 0x22ED),
+    (0x3046, 0x3099, 0x3094), (0x304B, 0x3099, 0x304C), (0x304D, 0x3099, 0x304E), (0x304F, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052),
+    (0x3053, 0x3099, 0x3054), (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305A), (0x305B, 0x3099, 0x305C),
+    (0x305D, 0x3099, 0x305E), (0x305F, 0x3099, 0x3060), (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065), (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069), (0x306F, 0x3099, 0x3070), (0x306F, 0x309A, 0x3071), (0x3072, 0x3099, 0x3073), (0x3072, 0x309A, 0x3074),
+    (0x3075, 0x3099, 0x3076), (0x3075, 0x309A, 0x3077), (0x3078, 0x3099, 0x3079), (0x3078, 0x309A, 0x307A), (0x307B, 0x3099, 0x307C),
+    (0x307B, 0x309A, 0x307D), (0x309D, 0x3099, 0x309E), (0x30A6, 0x3099, 0x30F4), (0x30AB, 0x3099, 0x30AC), (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0), (0x30B1, 0x3099, 0x30B2), (0x30B3, 0x3099, 0x30B4), (0x30B5, 0x3099, 0x30B6), (0x30B7, 0x3099, 0x30B8),
+    (0x30B9, 0x3099, 0x30BA), (0x30BB, 0x3099, 0x30BC), (0x30BD, 0x3099, 0x30BE), (0x30BF, 0x3099, 0x30C0), (0x30C1, 0x3099, 0x30C2),
+    (0x30C4, 0x3099, 0x30C5), (0x30C6, 0x3099, 0x30C7), (0x30C8, 0x3099, 0x30C9), (0x30CF, 0x3099, 0x30D0), (0x30CF, 0x309A, 0x30D1),
+    (0x30D2, 0x3099, 0x30D3), (0x30D2, 0x309A, 0x30D4), (0x30D5, 0x3099, 0x30D6), (0x30D5, 0x309A, 0x30D7), (0x30D8, 0x3099, 0x30D9),
+    (0x30D8, 0x309A, 0x30DA), (0x30DB, 0x3099, 0x30DC), (0x30DB, 0x309A, 0x30DD), (0x30EF, 0x3099, 0x30F7), (0x30F0, 0x3099, 0x30F8),
+    (0x30F1, 0x3099, 0x30F9), (0x30F2, 0x3099, 0x30FA), (0x30FD, 0x3099, 0x30FE), (0x11099, 0x110BA, 0x1109A), (0x1109B, 0x110BA, 0x1109C),
+    (0x110A5, 0x110BA, 0x110AB), (0x11131, 0x11127, 0x1112E), (0x11132, 0x11127, 0x1112F), (0x11347, 0x1133E, 0x1134B), (0x11347, 0x11357, 0x1134C),
+    (0x114B9, 0x114B0, 0x114BC), (0x114B9, 0x114BA, 0x114BB), (0x114B9, 0x114BD, 0x114BE), (0x115B8, 0x115AF, 0x115BA), (0x115B9, 0x115AF, 0x115BB),
+    (0x11935, 0x11930, 0x11938),
+];
@@ -0,0 +1,1088 @@
+//! Reference test vectors and the built-in known-answer self-test.
+//!
+//! All the tables follow the layout of the reference `vectors.h` from <https://github.com/veorq/SipHash>:
+//! the key is the 16 bytes `00 01 .. 0f` ([`KEY`]) and the `i`-th entry is the hash of the `i` bytes `00 01 .. (i-1)`.
+//!
+//! 64-bit values are given as `u64` integers, 128-bit values as the little-endian bytes of the `u128` integer,
+//! as written in the `out[]` buffer of the reference implementation.
+use crate::{Hash128, Hash64, SipError, SipHash};
+
+/// The key used for all the test vectors.
+pub const KEY: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+/// Run the known-answer tests for all the variants having a table in this module.
+///
+/// No allocation is made, so this can be used as a power-on self-test in a `no_std` environment.
+/// The first failing vector is reported using `SipError::SelfTestFailed`.
+///
+/// ```rust
+/// assert!(siphash_c_d::self_test().is_ok());
+/// ```
+pub fn self_test() -> Result<(), SipError> {
+    check_64::<2, 4>(&SIPHASH_2_4_64)?;
+    check_128::<2, 4>(&SIPHASH_2_4_128)?;
+    check_64::<1, 3>(&SIPHASH_1_3_64)?;
+    check_128::<1, 3>(&SIPHASH_1_3_128)?;
+    check_64::<4, 8>(&SIPHASH_4_8_64)?;
+    check_128::<4, 8>(&SIPHASH_4_8_128)?;
+
+    Ok(())
+}
+
+// the message of the i-th vector is msg[0..i]
+fn message() -> [u8; 64] {
+    let mut msg = [0u8; 64];
+    msg.iter_mut().enumerate().for_each(|(i, b)| *b = i as u8);
+    msg
+}
+
+fn check_64<const C: u8, const D: u8>(expected: &[u64; 64]) -> Result<(), SipError> {
+    let msg = message();
+
+    for (i, value) in expected.iter().enumerate() {
+        if SipHash::<C, D, Hash64>::with_key(&KEY, &msg[0..i])? != *value {
+            return Err(SipError::SelfTestFailed {
+                c: C,
+                d: D,
+                bits: 64,
+                length: i,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn check_128<const C: u8, const D: u8>(expected: &[[u8; 16]; 64]) -> Result<(), SipError> {
+    let msg = message();
+
+    for (i, value) in expected.iter().enumerate() {
+        if SipHash::<C, D, Hash128>::with_key(&KEY, &msg[0..i])?.to_le_bytes() != *value {
+            return Err(SipError::SelfTestFailed {
+                c: C,
+                d: D,
+                bits: 128,
+                length: i,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// `siphash_2_4` 64-bit values, as listed in Guava's `SipHashFunctionTest` and the reference `vectors.h`.
+pub const SIPHASH_2_4_64: [u64; 64] = [
+    0x726fdb47dd0e0e31,
+    0x74f839c593dc67fd,
+    0x0d6c8009d9a94f5a,
+    0x85676696d7fb7e2d,
+    0xcf2794e0277187b7,
+    0x18765564cd99a68d,
+    0xcbc9466e58fee3ce,
+    0xab0200f58b01d137,
+    0x93f5f5799a932462,
+    0x9e0082df0ba9e4b0,
+    0x7a5dbbc594ddb9f3,
+    0xf4b32f46226bada7,
+    0x751e8fbc860ee5fb,
+    0x14ea5627c0843d90,
+    0xf723ca908e7af2ee,
+    0xa129ca6149be45e5,
+    0x3f2acc7f57c29bdb,
+    0x699ae9f52cbe4794,
+    0x4bc1b3f0968dd39c,
+    0xbb6dc91da77961bd,
+    0xbed65cf21aa2ee98,
+    0xd0f2cbb02e3b67c7,
+    0x93536795e3a33e88,
+    0xa80c038ccd5ccec8,
+    0xb8ad50c6f649af94,
+    0xbce192de8a85b8ea,
+    0x17d835b85bbb15f3,
+    0x2f2e6163076bcfad,
+    0xde4daaaca71dc9a5,
+    0xa6a2506687956571,
+    0xad87a3535c49ef28,
+    0x32d892fad841c342,
+    0x7127512f72f27cce,
+    0xa7f32346f95978e3,
+    0x12e0b01abb051238,
+    0x15e034d40fa197ae,
+    0x314dffbe0815a3b4,
+    0x027990f029623981,
+    0xcadcd4e59ef40c4d,
+    0x9abfd8766a33735c,
+    0x0e3ea96b5304a7d0,
+    0xad0c42d6fc585992,
+    0x187306c89bc215a9,
+    0xd4a60abcf3792b95,
+    0xf935451de4f21df2,
+    0xa9538f0419755787,
+    0xdb9acddff56ca510,
+    0xd06c98cd5c0975eb,
+    0xe612a3cb9ecba951,
+    0xc766e62cfcadaf96,
+    0xee64435a9752fe72,
+    0xa192d576b245165a,
+    0x0a8787bf8ecb74b2,
+    0x81b3e73d20b49b6f,
+    0x7fa8220ba3b2ecea,
+    0x245731c13ca42499,
+    0xb78dbfaf3a8d83bd,
+    0xea1ad565322a1a0b,
+    0x60e61c23a3795013,
+    0x6606d7e446282b93,
+    0x6ca4ecb15c5f91e1,
+    0x9f626da15c9625f3,
+    0xe51b38608ef25f57,
+    0x958a324ceb064572,
+];
+
+/// `siphash_2_4` 128-bit values as little-endian bytes, as listed in the reference `vectors.h`.
+pub const SIPHASH_2_4_128: [[u8; 16]; 64] = [
+    [
+        0xa3, 0x81, 0x7f, 0x04, 0xba, 0x25, 0xa8, 0xe6, 0x6d, 0xf6, 0x72, 0x14, 0xc7, 0x55, 0x02,
+        0x93,
+    ],
+    [
+        0xda, 0x87, 0xc1, 0xd8, 0x6b, 0x99, 0xaf, 0x44, 0x34, 0x76, 0x59, 0x11, 0x9b, 0x22, 0xfc,
+        0x45,
+    ],
+    [
+        0x81, 0x77, 0x22, 0x8d, 0xa4, 0xa4, 0x5d, 0xc7, 0xfc, 0xa3, 0x8b, 0xde, 0xf6, 0x0a, 0xff,
+        0xe4,
+    ],
+    [
+        0x9c, 0x70, 0xb6, 0x0c, 0x52, 0x67, 0xa9, 0x4e, 0x5f, 0x33, 0xb6, 0xb0, 0x29, 0x85, 0xed,
+        0x51,
+    ],
+    [
+        0xf8, 0x81, 0x64, 0xc1, 0x2d, 0x9c, 0x8f, 0xaf, 0x7d, 0x0f, 0x6e, 0x7c, 0x7b, 0xcd, 0x55,
+        0x79,
+    ],
+    [
+        0x13, 0x68, 0x87, 0x59, 0x80, 0x77, 0x6f, 0x88, 0x54, 0x52, 0x7a, 0x07, 0x69, 0x0e, 0x96,
+        0x27,
+    ],
+    [
+        0x14, 0xee, 0xca, 0x33, 0x8b, 0x20, 0x86, 0x13, 0x48, 0x5e, 0xa0, 0x30, 0x8f, 0xd7, 0xa1,
+        0x5e,
+    ],
+    [
+        0xa1, 0xf1, 0xeb, 0xbe, 0xd8, 0xdb, 0xc1, 0x53, 0xc0, 0xb8, 0x4a, 0xa6, 0x1f, 0xf0, 0x82,
+        0x39,
+    ],
+    [
+        0x3b, 0x62, 0xa9, 0xba, 0x62, 0x58, 0xf5, 0x61, 0x0f, 0x83, 0xe2, 0x64, 0xf3, 0x14, 0x97,
+        0xb4,
+    ],
+    [
+        0x26, 0x44, 0x99, 0x06, 0x0a, 0xd9, 0xba, 0xab, 0xc4, 0x7f, 0x8b, 0x02, 0xbb, 0x6d, 0x71,
+        0xed,
+    ],
+    [
+        0x00, 0x11, 0x0d, 0xc3, 0x78, 0x14, 0x69, 0x56, 0xc9, 0x54, 0x47, 0xd3, 0xf3, 0xd0, 0xfb,
+        0xba,
+    ],
+    [
+        0x01, 0x51, 0xc5, 0x68, 0x38, 0x6b, 0x66, 0x77, 0xa2, 0xb4, 0xdc, 0x6f, 0x81, 0xe5, 0xdc,
+        0x18,
+    ],
+    [
+        0xd6, 0x26, 0xb2, 0x66, 0x90, 0x5e, 0xf3, 0x58, 0x82, 0x63, 0x4d, 0xf6, 0x85, 0x32, 0xc1,
+        0x25,
+    ],
+    [
+        0x98, 0x69, 0xe2, 0x47, 0xe9, 0xc0, 0x8b, 0x10, 0xd0, 0x29, 0x93, 0x4f, 0xc4, 0xb9, 0x52,
+        0xf7,
+    ],
+    [
+        0x31, 0xfc, 0xef, 0xac, 0x66, 0xd7, 0xde, 0x9c, 0x7e, 0xc7, 0x48, 0x5f, 0xe4, 0x49, 0x49,
+        0x02,
+    ],
+    [
+        0x54, 0x93, 0xe9, 0x99, 0x33, 0xb0, 0xa8, 0x11, 0x7e, 0x08, 0xec, 0x0f, 0x97, 0xcf, 0xc3,
+        0xd9,
+    ],
+    [
+        0x6e, 0xe2, 0xa4, 0xca, 0x67, 0xb0, 0x54, 0xbb, 0xfd, 0x33, 0x15, 0xbf, 0x85, 0x23, 0x05,
+        0x77,
+    ],
+    [
+        0x47, 0x3d, 0x06, 0xe8, 0x73, 0x8d, 0xb8, 0x98, 0x54, 0xc0, 0x66, 0xc4, 0x7a, 0xe4, 0x77,
+        0x40,
+    ],
+    [
+        0xa4, 0x26, 0xe5, 0xe4, 0x23, 0xbf, 0x48, 0x85, 0x29, 0x4d, 0xa4, 0x81, 0xfe, 0xae, 0xf7,
+        0x23,
+    ],
+    [
+        0x78, 0x01, 0x77, 0x31, 0xcf, 0x65, 0xfa, 0xb0, 0x74, 0xd5, 0x20, 0x89, 0x52, 0x51, 0x2e,
+        0xb1,
+    ],
+    [
+        0x9e, 0x25, 0xfc, 0x83, 0x3f, 0x22, 0x90, 0x73, 0x3e, 0x93, 0x44, 0xa5, 0xe8, 0x38, 0x39,
+        0xeb,
+    ],
+    [
+        0x56, 0x8e, 0x49, 0x5a, 0xbe, 0x52, 0x5a, 0x21, 0x8a, 0x22, 0x14, 0xcd, 0x3e, 0x07, 0x1d,
+        0x12,
+    ],
+    [
+        0x4a, 0x29, 0xb5, 0x45, 0x52, 0xd1, 0x6b, 0x9a, 0x46, 0x9c, 0x10, 0x52, 0x8e, 0xff, 0x0a,
+        0xae,
+    ],
+    [
+        0xc9, 0xd1, 0x84, 0xdd, 0xd5, 0xa9, 0xf5, 0xe0, 0xcf, 0x8c, 0xe2, 0x9a, 0x9a, 0xbf, 0x69,
+        0x1c,
+    ],
+    [
+        0x2d, 0xb4, 0x79, 0xae, 0x78, 0xbd, 0x50, 0xd8, 0x88, 0x2a, 0x8a, 0x17, 0x8a, 0x61, 0x32,
+        0xad,
+    ],
+    [
+        0x8e, 0xce, 0x5f, 0x04, 0x2d, 0x5e, 0x44, 0x7b, 0x50, 0x51, 0xb9, 0xea, 0xcb, 0x8d, 0x8f,
+        0x6f,
+    ],
+    [
+        0x9c, 0x0b, 0x53, 0xb4, 0xb3, 0xc3, 0x07, 0xe8, 0x7e, 0xae, 0xe0, 0x86, 0x78, 0x14, 0x1f,
+        0x66,
+    ],
+    [
+        0xab, 0xf2, 0x48, 0xaf, 0x69, 0xa6, 0xea, 0xe4, 0xbf, 0xd3, 0xeb, 0x2f, 0x12, 0x9e, 0xeb,
+        0x94,
+    ],
+    [
+        0x06, 0x64, 0xda, 0x16, 0x68, 0x57, 0x4b, 0x88, 0xb9, 0x35, 0xf3, 0x02, 0x73, 0x58, 0xae,
+        0xf4,
+    ],
+    [
+        0xaa, 0x4b, 0x9d, 0xc4, 0xbf, 0x33, 0x7d, 0xe9, 0x0c, 0xd4, 0xfd, 0x3c, 0x46, 0x7c, 0x6a,
+        0xb7,
+    ],
+    [
+        0xea, 0x5c, 0x7f, 0x47, 0x1f, 0xaf, 0x6b, 0xde, 0x2b, 0x1a, 0xd7, 0xd4, 0x68, 0x6d, 0x22,
+        0x87,
+    ],
+    [
+        0x29, 0x39, 0xb0, 0x18, 0x32, 0x23, 0xfa, 0xfc, 0x17, 0x23, 0xde, 0x4f, 0x52, 0xc4, 0x3d,
+        0x35,
+    ],
+    [
+        0x7c, 0x39, 0x56, 0xca, 0x5e, 0xea, 0xfc, 0x3e, 0x36, 0x3e, 0x9d, 0x55, 0x65, 0x46, 0xeb,
+        0x68,
+    ],
+    [
+        0x77, 0xc6, 0x07, 0x71, 0x46, 0xf0, 0x1c, 0x32, 0xb6, 0xb6, 0x9d, 0x5f, 0x4e, 0xa9, 0xff,
+        0xcf,
+    ],
+    [
+        0x37, 0xa6, 0x98, 0x6c, 0xb8, 0x84, 0x7e, 0xdf, 0x09, 0x25, 0xf0, 0xf1, 0x30, 0x9b, 0x54,
+        0xde,
+    ],
+    [
+        0xa7, 0x05, 0xf0, 0xe6, 0x9d, 0xa9, 0xa8, 0xf9, 0x07, 0x24, 0x1a, 0x2e, 0x92, 0x3c, 0x8c,
+        0xc8,
+    ],
+    [
+        0x3d, 0xc4, 0x7d, 0x1f, 0x29, 0xc4, 0x48, 0x46, 0x1e, 0x9e, 0x76, 0xed, 0x90, 0x4f, 0x67,
+        0x11,
+    ],
+    [
+        0x0d, 0x62, 0xbf, 0x01, 0xe6, 0xfc, 0x0e, 0x1a, 0x0d, 0x3c, 0x47, 0x51, 0xc5, 0xd3, 0x69,
+        0x2b,
+    ],
+    [
+        0x8c, 0x03, 0x46, 0x8b, 0xca, 0x7c, 0x66, 0x9e, 0xe4, 0xfd, 0x5e, 0x08, 0x4b, 0xbe, 0xe7,
+        0xb5,
+    ],
+    [
+        0x52, 0x8a, 0x5b, 0xb9, 0x3b, 0xaf, 0x2c, 0x9c, 0x44, 0x73, 0xcc, 0xe5, 0xd0, 0xd2, 0x2b,
+        0xd9,
+    ],
+    [
+        0xdf, 0x6a, 0x30, 0x1e, 0x95, 0xc9, 0x5d, 0xad, 0x97, 0xae, 0x0c, 0xc8, 0xc6, 0x91, 0x3b,
+        0xd8,
+    ],
+    [
+        0x80, 0x11, 0x89, 0x90, 0x2c, 0x85, 0x7f, 0x39, 0xe7, 0x35, 0x91, 0x28, 0x5e, 0x70, 0xb6,
+        0xdb,
+    ],
+    [
+        0xe6, 0x17, 0x34, 0x6a, 0xc9, 0xc2, 0x31, 0xbb, 0x36, 0x50, 0xae, 0x34, 0xcc, 0xca, 0x0c,
+        0x5b,
+    ],
+    [
+        0x27, 0xd9, 0x34, 0x37, 0xef, 0xb7, 0x21, 0xaa, 0x40, 0x18, 0x21, 0xdc, 0xec, 0x5a, 0xdf,
+        0x89,
+    ],
+    [
+        0x89, 0x23, 0x7d, 0x9d, 0xed, 0x9c, 0x5e, 0x78, 0xd8, 0xb1, 0xc9, 0xb1, 0x66, 0xcc, 0x73,
+        0x42,
+    ],
+    [
+        0x4a, 0x6d, 0x80, 0x91, 0xbf, 0x5e, 0x7d, 0x65, 0x11, 0x89, 0xfa, 0x94, 0xa2, 0x50, 0xb1,
+        0x4c,
+    ],
+    [
+        0x0e, 0x33, 0xf9, 0x60, 0x55, 0xe7, 0xae, 0x89, 0x3f, 0xfc, 0x0e, 0x3d, 0xcf, 0x49, 0x29,
+        0x02,
+    ],
+    [
+        0xe6, 0x1c, 0x43, 0x2b, 0x72, 0x0b, 0x19, 0xd1, 0x8e, 0xc8, 0xd8, 0x4b, 0xdc, 0x63, 0x15,
+        0x1b,
+    ],
+    [
+        0xf7, 0xe5, 0xae, 0xf5, 0x49, 0xf7, 0x82, 0xcf, 0x37, 0x90, 0x55, 0xa6, 0x08, 0x26, 0x9b,
+        0x16,
+    ],
+    [
+        0x43, 0x8d, 0x03, 0x0f, 0xd0, 0xb7, 0xa5, 0x4f, 0xa8, 0x37, 0xf2, 0xad, 0x20, 0x1a, 0x64,
+        0x03,
+    ],
+    [
+        0xa5, 0x90, 0xd3, 0xee, 0x4f, 0xbf, 0x04, 0xe3, 0x24, 0x7e, 0x0d, 0x27, 0xf2, 0x86, 0x42,
+        0x3f,
+    ],
+    [
+        0x5f, 0xe2, 0xc1, 0xa1, 0x72, 0xfe, 0x93, 0xc4, 0xb1, 0x5c, 0xd3, 0x7c, 0xae, 0xf9, 0xf5,
+        0x38,
+    ],
+    [
+        0x2c, 0x97, 0x32, 0x5c, 0xbd, 0x06, 0xb3, 0x6e, 0xb2, 0x13, 0x3d, 0xd0, 0x8b, 0x3a, 0x01,
+        0x7c,
+    ],
+    [
+        0x92, 0xc8, 0x14, 0x22, 0x7a, 0x6b, 0xca, 0x94, 0x9f, 0xf0, 0x65, 0x9f, 0x00, 0x2a, 0xd3,
+        0x9e,
+    ],
+    [
+        0xdc, 0xe8, 0x50, 0x11, 0x0b, 0xd8, 0x32, 0x8c, 0xfb, 0xd5, 0x08, 0x41, 0xd6, 0x91, 0x1d,
+        0x87,
+    ],
+    [
+        0x67, 0xf1, 0x49, 0x84, 0xc7, 0xda, 0x79, 0x12, 0x48, 0xe3, 0x2b, 0xb5, 0x92, 0x25, 0x83,
+        0xda,
+    ],
+    [
+        0x19, 0x38, 0xf2, 0xcf, 0x72, 0xd5, 0x4e, 0xe9, 0x7e, 0x94, 0x16, 0x6f, 0xa9, 0x1d, 0x2a,
+        0x36,
+    ],
+    [
+        0x74, 0x48, 0x1e, 0x96, 0x46, 0xed, 0x49, 0xfe, 0x0f, 0x62, 0x24, 0x30, 0x16, 0x04, 0x69,
+        0x8e,
+    ],
+    [
+        0x57, 0xfc, 0xa5, 0xde, 0x98, 0xa9, 0xd6, 0xd8, 0x00, 0x64, 0x38, 0xd0, 0x58, 0x3d, 0x8a,
+        0x1d,
+    ],
+    [
+        0x9f, 0xec, 0xde, 0x1c, 0xef, 0xdc, 0x1c, 0xbe, 0xd4, 0x76, 0x36, 0x74, 0xd9, 0x57, 0x53,
+        0x59,
+    ],
+    [
+        0xe3, 0x04, 0x0c, 0x00, 0xeb, 0x28, 0xf1, 0x53, 0x66, 0xca, 0x73, 0xcb, 0xd8, 0x72, 0xe7,
+        0x40,
+    ],
+    [
+        0x76, 0x97, 0x00, 0x9a, 0x6a, 0x83, 0x1d, 0xfe, 0xcc, 0xa9, 0x1c, 0x59, 0x93, 0x67, 0x0f,
+        0x7a,
+    ],
+    [
+        0x58, 0x53, 0x54, 0x23, 0x21, 0xf5, 0x67, 0xa0, 0x05, 0xd5, 0x47, 0xa4, 0xf0, 0x47, 0x59,
+        0xbd,
+    ],
+    [
+        0x51, 0x50, 0xd1, 0x77, 0x2f, 0x50, 0x83, 0x4a, 0x50, 0x3e, 0x06, 0x9a, 0x97, 0x3f, 0xbd,
+        0x7c,
+    ],
+];
+
+/// `siphash_1_3` 64-bit values.
+pub const SIPHASH_1_3_64: [u64; 64] = [
+    0xabac0158050fc4dc,
+    0xc9f49bf37d57ca93,
+    0x82cb9b024dc7d44d,
+    0x8bf80ab8e7ddf7fb,
+    0xcf75576088d38328,
+    0xdef9d52f49533b67,
+    0xc50d2b50c59f22a7,
+    0xd3927d989bb11140,
+    0x369095118d299a8e,
+    0x25a48eb36c063de4,
+    0x79de85ee92ff097f,
+    0x70c118c1f94dc352,
+    0x78a384b157b4d9a2,
+    0x306f760c1229ffa7,
+    0x605aa111c0f95d34,
+    0xd320d86d2a519956,
+    0xcc4fdd1a7d908b66,
+    0x9cf2689063dbd80c,
+    0x8ffc389cb473e63e,
+    0xf21f9de58d297d1c,
+    0xc0dc2f46a6cce040,
+    0xb992abfe2b45f844,
+    0x7ffe7b9ba320872e,
+    0x525a0e7fdae6c123,
+    0xf464aeb267349c8c,
+    0x45cd5928705b0979,
+    0x3a3e35e3ca9913a5,
+    0xa91dc74e4ade3b35,
+    0xfb0bed02ef6cd00d,
+    0x88d93cb44ab1e1f4,
+    0x540f11d643c5e663,
+    0x2370dd1f8c21d1bc,
+    0x81157b6c16a7b60d,
+    0x4d54b9e57a8ff9bf,
+    0x759f12781f2a753e,
+    0xcea1a3bebf186b91,
+    0x2cf508d3ada26206,
+    0xb6101c2da3c33057,
+    0xb3f47496ae3a36a1,
+    0x626b57547b108392,
+    0xc1d2363299e41531,
+    0x667cc1923f1ad944,
+    0x65704ffec8138825,
+    0x24f280d1c28949a6,
+    0xc2ca1cedfaf8876b,
+    0xc2164bfc9f042196,
+    0xa16e9c9368b1d623,
+    0x49fb169c8b5114fd,
+    0x9f3143f8df074c46,
+    0xc6fdaf2412cc86b3,
+    0x7eaf49d10a52098f,
+    0x1cf313559d292f9a,
+    0xc44a30dda2f41f12,
+    0x36fae98943a71ed0,
+    0x318fb34c73f0bce6,
+    0xa27abf3670a7e980,
+    0xb4bcc0db243c6d75,
+    0x23f8d852fdb71513,
+    0x8f035f4da67d8a08,
+    0xd89cd0e5b7e8f148,
+    0xf6f4e6bcf7a644ee,
+    0xaec59ad80f1837f2,
+    0xc3b2f6154b6694e0,
+    0x9d199062b7bbb3a8,
+];
+
+/// `siphash_1_3` 128-bit values as little-endian bytes.
+pub const SIPHASH_1_3_128: [[u8; 16]; 64] = [
+    [
+        0xe7, 0x7e, 0xbc, 0xb2, 0x27, 0x88, 0xa5, 0xbe, 0xfd, 0x62, 0xdb, 0x6a, 0xdd, 0x30, 0x30,
+        0x01,
+    ],
+    [
+        0xfc, 0x6f, 0x37, 0x04, 0x60, 0xd3, 0xed, 0xa8, 0x5e, 0x05, 0x73, 0xcc, 0x2b, 0x2f, 0xf0,
+        0x63,
+    ],
+    [
+        0x75, 0x78, 0x7f, 0x09, 0x05, 0x69, 0x83, 0x9b, 0x85, 0x5b, 0xc9, 0x54, 0x8c, 0x6a, 0xea,
+        0x95,
+    ],
+    [
+        0x6b, 0xc5, 0xcc, 0xfa, 0x1e, 0xdc, 0xf7, 0x9f, 0x48, 0x23, 0x18, 0x77, 0x12, 0xeb, 0xd7,
+        0x43,
+    ],
+    [
+        0x0c, 0x78, 0x4e, 0x71, 0xac, 0x2b, 0x28, 0x5a, 0x9f, 0x8e, 0x92, 0xe7, 0x8f, 0xbf, 0x2c,
+        0x25,
+    ],
+    [
+        0xf3, 0x28, 0xdb, 0x89, 0x34, 0x5b, 0x62, 0x0c, 0x79, 0x52, 0x29, 0xa4, 0x26, 0x95, 0x84,
+        0x3e,
+    ],
+    [
+        0xdc, 0xd0, 0x3d, 0x29, 0xf7, 0x43, 0xe7, 0x10, 0x09, 0x51, 0xb0, 0xe8, 0x39, 0x85, 0xa6,
+        0xf8,
+    ],
+    [
+        0x10, 0x84, 0xb9, 0x23, 0xf2, 0xaa, 0xe0, 0xc3, 0xa6, 0x2f, 0x2e, 0xc8, 0x08, 0x48, 0xab,
+        0x77,
+    ],
+    [
+        0xaa, 0x12, 0xfe, 0xe1, 0xd5, 0xe3, 0xda, 0xb4, 0x72, 0x4f, 0x16, 0xab, 0x35, 0xf9, 0xc7,
+        0x99,
+    ],
+    [
+        0x81, 0xdd, 0xb8, 0x04, 0x2c, 0xf3, 0x39, 0x94, 0xf4, 0x72, 0x0e, 0x00, 0x94, 0x13, 0x7c,
+        0x42,
+    ],
+    [
+        0x4f, 0xaa, 0x54, 0x1d, 0x5d, 0x49, 0x8e, 0x89, 0xba, 0x0e, 0xa4, 0xc3, 0x87, 0xb2, 0x2f,
+        0xb4,
+    ],
+    [
+        0x72, 0x3b, 0x9a, 0xf3, 0x55, 0x44, 0x91, 0xdb, 0xb1, 0xd6, 0x63, 0x3d, 0xfc, 0x6e, 0x0c,
+        0x4e,
+    ],
+    [
+        0xe5, 0x3f, 0x92, 0x85, 0x9e, 0x48, 0x19, 0xa8, 0xdc, 0x06, 0x95, 0x73, 0x9f, 0xea, 0x8c,
+        0x65,
+    ],
+    [
+        0xb2, 0xf8, 0x58, 0xc7, 0xc9, 0xea, 0x80, 0x1d, 0x53, 0xd6, 0x03, 0x59, 0x6d, 0x65, 0x78,
+        0x44,
+    ],
+    [
+        0x87, 0xe7, 0x62, 0x68, 0xdb, 0xc9, 0x22, 0x72, 0x26, 0xb0, 0xca, 0x66, 0x5f, 0x64, 0xe3,
+        0x78,
+    ],
+    [
+        0xc1, 0x7e, 0x55, 0x05, 0xb2, 0xbd, 0x52, 0x6c, 0x29, 0x21, 0xcd, 0xec, 0x1e, 0x7e, 0x01,
+        0x09,
+    ],
+    [
+        0xd0, 0xa8, 0xd9, 0x57, 0x15, 0x51, 0x8e, 0xeb, 0xb5, 0x13, 0xb0, 0xf8, 0x3d, 0x9e, 0x17,
+        0x93,
+    ],
+    [
+        0x23, 0x41, 0x26, 0xf9, 0x3f, 0xbb, 0x66, 0x8d, 0x97, 0x51, 0x12, 0xe8, 0xfe, 0xbd, 0xf7,
+        0xec,
+    ],
+    [
+        0xef, 0x42, 0xf0, 0x3d, 0xb7, 0x8f, 0x70, 0x4d, 0x02, 0x3c, 0x44, 0x9f, 0x16, 0xb7, 0x09,
+        0x2b,
+    ],
+    [
+        0xab, 0xf7, 0x62, 0x38, 0xc2, 0x0a, 0xf1, 0x61, 0xb2, 0x31, 0x4b, 0x4d, 0x55, 0x26, 0xbc,
+        0xe9,
+    ],
+    [
+        0x3c, 0x2c, 0x2f, 0x11, 0xbb, 0x90, 0xcf, 0x0b, 0xe3, 0x35, 0xca, 0x9b, 0x2e, 0x91, 0xe9,
+        0xb7,
+    ],
+    [
+        0x2a, 0x7a, 0x68, 0x0f, 0x22, 0xa0, 0x2a, 0x92, 0xf4, 0x51, 0x49, 0xd2, 0x0f, 0xec, 0xe0,
+        0xef,
+    ],
+    [
+        0xc9, 0xa8, 0xd1, 0x30, 0x23, 0x1d, 0xd4, 0x3e, 0x42, 0xe6, 0x45, 0x69, 0x57, 0xf8, 0x37,
+        0x79,
+    ],
+    [
+        0x1d, 0x12, 0x7b, 0x84, 0x40, 0x5c, 0xea, 0xb9, 0x9f, 0xd8, 0x77, 0x5a, 0x9b, 0xe6, 0xc5,
+        0x59,
+    ],
+    [
+        0x9e, 0x4b, 0xf8, 0x37, 0xbc, 0xfd, 0x92, 0xca, 0xce, 0x09, 0xd2, 0x06, 0x1a, 0x84, 0xd0,
+        0x4a,
+    ],
+    [
+        0x39, 0x03, 0x1a, 0x96, 0x5d, 0x73, 0xb4, 0xaf, 0x5a, 0x27, 0x4d, 0x18, 0xf9, 0x73, 0xb1,
+        0xd2,
+    ],
+    [
+        0x7f, 0x4d, 0x0a, 0x12, 0x09, 0xd6, 0x7e, 0x4e, 0xd0, 0x6f, 0x75, 0x38, 0xe1, 0xcf, 0xad,
+        0x64,
+    ],
+    [
+        0xe6, 0x1e, 0xe2, 0x40, 0xfb, 0xdc, 0xce, 0x38, 0x96, 0x9f, 0x4c, 0xd2, 0x49, 0x27, 0xdd,
+        0x93,
+    ],
+    [
+        0x4c, 0x3b, 0xa2, 0xb3, 0x7b, 0x0f, 0xdd, 0x8c, 0xfa, 0x5e, 0x95, 0xc1, 0x89, 0xb2, 0x94,
+        0x14,
+    ],
+    [
+        0xe0, 0x6f, 0xd4, 0xca, 0x06, 0x6f, 0xec, 0xdd, 0x54, 0x06, 0x8a, 0x5a, 0xd8, 0x89, 0x6f,
+        0x86,
+    ],
+    [
+        0x5c, 0xa8, 0x4c, 0x34, 0x13, 0x9c, 0x65, 0x80, 0xa8, 0x8a, 0xf2, 0x49, 0x90, 0x72, 0x07,
+        0x06,
+    ],
+    [
+        0x42, 0xea, 0x96, 0x1c, 0x5b, 0x3c, 0x85, 0x8b, 0x17, 0xc3, 0xe5, 0x50, 0xdf, 0xa7, 0x90,
+        0x10,
+    ],
+    [
+        0x40, 0x6c, 0x44, 0xde, 0xe6, 0x78, 0x57, 0xb2, 0x94, 0x31, 0x60, 0xf3, 0x0c, 0x74, 0x17,
+        0xd3,
+    ],
+    [
+        0xc5, 0xf5, 0x7b, 0xae, 0x13, 0x20, 0xfc, 0xf4, 0xb4, 0xe8, 0x68, 0xe7, 0x1d, 0x56, 0xc6,
+        0x6b,
+    ],
+    [
+        0x04, 0xbf, 0x73, 0x7a, 0x5b, 0x67, 0x6b, 0xe7, 0xc3, 0xde, 0x05, 0x01, 0x7d, 0xf4, 0xbf,
+        0xf9,
+    ],
+    [
+        0x51, 0x63, 0xc9, 0xc0, 0x3f, 0x19, 0x07, 0xea, 0x10, 0x44, 0xed, 0x5c, 0x30, 0x72, 0x7b,
+        0x4f,
+    ],
+    [
+        0x37, 0xa1, 0x10, 0xf0, 0x02, 0x71, 0x8e, 0xda, 0xd2, 0x4b, 0x3f, 0x9e, 0xe4, 0x53, 0xf1,
+        0x40,
+    ],
+    [
+        0xb9, 0x87, 0x7e, 0x38, 0x1a, 0xed, 0xd3, 0xda, 0x08, 0xc3, 0x3e, 0x75, 0xff, 0x23, 0xac,
+        0x10,
+    ],
+    [
+        0x7c, 0x50, 0x04, 0x00, 0x5e, 0xc5, 0xda, 0x4c, 0x5a, 0xc9, 0x44, 0x0e, 0x5c, 0x72, 0x31,
+        0x93,
+    ],
+    [
+        0x81, 0xb8, 0x24, 0x37, 0x83, 0xdb, 0xc6, 0x46, 0xca, 0x9d, 0x0c, 0xd8, 0x2a, 0xbd, 0xb4,
+        0x6c,
+    ],
+    [
+        0x50, 0x57, 0x20, 0x54, 0x3e, 0xb9, 0xb4, 0x13, 0xd5, 0x0b, 0x3c, 0xfa, 0xd9, 0xee, 0xf9,
+        0x38,
+    ],
+    [
+        0x94, 0x5f, 0x59, 0x4d, 0xe7, 0x24, 0x11, 0xe4, 0xd3, 0x35, 0xbe, 0x87, 0x44, 0x56, 0xd8,
+        0xf3,
+    ],
+    [
+        0x37, 0x92, 0x3b, 0x3e, 0x37, 0x17, 0x77, 0xb2, 0x11, 0x70, 0xbf, 0x9d, 0x7e, 0x62, 0xf6,
+        0x02,
+    ],
+    [
+        0x3a, 0xd4, 0xe7, 0xc8, 0x57, 0x64, 0x96, 0x46, 0x11, 0xeb, 0x0a, 0x6c, 0x4d, 0x62, 0xde,
+        0x56,
+    ],
+    [
+        0xcd, 0x91, 0x39, 0x6c, 0x44, 0xaf, 0x4f, 0x51, 0x85, 0x57, 0x8d, 0x9d, 0xd9, 0x80, 0x3f,
+        0x0a,
+    ],
+    [
+        0xfe, 0x28, 0x15, 0x8e, 0x72, 0x7b, 0x86, 0x8f, 0x39, 0x03, 0xc9, 0xac, 0xda, 0x64, 0xa2,
+        0x58,
+    ],
+    [
+        0x40, 0xcc, 0x10, 0xb8, 0x28, 0x8c, 0xe5, 0xf0, 0xbc, 0x3a, 0xc0, 0xb6, 0x8a, 0x0e, 0xeb,
+        0xc8,
+    ],
+    [
+        0x6f, 0x14, 0x90, 0xf5, 0x40, 0x69, 0x9a, 0x3c, 0xd4, 0x97, 0x44, 0x20, 0xec, 0xc9, 0x27,
+        0x37,
+    ],
+    [
+        0xd5, 0x05, 0xf1, 0xb7, 0x5e, 0x1a, 0x84, 0xa6, 0x03, 0xc4, 0x35, 0x83, 0xb2, 0xed, 0x03,
+        0x08,
+    ],
+    [
+        0x49, 0x15, 0x73, 0xcf, 0xd7, 0x2b, 0xb4, 0x68, 0x2b, 0x7c, 0xa5, 0x88, 0x0e, 0x1c, 0x8d,
+        0x6f,
+    ],
+    [
+        0x3e, 0xd6, 0x9c, 0xfe, 0x45, 0xab, 0x40, 0x3f, 0x2f, 0xd2, 0xad, 0x95, 0x9b, 0xa2, 0x76,
+        0x66,
+    ],
+    [
+        0x8b, 0xe8, 0x39, 0xef, 0x1b, 0x20, 0xb5, 0x7c, 0x83, 0xba, 0x7e, 0xb6, 0xa8, 0xc2, 0x2b,
+        0x6a,
+    ],
+    [
+        0x14, 0x09, 0x18, 0x6a, 0xb4, 0x22, 0x31, 0xfe, 0xde, 0xe1, 0x81, 0x62, 0xcf, 0x1c, 0xb4,
+        0xca,
+    ],
+    [
+        0x2b, 0xf3, 0xcc, 0xc2, 0x4a, 0xb6, 0x72, 0xcf, 0x15, 0x1f, 0xb8, 0xd2, 0xf3, 0xf3, 0x06,
+        0x9b,
+    ],
+    [
+        0xb9, 0xb9, 0x3a, 0x28, 0x82, 0xd6, 0x02, 0x5c, 0xdb, 0x8c, 0x56, 0xfa, 0x13, 0xf7, 0x53,
+        0x7b,
+    ],
+    [
+        0xd9, 0x7c, 0xca, 0x36, 0x94, 0xfb, 0x20, 0x6d, 0xb8, 0xbd, 0x1f, 0x36, 0x50, 0xc3, 0x33,
+        0x22,
+    ],
+    [
+        0x94, 0xec, 0x2e, 0x19, 0xa4, 0x0b, 0xe4, 0x1a, 0xf3, 0x94, 0x0d, 0x6b, 0x30, 0xc4, 0x93,
+        0x84,
+    ],
+    [
+        0x4b, 0x41, 0x60, 0x3f, 0x20, 0x9a, 0x04, 0x5b, 0xe1, 0x40, 0xa3, 0x41, 0xa3, 0xdf, 0xfe,
+        0x10,
+    ],
+    [
+        0x23, 0xfb, 0xcb, 0x30, 0x9f, 0x1c, 0xf0, 0x94, 0x89, 0x07, 0x55, 0xab, 0x1b, 0x42, 0x65,
+        0x69,
+    ],
+    [
+        0xe7, 0xd9, 0xb6, 0x56, 0x90, 0x91, 0x8a, 0x2b, 0x23, 0x2f, 0x2f, 0x5c, 0x12, 0xc8, 0x30,
+        0x0e,
+    ],
+    [
+        0xad, 0xe8, 0x3c, 0xf7, 0xe7, 0xf3, 0x84, 0x7b, 0x36, 0xfa, 0x4b, 0x54, 0xb0, 0x0d, 0xce,
+        0x61,
+    ],
+    [
+        0x06, 0x10, 0xc5, 0xf2, 0xee, 0x57, 0x1c, 0x8a, 0xc8, 0x0c, 0xbf, 0xe5, 0x38, 0xbd, 0xf1,
+        0xc7,
+    ],
+    [
+        0x27, 0x1d, 0x5d, 0x00, 0xfb, 0xdb, 0x5d, 0x15, 0x5d, 0x9d, 0xce, 0xa9, 0x7c, 0xb4, 0x02,
+        0x18,
+    ],
+    [
+        0x4c, 0x58, 0x00, 0xe3, 0x4e, 0xfe, 0x42, 0x6f, 0x07, 0x9f, 0x6b, 0x0a, 0xa7, 0x52, 0x60,
+        0xad,
+    ],
+];
+
+/// `siphash_4_8` 64-bit values.
+pub const SIPHASH_4_8_64: [u64; 64] = [
+    0xc879052b9938da41,
+    0xc85914f95295b851,
+    0x33c3ddbef0163792,
+    0x05c147657dd4466a,
+    0x48fac14a2b5938c2,
+    0xe14752cfd9d7c2f6,
+    0x8e5535c834bcb66b,
+    0x4efdbe5a713fd747,
+    0x50db2f079c8bb520,
+    0x5312e15ef39a3136,
+    0x8f848d0adbd0a948,
+    0x810a0436603969cc,
+    0x6197a77a53686d4b,
+    0x6950c9f2e9963729,
+    0x689a62a7ea1b4388,
+    0x83d389d57da9a6e0,
+    0x70acb28053f59c55,
+    0x4e79e37a11c5b7d5,
+    0x2b10ad3446453c5a,
+    0xbc3d5aa3af80a4c0,
+    0xc84b28e50927c278,
+    0x9dd6eb0d467026ef,
+    0xd884d0a986ef76d9,
+    0xe8d0ea191881d9e3,
+    0x16ecea3eb53c3389,
+    0xc64973645f6c1531,
+    0xa432763535ce4ca5,
+    0xfed2a7c025895d06,
+    0x8b3a1a2282aabb2b,
+    0x707b0964cefb0b87,
+    0x8bee9564f9e0d840,
+    0x12dffa0bf4a7fc79,
+    0xd29e762ff2fb0b00,
+    0xfa22e5f891556840,
+    0x0d9d14d874fee62b,
+    0xed60750b0e2f7eba,
+    0x97e1a7ed84e3e902,
+    0xb6632795620ae8c4,
+    0xd36d5c5dc6ed2783,
+    0xc02fa464d164fc79,
+    0x4e61fccb11754a15,
+    0x6fe6a0ec7c8d148b,
+    0xfa03c454b669eedf,
+    0xc9b77b69a6368fc5,
+    0x2131c6059cbec5a6,
+    0x3189cdfb59878ab5,
+    0x25c4cc04673a68d7,
+    0x8d44a2e5e1e66acb,
+    0x73513a3a5b69266e,
+    0x4aac339fcf077178,
+    0x84747bd9da907516,
+    0x06f36bf01e686b00,
+    0xa6cfef6602309b1c,
+    0x4bb3b0d1882f8d28,
+    0xfe6bf5acbd0611e0,
+    0x28036e5b0e1f10c0,
+    0x0a1c1b5b4591a7c3,
+    0x0f3a0b9ee1af0757,
+    0x4f5953fe29725ae6,
+    0x4caf1aabb99d2f00,
+    0x0606c14450cb2859,
+    0x2173857b960138d5,
+    0xcc99091a4f36db05,
+    0x23de0355bc8477e6,
+];
+
+/// `siphash_4_8` 128-bit values as little-endian bytes.
+pub const SIPHASH_4_8_128: [[u8; 16]; 64] = [
+    [
+        0x1f, 0x64, 0xce, 0x58, 0x6d, 0xa9, 0x04, 0xe9, 0xcf, 0xec, 0xe8, 0x54, 0x83, 0xa7, 0x0a,
+        0x6c,
+    ],
+    [
+        0x47, 0x34, 0x5d, 0xa8, 0xef, 0x4c, 0x79, 0x47, 0x6a, 0xf2, 0x7c, 0xa7, 0x91, 0xc7, 0xa2,
+        0x80,
+    ],
+    [
+        0xe1, 0x49, 0x5f, 0xa3, 0x96, 0xca, 0x2d, 0xc6, 0x22, 0x73, 0x81, 0x5f, 0x18, 0x82, 0x21,
+        0xa4,
+    ],
+    [
+        0xc7, 0xa2, 0x73, 0x84, 0x4a, 0xc5, 0x4e, 0x83, 0x5a, 0x9c, 0xb6, 0x7f, 0x81, 0x05, 0x76,
+        0x02,
+    ],
+    [
+        0x54, 0x1f, 0x52, 0xbb, 0xf4, 0x3e, 0xce, 0x4e, 0x2a, 0x95, 0xc8, 0xe0, 0x1f, 0x65, 0x6d,
+        0xef,
+    ],
+    [
+        0x17, 0x97, 0x3b, 0xd4, 0x0d, 0xf3, 0x48, 0x15, 0x24, 0x4f, 0x99, 0x0c, 0xbf, 0x12, 0xbe,
+        0x5d,
+    ],
+    [
+        0x6b, 0x0b, 0x36, 0x0d, 0x56, 0x32, 0x80, 0xcd, 0xb1, 0x7d, 0x56, 0xc9, 0x08, 0xe1, 0xf5,
+        0xff,
+    ],
+    [
+        0xed, 0x00, 0xe1, 0x3b, 0x18, 0x4b, 0xf1, 0xc2, 0x72, 0x6b, 0x8b, 0x54, 0xff, 0xd2, 0xee,
+        0xe0,
+    ],
+    [
+        0xa7, 0xd9, 0x46, 0x13, 0x8f, 0xf9, 0xed, 0xf5, 0x36, 0x4a, 0x5a, 0x23, 0xaf, 0xca, 0xe0,
+        0x63,
+    ],
+    [
+        0x9e, 0x73, 0x14, 0xb7, 0x54, 0x5c, 0xec, 0xa3, 0x8b, 0x9a, 0x55, 0x49, 0xe4, 0xfb, 0x0b,
+        0xe8,
+    ],
+    [
+        0x58, 0x6c, 0x62, 0xc6, 0x84, 0x89, 0xd1, 0x68, 0xae, 0xe6, 0x5b, 0x88, 0x9a, 0xb9, 0x12,
+        0x75,
+    ],
+    [
+        0xe6, 0x71, 0x52, 0xa6, 0x4c, 0xa3, 0xd1, 0x47, 0xc4, 0xab, 0x84, 0x1e, 0x2f, 0x2e, 0x7a,
+        0x99,
+    ],
+    [
+        0x7f, 0x1c, 0x7a, 0xea, 0x90, 0x8d, 0xe5, 0x2e, 0x3e, 0x9e, 0x08, 0x83, 0xee, 0xa8, 0x16,
+        0xaf,
+    ],
+    [
+        0xde, 0x82, 0x7a, 0xbf, 0x92, 0xb7, 0x33, 0x92, 0x3f, 0x35, 0x33, 0x0d, 0xb5, 0xef, 0x4a,
+        0x34,
+    ],
+    [
+        0x59, 0x75, 0x63, 0x64, 0x0f, 0x37, 0x9a, 0xc5, 0x37, 0x67, 0x8e, 0xe2, 0x35, 0x4c, 0x7d,
+        0xf9,
+    ],
+    [
+        0x28, 0x4d, 0x03, 0x30, 0x3a, 0x45, 0x3a, 0x59, 0x3d, 0x78, 0xf7, 0xfa, 0xdc, 0x90, 0x62,
+        0xcb,
+    ],
+    [
+        0x91, 0x4a, 0xc7, 0xa2, 0x59, 0x7f, 0x63, 0xb7, 0xc0, 0xfd, 0xe5, 0xab, 0x8d, 0x4e, 0xad,
+        0x9c,
+    ],
+    [
+        0x0d, 0x51, 0x15, 0xa4, 0x4b, 0xa4, 0x55, 0xee, 0x3a, 0x45, 0x3b, 0x95, 0xce, 0x87, 0xc3,
+        0xcb,
+    ],
+    [
+        0x54, 0x9b, 0x93, 0x9d, 0x0b, 0xf1, 0xd8, 0x94, 0x83, 0x37, 0x88, 0x5a, 0x84, 0xce, 0x79,
+        0x14,
+    ],
+    [
+        0x6c, 0x17, 0x97, 0x69, 0xcd, 0x34, 0x8a, 0xeb, 0xd2, 0xfb, 0x13, 0x57, 0x8c, 0x72, 0xb4,
+        0x6c,
+    ],
+    [
+        0xaa, 0xd0, 0x36, 0xc1, 0x38, 0xc9, 0x57, 0xe0, 0x68, 0x2a, 0x00, 0xee, 0x2f, 0x86, 0x40,
+        0x8b,
+    ],
+    [
+        0x21, 0xb1, 0xee, 0xc4, 0x2f, 0xb6, 0x70, 0xbf, 0xee, 0x90, 0x44, 0xff, 0x4e, 0xd7, 0x3a,
+        0x26,
+    ],
+    [
+        0x05, 0x93, 0xa1, 0xd6, 0x29, 0x97, 0xed, 0x37, 0x46, 0x53, 0xc9, 0x17, 0x46, 0x3f, 0x14,
+        0xeb,
+    ],
+    [
+        0x11, 0x3d, 0x31, 0x62, 0x77, 0x19, 0xf9, 0x1e, 0xa0, 0xf1, 0xff, 0xc6, 0x86, 0x57, 0xe2,
+        0x4e,
+    ],
+    [
+        0xb3, 0x39, 0x4c, 0xf7, 0x2d, 0xe0, 0x6a, 0xdd, 0x0e, 0x73, 0x14, 0xf0, 0xc2, 0x52, 0xc4,
+        0xd6,
+    ],
+    [
+        0x92, 0x2a, 0x98, 0xda, 0x9d, 0x35, 0xc3, 0x41, 0xe2, 0x45, 0x6b, 0xe4, 0xcd, 0x63, 0x89,
+        0xd2,
+    ],
+    [
+        0x59, 0x6b, 0x62, 0x30, 0xf7, 0x57, 0xb3, 0x4a, 0xa2, 0xdc, 0xea, 0x50, 0xcb, 0xb2, 0x8d,
+        0x4d,
+    ],
+    [
+        0xc2, 0x4e, 0xe4, 0x97, 0xd5, 0x5b, 0x7e, 0x80, 0x06, 0x84, 0xdf, 0x75, 0x65, 0x59, 0xee,
+        0x48,
+    ],
+    [
+        0x5e, 0x9c, 0xb6, 0xa1, 0x36, 0x68, 0x1e, 0xd4, 0x5e, 0x2b, 0x9d, 0xe4, 0xdc, 0x01, 0x81,
+        0x77,
+    ],
+    [
+        0xbf, 0xfa, 0x39, 0xca, 0x86, 0x56, 0xd3, 0x04, 0x79, 0x33, 0xed, 0xfe, 0x9d, 0x81, 0x78,
+        0xb2,
+    ],
+    [
+        0x18, 0x22, 0x94, 0x18, 0xa1, 0xd0, 0x79, 0x5a, 0x35, 0x7a, 0x80, 0x3a, 0x81, 0x34, 0xae,
+        0xa3,
+    ],
+    [
+        0x4a, 0x3e, 0x96, 0xff, 0x53, 0x47, 0x4e, 0x2e, 0x73, 0x7b, 0x69, 0x57, 0x1a, 0x77, 0xb0,
+        0x6e,
+    ],
+    [
+        0xfe, 0xd5, 0xf0, 0xf9, 0xd0, 0x37, 0x72, 0x84, 0x2e, 0x2f, 0x57, 0x2f, 0x63, 0xf1, 0x94,
+        0x50,
+    ],
+    [
+        0x39, 0x33, 0x58, 0x86, 0xc1, 0xf9, 0x42, 0x63, 0xc4, 0x0c, 0x66, 0x29, 0xc6, 0xbc, 0x44,
+        0x6f,
+    ],
+    [
+        0xee, 0xa5, 0xf9, 0x3b, 0xb3, 0x87, 0x10, 0xb0, 0x8b, 0x2c, 0x46, 0x97, 0x19, 0x8b, 0xbf,
+        0x9f,
+    ],
+    [
+        0x80, 0x6e, 0xc7, 0xb6, 0x70, 0x4f, 0x72, 0x0e, 0x37, 0x43, 0x12, 0x06, 0x61, 0x66, 0xd4,
+        0x3a,
+    ],
+    [
+        0x6e, 0x69, 0xed, 0x9d, 0xf0, 0xc9, 0x39, 0xb4, 0x9d, 0xaf, 0xee, 0xae, 0x60, 0x47, 0xb2,
+        0xa2,
+    ],
+    [
+        0x93, 0xc7, 0x7b, 0xf2, 0x98, 0xb6, 0xf9, 0xc7, 0x94, 0xa2, 0x30, 0x17, 0x7f, 0x2f, 0xd7,
+        0x38,
+    ],
+    [
+        0xff, 0xad, 0x9c, 0xd9, 0x8c, 0x2a, 0xa8, 0x75, 0xda, 0xff, 0x3a, 0x2a, 0x4c, 0xe6, 0x0c,
+        0xe6,
+    ],
+    [
+        0x4d, 0x99, 0x2f, 0xfd, 0xf9, 0x4a, 0x93, 0xcd, 0xcd, 0x64, 0xef, 0x76, 0x57, 0xf5, 0x10,
+        0xe3,
+    ],
+    [
+        0x32, 0x70, 0x62, 0x4e, 0x24, 0xe0, 0xa1, 0x1e, 0xa1, 0x86, 0xe0, 0x96, 0xbe, 0x1b, 0xce,
+        0x9b,
+    ],
+    [
+        0x31, 0xe8, 0xbb, 0xe0, 0xcb, 0x4e, 0xff, 0x51, 0x1f, 0xff, 0xc7, 0xc4, 0x09, 0x34, 0x31,
+        0x77,
+    ],
+    [
+        0xcb, 0xe1, 0x7d, 0x05, 0x87, 0x9a, 0xd9, 0x07, 0x64, 0x8a, 0x12, 0xa0, 0x70, 0x16, 0xab,
+        0x5b,
+    ],
+    [
+        0x88, 0x48, 0xd4, 0x43, 0x70, 0xe9, 0x8b, 0xe2, 0xd5, 0xd2, 0x8b, 0x46, 0x36, 0x6a, 0x0a,
+        0xfc,
+    ],
+    [
+        0xb7, 0xff, 0xd1, 0xb2, 0x42, 0x10, 0x76, 0xa9, 0x0c, 0xb5, 0xcf, 0x65, 0x54, 0x09, 0x5e,
+        0x0c,
+    ],
+    [
+        0x6a, 0x6b, 0x66, 0x6c, 0xd5, 0x23, 0xa8, 0xf6, 0xbb, 0xd8, 0x84, 0xfe, 0x1f, 0xd1, 0x05,
+        0x0c,
+    ],
+    [
+        0xa8, 0xfe, 0x8a, 0x83, 0x50, 0xfb, 0xf5, 0xc8, 0x05, 0xf1, 0x8c, 0xbd, 0x30, 0x13, 0x62,
+        0x24,
+    ],
+    [
+        0xcc, 0xe7, 0x11, 0x7a, 0xee, 0x82, 0x36, 0xf2, 0xeb, 0x3a, 0x96, 0x94, 0xd5, 0x7e, 0x62,
+        0xb5,
+    ],
+    [
+        0x3a, 0x25, 0xf0, 0xe4, 0xfc, 0x28, 0xb7, 0x0c, 0x6b, 0x30, 0x90, 0xba, 0xfe, 0xf6, 0x9f,
+        0x04,
+    ],
+    [
+        0x3f, 0x05, 0xe6, 0x26, 0x74, 0x9f, 0xc4, 0x8b, 0x81, 0x06, 0xf8, 0xe4, 0x44, 0x31, 0xdd,
+        0x4a,
+    ],
+    [
+        0x76, 0x68, 0x79, 0xf9, 0x76, 0x72, 0x16, 0x5c, 0x0a, 0xff, 0xd5, 0xfa, 0xdc, 0x77, 0x34,
+        0x5b,
+    ],
+    [
+        0x43, 0x71, 0xa0, 0x5a, 0xb6, 0x6c, 0x59, 0x8b, 0xc9, 0xc2, 0x84, 0x94, 0xa1, 0xdd, 0x2f,
+        0x0e,
+    ],
+    [
+        0x65, 0xf8, 0x5b, 0xd3, 0xa2, 0xa5, 0xf1, 0xba, 0x1f, 0x22, 0xb6, 0xef, 0xd6, 0xe0, 0x02,
+        0x66,
+    ],
+    [
+        0x76, 0xcf, 0x61, 0xda, 0xe5, 0x4b, 0x22, 0xef, 0xca, 0x6a, 0x9f, 0x22, 0x8a, 0xaf, 0x66,
+        0x11,
+    ],
+    [
+        0x6c, 0xdc, 0xc2, 0xe3, 0x9f, 0xdb, 0xa2, 0x9f, 0x88, 0x53, 0x90, 0xab, 0x9d, 0xa4, 0x84,
+        0xda,
+    ],
+    [
+        0xe1, 0xee, 0xac, 0xea, 0xcc, 0x3b, 0x67, 0xb2, 0xd8, 0xe4, 0xe2, 0x61, 0x7b, 0x2f, 0xaa,
+        0x5a,
+    ],
+    [
+        0x0b, 0xd2, 0x9f, 0x6f, 0x4c, 0xe1, 0x0f, 0x17, 0x78, 0xd6, 0xb0, 0x2e, 0xd5, 0xab, 0x5a,
+        0x6d,
+    ],
+    [
+        0xad, 0x18, 0x9f, 0x15, 0x6a, 0x52, 0x26, 0x7c, 0xe0, 0x87, 0x45, 0x83, 0x5b, 0x65, 0xa6,
+        0x07,
+    ],
+    [
+        0x0f, 0x6b, 0x99, 0x71, 0x72, 0x25, 0x66, 0xd4, 0x3d, 0xec, 0x6b, 0x99, 0xe3, 0x1c, 0x21,
+        0x8f,
+    ],
+    [
+        0xa1, 0xa4, 0xc8, 0xfa, 0x4f, 0x3d, 0xf4, 0x66, 0xd3, 0xf3, 0x9c, 0x6f, 0x3d, 0x9e, 0x1a,
+        0x74,
+    ],
+    [
+        0x3b, 0x1a, 0x3d, 0xb8, 0x8c, 0xf0, 0xc2, 0x1f, 0xc1, 0xa6, 0xd8, 0xa7, 0x2d, 0x9e, 0xf9,
+        0x1d,
+    ],
+    [
+        0xd1, 0x48, 0x68, 0x02, 0xef, 0xc0, 0x00, 0x28, 0x56, 0xc3, 0x63, 0x5a, 0x8a, 0x69, 0x2e,
+        0xe5,
+    ],
+    [
+        0xee, 0xa1, 0x5f, 0x8f, 0x7c, 0xae, 0x19, 0x99, 0xfd, 0x56, 0x49, 0x31, 0xc2, 0x2c, 0x1c,
+        0x3c,
+    ],
+    [
+        0x63, 0xf5, 0xae, 0x63, 0x28, 0xc4, 0xdb, 0x93, 0x20, 0x79, 0x61, 0xee, 0x90, 0x6b, 0xd4,
+        0xa5,
+    ],
+];
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_test() {
+        assert!(self_test().is_ok());
+    }
+
+    #[test]
+    fn test_failure_reported() {
+        let mut wrong = SIPHASH_1_3_64;
+        wrong[5] ^= 1;
+
+        let err = check_64::<1, 3>(&wrong).unwrap_err();
+        assert!(matches!(
+            err,
+            SipError::SelfTestFailed {
+                c: 1,
+                d: 3,
+                bits: 64,
+                length: 5
+            }
+        ));
+    }
+
+    #[test]
+    fn test_message() {
+        let msg = message();
+        assert_eq!(msg[0], 0);
+        assert_eq!(msg[63], 63);
+    }
+}
@@ -0,0 +1,94 @@
+#!/usr/bin/env python3
+# Generate src/unicode_tables.rs from the unicodedata module of Python.
+#
+# The Unicode version of the tables is part of the hash values of the text module: don't regenerate them with
+# another Python version unless a new Unicode version is wanted.
+import sys
+import unicodedata
+
+HANGUL = range(0xAC00, 0xD7A4)
+
+
+def chars():
+    for cp in range(0x110000):
+        if 0xD800 <= cp <= 0xDFFF or cp in HANGUL:
+            continue
+        yield cp, chr(cp)
+
+
+def mapping_table(name, doc, pairs):
+    # the mapped chars are stored in a single array, the table keeping their offset and length
+    entries, mapped = [], []
+    for cp, s in pairs:
+        entries.append("(0x%X, %d, %d)" % (cp, len(mapped), len(s)))
+        mapped.extend(s)
+
+    out = ["// %s" % doc, "#[rustfmt::skip]", "pub(crate) const %s: &[(u32, u16, u8)] = &[" % name]
+    out += ["    " + ", ".join(entries[i:i + 8]) + "," for i in range(0, len(entries), 8)]
+    out += ["];", "", "#[rustfmt::skip]", "pub(crate) const %s_CHARS: &[char] = &[" % name]
+    chunk = ["'\\u{%X}'" % ord(c) for c in mapped]
+    out += ["    " + ", ".join(chunk[i:i + 10]) + "," for i in range(0, len(chunk), 10)]
+    out += ["];", ""]
+    return out
+
+
+def main():
+    canonical, compatibility, folding, ccc, composition = [], [], [], [], []
+
+    for cp, c in chars():
+        nfd = unicodedata.normalize("NFD", c)
+        nfkd = unicodedata.normalize("NFKD", c)
+        if nfd != c:
+            canonical.append((cp, nfd))
+        if nfkd != c:
+            compatibility.append((cp, nfkd))
+
+        folded = c.casefold()
+        if folded != c:
+            folding.append((cp, folded))
+
+        combining = unicodedata.combining(c)
+        if combining:
+            if ccc and ccc[-1][1] == cp - 1 and ccc[-1][2] == combining:
+                ccc[-1][1] = cp
+            else:
+                ccc.append([cp, cp, combining])
+
+        # primary composites: the canonical decompositions of 2 chars which are recomposed by NFC
+        decomposition = unicodedata.decomposition(c).split()
+        if len(decomposition) == 2 and not decomposition[0].startswith("<"):
+            first, second = (int(x, 16) for x in decomposition)
+            if unicodedata.normalize("NFC", chr(first) + chr(second)) == c:
+                composition.append((first, second, cp))
+
+    composition.sort()
+
+    out = [
+        "// Generated by unicode_tables.py from the Unicode %s data: don't edit." % unicodedata.unidata_version,
+        "",
+        "// the version of the Unicode data",
+        "pub(crate) const UNICODE_VERSION: (u8, u8, u8) = (%s);" % ", ".join(unicodedata.unidata_version.split(".")),
+        "",
+    ]
+    out += mapping_table("CANONICAL", "full canonical decompositions, except Hangul syllables", canonical)
+    out += mapping_table("COMPATIBILITY", "full compatibility decompositions, except Hangul syllables", compatibility)
+    out += mapping_table("CASE_FOLDING", "full case folding (C and F status of CaseFolding.txt)", folding)
+
+    out += ["// canonical combining classes which are not 0: first and last chars of the range, class",
+            "#[rustfmt::skip]", "pub(crate) const COMBINING_CLASS: &[(u32, u32, u8)] = &["]
+    entries = ["(0x%X, 0x%X, %d)" % tuple(r) for r in ccc]
+    out += ["    " + ", ".join(entries[i:i + 6]) + "," for i in range(0, len(entries), 6)]
+    out += ["];", ""]
+
+    out += ["// primary composites, except Hangul syllables: first char, second char, composite",
+            "#[rustfmt::skip]", "pub(crate) const COMPOSITION: &[(u32, u32, u32)] = &["]
+    entries = ["(0x%X, 0x%X, 0x%X)" % c for c in composition]
+    out += ["    " + ", ".join(entries[i:i + 5]) + "," for i in range(0, len(entries), 5)]
+    out += ["];"]
+
+    with open(sys.argv[1] if len(sys.argv) > 1 else "src/unicode_tables.rs", "w") as f:
+        f.write("\n".join(out) + "\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,271 @@
+//! WebAssembly bindings of the `siphash_c_d` crate, for browsers, node and edge runtimes.
+//!
+//! The keys and messages are `Uint8Array`, and the hash values are either a `BigInt` or the little endian bytes
+//! in a `Uint8Array`. The `siphash_1_3`, `siphash_2_4` (the default) and `siphash_4_8` variants are available in
+//! 64 and 128 bits:
+//!
+//! ```js
+//! import { siphash, siphash128, siphashBytes, verify, SipHash } from "siphash_c_d_wasm";
+//!
+//! const key = Uint8Array.from({ length: 16 }, (_, i) => i);
+//! const msg = new TextEncoder().encode("hello, world");
+//!
+//! siphash(key, msg);                // 0x5222c673f3faebb2n
+//! siphash128(key, msg, 4, 8);       // siphash_4_8 with a 128-bit output
+//! const tag = siphashBytes(key, msg, 2, 4, 128);
+//! verify(key, msg, tag);            // true
+//!
+//! const h = new SipHash(key);
+//! h.update(msg.subarray(0, 5));
+//! h.update(msg.subarray(5));
+//! h.hexDigest();                    // "b2ebfaf373c62252"
+//! h.free();
+//! ```
+//!
+//! The core crate is used without any feature, so it stays `no_std`. The bindings are built with:
+//!
+//! ```text
+//! wasm-pack build --release --target web
+//! ```
+//!
+//! and tested in node with `wasm-pack test --node`, or `cargo test --target wasm32-unknown-unknown` from the
+//! `wasm` directory with `wasm-bindgen-test-runner` in the `PATH`.
+use siphash_c_d::{AnySipHash, SipError};
+use wasm_bindgen::prelude::*;
+
+// the calculation, c and d being 2 and 4 if not given
+fn hasher(key: &[u8], c: Option<u8>, d: Option<u8>, bits: u16) -> Result<AnySipHash, SipError> {
+    AnySipHash::new(c.unwrap_or(2), d.unwrap_or(4), bits, key)
+}
+
+fn hash(
+    key: &[u8],
+    message: &[u8],
+    c: Option<u8>,
+    d: Option<u8>,
+    bits: u16,
+) -> Result<AnySipHash, SipError> {
+    let mut siphash = hasher(key, c, d, bits)?;
+    siphash.update(message);
+    Ok(siphash)
+}
+
+// the little endian bytes of the hash value
+fn digest(siphash: &AnySipHash) -> Vec<u8> {
+    let mut out = vec![0; siphash.bits() as usize / 8];
+    siphash
+        .finalize_into(&mut out)
+        .expect("the output is the size of the hash value");
+    out
+}
+
+// a JS Error can only be created on a wasm target
+fn js_error(e: SipError) -> JsError {
+    JsError::new(&e.to_string())
+}
+
+// compare in a time which only depends on the lengths
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Return the 64-bit hash value of `message` as a `BigInt`. `c` and `d` are 2 and 4 if not given.
+#[wasm_bindgen]
+pub fn siphash(key: &[u8], message: &[u8], c: Option<u8>, d: Option<u8>) -> Result<u64, JsError> {
+    Ok(hash(key, message, c, d, 64).map_err(js_error)?.finalize() as u64)
+}
+
+/// Return the 128-bit hash value of `message` as a `BigInt`. `c` and `d` are 2 and 4 if not given.
+#[wasm_bindgen]
+pub fn siphash128(
+    key: &[u8],
+    message: &[u8],
+    c: Option<u8>,
+    d: Option<u8>,
+) -> Result<u128, JsError> {
+    Ok(hash(key, message, c, d, 128).map_err(js_error)?.finalize())
+}
+
+/// Return the little endian bytes of the hash value of `message` in a `Uint8Array`. `c`, `d` and `bits` are 2, 4
+/// and 64 if not given.
+#[wasm_bindgen(js_name = siphashBytes)]
+pub fn siphash_bytes(
+    key: &[u8],
+    message: &[u8],
+    c: Option<u8>,
+    d: Option<u8>,
+    bits: Option<u16>,
+) -> Result<Vec<u8>, JsError> {
+    Ok(digest(
+        &hash(key, message, c, d, bits.unwrap_or(64)).map_err(js_error)?,
+    ))
+}
+
+/// Check in constant time that `tag` is the 8 or 16 bytes of the hash value of `message`. `c` and `d` are 2 and 4
+/// if not given.
+#[wasm_bindgen]
+pub fn verify(
+    key: &[u8],
+    message: &[u8],
+    tag: &[u8],
+    c: Option<u8>,
+    d: Option<u8>,
+) -> Result<bool, JsError> {
+    verify_tag(key, message, tag, c, d).map_err(js_error)
+}
+
+fn verify_tag(
+    key: &[u8],
+    message: &[u8],
+    tag: &[u8],
+    c: Option<u8>,
+    d: Option<u8>,
+) -> Result<bool, SipError> {
+    match tag.len() {
+        8 | 16 => Ok(constant_time_eq(
+            &digest(&hash(key, message, c, d, tag.len() as u16 * 8)?),
+            tag,
+        )),
+        _ => Err(SipError::InvalidOutputLength(tag.len())),
+    }
+}
+
+/// A streaming hash object.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct SipHash(AnySipHash);
+
+#[wasm_bindgen]
+impl SipHash {
+    /// Create the object using `key`. `c`, `d` and `bits` are 2, 4 and 64 if not given.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        key: &[u8],
+        c: Option<u8>,
+        d: Option<u8>,
+        bits: Option<u16>,
+    ) -> Result<SipHash, JsError> {
+        Ok(SipHash(
+            hasher(key, c, d, bits.unwrap_or(64)).map_err(js_error)?,
+        ))
+    }
+
+    /// Add the bytes of `data` to the message being hashed.
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    /// Return the hash value of the data added so far as a `BigInt`.
+    pub fn value(&self) -> u128 {
+        self.0.finalize()
+    }
+
+    /// Return the little endian bytes of the hash value of the data added so far.
+    pub fn digest(&self) -> Vec<u8> {
+        digest(&self.0)
+    }
+
+    /// Return the digest as a string of hexadecimal digits.
+    #[wasm_bindgen(js_name = hexDigest)]
+    pub fn hex_digest(&self) -> String {
+        digest(&self.0)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    /// Return a copy of the object, which can be updated independently.
+    pub fn copy(&self) -> SipHash {
+        self.clone()
+    }
+
+    /// The number of bits of the hash value, 64 or 128.
+    #[wasm_bindgen(getter)]
+    pub fn bits(&self) -> u16 {
+        self.0.bits()
+    }
+}
+
+// only the code which doesn't call JS can be run on a native target: see tests/wasm.rs for the bindings
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use siphash_c_d::vectors::{self, KEY};
+
+    #[test]
+    fn test_hash() {
+        let msg: Vec<u8> = (0..64).collect();
+
+        for i in 0..64 {
+            let v = hash(&KEY, &msg[..i], None, None, 64).unwrap();
+            assert_eq!(v.finalize(), vectors::SIPHASH_2_4_64[i] as u128);
+            assert_eq!(digest(&v), vectors::SIPHASH_2_4_64[i].to_le_bytes());
+
+            let v = hash(&KEY, &msg[..i], Some(1), Some(3), 128).unwrap();
+            assert_eq!(digest(&v), vectors::SIPHASH_1_3_128[i]);
+
+            let v = hash(&KEY, &msg[..i], Some(4), Some(8), 64).unwrap();
+            assert_eq!(v.finalize(), vectors::SIPHASH_4_8_64[i] as u128);
+        }
+    }
+
+    #[test]
+    fn test_streaming() {
+        let msg: Vec<u8> = (0..64).collect();
+
+        let mut h = SipHash(hasher(&KEY, Some(4), Some(8), 128).unwrap());
+        h.update(&msg[..17]);
+        let copy = h.copy();
+        h.update(&msg[17..63]);
+
+        assert_eq!(h.digest(), vectors::SIPHASH_4_8_128[63]);
+        assert_eq!(copy.digest(), vectors::SIPHASH_4_8_128[17]);
+        assert_eq!(h.bits(), 128);
+        assert_eq!(
+            h.hex_digest(),
+            vectors::SIPHASH_4_8_128[63]
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let msg = b"hello, world";
+        let mut tag = digest(&hash(&KEY, msg, None, None, 128).unwrap());
+
+        assert_eq!(verify_tag(&KEY, msg, &tag, None, None), Ok(true));
+        assert_eq!(verify_tag(&KEY, msg, &tag[..8], None, None), Ok(false));
+        tag[15] ^= 1;
+        assert_eq!(verify_tag(&KEY, msg, &tag, None, None), Ok(false));
+        assert_eq!(
+            verify_tag(&KEY, msg, &tag[..4], None, None),
+            Err(SipError::InvalidOutputLength(4))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            hasher(&KEY[..15], None, None, 64).err(),
+            Some(SipError::KeyTooShort(15))
+        );
+        assert_eq!(
+            hasher(&KEY, Some(3), Some(5), 64).err(),
+            Some(SipError::UnsupportedVariant {
+                c: 3,
+                d: 5,
+                bits: 64
+            })
+        );
+        assert_eq!(
+            hasher(&KEY, None, None, 32).err(),
+            Some(SipError::UnsupportedVariant {
+                c: 2,
+                d: 4,
+                bits: 32
+            })
+        );
+    }
+}
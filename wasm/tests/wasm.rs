@@ -0,0 +1,49 @@
+// Tests of the bindings, which need a wasm runtime: cargo test --target wasm32-unknown-unknown, or wasm-pack test --node
+#![cfg(target_arch = "wasm32")]
+
+use siphash_c_d::vectors::{self, KEY};
+use siphash_c_d_wasm::{siphash, siphash128, siphash_bytes, verify, SipHash};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn test_one_shot() {
+    let msg: Vec<u8> = (0..64).collect();
+
+    for i in 0..64 {
+        assert_eq!(
+            siphash(&KEY, &msg[..i], None, None).unwrap(),
+            vectors::SIPHASH_2_4_64[i]
+        );
+        assert_eq!(
+            siphash128(&KEY, &msg[..i], Some(4), Some(8)).unwrap(),
+            u128::from_le_bytes(vectors::SIPHASH_4_8_128[i])
+        );
+        assert_eq!(
+            siphash_bytes(&KEY, &msg[..i], Some(1), Some(3), Some(128)).unwrap(),
+            vectors::SIPHASH_1_3_128[i]
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_streaming() {
+    let msg: Vec<u8> = (0..64).collect();
+
+    let mut h = SipHash::new(&KEY, None, None, None).unwrap();
+    h.update(&msg[..30]);
+    h.update(&msg[30..63]);
+    assert_eq!(h.value(), vectors::SIPHASH_2_4_64[63] as u128);
+    assert_eq!(h.digest(), vectors::SIPHASH_2_4_64[63].to_le_bytes());
+}
+
+#[wasm_bindgen_test]
+fn test_verify_and_errors() {
+    let tag = siphash_bytes(&KEY, b"tag", None, None, None).unwrap();
+    assert!(verify(&KEY, b"tag", &tag, None, None).unwrap());
+    assert!(!verify(&KEY, b"gat", &tag, None, None).unwrap());
+
+    assert!(verify(&KEY, b"tag", &tag[..3], None, None).is_err());
+    assert!(siphash(&KEY[..8], b"", None, None).is_err());
+    assert!(SipHash::new(&KEY, Some(3), Some(3), None).is_err());
+    assert!(SipHash::new(&KEY, None, None, Some(96)).is_err());
+}